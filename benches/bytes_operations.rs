@@ -0,0 +1,112 @@
+//! Compares marshaling into `Vec<u8>` against `bytes::BytesMut`.
+//!
+//! Run with `cargo bench --bench bytes_operations --features bytes`.
+
+use bytes::BytesMut;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rs_pfcp::ie::*;
+use rs_pfcp::message::*;
+use std::net::Ipv4Addr;
+use std::time::SystemTime;
+
+/// Create a heartbeat request for baseline performance
+fn create_heartbeat() -> heartbeat_request::HeartbeatRequest {
+    heartbeat_request::HeartbeatRequestBuilder::new(1)
+        .recovery_time_stamp(SystemTime::now())
+        .build()
+}
+
+/// Create a session establishment request with a handful of rules
+fn create_session_request() -> session_establishment_request::SessionEstablishmentRequest {
+    let pdrs: Vec<_> = (1..=4)
+        .map(|i| {
+            create_pdr::CreatePdr::uplink_access(
+                pdr_id::PdrId::new(i),
+                precedence::Precedence::new(100),
+            )
+            .to_ie()
+        })
+        .collect();
+    let fars: Vec<_> = (1..=4)
+        .map(|i| {
+            create_far::CreateFar::uplink_forward(
+                far_id::FarId::new(i),
+                destination_interface::Interface::Core,
+            )
+            .to_ie()
+        })
+        .collect();
+
+    session_establishment_request::SessionEstablishmentRequestBuilder::new(0, 1)
+        .node_id(Ipv4Addr::new(10, 0, 0, 1))
+        .fseid(0x1234567890ABCDEF, Ipv4Addr::new(10, 0, 0, 1))
+        .create_pdrs(pdrs)
+        .create_fars(fars)
+        .build()
+        .unwrap()
+}
+
+fn bench_message_vec_vs_bytes_mut(c: &mut Criterion) {
+    let messages: [(&str, Box<dyn Message>); 2] = [
+        ("heartbeat", Box::new(create_heartbeat())),
+        ("session_establishment", Box::new(create_session_request())),
+    ];
+
+    let mut group = c.benchmark_group("message_vec_vs_bytes_mut");
+    for (name, msg) in &messages {
+        group.bench_function(format!("{name}/marshal_into_vec"), |b| {
+            let mut buf = Vec::with_capacity(msg.marshaled_size());
+            b.iter(|| {
+                buf.clear();
+                black_box(msg).marshal_into(&mut buf);
+                black_box(buf.len())
+            })
+        });
+
+        group.bench_function(format!("{name}/write_to_bytes_mut"), |b| {
+            let mut buf = BytesMut::with_capacity(msg.marshaled_size());
+            b.iter(|| {
+                buf.clear();
+                black_box(msg).write_to_bytes(&mut buf);
+                black_box(buf.len())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_ie_vec_vs_bytes_mut(c: &mut Criterion) {
+    let ie = create_pdr::CreatePdr::uplink_access(
+        pdr_id::PdrId::new(1),
+        precedence::Precedence::new(100),
+    )
+    .to_ie();
+
+    let mut group = c.benchmark_group("ie_vec_vs_bytes_mut");
+    group.bench_function("marshal_into_vec", |b| {
+        let mut buf = Vec::with_capacity(ie.encoded_size());
+        b.iter(|| {
+            buf.clear();
+            black_box(&ie).marshal_into(&mut buf);
+            black_box(buf.len())
+        })
+    });
+
+    group.bench_function("write_to_bytes_mut", |b| {
+        let mut buf = BytesMut::with_capacity(ie.encoded_size());
+        b.iter(|| {
+            buf.clear();
+            black_box(&ie).write_to_bytes(&mut buf);
+            black_box(buf.len())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    bytes_marshal,
+    bench_message_vec_vs_bytes_mut,
+    bench_ie_vec_vs_bytes_mut,
+);
+
+criterion_main!(bytes_marshal);
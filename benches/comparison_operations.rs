@@ -6,9 +6,9 @@ use std::net::Ipv4Addr;
 use std::time::SystemTime;
 
 fn create_heartbeat(seq: u32) -> heartbeat_request::HeartbeatRequest {
-    let recovery_ts = recovery_time_stamp::RecoveryTimeStamp::new(SystemTime::now());
-    let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-    heartbeat_request::HeartbeatRequest::new(seq, ts_ie, None, vec![])
+    heartbeat_request::HeartbeatRequestBuilder::new(seq)
+        .recovery_time_stamp(SystemTime::now())
+        .build()
 }
 
 fn create_session_request(
@@ -52,8 +52,10 @@ fn create_session_request(
         create_bars: vec![],
         create_traffic_endpoints: vec![],
         pdn_type: None,
+        fq_csids: vec![],
         user_id: None,
         s_nssai: None,
+        hplmn_s_nssai: None,
         trace_information: None,
         recovery_time_stamp: None,
         cp_function_features: None,
@@ -62,6 +64,7 @@ fn create_session_request(
         pfcpsm_req_flags: None,
         ethernet_pdu_session_information: None,
         ies: vec![],
+        extra_ies: vec![],
     }
 }
 
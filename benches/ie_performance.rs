@@ -147,6 +147,39 @@ fn bench_memory_patterns(c: &mut Criterion) {
         })
     });
 
+    // 1000 Cause IEs: a fresh Vec per IE vs. a stack buffer vs. one reused Vec
+    let cause = cause::Cause::new(cause::CauseValue::RequestAccepted);
+    let causes: Vec<Ie> = (0..1000)
+        .map(|_| Ie::new(IeType::Cause, cause.marshal().to_vec()))
+        .collect();
+
+    group.bench_function("cause_marshal_1000_vec", |b| {
+        b.iter(|| {
+            for ie in &causes {
+                black_box(ie.marshal());
+            }
+        })
+    });
+
+    group.bench_function("cause_marshal_1000_stack", |b| {
+        b.iter(|| {
+            for ie in &causes {
+                black_box(ie.marshal_stack());
+            }
+        })
+    });
+
+    group.bench_function("cause_marshal_1000_reused_buffer", |b| {
+        let mut buf = Vec::with_capacity(16);
+        b.iter(|| {
+            for ie in &causes {
+                buf.clear();
+                ie.marshal_into(&mut buf);
+                black_box(&buf);
+            }
+        })
+    });
+
     group.finish();
 }
 
@@ -6,28 +6,16 @@ use std::time::SystemTime;
 
 /// Create a minimal heartbeat request for baseline performance
 fn create_heartbeat() -> heartbeat_request::HeartbeatRequest {
-    let recovery_ts = recovery_time_stamp::RecoveryTimeStamp::new(SystemTime::now());
-    let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-
-    heartbeat_request::HeartbeatRequest::new(
-        1, // sequence
-        ts_ie,
-        None, // source_ip_address
-        vec![],
-    )
+    heartbeat_request::HeartbeatRequestBuilder::new(1)
+        .recovery_time_stamp(SystemTime::now())
+        .build()
 }
 
 /// Create a heartbeat with recovery timestamp
 fn create_heartbeat_with_timestamp() -> heartbeat_request::HeartbeatRequest {
-    let recovery_ts = recovery_time_stamp::RecoveryTimeStamp::new(SystemTime::now());
-    let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-
-    heartbeat_request::HeartbeatRequest::new(
-        1, // sequence
-        ts_ie,
-        None, // source_ip_address
-        vec![],
-    )
+    heartbeat_request::HeartbeatRequestBuilder::new(1)
+        .recovery_time_stamp(SystemTime::now())
+        .build()
 }
 
 /// Create a session establishment request with varying complexity
@@ -73,8 +61,10 @@ fn create_session_request(
         create_bars: vec![],
         create_traffic_endpoints: vec![],
         pdn_type: None,
+        fq_csids: vec![],
         user_id: None,
         s_nssai: None,
+        hplmn_s_nssai: None,
         trace_information: None,
         recovery_time_stamp: None,
         cp_function_features: None,
@@ -83,6 +73,7 @@ fn create_session_request(
         pfcpsm_req_flags: None,
         ethernet_pdu_session_information: None,
         ies: vec![],
+        extra_ies: vec![],
     }
 }
 
@@ -312,6 +303,44 @@ fn bench_batch_marshaling(c: &mut Criterion) {
     group.finish();
 }
 
+// ============================================================================
+// IE Lookup Benchmarks
+// ============================================================================
+
+/// Benchmark `ies()` lookup of Create PDRs, which iterates the message's
+/// dedicated `create_pdrs` slice, against a linear scan over every IE.
+fn bench_ie_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ie_lookup");
+
+    for num_pdrs in [1, 10, 50].iter() {
+        let msg = create_session_request(*num_pdrs, *num_pdrs);
+        let flattened: Vec<Ie> = msg.all_ies().into_iter().cloned().collect();
+
+        group.bench_with_input(BenchmarkId::new("typed_slice", num_pdrs), &msg, |b, msg| {
+            b.iter(|| {
+                black_box(msg)
+                    .ies(IeType::CreatePdr)
+                    .map(|ie| ie.payload.len())
+                    .sum::<usize>()
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("generic_scan", num_pdrs),
+            &flattened,
+            |b, ies| {
+                b.iter(|| {
+                    IeIter::generic(black_box(ies), IeType::CreatePdr)
+                        .map(|ie| ie.payload.len())
+                        .sum::<usize>()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     message_marshal,
     bench_marshal_heartbeat,
@@ -335,9 +364,12 @@ criterion_group!(
 
 criterion_group!(message_parse, bench_parse_generic,);
 
+criterion_group!(message_ie_lookup, bench_ie_lookup,);
+
 criterion_main!(
     message_marshal,
     message_unmarshal,
     message_roundtrip,
     message_parse,
+    message_ie_lookup,
 );
@@ -0,0 +1,228 @@
+//! Session Establishment Request throughput: build, marshal, and the combined
+//! build+marshal pipeline, plus a comparison of builders against raw `Ie::new`.
+//!
+//! Each measured iteration processes a batch of `BATCH` messages so results are
+//! reported both as messages/second and bytes/second.
+//!
+//! Run with `cargo bench --bench session_throughput`.
+
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion, Throughput,
+};
+use rs_pfcp::ie::*;
+use rs_pfcp::message::*;
+use std::net::Ipv4Addr;
+
+/// Messages processed per measured iteration.
+const BATCH: u64 = 1000;
+
+const PDR_COUNT: u16 = 4;
+const FAR_COUNT: u32 = 4;
+const QER_COUNT: u32 = 2;
+const SEID: u64 = 0x1234567890ABCDEF;
+const NODE_ADDR: Ipv4Addr = Ipv4Addr::new(10, 0, 0, 1);
+
+/// Build a Session Establishment Request with 4 PDRs, 4 FARs, and 2 QERs using
+/// the typed IE and message builders.
+fn build_with_builders() -> session_establishment_request::SessionEstablishmentRequest {
+    let mut builder = session_establishment_request::SessionEstablishmentRequestBuilder::new(0, 1)
+        .node_id(NODE_ADDR)
+        .fseid(SEID, NODE_ADDR);
+
+    for i in 1..=PDR_COUNT {
+        let pdr = create_pdr::CreatePdrBuilder::new(pdr_id::PdrId::new(i))
+            .precedence(precedence::Precedence::new(100))
+            .pdi(pdi::Pdi::uplink_access())
+            .far_id(far_id::FarId::new(u32::from(i)))
+            .qer_id(qer_id::QerId::new(u32::from(i % 2) + 1))
+            .build()
+            .unwrap();
+        builder = builder.add_pdr(pdr);
+    }
+    for i in 1..=FAR_COUNT {
+        let far = create_far::CreateFarBuilder::new(far_id::FarId::new(i))
+            .forward_to(destination_interface::Interface::Core)
+            .build()
+            .unwrap();
+        builder = builder.add_far(far);
+    }
+    for i in 1..=QER_COUNT {
+        let qer = create_qer::CreateQerBuilder::open_gate(qer_id::QerId::new(i))
+            .rate_limit(1_000_000, 2_000_000)
+            .build()
+            .unwrap();
+        builder = builder.add_qer(qer);
+    }
+
+    builder.build().unwrap()
+}
+
+/// Build the same message as [`build_with_builders`], assembling every rule
+/// from raw `Ie::new` payloads instead of typed builders.
+fn build_with_raw_ies() -> session_establishment_request::SessionEstablishmentRequest {
+    let pdrs: Vec<_> = (1..=PDR_COUNT)
+        .map(|i| {
+            Ie::new_grouped(
+                IeType::CreatePdr,
+                vec![
+                    Ie::new(IeType::PdrId, i.to_be_bytes().to_vec()),
+                    Ie::new(IeType::Precedence, 100u32.to_be_bytes().to_vec()),
+                    Ie::new_grouped(
+                        IeType::Pdi,
+                        // Source Interface: Access
+                        vec![Ie::new(IeType::SourceInterface, vec![0x00])],
+                    ),
+                    Ie::new(IeType::FarId, u32::from(i).to_be_bytes().to_vec()),
+                    Ie::new(IeType::QerId, (u32::from(i % 2) + 1).to_be_bytes().to_vec()),
+                ],
+            )
+        })
+        .collect();
+    let fars: Vec<_> = (1..=FAR_COUNT)
+        .map(|i| {
+            Ie::new_grouped(
+                IeType::CreateFar,
+                vec![
+                    Ie::new(IeType::FarId, i.to_be_bytes().to_vec()),
+                    // Apply Action: FORW
+                    Ie::new(IeType::ApplyAction, vec![0x02]),
+                    Ie::new_grouped(
+                        IeType::ForwardingParameters,
+                        // Destination Interface: Core
+                        vec![Ie::new(IeType::DestinationInterface, vec![0x01])],
+                    ),
+                ],
+            )
+        })
+        .collect();
+    let qers: Vec<_> = (1..=QER_COUNT)
+        .map(|i| {
+            let mut mbr = Vec::with_capacity(10);
+            mbr.extend_from_slice(&1_000_000u64.to_be_bytes()[3..]);
+            mbr.extend_from_slice(&2_000_000u64.to_be_bytes()[3..]);
+            Ie::new_grouped(
+                IeType::CreateQer,
+                vec![
+                    Ie::new(IeType::QerId, i.to_be_bytes().to_vec()),
+                    // Gate Status: UL and DL open
+                    Ie::new(IeType::GateStatus, vec![0x00]),
+                    Ie::new(IeType::Mbr, mbr),
+                ],
+            )
+        })
+        .collect();
+
+    session_establishment_request::SessionEstablishmentRequestBuilder::new(0, 1)
+        .node_id_ie(Ie::new(IeType::NodeId, {
+            // Node ID type: IPv4
+            let mut node_id = vec![0x00];
+            node_id.extend_from_slice(&NODE_ADDR.octets());
+            node_id
+        }))
+        .fseid_ie(Ie::new(IeType::Fseid, {
+            // Flags: V4
+            let mut fseid = vec![0x02];
+            fseid.extend_from_slice(&SEID.to_be_bytes());
+            fseid.extend_from_slice(&NODE_ADDR.octets());
+            fseid
+        }))
+        .create_pdrs(pdrs)
+        .create_fars(fars)
+        .create_qers(qers)
+        .build()
+        .unwrap()
+}
+
+/// Run `register` once under message throughput and once under byte throughput.
+fn bench_both_throughputs<F>(
+    c: &mut Criterion,
+    group_name: &str,
+    message_len: usize,
+    mut register: F,
+) where
+    F: FnMut(&mut BenchmarkGroup<'_, WallTime>),
+{
+    let mut group = c.benchmark_group(format!("{group_name}/messages"));
+    group.throughput(Throughput::Elements(BATCH));
+    register(&mut group);
+    group.finish();
+
+    let mut group = c.benchmark_group(format!("{group_name}/bytes"));
+    group.throughput(Throughput::Bytes(BATCH * message_len as u64));
+    register(&mut group);
+    group.finish();
+}
+
+fn bench_session_build(c: &mut Criterion) {
+    let len = build_with_builders().marshaled_size();
+    bench_both_throughputs(c, "session_throughput", len, |group| {
+        group.bench_function("build", |b| {
+            b.iter(|| {
+                for _ in 0..BATCH {
+                    black_box(build_with_builders());
+                }
+            })
+        });
+    });
+}
+
+fn bench_session_marshal(c: &mut Criterion) {
+    let msg = build_with_builders();
+    let len = msg.marshaled_size();
+    bench_both_throughputs(c, "session_throughput", len, |group| {
+        group.bench_function("marshal", |b| {
+            b.iter(|| {
+                for _ in 0..BATCH {
+                    black_box(black_box(&msg).marshal());
+                }
+            })
+        });
+    });
+}
+
+fn bench_session_build_marshal(c: &mut Criterion) {
+    let len = build_with_builders().marshaled_size();
+    bench_both_throughputs(c, "session_throughput", len, |group| {
+        group.bench_function("build_marshal", |b| {
+            b.iter(|| {
+                for _ in 0..BATCH {
+                    black_box(build_with_builders().marshal());
+                }
+            })
+        });
+    });
+}
+
+fn bench_builder_vs_raw(c: &mut Criterion) {
+    // Both paths must produce the same message for the comparison to be fair
+    let expected = build_with_builders().marshal();
+    assert_eq!(build_with_raw_ies().marshal(), expected);
+    let len = expected.len();
+    bench_both_throughputs(c, "session_throughput/compare", len, |group| {
+        group.bench_function("builder", |b| {
+            b.iter(|| {
+                for _ in 0..BATCH {
+                    black_box(build_with_builders().marshal());
+                }
+            })
+        });
+        group.bench_function("raw_ie", |b| {
+            b.iter(|| {
+                for _ in 0..BATCH {
+                    black_box(build_with_raw_ies().marshal());
+                }
+            })
+        });
+    });
+}
+
+criterion_group!(
+    session_throughput,
+    bench_session_build,
+    bench_session_marshal,
+    bench_session_build_marshal,
+    bench_builder_vs_raw,
+);
+
+criterion_main!(session_throughput);
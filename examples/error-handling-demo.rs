@@ -208,7 +208,7 @@ fn demo_error_to_cause_mapping() {
 
     for (name, err) in errors {
         let cause = err.to_cause_code();
-        println!("   {} -> {:?} (code {})", name, cause, cause as u8);
+        println!("   {} -> {:?} (code {})", name, cause, u8::from(cause));
     }
 
     println!();
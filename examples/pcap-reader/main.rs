@@ -2,15 +2,17 @@
 
 use clap::Parser;
 use pcap_file::pcap::PcapReader;
-use pcap_file::DataLink;
+use pcap_file::pcapng::{Block, PcapNgReader};
+use pcap_file::{DataLink, PcapError};
 use rs_pfcp::message::display::MessageDisplay;
 use std::fs::File;
+use std::io::Read;
 use std::path::Path;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Path to the pcap file to read
+    /// Path to the pcap or pcapng file to read
     #[arg(short, long)]
     pcap: String,
 
@@ -23,6 +25,234 @@ struct Args {
     format: String,
 }
 
+/// A captured frame together with the link type it was recorded on.
+///
+/// Legacy pcap files have a single link type in the file header, while
+/// pcapng files carry one per interface, so it travels with each packet.
+struct CapturedPacket {
+    datalink: DataLink,
+    data: Vec<u8>,
+}
+
+/// Iterates over the packets of a capture file regardless of its format.
+trait PacketIterator {
+    /// Name of the capture format, for display.
+    fn format_name(&self) -> &'static str;
+
+    /// Returns the next packet, or `None` at the end of the file.
+    fn next_packet(&mut self) -> Option<Result<CapturedPacket, PcapError>>;
+}
+
+/// Legacy `.pcap` file.
+struct PcapPackets {
+    reader: PcapReader<File>,
+    datalink: DataLink,
+}
+
+impl PacketIterator for PcapPackets {
+    fn format_name(&self) -> &'static str {
+        "pcap"
+    }
+
+    fn next_packet(&mut self) -> Option<Result<CapturedPacket, PcapError>> {
+        let datalink = self.datalink;
+        self.reader.next_packet().map(|pkt| {
+            pkt.map(|pkt| CapturedPacket {
+                datalink,
+                data: pkt.data.into_owned(),
+            })
+        })
+    }
+}
+
+/// `.pcapng` file; only packet blocks are yielded, all other blocks are skipped.
+struct PcapNgPackets {
+    reader: PcapNgReader<File>,
+}
+
+impl PacketIterator for PcapNgPackets {
+    fn format_name(&self) -> &'static str {
+        "pcapng"
+    }
+
+    fn next_packet(&mut self) -> Option<Result<CapturedPacket, PcapError>> {
+        loop {
+            let (interface_id, data) = match self.reader.next_block()? {
+                Ok(Block::EnhancedPacket(epb)) => (epb.interface_id, epb.data.into_owned()),
+                // Simple Packet Blocks always belong to the first interface
+                Ok(Block::SimplePacket(spb)) => (0, spb.data.into_owned()),
+                Ok(_) => continue,
+                Err(e) => return Some(Err(e)),
+            };
+
+            return Some(match self.reader.interfaces().get(interface_id as usize) {
+                Some(interface) => Ok(CapturedPacket {
+                    datalink: interface.linktype,
+                    data,
+                }),
+                None => Err(PcapError::InvalidInterfaceId(interface_id)),
+            });
+        }
+    }
+}
+
+const PCAP_MAGIC_LE: [u8; 4] = [0xD4, 0xC3, 0xB2, 0xA1];
+const PCAP_MAGIC_BE: [u8; 4] = [0xA1, 0xB2, 0xC3, 0xD4];
+const PCAP_NS_MAGIC_LE: [u8; 4] = [0x4D, 0x3C, 0xB2, 0xA1];
+const PCAP_NS_MAGIC_BE: [u8; 4] = [0xA1, 0xB2, 0x3C, 0x4D];
+const PCAPNG_MAGIC: [u8; 4] = [0x0A, 0x0D, 0x0D, 0x0A];
+
+/// Opens a capture file, choosing the pcap or pcapng reader from its magic bytes.
+fn open_pcap(path: &Path) -> Result<Box<dyn PacketIterator>, Box<dyn std::error::Error>> {
+    let mut magic = [0u8; 4];
+    File::open(path)?.read_exact(&mut magic)?;
+
+    let file = File::open(path)?;
+    match magic {
+        PCAPNG_MAGIC => Ok(Box::new(PcapNgPackets {
+            reader: PcapNgReader::new(file)?,
+        })),
+        PCAP_MAGIC_LE | PCAP_MAGIC_BE | PCAP_NS_MAGIC_LE | PCAP_NS_MAGIC_BE => {
+            let reader = PcapReader::new(file)?;
+            let datalink = reader.header().datalink;
+            Ok(Box::new(PcapPackets { reader, datalink }))
+        }
+        _ => Err(format!("unrecognized capture file magic: {magic:02x?}").into()),
+    }
+}
+
+/// UDP datagram extracted from a captured frame.
+struct UdpDatagram<'a> {
+    header_type: &'static str,
+    src_addr: String,
+    dst_addr: String,
+    src_port: u16,
+    dst_port: u16,
+    payload: &'a [u8],
+}
+
+/// Strips the link-layer, IP and UDP headers from a frame.
+///
+/// Returns `Ok(None)` for truncated frames and `Err` with a description for
+/// frames that are well-formed but not UDP over IP.
+fn extract_udp(datalink: DataLink, data: &[u8]) -> Result<Option<UdpDatagram<'_>>, String> {
+    let (ip_data, header_type) = match datalink {
+        DataLink::ETHERNET => {
+            // Ethernet (DLT_EN10MB)
+            if data.len() < 14 {
+                return Ok(None);
+            }
+            let eth_type = u16::from_be_bytes([data[12], data[13]]);
+            if eth_type != 0x0800 && eth_type != 0x86DD {
+                return Err(format!("Non-IP (EtherType: 0x{eth_type:04x})"));
+            }
+            (&data[14..], "Ethernet")
+        }
+        DataLink::LINUX_SLL => {
+            // Linux cooked v1 (DLT_LINUX_SLL) - protocol type at offset 14-15
+            if data.len() < 16 {
+                return Ok(None);
+            }
+            let protocol_type = u16::from_be_bytes([data[14], data[15]]);
+            if protocol_type != 0x0800 && protocol_type != 0x86DD {
+                return Err(format!("Non-IP (Protocol: 0x{protocol_type:04x})"));
+            }
+            (&data[16..], "Linux cooked")
+        }
+        DataLink::LINUX_SLL2 => {
+            // Linux cooked v2 (DLT_LINUX_SLL2)
+            if data.len() < 20 {
+                return Ok(None);
+            }
+            // In Linux cooked v2, protocol type is at offset 0-1
+            let protocol_type = u16::from_be_bytes([data[0], data[1]]);
+            if protocol_type != 0x0800 && protocol_type != 0x86DD {
+                return Err(format!("Non-IP (Protocol: 0x{protocol_type:04x})"));
+            }
+            (&data[20..], "Linux cooked v2")
+        }
+        DataLink::RAW | DataLink::IPV4 | DataLink::IPV6 => {
+            // Raw IP (DLT_RAW, LINKTYPE_IPV4, LINKTYPE_IPV6) - no link layer
+            // header, starts directly with IP. pcapng writers commonly
+            // record the version-specific link types.
+            if data.is_empty() {
+                return Ok(None);
+            }
+            (data, "Raw IP")
+        }
+        _ => return Err(format!("Unsupported datalink type: {datalink:?}")),
+    };
+
+    if ip_data.is_empty() {
+        return Ok(None);
+    }
+
+    // Parse IP header (IPv4 or IPv6)
+    let ip_version = ip_data[0] >> 4;
+
+    let (udp_data, src_addr, dst_addr) = if ip_version == 4 {
+        // IPv4 header
+        if ip_data.len() < 20 {
+            return Ok(None);
+        }
+
+        let ip_ihl = (ip_data[0] & 0x0f) * 4;
+        let ip_protocol = ip_data[9];
+
+        if ip_protocol != 17 {
+            return Err(format!("Not UDP (protocol: {ip_protocol})"));
+        }
+
+        let udp_start = ip_ihl as usize;
+        if ip_data.len() < udp_start + 8 {
+            return Ok(None);
+        }
+
+        let src = extract_ipv4_address(&ip_data[12..16]);
+        let dst = extract_ipv4_address(&ip_data[16..20]);
+
+        (&ip_data[udp_start..], src, dst)
+    } else if ip_version == 6 {
+        // IPv6 header (fixed 40 bytes)
+        if ip_data.len() < 40 {
+            return Ok(None);
+        }
+
+        let next_header = ip_data[6];
+
+        if next_header != 17 {
+            // Not UDP (no extension header support for now)
+            return Err(format!("Not UDP (next header: {next_header})"));
+        }
+
+        if ip_data.len() < 48 {
+            // Need at least IPv6 header + UDP header
+            return Ok(None);
+        }
+
+        let src = extract_ipv6_address(&ip_data[8..24]);
+        let dst = extract_ipv6_address(&ip_data[24..40]);
+
+        (&ip_data[40..], src, dst)
+    } else {
+        return Err(format!("Invalid IP version: {ip_version}"));
+    };
+
+    // Parse UDP header
+    if udp_data.len() < 8 {
+        return Ok(None);
+    }
+
+    Ok(Some(UdpDatagram {
+        header_type,
+        src_addr,
+        dst_addr,
+        src_port: u16::from_be_bytes([udp_data[0], udp_data[1]]),
+        dst_port: u16::from_be_bytes([udp_data[2], udp_data[3]]),
+        payload: &udp_data[8..],
+    }))
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
@@ -31,15 +261,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         std::process::exit(1);
     }
 
-    let file = File::open(&args.pcap)?;
-    let mut pcap_reader = PcapReader::new(file)?;
+    let mut packets = open_pcap(Path::new(&args.pcap))?;
     let mut packet_count = 0;
     let mut pfcp_count = 0;
 
-    // Detect link type from pcap header
-    let datalink = pcap_reader.header().datalink;
     println!("Reading PCAP file: {}", args.pcap);
-    println!("Datalink type: {datalink:?}");
+    println!("Capture format: {}", packets.format_name());
     println!("Format: {}", args.format.to_uppercase());
     if args.pfcp_only {
         println!("Filtering: PFCP messages only");
@@ -47,151 +274,40 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("{}", "=".repeat(60));
 
     loop {
-        match pcap_reader.next_packet() {
+        match packets.next_packet() {
             Some(Ok(pkt)) => {
                 packet_count += 1;
-                let data = pkt.data;
+                let data = &pkt.data;
 
                 // Debug: print first few packets to understand format
                 if packet_count <= 3 {
                     println!(
-                        "Packet {} data length: {}, first 20 bytes: {:?}",
+                        "Packet {} ({:?}) data length: {}, first 20 bytes: {:?}",
                         packet_count,
+                        pkt.datalink,
                         data.len(),
                         &data[..data.len().min(20)]
                     );
                 }
 
-                let (ip_data, header_type) = match datalink {
-                    DataLink::ETHERNET => {
-                        // Ethernet (DLT_EN10MB)
-                        if data.len() < 14 {
-                            continue;
-                        }
-                        let eth_type = u16::from_be_bytes([data[12], data[13]]);
-                        if eth_type != 0x0800 && eth_type != 0x86DD {
-                            if !args.pfcp_only {
-                                println!(
-                                    "Packet {packet_count}: Non-IP (EtherType: 0x{eth_type:04x})"
-                                );
-                            }
-                            continue;
-                        }
-                        (&data[14..], "Ethernet")
-                    }
-                    DataLink::LINUX_SLL2 => {
-                        // Linux cooked v2 (DLT_LINUX_SLL2)
-                        if data.len() < 20 {
-                            continue;
-                        }
-                        // In Linux cooked v2, protocol type is at offset 0-1
-                        let protocol_type = u16::from_be_bytes([data[0], data[1]]);
-                        if protocol_type != 0x0800 && protocol_type != 0x86DD {
-                            if !args.pfcp_only {
-                                println!(
-                                    "Packet {packet_count}: Non-IP (Protocol: 0x{protocol_type:04x})"
-                                );
-                            }
-                            continue;
-                        }
-                        (&data[20..], "Linux cooked v2")
-                    }
-                    DataLink::RAW => {
-                        // Raw IP (DLT_RAW) - no link layer header, starts directly with IP
-                        if data.is_empty() {
-                            continue;
-                        }
-                        // Verify it's IPv4 or IPv6 by checking version in first nibble
-                        let ip_version = data[0] >> 4;
-                        if ip_version != 4 && ip_version != 6 {
-                            if !args.pfcp_only {
-                                println!(
-                                    "Packet {packet_count}: Not IPv4/IPv6 (version: {ip_version})"
-                                );
-                            }
-                            continue;
-                        }
-                        (&data[..], "Raw IP")
-                    }
-                    _ => {
-                        if !args.pfcp_only {
-                            println!(
-                                "Packet {packet_count}: Unsupported datalink type: {datalink:?}"
-                            );
-                        }
-                        continue;
-                    }
-                };
-
-                // Parse IP header (IPv4 or IPv6)
-                let ip_version = ip_data[0] >> 4;
-
-                let (udp_data, src_addr, dst_addr) = if ip_version == 4 {
-                    // IPv4 header
-                    if ip_data.len() < 20 {
-                        continue;
-                    }
-
-                    let ip_ihl = (ip_data[0] & 0x0f) * 4;
-                    let ip_protocol = ip_data[9];
-
-                    if ip_protocol != 17 {
-                        // Not UDP
-                        if !args.pfcp_only {
-                            println!("Packet {packet_count}: Not UDP (protocol: {ip_protocol})");
-                        }
-                        continue;
-                    }
-
-                    let udp_start = ip_ihl as usize;
-                    if ip_data.len() < udp_start + 8 {
-                        continue;
-                    }
-
-                    let src = extract_ipv4_address(&ip_data[12..16]);
-                    let dst = extract_ipv4_address(&ip_data[16..20]);
-
-                    (&ip_data[udp_start..], src, dst)
-                } else if ip_version == 6 {
-                    // IPv6 header (fixed 40 bytes)
-                    if ip_data.len() < 40 {
-                        continue;
-                    }
-
-                    let next_header = ip_data[6];
-
-                    if next_header != 17 {
-                        // Not UDP (no extension header support for now)
+                let udp = match extract_udp(pkt.datalink, data) {
+                    Ok(Some(udp)) => udp,
+                    Ok(None) => continue,
+                    Err(reason) => {
                         if !args.pfcp_only {
-                            println!("Packet {packet_count}: Not UDP (next header: {next_header})");
+                            println!("Packet {packet_count}: {reason}");
                         }
                         continue;
                     }
-
-                    if ip_data.len() < 48 {
-                        // Need at least IPv6 header + UDP header
-                        continue;
-                    }
-
-                    let src = extract_ipv6_address(&ip_data[8..24]);
-                    let dst = extract_ipv6_address(&ip_data[24..40]);
-
-                    (&ip_data[40..], src, dst)
-                } else {
-                    if !args.pfcp_only {
-                        println!("Packet {packet_count}: Invalid IP version: {ip_version}");
-                    }
-                    continue;
                 };
-
-                // Parse UDP header
-                if udp_data.len() < 8 {
-                    continue;
-                }
-
-                let src_port = u16::from_be_bytes([udp_data[0], udp_data[1]]);
-                let dst_port = u16::from_be_bytes([udp_data[2], udp_data[3]]);
-                let _udp_len = u16::from_be_bytes([udp_data[4], udp_data[5]]) as usize;
+                let UdpDatagram {
+                    header_type,
+                    src_addr,
+                    dst_addr,
+                    src_port,
+                    dst_port,
+                    payload: pfcp_data,
+                } = udp;
 
                 // Check if it's PFCP (port 8805)
                 if src_port != 8805 && dst_port != 8805 {
@@ -205,7 +321,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
 
                 // Extract PFCP payload
-                let pfcp_data = &udp_data[8..];
                 if pfcp_data.is_empty() {
                     if !args.pfcp_only {
                         println!("Packet {packet_count}: Empty PFCP payload");
@@ -224,7 +339,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             let s_flag = (pfcp_data[0] & 0x01) != 0;
                             let msg_type = pfcp_data[1];
                             let length = u16::from_be_bytes([pfcp_data[2], pfcp_data[3]]);
-                            let _seid_or_seq_start = 4; // Header start for both S flag cases
                             let seq_offset = if s_flag { 12 } else { 4 };
                             let sequence = if pfcp_data.len() > seq_offset + 2 {
                                 u32::from_be_bytes([
@@ -336,3 +450,117 @@ fn extract_ipv6_address(bytes: &[u8]) -> String {
         "unknown".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pcap_file::pcap::{PcapHeader, PcapPacket, PcapWriter};
+    use pcap_file::pcapng::blocks::enhanced_packet::EnhancedPacketBlock;
+    use pcap_file::pcapng::blocks::interface_description::InterfaceDescriptionBlock;
+    use pcap_file::pcapng::PcapNgWriter;
+    use rs_pfcp::message::heartbeat_request::HeartbeatRequestBuilder;
+    use rs_pfcp::message::{Message, MsgType};
+    use std::borrow::Cow;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    fn heartbeat() -> Vec<u8> {
+        HeartbeatRequestBuilder::new(0x000123)
+            .recovery_time_stamp(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+            .marshal()
+    }
+
+    /// Wraps a PFCP message in IPv6 and UDP headers (port 8805 both ways).
+    fn ipv6_udp(pfcp: &[u8]) -> Vec<u8> {
+        let udp_len = (8 + pfcp.len()) as u16;
+        let mut packet = vec![0x60, 0, 0, 0];
+        packet.extend_from_slice(&udp_len.to_be_bytes());
+        packet.extend_from_slice(&[17, 64]);
+        packet.extend_from_slice(&[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        packet.extend_from_slice(&[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+        packet.extend_from_slice(&8805u16.to_be_bytes());
+        packet.extend_from_slice(&8805u16.to_be_bytes());
+        packet.extend_from_slice(&udp_len.to_be_bytes());
+        packet.extend_from_slice(&[0, 0]);
+        packet.extend_from_slice(pfcp);
+        packet
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rs-pfcp-{}-{name}", std::process::id()))
+    }
+
+    fn read_single_pfcp(path: &Path) -> Box<dyn Message> {
+        let mut packets = open_pcap(path).unwrap();
+        let pkt = packets.next_packet().unwrap().unwrap();
+        assert!(packets.next_packet().is_none());
+
+        let udp = extract_udp(pkt.datalink, &pkt.data).unwrap().unwrap();
+        assert_eq!(udp.dst_port, 8805);
+        assert_eq!(udp.src_addr, "2001:0db8:0000:0000:0000:0000:0000:0001");
+        rs_pfcp::message::parse(udp.payload).unwrap()
+    }
+
+    #[test]
+    fn test_open_pcapng_ipv6() {
+        let path = temp_path("ipv6.pcapng");
+        let frame = ipv6_udp(&heartbeat());
+
+        let mut writer = PcapNgWriter::new(File::create(&path).unwrap()).unwrap();
+        writer
+            .write_pcapng_block(InterfaceDescriptionBlock::new(DataLink::IPV6, 0))
+            .unwrap();
+        writer
+            .write_pcapng_block(EnhancedPacketBlock {
+                interface_id: 0,
+                timestamp: Duration::from_secs(1),
+                original_len: frame.len() as u32,
+                data: Cow::Borrowed(&frame),
+                options: vec![],
+            })
+            .unwrap();
+        drop(writer);
+
+        assert_eq!(open_pcap(&path).unwrap().format_name(), "pcapng");
+        let msg = read_single_pfcp(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(msg.msg_type(), MsgType::HeartbeatRequest);
+        assert_eq!(*msg.sequence(), 0x000123);
+    }
+
+    #[test]
+    fn test_open_pcap_raw() {
+        let path = temp_path("raw.pcap");
+        let frame = ipv6_udp(&heartbeat());
+
+        let header = PcapHeader {
+            datalink: DataLink::RAW,
+            ..Default::default()
+        };
+        let mut writer = PcapWriter::with_header(File::create(&path).unwrap(), header).unwrap();
+        writer
+            .write_packet(&PcapPacket::new(
+                Duration::from_secs(1),
+                frame.len() as u32,
+                &frame,
+            ))
+            .unwrap();
+        drop(writer);
+
+        assert_eq!(open_pcap(&path).unwrap().format_name(), "pcap");
+        let msg = read_single_pfcp(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(msg.msg_type(), MsgType::HeartbeatRequest);
+    }
+
+    #[test]
+    fn test_open_unknown_magic() {
+        let path = temp_path("garbage.bin");
+        std::fs::write(&path, [0xde, 0xad, 0xbe, 0xef, 0, 0, 0, 0]).unwrap();
+        let result = open_pcap(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+}
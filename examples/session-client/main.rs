@@ -37,7 +37,8 @@ use rs_pfcp::ie::{
     create_far::{CreateFar, CreateFarBuilder},
     create_pdr::CreatePdrBuilder,
     create_qer::{CreateQer, CreateQerBuilder},
-    destination_interface::Interface,
+    destination_interface::{DestinationInterface, Interface},
+    duplicating_parameters::DuplicatingParameters,
     f_teid::FteidBuilder,
     far_id::FarId,
     network_instance::NetworkInstance,
@@ -60,6 +61,7 @@ use rs_pfcp::message::{
     association_setup_request::AssociationSetupRequestBuilder,
     session_deletion_request::SessionDeletionRequestBuilder,
     session_establishment_request::SessionEstablishmentRequestBuilder,
+    session_establishment_response::SessionEstablishmentResponse,
     session_modification_request::SessionModificationRequestBuilder,
     session_report_response::SessionReportResponseBuilder, Message, MsgType,
 };
@@ -254,8 +256,16 @@ fn main() -> Result<(), Box<dyn Error>> {
             .add_qer(qer)
             .marshal()?;
         socket.send(&session_req_bytes)?;
-        let (_len, _) = socket.recv_from(&mut buf)?;
+        let (len, _) = socket.recv_from(&mut buf)?;
         println!("[{seid}] Received Session Establishment Response.");
+        let session_res = SessionEstablishmentResponse::unmarshal(&buf[..len])?;
+        if let Some(created_pdr) = session_res.first_created_pdr()? {
+            let fteid = &created_pdr.f_teid;
+            println!(
+                "[{seid}]   PDR {} allocated F-TEID: TEID={} IPv4={:?} IPv6={:?}",
+                created_pdr.pdr_id.value, fteid.teid, fteid.ipv4_address, fteid.ipv6_address
+            );
+        }
 
         // Listen for Session Report Requests (quota exhaustion notifications)
         println!("[{seid}] Listening for Session Report Requests...");
@@ -406,6 +416,11 @@ fn main() -> Result<(), Box<dyn Error>> {
             // Example 3: Advanced FAR with forwarding and duplication
             let _intercept_far =
                 CreateFarBuilder::forward_and_duplicate(FarId::new(200), Interface::Core)
+                    .duplicating_parameters(DuplicatingParameters::new(
+                        DestinationInterface::new(Interface::Lcs),
+                        None,
+                        None,
+                    ))
                     .build()
                     .unwrap();
             println!("✅ Lawful intercept FAR created: forward + duplicate");
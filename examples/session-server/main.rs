@@ -59,7 +59,6 @@ use network_interface::{NetworkInterface, NetworkInterfaceConfig};
 use rs_pfcp::error::PfcpError;
 use rs_pfcp::ie::{
     cause::{Cause, CauseValue},
-    create_pdr::CreatePdr,
     created_pdr::CreatedPdr,
     duration_measurement::DurationMeasurement,
     f_teid::FteidBuilder,
@@ -297,8 +296,12 @@ fn handle_session_establishment_request(
         establishment_req.create_pdrs.len()
     );
 
-    for (index, create_pdr_ie) in establishment_req.create_pdrs.iter().enumerate() {
-        match create_pdr_ie.parse::<CreatePdr>() {
+    for (index, create_pdr) in establishment_req
+        .ies(IeType::CreatePdr)
+        .as_create_pdrs()
+        .enumerate()
+    {
+        match create_pdr {
             Ok(received_pdr) => {
                 println!(
                     "    CreatePdr {}: PDR ID: {}, Precedence: {}",
@@ -1,12 +1,31 @@
 //! Message comparison builder.
 
-use super::{ComparisonOptions, ComparisonResult, IeMultiplicityMode, MessageDiff, OptionalIeMode};
+use super::{
+    ComparisonOptions, ComparisonReport, ComparisonResult, IeMultiplicityMode, MessageDiff,
+    OptionalIeMode,
+};
 use crate::error::PfcpError;
 use crate::ie::IeType;
-use crate::message::Message;
+use crate::message::{parse, Message};
 
 mod compare;
 
+/// A message under comparison, either borrowed from the caller or parsed
+/// from raw bytes and owned by the comparator.
+enum Operand<'a> {
+    Borrowed(&'a dyn Message),
+    Owned(Box<dyn Message>),
+}
+
+impl Operand<'_> {
+    fn get(&self) -> &dyn Message {
+        match self {
+            Operand::Borrowed(msg) => *msg,
+            Operand::Owned(msg) => msg.as_ref(),
+        }
+    }
+}
+
 /// Builder for configuring and executing message comparisons.
 ///
 /// Provides a fluent API for setting comparison options and executing
@@ -42,8 +61,8 @@ mod compare;
 /// The builder is zero-cost - all configuration is compile-time.
 /// Only the final `compare()` or `diff()` call performs work.
 pub struct MessageComparator<'a> {
-    left: &'a dyn Message,
-    right: &'a dyn Message,
+    left: Operand<'a>,
+    right: Operand<'a>,
     options: ComparisonOptions,
 }
 
@@ -79,11 +98,7 @@ impl<'a> MessageComparator<'a> {
             );
         }
 
-        Self {
-            left,
-            right,
-            options: ComparisonOptions::default(),
-        }
+        Self::new_unchecked(left, right)
     }
 
     /// Create a comparator that allows comparing different message types.
@@ -103,12 +118,72 @@ impl<'a> MessageComparator<'a> {
     /// ```
     pub fn new_unchecked(left: &'a dyn Message, right: &'a dyn Message) -> Self {
         Self {
-            left,
-            right,
+            left: Operand::Borrowed(left),
+            right: Operand::Borrowed(right),
             options: ComparisonOptions::default(),
         }
     }
 
+    /// Create a comparator from two raw PFCP messages, e.g. captured traffic.
+    ///
+    /// Both inputs are decoded with [`parse`]. Unlike [`new`](Self::new),
+    /// differing message types do not panic; they are reported as a header
+    /// mismatch in the comparison result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either input is not a valid PFCP message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rs_pfcp::comparison::MessageComparator;
+    /// use rs_pfcp::message::heartbeat_request::HeartbeatRequestBuilder;
+    /// use rs_pfcp::message::Message;
+    /// use std::time::SystemTime;
+    ///
+    /// let ts = SystemTime::now();
+    /// let left = HeartbeatRequestBuilder::new(1).recovery_time_stamp(ts).build().marshal();
+    /// let right = HeartbeatRequestBuilder::new(2).recovery_time_stamp(ts).build().marshal();
+    ///
+    /// let result = MessageComparator::from_bytes(&left, &right)?
+    ///     .ignore_sequence()
+    ///     .compare()?;
+    /// assert!(result.is_match());
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    pub fn from_bytes(left: &[u8], right: &[u8]) -> Result<Self, PfcpError> {
+        Ok(Self {
+            left: Operand::Owned(parse(left)?),
+            right: Operand::Owned(parse(right)?),
+            options: ComparisonOptions::default(),
+        })
+    }
+
+    /// Create a comparator from two hex-encoded PFCP messages.
+    ///
+    /// Whitespace in either string is ignored, so hex dumps can be pasted
+    /// as-is. The decoded bytes are handled as in
+    /// [`from_bytes`](Self::from_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either string is not valid hex or does not decode
+    /// to a valid PFCP message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rs_pfcp::comparison::MessageComparator;
+    ///
+    /// let hex = "20 01 00 0c 00 00 01 00 00 60 00 04 e9 6b 7e 00";
+    /// assert!(MessageComparator::from_hex(hex, hex)?.matches()?);
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    pub fn from_hex(left_hex: &str, right_hex: &str) -> Result<Self, PfcpError> {
+        Self::from_bytes(&decode_hex(left_hex)?, &decode_hex(right_hex)?)
+    }
+
     // ========================================================================
     // Header Field Filtering
     // ========================================================================
@@ -241,6 +316,31 @@ impl<'a> MessageComparator<'a> {
         self
     }
 
+    /// Ignore a child IE type within a specific parent grouped IE.
+    ///
+    /// Only applies when grouped IEs are deep-compared. All other children
+    /// of the parent are still compared. Multiple calls accumulate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use rs_pfcp::comparison::MessageComparator;
+    /// use rs_pfcp::ie::IeType;
+    /// # use rs_pfcp::message::Message;
+    /// # fn example(msg1: &dyn Message, msg2: &dyn Message) -> Result<(), rs_pfcp::error::PfcpError> {
+    ///
+    /// // PDR precedence may legitimately differ between SMF implementations
+    /// let result = MessageComparator::new(msg1, msg2)
+    ///     .ignore_ie_child(IeType::CreatePdr, IeType::Precedence)
+    ///     .compare()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ignore_ie_child(mut self, parent: IeType, child: IeType) -> Self {
+        self.options.ignored_ie_children.insert((parent, child));
+        self
+    }
+
     /// Only compare specific IE types, ignore all others.
     ///
     /// Useful for focused validation of critical IEs.
@@ -480,7 +580,7 @@ impl<'a> MessageComparator<'a> {
     /// # }
     /// ```
     pub fn compare(self) -> Result<ComparisonResult, PfcpError> {
-        compare::execute_comparison(self.left, self.right, &self.options)
+        compare::execute_comparison(self.left.get(), self.right.get(), &self.options)
     }
 
     /// Execute comparison and return a detailed diff.
@@ -502,10 +602,50 @@ impl<'a> MessageComparator<'a> {
     /// ```
     pub fn diff(mut self) -> Result<MessageDiff, PfcpError> {
         self.options.generate_diff = true;
-        let result = compare::execute_comparison(self.left, self.right, &self.options)?;
+        let result = compare::execute_comparison(self.left.get(), self.right.get(), &self.options)?;
         Ok(result.into_diff())
     }
 
+    /// Execute comparison and return a machine-readable report.
+    ///
+    /// The report records the options used alongside the result and the
+    /// full list of differences, and can be serialized with
+    /// [`ComparisonReport::to_json`] or [`ComparisonReport::to_yaml`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use rs_pfcp::comparison::MessageComparator;
+    /// # use rs_pfcp::message::Message;
+    /// # fn example(msg1: &dyn Message, msg2: &dyn Message) -> Result<(), rs_pfcp::error::PfcpError> {
+    /// let report = MessageComparator::new(msg1, msg2)
+    ///     .test_mode()
+    ///     .report()?;
+    ///
+    /// println!("{}", report.to_json_pretty().unwrap());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn report(mut self) -> Result<ComparisonReport, PfcpError> {
+        let timestamp = std::time::SystemTime::now();
+        self.options.generate_diff = true;
+        let mut result =
+            compare::execute_comparison(self.left.get(), self.right.get(), &self.options)?;
+        let differences = match result.diff.take() {
+            Some(diff) => diff.differences,
+            None => MessageDiff::from_result(&result).differences,
+        };
+
+        Ok(ComparisonReport {
+            timestamp,
+            left_message_type: result.left_type,
+            right_message_type: result.right_type,
+            options_used: self.options,
+            result,
+            differences,
+        })
+    }
+
     /// Quick check: returns true if messages match according to options.
     ///
     /// More efficient than `compare()` if you only need boolean result.
@@ -523,11 +663,33 @@ impl<'a> MessageComparator<'a> {
     /// # }
     /// ```
     pub fn matches(self) -> Result<bool, PfcpError> {
-        let result = compare::execute_comparison(self.left, self.right, &self.options)?;
+        let result = compare::execute_comparison(self.left.get(), self.right.get(), &self.options)?;
         Ok(result.is_match())
     }
 }
 
+/// Decodes a hex string, skipping any whitespace.
+fn decode_hex(hex: &str) -> Result<Vec<u8>, PfcpError> {
+    let digits: Vec<u8> = hex.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err(PfcpError::invalid_value(
+            "hex message",
+            hex,
+            "hex string must have an even number of digits",
+        ));
+    }
+
+    digits
+        .chunks_exact(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|s| u8::from_str_radix(s, 16).ok())
+                .ok_or_else(|| PfcpError::invalid_value("hex message", hex, "invalid hex digit"))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -603,4 +765,60 @@ mod tests {
         assert!(comparator.options.strict_ie_order);
         assert_eq!(comparator.options.optional_ie_mode, OptionalIeMode::Strict);
     }
+
+    const HEARTBEAT_HEX: &str = "20 01 00 0c 00 00 01 00
+                                 00 60 00 04 e9 6b 7e 00";
+
+    #[test]
+    fn test_from_hex_identical() {
+        let comparator = MessageComparator::from_hex(HEARTBEAT_HEX, HEARTBEAT_HEX).unwrap();
+        assert!(comparator.compare().unwrap().is_match());
+    }
+
+    #[test]
+    fn test_from_hex_single_byte_change() {
+        // Last byte of the Recovery Time Stamp differs
+        let changed = HEARTBEAT_HEX.replace("7e 00", "7e 01");
+        let comparator = MessageComparator::from_hex(HEARTBEAT_HEX, &changed).unwrap();
+        assert!(!comparator.compare().unwrap().is_match());
+    }
+
+    #[test]
+    fn test_from_bytes_matches_new() {
+        let msg1 = HeartbeatRequestBuilder::new(100)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+        let msg2 = HeartbeatRequestBuilder::new(200)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+
+        let from_bytes = MessageComparator::from_bytes(&msg1.marshal(), &msg2.marshal())
+            .unwrap()
+            .test_mode()
+            .matches()
+            .unwrap();
+        let from_messages = MessageComparator::new(&msg1, &msg2)
+            .test_mode()
+            .matches()
+            .unwrap();
+        assert!(from_bytes);
+        assert_eq!(from_bytes, from_messages);
+    }
+
+    #[test]
+    fn test_from_bytes_invalid_message() {
+        assert!(MessageComparator::from_bytes(&[0x20, 0x01], &[]).is_err());
+    }
+
+    #[test]
+    fn test_from_hex_invalid() {
+        assert!(matches!(
+            MessageComparator::from_hex("20 0", HEARTBEAT_HEX),
+            Err(PfcpError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            MessageComparator::from_hex(HEARTBEAT_HEX, "zz"),
+            Err(PfcpError::InvalidValue { .. })
+        ));
+    }
 }
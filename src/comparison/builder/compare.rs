@@ -230,7 +230,7 @@ fn compare_ies(
                             IeMatchType::DeepGrouped => stats.exact_matches += 1,
                         }
                     }
-                    IeComparisonResult::Mismatch(reason, left_payload, right_payload) => {
+                    IeComparisonResult::Mismatch(reason, left_payload, right_payload, children) => {
                         ie_mismatches.push(IeMismatch {
                             ie_type,
                             reason,
@@ -245,6 +245,7 @@ fn compare_ies(
                                 None
                             },
                             context: None,
+                            children,
                         });
                         stats.mismatches += 1;
                     }
@@ -275,7 +276,13 @@ fn compare_ies(
 /// Result of comparing IE instances.
 enum IeComparisonResult {
     Match(IeMatchType),
-    Mismatch(MismatchReason, Option<Vec<u8>>, Option<Vec<u8>>),
+    /// Reason, left and right payloads, and child mismatches of a grouped IE.
+    Mismatch(
+        MismatchReason,
+        Option<Vec<u8>>,
+        Option<Vec<u8>>,
+        Vec<IeMismatch>,
+    ),
 }
 
 /// Compare instances of a specific IE type.
@@ -294,6 +301,7 @@ fn compare_ie_instances(
             },
             None,
             None,
+            Vec::new(),
         ));
     }
 
@@ -321,6 +329,7 @@ fn compare_ie_instances(
                         MismatchReason::ValueMismatch,
                         Some(left_ie.payload.clone()),
                         None,
+                        Vec::new(),
                     ));
                 }
             }
@@ -352,6 +361,7 @@ fn compare_ie_instances(
                 MismatchReason::ValueMismatch,
                 None,
                 None,
+                Vec::new(),
             ))
         }
     }
@@ -365,22 +375,27 @@ fn compare_ie_instances(
 fn compare_grouped_ie_deep(
     left: &Ie,
     right: &Ie,
-    _ie_type: IeType,
+    ie_type: IeType,
     options: &ComparisonOptions,
 ) -> Result<IeComparisonResult, PfcpError> {
     // Parse child IEs from both payloads
     let left_children = parse_child_ies(&left.payload)?;
     let right_children = parse_child_ies(&right.payload)?;
 
-    // Group child IEs by type using references (same as top-level IE collection)
+    // Group child IEs by type using references (same as top-level IE collection),
+    // skipping children ignored within this parent
     let mut left_grouped: HashMap<IeType, Vec<&Ie>> = HashMap::new();
     for ie in &left_children {
-        left_grouped.entry(ie.ie_type).or_default().push(ie);
+        if options.should_compare_child_ie(ie_type, ie.ie_type) {
+            left_grouped.entry(ie.ie_type).or_default().push(ie);
+        }
     }
 
     let mut right_grouped: HashMap<IeType, Vec<&Ie>> = HashMap::new();
     for ie in &right_children {
-        right_grouped.entry(ie.ie_type).or_default().push(ie);
+        if options.should_compare_child_ie(ie_type, ie.ie_type) {
+            right_grouped.entry(ie.ie_type).or_default().push(ie);
+        }
     }
 
     // Compare child IEs using the same logic as top-level IEs
@@ -409,6 +424,7 @@ fn compare_grouped_ie_deep(
             },
             Some(left.payload.clone()),
             Some(right.payload.clone()),
+            ie_mismatches,
         ))
     }
 }
@@ -444,6 +460,7 @@ fn compare_single_ie(
                         MismatchReason::SemanticMismatch { details },
                         Some(left.payload.clone()),
                         Some(right.payload.clone()),
+                        Vec::new(),
                     ))
                 }
             };
@@ -459,6 +476,7 @@ fn compare_single_ie(
             MismatchReason::ValueMismatch,
             Some(left.payload.clone()),
             Some(right.payload.clone()),
+            Vec::new(),
         ))
     }
 }
@@ -704,7 +722,7 @@ mod tests {
             compare_grouped_ie_deep(&grouped1, &grouped2, IeType::CreatePdr, &options).unwrap();
 
         match result {
-            IeComparisonResult::Mismatch(reason, _, _) => match reason {
+            IeComparisonResult::Mismatch(reason, _, _, _) => match reason {
                 MismatchReason::GroupedIeMismatch {
                     child_mismatches, ..
                 } => {
@@ -745,7 +763,7 @@ mod tests {
             compare_grouped_ie_deep(&grouped1, &grouped2, IeType::CreatePdr, &options).unwrap();
 
         match result {
-            IeComparisonResult::Mismatch(reason, _, _) => {
+            IeComparisonResult::Mismatch(reason, _, _, _) => {
                 match reason {
                     MismatchReason::GroupedIeMismatch {
                         missing_in_right, ..
@@ -786,7 +804,7 @@ mod tests {
 
         // Should detect a mismatch due to byte-level differences (ordering)
         match result {
-            IeComparisonResult::Mismatch(MismatchReason::ValueMismatch, _, _) => {
+            IeComparisonResult::Mismatch(MismatchReason::ValueMismatch, _, _, _) => {
                 // Expected - byte payloads differ
             }
             _ => panic!("Expected value mismatch in shallow mode"),
@@ -827,4 +845,97 @@ mod tests {
             _ => panic!("Expected deep match with unordered IEs"),
         }
     }
+
+    fn session_request_with_precedence(
+        precedence: u32,
+    ) -> crate::message::session_establishment_request::SessionEstablishmentRequest {
+        use crate::ie::create_far::CreateFar;
+        use crate::ie::create_pdr::CreatePdrBuilder;
+        use crate::ie::destination_interface::Interface;
+        use crate::ie::far_id::FarId;
+        use crate::ie::pdi::Pdi;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+        use crate::message::session_establishment_request::SessionEstablishmentRequestBuilder;
+        use std::net::Ipv4Addr;
+
+        let pdr = CreatePdrBuilder::new(PdrId::new(1))
+            .precedence(Precedence::new(precedence))
+            .pdi(Pdi::uplink_access())
+            .far_id(FarId::new(1))
+            .build()
+            .unwrap();
+
+        SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .add_pdr(pdr)
+            .add_far(CreateFar::uplink_forward(FarId::new(1), Interface::Core))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ignore_ie_child_precedence_in_create_pdr() {
+        let left = session_request_with_precedence(100);
+        let right = session_request_with_precedence(200);
+
+        let options = ComparisonOptions::default();
+        let result = execute_comparison(&left, &right, &options).unwrap();
+        assert!(!result.is_match);
+        assert_eq!(result.ie_mismatches.len(), 1);
+        assert_eq!(result.ie_mismatches[0].ie_type, IeType::CreatePdr);
+
+        let mut options = ComparisonOptions::default();
+        options
+            .ignored_ie_children
+            .insert((IeType::CreatePdr, IeType::Precedence));
+        let result = execute_comparison(&left, &right, &options).unwrap();
+        assert!(result.is_match);
+    }
+
+    #[test]
+    fn test_diff_display_describes_child_precedence() {
+        use crate::comparison::MessageComparator;
+
+        let left = session_request_with_precedence(100);
+        let right = session_request_with_precedence(200);
+
+        let diff = MessageComparator::new(&left, &right)
+            .include_payload_in_diff()
+            .diff()
+            .unwrap();
+
+        let display = diff.to_string();
+        assert!(display.contains("Create PDR > Precedence: expected 100, actual 200"));
+    }
+
+    #[test]
+    fn test_ignore_ie_child_still_compares_other_children() {
+        use crate::comparison::MessageComparator;
+        use crate::ie::far_id::FarId;
+
+        let left = session_request_with_precedence(100);
+        let mut right = session_request_with_precedence(200);
+
+        // Same PDR except for Precedence and the FAR ID it points to
+        let mut pdr =
+            crate::ie::create_pdr::CreatePdr::unmarshal(&right.create_pdrs[0].payload).unwrap();
+        pdr.far_id = Some(FarId::new(2));
+        right.create_pdrs[0] = pdr.to_ie();
+
+        let matches = MessageComparator::new(&left, &right)
+            .ignore_ie_child(IeType::CreatePdr, IeType::Precedence)
+            .ignore_ie_child(IeType::CreatePdr, IeType::UrrId)
+            .matches()
+            .unwrap();
+        assert!(!matches);
+
+        let matches = MessageComparator::new(&left, &right)
+            .ignore_ie_child(IeType::CreatePdr, IeType::Precedence)
+            .ignore_ie_child(IeType::CreatePdr, IeType::FarId)
+            .matches()
+            .unwrap();
+        assert!(matches);
+    }
 }
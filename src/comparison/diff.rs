@@ -1,7 +1,9 @@
 //! Diff generation and formatting for message comparisons.
 
+use super::serialize;
 use crate::ie::IeType;
 use crate::message::MsgType;
+use serde::Serialize;
 use std::fmt;
 
 /// Detailed difference between two messages.
@@ -25,12 +27,14 @@ use std::fmt;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MessageDiff {
     /// Left message type
+    #[serde(serialize_with = "serialize::debug_name")]
     pub left_type: MsgType,
 
     /// Right message type
+    #[serde(serialize_with = "serialize::debug_name")]
     pub right_type: MsgType,
 
     /// List of differences found
@@ -79,83 +83,26 @@ impl MessageDiff {
         // Add header differences
         if !header_match.is_complete_match() {
             if !header_match.message_type_match {
-                diff.differences.push(Difference::HeaderField {
-                    field: HeaderField::MessageType,
-                    left_value: format!("{:?}", left_type),
-                    right_value: format!("{:?}", right_type),
-                });
+                diff.push_header_field(
+                    HeaderField::MessageType,
+                    format!("{:?}", left_type),
+                    format!("{:?}", right_type),
+                );
             }
             if header_match.sequence_match == Some(false) {
-                diff.differences.push(Difference::HeaderField {
-                    field: HeaderField::Sequence,
-                    left_value: "differs".to_string(),
-                    right_value: "differs".to_string(),
-                });
+                diff.push_header_field(HeaderField::Sequence, "differs", "differs");
             }
             if header_match.seid_match == Some(false) {
-                diff.differences.push(Difference::HeaderField {
-                    field: HeaderField::Seid,
-                    left_value: "differs".to_string(),
-                    right_value: "differs".to_string(),
-                });
+                diff.push_header_field(HeaderField::Seid, "differs", "differs");
             }
             if header_match.priority_match == Some(false) {
-                diff.differences.push(Difference::HeaderField {
-                    field: HeaderField::Priority,
-                    left_value: "differs".to_string(),
-                    right_value: "differs".to_string(),
-                });
+                diff.push_header_field(HeaderField::Priority, "differs", "differs");
             }
         }
 
         // Add IE mismatches
         for mismatch in ie_mismatches {
-            match &mismatch.reason {
-                super::result::MismatchReason::ValueMismatch => {
-                    let left_hex = mismatch
-                        .left_payload
-                        .as_ref()
-                        .map(|p| format_hex(p))
-                        .unwrap_or_else(|| "N/A".to_string());
-                    let right_hex = mismatch
-                        .right_payload
-                        .as_ref()
-                        .map(|p| format_hex(p))
-                        .unwrap_or_else(|| "N/A".to_string());
-
-                    diff.differences.push(Difference::IeValue {
-                        ie_type: mismatch.ie_type,
-                        context: vec![],
-                        left_hex,
-                        right_hex,
-                    });
-                }
-                super::result::MismatchReason::CountMismatch {
-                    left_count,
-                    right_count,
-                } => {
-                    diff.differences.push(Difference::IeCount {
-                        ie_type: mismatch.ie_type,
-                        left_count: *left_count,
-                        right_count: *right_count,
-                    });
-                }
-                super::result::MismatchReason::GroupedIeMismatch { .. } => {
-                    diff.differences.push(Difference::GroupedIeStructure {
-                        ie_type: mismatch.ie_type,
-                        details: mismatch.reason.to_string(),
-                    });
-                }
-                super::result::MismatchReason::SemanticMismatch { details } => {
-                    diff.differences.push(Difference::IeValue {
-                        ie_type: mismatch.ie_type,
-                        context: vec![],
-                        left_hex: format!("semantic: {}", details),
-                        right_hex: "differs".to_string(),
-                    });
-                }
-                _ => {}
-            }
+            diff.push_mismatch(mismatch, &[]);
         }
 
         // Add left-only IEs
@@ -163,6 +110,7 @@ impl MessageDiff {
             diff.differences.push(Difference::LeftOnly {
                 ie_type: *ie_type,
                 context: vec![],
+                description: format!("{}: expected present, actual missing", ie_type),
             });
         }
 
@@ -171,12 +119,106 @@ impl MessageDiff {
             diff.differences.push(Difference::RightOnly {
                 ie_type: *ie_type,
                 context: vec![],
+                description: format!("{}: expected missing, actual present", ie_type),
             });
         }
 
         diff
     }
 
+    fn push_header_field(
+        &mut self,
+        field: HeaderField,
+        left_value: impl Into<String>,
+        right_value: impl Into<String>,
+    ) {
+        let left_value = left_value.into();
+        let right_value = right_value.into();
+        self.differences.push(Difference::HeaderField {
+            description: format!(
+                "Header {:?}: expected {}, actual {}",
+                field, left_value, right_value
+            ),
+            field,
+            left_value,
+            right_value,
+        });
+    }
+
+    /// Adds a mismatch, then the mismatches of its children under `context`.
+    fn push_mismatch(&mut self, mismatch: &super::result::IeMismatch, context: &[IeType]) {
+        let ie_type = mismatch.ie_type;
+        let path = format_path(context, ie_type);
+        match &mismatch.reason {
+            super::result::MismatchReason::ValueMismatch => {
+                let left_hex = mismatch
+                    .left_payload
+                    .as_ref()
+                    .map(|p| format_hex(p))
+                    .unwrap_or_else(|| "N/A".to_string());
+                let right_hex = mismatch
+                    .right_payload
+                    .as_ref()
+                    .map(|p| format_hex(p))
+                    .unwrap_or_else(|| "N/A".to_string());
+                let description = match (&mismatch.left_payload, &mismatch.right_payload) {
+                    (Some(left), Some(right)) => format!(
+                        "{}: expected {}, actual {}",
+                        path,
+                        format_value(ie_type, left),
+                        format_value(ie_type, right)
+                    ),
+                    _ => format!("{}: values differ", path),
+                };
+
+                self.differences.push(Difference::IeValue {
+                    ie_type,
+                    context: context.to_vec(),
+                    left_hex,
+                    right_hex,
+                    description,
+                });
+            }
+            super::result::MismatchReason::CountMismatch {
+                left_count,
+                right_count,
+            } => {
+                self.differences.push(Difference::IeCount {
+                    ie_type,
+                    left_count: *left_count,
+                    right_count: *right_count,
+                    description: format!(
+                        "{}: expected {} instance(s), actual {}",
+                        path, left_count, right_count
+                    ),
+                });
+            }
+            super::result::MismatchReason::GroupedIeMismatch { .. } => {
+                self.differences.push(Difference::GroupedIeStructure {
+                    ie_type,
+                    details: mismatch.reason.to_string(),
+                    description: format!("{}: {}", path, mismatch.reason),
+                });
+
+                let mut child_context = context.to_vec();
+                child_context.push(ie_type);
+                for child in &mismatch.children {
+                    self.push_mismatch(child, &child_context);
+                }
+            }
+            super::result::MismatchReason::SemanticMismatch { details } => {
+                self.differences.push(Difference::IeValue {
+                    ie_type,
+                    context: context.to_vec(),
+                    left_hex: format!("semantic: {}", details),
+                    right_hex: "differs".to_string(),
+                    description: format!("{}: {}", path, details),
+                });
+            }
+            _ => {}
+        }
+    }
+
     /// Returns true if there are no differences.
     pub fn is_empty(&self) -> bool {
         self.differences.is_empty()
@@ -220,52 +262,92 @@ impl MessageDiff {
 
 impl fmt::Display for MessageDiff {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_yaml())
+        writeln!(f, "left:  {:?}", self.left_type)?;
+        writeln!(f, "right: {:?}", self.right_type)?;
+        writeln!(f, "differences: {}", self.len())?;
+        for diff in &self.differences {
+            writeln!(f, "  - {}", diff.description())?;
+        }
+        Ok(())
     }
 }
 
 /// A specific difference between messages.
-#[derive(Debug, Clone)]
+///
+/// Each variant carries a human-readable `description` naming the IE path
+/// and the expected (left) and actual (right) values, for example
+/// `"Create PDR > Precedence: expected 100, actual 200"`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum Difference {
     /// Header field differs
     HeaderField {
         field: HeaderField,
         left_value: String,
         right_value: String,
+        description: String,
     },
 
     /// IE value differs
     IeValue {
+        #[serde(serialize_with = "serialize::debug_name")]
         ie_type: IeType,
+        #[serde(serialize_with = "serialize::debug_names")]
         context: Vec<IeType>, // Parent chain for grouped IEs
         left_hex: String,
         right_hex: String,
+        description: String,
     },
 
     /// IE count differs
     IeCount {
+        #[serde(serialize_with = "serialize::debug_name")]
         ie_type: IeType,
         left_count: usize,
         right_count: usize,
+        description: String,
     },
 
     /// IE only in left
     LeftOnly {
+        #[serde(serialize_with = "serialize::debug_name")]
         ie_type: IeType,
+        #[serde(serialize_with = "serialize::debug_names")]
         context: Vec<IeType>,
+        description: String,
     },
 
     /// IE only in right
     RightOnly {
+        #[serde(serialize_with = "serialize::debug_name")]
         ie_type: IeType,
+        #[serde(serialize_with = "serialize::debug_names")]
         context: Vec<IeType>,
+        description: String,
     },
 
     /// Grouped IE structure differs
-    GroupedIeStructure { ie_type: IeType, details: String },
+    GroupedIeStructure {
+        #[serde(serialize_with = "serialize::debug_name")]
+        ie_type: IeType,
+        details: String,
+        description: String,
+    },
 }
 
 impl Difference {
+    /// Human-readable description of this difference.
+    pub fn description(&self) -> &str {
+        match self {
+            Difference::HeaderField { description, .. }
+            | Difference::IeValue { description, .. }
+            | Difference::IeCount { description, .. }
+            | Difference::LeftOnly { description, .. }
+            | Difference::RightOnly { description, .. }
+            | Difference::GroupedIeStructure { description, .. } => description,
+        }
+    }
+
     fn to_yaml_entry(&self) -> String {
         let mut output = String::new();
         match self {
@@ -273,6 +355,7 @@ impl Difference {
                 field,
                 left_value,
                 right_value,
+                ..
             } => {
                 output.push_str("    type: header_field\n");
                 output.push_str(&format!("    field: {:?}\n", field));
@@ -284,6 +367,7 @@ impl Difference {
                 context,
                 left_hex,
                 right_hex,
+                ..
             } => {
                 output.push_str("    type: ie_value\n");
                 let path = if context.is_empty() {
@@ -307,13 +391,16 @@ impl Difference {
                 ie_type,
                 left_count,
                 right_count,
+                ..
             } => {
                 output.push_str("    type: ie_count\n");
                 output.push_str(&format!("    ie: {:?}\n", ie_type));
                 output.push_str(&format!("    left_count:  {}\n", left_count));
                 output.push_str(&format!("    right_count: {}\n", right_count));
             }
-            Difference::LeftOnly { ie_type, context } => {
+            Difference::LeftOnly {
+                ie_type, context, ..
+            } => {
                 output.push_str("    type: left_only\n");
                 let path = if context.is_empty() {
                     format!("{:?}", ie_type)
@@ -330,7 +417,9 @@ impl Difference {
                 };
                 output.push_str(&format!("    ie: {}\n", path));
             }
-            Difference::RightOnly { ie_type, context } => {
+            Difference::RightOnly {
+                ie_type, context, ..
+            } => {
                 output.push_str("    type: right_only\n");
                 let path = if context.is_empty() {
                     format!("{:?}", ie_type)
@@ -347,7 +436,9 @@ impl Difference {
                 };
                 output.push_str(&format!("    ie: {}\n", path));
             }
-            Difference::GroupedIeStructure { ie_type, details } => {
+            Difference::GroupedIeStructure {
+                ie_type, details, ..
+            } => {
                 output.push_str("    type: grouped_ie_structure\n");
                 output.push_str(&format!("    ie: {:?}\n", ie_type));
                 output.push_str(&format!("    details: {}\n", details));
@@ -358,7 +449,7 @@ impl Difference {
 }
 
 /// Header fields that can differ.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum HeaderField {
     /// Message type
     MessageType,
@@ -370,6 +461,39 @@ pub enum HeaderField {
     Priority,
 }
 
+/// Format an IE path such as "Create PDR > Precedence".
+fn format_path(context: &[IeType], ie_type: IeType) -> String {
+    context
+        .iter()
+        .chain(std::iter::once(&ie_type))
+        .map(|t| t.to_string())
+        .collect::<Vec<_>>()
+        .join(" > ")
+}
+
+/// Format an IE payload for a difference description.
+///
+/// Integer-valued IEs such as Precedence and the rule IDs are shown as
+/// decimal numbers; everything else falls back to hex.
+fn format_value(ie_type: IeType, payload: &[u8]) -> String {
+    match ie_type {
+        IeType::Precedence
+        | IeType::PdrId
+        | IeType::FarId
+        | IeType::UrrId
+        | IeType::QerId
+        | IeType::BarId
+            if (1..=8).contains(&payload.len()) =>
+        {
+            payload
+                .iter()
+                .fold(0u64, |acc, b| (acc << 8) | u64::from(*b))
+                .to_string()
+        }
+        _ => format_hex(payload),
+    }
+}
+
 /// Format bytes as hex string.
 ///
 /// Formats up to 16 bytes, truncating with "..." if longer.
@@ -415,6 +539,7 @@ mod tests {
             field: HeaderField::Sequence,
             left_value: "100".to_string(),
             right_value: "200".to_string(),
+            description: "Header Sequence: expected 100, actual 200".to_string(),
         });
         assert_eq!(diff.summary(), "1 difference(s) found");
     }
@@ -439,6 +564,7 @@ mod tests {
             field: HeaderField::Sequence,
             left_value: "100".to_string(),
             right_value: "200".to_string(),
+            description: "Header Sequence: expected 100, actual 200".to_string(),
         };
         let yaml = diff.to_yaml_entry();
         assert!(yaml.contains("type: header_field"));
@@ -454,6 +580,7 @@ mod tests {
             context: vec![],
             left_hex: "01".to_string(),
             right_hex: "02".to_string(),
+            description: "Cause: expected 01, actual 02".to_string(),
         };
         let yaml = diff.to_yaml_entry();
         assert!(yaml.contains("type: ie_value"));
@@ -468,6 +595,7 @@ mod tests {
             ie_type: IeType::CreatePdr,
             left_count: 2,
             right_count: 3,
+            description: "Create PDR: expected 2 instance(s), actual 3".to_string(),
         };
         let yaml = diff.to_yaml_entry();
         assert!(yaml.contains("type: ie_count"));
@@ -483,11 +611,25 @@ mod tests {
             field: HeaderField::Sequence,
             left_value: "100".to_string(),
             right_value: "200".to_string(),
+            description: "Header Sequence: expected 100, actual 200".to_string(),
         });
 
         let display = format!("{}", diff);
         assert!(display.contains("HeartbeatRequest"));
         assert!(display.contains("differences: 1"));
+        assert!(display.contains("  - Header Sequence: expected 100, actual 200"));
+    }
+
+    #[test]
+    fn test_format_path_and_value() {
+        assert_eq!(format_path(&[], IeType::Cause), "Cause");
+        assert_eq!(
+            format_path(&[IeType::CreatePdr, IeType::Pdi], IeType::SourceInterface),
+            "Create PDR > PDI > Source Interface"
+        );
+        assert_eq!(format_value(IeType::Precedence, &[0, 0, 0, 100]), "100");
+        assert_eq!(format_value(IeType::PdrId, &[0x01, 0x00]), "256");
+        assert_eq!(format_value(IeType::Cause, &[0x01]), "01");
     }
 
     #[test]
@@ -496,6 +638,7 @@ mod tests {
         diff.differences.push(Difference::LeftOnly {
             ie_type: IeType::NodeId,
             context: vec![],
+            description: "Node ID: expected present, actual missing".to_string(),
         });
 
         let yaml = diff.to_yaml();
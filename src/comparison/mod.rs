@@ -139,12 +139,15 @@
 pub mod builder;
 pub mod diff;
 pub mod options;
+pub mod report;
 pub mod result;
 pub mod semantic;
+mod serialize;
 
 pub use builder::MessageComparator;
 pub use diff::{Difference, HeaderField, MessageDiff};
 pub use options::{ComparisonOptions, IeMultiplicityMode, OptionalIeMode};
+pub use report::ComparisonReport;
 pub use result::{
     ComparisonResult, ComparisonStats, HeaderMatch, IeMatch, IeMatchType, IeMismatch,
     MismatchReason,
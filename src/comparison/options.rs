@@ -1,6 +1,8 @@
 //! Configuration options for message comparison.
 
+use super::serialize;
 use crate::ie::IeType;
+use serde::Serialize;
 use std::collections::HashSet;
 
 /// Configuration options for message comparison.
@@ -17,7 +19,7 @@ use std::collections::HashSet;
 /// options.ignore_sequence = true;
 /// options.ignore_timestamps = true;
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ComparisonOptions {
     // ========================================================================
     // Header Field Filtering
@@ -59,11 +61,20 @@ pub struct ComparisonOptions {
     /// Set of IE types to ignore during comparison.
     ///
     /// These IEs will be skipped entirely and not compared.
+    #[serde(serialize_with = "serialize::sorted_debug_names")]
     pub ignored_ie_types: HashSet<IeType>,
 
+    /// Set of `(parent, child)` IE type pairs to ignore inside grouped IEs.
+    ///
+    /// The child IE type is skipped only when it appears directly within
+    /// the given parent grouped IE; other children are still compared.
+    #[serde(serialize_with = "serialize::sorted_ie_pairs")]
+    pub ignored_ie_children: HashSet<(IeType, IeType)>,
+
     /// If set, only compare these IE types (ignore all others).
     ///
     /// Useful for focused validation of specific IEs.
+    #[serde(serialize_with = "serialize::optional_sorted_debug_names")]
     pub focus_ie_types: Option<HashSet<IeType>>,
 
     // ========================================================================
@@ -104,6 +115,7 @@ pub struct ComparisonOptions {
     /// Set of IE types to use semantic comparison for.
     ///
     /// Overrides use_semantic_comparison for specific types.
+    #[serde(serialize_with = "serialize::sorted_debug_names")]
     pub semantic_ie_types: HashSet<IeType>,
 
     // ========================================================================
@@ -135,6 +147,7 @@ impl Default for ComparisonOptions {
             ignore_timestamps: false,
             timestamp_tolerance_secs: None,
             ignored_ie_types: HashSet::new(),
+            ignored_ie_children: HashSet::new(),
             focus_ie_types: None,
             strict_ie_order: false,
             ie_multiplicity_mode: IeMultiplicityMode::ExactMatch,
@@ -173,6 +186,13 @@ impl ComparisonOptions {
         true
     }
 
+    /// Check if a child IE type should be compared within a grouped parent IE.
+    ///
+    /// Returns false if the `(parent, child)` pair has been marked as ignored.
+    pub fn should_compare_child_ie(&self, parent: IeType, child: IeType) -> bool {
+        !self.ignored_ie_children.contains(&(parent, child))
+    }
+
     /// Check if semantic comparison should be used for an IE type.
     pub fn use_semantic_for_ie(&self, ie_type: IeType) -> bool {
         self.use_semantic_comparison || self.semantic_ie_types.contains(&ie_type)
@@ -183,7 +203,7 @@ impl ComparisonOptions {
 ///
 /// Per 3GPP TS 29.244, some IEs can appear multiple times in a message
 /// (e.g., multiple CreatePDR IEs in a SessionEstablishmentRequest).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum IeMultiplicityMode {
     /// Must have exactly the same count and values (order-independent).
     ///
@@ -225,7 +245,7 @@ pub enum IeMultiplicityMode {
 /// How to handle IEs present in one message but not the other.
 ///
 /// Controls comparison behavior for optional IEs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum OptionalIeMode {
     /// Any difference in IE presence is a mismatch.
     ///
@@ -332,6 +352,20 @@ mod tests {
         assert!(options.should_compare_ie(IeType::Cause));
     }
 
+    #[test]
+    fn test_should_compare_child_ie() {
+        let mut options = ComparisonOptions::default();
+        assert!(options.should_compare_child_ie(IeType::CreatePdr, IeType::Precedence));
+
+        options
+            .ignored_ie_children
+            .insert((IeType::CreatePdr, IeType::Precedence));
+
+        assert!(!options.should_compare_child_ie(IeType::CreatePdr, IeType::Precedence));
+        assert!(options.should_compare_child_ie(IeType::CreatePdr, IeType::PdrId));
+        assert!(options.should_compare_child_ie(IeType::UpdatePdr, IeType::Precedence));
+    }
+
     #[test]
     fn test_should_compare_ie_ignore_timestamps() {
         let options = ComparisonOptions {
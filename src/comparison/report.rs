@@ -0,0 +1,141 @@
+//! Machine-readable comparison reports.
+
+use super::{serialize, ComparisonOptions, ComparisonResult, Difference};
+use crate::message::MsgType;
+use serde::Serialize;
+use std::time::SystemTime;
+
+/// A comparison outcome bundled with the options that produced it.
+///
+/// Serializes to JSON or YAML so CI tools can ingest diffs
+/// programmatically.
+///
+/// # Examples
+///
+/// ```rust
+/// use rs_pfcp::comparison::MessageComparator;
+/// use rs_pfcp::message::heartbeat_request::HeartbeatRequestBuilder;
+/// use std::time::SystemTime;
+///
+/// let left = HeartbeatRequestBuilder::new(1)
+///     .recovery_time_stamp(SystemTime::now())
+///     .build();
+/// let right = HeartbeatRequestBuilder::new(2)
+///     .recovery_time_stamp(SystemTime::now())
+///     .build();
+///
+/// let report = MessageComparator::new(&left, &right).report()?;
+/// let json = report.to_json().unwrap();
+/// assert!(json.contains("\"differences\""));
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparisonReport {
+    /// When the comparison was run, serialized as seconds since the Unix epoch
+    #[serde(serialize_with = "serialize::unix_secs")]
+    pub timestamp: SystemTime,
+
+    /// Message types being compared
+    #[serde(serialize_with = "serialize::debug_name")]
+    pub left_message_type: MsgType,
+    #[serde(serialize_with = "serialize::debug_name")]
+    pub right_message_type: MsgType,
+
+    /// Options the comparison ran with
+    pub options_used: ComparisonOptions,
+
+    /// Full comparison result
+    pub result: ComparisonResult,
+
+    /// Differences found, as in [`MessageDiff`](super::MessageDiff)
+    pub differences: Vec<Difference>,
+}
+
+impl ComparisonReport {
+    /// Serializes the report as compact JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Serializes the report as pretty-printed JSON.
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serializes the report as YAML.
+    pub fn to_yaml(&self) -> Result<String, serde_yaml_ng::Error> {
+        serde_yaml_ng::to_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::comparison::MessageComparator;
+    use crate::ie::IeType;
+    use crate::message::heartbeat_request::HeartbeatRequestBuilder;
+    use serde_json::Value;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_report_to_json() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let left = HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(time)
+            .build();
+        let right = HeartbeatRequestBuilder::new(2)
+            .recovery_time_stamp(time)
+            .build();
+
+        let report = MessageComparator::new(&left, &right)
+            .ignore_ie_types(&[IeType::SourceIpAddress, IeType::NodeId])
+            .report()
+            .unwrap();
+        assert!(!report.result.is_match());
+        assert_eq!(report.differences.len(), 1);
+
+        let json = report.to_json().unwrap();
+        assert!(json.contains("\"differences\""));
+
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert!(value["timestamp"].is_u64());
+        assert_eq!(value["left_message_type"], "HeartbeatRequest");
+        assert_eq!(value["result"]["is_match"], false);
+        assert_eq!(value["result"]["left_type"], "HeartbeatRequest");
+        assert_eq!(value["result"]["header_match"]["sequence_match"], false);
+        assert_eq!(value["options_used"]["ignore_sequence"], false);
+        assert_eq!(
+            value["options_used"]["ignored_ie_types"],
+            serde_json::json!(["NodeId", "SourceIpAddress"])
+        );
+        assert_eq!(value["options_used"]["ie_multiplicity_mode"], "ExactMatch");
+        assert_eq!(value["differences"][0]["type"], "header_field");
+        assert_eq!(value["differences"][0]["field"], "Sequence");
+        assert!(value["differences"][0]["description"]
+            .as_str()
+            .unwrap()
+            .contains("Sequence"));
+    }
+
+    #[test]
+    fn test_report_matching_messages_to_yaml() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let left = HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(time)
+            .build();
+        let right = HeartbeatRequestBuilder::new(2)
+            .recovery_time_stamp(time)
+            .build();
+
+        let report = MessageComparator::new(&left, &right)
+            .ignore_sequence()
+            .report()
+            .unwrap();
+        assert!(report.result.is_match());
+        assert!(report.differences.is_empty());
+
+        let yaml = report.to_yaml().unwrap();
+        assert!(yaml.contains("is_match: true"));
+        assert!(yaml.contains("ignore_sequence: true"));
+        assert!(yaml.contains("differences: []"));
+    }
+}
@@ -1,8 +1,9 @@
 //! Comparison result types.
 
-use super::MessageDiff;
+use super::{serialize, MessageDiff};
 use crate::ie::IeType;
 use crate::message::MsgType;
+use serde::Serialize;
 
 /// Result of a message comparison operation.
 ///
@@ -31,10 +32,12 @@ use crate::message::MsgType;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ComparisonResult {
     /// Message types being compared
+    #[serde(serialize_with = "serialize::debug_name")]
     pub left_type: MsgType,
+    #[serde(serialize_with = "serialize::debug_name")]
     pub right_type: MsgType,
 
     /// Overall match status
@@ -50,9 +53,11 @@ pub struct ComparisonResult {
     pub ie_mismatches: Vec<IeMismatch>,
 
     /// IEs only in left message
+    #[serde(serialize_with = "serialize::debug_names")]
     pub left_only_ies: Vec<IeType>,
 
     /// IEs only in right message
+    #[serde(serialize_with = "serialize::debug_names")]
     pub right_only_ies: Vec<IeType>,
 
     /// Detailed diff (if requested)
@@ -194,7 +199,7 @@ impl ComparisonResult {
 /// Header comparison result.
 ///
 /// Indicates which header fields matched and which didn't.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct HeaderMatch {
     /// Message type match status (always checked)
     pub message_type_match: bool,
@@ -244,9 +249,10 @@ impl HeaderMatch {
 /// Successful IE match.
 ///
 /// Records that an IE was successfully compared and matched.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IeMatch {
     /// The IE type that matched
+    #[serde(serialize_with = "serialize::debug_name")]
     pub ie_type: IeType,
 
     /// How the IE matched
@@ -254,7 +260,7 @@ pub struct IeMatch {
 }
 
 /// Type of IE match that occurred.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum IeMatchType {
     /// Exact byte-for-byte match
     Exact,
@@ -272,9 +278,10 @@ pub enum IeMatchType {
 /// IE that didn't match between messages.
 ///
 /// Contains information about why the IE failed to match.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IeMismatch {
     /// The IE type that didn't match
+    #[serde(serialize_with = "serialize::debug_name")]
     pub ie_type: IeType,
 
     /// Why the IE didn't match
@@ -290,10 +297,13 @@ pub struct IeMismatch {
     ///
     /// For example: "CreatePdr > Pdi > SourceInterface"
     pub context: Option<String>,
+
+    /// Child IE mismatches of a deep-compared grouped IE
+    pub children: Vec<IeMismatch>,
 }
 
 /// Reason why an IE didn't match.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum MismatchReason {
     /// Payload values differ
     ValueMismatch,
@@ -363,7 +373,7 @@ impl std::fmt::Display for MismatchReason {
 /// Statistics about the comparison.
 ///
 /// Tracks how many IEs were compared and what the outcomes were.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Serialize)]
 pub struct ComparisonStats {
     /// Total number of IEs compared
     pub total_ies_compared: usize,
@@ -562,6 +572,7 @@ mod tests {
                 left_payload: None,
                 right_payload: None,
                 context: None,
+                children: vec![],
             }],
             left_only_ies: vec![IeType::NodeId],
             right_only_ies: vec![IeType::RecoveryTimeStamp],
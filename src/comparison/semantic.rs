@@ -175,6 +175,7 @@ fn compare_fteid(left: &Fteid, right: &Fteid) -> SemanticMatch {
 ///
 /// UE IP Address semantic comparison focuses on:
 /// - IP addresses (IPv4 and/or IPv6)
+/// - S/D flag (whether the address is matched as source or destination)
 ///
 /// Ignores:
 /// - v4/v6 flags (derived from presence of IP addresses)
@@ -189,6 +190,13 @@ fn compare_fteid(left: &Fteid, right: &Fteid) -> SemanticMatch {
 /// different implementations might set them differently. We ignore them
 /// for semantic comparison, focusing only on the actual IP addresses.
 fn compare_ue_ip_address(left: &UeIpAddress, right: &UeIpAddress) -> SemanticMatch {
+    // Compare S/D flag
+    if left.sd != right.sd {
+        return SemanticMatch::Mismatch {
+            details: format!("S/D flag differs: {} vs {}", left.sd, right.sd),
+        };
+    }
+
     // Compare IPv4 address
     if left.ipv4_address != right.ipv4_address {
         return SemanticMatch::Mismatch {
@@ -408,6 +416,16 @@ mod tests {
         assert!(result.is_match());
     }
 
+    #[test]
+    fn test_ue_ip_address_semantic_mismatch_sd_flag() {
+        let ue1 = UeIpAddress::new(Some(Ipv4Addr::new(10, 0, 0, 1)), None);
+        let ue2 = UeIpAddress::downlink_source(Ipv4Addr::new(10, 0, 0, 1));
+
+        let result = compare_ue_ip_address(&ue1, &ue2);
+        assert!(!result.is_match());
+        assert!(result.details().unwrap().contains("S/D flag differs"));
+    }
+
     #[test]
     fn test_ue_ip_address_semantic_mismatch_different_ipv4() {
         let ue1 = UeIpAddress::new(Some(Ipv4Addr::new(10, 0, 0, 1)), None);
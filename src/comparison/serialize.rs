@@ -0,0 +1,60 @@
+//! `serialize_with` helpers for the comparison types.
+//!
+//! [`IeType`] and [`MsgType`](crate::message::MsgType) serialize by their
+//! variant names, set-valued fields as sorted lists so reports are
+//! deterministic, and timestamps as seconds since the Unix epoch.
+
+use crate::ie::IeType;
+use serde::ser::{Serialize, Serializer};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub(super) fn unix_secs<S: Serializer>(time: &SystemTime, s: S) -> Result<S::Ok, S::Error> {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    s.serialize_u64(secs)
+}
+
+pub(super) fn debug_name<T: Debug, S: Serializer>(value: &T, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_str(&format_args!("{:?}", value))
+}
+
+pub(super) fn debug_names<T: Debug, S: Serializer>(values: &[T], s: S) -> Result<S::Ok, S::Error> {
+    s.collect_seq(values.iter().map(|v| format!("{:?}", v)))
+}
+
+fn sorted_names<'a, T: Debug + 'a>(values: impl IntoIterator<Item = &'a T>) -> Vec<String> {
+    let mut names: Vec<String> = values.into_iter().map(|v| format!("{:?}", v)).collect();
+    names.sort();
+    names
+}
+
+pub(super) fn sorted_debug_names<T: Debug, S: Serializer>(
+    values: &HashSet<T>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    sorted_names(values).serialize(s)
+}
+
+pub(super) fn optional_sorted_debug_names<T: Debug, S: Serializer>(
+    values: &Option<HashSet<T>>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    values.as_ref().map(sorted_names).serialize(s)
+}
+
+/// Serializes `(parent, child)` pairs as sorted `"Parent > Child"` strings.
+pub(super) fn sorted_ie_pairs<S: Serializer>(
+    pairs: &HashSet<(IeType, IeType)>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    let mut names: Vec<String> = pairs
+        .iter()
+        .map(|(parent, child)| format!("{:?} > {:?}", parent, child))
+        .collect();
+    names.sort();
+    names.serialize(s)
+}
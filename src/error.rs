@@ -5,7 +5,7 @@
 //!
 //! ## PfcpError (v0.2.5+)
 //!
-//! The `PfcpError` enum provides structured error handling with 8 variants:
+//! The `PfcpError` enum provides structured error handling with 10 variants:
 //! - `MissingMandatoryIe` - Required IE not present
 //! - `InvalidLength` - Payload too short or incorrect size
 //! - `InvalidValue` - Invalid field value
@@ -14,6 +14,7 @@
 //! - `IeParseError` - IE-specific parsing error
 //! - `EncodingError` - UTF-8 or other encoding error
 //! - `MessageParseError` - Message-level parsing error
+//! - `UnsupportedVersion` - PFCP version other than 1 in a message header
 //! - `IoError` - Underlying I/O error wrapper
 //!
 //! All unmarshal methods in the library return `Result<T, PfcpError>`.
@@ -500,6 +501,16 @@ pub enum PfcpError {
         reason: String,
     },
 
+    /// Unsupported PFCP version
+    ///
+    /// This error occurs when a message header carries a PFCP version other than 1.
+    /// Per 3GPP TS 29.244 Section 7.2.2.1, the receiver should reply with a
+    /// [`VersionNotSupportedResponse`](crate::message::VersionNotSupportedResponse).
+    ///
+    /// # Fields
+    /// - `version`: The version found in the header
+    UnsupportedVersion { version: u8 },
+
     /// Underlying I/O error
     ///
     /// This error wraps transport-level I/O errors from the standard library.
@@ -519,23 +530,13 @@ impl fmt::Display for PfcpError {
                 message_type,
                 parent_ie,
             } => {
-                let ie_name = format!("{:?}", ie_type);
+                write!(f, "Missing mandatory IE '{}'", ie_type.name())?;
                 if let Some(msg_type) = message_type {
-                    write!(
-                        f,
-                        "{} in {:?}",
-                        messages::missing_mandatory_ie_short(&ie_name),
-                        msg_type
-                    )
+                    write!(f, " in {:?}", msg_type)
                 } else if let Some(parent) = parent_ie {
-                    write!(
-                        f,
-                        "{} in grouped IE {:?}",
-                        messages::missing_mandatory_ie_short(&ie_name),
-                        parent
-                    )
+                    write!(f, " in grouped IE '{}'", parent.name())
                 } else {
-                    write!(f, "{}", messages::missing_mandatory_ie_short(&ie_name))
+                    Ok(())
                 }
             }
 
@@ -547,24 +548,26 @@ impl fmt::Display for PfcpError {
                 if let Some(off) = offset {
                     write!(
                         f,
-                        "Failed to parse {:?} at offset {}: {}",
-                        ie_type, off, reason
+                        "Failed to parse IE '{}' at offset {}: {}",
+                        ie_type.name(),
+                        off,
+                        reason
                     )
                 } else {
-                    write!(f, "Failed to parse {:?}: {}", ie_type, reason)
+                    write!(f, "Failed to parse IE '{}': {}", ie_type.name(), reason)
                 }
             }
 
             PfcpError::InvalidLength {
                 ie_name,
+                ie_type,
                 expected,
                 actual,
-                ..
             } => {
                 write!(
                     f,
-                    "{}",
-                    messages::invalid_length(ie_name, *expected, *actual)
+                    "Invalid length for IE '{}' (type {}): expected {} bytes, got {} bytes",
+                    ie_name, *ie_type as u16, expected, actual
                 )
             }
 
@@ -611,6 +614,14 @@ impl fmt::Display for PfcpError {
                 }
             }
 
+            PfcpError::UnsupportedVersion { version } => {
+                write!(
+                    f,
+                    "Unsupported PFCP version {}: only version 1 is supported; reply with a Version Not Supported Response",
+                    version
+                )
+            }
+
             PfcpError::IoError { kind, message } => {
                 write!(f, "I/O error ({:?}): {}", kind, message)
             }
@@ -653,6 +664,53 @@ impl From<std::str::Utf8Error> for PfcpError {
     }
 }
 
+/// Extension methods for converting `io::Error` results into [`PfcpError`]
+/// with added context.
+///
+/// The `io::ErrorKind` of the original error is preserved; the context is
+/// prepended to the error message.
+///
+/// # Examples
+///
+/// ```rust
+/// use rs_pfcp::error::{PfcpError, ResultExt};
+/// use std::io::{self, Read};
+///
+/// fn read_header(mut reader: impl Read) -> Result<[u8; 4], PfcpError> {
+///     let mut buf = [0u8; 4];
+///     reader.read_exact(&mut buf).or_pfcp_error("reading PFCP header")?;
+///     Ok(buf)
+/// }
+///
+/// let err = read_header(io::empty()).unwrap_err();
+/// assert!(err.to_string().contains("reading PFCP header"));
+/// ```
+pub trait ResultExt<T> {
+    /// Converts the error into [`PfcpError::IoError`], prefixing the message
+    /// with `context`.
+    fn or_pfcp_error(self, context: &'static str) -> Result<T, PfcpError>;
+
+    /// Converts the error into [`PfcpError::IoError`], prefixing the message
+    /// with the message type being processed.
+    fn pfcp_context(self, msg_type: crate::message::MsgType) -> Result<T, PfcpError>;
+}
+
+impl<T> ResultExt<T> for Result<T, io::Error> {
+    fn or_pfcp_error(self, context: &'static str) -> Result<T, PfcpError> {
+        self.map_err(|err| PfcpError::IoError {
+            kind: err.kind(),
+            message: format!("{}: {}", context, err),
+        })
+    }
+
+    fn pfcp_context(self, msg_type: crate::message::MsgType) -> Result<T, PfcpError> {
+        self.map_err(|err| PfcpError::IoError {
+            kind: err.kind(),
+            message: format!("{:?}: {}", msg_type, err),
+        })
+    }
+}
+
 // ============================================================================
 // Helper constructors for common error patterns
 // ============================================================================
@@ -759,6 +817,23 @@ impl PfcpError {
         }
     }
 
+    /// Create an unsupported PFCP version error
+    ///
+    /// Per 3GPP TS 29.244 Section 7.2.2.1, the only supported version is 1. A
+    /// peer receiving any other version should reply with a
+    /// [`VersionNotSupportedResponse`](crate::message::VersionNotSupportedResponse).
+    pub fn unsupported_version(version: u8) -> Self {
+        PfcpError::UnsupportedVersion { version }
+    }
+
+    /// Returns the rejected version if this error reports an unsupported PFCP version.
+    pub fn unsupported_version_number(&self) -> Option<u8> {
+        match self {
+            PfcpError::UnsupportedVersion { version } => Some(*version),
+            _ => None,
+        }
+    }
+
     /// Create a message parse error
     pub fn message_parse_error(reason: impl Into<String>) -> Self {
         PfcpError::MessageParseError {
@@ -797,7 +872,7 @@ impl PfcpError {
     /// - IE parsing errors → Cause 69 (Mandatory IE Incorrect)
     /// - Validation errors → Cause 73 (Rule Creation/Modification Failure)
     /// - System errors → Cause 77 (System Failure)
-    /// - Message parsing errors → Cause 64 (Request Rejected)
+    /// - Message parsing errors and unsupported versions → Cause 64 (Request Rejected)
     ///
     /// # Examples
     ///
@@ -845,6 +920,11 @@ impl PfcpError {
             // Unable to parse message structure itself
             PfcpError::MessageParseError { .. } => CauseValue::RequestRejected,
 
+            // Unsupported version → Cause 64 (Request Rejected)
+            // There is no dedicated cause; peers should get a Version Not
+            // Supported Response instead of a response carrying a cause
+            PfcpError::UnsupportedVersion { .. } => CauseValue::RequestRejected,
+
             // I/O errors → Cause 77 (System Failure)
             // Underlying transport or system issues
             PfcpError::IoError { .. } => CauseValue::SystemFailure,
@@ -960,7 +1040,7 @@ mod tests {
     // PfcpError Tests (v0.3.0+)
     // ========================================================================
 
-    use super::PfcpError;
+    use super::{PfcpError, ResultExt};
     use std::error::Error;
     use std::io;
 
@@ -976,8 +1056,7 @@ mod tests {
             }
         ));
         let display = format!("{}", err);
-        assert!(display.contains("Missing mandatory"));
-        assert!(display.contains("PdrId"));
+        assert_eq!(display, "Missing mandatory IE 'PDR ID'");
     }
 
     #[test]
@@ -995,9 +1074,10 @@ mod tests {
             }
         ));
         let display = format!("{}", err);
-        assert!(display.contains("Missing mandatory"));
-        assert!(display.contains("NodeId"));
-        assert!(display.contains("HeartbeatRequest"));
+        assert_eq!(
+            display,
+            "Missing mandatory IE 'Node ID' in HeartbeatRequest"
+        );
     }
 
     #[test]
@@ -1015,9 +1095,10 @@ mod tests {
             }
         ));
         let display = format!("{}", err);
-        assert!(display.contains("Missing mandatory"));
-        assert!(display.contains("PdrId"));
-        assert!(display.contains("CreatePdr"));
+        assert_eq!(
+            display,
+            "Missing mandatory IE 'PDR ID' in grouped IE 'Create PDR'"
+        );
     }
 
     #[test]
@@ -1032,9 +1113,7 @@ mod tests {
             }
         ));
         let display = format!("{}", err);
-        assert!(display.contains("Failed to parse"));
-        assert!(display.contains("Fteid"));
-        assert!(display.contains("Invalid flags"));
+        assert_eq!(display, "Failed to parse IE 'F-TEID': Invalid flags");
     }
 
     #[test]
@@ -1049,10 +1128,10 @@ mod tests {
             }
         ));
         let display = format!("{}", err);
-        assert!(display.contains("Failed to parse"));
-        assert!(display.contains("CreatePdr"));
-        assert!(display.contains("Bad PDI"));
-        assert!(display.contains("offset 42"));
+        assert_eq!(
+            display,
+            "Failed to parse IE 'Create PDR' at offset 42: Bad PDI"
+        );
     }
 
     #[test]
@@ -1068,9 +1147,28 @@ mod tests {
             }
         ));
         let display = format!("{}", err);
-        assert!(display.contains("Invalid F-TEID length"));
-        assert!(display.contains("expected at least 9"));
-        assert!(display.contains("got 5"));
+        assert_eq!(
+            display,
+            "Invalid length for IE 'F-TEID' (type 21): expected 9 bytes, got 5 bytes"
+        );
+    }
+
+    #[test]
+    fn test_pfcp_error_display_uses_spec_ie_names() {
+        let err = PfcpError::invalid_length("Create PDR", crate::ie::IeType::CreatePdr, 4, 2);
+        assert_eq!(
+            err.to_string(),
+            "Invalid length for IE 'Create PDR' (type 1): expected 4 bytes, got 2 bytes"
+        );
+
+        let err = PfcpError::missing_ie_in_message(
+            crate::ie::IeType::NodeId,
+            crate::message::MsgType::SessionEstablishmentRequest,
+        );
+        assert_eq!(
+            err.to_string(),
+            "Missing mandatory IE 'Node ID' in SessionEstablishmentRequest"
+        );
     }
 
     #[test]
@@ -1154,6 +1252,57 @@ mod tests {
         assert!(display.contains("short read"));
     }
 
+    #[test]
+    fn test_result_ext_or_pfcp_error() {
+        let result: Result<(), io::Error> =
+            Err(io::Error::new(io::ErrorKind::UnexpectedEof, "short read"));
+        let err = result.or_pfcp_error("reading PFCP header").unwrap_err();
+        assert!(matches!(
+            err,
+            PfcpError::IoError {
+                kind: io::ErrorKind::UnexpectedEof,
+                ..
+            }
+        ));
+        let display = err.to_string();
+        assert!(display.contains("reading PFCP header"));
+        assert!(display.contains("short read"));
+
+        let ok: Result<u8, io::Error> = Ok(7);
+        assert_eq!(ok.or_pfcp_error("unused"), Ok(7));
+    }
+
+    #[test]
+    fn test_result_ext_pfcp_context() {
+        let result: Result<(), io::Error> =
+            Err(io::Error::new(io::ErrorKind::TimedOut, "no response"));
+        let err = result
+            .pfcp_context(crate::message::MsgType::HeartbeatRequest)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PfcpError::IoError {
+                kind: io::ErrorKind::TimedOut,
+                ..
+            }
+        ));
+        let display = err.to_string();
+        assert!(display.contains("HeartbeatRequest"));
+        assert!(display.contains("no response"));
+    }
+
+    #[test]
+    fn test_pfcp_error_unsupported_version() {
+        let err = PfcpError::unsupported_version(2);
+        assert_eq!(err, PfcpError::UnsupportedVersion { version: 2 });
+        assert_eq!(err.unsupported_version_number(), Some(2));
+        assert!(err.to_string().contains("Version Not Supported Response"));
+
+        // The message text plays no part in detecting the error
+        let other = PfcpError::invalid_value("PFCP version", "2", "reason");
+        assert_eq!(other.unsupported_version_number(), None);
+    }
+
     #[test]
     fn test_pfcp_error_from_utf8_error() {
         let invalid_utf8 = vec![0xFF, 0xFE, 0xFD];
@@ -1309,6 +1458,12 @@ mod tests {
         assert_eq!(err.to_cause_code(), CauseValue::RequestRejected);
     }
 
+    #[test]
+    fn test_to_cause_code_unsupported_version() {
+        let err = PfcpError::unsupported_version(2);
+        assert_eq!(err.to_cause_code(), CauseValue::RequestRejected);
+    }
+
     #[test]
     fn test_to_cause_code_io_error() {
         let io_err = io::Error::new(io::ErrorKind::UnexpectedEof, "short read");
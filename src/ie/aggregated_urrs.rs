@@ -66,7 +66,7 @@ mod tests {
     use super::*;
 
     fn make_ie() -> AggregatedUrrs {
-        AggregatedUrrs::new(AggregatedUrrId::new(10), Multiplier::new(2))
+        AggregatedUrrs::new(AggregatedUrrId::new(10), Multiplier::new(2, 0))
     }
 
     #[test]
@@ -78,7 +78,7 @@ mod tests {
 
     #[test]
     fn test_missing_aggregated_urr_id() {
-        let ie = AggregatedUrrs::new(AggregatedUrrId::new(1), Multiplier::new(1));
+        let ie = AggregatedUrrs::new(AggregatedUrrId::new(1), Multiplier::new(1, 0));
         let payload = marshal_ies(&[ie.multiplier.to_ie()]);
         assert!(matches!(
             AggregatedUrrs::unmarshal(&payload),
@@ -1,56 +1,71 @@
 //! Application IDs PFDs IE.
 
 use crate::error::PfcpError;
-use crate::ie::{application_id::ApplicationId, pfd_context::PfdContext, Ie, IeType};
+use crate::ie::{application_id::ApplicationId, pfd_context::PfdContext, Ie, IeIterator, IeType};
 
 /// Represents Application IDs PFDs.
+///
+/// Per 3GPP TS 29.244 Table 7.4.3.1-2, an application may carry several PFD
+/// Contexts. Sending none removes all PFDs provisioned for the application.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ApplicationIdsPfds {
     pub application_id: ApplicationId,
-    pub pfd_context: PfdContext,
+    pub pfd_contexts: Vec<PfdContext>,
 }
 
 impl ApplicationIdsPfds {
-    /// Creates a new Application IDs PFDs.
+    /// Creates a new Application IDs PFDs with a single PFD Context.
     pub fn new(application_id: ApplicationId, pfd_context: PfdContext) -> Self {
         ApplicationIdsPfds {
             application_id,
-            pfd_context,
+            pfd_contexts: vec![pfd_context],
         }
     }
 
+    /// Creates a new Application IDs PFDs with any number of PFD Contexts.
+    pub fn with_pfd_contexts(application_id: ApplicationId, pfd_contexts: Vec<PfdContext>) -> Self {
+        ApplicationIdsPfds {
+            application_id,
+            pfd_contexts,
+        }
+    }
+
+    /// Adds a PFD Context.
+    pub fn add_pfd_context(mut self, pfd_context: PfdContext) -> Self {
+        self.pfd_contexts.push(pfd_context);
+        self
+    }
+
     /// Marshals the Application IDs PFDs into a byte vector, which is the payload of the IE.
     pub fn marshal(&self) -> Vec<u8> {
         let mut data = Vec::new();
         data.extend_from_slice(&self.application_id.to_ie().marshal());
-        data.extend_from_slice(&self.pfd_context.to_ie().marshal());
+        for pfd_context in &self.pfd_contexts {
+            data.extend_from_slice(&pfd_context.to_ie().marshal());
+        }
         data
     }
 
     /// Unmarshals a byte slice into Application IDs PFDs.
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut application_id = None;
-        let mut pfd_context = None;
-        let mut offset = 0;
-        while offset < payload.len() {
-            let ie = Ie::unmarshal(&payload[offset..])?;
+        let mut pfd_contexts = Vec::new();
+        for ie_result in IeIterator::new(payload) {
+            let ie = ie_result?;
             match ie.ie_type {
                 IeType::ApplicationId => {
                     application_id = Some(ApplicationId::unmarshal(&ie.payload)?)
                 }
-                IeType::PfdContext => pfd_context = Some(PfdContext::unmarshal(&ie.payload)?),
+                IeType::PfdContext => pfd_contexts.push(PfdContext::unmarshal(&ie.payload)?),
                 _ => (),
             }
-            offset += ie.len() as usize;
         }
 
         Ok(ApplicationIdsPfds {
             application_id: application_id.ok_or_else(|| {
                 PfcpError::missing_ie_in_grouped(IeType::ApplicationId, IeType::ApplicationIdsPfds)
             })?,
-            pfd_context: pfd_context.ok_or_else(|| {
-                PfcpError::missing_ie_in_grouped(IeType::PfdContext, IeType::ApplicationIdsPfds)
-            })?,
+            pfd_contexts,
         })
     }
 
@@ -59,3 +74,45 @@ impl ApplicationIdsPfds {
         Ie::new(IeType::ApplicationIdsPfds, self.marshal())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ie::pfd_contents::PfdContents;
+
+    #[test]
+    fn test_application_ids_pfds_multiple_contexts_round_trip() {
+        let app = ApplicationIdsPfds::new(
+            ApplicationId::new("video.example"),
+            PfdContext::new(vec![PfdContents::url("https://video.example/live").unwrap()]),
+        )
+        .add_pfd_context(PfdContext::new(vec![PfdContents::domain_name(
+            "cdn.video.example",
+        )
+        .unwrap()]));
+
+        let parsed = ApplicationIdsPfds::unmarshal(&app.marshal()).unwrap();
+        assert_eq!(parsed.pfd_contexts.len(), 2);
+        assert_eq!(parsed, app);
+    }
+
+    #[test]
+    fn test_application_ids_pfds_without_contexts() {
+        // No PFD Context means all PFDs of the application are removed
+        let app = ApplicationIdsPfds::with_pfd_contexts(ApplicationId::new("app"), vec![]);
+        let parsed = ApplicationIdsPfds::unmarshal(&app.to_ie().payload).unwrap();
+        assert!(parsed.pfd_contexts.is_empty());
+        assert_eq!(parsed, app);
+    }
+
+    #[test]
+    fn test_application_ids_pfds_missing_application_id() {
+        let payload = PfdContext::new(vec![PfdContents::domain_name("example.com").unwrap()])
+            .to_ie()
+            .marshal();
+        assert!(matches!(
+            ApplicationIdsPfds::unmarshal(&payload),
+            Err(PfcpError::MissingMandatoryIe { .. })
+        ));
+    }
+}
@@ -37,6 +37,39 @@ impl ApplyAction {
         }
         Ok(ApplyAction::from_bits_truncate(data[0]))
     }
+
+    /// Checks that the action is usable in a FAR.
+    ///
+    /// Per 3GPP TS 29.244 Section 8.2.26, an empty action does nothing and
+    /// DROP, FORW and BUFF are mutually exclusive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PfcpError::InvalidValue`] if no flag is set or more than one
+    /// of DROP, FORW and BUFF is set.
+    #[must_use = "an invalid Apply Action is only reported through the returned Result"]
+    pub fn validate(&self) -> Result<(), PfcpError> {
+        match self.conflict() {
+            Some(reason) => Err(PfcpError::invalid_value(
+                "Apply Action",
+                format!("{:#04x}", self.bits()),
+                reason,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Describes why the action is invalid, if it is.
+    pub(crate) fn conflict(&self) -> Option<&'static str> {
+        let exclusive = ApplyAction::DROP | ApplyAction::FORW | ApplyAction::BUFF;
+        if self.is_empty() {
+            Some("at least one action must be set")
+        } else if self.intersection(exclusive).bits().count_ones() > 1 {
+            Some("DROP, FORW and BUFF actions are mutually exclusive")
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -60,4 +93,41 @@ mod tests {
         assert!(matches!(err, PfcpError::InvalidLength { .. }));
         assert!(err.to_string().contains("Apply Action"));
     }
+
+    #[test]
+    fn test_apply_action_validate_valid() {
+        for action in [
+            ApplyAction::FORW,
+            ApplyAction::DROP,
+            ApplyAction::BUFF | ApplyAction::NOCP,
+            ApplyAction::FORW | ApplyAction::DUPL,
+            ApplyAction::DUPL,
+        ] {
+            assert!(action.validate().is_ok(), "{action:?}");
+        }
+    }
+
+    #[test]
+    fn test_apply_action_validate_empty() {
+        let result = ApplyAction::empty().validate();
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_apply_action_validate_conflicts() {
+        for action in [
+            ApplyAction::DROP | ApplyAction::FORW,
+            ApplyAction::DROP | ApplyAction::BUFF,
+            ApplyAction::FORW | ApplyAction::BUFF,
+            ApplyAction::DROP | ApplyAction::BUFF | ApplyAction::FORW,
+        ] {
+            match action.validate() {
+                Err(PfcpError::InvalidValue { field, reason, .. }) => {
+                    assert_eq!(field, "Apply Action");
+                    assert!(reason.contains("mutually exclusive"), "{reason}");
+                }
+                other => panic!("Expected InvalidValue for {action:?}, got {other:?}"),
+            }
+        }
+    }
 }
@@ -57,7 +57,7 @@ impl Bar {
                     downlink_data_notification_delay =
                         Some(DownlinkDataNotificationDelay::unmarshal(&ie.payload)?);
                 }
-                IeType::DlBufferingSuggestedPacketCount => {
+                IeType::SuggestedBufferingPacketsCount => {
                     suggested_buffering_packets_count =
                         Some(SuggestedBufferingPacketsCount::unmarshal(&ie.payload)?);
                 }
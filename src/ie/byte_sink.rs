@@ -0,0 +1,35 @@
+//! Append-only byte buffers that IEs, headers and messages are written into.
+//!
+//! `marshal_into` (for `Vec<u8>`) and `write_to_bytes` (for
+//! [`bytes::BytesMut`], behind the `bytes` feature) share a single
+//! `write_into<B: ByteSink>` walk per type, so the wire order of a message's
+//! IEs is spelled out exactly once.
+
+pub(crate) trait ByteSink {
+    /// Reserves capacity for at least `additional` more bytes.
+    fn reserve(&mut self, additional: usize);
+
+    /// Appends `bytes` to the end of the buffer.
+    fn write_slice(&mut self, bytes: &[u8]);
+}
+
+impl ByteSink for Vec<u8> {
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
+    fn write_slice(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl ByteSink for bytes::BytesMut {
+    fn reserve(&mut self, additional: usize) {
+        bytes::BytesMut::reserve(self, additional);
+    }
+
+    fn write_slice(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
@@ -3,26 +3,30 @@
 //! The C-TAG IE contains customer VLAN tagging information for Ethernet packet filtering.
 //! Per 3GPP TS 29.244 Section 8.2.94, this IE is used in Ethernet packet filtering scenarios.
 //!
-//! C-TAG consists of priority, DEI (Drop Eligible Indicator), and VID (VLAN ID).
+//! C-TAG consists of priority, DEI (Drop Eligible Indicator), and VID (VLAN ID),
+//! each preceded by a flag telling whether the UP function shall use it.
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
 
+const PCP: u8 = 0x01;
+const DEI: u8 = 0x02;
+const VID: u8 = 0x04;
+
 /// C-TAG (Customer VLAN Tag)
 ///
 /// Represents customer VLAN tagging information per IEEE 802.1Q.
+/// A field that is `None` is not used for matching and its flag is cleared
+/// on the wire.
 ///
 /// # 3GPP Reference
 /// 3GPP TS 29.244 Section 8.2.94
 ///
 /// # Structure
-/// - Octet 5: Flags (PCP Priority, DEI)
-/// - Octet 5-6: VID (VLAN ID) - 12 bits
-///
 /// ```text
-/// Bits 8-6: PCP (Priority Code Point) - 3 bits
-/// Bit 5: DEI (Drop Eligible Indicator) - 1 bit
-/// Bits 4-1 (and next 8 bits): VID (VLAN ID) - 12 bits
+/// Octet 5: Spare (bits 8-4) | VID (bit 3) | DEI (bit 2) | PCP (bit 1)
+/// Octet 6: C-VID value bits 12-9 (bits 8-5) | DEI flag (bit 4) | PCP value (bits 3-1)
+/// Octet 7: C-VID value bits 8-1
 /// ```
 ///
 /// # Examples
@@ -32,23 +36,24 @@ use crate::ie::{Ie, IeType};
 ///
 /// // Create C-TAG with priority 3, no DEI, VLAN ID 100
 /// let ctag = CTag::new(3, false, 100).unwrap();
-/// assert_eq!(ctag.priority(), 3);
-/// assert_eq!(ctag.dei(), false);
-/// assert_eq!(ctag.vid(), 100);
+/// assert_eq!(ctag.priority(), Some(3));
+/// assert_eq!(ctag.dei(), Some(false));
+/// assert_eq!(ctag.vid(), Some(100));
 ///
 /// // Marshal and unmarshal
 /// let bytes = ctag.marshal();
+/// assert_eq!(bytes, [0x07, 0x03, 0x64]);
 /// let parsed = CTag::unmarshal(&bytes).unwrap();
 /// assert_eq!(ctag, parsed);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct CTag {
     /// Priority Code Point (0-7, 3 bits)
-    pcp: u8,
+    pcp: Option<u8>,
     /// Drop Eligible Indicator (boolean)
-    dei: bool,
+    dei: Option<bool>,
     /// VLAN ID (0-4095, 12 bits)
-    vid: u16,
+    vid: Option<u16>,
 }
 
 impl CTag {
@@ -57,7 +62,7 @@ impl CTag {
     /// Maximum valid VID (12 bits = 0-4095)
     pub const MAX_VID: u16 = 4095;
 
-    /// Create a new C-TAG
+    /// Create a new C-TAG with all of PCP, DEI and VID set
     ///
     /// # Arguments
     /// * `pcp` - Priority Code Point (0-7)
@@ -72,19 +77,41 @@ impl CTag {
     /// use rs_pfcp::ie::c_tag::CTag;
     ///
     /// let ctag = CTag::new(5, true, 1000).unwrap();
-    /// assert_eq!(ctag.priority(), 5);
-    /// assert_eq!(ctag.dei(), true);
-    /// assert_eq!(ctag.vid(), 1000);
+    /// assert_eq!(ctag.priority(), Some(5));
+    /// assert_eq!(ctag.dei(), Some(true));
+    /// assert_eq!(ctag.vid(), Some(1000));
     /// ```
     pub fn new(pcp: u8, dei: bool, vid: u16) -> Result<Self, PfcpError> {
-        if pcp > Self::MAX_PRIORITY {
+        Self::from_fields(Some(pcp), Some(dei), Some(vid))
+    }
+
+    /// Create a C-TAG with only some of PCP, DEI and VID set
+    ///
+    /// # Errors
+    /// Returns error if PCP > 7 or VID > 4095
+    ///
+    /// # Example
+    /// ```
+    /// use rs_pfcp::ie::c_tag::CTag;
+    ///
+    /// // Match VLAN 100 regardless of priority and DEI
+    /// let ctag = CTag::from_fields(None, None, Some(100)).unwrap();
+    /// assert_eq!(ctag.priority(), None);
+    /// assert_eq!(ctag.marshal(), [0x04, 0x00, 0x64]);
+    /// ```
+    pub fn from_fields(
+        pcp: Option<u8>,
+        dei: Option<bool>,
+        vid: Option<u16>,
+    ) -> Result<Self, PfcpError> {
+        if let Some(pcp) = pcp.filter(|pcp| *pcp > Self::MAX_PRIORITY) {
             return Err(PfcpError::invalid_value(
                 "C-TAG PCP",
                 pcp.to_string(),
                 format!("exceeds maximum {}", Self::MAX_PRIORITY),
             ));
         }
-        if vid > Self::MAX_VID {
+        if let Some(vid) = vid.filter(|vid| *vid > Self::MAX_VID) {
             return Err(PfcpError::invalid_value(
                 "C-TAG VID",
                 vid.to_string(),
@@ -94,40 +121,47 @@ impl CTag {
         Ok(CTag { pcp, dei, vid })
     }
 
-    /// Get the Priority Code Point
-    pub fn priority(&self) -> u8 {
+    /// Get the Priority Code Point, if set
+    pub fn priority(&self) -> Option<u8> {
         self.pcp
     }
 
-    /// Get the Drop Eligible Indicator
-    pub fn dei(&self) -> bool {
+    /// Get the Drop Eligible Indicator, if set
+    pub fn dei(&self) -> Option<bool> {
         self.dei
     }
 
-    /// Get the VLAN ID
-    pub fn vid(&self) -> u16 {
+    /// Get the VLAN ID, if set
+    pub fn vid(&self) -> Option<u16> {
         self.vid
     }
 
     /// Marshal C-TAG to bytes
     ///
     /// # Returns
-    /// 3-byte array with C-TAG encoded as:
-    /// - Byte 0: PCP (bits 7-5) | DEI (bit 4) | VID high nibble (bits 3-0)
-    /// - Bytes 1-2: VID low byte
+    /// 3-byte array: the flags octet, then the PCP, DEI and VID values
+    /// (values whose flag is clear are encoded as zero)
     pub fn marshal(&self) -> [u8; 3] {
-        let mut bytes = [0u8; 3];
-
-        // Byte 0: PCP (3 bits) | DEI (1 bit) | VID[11:8] (4 bits)
-        bytes[0] = (self.pcp << 5) | ((self.dei as u8) << 4) | ((self.vid >> 8) as u8 & 0x0F);
-
-        // Byte 1: VID[7:0]
-        bytes[1] = (self.vid & 0xFF) as u8;
+        let mut flags = 0;
+        if self.pcp.is_some() {
+            flags |= PCP;
+        }
+        if self.dei.is_some() {
+            flags |= DEI;
+        }
+        if self.vid.is_some() {
+            flags |= VID;
+        }
 
-        // Byte 2: Spare (0)
-        bytes[2] = 0;
+        let pcp = self.pcp.unwrap_or(0) & 0x07;
+        let dei = self.dei.unwrap_or(false) as u8;
+        let vid = self.vid.unwrap_or(0);
 
-        bytes
+        [
+            flags,
+            ((vid >> 8) as u8 & 0x0F) << 4 | dei << 3 | pcp,
+            (vid & 0xFF) as u8,
+        ]
     }
 
     /// Unmarshal C-TAG from bytes
@@ -157,14 +191,10 @@ impl CTag {
             ));
         }
 
-        // Extract PCP (bits 7-5 of byte 0)
-        let pcp = (data[0] >> 5) & 0x07;
-
-        // Extract DEI (bit 4 of byte 0)
-        let dei = (data[0] & 0x10) != 0;
-
-        // Extract VID (bits 3-0 of byte 0 + byte 1)
-        let vid = (((data[0] & 0x0F) as u16) << 8) | (data[1] as u16);
+        let flags = data[0];
+        let pcp = (flags & PCP != 0).then_some(data[1] & 0x07);
+        let dei = (flags & DEI != 0).then_some(data[1] & 0x08 != 0);
+        let vid = (flags & VID != 0).then_some(((data[1] >> 4) as u16) << 8 | data[2] as u16);
 
         Ok(CTag { pcp, dei, vid })
     }
@@ -192,14 +222,14 @@ mod tests {
     #[test]
     fn test_ctag_new_valid() {
         let ctag = CTag::new(0, false, 0).unwrap();
-        assert_eq!(ctag.priority(), 0);
-        assert!(!ctag.dei());
-        assert_eq!(ctag.vid(), 0);
+        assert_eq!(ctag.priority(), Some(0));
+        assert_eq!(ctag.dei(), Some(false));
+        assert_eq!(ctag.vid(), Some(0));
 
         let ctag2 = CTag::new(7, true, 4095).unwrap();
-        assert_eq!(ctag2.priority(), 7);
-        assert!(ctag2.dei());
-        assert_eq!(ctag2.vid(), 4095);
+        assert_eq!(ctag2.priority(), Some(7));
+        assert_eq!(ctag2.dei(), Some(true));
+        assert_eq!(ctag2.vid(), Some(4095));
     }
 
     #[test]
@@ -216,6 +246,9 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, PfcpError::InvalidValue { .. }));
+
+        assert!(CTag::from_fields(None, None, Some(4096)).is_err());
+        assert!(CTag::from_fields(Some(8), None, None).is_err());
     }
 
     #[test]
@@ -224,31 +257,47 @@ mod tests {
         let bytes = ctag.marshal();
         assert_eq!(bytes.len(), 3);
 
-        // Verify PCP (bits 7-5) = 5 (101)
-        // Verify DEI (bit 4) = 1
-        // Verify VID high nibble (bits 3-0) = 3 (from 1000 = 0x3E8)
-        // Expected byte 0: 101 1 0011 = 0xB3
-        assert_eq!(bytes[0], 0xB3);
+        // PCP, DEI and VID flags all set
+        assert_eq!(bytes[0], 0x07);
 
-        // Verify VID low byte = 0xE8
-        assert_eq!(bytes[1], 0xE8);
+        // VID high nibble 3 (from 1000 = 0x3E8) | DEI 1 | PCP 5 (101)
+        // Expected byte: 0011 1 101 = 0x3D
+        assert_eq!(bytes[1], 0x3D);
 
-        // Spare byte
-        assert_eq!(bytes[2], 0x00);
+        // VID low byte
+        assert_eq!(bytes[2], 0xE8);
     }
 
     #[test]
     fn test_ctag_unmarshal_valid() {
-        let data = [0xB3, 0xE8, 0x00]; // PCP=5, DEI=1, VID=1000
+        let data = [0x07, 0x3D, 0xE8]; // PCP=5, DEI=1, VID=1000
         let ctag = CTag::unmarshal(&data).unwrap();
-        assert_eq!(ctag.priority(), 5);
-        assert!(ctag.dei());
-        assert_eq!(ctag.vid(), 1000);
+        assert_eq!(ctag.priority(), Some(5));
+        assert_eq!(ctag.dei(), Some(true));
+        assert_eq!(ctag.vid(), Some(1000));
+    }
+
+    #[test]
+    fn test_ctag_flags_select_fields() {
+        // No flags: no field is used, whatever the value octets hold
+        let ctag = CTag::unmarshal(&[0x00, 0x3D, 0xE8]).unwrap();
+        assert_eq!(ctag, CTag::from_fields(None, None, None).unwrap());
+        assert_eq!(ctag.marshal(), [0x00, 0x00, 0x00]);
+
+        // VID only
+        let ctag = CTag::from_fields(None, None, Some(100)).unwrap();
+        assert_eq!(ctag.marshal(), [0x04, 0x00, 0x64]);
+        assert_eq!(CTag::unmarshal(&ctag.marshal()).unwrap(), ctag);
+
+        // PCP and DEI only
+        let ctag = CTag::from_fields(Some(6), Some(true), None).unwrap();
+        assert_eq!(ctag.marshal(), [0x03, 0x0E, 0x00]);
+        assert_eq!(CTag::unmarshal(&ctag.marshal()).unwrap(), ctag);
     }
 
     #[test]
     fn test_ctag_unmarshal_short() {
-        let data = [0xB3, 0xE8];
+        let data = [0x07, 0x3D];
         let result = CTag::unmarshal(&data);
         assert!(result.is_err());
     }
@@ -299,28 +348,28 @@ mod tests {
     fn test_ctag_scenarios() {
         // Scenario 1: Default VLAN (VID 1, no priority)
         let default_vlan = CTag::new(0, false, 1).unwrap();
-        assert_eq!(default_vlan.vid(), 1);
-        assert_eq!(default_vlan.priority(), 0);
+        assert_eq!(default_vlan.vid(), Some(1));
+        assert_eq!(default_vlan.priority(), Some(0));
 
         // Scenario 2: High priority voice traffic (PCP 6)
         let voice = CTag::new(6, false, 100).unwrap();
-        assert_eq!(voice.priority(), 6);
+        assert_eq!(voice.priority(), Some(6));
 
         // Scenario 3: Video traffic with DEI set
         let video = CTag::new(5, true, 200).unwrap();
-        assert_eq!(video.priority(), 5);
-        assert!(video.dei());
+        assert_eq!(video.priority(), Some(5));
+        assert_eq!(video.dei(), Some(true));
 
         // Scenario 4: Management VLAN
         let mgmt = CTag::new(7, false, 10).unwrap();
-        assert_eq!(mgmt.vid(), 10);
-        assert_eq!(mgmt.priority(), 7);
+        assert_eq!(mgmt.vid(), Some(10));
+        assert_eq!(mgmt.priority(), Some(7));
 
         // Scenario 5: Maximum values
         let max_ctag = CTag::new(7, true, 4095).unwrap();
-        assert_eq!(max_ctag.priority(), 7);
-        assert!(max_ctag.dei());
-        assert_eq!(max_ctag.vid(), 4095);
+        assert_eq!(max_ctag.priority(), Some(7));
+        assert_eq!(max_ctag.dei(), Some(true));
+        assert_eq!(max_ctag.vid(), Some(4095));
     }
 
     #[test]
@@ -76,13 +76,24 @@ pub enum CauseValue {
     /// L2TP tunnel Establishment failure – LNS not reachable
     L2tpTunnelEstablishmentFailureLnsNotReachable = 89,
 
-    /// Unknown cause value (not in spec or spare range)
-    Unknown,
+    /// Unknown cause value (not in spec or spare range), carrying the raw code
+    Unknown(u8),
 }
 
-impl From<u8> for CauseValue {
-    fn from(v: u8) -> Self {
-        match v {
+impl CauseValue {
+    /// Reports whether the cause is in the acceptance range (1-63) of
+    /// Table 8.2.1-1, including spare acceptance codes.
+    pub fn is_accepted(self) -> bool {
+        matches!(u8::from(self), 1..=63)
+    }
+
+    /// Converts a cause code, rejecting values not defined in Table 8.2.1-1.
+    ///
+    /// This is the strict counterpart of `From<u8>`, which keeps undefined
+    /// codes as [`CauseValue::Unknown`]. It is not a `TryFrom<u8>` impl
+    /// because the `From<u8>` impl already provides an infallible one.
+    pub fn try_from_code(v: u8) -> Result<Self, PfcpError> {
+        Ok(match v {
             0 => CauseValue::Reserved,
             1 => CauseValue::RequestAccepted,
             2 => CauseValue::MoreUsageReportToSend,
@@ -113,7 +124,58 @@ impl From<u8> for CauseValue {
             87 => CauseValue::L2tpTunnelEstablishmentFailureTunnelAuthFailure,
             88 => CauseValue::L2tpSessionEstablishmentFailureSessionAuthFailure,
             89 => CauseValue::L2tpTunnelEstablishmentFailureLnsNotReachable,
-            _ => CauseValue::Unknown,
+            _ => {
+                return Err(PfcpError::invalid_value(
+                    "Cause",
+                    v.to_string(),
+                    "not a cause value defined in 3GPP TS 29.244 Table 8.2.1-1",
+                ))
+            }
+        })
+    }
+}
+
+impl From<u8> for CauseValue {
+    /// Converts a cause code, mapping undefined values to [`CauseValue::Unknown`].
+    fn from(v: u8) -> Self {
+        CauseValue::try_from_code(v).unwrap_or(CauseValue::Unknown(v))
+    }
+}
+
+impl From<CauseValue> for u8 {
+    fn from(value: CauseValue) -> u8 {
+        match value {
+            CauseValue::Reserved => 0,
+            CauseValue::RequestAccepted => 1,
+            CauseValue::MoreUsageReportToSend => 2,
+            CauseValue::RequestPartiallyAccepted => 3,
+            CauseValue::RequestRejected => 64,
+            CauseValue::SessionContextNotFound => 65,
+            CauseValue::MandatoryIeMissing => 66,
+            CauseValue::ConditionalIeMissing => 67,
+            CauseValue::InvalidLength => 68,
+            CauseValue::MandatoryIeIncorrect => 69,
+            CauseValue::InvalidForwardingPolicy => 70,
+            CauseValue::InvalidFteidAllocationOption => 71,
+            CauseValue::NoEstablishedPfcpAssociation => 72,
+            CauseValue::RuleCreationModificationFailure => 73,
+            CauseValue::PfcpEntityInCongestion => 74,
+            CauseValue::NoResourcesAvailable => 75,
+            CauseValue::ServiceNotSupported => 76,
+            CauseValue::SystemFailure => 77,
+            CauseValue::RedirectionRequested => 78,
+            CauseValue::AllDynamicAddressesOccupied => 79,
+            CauseValue::UnknownPredefinedRule => 80,
+            CauseValue::UnknownApplicationId => 81,
+            CauseValue::L2tpTunnelEstablishmentFailure => 82,
+            CauseValue::L2tpSessionEstablishmentFailure => 83,
+            CauseValue::L2tpTunnelRelease => 84,
+            CauseValue::L2tpSessionRelease => 85,
+            CauseValue::PfcpSessionRestorationFailure => 86,
+            CauseValue::L2tpTunnelEstablishmentFailureTunnelAuthFailure => 87,
+            CauseValue::L2tpSessionEstablishmentFailureSessionAuthFailure => 88,
+            CauseValue::L2tpTunnelEstablishmentFailureLnsNotReachable => 89,
+            CauseValue::Unknown(v) => v,
         }
     }
 }
@@ -129,16 +191,22 @@ impl Cause {
     }
 
     pub fn marshal(&self) -> [u8; 1] {
-        [self.value as u8]
+        [u8::from(self.value)]
     }
 
+    /// Unmarshals a Cause IE.
+    ///
+    /// Undefined cause codes are kept as [`CauseValue::Unknown`], or rejected
+    /// when the `strict-ie-parsing` feature is enabled.
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
         if data.is_empty() {
             return Err(PfcpError::invalid_length("Cause", IeType::Cause, 1, 0));
         }
-        Ok(Cause {
-            value: CauseValue::from(data[0]),
-        })
+        #[cfg(feature = "strict-ie-parsing")]
+        let value = CauseValue::try_from_code(data[0])?;
+        #[cfg(not(feature = "strict-ie-parsing"))]
+        let value = CauseValue::from(data[0]);
+        Ok(Cause { value })
     }
 }
 
@@ -185,10 +253,67 @@ mod tests {
         );
 
         // Unknown/spare values
-        assert_eq!(CauseValue::from(4), CauseValue::Unknown); // Spare acceptance range
-        assert_eq!(CauseValue::from(63), CauseValue::Unknown); // Spare acceptance range
-        assert_eq!(CauseValue::from(90), CauseValue::Unknown); // Spare rejection range
-        assert_eq!(CauseValue::from(255), CauseValue::Unknown); // Spare rejection range
+        assert_eq!(CauseValue::from(4), CauseValue::Unknown(4)); // Spare acceptance range
+        assert_eq!(CauseValue::from(63), CauseValue::Unknown(63)); // Spare acceptance range
+        assert_eq!(CauseValue::from(90), CauseValue::Unknown(90)); // Spare rejection range
+        assert_eq!(CauseValue::from(200), CauseValue::Unknown(200)); // Spare rejection range
+        assert_eq!(CauseValue::from(255), CauseValue::Unknown(255)); // Spare rejection range
+    }
+
+    #[test]
+    fn test_cause_value_is_accepted() {
+        assert!(CauseValue::RequestAccepted.is_accepted());
+        assert!(CauseValue::RequestPartiallyAccepted.is_accepted());
+        assert!(CauseValue::Unknown(63).is_accepted());
+        assert!(!CauseValue::Reserved.is_accepted());
+        assert!(!CauseValue::RequestRejected.is_accepted());
+        assert!(!CauseValue::Unknown(200).is_accepted());
+    }
+
+    #[test]
+    fn test_cause_value_try_from_code() {
+        assert_eq!(
+            CauseValue::try_from_code(1).unwrap(),
+            CauseValue::RequestAccepted
+        );
+        assert_eq!(
+            CauseValue::try_from_code(77).unwrap(),
+            CauseValue::SystemFailure
+        );
+        assert!(matches!(
+            CauseValue::try_from_code(200),
+            Err(PfcpError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cause_known_values_round_trip() {
+        let known: Vec<u8> = (0..=u8::MAX)
+            .filter(|v| CauseValue::try_from_code(*v).is_ok())
+            .collect();
+        // 0-3 and 64-89
+        assert_eq!(known.len(), 30);
+
+        for code in known {
+            let cause = Cause::new(CauseValue::try_from_code(code).unwrap());
+            assert_eq!(cause.marshal(), [code]);
+            assert_eq!(Cause::unmarshal(&cause.marshal()).unwrap(), cause);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-ie-parsing"))]
+    fn test_cause_unmarshal_unknown_lenient() {
+        let cause = Cause::unmarshal(&[200]).unwrap();
+        assert_eq!(cause.value, CauseValue::Unknown(200));
+        assert_eq!(cause.marshal(), [200]);
+    }
+
+    #[test]
+    #[cfg(feature = "strict-ie-parsing")]
+    fn test_cause_unmarshal_unknown_strict() {
+        let result = Cause::unmarshal(&[200]);
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
     }
 
     #[test]
@@ -3,7 +3,7 @@
 //! CP Function Features Information Element.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::{Ie, IeType};
 use bitflags::bitflags;
 
 bitflags! {
@@ -39,6 +39,10 @@ impl CPFunctionFeatures {
         }
         Ok(CPFunctionFeatures::from_bits_truncate(data[0]))
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::CpFunctionFeatures, self.marshal().to_vec())
+    }
 }
 
 #[cfg(test)]
@@ -264,6 +264,7 @@ mod tests {
 
         assert_eq!(ie.ie_type, IeType::CpIpAddress);
         assert_eq!(ie.payload, cp_ip.marshal());
+        assert_eq!(Ie::from(cp_ip), ie);
     }
 
     #[test]
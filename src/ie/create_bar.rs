@@ -4,13 +4,18 @@
 
 use crate::error::PfcpError;
 use crate::ie::bar_id::BarId;
+use crate::ie::dl_buffering_duration::DlBufferingDuration;
+use crate::ie::downlink_data_notification_delay::DownlinkDataNotificationDelay;
 use crate::ie::suggested_buffering_packets_count::SuggestedBufferingPacketsCount;
 use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateBar {
     pub bar_id: BarId,
+    pub downlink_data_notification_delay: Option<DownlinkDataNotificationDelay>,
     pub suggested_buffering_packets_count: Option<SuggestedBufferingPacketsCount>,
+    pub dl_buffering_duration: Option<DlBufferingDuration>,
 }
 
 impl CreateBar {
@@ -20,23 +25,38 @@ impl CreateBar {
     ) -> Self {
         CreateBar {
             bar_id,
+            downlink_data_notification_delay: None,
             suggested_buffering_packets_count,
+            dl_buffering_duration: None,
         }
     }
 
+    /// Returns a builder for constructing Create BAR instances.
+    pub fn builder(bar_id: BarId) -> CreateBarBuilder {
+        CreateBarBuilder::new(bar_id)
+    }
+
     pub fn marshal(&self) -> Vec<u8> {
         let mut ies = vec![self.bar_id.to_ie()];
 
+        if let Some(delay) = &self.downlink_data_notification_delay {
+            ies.push(delay.to_ie());
+        }
         if let Some(sbpc) = &self.suggested_buffering_packets_count {
             ies.push(sbpc.to_ie());
         }
+        if let Some(duration) = &self.dl_buffering_duration {
+            ies.push(duration.to_ie());
+        }
 
         marshal_ies(&ies)
     }
 
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut bar_id = None;
+        let mut downlink_data_notification_delay = None;
         let mut suggested_buffering_packets_count = None;
+        let mut dl_buffering_duration = None;
 
         for ie_result in IeIterator::new(payload) {
             let ie = ie_result?;
@@ -44,10 +64,17 @@ impl CreateBar {
                 IeType::BarId => {
                     bar_id = Some(BarId::unmarshal(&ie.payload)?);
                 }
-                IeType::DlBufferingSuggestedPacketCount => {
+                IeType::DownlinkDataNotificationDelay => {
+                    downlink_data_notification_delay =
+                        Some(DownlinkDataNotificationDelay::unmarshal(&ie.payload)?);
+                }
+                IeType::SuggestedBufferingPacketsCount => {
                     suggested_buffering_packets_count =
                         Some(SuggestedBufferingPacketsCount::unmarshal(&ie.payload)?);
                 }
+                IeType::DlBufferingDuration => {
+                    dl_buffering_duration = Some(DlBufferingDuration::unmarshal(&ie.payload)?);
+                }
                 _ => (),
             }
         }
@@ -56,7 +83,9 @@ impl CreateBar {
             bar_id: bar_id.ok_or_else(|| {
                 PfcpError::missing_ie_in_grouped(IeType::BarId, IeType::CreateBar)
             })?,
+            downlink_data_notification_delay,
             suggested_buffering_packets_count,
+            dl_buffering_duration,
         })
     }
 
@@ -65,9 +94,86 @@ impl CreateBar {
     }
 }
 
+/// Builder for Create BAR Information Elements.
+///
+/// BAR ID is the only mandatory field, so building cannot fail.
+/// [`Default`] yields a placeholder builder with BAR ID 0; it is intended
+/// for testing.
+///
+/// # Examples
+///
+/// ```rust
+/// use rs_pfcp::ie::bar_id::BarId;
+/// use rs_pfcp::ie::create_bar::CreateBarBuilder;
+///
+/// let bar = CreateBarBuilder::new(BarId::new(1))
+///     .downlink_data_notification_delay(20)
+///     .suggested_buffering_packets_count(10)
+///     .build();
+///
+/// assert_eq!(bar.bar_id, BarId::new(1));
+/// assert!(bar.dl_buffering_duration.is_none());
+/// ```
+#[derive(Debug)]
+pub struct CreateBarBuilder {
+    bar_id: BarId,
+    downlink_data_notification_delay: Option<DownlinkDataNotificationDelay>,
+    suggested_buffering_packets_count: Option<SuggestedBufferingPacketsCount>,
+    dl_buffering_duration: Option<DlBufferingDuration>,
+}
+
+impl CreateBarBuilder {
+    /// Creates a new Create BAR builder with the specified BAR ID.
+    pub fn new(bar_id: BarId) -> Self {
+        CreateBarBuilder {
+            bar_id,
+            downlink_data_notification_delay: None,
+            suggested_buffering_packets_count: None,
+            dl_buffering_duration: None,
+        }
+    }
+
+    /// Sets the Downlink Data Notification Delay, in multiples of 50 ms.
+    pub fn downlink_data_notification_delay(mut self, delay: u8) -> Self {
+        self.downlink_data_notification_delay = Some(DownlinkDataNotificationDelay::new(
+            Duration::from_millis(u64::from(delay) * 50),
+        ));
+        self
+    }
+
+    /// Sets the Suggested Buffering Packets Count.
+    pub fn suggested_buffering_packets_count(mut self, count: u8) -> Self {
+        self.suggested_buffering_packets_count = Some(SuggestedBufferingPacketsCount::new(count));
+        self
+    }
+
+    /// Sets the DL Buffering Duration.
+    pub fn dl_buffering_duration(mut self, duration: DlBufferingDuration) -> Self {
+        self.dl_buffering_duration = Some(duration);
+        self
+    }
+
+    /// Builds the Create BAR.
+    pub fn build(self) -> CreateBar {
+        CreateBar {
+            bar_id: self.bar_id,
+            downlink_data_notification_delay: self.downlink_data_notification_delay,
+            suggested_buffering_packets_count: self.suggested_buffering_packets_count,
+            dl_buffering_duration: self.dl_buffering_duration,
+        }
+    }
+}
+
+impl Default for CreateBarBuilder {
+    fn default() -> Self {
+        CreateBarBuilder::new(BarId::new(0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ie::timer::{Timer, TimerUnit};
 
     #[test]
     fn test_create_bar_marshal_unmarshal() {
@@ -91,4 +197,57 @@ mod tests {
 
         assert_eq!(create_bar, unmarshaled);
     }
+
+    #[test]
+    fn test_create_bar_builder_minimal() {
+        let create_bar = CreateBarBuilder::new(BarId::new(3)).build();
+
+        assert_eq!(create_bar, CreateBar::new(BarId::new(3), None));
+        assert_eq!(CreateBar::builder(BarId::new(3)).build(), create_bar);
+    }
+
+    #[test]
+    fn test_create_bar_builder_round_trip() {
+        let duration = DlBufferingDuration::new(Timer::new(TimerUnit::OneMinute, 1).unwrap());
+        let create_bar = CreateBarBuilder::new(BarId::new(5))
+            .downlink_data_notification_delay(20)
+            .suggested_buffering_packets_count(10)
+            .dl_buffering_duration(duration)
+            .build();
+
+        assert_eq!(
+            create_bar.downlink_data_notification_delay,
+            Some(DownlinkDataNotificationDelay::new(Duration::from_millis(
+                1000
+            )))
+        );
+        assert_eq!(
+            create_bar.suggested_buffering_packets_count,
+            Some(SuggestedBufferingPacketsCount::new(10))
+        );
+        assert_eq!(create_bar.dl_buffering_duration, Some(duration));
+        assert_eq!(
+            create_bar
+                .dl_buffering_duration
+                .and_then(|d| d.as_duration()),
+            Some(Duration::from_secs(60))
+        );
+
+        let ie = create_bar.to_ie();
+        assert_eq!(ie.ie_type, IeType::CreateBar);
+        let count = IeIterator::new(&ie.payload)
+            .map(Result::unwrap)
+            .find(|child| child.ie_type == IeType::SuggestedBufferingPacketsCount)
+            .unwrap();
+        assert_eq!(count.payload, vec![10]);
+        let unmarshaled = CreateBar::unmarshal(&ie.payload).unwrap();
+        assert_eq!(unmarshaled, create_bar);
+    }
+
+    #[test]
+    fn test_create_bar_unmarshal_missing_bar_id() {
+        let payload = SuggestedBufferingPacketsCount::new(10).to_ie().marshal();
+        let result = CreateBar::unmarshal(&payload);
+        assert!(matches!(result, Err(PfcpError::MissingMandatoryIe { .. })));
+    }
 }
@@ -10,6 +10,7 @@ use crate::ie::duplicating_parameters::DuplicatingParameters;
 use crate::ie::far_id::FarId;
 use crate::ie::forwarding_parameters::ForwardingParameters;
 use crate::ie::network_instance::NetworkInstance;
+use crate::ie::redirect_information::RedirectInformation;
 use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
 
 /// Traffic direction for FAR rules
@@ -217,7 +218,10 @@ impl CreateFar {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Debug, Default)]
+///
+/// [`Default`] yields a placeholder builder (FAR ID 0 with a DROP action)
+/// whose `build()` succeeds; it is intended for testing.
+#[derive(Debug)]
 pub struct CreateFarBuilder {
     far_id: Option<FarId>,
     apply_action: Option<ApplyAction>,
@@ -233,7 +237,10 @@ impl CreateFarBuilder {
     pub fn new(far_id: FarId) -> Self {
         CreateFarBuilder {
             far_id: Some(far_id),
-            ..Default::default()
+            apply_action: None,
+            forwarding_parameters: None,
+            duplicating_parameters: None,
+            bar_id: None,
         }
     }
 
@@ -284,6 +291,44 @@ impl CreateFarBuilder {
         self
     }
 
+    /// Redirects the traffic matched by this FAR.
+    ///
+    /// Sets the Redirect Information of the forwarding parameters. If no
+    /// forwarding parameters have been set yet, they are created with the
+    /// Core destination interface, which is where redirected uplink traffic
+    /// is headed. Like [`forward_to`](Self::forward_to), this sets the FORW
+    /// action if no action is set.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_pfcp::ie::create_far::CreateFarBuilder;
+    /// use rs_pfcp::ie::far_id::FarId;
+    /// use rs_pfcp::ie::redirect_information::{RedirectAddressType, RedirectInformation};
+    ///
+    /// let far = CreateFarBuilder::new(FarId::new(1))
+    ///     .redirect_to(RedirectInformation::new(
+    ///         RedirectAddressType::Url,
+    ///         "https://captive-portal.example.com",
+    ///     )?)
+    ///     .build()?;
+    ///
+    /// let params = far.forwarding_parameters.unwrap();
+    /// assert!(params.redirect_information.is_some());
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    pub fn redirect_to(mut self, redirect_information: RedirectInformation) -> Self {
+        let forwarding_params = self.forwarding_parameters.take().unwrap_or_else(|| {
+            ForwardingParameters::new(DestinationInterface::new(Interface::Core))
+        });
+        self.forwarding_parameters =
+            Some(forwarding_params.with_redirect_information(redirect_information));
+        // Only set FORW action if no action is already set
+        if self.apply_action.is_none() {
+            self.apply_action = Some(ApplyAction::FORW);
+        }
+        self
+    }
+
     /// Adds duplicating parameters.
     pub fn duplicating_parameters(mut self, params: DuplicatingParameters) -> Self {
         self.duplicating_parameters = Some(params);
@@ -303,8 +348,8 @@ impl CreateFarBuilder {
     /// Returns an error if:
     /// - FAR ID is not set (should not happen with current API)
     /// - Apply Action is not set
-    /// - Action and parameter combinations are invalid (e.g., BUFF without BAR ID)
-    /// - FORW action without forwarding parameters
+    /// - Apply Action is empty, or more than one of DROP, FORW and BUFF is set
+    /// - BUFF action without BAR ID, unless NOCP is also set
     /// - DUPL action without duplicating parameters
     pub fn build(self) -> Result<CreateFar, PfcpError> {
         let far_id = self.far_id.ok_or(PfcpError::MissingMandatoryIe {
@@ -333,12 +378,23 @@ impl CreateFarBuilder {
 
     /// Validates that action and parameter combinations are correct.
     fn validate_action_parameters(&self, apply_action: &ApplyAction) -> Result<(), PfcpError> {
-        // Check BUFF requires BAR ID
-        if apply_action.contains(ApplyAction::BUFF) && self.bar_id.is_none() {
+        if let Some(reason) = apply_action.conflict() {
+            return Err(PfcpError::validation_error(
+                "CreateFarBuilder",
+                "apply_action",
+                reason,
+            ));
+        }
+
+        // Check BUFF requires BAR ID, unless the CP function handles buffering (NOCP)
+        if apply_action.contains(ApplyAction::BUFF)
+            && !apply_action.contains(ApplyAction::NOCP)
+            && self.bar_id.is_none()
+        {
             return Err(PfcpError::validation_error(
                 "CreateFarBuilder",
                 "bar_id",
-                "BUFF action requires BAR ID to be set",
+                "BUFF action requires BAR ID to be set unless NOCP is set",
             ));
         }
 
@@ -347,9 +403,13 @@ impl CreateFarBuilder {
             // This is valid according to spec, but unusual - could be a warning in real implementation
         }
 
-        // Check DUPL should have duplicating parameters (warning, not error)
+        // Duplicating Parameters are conditional on the DUPL action (Table 7.5.2.3-1)
         if apply_action.contains(ApplyAction::DUPL) && self.duplicating_parameters.is_none() {
-            // This is valid according to spec, but unusual - could be a warning in real implementation
+            return Err(PfcpError::validation_error(
+                "CreateFarBuilder",
+                "duplicating_parameters",
+                "DUPL action requires duplicating parameters to be set",
+            ));
         }
 
         // Check BAR ID without BUFF action (unusual but not invalid)
@@ -388,6 +448,9 @@ impl CreateFarBuilder {
     }
 
     /// Creates a FAR builder with forwarding and duplication.
+    ///
+    /// Set where the copies go with
+    /// [`duplicating_parameters`](Self::duplicating_parameters) before building.
     pub fn forward_and_duplicate(far_id: FarId, destination: Interface) -> Self {
         let dest_interface = DestinationInterface::new(destination);
         let forwarding_params = ForwardingParameters::new(dest_interface);
@@ -398,6 +461,12 @@ impl CreateFarBuilder {
     }
 }
 
+impl Default for CreateFarBuilder {
+    fn default() -> Self {
+        CreateFarBuilder::new(FarId::new(0)).action(FarAction::Drop)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -556,6 +625,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_far_builder_default() {
+        let far = CreateFarBuilder::default().build().unwrap();
+
+        assert_eq!(far.far_id, FarId::new(0));
+        assert_eq!(far.apply_action, ApplyAction::DROP);
+        assert!(far.forwarding_parameters.is_none());
+    }
+
     #[test]
     fn test_create_far_marshal_unmarshal() {
         let far_id = FarId::new(9);
@@ -654,6 +732,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_builder_validation_exclusive_actions() {
+        for apply_action in [
+            ApplyAction::BUFF | ApplyAction::FORW,
+            ApplyAction::DROP | ApplyAction::FORW,
+            ApplyAction::DROP | ApplyAction::BUFF,
+        ] {
+            let result = CreateFarBuilder::new(FarId::new(100))
+                .apply_action(apply_action)
+                .bar_id(BarId::new(1))
+                .build();
+            match result {
+                Err(PfcpError::ValidationError { field, reason, .. }) => {
+                    assert_eq!(field, "apply_action");
+                    assert!(reason.contains("mutually exclusive"));
+                }
+                other => panic!("Expected ValidationError for {apply_action:?}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_builder_validation_empty_action() {
+        let result = CreateFarBuilder::new(FarId::new(100))
+            .apply_action(ApplyAction::empty())
+            .build();
+        match result {
+            Err(PfcpError::ValidationError { field, reason, .. }) => {
+                assert_eq!(field, "apply_action");
+                assert!(reason.contains("at least one action"));
+            }
+            other => panic!("Expected ValidationError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_builder_validation_duplicate_requires_parameters() {
+        let result =
+            CreateFarBuilder::forward_and_duplicate(FarId::new(100), Interface::Core).build();
+        match result {
+            Err(PfcpError::ValidationError { field, .. }) => {
+                assert_eq!(field, "duplicating_parameters");
+            }
+            other => panic!("Expected ValidationError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_builder_validation_buffer_nocp_without_bar_id() {
+        let far = CreateFarBuilder::new(FarId::new(100))
+            .apply_action(ApplyAction::BUFF | ApplyAction::NOCP)
+            .build()
+            .unwrap();
+        assert_eq!(far.apply_action, ApplyAction::BUFF | ApplyAction::NOCP);
+        assert!(far.bar_id.is_none());
+    }
+
+    #[test]
+    fn test_builder_validation_forward_with_parameters() {
+        let far = CreateFarBuilder::new(FarId::new(100))
+            .apply_action(ApplyAction::FORW)
+            .forwarding_parameters(ForwardingParameters::new(DestinationInterface::new(
+                Interface::Access,
+            )))
+            .build()
+            .unwrap();
+        assert_eq!(far.apply_action, ApplyAction::FORW);
+        assert!(far.forwarding_parameters.is_some());
+    }
+
     #[test]
     fn test_builder_validation_buffer_with_bar_id() {
         let far_id = FarId::new(101);
@@ -753,12 +901,18 @@ mod tests {
     fn test_builder_convenience_forward_and_duplicate() {
         let far_id = FarId::new(107);
         let far = CreateFarBuilder::forward_and_duplicate(far_id, Interface::Core)
+            .duplicating_parameters(DuplicatingParameters::new(
+                DestinationInterface::new(Interface::Lcs),
+                None,
+                None,
+            ))
             .build()
             .unwrap();
 
         assert_eq!(far.far_id, far_id);
         assert_eq!(far.apply_action, ApplyAction::FORW | ApplyAction::DUPL);
         assert!(far.forwarding_parameters.is_some());
+        assert!(far.duplicating_parameters.is_some());
 
         let forwarding_params = far.forwarding_parameters.unwrap();
         assert_eq!(
@@ -771,8 +925,8 @@ mod tests {
     fn test_builder_enhanced_validation_missing_far_id() {
         // This shouldn't happen with current API, but test for completeness
         let builder = CreateFarBuilder {
-            apply_action: Some(ApplyAction::FORW),
-            ..Default::default()
+            far_id: None,
+            ..CreateFarBuilder::new(FarId::new(1)).apply_action(ApplyAction::FORW)
         };
 
         let result = builder.build();
@@ -840,4 +994,52 @@ mod tests {
         );
         assert_eq!(forwarding_params.network_instance, Some(network_instance));
     }
+
+    #[test]
+    fn test_create_far_redirect_to_round_trip() {
+        use crate::ie::redirect_information::RedirectAddressType;
+
+        let far = CreateFarBuilder::new(FarId::new(120))
+            .forward_to_network(Interface::Dn, NetworkInstance::new("internet"))
+            .redirect_to(
+                RedirectInformation::new(
+                    RedirectAddressType::Url,
+                    "https://captive-portal.example.com",
+                )
+                .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let unmarshaled = CreateFar::unmarshal(&far.marshal()).unwrap();
+        assert_eq!(unmarshaled, far);
+        assert_eq!(unmarshaled.apply_action, ApplyAction::FORW);
+
+        let params = unmarshaled.forwarding_parameters.unwrap();
+        assert_eq!(params.destination_interface.interface, Interface::Dn);
+        assert_eq!(
+            params.network_instance,
+            Some(NetworkInstance::new("internet"))
+        );
+        let redirect = params.redirect_information.unwrap();
+        assert_eq!(redirect.address_type, RedirectAddressType::Url);
+        assert_eq!(
+            redirect.server_address,
+            "https://captive-portal.example.com"
+        );
+    }
+
+    #[test]
+    fn test_create_far_redirect_to_defaults_to_core() {
+        use crate::ie::redirect_information::RedirectAddressType;
+
+        let far = CreateFarBuilder::new(FarId::new(121))
+            .redirect_to(RedirectInformation::new(RedirectAddressType::Ipv4, "192.0.2.1").unwrap())
+            .build()
+            .unwrap();
+
+        let params = far.forwarding_parameters.unwrap();
+        assert_eq!(params.destination_interface.interface, Interface::Core);
+        assert!(params.redirect_information.is_some());
+    }
 }
@@ -131,7 +131,11 @@ impl CreatePdr {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+/// Builder for Create PDR Information Elements.
+///
+/// [`Default`] yields a placeholder builder (PDR ID 0, precedence 0 and an
+/// Access PDI) whose `build()` succeeds; it is intended for testing.
+#[derive(Debug, Clone)]
 pub struct CreatePdrBuilder {
     pdr_id: Option<PdrId>,
     precedence: Option<Precedence>,
@@ -147,7 +151,13 @@ impl CreatePdrBuilder {
     pub fn new(pdr_id: PdrId) -> Self {
         CreatePdrBuilder {
             pdr_id: Some(pdr_id),
-            ..Default::default()
+            precedence: None,
+            pdi: None,
+            outer_header_removal: None,
+            far_id: None,
+            urr_id: None,
+            qer_id: None,
+            activate_predefined_rules: None,
         }
     }
 
@@ -189,6 +199,14 @@ impl CreatePdrBuilder {
         self
     }
 
+    /// Builds the Create PDR.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PfcpError::MissingMandatoryIe`] if the precedence or PDI is
+    /// missing. Because the PDI is always required, a PDR that references a
+    /// FAR always has packet detection information to match on. PDR ID 0 is
+    /// a valid rule ID per 3GPP TS 29.244 §8.2.36 and is accepted.
     pub fn build(self) -> Result<CreatePdr, PfcpError> {
         let pdr_id = self.pdr_id.ok_or(PfcpError::MissingMandatoryIe {
             ie_type: IeType::PdrId,
@@ -219,6 +237,14 @@ impl CreatePdrBuilder {
     }
 }
 
+impl Default for CreatePdrBuilder {
+    fn default() -> Self {
+        CreatePdrBuilder::new(PdrId::new(0))
+            .precedence(Precedence::new(0))
+            .pdi(Pdi::uplink_access())
+    }
+}
+
 impl CreatePdr {
     pub fn uplink_access(pdr_id: PdrId, precedence: Precedence) -> CreatePdr {
         use crate::ie::source_interface::{SourceInterface, SourceInterfaceValue};
@@ -313,6 +339,25 @@ mod tests {
         assert_eq!(create_pdr, unmarshaled);
     }
 
+    #[test]
+    fn test_create_pdr_into_ie() {
+        let create_pdr = CreatePdr::new(
+            test_pdr_id(),
+            test_precedence(),
+            test_pdi_access(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let expected = create_pdr.to_ie();
+        let ie: Ie = create_pdr.into();
+        assert_eq!(ie, expected);
+        assert_eq!(ie.ie_type, IeType::CreatePdr);
+    }
+
     // BEFORE: 32 lines of setup
     // AFTER: 10 lines using test helpers ✨
     #[test]
@@ -408,6 +453,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_pdr_builder_far_id_without_pdi() {
+        let result = CreatePdrBuilder::new(test_pdr_id())
+            .precedence(test_precedence())
+            .far_id(FarId::new(1))
+            .build();
+        assert!(matches!(
+            result,
+            Err(PfcpError::MissingMandatoryIe {
+                ie_type: IeType::Pdi,
+                parent_ie: Some(IeType::CreatePdr),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_create_pdr_builder_with_far_reference() {
+        let pdr = CreatePdrBuilder::new(test_pdr_id())
+            .precedence(test_precedence())
+            .pdi(test_pdi_access())
+            .far_id(FarId::new(7))
+            .build()
+            .unwrap();
+        assert_eq!(pdr.far_id, Some(FarId::new(7)));
+
+        let parsed = CreatePdr::unmarshal(&pdr.marshal()).unwrap();
+        assert_eq!(parsed, pdr);
+    }
+
+    #[test]
+    fn test_create_pdr_builder_accepts_pdr_id_zero() {
+        let pdr = CreatePdrBuilder::new(PdrId::new(0))
+            .precedence(test_precedence())
+            .pdi(test_pdi_access())
+            .build()
+            .unwrap();
+        assert_eq!(pdr.pdr_id, PdrId::new(0));
+    }
+
     #[test]
     fn test_create_pdr_uplink_access() {
         let pdr_id = PdrId::new(1);
@@ -3,9 +3,11 @@
 //! Create QER Information Element.
 
 use crate::error::PfcpError;
+use crate::ie::dl_flow_level_marking::DlFlowLevelMarking;
 use crate::ie::gate_status::GateStatus;
 use crate::ie::gbr::Gbr;
 use crate::ie::mbr::Mbr;
+use crate::ie::packet_rate::PacketRate;
 use crate::ie::qer_correlation_id::QerCorrelationId;
 use crate::ie::qer_id::QerId;
 use crate::ie::qfi::Qfi;
@@ -18,6 +20,9 @@ pub struct CreateQer {
     pub gate_status: Option<GateStatus>,
     pub mbr: Option<Mbr>,
     pub gbr: Option<Gbr>,
+    pub packet_rate: Option<PacketRate>,
+    /// DSCP/Traffic Class and Service Class Indicator for downlink packets.
+    pub dl_flow_level_marking: Option<DlFlowLevelMarking>,
     /// QoS Flow Identifier — maps this QER to a specific 5G QoS flow.
     /// Per 3GPP TS 29.244 Table 7.5.4.3-1, IE Type 124.
     pub qfi: Option<Qfi>,
@@ -32,6 +37,8 @@ impl CreateQer {
             gate_status: None,
             mbr: None,
             gbr: None,
+            packet_rate: None,
+            dl_flow_level_marking: None,
             qfi: None,
         }
     }
@@ -55,6 +62,12 @@ impl CreateQer {
         if let Some(gbr) = &self.gbr {
             ies.push(Ie::new(IeType::Gbr, gbr.marshal().to_vec()));
         }
+        if let Some(packet_rate) = &self.packet_rate {
+            ies.push(packet_rate.to_ie());
+        }
+        if let Some(marking) = &self.dl_flow_level_marking {
+            ies.push(marking.to_ie());
+        }
         if let Some(qfi) = &self.qfi {
             ies.push(Ie::new(IeType::Qfi, qfi.marshal().to_vec()));
         }
@@ -69,6 +82,8 @@ impl CreateQer {
         let mut gate_status = None;
         let mut mbr = None;
         let mut gbr = None;
+        let mut packet_rate = None;
+        let mut dl_flow_level_marking = None;
         let mut qfi = None;
 
         for ie_result in IeIterator::new(payload) {
@@ -89,6 +104,12 @@ impl CreateQer {
                 IeType::Gbr => {
                     gbr = Some(Gbr::unmarshal(&ie.payload)?);
                 }
+                IeType::PacketRate => {
+                    packet_rate = Some(PacketRate::unmarshal(&ie.payload)?);
+                }
+                IeType::DlFlowLevelMarking => {
+                    dl_flow_level_marking = Some(DlFlowLevelMarking::unmarshal(&ie.payload)?);
+                }
                 IeType::Qfi => {
                     qfi = Some(Qfi::unmarshal(&ie.payload)?);
                 }
@@ -107,6 +128,8 @@ impl CreateQer {
             gate_status,
             mbr,
             gbr,
+            packet_rate,
+            dl_flow_level_marking,
             qfi,
         })
     }
@@ -148,14 +171,20 @@ impl CreateQer {
 /// let open_qer = CreateQerBuilder::open_gate(QerId::new(3)).build().unwrap();
 /// let closed_qer = CreateQerBuilder::closed_gate(QerId::new(4)).build().unwrap();
 /// ```
-#[derive(Debug, Default)]
+///
+/// [`Default`] yields a placeholder builder with QER ID 0; it is intended
+/// for testing.
+#[derive(Debug)]
 pub struct CreateQerBuilder {
     qer_id: Option<QerId>,
     qer_correlation_id: Option<QerCorrelationId>,
     gate_status: Option<GateStatus>,
     mbr: Option<Mbr>,
     gbr: Option<Gbr>,
+    packet_rate: Option<PacketRate>,
+    dl_flow_level_marking: Option<DlFlowLevelMarking>,
     qfi: Option<Qfi>,
+    strict_validation: bool,
 }
 
 impl CreateQerBuilder {
@@ -165,7 +194,14 @@ impl CreateQerBuilder {
     pub fn new(qer_id: QerId) -> Self {
         CreateQerBuilder {
             qer_id: Some(qer_id),
-            ..Default::default()
+            qer_correlation_id: None,
+            gate_status: None,
+            mbr: None,
+            gbr: None,
+            packet_rate: None,
+            dl_flow_level_marking: None,
+            qfi: None,
+            strict_validation: false,
         }
     }
 
@@ -193,6 +229,19 @@ impl CreateQerBuilder {
         self
     }
 
+    /// Sets the Packet Rate for limiting packets per time unit.
+    pub fn packet_rate(mut self, packet_rate: PacketRate) -> Self {
+        self.packet_rate = Some(packet_rate);
+        self
+    }
+
+    /// Sets the DL Flow Level Marking (ToS/Traffic Class and/or Service
+    /// Class Indicator) applied to downlink packets.
+    pub fn dl_flow_level_marking(mut self, marking: DlFlowLevelMarking) -> Self {
+        self.dl_flow_level_marking = Some(marking);
+        self
+    }
+
     /// Sets both uplink and downlink rates with the same values for MBR.
     pub fn rate_limit(mut self, uplink_bps: u64, downlink_bps: u64) -> Self {
         self.mbr = Some(Mbr::new(uplink_bps, downlink_bps));
@@ -213,11 +262,24 @@ impl CreateQerBuilder {
         self
     }
 
+    /// Enables or disables strict validation.
+    ///
+    /// In strict mode, [`build`](Self::build) rejects a QER whose gate is
+    /// closed in a direction that also has a non-zero MBR, since the rate
+    /// limit can never apply. Off by default.
+    pub fn strict_validation(mut self, on: bool) -> Self {
+        self.strict_validation = on;
+        self
+    }
+
     /// Builds the Create QER with validation.
     ///
     /// # Errors
     ///
-    /// Returns an error if the QER ID is not set.
+    /// Returns an error if the QER ID is not set, if both GBR and MBR are set
+    /// and the GBR exceeds the MBR in either direction, or, with
+    /// [`strict_validation`](Self::strict_validation), if a closed gate has
+    /// a non-zero MBR in the same direction.
     pub fn build(self) -> Result<CreateQer, PfcpError> {
         let qer_id = self.qer_id.ok_or(PfcpError::MissingMandatoryIe {
             ie_type: IeType::QerId,
@@ -225,16 +287,63 @@ impl CreateQerBuilder {
             parent_ie: Some(IeType::CreateQer),
         })?;
 
+        if let Some(reason) = self.gbr_exceeding_mbr() {
+            return Err(PfcpError::validation_error(
+                "CreateQerBuilder",
+                "gbr",
+                reason,
+            ));
+        }
+
+        if self.strict_validation {
+            if let Some(reason) = self.closed_gate_with_rate_limit() {
+                return Err(PfcpError::validation_error(
+                    "CreateQerBuilder",
+                    "mbr",
+                    reason,
+                ));
+            }
+        }
+
         Ok(CreateQer {
             qer_id,
             qer_correlation_id: self.qer_correlation_id,
             gate_status: self.gate_status,
             mbr: self.mbr,
             gbr: self.gbr,
+            packet_rate: self.packet_rate,
+            dl_flow_level_marking: self.dl_flow_level_marking,
             qfi: self.qfi,
         })
     }
 
+    /// Describes a direction whose GBR exceeds its MBR, if any.
+    ///
+    /// A GBR without an MBR is not checked.
+    fn gbr_exceeding_mbr(&self) -> Option<&'static str> {
+        let (gbr, mbr) = (self.gbr.as_ref()?, self.mbr.as_ref()?);
+        if gbr.uplink > mbr.uplink {
+            return Some("GBR exceeds MBR for uplink");
+        }
+        if gbr.downlink > mbr.downlink {
+            return Some("GBR exceeds MBR for downlink");
+        }
+        None
+    }
+
+    /// Describes a closed gate that also carries a non-zero MBR, if any.
+    fn closed_gate_with_rate_limit(&self) -> Option<&'static str> {
+        use crate::ie::gate_status::GateStatusValue;
+        let (gate_status, mbr) = (self.gate_status?, self.mbr.as_ref()?);
+        if gate_status.uplink_gate == GateStatusValue::Closed && mbr.uplink > 0 {
+            return Some("uplink gate is closed but an uplink MBR is set");
+        }
+        if gate_status.downlink_gate == GateStatusValue::Closed && mbr.downlink > 0 {
+            return Some("downlink gate is closed but a downlink MBR is set");
+        }
+        None
+    }
+
     /// Creates a QER builder with open gates for both directions.
     ///
     /// This is a common pattern for allowing traffic to flow freely.
@@ -285,6 +394,12 @@ impl CreateQerBuilder {
     }
 }
 
+impl Default for CreateQerBuilder {
+    fn default() -> Self {
+        CreateQerBuilder::new(QerId::new(0))
+    }
+}
+
 impl CreateQer {
     /// Returns a builder for constructing Create QER instances.
     pub fn builder(qer_id: QerId) -> CreateQerBuilder {
@@ -370,6 +485,8 @@ mod tests {
             gate_status: Some(gate_status),
             mbr: Some(mbr),
             gbr: Some(gbr),
+            packet_rate: None,
+            dl_flow_level_marking: None,
             qfi: None,
         };
 
@@ -681,4 +798,143 @@ mod tests {
         assert_eq!(original, unmarshaled);
         assert_eq!(unmarshaled.qfi, Some(qfi));
     }
+
+    #[test]
+    fn test_packet_rate_round_trip() {
+        use crate::ie::packet_rate::{PacketRateBuilder, PacketRateTimeUnit};
+
+        let packet_rate = PacketRateBuilder::new()
+            .max_uplink(1000, PacketRateTimeUnit::Minute)
+            .max_downlink(2000, PacketRateTimeUnit::Minute)
+            .build()
+            .unwrap();
+        let original = CreateQerBuilder::open_gate(QerId::new(7))
+            .packet_rate(packet_rate)
+            .build()
+            .unwrap();
+
+        let ie = original.to_ie();
+        let unmarshaled = CreateQer::unmarshal(&ie.payload).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert_eq!(unmarshaled.packet_rate, Some(packet_rate));
+    }
+
+    #[test]
+    fn test_dl_flow_level_marking_round_trip() {
+        let marking = DlFlowLevelMarking::builder()
+            .traffic_class(0xB8)
+            .service_class_indicator(0x2000)
+            .build()
+            .unwrap();
+        let original = CreateQerBuilder::open_gate(QerId::new(3))
+            .dl_flow_level_marking(marking)
+            .build()
+            .unwrap();
+
+        let unmarshaled = CreateQer::unmarshal(&original.marshal()).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert_eq!(unmarshaled.dl_flow_level_marking, Some(marking));
+    }
+
+    #[test]
+    fn test_gbr_exceeding_mbr_rejected() {
+        let result = CreateQerBuilder::open_gate(QerId::new(4))
+            .rate_limit(1_000_000, 2_000_000)
+            .guaranteed_rate(1_000_001, 2_000_000)
+            .build();
+        assert!(matches!(
+            result,
+            Err(PfcpError::ValidationError { ref reason, .. }) if reason.contains("uplink")
+        ));
+
+        // Order of the setters does not matter
+        let result = CreateQerBuilder::open_gate(QerId::new(4))
+            .guaranteed_rate(1_000_000, 3_000_000)
+            .rate_limit(1_000_000, 2_000_000)
+            .build();
+        assert!(matches!(
+            result,
+            Err(PfcpError::ValidationError { ref reason, .. }) if reason.contains("downlink")
+        ));
+    }
+
+    #[test]
+    fn test_gbr_within_mbr_accepted() {
+        let equal = CreateQerBuilder::open_gate(QerId::new(5))
+            .rate_limit(1_000_000, 2_000_000)
+            .guaranteed_rate(1_000_000, 2_000_000)
+            .build();
+        assert!(equal.is_ok());
+
+        let below = CreateQerBuilder::open_gate(QerId::new(5))
+            .rate_limit(1_000_000, 2_000_000)
+            .guaranteed_rate(500_000, 1_000_000)
+            .build();
+        assert!(below.is_ok());
+
+        let gbr_only = CreateQerBuilder::open_gate(QerId::new(5))
+            .guaranteed_rate(5_000_000, 5_000_000)
+            .build()
+            .unwrap();
+        assert_eq!(gbr_only.mbr, None);
+        assert_eq!(gbr_only.gbr, Some(Gbr::new(5_000_000, 5_000_000)));
+    }
+
+    #[test]
+    fn test_closed_gate_with_rate_limit_lenient() {
+        // Without strict validation the conflicting QER is still built
+        let qer = CreateQerBuilder::closed_gate(QerId::new(8))
+            .rate_limit(1_000_000, 2_000_000)
+            .build()
+            .unwrap();
+        assert_eq!(qer.mbr, Some(Mbr::new(1_000_000, 2_000_000)));
+
+        let qer = CreateQerBuilder::closed_gate(QerId::new(8))
+            .rate_limit(1_000_000, 2_000_000)
+            .strict_validation(false)
+            .build();
+        assert!(qer.is_ok());
+    }
+
+    #[test]
+    fn test_closed_gate_with_rate_limit_strict() {
+        use crate::ie::gate_status::{GateStatus, GateStatusValue};
+        let uplink_closed = GateStatus::new(GateStatusValue::Open, GateStatusValue::Closed);
+        let downlink_closed = GateStatus::new(GateStatusValue::Closed, GateStatusValue::Open);
+
+        // Uplink closed, uplink MBR set
+        let result = CreateQerBuilder::new(QerId::new(9))
+            .gate_status(uplink_closed)
+            .rate_limit(1_000_000, 0)
+            .strict_validation(true)
+            .build();
+        let err = result.unwrap_err();
+        assert!(matches!(err, PfcpError::ValidationError { .. }));
+        assert!(err.to_string().contains("uplink gate is closed"));
+
+        // Downlink closed, downlink MBR set
+        let result = CreateQerBuilder::new(QerId::new(9))
+            .gate_status(downlink_closed)
+            .rate_limit(0, 2_000_000)
+            .strict_validation(true)
+            .build();
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("downlink gate is closed"));
+
+        // Rates only in the open direction are fine
+        assert!(CreateQerBuilder::new(QerId::new(9))
+            .gate_status(uplink_closed)
+            .rate_limit(0, 2_000_000)
+            .strict_validation(true)
+            .build()
+            .is_ok());
+        assert!(
+            CreateQerBuilder::with_rate_limit(QerId::new(9), 1_000_000, 2_000_000)
+                .strict_validation(true)
+                .build()
+                .is_ok()
+        );
+    }
 }
@@ -5,7 +5,12 @@
 //! Per 3GPP TS 29.244 Section 8.2.92.
 
 use crate::error::PfcpError;
+use crate::ie::ethernet_pdu_session_information::EthernetPduSessionInformation;
 use crate::ie::f_teid::Fteid;
+use crate::ie::framed_ipv6_route::FramedIpv6Route;
+use crate::ie::framed_route::FramedRoute;
+use crate::ie::framed_routing::FramedRouting;
+use crate::ie::network_instance::NetworkInstance;
 use crate::ie::ue_ip_address::UeIpAddress;
 use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
 
@@ -132,7 +137,12 @@ impl TrafficEndpointId {
 /// # Structure
 /// - Traffic Endpoint ID (mandatory) - Identifies this endpoint
 /// - Local F-TEID (optional) - Tunnel endpoint allocated by UP function
+/// - Network Instance (optional) - Network instance associated with the endpoint
 /// - UE IP Address (optional) - UE IP address for this endpoint
+/// - Ethernet PDU Session Information (optional) - Ethernet PDU session indication
+/// - Framed-Route (optional, multiple) - IPv4 routes for a framed network behind the UE
+/// - Framed-Routing (optional) - Framed routing method
+/// - Framed-IPv6-Route (optional, multiple) - IPv6 routes for a framed network behind the UE
 ///
 /// # Examples
 ///
@@ -154,8 +164,18 @@ pub struct CreateTrafficEndpoint {
     pub traffic_endpoint_id: TrafficEndpointId,
     /// Local F-TEID allocated by UP function (optional)
     pub local_f_teid: Option<Fteid>,
+    /// Network Instance for this endpoint (optional)
+    pub network_instance: Option<NetworkInstance>,
     /// UE IP Address for this endpoint (optional)
     pub ue_ip_address: Option<UeIpAddress>,
+    /// Ethernet PDU Session Information (optional)
+    pub ethernet_pdu_session_information: Option<EthernetPduSessionInformation>,
+    /// Framed-Routes (optional, multiple)
+    pub framed_routes: Vec<FramedRoute>,
+    /// Framed-Routing (optional)
+    pub framed_routing: Option<FramedRouting>,
+    /// Framed-IPv6-Routes (optional, multiple)
+    pub framed_ipv6_routes: Vec<FramedIpv6Route>,
 }
 
 impl CreateTrafficEndpoint {
@@ -176,10 +196,20 @@ impl CreateTrafficEndpoint {
         CreateTrafficEndpoint {
             traffic_endpoint_id,
             local_f_teid: None,
+            network_instance: None,
             ue_ip_address: None,
+            ethernet_pdu_session_information: None,
+            framed_routes: Vec::new(),
+            framed_routing: None,
+            framed_ipv6_routes: Vec::new(),
         }
     }
 
+    /// Returns a builder for constructing Create Traffic Endpoint instances.
+    pub fn builder(traffic_endpoint_id: TrafficEndpointId) -> CreateTrafficEndpointBuilder {
+        CreateTrafficEndpointBuilder::new(traffic_endpoint_id)
+    }
+
     /// Add a Local F-TEID to the Traffic Endpoint
     ///
     /// # Arguments
@@ -238,10 +268,26 @@ impl CreateTrafficEndpoint {
             ies.push(Ie::new(IeType::Fteid, f_teid.marshal()));
         }
 
+        if let Some(ref network_instance) = self.network_instance {
+            ies.push(network_instance.to_ie());
+        }
+
         if let Some(ref ue_ip) = self.ue_ip_address {
             ies.push(Ie::new(IeType::UeIpAddress, ue_ip.marshal()));
         }
 
+        if let Some(ref info) = self.ethernet_pdu_session_information {
+            ies.push(info.to_ie());
+        }
+
+        ies.extend(self.framed_routes.iter().map(FramedRoute::to_ie));
+
+        if let Some(ref framed_routing) = self.framed_routing {
+            ies.push(framed_routing.to_ie());
+        }
+
+        ies.extend(self.framed_ipv6_routes.iter().map(FramedIpv6Route::to_ie));
+
         marshal_ies(&ies)
     }
 
@@ -268,7 +314,12 @@ impl CreateTrafficEndpoint {
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut traffic_endpoint_id = None;
         let mut local_f_teid = None;
+        let mut network_instance = None;
         let mut ue_ip_address = None;
+        let mut ethernet_pdu_session_information = None;
+        let mut framed_routes = Vec::new();
+        let mut framed_routing = None;
+        let mut framed_ipv6_routes = Vec::new();
 
         for ie_result in IeIterator::new(payload) {
             let ie = ie_result?;
@@ -279,9 +330,25 @@ impl CreateTrafficEndpoint {
                 IeType::Fteid => {
                     local_f_teid = Some(Fteid::unmarshal(&ie.payload)?);
                 }
+                IeType::NetworkInstance => {
+                    network_instance = Some(NetworkInstance::unmarshal(&ie.payload)?);
+                }
                 IeType::UeIpAddress => {
                     ue_ip_address = Some(UeIpAddress::unmarshal(&ie.payload)?);
                 }
+                IeType::EthernetPduSessionInformation => {
+                    ethernet_pdu_session_information =
+                        Some(EthernetPduSessionInformation::unmarshal(&ie.payload)?);
+                }
+                IeType::FramedRoute => {
+                    framed_routes.push(FramedRoute::unmarshal(&ie.payload)?);
+                }
+                IeType::FramedRouting => {
+                    framed_routing = Some(FramedRouting::unmarshal(&ie.payload)?);
+                }
+                IeType::FramedIpv6Route => {
+                    framed_ipv6_routes.push(FramedIpv6Route::unmarshal(&ie.payload)?);
+                }
                 _ => (),
             }
         }
@@ -296,7 +363,12 @@ impl CreateTrafficEndpoint {
         Ok(CreateTrafficEndpoint {
             traffic_endpoint_id,
             local_f_teid,
+            network_instance,
             ue_ip_address,
+            ethernet_pdu_session_information,
+            framed_routes,
+            framed_routing,
+            framed_ipv6_routes,
         })
     }
 
@@ -317,6 +389,109 @@ impl CreateTrafficEndpoint {
     }
 }
 
+/// Builder for Create Traffic Endpoint Information Elements.
+///
+/// Traffic Endpoint ID is the only mandatory field, so building cannot fail.
+///
+/// # Examples
+///
+/// ```rust
+/// use rs_pfcp::ie::create_traffic_endpoint::{CreateTrafficEndpointBuilder, TrafficEndpointId};
+/// use rs_pfcp::ie::f_teid::Fteid;
+/// use std::net::Ipv4Addr;
+///
+/// let create_te = CreateTrafficEndpointBuilder::new(TrafficEndpointId::new(1))
+///     .local_f_teid(Fteid::ipv4(0x12345678, Ipv4Addr::new(10, 0, 0, 1)))
+///     .network_instance("internet")
+///     .framed_route("192.0.2.0/24 0.0.0.0 1")
+///     .build();
+///
+/// assert_eq!(create_te.framed_routes.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct CreateTrafficEndpointBuilder {
+    traffic_endpoint_id: TrafficEndpointId,
+    local_f_teid: Option<Fteid>,
+    network_instance: Option<NetworkInstance>,
+    ue_ip_address: Option<UeIpAddress>,
+    ethernet_pdu_session_information: Option<EthernetPduSessionInformation>,
+    framed_routes: Vec<FramedRoute>,
+    framed_routing: Option<FramedRouting>,
+    framed_ipv6_routes: Vec<FramedIpv6Route>,
+}
+
+impl CreateTrafficEndpointBuilder {
+    /// Creates a new Create Traffic Endpoint builder with the specified Traffic Endpoint ID.
+    pub fn new(traffic_endpoint_id: TrafficEndpointId) -> Self {
+        CreateTrafficEndpointBuilder {
+            traffic_endpoint_id,
+            local_f_teid: None,
+            network_instance: None,
+            ue_ip_address: None,
+            ethernet_pdu_session_information: None,
+            framed_routes: Vec::new(),
+            framed_routing: None,
+            framed_ipv6_routes: Vec::new(),
+        }
+    }
+
+    /// Sets the Local F-TEID.
+    pub fn local_f_teid(mut self, f_teid: Fteid) -> Self {
+        self.local_f_teid = Some(f_teid);
+        self
+    }
+
+    /// Sets the Network Instance.
+    pub fn network_instance(mut self, instance: &str) -> Self {
+        self.network_instance = Some(NetworkInstance::new(instance));
+        self
+    }
+
+    /// Sets the UE IP Address.
+    pub fn ue_ip_address(mut self, ue_ip: UeIpAddress) -> Self {
+        self.ue_ip_address = Some(ue_ip);
+        self
+    }
+
+    /// Sets the Ethernet PDU Session Information.
+    pub fn ethernet_pdu_session_information(mut self, info: EthernetPduSessionInformation) -> Self {
+        self.ethernet_pdu_session_information = Some(info);
+        self
+    }
+
+    /// Adds a Framed-Route; may be called multiple times.
+    pub fn framed_route(mut self, route: &str) -> Self {
+        self.framed_routes.push(FramedRoute::new(route));
+        self
+    }
+
+    /// Sets the Framed-Routing method.
+    pub fn framed_routing(mut self, routing: u32) -> Self {
+        self.framed_routing = Some(FramedRouting::new(routing));
+        self
+    }
+
+    /// Adds a Framed-IPv6-Route; may be called multiple times.
+    pub fn framed_ipv6_route(mut self, route: &str) -> Self {
+        self.framed_ipv6_routes.push(FramedIpv6Route::new(route));
+        self
+    }
+
+    /// Builds the Create Traffic Endpoint.
+    pub fn build(self) -> CreateTrafficEndpoint {
+        CreateTrafficEndpoint {
+            traffic_endpoint_id: self.traffic_endpoint_id,
+            local_f_teid: self.local_f_teid,
+            network_instance: self.network_instance,
+            ue_ip_address: self.ue_ip_address,
+            ethernet_pdu_session_information: self.ethernet_pdu_session_information,
+            framed_routes: self.framed_routes,
+            framed_routing: self.framed_routing,
+            framed_ipv6_routes: self.framed_ipv6_routes,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -411,4 +586,60 @@ mod tests {
         let result = CreateTrafficEndpoint::unmarshal(&[0xFF]);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_create_traffic_endpoint_builder_minimal() {
+        let create_te = CreateTrafficEndpoint::builder(TrafficEndpointId::new(4)).build();
+        assert_eq!(
+            create_te,
+            CreateTrafficEndpoint::new(TrafficEndpointId::new(4))
+        );
+
+        // Only the Traffic Endpoint ID IE is encoded
+        assert_eq!(create_te.marshal(), vec![0x00, 0x83, 0x00, 0x01, 0x04]);
+    }
+
+    #[test]
+    fn test_create_traffic_endpoint_builder_full_round_trip() {
+        let create_te = CreateTrafficEndpointBuilder::new(TrafficEndpointId::new(9))
+            .local_f_teid(Fteid::ipv4(0x0A0B0C0D, Ipv4Addr::new(192, 0, 2, 10)))
+            .network_instance("internet")
+            .ue_ip_address(UeIpAddress::new(Some(Ipv4Addr::new(10, 45, 0, 1)), None))
+            .ethernet_pdu_session_information(EthernetPduSessionInformation::with_ethernet_header())
+            .framed_route("198.51.100.0/24 0.0.0.0 1")
+            .framed_route("203.0.113.0/24 0.0.0.0 1")
+            .framed_routing(1)
+            .framed_ipv6_route("2001:db8:1::/48 :: 1")
+            .build();
+
+        let ie = create_te.to_ie();
+        assert_eq!(ie.ie_type, IeType::CreateTrafficEndpoint);
+        let unmarshaled = CreateTrafficEndpoint::unmarshal(&ie.payload).unwrap();
+
+        assert_eq!(unmarshaled, create_te);
+        assert_eq!(
+            unmarshaled.network_instance,
+            Some(NetworkInstance::new("internet"))
+        );
+        assert_eq!(unmarshaled.framed_routes.len(), 2);
+        assert_eq!(unmarshaled.framed_routing, Some(FramedRouting::new(1)));
+        assert_eq!(unmarshaled.framed_ipv6_routes.len(), 1);
+        assert!(unmarshaled
+            .ethernet_pdu_session_information
+            .unwrap()
+            .has_ethernet_header());
+    }
+
+    #[test]
+    fn test_create_traffic_endpoint_unmarshal_missing_id() {
+        let payload = NetworkInstance::new("internet").to_ie().marshal();
+        let result = CreateTrafficEndpoint::unmarshal(&payload);
+        assert!(matches!(
+            result,
+            Err(PfcpError::MissingMandatoryIe {
+                ie_type: IeType::TrafficEndpointId,
+                ..
+            })
+        ));
+    }
 }
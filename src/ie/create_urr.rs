@@ -2,9 +2,11 @@
 
 use crate::error::PfcpError;
 use crate::ie::{
+    aggregated_urr_id::AggregatedUrrId, aggregated_urrs::AggregatedUrrs,
     inactivity_detection_time::InactivityDetectionTime, marshal_ies,
-    measurement_method::MeasurementMethod, measurement_period::MeasurementPeriod,
-    monitoring_time::MonitoringTime, reporting_triggers::ReportingTriggers,
+    measurement_information::MeasurementInformation, measurement_method::MeasurementMethod,
+    measurement_period::MeasurementPeriod, monitoring_time::MonitoringTime, multiplier::Multiplier,
+    quota_holding_time::QuotaHoldingTime, reporting_triggers::ReportingTriggers,
     subsequent_time_threshold::SubsequentTimeThreshold,
     subsequent_volume_threshold::SubsequentVolumeThreshold, time_quota::TimeQuota,
     time_threshold::TimeThreshold, urr_id::UrrId, volume_quota::VolumeQuota,
@@ -29,9 +31,18 @@ pub struct CreateUrr {
     /// Hard time limit — UPF terminates sessions after quota is exhausted.
     /// Per 3GPP TS 29.244 Table 7.5.2.6-1, IE Type 74.
     pub time_quota: Option<TimeQuota>,
+    /// Time after which a quota is reported if no packets were received for it.
+    /// Per 3GPP TS 29.244 Table 7.5.2.4-1, IE Type 71.
+    pub quota_holding_time: Option<QuotaHoldingTime>,
     /// Periodic measurement reporting interval in seconds.
     /// Per 3GPP TS 29.244 Table 7.5.2.6-1, IE Type 64.
     pub measurement_period: Option<MeasurementPeriod>,
+    /// Aggregated URRs whose usage this URR aggregates, each with its Multiplier.
+    /// Per 3GPP TS 29.244 Table 7.5.2.4-1, IE Type 118.
+    pub aggregated_urrs: Vec<AggregatedUrrs>,
+    /// Measurement control flags (MBQE, INAM, RADI, ...).
+    /// Per 3GPP TS 29.244 Table 7.5.2.4-1, IE Type 100.
+    pub measurement_information: Option<MeasurementInformation>,
 }
 
 impl CreateUrr {
@@ -60,7 +71,10 @@ impl CreateUrr {
             inactivity_detection_time,
             volume_quota: None,
             time_quota: None,
+            quota_holding_time: None,
             measurement_period: None,
+            aggregated_urrs: Vec::new(),
+            measurement_information: None,
         }
     }
 
@@ -81,13 +95,13 @@ impl CreateUrr {
             ies.push(Ie::new(IeType::MonitoringTime, mt.marshal().to_vec()));
         }
         if let Some(vt) = &self.volume_threshold {
-            ies.push(Ie::new(IeType::VolumeThreshold, vt.marshal()));
+            ies.push(vt.to_ie());
         }
         if let Some(tt) = &self.time_threshold {
             ies.push(Ie::new(IeType::TimeThreshold, tt.marshal().to_vec()));
         }
         if let Some(svt) = &self.subsequent_volume_threshold {
-            ies.push(Ie::new(IeType::SubsequentVolumeThreshold, svt.marshal()));
+            ies.push(svt.to_ie());
         }
         if let Some(stt) = &self.subsequent_time_threshold {
             ies.push(Ie::new(
@@ -111,9 +125,18 @@ impl CreateUrr {
         if let Some(tq) = &self.time_quota {
             ies.push(Ie::new(IeType::TimeQuota, tq.marshal()));
         }
+        if let Some(qht) = &self.quota_holding_time {
+            ies.push(qht.to_ie());
+        }
         if let Some(mp) = &self.measurement_period {
             ies.push(Ie::new(IeType::MeasurementPeriod, mp.marshal().to_vec()));
         }
+        for au in &self.aggregated_urrs {
+            ies.push(au.to_ie());
+        }
+        if let Some(mi) = &self.measurement_information {
+            ies.push(mi.to_ie());
+        }
 
         marshal_ies(&ies)
     }
@@ -131,7 +154,10 @@ impl CreateUrr {
         let mut inactivity_detection_time = None;
         let mut volume_quota = None;
         let mut time_quota = None;
+        let mut quota_holding_time = None;
         let mut measurement_period = None;
+        let mut aggregated_urrs = Vec::new();
+        let mut measurement_information = None;
 
         for ie_result in IeIterator::new(payload) {
             let ie = ie_result?;
@@ -172,9 +198,18 @@ impl CreateUrr {
                 IeType::TimeQuota => {
                     time_quota = Some(TimeQuota::unmarshal(&ie.payload)?);
                 }
+                IeType::QuotaHoldingTime => {
+                    quota_holding_time = Some(QuotaHoldingTime::unmarshal(&ie.payload)?);
+                }
                 IeType::MeasurementPeriod => {
                     measurement_period = Some(MeasurementPeriod::unmarshal(&ie.payload)?);
                 }
+                IeType::AggregatedUrrs => {
+                    aggregated_urrs.push(AggregatedUrrs::unmarshal(&ie.payload)?);
+                }
+                IeType::MeasurementInformation => {
+                    measurement_information = Some(MeasurementInformation::unmarshal(&ie.payload)?);
+                }
                 _ => (),
             }
         }
@@ -200,7 +235,10 @@ impl CreateUrr {
             inactivity_detection_time,
             volume_quota,
             time_quota,
+            quota_holding_time,
             measurement_period,
+            aggregated_urrs,
+            measurement_information,
         })
     }
 
@@ -257,7 +295,10 @@ pub struct CreateUrrBuilder {
     inactivity_detection_time: Option<InactivityDetectionTime>,
     volume_quota: Option<VolumeQuota>,
     time_quota: Option<TimeQuota>,
+    quota_holding_time: Option<QuotaHoldingTime>,
     measurement_period: Option<MeasurementPeriod>,
+    aggregated_urrs: Vec<AggregatedUrrs>,
+    measurement_information: Option<MeasurementInformation>,
 }
 
 impl CreateUrrBuilder {
@@ -307,27 +348,21 @@ impl CreateUrrBuilder {
     ///
     /// Creates a volume threshold for total traffic (uplink + downlink).
     pub fn volume_threshold_bytes(mut self, bytes: u64) -> Self {
-        self.volume_threshold = Some(VolumeThreshold::new(
-            true,  // total volume
-            false, // not uplink only
-            false, // not downlink only
-            Some(bytes),
-            None,
-            None,
-        ));
+        self.volume_threshold = Some(VolumeThreshold {
+            total: Some(bytes),
+            uplink: None,
+            downlink: None,
+        });
         self
     }
 
     /// Convenience method to set volume threshold for uplink and downlink separately.
     pub fn volume_threshold_uplink_downlink(mut self, uplink: u64, downlink: u64) -> Self {
-        self.volume_threshold = Some(VolumeThreshold::new(
-            false, // not total
-            true,  // uplink
-            true,  // downlink
-            None,
-            Some(uplink),
-            Some(downlink),
-        ));
+        self.volume_threshold = Some(VolumeThreshold {
+            total: None,
+            uplink: Some(uplink),
+            downlink: Some(downlink),
+        });
         self
     }
 
@@ -355,14 +390,11 @@ impl CreateUrrBuilder {
 
     /// Convenience method to set subsequent volume threshold in bytes.
     pub fn subsequent_volume_threshold_bytes(mut self, bytes: u64) -> Self {
-        self.subsequent_volume_threshold = Some(SubsequentVolumeThreshold::new(
-            true,  // total volume
-            false, // not uplink only
-            false, // not downlink only
-            Some(bytes),
-            None,
-            None,
-        ));
+        self.subsequent_volume_threshold = Some(SubsequentVolumeThreshold {
+            total: Some(bytes),
+            uplink: None,
+            downlink: None,
+        });
         self
     }
 
@@ -422,6 +454,16 @@ impl CreateUrrBuilder {
         self
     }
 
+    /// Sets the quota holding time — how long the UPF waits without traffic
+    /// before reporting a quota.
+    ///
+    /// Per 3GPP TS 29.244 Table 7.5.2.4-1, IE Type 71. Only meaningful
+    /// together with a volume or time quota.
+    pub fn quota_holding_time(mut self, qht: QuotaHoldingTime) -> Self {
+        self.quota_holding_time = Some(qht);
+        self
+    }
+
     /// Sets the measurement period for periodic reporting in seconds.
     ///
     /// Per 3GPP TS 29.244 Table 7.5.2.6-1, IE Type 64.
@@ -436,6 +478,32 @@ impl CreateUrrBuilder {
         self
     }
 
+    /// Adds an Aggregated URRs entry.
+    ///
+    /// Per 3GPP TS 29.244 Table 7.5.2.4-1, IE Type 118. May be called
+    /// multiple times to aggregate several URRs.
+    pub fn aggregated_urrs(mut self, aggregated_urrs: AggregatedUrrs) -> Self {
+        self.aggregated_urrs.push(aggregated_urrs);
+        self
+    }
+
+    /// Convenience method: aggregate the URR identified by `aggregated_urr_id`,
+    /// scaling its usage by `multiplier`.
+    ///
+    /// A Multiplier is only meaningful inside Aggregated URRs, so it is always
+    /// paired with the ID of the URR it applies to.
+    pub fn multiplier(self, aggregated_urr_id: AggregatedUrrId, multiplier: Multiplier) -> Self {
+        self.aggregated_urrs(AggregatedUrrs::new(aggregated_urr_id, multiplier))
+    }
+
+    /// Sets the measurement information flags.
+    ///
+    /// Per 3GPP TS 29.244 Table 7.5.2.4-1, IE Type 100.
+    pub fn measurement_information(mut self, info: MeasurementInformation) -> Self {
+        self.measurement_information = Some(info);
+        self
+    }
+
     /// Builds the Create URR IE with comprehensive validation.
     ///
     /// # Errors
@@ -447,6 +515,8 @@ impl CreateUrrBuilder {
     ///   - Duration measurement enabled but no time threshold set
     ///   - Volume threshold set but volume measurement disabled
     ///   - Time threshold set but duration measurement disabled
+    /// - The periodic reporting trigger is set without a measurement period
+    /// - A quota holding time is set without a volume or time quota
     pub fn build(self) -> Result<CreateUrr, PfcpError> {
         // Validate required fields first (without consuming)
         self.urr_id.as_ref().ok_or(PfcpError::MissingMandatoryIe {
@@ -464,13 +534,34 @@ impl CreateUrrBuilder {
                     parent_ie: Some(IeType::CreateUrr),
                 })?;
 
-        self.reporting_triggers
-            .as_ref()
-            .ok_or(PfcpError::MissingMandatoryIe {
-                ie_type: IeType::ReportingTriggers,
-                message_type: None,
-                parent_ie: Some(IeType::CreateUrr),
-            })?;
+        let reporting_triggers =
+            self.reporting_triggers
+                .as_ref()
+                .ok_or(PfcpError::MissingMandatoryIe {
+                    ie_type: IeType::ReportingTriggers,
+                    message_type: None,
+                    parent_ie: Some(IeType::CreateUrr),
+                })?;
+
+        // Periodic reporting needs a period to report on
+        if reporting_triggers.periodic && self.measurement_period.is_none() {
+            return Err(PfcpError::validation_error(
+                "CreateUrrBuilder",
+                "measurement_period",
+                "Periodic reporting trigger set but no measurement period configured",
+            ));
+        }
+
+        if self.quota_holding_time.is_some()
+            && self.volume_quota.is_none()
+            && self.time_quota.is_none()
+        {
+            return Err(PfcpError::validation_error(
+                "CreateUrrBuilder",
+                "quota_holding_time",
+                "Quota holding time set but no volume or time quota configured",
+            ));
+        }
 
         // Validate measurement method and threshold consistency
         self.validate_measurement_thresholds(measurement_method)?;
@@ -488,7 +579,10 @@ impl CreateUrrBuilder {
             inactivity_detection_time: self.inactivity_detection_time,
             volume_quota: self.volume_quota,
             time_quota: self.time_quota,
+            quota_holding_time: self.quota_holding_time,
             measurement_period: self.measurement_period,
+            aggregated_urrs: self.aggregated_urrs,
+            measurement_information: self.measurement_information,
         })
     }
 
@@ -582,10 +676,10 @@ mod tests {
             .as_secs();
         let monitoring_time =
             MonitoringTime::new(SystemTime::UNIX_EPOCH + Duration::from_secs(now_secs));
-        let volume_threshold = VolumeThreshold::new(true, true, false, Some(1000), Some(500), None);
+        let volume_threshold = VolumeThreshold::new(Some(1000), Some(500), None).unwrap();
         let time_threshold = TimeThreshold::new(3600);
         let subsequent_volume_threshold =
-            SubsequentVolumeThreshold::new(false, true, true, None, Some(200), Some(300));
+            SubsequentVolumeThreshold::new(None, Some(200), Some(300)).unwrap();
         let subsequent_time_threshold = SubsequentTimeThreshold::new(1800);
         let inactivity_detection_time = InactivityDetectionTime::new(60);
 
@@ -631,7 +725,7 @@ mod tests {
 
         assert!(urr.volume_threshold.is_some());
         let vt = urr.volume_threshold.unwrap();
-        assert_eq!(vt.total_volume, Some(1_000_000_000));
+        assert_eq!(vt.total, Some(1_000_000_000));
     }
 
     #[test]
@@ -688,9 +782,9 @@ mod tests {
             .unwrap();
 
         let vt = urr.volume_threshold.unwrap();
-        assert_eq!(vt.uplink_volume, Some(500_000_000));
-        assert_eq!(vt.downlink_volume, Some(1_000_000_000));
-        assert_eq!(vt.total_volume, None);
+        assert_eq!(vt.uplink, Some(500_000_000));
+        assert_eq!(vt.downlink, Some(1_000_000_000));
+        assert_eq!(vt.total, None);
     }
 
     #[test]
@@ -962,4 +1056,99 @@ mod tests {
         assert_eq!(urr, unmarshaled);
         assert_eq!(unmarshaled.measurement_period.unwrap().value, 60);
     }
+
+    #[test]
+    fn test_quota_holding_time_round_trip() {
+        let urr = CreateUrrBuilder::new(UrrId::new(1))
+            .measurement_method(MeasurementMethod::new(false, true, false)) // volume
+            .reporting_triggers(ReportingTriggers::new())
+            .volume_threshold_bytes(1_000_000)
+            .volume_quota_bytes(10_000_000)
+            .quota_holding_time(QuotaHoldingTime::from_duration(Duration::from_secs(600)).unwrap())
+            .build()
+            .unwrap();
+
+        let unmarshaled = CreateUrr::unmarshal(&urr.marshal()).unwrap();
+        assert_eq!(urr, unmarshaled);
+        assert_eq!(
+            unmarshaled.quota_holding_time.unwrap().as_duration(),
+            Duration::from_secs(600)
+        );
+    }
+
+    #[test]
+    fn test_quota_holding_time_requires_quota() {
+        let err = CreateUrrBuilder::new(UrrId::new(1))
+            .measurement_method(MeasurementMethod::new(false, true, false)) // volume
+            .reporting_triggers(ReportingTriggers::new())
+            .volume_threshold_bytes(1_000_000)
+            .quota_holding_time(QuotaHoldingTime::new(60))
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PfcpError::ValidationError { ref field, .. } if field == "quota_holding_time"
+        ));
+    }
+
+    #[test]
+    fn test_periodic_trigger_requires_measurement_period() {
+        let builder = || {
+            CreateUrrBuilder::new(UrrId::new(1))
+                .measurement_method(MeasurementMethod::new(false, false, true)) // event
+                .reporting_triggers(ReportingTriggers::new().with_periodic(true))
+        };
+
+        let err = builder().build().unwrap_err();
+        assert!(matches!(
+            err,
+            PfcpError::ValidationError { ref field, .. } if field == "measurement_period"
+        ));
+
+        let urr = builder()
+            .measurement_period(MeasurementPeriod::new(300))
+            .build()
+            .unwrap();
+        assert!(urr.reporting_triggers.periodic);
+        assert_eq!(urr.measurement_period, Some(MeasurementPeriod::new(300)));
+    }
+
+    #[test]
+    fn test_multiplier_round_trip() {
+        let urr = CreateUrrBuilder::new(UrrId::new(1))
+            .measurement_method(MeasurementMethod::new(false, false, true)) // event
+            .reporting_triggers(ReportingTriggers::new())
+            .multiplier(AggregatedUrrId::new(10), Multiplier::new(125, -2))
+            .multiplier(AggregatedUrrId::new(11), Multiplier::new(2, 0))
+            .build()
+            .unwrap();
+
+        assert_eq!(urr.aggregated_urrs.len(), 2);
+        let unmarshaled = CreateUrr::unmarshal(&urr.marshal()).unwrap();
+        assert_eq!(urr, unmarshaled);
+        assert_eq!(unmarshaled.aggregated_urrs[0].multiplier.as_f64(), 1.25);
+        assert_eq!(
+            unmarshaled.aggregated_urrs[1].aggregated_urr_id,
+            AggregatedUrrId::new(11)
+        );
+    }
+
+    #[test]
+    fn test_measurement_information_round_trip() {
+        let urr = CreateUrrBuilder::new(UrrId::new(1))
+            .measurement_method(MeasurementMethod::new(false, false, true)) // event
+            .reporting_triggers(ReportingTriggers::new())
+            .measurement_information(MeasurementInformation::new(
+                MeasurementInformation::CIAM | MeasurementInformation::RADI,
+            ))
+            .build()
+            .unwrap();
+
+        let unmarshaled = CreateUrr::unmarshal(&urr.marshal()).unwrap();
+        assert_eq!(urr, unmarshaled);
+        let mi = unmarshaled.measurement_information.unwrap();
+        assert!(mi.ciam());
+        assert!(mi.radi());
+        assert!(!mi.inam());
+    }
 }
@@ -5,7 +5,7 @@
 use crate::error::PfcpError;
 use crate::ie::f_teid::Fteid;
 use crate::ie::pdr_id::PdrId;
-use crate::ie::{Ie, IeType};
+use crate::ie::{Ie, IeIterator, IeType};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreatedPdr {
@@ -20,7 +20,7 @@ impl CreatedPdr {
 
     pub fn marshal(&self) -> Vec<u8> {
         let ies = vec![self.pdr_id.to_ie(), self.f_teid.to_ie()];
-        let capacity: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+        let capacity: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
 
         let mut data = Vec::with_capacity(capacity);
         for ie in ies {
@@ -33,15 +33,13 @@ impl CreatedPdr {
         let mut pdr_id = None;
         let mut f_teid = None;
 
-        let mut offset = 0;
-        while offset < payload.len() {
-            let ie = Ie::unmarshal(&payload[offset..])?;
+        for ie_result in IeIterator::new(payload) {
+            let ie = ie_result?;
             match ie.ie_type {
                 IeType::PdrId => pdr_id = Some(PdrId::unmarshal(&ie.payload)?),
                 IeType::Fteid => f_teid = Some(Fteid::unmarshal(&ie.payload)?),
                 _ => (),
             }
-            offset += ie.len() as usize;
         }
 
         Ok(CreatedPdr {
@@ -2,6 +2,7 @@
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
+use std::str::FromStr;
 
 /// Represents the possible values for a Destination Interface.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -92,6 +93,50 @@ impl From<Interface> for u8 {
     }
 }
 
+/// Parses the variant name case-insensitively, ignoring `-`, `_` and spaces,
+/// so `"Access"`, `"core"` and `"S5-S8"` are all accepted.
+impl FromStr for Interface {
+    type Err = PfcpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: String = s
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match name.as_str() {
+            "access" => Ok(Interface::Access),
+            "core" => Ok(Interface::Core),
+            "smf" => Ok(Interface::Smf),
+            "amf" => Ok(Interface::Amf),
+            "dn" => Ok(Interface::Dn),
+            "lcs" => Ok(Interface::Lcs),
+            "scscf" => Ok(Interface::ScScf),
+            "intersystem" => Ok(Interface::InterSystem),
+            "iu" => Ok(Interface::Iu),
+            "s1" => Ok(Interface::S1),
+            "s11" => Ok(Interface::S11),
+            "s12" => Ok(Interface::S12),
+            "s1u" => Ok(Interface::S1U),
+            "s2a" => Ok(Interface::S2a),
+            "s2b" => Ok(Interface::S2b),
+            "s4" => Ok(Interface::S4),
+            "s5s8" => Ok(Interface::S5S8),
+            "s6a" => Ok(Interface::S6a),
+            "sgi" => Ok(Interface::SGi),
+            "sm" => Ok(Interface::Sm),
+            "sn" => Ok(Interface::Sn),
+            "szn" => Ok(Interface::Szn),
+            "x2" => Ok(Interface::X2),
+            _ => Err(PfcpError::invalid_value(
+                "Destination Interface",
+                s,
+                "unknown interface name",
+            )),
+        }
+    }
+}
+
 /// Represents a Destination Interface.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DestinationInterface {
@@ -132,6 +177,14 @@ impl DestinationInterface {
     }
 }
 
+impl FromStr for DestinationInterface {
+    type Err = PfcpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(DestinationInterface::new)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,4 +207,34 @@ mod tests {
         assert!(err.to_string().contains("1"));
         assert!(err.to_string().contains("0"));
     }
+
+    #[test]
+    fn test_destination_interface_from_str() {
+        let cases = [
+            ("Access", Interface::Access),
+            ("ACCESS", Interface::Access),
+            ("Core", Interface::Core),
+            ("core", Interface::Core),
+            ("SGi", Interface::SGi),
+            ("S5-S8", Interface::S5S8),
+            ("S1-U", Interface::S1U),
+            ("Inter_System", Interface::InterSystem),
+        ];
+        for (name, interface) in cases {
+            assert_eq!(name.parse::<Interface>().unwrap(), interface);
+            assert_eq!(
+                DestinationInterface::from_str(name).unwrap(),
+                DestinationInterface::new(interface)
+            );
+        }
+    }
+
+    #[test]
+    fn test_destination_interface_from_str_unknown() {
+        for name in ["", "Unknown", "N3"] {
+            let err = name.parse::<Interface>().unwrap_err();
+            assert!(matches!(err, PfcpError::InvalidValue { .. }));
+            assert!(DestinationInterface::from_str(name).is_err());
+        }
+    }
 }
@@ -1,55 +1,132 @@
 // src/ie/dl_buffering_duration.rs
 
 //! DL Buffering Duration Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.35, the DL Buffering Duration IE indicates
+//! how long the UP function is requested to buffer downlink data packets.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::timer::Timer;
+use crate::ie::{Ie, IeType};
+use std::time::Duration;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct DlBufferingDuration {
-    pub value: u32,
-}
+/// DL Buffering Duration per 3GPP TS 29.244 §8.2.35.
+///
+/// # Wire Format
+/// - Octet 5: Timer unit (bits 8-6) | Timer value (bits 5-1), encoded as a
+///   [`Timer`] (Section 8.2.44)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::dl_buffering_duration::DlBufferingDuration;
+/// use rs_pfcp::ie::timer::{Timer, TimerUnit};
+/// use std::time::Duration;
+///
+/// let duration = DlBufferingDuration::new(Timer::new(TimerUnit::TenMinutes, 3)?);
+/// assert_eq!(duration.marshal(), [0x43]);
+/// assert_eq!(duration.as_duration(), Some(Duration::from_secs(1800)));
+///
+/// let parsed = DlBufferingDuration::unmarshal(&duration.marshal())?;
+/// assert_eq!(parsed, duration);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DlBufferingDuration(pub Timer);
 
 impl DlBufferingDuration {
-    pub fn new(value: u32) -> Self {
-        DlBufferingDuration { value }
+    pub fn new(timer: Timer) -> Self {
+        DlBufferingDuration(timer)
+    }
+
+    /// Returns the underlying Timer.
+    pub fn timer(&self) -> Timer {
+        self.0
+    }
+
+    /// Returns the buffering duration, or `None` if buffering is infinite.
+    pub fn as_duration(&self) -> Option<Duration> {
+        self.0.as_duration()
     }
 
-    pub fn marshal(&self) -> [u8; 4] {
-        self.value.to_be_bytes()
+    pub fn marshal(&self) -> [u8; 1] {
+        self.0.marshal()
     }
 
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 4 {
+        if data.is_empty() {
             return Err(PfcpError::invalid_length(
                 "DL Buffering Duration",
                 IeType::DlBufferingDuration,
-                4,
-                data.len(),
+                1,
+                0,
             ));
         }
-        Ok(DlBufferingDuration {
-            value: u32::from_be_bytes(data[0..4].try_into().unwrap()),
-        })
+        Ok(DlBufferingDuration(Timer::unmarshal(data)?))
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::DlBufferingDuration, self.marshal().to_vec())
+    }
+}
+
+impl From<Timer> for DlBufferingDuration {
+    fn from(timer: Timer) -> Self {
+        DlBufferingDuration(timer)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ie::timer::TimerUnit;
 
     #[test]
     fn test_dl_buffering_duration_marshal_unmarshal() {
-        let dbd = DlBufferingDuration::new(3600);
+        let dbd = DlBufferingDuration::new(Timer::new(TimerUnit::OneHour, 1).unwrap());
         let marshaled = dbd.marshal();
+        assert_eq!(marshaled, [0x61]);
         let unmarshaled = DlBufferingDuration::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, dbd);
+        assert_eq!(unmarshaled.as_duration(), Some(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn test_dl_buffering_duration_all_units() {
+        let cases = [
+            (TimerUnit::TwoSeconds, 0x05, 10),
+            (TimerUnit::OneMinute, 0x25, 300),
+            (TimerUnit::TenMinutes, 0x45, 3000),
+            (TimerUnit::OneHour, 0x65, 18_000),
+            (TimerUnit::TenHours, 0x85, 180_000),
+        ];
+        for (unit, byte, secs) in cases {
+            let dbd = DlBufferingDuration::new(Timer::new(unit, 5).unwrap());
+            assert_eq!(dbd.marshal(), [byte], "{unit:?}");
+            assert_eq!(
+                dbd.as_duration(),
+                Some(Duration::from_secs(secs)),
+                "{unit:?}"
+            );
+
+            let ie = dbd.to_ie();
+            assert_eq!(ie.ie_type, IeType::DlBufferingDuration);
+            assert_eq!(ie.payload, vec![byte]);
+            assert_eq!(DlBufferingDuration::unmarshal(&ie.payload).unwrap(), dbd);
+        }
+    }
+
+    #[test]
+    fn test_dl_buffering_duration_infinite() {
+        let dbd = DlBufferingDuration::from(Timer::infinite());
+        assert_eq!(dbd.marshal(), [0xE0]);
+        assert_eq!(dbd.as_duration(), None);
+        assert_eq!(dbd.timer().unit, TimerUnit::Infinite);
     }
 
     #[test]
     fn test_dl_buffering_duration_unmarshal_invalid_data() {
-        let data = [0; 3];
-        let result = DlBufferingDuration::unmarshal(&data);
+        let result = DlBufferingDuration::unmarshal(&[]);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, PfcpError::InvalidLength { .. }));
@@ -1,32 +1,47 @@
 //! DL Flow Level Marking Information Element
 //!
-//! The DL Flow Level Marking IE contains a DSCP (Differentiated Services Code Point)
-//! value for marking downlink packets to indicate QoS treatment.
-//! Per 3GPP TS 29.244 Section 8.2.66.
+//! The DL Flow Level Marking IE tells the UP function how to mark downlink
+//! packets: the ToS/Traffic Class of the inner IP header and/or the Service
+//! Class Indicator of the GTP-U header.
+//! Per 3GPP TS 29.244 Section 8.2.55.
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
 
+/// TTC flag: ToS/Traffic Class field present.
+const TTC: u8 = 0x01;
+/// SCI flag: Service Class Indicator field present.
+const SCI: u8 = 0x02;
+
 /// DL Flow Level Marking
 ///
-/// Contains DSCP value for downlink packet marking to ensure proper QoS treatment
-/// in the data network. The DSCP value is a 6-bit field used in IP packet headers.
+/// Carries the ToS/Traffic Class and/or the Service Class Indicator to apply
+/// to downlink packets.
 ///
 /// # 3GPP Reference
-/// 3GPP TS 29.244 Section 8.2.66
+/// 3GPP TS 29.244 Section 8.2.55
 ///
 /// # Structure
-/// - Octet 5: DSCP value (6 bits) left-shifted by 2
-/// - Octet 6: Spare (set to 0)
+/// - Octet 5: Flags (bit 1 = TTC, bit 2 = SCI, bits 3-8 spare)
+/// - Octets m to (m+1): ToS/Traffic Class value and mask (present if TTC = 1)
+/// - Octets p to (p+1): Service Class Indicator (present if SCI = 1)
 ///
 /// # Examples
 ///
 /// ```
 /// use rs_pfcp::ie::dl_flow_level_marking::DlFlowLevelMarking;
 ///
-/// // Create marking for expedited forwarding (EF)
+/// // Mark with expedited forwarding (EF)
 /// let marking = DlFlowLevelMarking::new(46).unwrap(); // EF DSCP value
-/// assert_eq!(marking.dscp(), 46);
+/// assert_eq!(marking.dscp(), Some(46));
+///
+/// // Traffic class and service class indicator together
+/// let marking = DlFlowLevelMarking::builder()
+///     .traffic_class(0xB8)
+///     .service_class_indicator(0x2000)
+///     .build()
+///     .unwrap();
+/// assert_eq!(marking.marshal(), [0x03, 0xB8, 0xFF, 0x20, 0x00]);
 ///
 /// // Marshal and unmarshal
 /// let bytes = marking.marshal();
@@ -35,15 +50,20 @@ use crate::ie::{Ie, IeType};
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct DlFlowLevelMarking {
-    /// DSCP value (6 bits, 0-63)
-    dscp: u8,
+    /// ToS/Traffic Class value (upper octet) and mask (lower octet)
+    tos_traffic_class: Option<u16>,
+    /// Service Class Indicator octets
+    service_class_indicator: Option<u16>,
 }
 
 impl DlFlowLevelMarking {
     /// Maximum valid DSCP value (6 bits = 0-63)
     pub const MAX_DSCP: u8 = 63;
 
-    /// Create a new DL Flow Level Marking
+    /// Create a DL Flow Level Marking setting only the DSCP
+    ///
+    /// The DSCP is carried in the upper 6 bits of the ToS/Traffic Class
+    /// octet, with a mask covering those bits.
     ///
     /// # Arguments
     /// * `dscp` - DSCP value (0-63)
@@ -57,51 +77,89 @@ impl DlFlowLevelMarking {
     ///
     /// // Create with AF41 (Assured Forwarding class 4, low drop)
     /// let marking = DlFlowLevelMarking::new(34).unwrap();
-    /// assert_eq!(marking.dscp(), 34);
+    /// assert_eq!(marking.dscp(), Some(34));
+    /// assert_eq!(marking.marshal(), [0x01, 34 << 2, 0xFC]);
     /// ```
-    pub fn new(dscp: u8) -> Result<Self, crate::error::PfcpError> {
+    pub fn new(dscp: u8) -> Result<Self, PfcpError> {
         if dscp > Self::MAX_DSCP {
-            return Err(crate::error::PfcpError::invalid_value(
+            return Err(PfcpError::invalid_value(
                 "DlFlowLevelMarking.dscp",
                 dscp.to_string(),
                 format!("DSCP value exceeds maximum {}", Self::MAX_DSCP),
             ));
         }
-        Ok(DlFlowLevelMarking { dscp })
+        Ok(Self::from_dscp(dscp))
+    }
+
+    fn from_dscp(dscp: u8) -> Self {
+        DlFlowLevelMarking {
+            tos_traffic_class: Some(u16::from_be_bytes([dscp << 2, 0xFC])),
+            service_class_indicator: None,
+        }
+    }
+
+    /// Returns a builder for setting the ToS/Traffic Class and Service Class
+    /// Indicator independently.
+    pub fn builder() -> DlFlowLevelMarkingBuilder {
+        DlFlowLevelMarkingBuilder::default()
+    }
+
+    /// Get the ToS/Traffic Class value, if present
+    pub fn traffic_class(&self) -> Option<u8> {
+        self.tos_traffic_class.map(|v| v.to_be_bytes()[0])
+    }
+
+    /// Get the ToS/Traffic Class mask, if present
+    pub fn traffic_class_mask(&self) -> Option<u8> {
+        self.tos_traffic_class.map(|v| v.to_be_bytes()[1])
+    }
+
+    /// Get the Service Class Indicator, if present
+    pub fn service_class_indicator(&self) -> Option<u16> {
+        self.service_class_indicator
     }
 
-    /// Get the DSCP value
+    /// Get the DSCP value, if a ToS/Traffic Class is present
     ///
     /// # Example
     /// ```
     /// use rs_pfcp::ie::dl_flow_level_marking::DlFlowLevelMarking;
     ///
     /// let marking = DlFlowLevelMarking::new(10).unwrap();
-    /// assert_eq!(marking.dscp(), 10);
+    /// assert_eq!(marking.dscp(), Some(10));
     /// ```
-    pub fn dscp(&self) -> u8 {
-        self.dscp
+    pub fn dscp(&self) -> Option<u8> {
+        self.traffic_class().map(|tc| tc >> 2)
     }
 
     /// Marshal DL Flow Level Marking to bytes
-    ///
-    /// # Returns
-    /// 2-byte vector with DSCP in octet 5 (left-shifted by 2)
     pub fn marshal(&self) -> Vec<u8> {
-        // DSCP occupies the upper 6 bits of octet 5
-        // Bits 7-2: DSCP value, Bits 1-0: spare (0)
-        let mut data = [0u8; 2];
-        data[0] = self.dscp << 2; // Left shift DSCP by 2 bits
-        data.to_vec()
+        let mut flags = 0;
+        if self.tos_traffic_class.is_some() {
+            flags |= TTC;
+        }
+        if self.service_class_indicator.is_some() {
+            flags |= SCI;
+        }
+
+        let mut data = Vec::with_capacity(5);
+        data.push(flags);
+        if let Some(tc) = self.tos_traffic_class {
+            data.extend_from_slice(&tc.to_be_bytes());
+        }
+        if let Some(sci) = self.service_class_indicator {
+            data.extend_from_slice(&sci.to_be_bytes());
+        }
+        data
     }
 
     /// Unmarshal DL Flow Level Marking from bytes
     ///
     /// # Arguments
-    /// * `data` - Byte slice containing DL Flow Level Marking data (must be at least 2 bytes)
+    /// * `data` - Byte slice containing DL Flow Level Marking data
     ///
     /// # Errors
-    /// Returns error if data is too short
+    /// Returns error if data is shorter than the fields announced by the flags
     ///
     /// # Example
     /// ```
@@ -113,19 +171,41 @@ impl DlFlowLevelMarking {
     /// assert_eq!(marking, parsed);
     /// ```
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 2 {
+        let Some(&flags) = data.first() else {
+            return Err(PfcpError::invalid_length(
+                "DL Flow Level Marking",
+                IeType::DlFlowLevelMarking,
+                1,
+                0,
+            ));
+        };
+
+        let expected =
+            1 + if flags & TTC != 0 { 2 } else { 0 } + if flags & SCI != 0 { 2 } else { 0 };
+        if data.len() < expected {
             return Err(PfcpError::invalid_length(
                 "DL Flow Level Marking",
                 IeType::DlFlowLevelMarking,
-                2,
+                expected,
                 data.len(),
             ));
         }
 
-        // Extract DSCP from upper 6 bits of octet 5
-        let dscp = data[0] >> 2;
-
-        Ok(DlFlowLevelMarking { dscp })
+        let mut offset = 1;
+        let mut read = |present: bool| {
+            present.then(|| {
+                let v = u16::from_be_bytes([data[offset], data[offset + 1]]);
+                offset += 2;
+                v
+            })
+        };
+        let tos_traffic_class = read(flags & TTC != 0);
+        let service_class_indicator = read(flags & SCI != 0);
+
+        Ok(DlFlowLevelMarking {
+            tos_traffic_class,
+            service_class_indicator,
+        })
     }
 
     /// Convert to generic IE
@@ -168,14 +248,70 @@ impl DlFlowLevelMarking {
 
     /// Create marking for Expedited Forwarding (voice traffic)
     pub fn expedited_forwarding() -> Self {
-        DlFlowLevelMarking { dscp: Self::EF }
+        Self::from_dscp(Self::EF)
     }
 
     /// Create marking for Best Effort
     pub fn best_effort() -> Self {
-        DlFlowLevelMarking {
-            dscp: Self::BEST_EFFORT,
+        Self::from_dscp(Self::BEST_EFFORT)
+    }
+}
+
+/// Builder for [`DlFlowLevelMarking`].
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::dl_flow_level_marking::DlFlowLevelMarking;
+///
+/// let marking = DlFlowLevelMarking::builder()
+///     .service_class_indicator(0x2000)
+///     .build()
+///     .unwrap();
+/// assert_eq!(marking.traffic_class(), None);
+/// assert_eq!(marking.service_class_indicator(), Some(0x2000));
+/// ```
+#[derive(Debug, Default)]
+pub struct DlFlowLevelMarkingBuilder {
+    tos_traffic_class: Option<u16>,
+    service_class_indicator: Option<u16>,
+}
+
+impl DlFlowLevelMarkingBuilder {
+    /// Sets the ToS/Traffic Class, replacing the whole octet (mask 0xFF).
+    pub fn traffic_class(self, tc: u8) -> Self {
+        self.tos_traffic_class(tc, 0xFF)
+    }
+
+    /// Sets the ToS/Traffic Class value and the mask of bits to apply.
+    pub fn tos_traffic_class(mut self, tc: u8, mask: u8) -> Self {
+        self.tos_traffic_class = Some(u16::from_be_bytes([tc, mask]));
+        self
+    }
+
+    /// Sets the Service Class Indicator.
+    pub fn service_class_indicator(mut self, sci: u16) -> Self {
+        self.service_class_indicator = Some(sci);
+        self
+    }
+
+    /// Builds the DL Flow Level Marking.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither field is set.
+    pub fn build(self) -> Result<DlFlowLevelMarking, PfcpError> {
+        if self.tos_traffic_class.is_none() && self.service_class_indicator.is_none() {
+            return Err(PfcpError::validation_error(
+                "DlFlowLevelMarkingBuilder",
+                "traffic_class",
+                "at least one of traffic class or service class indicator is required",
+            ));
         }
+        Ok(DlFlowLevelMarking {
+            tos_traffic_class: self.tos_traffic_class,
+            service_class_indicator: self.service_class_indicator,
+        })
     }
 }
 
@@ -186,13 +322,10 @@ mod tests {
     #[test]
     fn test_dl_flow_level_marking_new() {
         let marking = DlFlowLevelMarking::new(10).unwrap();
-        assert_eq!(marking.dscp(), 10);
-    }
-
-    #[test]
-    fn test_dl_flow_level_marking_dscp() {
-        let marking = DlFlowLevelMarking::new(46).unwrap();
-        assert_eq!(marking.dscp(), 46);
+        assert_eq!(marking.dscp(), Some(10));
+        assert_eq!(marking.traffic_class(), Some(10 << 2));
+        assert_eq!(marking.traffic_class_mask(), Some(0xFC));
+        assert_eq!(marking.service_class_indicator(), None);
     }
 
     #[test]
@@ -202,36 +335,70 @@ mod tests {
     }
 
     #[test]
-    fn test_dl_flow_level_marking_marshal() {
-        let marking = DlFlowLevelMarking::new(10).unwrap();
+    fn test_dl_flow_level_marking_ttc_only() {
+        let marking = DlFlowLevelMarking::builder()
+            .traffic_class(0xB8)
+            .build()
+            .unwrap();
         let bytes = marking.marshal();
-        assert_eq!(bytes.len(), 2);
-        assert_eq!(bytes[0], 10 << 2); // DSCP left-shifted by 2
-        assert_eq!(bytes[1], 0); // Spare byte
+        assert_eq!(bytes, vec![0x01, 0xB8, 0xFF]);
+
+        let parsed = DlFlowLevelMarking::unmarshal(&bytes).unwrap();
+        assert_eq!(parsed, marking);
+        assert_eq!(parsed.traffic_class(), Some(0xB8));
+        assert_eq!(parsed.dscp(), Some(46));
+        assert_eq!(parsed.service_class_indicator(), None);
     }
 
     #[test]
-    fn test_dl_flow_level_marking_unmarshal() {
-        let data = vec![40, 0]; // DSCP 10 (10 << 2 = 40)
-        let marking = DlFlowLevelMarking::unmarshal(&data).unwrap();
-        assert_eq!(marking.dscp(), 10);
+    fn test_dl_flow_level_marking_sci_only() {
+        let marking = DlFlowLevelMarking::builder()
+            .service_class_indicator(0x1234)
+            .build()
+            .unwrap();
+        let bytes = marking.marshal();
+        assert_eq!(bytes, vec![0x02, 0x12, 0x34]);
+
+        let parsed = DlFlowLevelMarking::unmarshal(&bytes).unwrap();
+        assert_eq!(parsed, marking);
+        assert_eq!(parsed.traffic_class(), None);
+        assert_eq!(parsed.dscp(), None);
+        assert_eq!(parsed.service_class_indicator(), Some(0x1234));
     }
 
     #[test]
-    fn test_dl_flow_level_marking_unmarshal_short() {
-        let data = vec![40]; // Only 1 byte
-        let result = DlFlowLevelMarking::unmarshal(&data);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(matches!(err, PfcpError::InvalidLength { .. }));
+    fn test_dl_flow_level_marking_ttc_and_sci() {
+        let marking = DlFlowLevelMarking::builder()
+            .tos_traffic_class(0x28, 0xFC)
+            .service_class_indicator(0x2000)
+            .build()
+            .unwrap();
+        let bytes = marking.marshal();
+        assert_eq!(bytes, vec![0x03, 0x28, 0xFC, 0x20, 0x00]);
+
+        let parsed = DlFlowLevelMarking::unmarshal(&bytes).unwrap();
+        assert_eq!(parsed, marking);
+        assert_eq!(parsed.traffic_class_mask(), Some(0xFC));
+        assert_eq!(parsed.dscp(), Some(10));
+        assert_eq!(parsed.service_class_indicator(), Some(0x2000));
     }
 
     #[test]
-    fn test_dl_flow_level_marking_round_trip() {
-        let original = DlFlowLevelMarking::new(26).unwrap();
-        let marshaled = original.marshal();
-        let unmarshaled = DlFlowLevelMarking::unmarshal(&marshaled).unwrap();
-        assert_eq!(original, unmarshaled);
+    fn test_dl_flow_level_marking_builder_requires_a_field() {
+        let result = DlFlowLevelMarking::builder().build();
+        assert!(matches!(result, Err(PfcpError::ValidationError { .. })));
+    }
+
+    #[test]
+    fn test_dl_flow_level_marking_unmarshal_short() {
+        for data in [&[][..], &[0x01, 0x28], &[0x03, 0x28, 0xFC, 0x20]] {
+            let result = DlFlowLevelMarking::unmarshal(data);
+            assert!(
+                matches!(result, Err(PfcpError::InvalidLength { .. })),
+                "{:?}",
+                data
+            );
+        }
     }
 
     #[test]
@@ -249,7 +416,7 @@ mod tests {
         let marking = DlFlowLevelMarking::new(18).unwrap();
         let ie = marking.to_ie();
         assert_eq!(ie.ie_type, IeType::DlFlowLevelMarking);
-        assert_eq!(ie.payload.len(), 2);
+        assert_eq!(ie.payload.len(), 3);
 
         // Verify IE can be unmarshaled
         let parsed = DlFlowLevelMarking::unmarshal(&ie.payload).unwrap();
@@ -268,53 +435,21 @@ mod tests {
     }
 
     #[test]
-    fn test_dl_flow_level_marking_expedited_forwarding() {
-        let marking = DlFlowLevelMarking::expedited_forwarding();
-        assert_eq!(marking.dscp(), DlFlowLevelMarking::EF);
-        assert_eq!(marking.dscp(), 46);
-    }
-
-    #[test]
-    fn test_dl_flow_level_marking_best_effort() {
-        let marking = DlFlowLevelMarking::best_effort();
-        assert_eq!(marking.dscp(), DlFlowLevelMarking::BEST_EFFORT);
-        assert_eq!(marking.dscp(), 0);
-    }
+    fn test_dl_flow_level_marking_presets() {
+        let voice = DlFlowLevelMarking::expedited_forwarding();
+        assert_eq!(voice.dscp(), Some(DlFlowLevelMarking::EF));
 
-    #[test]
-    fn test_dl_flow_level_marking_clone() {
-        let marking1 = DlFlowLevelMarking::new(10).unwrap();
-        let marking2 = marking1;
-        assert_eq!(marking1, marking2);
+        let data = DlFlowLevelMarking::best_effort();
+        assert_eq!(data.dscp(), Some(DlFlowLevelMarking::BEST_EFFORT));
     }
 
     #[test]
     fn test_dl_flow_level_marking_boundary_values() {
-        // Test minimum value
         let min = DlFlowLevelMarking::new(0).unwrap();
-        assert_eq!(min.dscp(), 0);
+        assert_eq!(min.dscp(), Some(0));
 
-        // Test maximum value
         let max = DlFlowLevelMarking::new(63).unwrap();
-        assert_eq!(max.dscp(), 63);
-    }
-
-    #[test]
-    fn test_dl_flow_level_marking_5g_scenarios() {
-        // Scenario 1: Voice call (Expedited Forwarding)
-        let voice = DlFlowLevelMarking::expedited_forwarding();
-        assert_eq!(voice.dscp(), 46);
-
-        // Scenario 2: Video streaming (AF41)
-        let video = DlFlowLevelMarking::new(DlFlowLevelMarking::AF41).unwrap();
-        assert_eq!(video.dscp(), 34);
-
-        // Scenario 3: Best effort data
-        let data = DlFlowLevelMarking::best_effort();
-        assert_eq!(data.dscp(), 0);
-
-        // Scenario 4: Premium data (AF31)
-        let premium = DlFlowLevelMarking::new(DlFlowLevelMarking::AF31).unwrap();
-        assert_eq!(premium.dscp(), 26);
+        assert_eq!(max.dscp(), Some(63));
+        assert_eq!(max.traffic_class(), Some(0xFC));
     }
 }
@@ -12,6 +12,20 @@ use crate::ie::pdr_id::PdrId;
 use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
 
 /// Downlink Data Report per 3GPP TS 29.244 §7.5.8.2-1.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::downlink_data_report::DownlinkDataReport;
+/// use rs_pfcp::ie::downlink_data_service_information::DownlinkDataServiceInformation;
+/// use rs_pfcp::ie::pdr_id::PdrId;
+///
+/// let report = DownlinkDataReport::new(PdrId::new(1))
+///     .with_downlink_data_service_information(DownlinkDataServiceInformation::new(None, Some(9)));
+/// let parsed = DownlinkDataReport::unmarshal(&report.marshal())?;
+/// assert_eq!(parsed, report);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DownlinkDataReport {
     /// PDR ID (mandatory).
@@ -34,13 +48,19 @@ impl DownlinkDataReport {
         }
     }
 
+    /// Adds a Downlink Data Service Information entry.
+    pub fn with_downlink_data_service_information(
+        mut self,
+        ddsi: DownlinkDataServiceInformation,
+    ) -> Self {
+        self.downlink_data_service_informations.push(ddsi);
+        self
+    }
+
     pub fn marshal(&self) -> Vec<u8> {
         let mut ies = vec![self.pdr_id.to_ie()];
         for ddsi in &self.downlink_data_service_informations {
-            ies.push(Ie::new(
-                IeType::DownlinkDataServiceInformation,
-                ddsi.marshal().to_vec(),
-            ));
+            ies.push(ddsi.to_ie());
         }
         if let Some(size) = &self.dl_data_packets_size {
             ies.push(size.to_ie());
@@ -107,8 +127,8 @@ mod tests {
     fn test_marshal_unmarshal_with_ddsi() {
         let mut ie = DownlinkDataReport::new(PdrId::new(2));
         ie.downlink_data_service_informations = vec![
-            DownlinkDataServiceInformation::new(true, false),
-            DownlinkDataServiceInformation::new(false, true),
+            DownlinkDataServiceInformation::new(Some(1), None),
+            DownlinkDataServiceInformation::new(None, Some(5)),
         ];
         let parsed = DownlinkDataReport::unmarshal(&ie.marshal()).unwrap();
         assert_eq!(parsed, ie);
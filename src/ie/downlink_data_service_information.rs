@@ -1,28 +1,76 @@
 // src/ie/downlink_data_service_information.rs
 
 //! Downlink Data Service Information IE and its flags.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.33, the Downlink Data Service Information IE
+//! carries the Paging Policy Indication value and/or the QFI of the buffered
+//! downlink packet that triggered a downlink data report.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::paging_policy_indicator::PagingPolicyIndicator;
+use crate::ie::{Ie, IeType};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+const PPI: u8 = 0x01;
+const QFII: u8 = 0x02;
+
+/// Downlink Data Service Information per 3GPP TS 29.244 §8.2.33.
+///
+/// The PPI and QFII flags are derived from which values are set.
+///
+/// # Wire Format
+/// - Octet 5: Spare | QFII | PPI
+/// - Paging Policy Indication value (1 byte, bits 6-1, if PPI)
+/// - QFI (1 byte, bits 6-1, if QFII)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::downlink_data_service_information::DownlinkDataServiceInformation;
+///
+/// let ddsi = DownlinkDataServiceInformation::new(Some(3), Some(9));
+/// assert_eq!(ddsi.marshal(), vec![0x03, 3, 9]);
+///
+/// let parsed = DownlinkDataServiceInformation::unmarshal(&ddsi.marshal())?;
+/// assert_eq!(parsed, ddsi);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct DownlinkDataServiceInformation {
-    pub ppi: bool,  // Paging Policy Indication
-    pub qfii: bool, // QoS Flow Identifier Indication
+    /// Paging Policy Indication value (6 bits).
+    pub ppi: Option<u8>,
+    /// QoS Flow Identifier (6 bits).
+    pub qfi: Option<u8>,
 }
 
 impl DownlinkDataServiceInformation {
-    pub fn new(ppi: bool, qfii: bool) -> Self {
-        DownlinkDataServiceInformation { ppi, qfii }
+    pub fn new(ppi: Option<u8>, qfi: Option<u8>) -> Self {
+        DownlinkDataServiceInformation { ppi, qfi }
+    }
+
+    /// Sets the Paging Policy Indication value from a [`PagingPolicyIndicator`].
+    ///
+    /// Prefer this over setting `ppi` directly: the indicator has already been
+    /// checked against the 0–7 range.
+    pub fn paging_policy_indicator(mut self, ppi: PagingPolicyIndicator) -> Self {
+        self.ppi = Some(ppi.value());
+        self
     }
 
-    pub fn marshal(&self) -> [u8; 1] {
-        let mut data = [0; 1];
-        if self.ppi {
-            data[0] |= 1;
+    pub fn marshal(&self) -> Vec<u8> {
+        let mut flags = 0;
+        if self.ppi.is_some() {
+            flags |= PPI;
+        }
+        if self.qfi.is_some() {
+            flags |= QFII;
+        }
+
+        let mut data = vec![flags];
+        if let Some(ppi) = self.ppi {
+            data.push(ppi & 0x3F);
         }
-        if self.qfii {
-            data[0] |= 1 << 1;
+        if let Some(qfi) = self.qfi {
+            data.push(qfi & 0x3F);
         }
         data
     }
@@ -36,10 +84,32 @@ impl DownlinkDataServiceInformation {
                 0,
             ));
         }
-        Ok(DownlinkDataServiceInformation {
-            ppi: data[0] & 1 != 0,
-            qfii: data[0] & (1 << 1) != 0,
-        })
+        let flags = data[0];
+
+        let mut offset = 1;
+        let mut read = |flag: u8| -> Result<Option<u8>, PfcpError> {
+            if flags & flag == 0 {
+                return Ok(None);
+            }
+            let value = *data.get(offset).ok_or_else(|| {
+                PfcpError::invalid_length(
+                    "Downlink Data Service Information",
+                    IeType::DownlinkDataServiceInformation,
+                    offset + 1,
+                    data.len(),
+                )
+            })?;
+            offset += 1;
+            Ok(Some(value & 0x3F))
+        };
+
+        let ppi = read(PPI)?;
+        let qfi = read(QFII)?;
+        Ok(DownlinkDataServiceInformation { ppi, qfi })
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::DownlinkDataServiceInformation, self.marshal())
     }
 }
 
@@ -49,20 +119,39 @@ mod tests {
 
     #[test]
     fn test_downlink_data_service_information_marshal_unmarshal() {
-        let ddsi = DownlinkDataServiceInformation::new(true, false);
+        let ddsi = DownlinkDataServiceInformation::new(Some(5), None);
         let marshaled = ddsi.marshal();
+        assert_eq!(marshaled, vec![PPI, 5]);
         let unmarshaled = DownlinkDataServiceInformation::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, ddsi);
 
-        let ddsi = DownlinkDataServiceInformation::new(false, true);
+        let ddsi = DownlinkDataServiceInformation::new(None, Some(9));
         let marshaled = ddsi.marshal();
+        assert_eq!(marshaled, vec![QFII, 9]);
         let unmarshaled = DownlinkDataServiceInformation::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, ddsi);
 
-        let ddsi = DownlinkDataServiceInformation::new(true, true);
+        let ddsi = DownlinkDataServiceInformation::new(Some(1), Some(63));
         let marshaled = ddsi.marshal();
+        assert_eq!(marshaled, vec![PPI | QFII, 1, 63]);
         let unmarshaled = DownlinkDataServiceInformation::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, ddsi);
+
+        let ddsi = DownlinkDataServiceInformation::default();
+        assert_eq!(ddsi.marshal(), vec![0]);
+        let unmarshaled = DownlinkDataServiceInformation::unmarshal(&ddsi.marshal()).unwrap();
+        assert_eq!(unmarshaled, ddsi);
+    }
+
+    #[test]
+    fn test_downlink_data_service_information_to_ie() {
+        let ddsi = DownlinkDataServiceInformation::new(None, Some(7));
+        let ie = ddsi.to_ie();
+        assert_eq!(ie.ie_type, IeType::DownlinkDataServiceInformation);
+        assert_eq!(
+            DownlinkDataServiceInformation::unmarshal(&ie.payload).unwrap(),
+            ddsi
+        );
     }
 
     #[test]
@@ -72,5 +161,18 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, PfcpError::InvalidLength { .. }));
+
+        // QFII set, but QFI octet missing
+        let result = DownlinkDataServiceInformation::unmarshal(&[PPI | QFII, 1]);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
+    }
+
+    #[test]
+    fn test_paging_policy_indicator_setter() {
+        let ppi = PagingPolicyIndicator::new(5).unwrap();
+        let ddsi = DownlinkDataServiceInformation::new(None, Some(9)).paging_policy_indicator(ppi);
+        assert_eq!(ddsi, DownlinkDataServiceInformation::new(Some(5), Some(9)));
+        assert_eq!(ddsi.marshal(), vec![0x03, 5, 9]);
+        assert!(PagingPolicyIndicator::new(8).is_err());
     }
 }
@@ -93,7 +93,7 @@ mod tests {
         let dp = DuplicatingParameters::new(
             DestinationInterface::new(Interface::Core),
             Some(TransportLevelMarking::new(0x12)),
-            Some(ForwardingPolicy::new("test-policy")),
+            Some(ForwardingPolicy::new("test-policy").unwrap()),
         );
         let marshaled = dp.marshal();
         let unmarshaled = DuplicatingParameters::unmarshal(&marshaled).unwrap();
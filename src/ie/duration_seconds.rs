@@ -0,0 +1,82 @@
+// src/ie/duration_seconds.rs
+
+//! `Duration` conversions shared by the IEs that carry a plain 32-bit count
+//! of seconds, such as Measurement Period and Quota Holding Time.
+
+use crate::error::PfcpError;
+use std::time::Duration;
+
+/// Converts a duration to the whole seconds carried by a 32-bit timer IE.
+///
+/// Any sub-second part is truncated. `field` names the IE in the error
+/// returned for durations that don't fit in 32 bits of seconds.
+pub(crate) fn seconds_from_duration(field: &str, duration: Duration) -> Result<u32, PfcpError> {
+    let secs = duration.as_secs();
+    u32::try_from(secs)
+        .map_err(|_| PfcpError::invalid_value(field, secs.to_string(), "exceeds u32::MAX seconds"))
+}
+
+/// Implements `from_duration` and `as_duration` for an IE whose `$field`
+/// is a `u32` count of seconds. `$name` names the IE in errors.
+macro_rules! impl_duration_seconds {
+    ($ty:ident, $field:ident, $name:literal) => {
+        impl $ty {
+            /// Creates the IE from a `Duration`.
+            ///
+            /// The value is carried in whole seconds, so any sub-second part
+            /// is truncated. Fails if the duration doesn't fit in 32 bits of
+            /// seconds.
+            pub fn from_duration(
+                duration: std::time::Duration,
+            ) -> Result<Self, $crate::error::PfcpError> {
+                Ok(Self {
+                    $field: $crate::ie::duration_seconds::seconds_from_duration($name, duration)?,
+                })
+            }
+
+            /// Returns the value as a `Duration`.
+            pub fn as_duration(&self) -> std::time::Duration {
+                std::time::Duration::from_secs(u64::from(self.$field))
+            }
+        }
+    };
+}
+pub(crate) use impl_duration_seconds;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ie::inactivity_detection_time::InactivityDetectionTime;
+    use crate::ie::measurement_period::MeasurementPeriod;
+    use crate::ie::quota_holding_time::QuotaHoldingTime;
+
+    #[test]
+    fn test_duration_seconds_conversions() {
+        for secs in [0u32, 1, 3600, u32::MAX] {
+            let duration = Duration::from_secs(u64::from(secs));
+
+            let period = MeasurementPeriod::from_duration(duration).unwrap();
+            assert_eq!(period, MeasurementPeriod::new(secs));
+            assert_eq!(period.as_duration(), duration);
+
+            let holding = QuotaHoldingTime::from_duration(duration).unwrap();
+            assert_eq!(holding, QuotaHoldingTime::new(secs));
+            assert_eq!(holding.as_duration(), duration);
+
+            let inactivity = InactivityDetectionTime::from_duration(duration).unwrap();
+            assert_eq!(inactivity, InactivityDetectionTime::new(secs));
+            assert_eq!(inactivity.as_duration(), duration);
+        }
+
+        // Sub-second precision is dropped
+        let period = MeasurementPeriod::from_duration(Duration::from_millis(1500)).unwrap();
+        assert_eq!(period.value, 1);
+
+        let too_long = Duration::from_secs(u64::from(u32::MAX) + 1);
+        let err = QuotaHoldingTime::from_duration(too_long).unwrap_err();
+        assert!(matches!(err, PfcpError::InvalidValue { .. }));
+        assert!(err.to_string().contains("Quota Holding Time"));
+        assert!(MeasurementPeriod::from_duration(too_long).is_err());
+        assert!(InactivityDetectionTime::from_duration(too_long).is_err());
+    }
+}
@@ -9,7 +9,7 @@
 
 use crate::error::PfcpError;
 use crate::ie::mac_addresses_detected::MacAddressesDetected;
-use crate::ie::{Ie, IeType};
+use crate::ie::{Ie, IeIterator, IeType};
 
 /// Ethernet Context Information (Grouped IE)
 ///
@@ -106,9 +106,8 @@ impl EthernetContextInformation {
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut mac_addresses_detected = Vec::new();
 
-        let mut offset = 0;
-        while offset < payload.len() {
-            let ie = Ie::unmarshal(&payload[offset..])?;
+        for ie_result in IeIterator::new(payload) {
+            let ie = ie_result?;
             match ie.ie_type {
                 IeType::MacAddressesDetected => {
                     mac_addresses_detected.push(MacAddressesDetected::unmarshal(&ie.payload)?);
@@ -117,7 +116,6 @@ impl EthernetContextInformation {
                     // Ignore unknown IEs for forward compatibility
                 }
             }
-            offset += ie.len() as usize;
         }
 
         // Validate that at least one MAC Addresses Detected IE is present (mandatory per spec)
@@ -12,7 +12,7 @@ use crate::ie::ethernet_filter_properties::EthernetFilterProperties;
 use crate::ie::ethertype::Ethertype;
 use crate::ie::mac_address::MacAddress;
 use crate::ie::s_tag::STag;
-use crate::ie::{Ie, IeType};
+use crate::ie::{Ie, IeIterator, IeType};
 
 /// Ethernet Packet Filter (Grouped IE)
 ///
@@ -111,7 +111,7 @@ impl EthernetPacketFilter {
             ies.push(stag.to_ie());
         }
 
-        let capacity: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+        let capacity: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
 
         let mut data = Vec::with_capacity(capacity);
         for ie in ies {
@@ -135,9 +135,8 @@ impl EthernetPacketFilter {
         let mut c_tag = None;
         let mut s_tag = None;
 
-        let mut offset = 0;
-        while offset < payload.len() {
-            let ie = Ie::unmarshal(&payload[offset..])?;
+        for ie_result in IeIterator::new(payload) {
+            let ie = ie_result?;
             match ie.ie_type {
                 IeType::EthernetFilterId => {
                     ethernet_filter_id = Some(EthernetFilterId::unmarshal(&ie.payload)?);
@@ -163,7 +162,6 @@ impl EthernetPacketFilter {
                     // Ignore unknown IEs for forward compatibility
                 }
             }
-            offset += ie.len() as usize;
         }
 
         Ok(EthernetPacketFilter {
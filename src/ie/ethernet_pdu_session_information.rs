@@ -123,6 +123,21 @@ impl EthernetPduSessionInformation {
         !self.ethi
     }
 
+    /// Returns the ETHI flag as carried on the wire.
+    ///
+    /// Same as [`is_untagged`](Self::is_untagged), under the spec's flag name.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_pfcp::ie::ethernet_pdu_session_information::EthernetPduSessionInformation;
+    ///
+    /// assert!(EthernetPduSessionInformation::untagged().ethi());
+    /// assert!(!EthernetPduSessionInformation::with_ethernet_header().ethi());
+    /// ```
+    pub fn ethi(&self) -> bool {
+        self.ethi
+    }
+
     /// Marshal Ethernet PDU Session Information to bytes
     ///
     /// # Returns
@@ -315,4 +330,14 @@ mod tests {
         let info3 = info1;
         assert_eq!(info1, info3);
     }
+
+    #[test]
+    fn test_ethernet_pdu_session_information_ethi_flag() {
+        for (byte, ethi) in [(0x00, false), (0x01, true), (0xFE, false), (0xFF, true)] {
+            let info = EthernetPduSessionInformation::unmarshal(&[byte]).unwrap();
+            assert_eq!(info.ethi(), ethi, "octet {:#04x}", byte);
+            // Spare bits are not carried over
+            assert_eq!(info.marshal(), [u8::from(ethi)]);
+        }
+    }
 }
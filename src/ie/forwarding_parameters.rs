@@ -5,7 +5,7 @@ use crate::ie::{
     create_traffic_endpoint::TrafficEndpointId, destination_interface::DestinationInterface,
     header_enrichment::HeaderEnrichment, marshal_ies, network_instance::NetworkInstance,
     outer_header_creation::OuterHeaderCreation, proxying::Proxying,
-    three_gpp_interface_type::ThreeGppInterfaceTypeIe,
+    redirect_information::RedirectInformation, three_gpp_interface_type::ThreeGppInterfaceTypeIe,
     transport_level_marking::TransportLevelMarking, Ie, IeIterator, IeType,
 };
 
@@ -14,6 +14,7 @@ use crate::ie::{
 pub struct ForwardingParameters {
     pub destination_interface: DestinationInterface,
     pub network_instance: Option<NetworkInstance>,
+    pub redirect_information: Option<RedirectInformation>,
     pub transport_level_marking: Option<TransportLevelMarking>,
     pub outer_header_creation: Option<OuterHeaderCreation>,
     pub traffic_endpoint_id: Option<TrafficEndpointId>,
@@ -28,6 +29,7 @@ impl ForwardingParameters {
         ForwardingParameters {
             destination_interface,
             network_instance: None,
+            redirect_information: None,
             transport_level_marking: None,
             outer_header_creation: None,
             traffic_endpoint_id: None,
@@ -43,6 +45,28 @@ impl ForwardingParameters {
         self
     }
 
+    /// Adds Redirect Information to the Forwarding Parameters.
+    ///
+    /// # Example
+    /// ```
+    /// use rs_pfcp::ie::forwarding_parameters::ForwardingParameters;
+    /// use rs_pfcp::ie::destination_interface::{DestinationInterface, Interface};
+    /// use rs_pfcp::ie::redirect_information::{RedirectAddressType, RedirectInformation};
+    ///
+    /// let params = ForwardingParameters::new(DestinationInterface::new(Interface::Core))
+    ///     .with_redirect_information(RedirectInformation::new(
+    ///         RedirectAddressType::Url,
+    ///         "http://portal.example.com",
+    ///     )?);
+    ///
+    /// assert!(params.redirect_information.is_some());
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    pub fn with_redirect_information(mut self, redirect_information: RedirectInformation) -> Self {
+        self.redirect_information = Some(redirect_information);
+        self
+    }
+
     /// Adds a Transport Level Marking to the Forwarding Parameters.
     pub fn with_transport_level_marking(
         mut self,
@@ -117,6 +141,9 @@ impl ForwardingParameters {
         if let Some(ref ni) = self.network_instance {
             ies.push(ni.to_ie());
         }
+        if let Some(ref ri) = self.redirect_information {
+            ies.push(ri.to_ie());
+        }
         if let Some(ref tlm) = self.transport_level_marking {
             ies.push(tlm.to_ie());
         }
@@ -143,6 +170,7 @@ impl ForwardingParameters {
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut destination_interface = None;
         let mut network_instance = None;
+        let mut redirect_information = None;
         let mut transport_level_marking = None;
         let mut outer_header_creation = None;
         let mut traffic_endpoint_id = None;
@@ -159,6 +187,9 @@ impl ForwardingParameters {
                 IeType::NetworkInstance => {
                     network_instance = Some(NetworkInstance::unmarshal(&ie.payload)?)
                 }
+                IeType::RedirectInformation => {
+                    redirect_information = Some(RedirectInformation::unmarshal(&ie.payload)?)
+                }
                 IeType::TransportLevelMarking => {
                     transport_level_marking = Some(TransportLevelMarking::unmarshal(&ie.payload)?)
                 }
@@ -190,6 +221,7 @@ impl ForwardingParameters {
         Ok(ForwardingParameters {
             destination_interface,
             network_instance,
+            redirect_information,
             transport_level_marking,
             outer_header_creation,
             traffic_endpoint_id,
@@ -427,4 +459,22 @@ mod tests {
 
         assert_eq!(params, unmarshaled);
     }
+
+    #[test]
+    fn test_forwarding_parameters_with_redirect_information() {
+        use crate::ie::redirect_information::{RedirectAddressType, RedirectInformation};
+
+        for ri in [
+            RedirectInformation::new(RedirectAddressType::Url, "http://portal.example.com")
+                .unwrap(),
+            RedirectInformation::new(RedirectAddressType::Ipv4, "192.0.2.10").unwrap(),
+        ] {
+            let params = ForwardingParameters::new(DestinationInterface::new(Interface::Core))
+                .with_redirect_information(ri.clone());
+
+            let unmarshaled = ForwardingParameters::unmarshal(&params.marshal()).unwrap();
+            assert_eq!(params, unmarshaled);
+            assert_eq!(unmarshaled.redirect_information, Some(ri));
+        }
+    }
 }
@@ -1,28 +1,87 @@
 // src/ie/forwarding_policy.rs
 
 //! Forwarding Policy Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.31, the Forwarding Policy IE carries a
+//! single Forwarding Policy Identifier referring to a policy preconfigured
+//! in the UP function, preceded by its length. A zero-length IE clears the
+//! policy (e.g. in an Update FAR) and decodes to an empty identifier.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::{Ie, IeType};
 
+/// Forwarding Policy per 3GPP TS 29.244 §8.2.31.
+///
+/// # Wire Format
+/// - Octet 5: Forwarding Policy Identifier Length
+/// - Octets 6 to (6+n-1): Forwarding Policy Identifier (max 255 octets)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::forwarding_policy::ForwardingPolicy;
+///
+/// let fp = ForwardingPolicy::new("fp-1")?;
+/// assert_eq!(fp.marshal(), [4, b'f', b'p', b'-', b'1']);
+/// assert_eq!(ForwardingPolicy::unmarshal(&fp.marshal())?, fp);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ForwardingPolicy {
-    pub identifier: String,
+    identifier: String,
 }
 
 impl ForwardingPolicy {
-    pub fn new(identifier: &str) -> Self {
-        ForwardingPolicy {
-            identifier: identifier.to_string(),
+    /// Longest Forwarding Policy Identifier, in octets.
+    pub const MAX_LEN: usize = 255;
+
+    /// Creates a new Forwarding Policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `identifier` is longer than 255 octets.
+    pub fn new(identifier: &str) -> Result<Self, PfcpError> {
+        if identifier.len() > Self::MAX_LEN {
+            return Err(PfcpError::invalid_value(
+                "Forwarding Policy Identifier",
+                identifier,
+                "must not exceed 255 octets",
+            ));
         }
+        Ok(ForwardingPolicy {
+            identifier: identifier.to_string(),
+        })
+    }
+
+    /// Returns the Forwarding Policy Identifier; empty if the policy is cleared.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
     }
 
     pub fn marshal(&self) -> Vec<u8> {
-        self.identifier.as_bytes().to_vec()
+        let mut data = Vec::with_capacity(1 + self.identifier.len());
+        data.push(self.identifier.len() as u8);
+        data.extend_from_slice(self.identifier.as_bytes());
+        data
     }
 
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        let identifier = String::from_utf8(data.to_vec()).map_err(|e| {
+        // A zero-length IE is allowed and clears the policy.
+        let Some((&len, rest)) = data.split_first() else {
+            return Ok(ForwardingPolicy {
+                identifier: String::new(),
+            });
+        };
+        let len = len as usize;
+        if rest.len() < len {
+            return Err(PfcpError::invalid_length(
+                "Forwarding Policy",
+                IeType::ForwardingPolicy,
+                1 + len,
+                data.len(),
+            ));
+        }
+        let identifier = String::from_utf8(rest[..len].to_vec()).map_err(|e| {
             PfcpError::encoding_error(
                 "Forwarding Policy",
                 IeType::ForwardingPolicy,
@@ -31,6 +90,10 @@ impl ForwardingPolicy {
         })?;
         Ok(ForwardingPolicy { identifier })
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::ForwardingPolicy, self.marshal())
+    }
 }
 
 #[cfg(test)]
@@ -39,15 +102,51 @@ mod tests {
 
     #[test]
     fn test_forwarding_policy_marshal_unmarshal() {
-        let fp = ForwardingPolicy::new("test-policy");
+        let fp = ForwardingPolicy::new("test-policy").unwrap();
         let marshaled = fp.marshal();
+        assert_eq!(marshaled[0], 11);
+        assert_eq!(&marshaled[1..], b"test-policy");
         let unmarshaled = ForwardingPolicy::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, fp);
     }
 
+    #[test]
+    fn test_forwarding_policy_length_limits() {
+        let longest = "a".repeat(ForwardingPolicy::MAX_LEN);
+        let fp = ForwardingPolicy::new(&longest).unwrap();
+        let ie = fp.to_ie();
+        assert_eq!(ie.ie_type, IeType::ForwardingPolicy);
+        assert_eq!(ie.payload.len(), 256);
+        assert_eq!(ie.payload[0], 255);
+        assert_eq!(ForwardingPolicy::unmarshal(&ie.payload).unwrap(), fp);
+
+        let too_long = "a".repeat(ForwardingPolicy::MAX_LEN + 1);
+        assert!(matches!(
+            ForwardingPolicy::new(&too_long),
+            Err(PfcpError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_forwarding_policy_unmarshal_empty_clears() {
+        let cleared = ForwardingPolicy::unmarshal(&[]).unwrap();
+        assert_eq!(cleared.identifier(), "");
+        assert_eq!(cleared, ForwardingPolicy::new("").unwrap());
+        assert_eq!(ForwardingPolicy::unmarshal(&[0]).unwrap(), cleared);
+    }
+
+    #[test]
+    fn test_forwarding_policy_unmarshal_truncated() {
+        // Length says 5, only 3 octets follow
+        assert!(matches!(
+            ForwardingPolicy::unmarshal(&[5, b'a', b'b', b'c']),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+    }
+
     #[test]
     fn test_forwarding_policy_invalid_utf8() {
-        let invalid_utf8 = vec![0xFF, 0xFE, 0xFD];
+        let invalid_utf8 = vec![3, 0xFF, 0xFE, 0xFD];
         let result = ForwardingPolicy::unmarshal(&invalid_utf8);
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -4,27 +4,32 @@
 //! Per 3GPP TS 29.244 Section 8.2.78.
 
 use crate::error::PfcpError;
+use crate::ie::timer::Timer;
 use crate::ie::{Ie, IeType};
+use std::time::Duration;
 
 /// Graceful Release Period
 ///
-/// Specifies the grace period (in seconds) for the release of PFCP association.
+/// Specifies the grace period for the release of PFCP association.
 /// Used during graceful shutdown of PFCP sessions.
 ///
 /// # 3GPP Reference
 /// 3GPP TS 29.244 Section 8.2.78
 ///
 /// # Structure
-/// - 2 bytes: Release period (u16, big-endian, seconds)
+/// - 1 byte: Timer unit (bits 8-6) | Timer value (bits 5-1), encoded as a
+///   [`Timer`] (Section 8.2.44)
 ///
 /// # Examples
 ///
 /// ```
 /// use rs_pfcp::ie::graceful_release_period::GracefulReleasePeriod;
+/// use rs_pfcp::ie::timer::{Timer, TimerUnit};
+/// use std::time::Duration;
 ///
-/// // Create Graceful Release Period with 60 seconds
-/// let period = GracefulReleasePeriod::new(60);
-/// assert_eq!(period.period(), 60);
+/// // Create Graceful Release Period of 2 minutes
+/// let period = GracefulReleasePeriod::new(Timer::new(TimerUnit::OneMinute, 2)?);
+/// assert_eq!(period.as_duration(), Some(Duration::from_secs(120)));
 ///
 /// // Marshal and unmarshal
 /// let bytes = period.marshal();
@@ -33,81 +38,49 @@ use crate::ie::{Ie, IeType};
 /// # Ok::<(), rs_pfcp::error::PfcpError>(())
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct GracefulReleasePeriod {
-    /// Release period in seconds (u16)
-    period: u16,
-}
+pub struct GracefulReleasePeriod(pub Timer);
 
 impl GracefulReleasePeriod {
-    /// Create a new Graceful Release Period
-    ///
-    /// # Arguments
-    /// * `period` - Release period in seconds (0-65535)
-    ///
-    /// # Example
-    /// ```
-    /// use rs_pfcp::ie::graceful_release_period::GracefulReleasePeriod;
-    ///
-    /// let period = GracefulReleasePeriod::new(120);
-    /// assert_eq!(period.period(), 120);
-    /// ```
-    pub fn new(period: u16) -> Self {
-        GracefulReleasePeriod { period }
+    /// Create a new Graceful Release Period from a Timer
+    pub fn new(timer: Timer) -> Self {
+        GracefulReleasePeriod(timer)
     }
 
-    /// Get the release period value in seconds
-    ///
-    /// # Example
-    /// ```
-    /// use rs_pfcp::ie::graceful_release_period::GracefulReleasePeriod;
-    ///
-    /// let period = GracefulReleasePeriod::new(300);
-    /// assert_eq!(period.period(), 300);
-    /// ```
-    pub fn period(&self) -> u16 {
-        self.period
+    /// Get the underlying Timer
+    pub fn timer(&self) -> Timer {
+        self.0
+    }
+
+    /// Get the release period as a duration, or `None` if it is infinite
+    pub fn as_duration(&self) -> Option<Duration> {
+        self.0.as_duration()
     }
 
     /// Marshal Graceful Release Period to bytes
     ///
     /// # Returns
-    /// 2-byte vector containing release period (big-endian)
-    pub fn marshal(&self) -> Vec<u8> {
-        self.period.to_be_bytes().to_vec()
+    /// 1-byte array containing the encoded Timer
+    pub fn marshal(&self) -> [u8; 1] {
+        self.0.marshal()
     }
 
     /// Unmarshal Graceful Release Period from bytes
     ///
     /// # Arguments
-    /// * `data` - Byte slice containing release period (must be at least 2 bytes)
+    /// * `data` - Byte slice containing the release period (must be at least 1 byte)
     ///
     /// # Errors
-    /// Returns error if data is too short
-    ///
-    /// # Example
-    /// ```
-    /// use rs_pfcp::ie::graceful_release_period::GracefulReleasePeriod;
-    ///
-    /// let period = GracefulReleasePeriod::new(180);
-    /// let bytes = period.marshal();
-    /// let parsed = GracefulReleasePeriod::unmarshal(&bytes)?;
-    /// assert_eq!(period, parsed);
-    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
-    /// ```
+    /// Returns error if data is empty
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 2 {
+        if data.is_empty() {
             return Err(PfcpError::invalid_length(
                 "Graceful Release Period",
                 IeType::GracefulReleasePeriod,
-                2,
-                data.len(),
+                1,
+                0,
             ));
         }
-
-        let bytes: [u8; 2] = data[0..2].try_into().unwrap();
-        let period = u16::from_be_bytes(bytes);
-
-        Ok(GracefulReleasePeriod { period })
+        Ok(GracefulReleasePeriod(Timer::unmarshal(data)?))
     }
 
     /// Convert to generic IE
@@ -115,115 +88,94 @@ impl GracefulReleasePeriod {
     /// # Example
     /// ```
     /// use rs_pfcp::ie::graceful_release_period::GracefulReleasePeriod;
+    /// use rs_pfcp::ie::timer::Timer;
     /// use rs_pfcp::ie::IeType;
     ///
-    /// let period = GracefulReleasePeriod::new(100);
+    /// let period = GracefulReleasePeriod::new(Timer::infinite());
     /// let ie = period.to_ie();
     /// assert_eq!(ie.ie_type, IeType::GracefulReleasePeriod);
     /// ```
     pub fn to_ie(&self) -> Ie {
-        Ie::new(IeType::GracefulReleasePeriod, self.marshal())
+        Ie::new(IeType::GracefulReleasePeriod, self.marshal().to_vec())
+    }
+}
+
+impl From<Timer> for GracefulReleasePeriod {
+    fn from(timer: Timer) -> Self {
+        GracefulReleasePeriod(timer)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ie::timer::TimerUnit;
 
     #[test]
     fn test_graceful_release_period_new() {
-        let period = GracefulReleasePeriod::new(60);
-        assert_eq!(period.period(), 60);
+        let timer = Timer::new(TimerUnit::OneMinute, 1).unwrap();
+        let period = GracefulReleasePeriod::new(timer);
+        assert_eq!(period.timer(), timer);
+        assert_eq!(period.as_duration(), Some(Duration::from_secs(60)));
     }
 
     #[test]
     fn test_graceful_release_period_marshal_unmarshal() {
-        let original = GracefulReleasePeriod::new(300);
+        let original = GracefulReleasePeriod::new(Timer::new(TimerUnit::TenMinutes, 3).unwrap());
         let bytes = original.marshal();
-        assert_eq!(bytes.len(), 2);
+        assert_eq!(bytes, [0x43]);
 
         let parsed = GracefulReleasePeriod::unmarshal(&bytes).unwrap();
         assert_eq!(original, parsed);
-        assert_eq!(parsed.period(), 300);
-    }
-
-    #[test]
-    fn test_graceful_release_period_marshal_zero() {
-        let period = GracefulReleasePeriod::new(0);
-        let bytes = period.marshal();
-        let parsed = GracefulReleasePeriod::unmarshal(&bytes).unwrap();
-
-        assert_eq!(period, parsed);
-        assert_eq!(parsed.period(), 0);
+        assert_eq!(parsed.as_duration(), Some(Duration::from_secs(1800)));
     }
 
     #[test]
-    fn test_graceful_release_period_marshal_max_value() {
-        let period = GracefulReleasePeriod::new(u16::MAX);
-        let bytes = period.marshal();
-        let parsed = GracefulReleasePeriod::unmarshal(&bytes).unwrap();
-
-        assert_eq!(period, parsed);
-        assert_eq!(parsed.period(), u16::MAX);
+    fn test_graceful_release_period_all_units() {
+        for unit in [
+            TimerUnit::TwoSeconds,
+            TimerUnit::OneMinute,
+            TimerUnit::TenMinutes,
+            TimerUnit::OneHour,
+            TimerUnit::TenHours,
+            TimerUnit::Infinite,
+        ] {
+            let original = GracefulReleasePeriod::new(Timer::new(unit, 31).unwrap());
+            let parsed = GracefulReleasePeriod::unmarshal(&original.marshal()).unwrap();
+            assert_eq!(original, parsed, "Failed for unit {unit:?}");
+        }
     }
 
     #[test]
-    fn test_graceful_release_period_unmarshal_short() {
-        let data = vec![0x00]; // Only 1 byte
-        let result = GracefulReleasePeriod::unmarshal(&data);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(matches!(err, PfcpError::InvalidLength { .. }));
+    fn test_graceful_release_period_infinite() {
+        let period = GracefulReleasePeriod::from(Timer::infinite());
+        assert_eq!(period.marshal(), [0xE0]);
+        assert_eq!(period.as_duration(), None);
     }
 
     #[test]
     fn test_graceful_release_period_unmarshal_empty() {
-        let data = vec![];
-        let result = GracefulReleasePeriod::unmarshal(&data);
+        let result = GracefulReleasePeriod::unmarshal(&[]);
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert!(matches!(err, PfcpError::InvalidLength { .. }));
+        assert!(matches!(
+            err,
+            PfcpError::InvalidLength {
+                ie_type: IeType::GracefulReleasePeriod,
+                ..
+            }
+        ));
     }
 
     #[test]
     fn test_graceful_release_period_to_ie() {
-        let period = GracefulReleasePeriod::new(500);
+        let period = GracefulReleasePeriod::new(Timer::new(TimerUnit::TwoSeconds, 15).unwrap());
         let ie = period.to_ie();
         assert_eq!(ie.ie_type, IeType::GracefulReleasePeriod);
-        assert_eq!(ie.payload.len(), 2);
+        assert_eq!(ie.payload, vec![0x0F]);
 
         // Verify IE can be unmarshaled
         let parsed = GracefulReleasePeriod::unmarshal(&ie.payload).unwrap();
         assert_eq!(period, parsed);
     }
-
-    #[test]
-    fn test_graceful_release_period_byte_order() {
-        // Verify big-endian encoding
-        let period = GracefulReleasePeriod::new(0x1234);
-        let bytes = period.marshal();
-        assert_eq!(bytes, vec![0x12, 0x34]);
-    }
-
-    #[test]
-    fn test_graceful_release_period_round_trip_various() {
-        let values = vec![1, 10, 60, 300, 3600, 65535];
-        for period_val in values {
-            let original = GracefulReleasePeriod::new(period_val);
-            let bytes = original.marshal();
-            let parsed = GracefulReleasePeriod::unmarshal(&bytes).unwrap();
-            assert_eq!(original, parsed, "Failed for period {}", period_val);
-        }
-    }
-
-    #[test]
-    fn test_graceful_release_period_5g_graceful_shutdown() {
-        // Scenario: PFCP association graceful shutdown
-        let period = GracefulReleasePeriod::new(30);
-        let bytes = period.marshal();
-        let parsed = GracefulReleasePeriod::unmarshal(&bytes).unwrap();
-
-        assert_eq!(parsed.period(), 30);
-        assert_eq!(period, parsed);
-    }
 }
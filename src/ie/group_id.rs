@@ -242,6 +242,7 @@ mod tests {
 
         assert_eq!(ie.ie_type, IeType::GroupId);
         assert_eq!(ie.payload, data);
+        assert_eq!(Ie::from(group_id), ie);
     }
 
     #[test]
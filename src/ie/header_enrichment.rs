@@ -1,12 +1,15 @@
 //! Header Enrichment IE.
 //!
-//! Specifies HTTP header or URL/URI enrichment for traffic steering
-//! and service identification in 5G networks.
+//! Per 3GPP TS 29.244 Section 8.2.67, the Header Enrichment IE names an
+//! HTTP header field that the UP function inserts into uplink traffic.
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
 
 /// Header Type for Header Enrichment
+///
+/// 3GPP TS 29.244 only defines `0` (HTTP); `1` is not standardized and is
+/// kept for peers that already use it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HeaderType {
     HttpHeaderField = 0,
@@ -36,8 +39,23 @@ impl HeaderType {
 /// Used to enrich HTTP headers or URLs for advanced traffic steering
 /// and application detection in 5G networks.
 ///
-/// # 3GPP TS 29.244 Reference
-/// - Section 8.2.98: Header Enrichment
+/// # Wire Format
+/// - Octet 5: Spare (bits 8-6) | Header Type (bits 5-1)
+/// - Octet 6: Length of Header Field Name
+/// - Header Field Name
+/// - Length of Header Field Value (1 byte)
+/// - Header Field Value
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::header_enrichment::HeaderEnrichment;
+///
+/// let he = HeaderEnrichment::http_header("X-Subscriber-ID".into(), "12345".into());
+/// assert_eq!(&he.marshal()[..2], &[0x00, 15]);
+/// assert_eq!(HeaderEnrichment::unmarshal(&he.marshal())?, he);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HeaderEnrichment {
     pub header_type: HeaderType,
@@ -95,9 +113,8 @@ impl HeaderEnrichment {
     pub fn marshal(&self) -> Vec<u8> {
         let mut data = Vec::new();
 
-        // Header Type (1 byte) + spare (1 byte)
+        // Header Type (bits 5-1, upper bits spare)
         data.push(self.header_type.to_u8());
-        data.push(0); // Spare byte
 
         // Length of Name (1 byte)
         let name_bytes = self.name.as_bytes();
@@ -126,22 +143,19 @@ impl HeaderEnrichment {
 
     /// Unmarshals bytes into a Header Enrichment IE
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
-        if payload.len() < 4 {
+        if payload.len() < 3 {
             return Err(PfcpError::invalid_length(
                 "Header Enrichment",
                 IeType::HeaderEnrichment,
-                4,
+                3,
                 payload.len(),
             ));
         }
 
         let mut offset = 0;
 
-        // Header Type (1 byte)
-        let header_type = HeaderType::from_u8(payload[offset])?;
-        offset += 1;
-
-        // Spare byte
+        // Header Type (bits 5-1)
+        let header_type = HeaderType::from_u8(payload[offset] & 0x1F)?;
         offset += 1;
 
         // Length of Name (1 byte)
@@ -282,11 +296,51 @@ mod tests {
 
     #[test]
     fn test_header_enrichment_unmarshal_invalid_utf8() {
-        let mut data = vec![0, 0, 3]; // header type, spare, name length
+        let mut data = vec![0, 3]; // header type, name length
         data.extend_from_slice(&[0xFF, 0xFE, 0xFD]); // Invalid UTF-8
         data.push(0); // value length
 
         let result = HeaderEnrichment::unmarshal(&data);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_header_enrichment_wire_format() {
+        let enrichment =
+            HeaderEnrichment::http_header("X-Subscriber-ID".to_string(), "12345".to_string());
+
+        let mut expected = vec![0x00, 15];
+        expected.extend_from_slice(b"X-Subscriber-ID");
+        expected.push(5);
+        expected.extend_from_slice(b"12345");
+
+        let ie = enrichment.to_ie();
+        assert_eq!(ie.payload, expected);
+        assert_eq!(
+            HeaderEnrichment::unmarshal(&ie.payload).unwrap(),
+            enrichment
+        );
+    }
+
+    #[test]
+    fn test_header_enrichment_unmarshal_ignores_spare_bits() {
+        let data = [0xE0, 1, b'X', 1, b'1'];
+        let enrichment = HeaderEnrichment::unmarshal(&data).unwrap();
+        assert_eq!(enrichment.header_type, HeaderType::HttpHeaderField);
+        assert_eq!(enrichment.name, "X");
+        assert_eq!(enrichment.value, "1");
+    }
+
+    #[test]
+    fn test_header_enrichment_unmarshal_truncated() {
+        assert!(matches!(
+            HeaderEnrichment::unmarshal(&[0, 0]),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+        // Name length runs past the payload
+        assert!(matches!(
+            HeaderEnrichment::unmarshal(&[0, 5, b'X', 0]),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+    }
 }
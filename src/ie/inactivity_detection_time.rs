@@ -1,9 +1,13 @@
 // src/ie/inactivity_detection_time.rs
 
 //! Inactivity Detection Time Information Element.
+//!
+//! Per 3GPP TS 29.244, the time in seconds without traffic after which the
+//! UP function stops counting time usage.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::duration_seconds::impl_duration_seconds;
+use crate::ie::{Ie, IeType};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InactivityDetectionTime {
@@ -32,8 +36,14 @@ impl InactivityDetectionTime {
             value: u32::from_be_bytes(data[0..4].try_into().unwrap()),
         })
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::InactivityDetectionTime, self.marshal().to_vec())
+    }
 }
 
+impl_duration_seconds!(InactivityDetectionTime, value, "Inactivity Detection Time");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +65,15 @@ mod tests {
         assert!(matches!(err, PfcpError::InvalidLength { .. }));
         assert!(err.to_string().contains("Inactivity Detection Time"));
     }
+
+    #[test]
+    fn test_inactivity_detection_time_to_ie() {
+        let idt = InactivityDetectionTime::new(3600);
+        let ie = Ie::from(idt);
+        assert_eq!(ie.ie_type, IeType::InactivityDetectionTime);
+        assert_eq!(
+            InactivityDetectionTime::unmarshal(&ie.payload).unwrap(),
+            idt
+        );
+    }
 }
@@ -1,10 +1,28 @@
 // src/ie/load_control_information.rs
 
 //! Load Control Information Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 7.5.3.3, the Load Control Information IE lets
+//! the UP function advertise its current load so the CP function can balance
+//! new sessions across UP functions.
 
 use crate::error::PfcpError;
-use crate::ie::{metric::Metric, sequence_number::SequenceNumber, Ie, IeType};
+use crate::ie::{metric::Metric, sequence_number::SequenceNumber, Ie, IeIterator, IeType};
 
+/// Load Control Information per 3GPP TS 29.244 Table 7.5.3.3-1.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::load_control_information::LoadControlInformation;
+/// use rs_pfcp::ie::metric::Metric;
+/// use rs_pfcp::ie::sequence_number::SequenceNumber;
+///
+/// let lci = LoadControlInformation::new(SequenceNumber::new(7), Metric::new(40)?);
+/// let parsed = LoadControlInformation::unmarshal(&lci.marshal())?;
+/// assert_eq!(parsed, lci);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LoadControlInformation {
     pub sequence_number: SequenceNumber,
@@ -25,10 +43,10 @@ impl LoadControlInformation {
                 IeType::SequenceNumber,
                 self.sequence_number.marshal().to_vec(),
             ),
-            Ie::new(IeType::Metric, self.metric.marshal().to_vec()),
+            self.metric.to_ie(),
         ];
 
-        let capacity: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+        let capacity: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
 
         let mut data = Vec::with_capacity(capacity);
         for ie in ies {
@@ -41,9 +59,8 @@ impl LoadControlInformation {
         let mut sequence_number = None;
         let mut metric = None;
 
-        let mut offset = 0;
-        while offset < payload.len() {
-            let ie = Ie::unmarshal(&payload[offset..])?;
+        for ie_result in IeIterator::new(payload) {
+            let ie = ie_result?;
             match ie.ie_type {
                 IeType::SequenceNumber => {
                     sequence_number = Some(SequenceNumber::unmarshal(&ie.payload)?);
@@ -53,7 +70,6 @@ impl LoadControlInformation {
                 }
                 _ => (),
             }
-            offset += ie.len() as usize;
         }
 
         Ok(LoadControlInformation {
@@ -68,6 +84,10 @@ impl LoadControlInformation {
             })?,
         })
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::LoadControlInformation, self.marshal())
+    }
 }
 
 #[cfg(test)]
@@ -76,9 +96,41 @@ mod tests {
 
     #[test]
     fn test_load_control_information_marshal_unmarshal() {
-        let lci = LoadControlInformation::new(SequenceNumber::new(1234), Metric::new(50));
+        let lci = LoadControlInformation::new(SequenceNumber::new(1234), Metric::new(50).unwrap());
         let marshaled = lci.marshal();
         let unmarshaled = LoadControlInformation::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, lci);
     }
+
+    #[test]
+    fn test_load_control_information_to_ie() {
+        let lci =
+            LoadControlInformation::new(SequenceNumber::new(u32::MAX), Metric::new(100).unwrap());
+        let ie = lci.to_ie();
+        assert_eq!(ie.ie_type, IeType::LoadControlInformation);
+        assert_eq!(LoadControlInformation::unmarshal(&ie.payload).unwrap(), lci);
+    }
+
+    #[test]
+    fn test_load_control_information_unmarshal_missing_metric() {
+        let payload = Ie::new(
+            IeType::SequenceNumber,
+            SequenceNumber::new(1).marshal().to_vec(),
+        )
+        .marshal();
+        let result = LoadControlInformation::unmarshal(&payload);
+        assert!(matches!(result, Err(PfcpError::MissingMandatoryIe { .. })));
+    }
+
+    #[test]
+    fn test_load_control_information_unmarshal_out_of_range_metric() {
+        let mut payload = Ie::new(
+            IeType::SequenceNumber,
+            SequenceNumber::new(1).marshal().to_vec(),
+        )
+        .marshal();
+        payload.extend_from_slice(&Ie::new(IeType::Metric, vec![150]).marshal());
+        let result = LoadControlInformation::unmarshal(&payload);
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
 }
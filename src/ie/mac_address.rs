@@ -352,38 +352,30 @@ impl MacAddress {
     }
 }
 
+/// Formats six octets as colon-separated uppercase hex, e.g. `AA:BB:CC:DD:EE:FF`.
+pub(crate) fn format_mac(mac: &[u8; 6]) -> String {
+    format!(
+        "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+        mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
+    )
+}
+
 impl fmt::Display for MacAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(src) = self.source_mac {
-            write!(
-                f,
-                "SRC:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-                src[0], src[1], src[2], src[3], src[4], src[5]
-            )?;
+        if let Some(ref src) = self.source_mac {
+            write!(f, "SRC:{}", format_mac(src))?;
         }
-        if let Some(dst) = self.destination_mac {
+        if let Some(ref dst) = self.destination_mac {
             if self.source_mac.is_some() {
                 write!(f, " ")?;
             }
-            write!(
-                f,
-                "DST:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-                dst[0], dst[1], dst[2], dst[3], dst[4], dst[5]
-            )?;
+            write!(f, "DST:{}", format_mac(dst))?;
         }
-        if let Some(upper_src) = self.upper_source_mac {
-            write!(
-                f,
-                " UPPER_SRC:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-                upper_src[0], upper_src[1], upper_src[2], upper_src[3], upper_src[4], upper_src[5]
-            )?;
+        if let Some(ref upper_src) = self.upper_source_mac {
+            write!(f, " UPPER_SRC:{}", format_mac(upper_src))?;
         }
-        if let Some(upper_dst) = self.upper_destination_mac {
-            write!(
-                f,
-                " UPPER_DST:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-                upper_dst[0], upper_dst[1], upper_dst[2], upper_dst[3], upper_dst[4], upper_dst[5]
-            )?;
+        if let Some(ref upper_dst) = self.upper_destination_mac {
+            write!(f, " UPPER_DST:{}", format_mac(upper_dst))?;
         }
         Ok(())
     }
@@ -447,6 +439,29 @@ mod tests {
         assert_eq!(original, unmarshaled);
     }
 
+    #[test]
+    fn test_mac_address_round_trip_destination_only() {
+        let original = MacAddress::destination([0x01, 0x00, 0x5E, 0x7F, 0x00, 0x01]);
+        let marshaled = original.marshal();
+        assert_eq!(marshaled[0], 0x02); // DEST flag
+        let unmarshaled = MacAddress::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert!(unmarshaled.source_mac.is_none());
+    }
+
+    #[test]
+    fn test_mac_address_round_trip_source_and_destination() {
+        let original = MacAddress::source_and_dest(
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+        );
+        let marshaled = original.marshal();
+        assert_eq!(marshaled.len(), 13);
+        assert_eq!(marshaled[0], 0x03); // SOUR | DEST
+        let unmarshaled = MacAddress::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+    }
+
     #[test]
     fn test_mac_address_constructors() {
         // Test source constructor
@@ -538,7 +538,7 @@ mod tests {
         assert_eq!(detected.count(), 1);
         assert!(detected.c_tag().is_some());
         assert!(detected.s_tag().is_none());
-        assert_eq!(detected.c_tag().unwrap().vid(), 100);
+        assert_eq!(detected.c_tag().unwrap().vid(), Some(100));
 
         // Test marshal/unmarshal round trip
         let bytes = detected.marshal();
@@ -557,7 +557,7 @@ mod tests {
         assert_eq!(detected.count(), 1);
         assert!(detected.c_tag().is_none());
         assert!(detected.s_tag().is_some());
-        assert_eq!(detected.s_tag().unwrap().vid(), 200);
+        assert_eq!(detected.s_tag().unwrap().vid(), Some(200));
 
         // Test marshal/unmarshal round trip
         let bytes = detected.marshal();
@@ -581,8 +581,8 @@ mod tests {
         assert_eq!(detected.count(), 2);
         assert!(detected.c_tag().is_some());
         assert!(detected.s_tag().is_some());
-        assert_eq!(detected.c_tag().unwrap().vid(), 100);
-        assert_eq!(detected.s_tag().unwrap().vid(), 200);
+        assert_eq!(detected.c_tag().unwrap().vid(), Some(100));
+        assert_eq!(detected.s_tag().unwrap().vid(), Some(200));
 
         // Test marshal/unmarshal round trip
         let bytes = detected.marshal();
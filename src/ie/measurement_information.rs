@@ -452,4 +452,20 @@ mod tests {
         assert!(parsed.ciam());
         assert_eq!(mi, parsed);
     }
+
+    #[test]
+    fn test_measurement_information_ciam_radi() {
+        let mut mi = MeasurementInformation::new(0);
+        mi.set_ciam();
+        mi.set_radi();
+
+        let bytes = mi.marshal();
+        assert_eq!(bytes, vec![0x84]); // Bit 8 (CIAM) | Bit 3 (RADI)
+
+        let parsed = MeasurementInformation::unmarshal(&bytes).unwrap();
+        assert!(parsed.ciam());
+        assert!(parsed.radi());
+        assert!(!parsed.mbqe() && !parsed.inam() && !parsed.istm());
+        assert_eq!(mi, parsed);
+    }
 }
@@ -4,6 +4,7 @@
 //! in seconds for periodic usage reporting.
 
 use crate::error::PfcpError;
+use crate::ie::duration_seconds::impl_duration_seconds;
 use crate::ie::{Ie, IeType};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,6 +40,8 @@ impl MeasurementPeriod {
     }
 }
 
+impl_duration_seconds!(MeasurementPeriod, value, "Measurement Period");
+
 #[cfg(test)]
 mod tests {
     use super::*;
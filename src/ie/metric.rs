@@ -1,18 +1,53 @@
-// src/ie/load_metric.rs
+// src/ie/metric.rs
 
 //! Metric Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.49, the Metric IE carries a load or
+//! overload level as a percentage in the range 0 to 100.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::{Ie, IeType};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Metric per 3GPP TS 29.244 §8.2.49.
+///
+/// # Wire Format
+/// - Octet 5: Metric (0-100, binary)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::metric::Metric;
+///
+/// let metric = Metric::new(75)?;
+/// assert_eq!(metric.marshal(), [75]);
+/// assert_eq!(Metric::unmarshal(&metric.marshal())?, metric);
+///
+/// assert!(Metric::new(101).is_err());
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Metric {
     pub value: u8,
 }
 
 impl Metric {
-    pub fn new(value: u8) -> Self {
-        Metric { value }
+    /// Highest valid metric value (100 percent).
+    pub const MAX: u8 = 100;
+
+    /// Creates a new Metric.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` is greater than 100.
+    pub fn new(value: u8) -> Result<Self, PfcpError> {
+        if value > Self::MAX {
+            return Err(PfcpError::invalid_value(
+                "Metric",
+                value.to_string(),
+                "must be in the range 0 to 100",
+            ));
+        }
+        Ok(Metric { value })
     }
 
     pub fn marshal(&self) -> [u8; 1] {
@@ -23,7 +58,11 @@ impl Metric {
         if data.is_empty() {
             return Err(PfcpError::invalid_length("Metric", IeType::Metric, 1, 0));
         }
-        Ok(Metric { value: data[0] })
+        Metric::new(data[0])
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::Metric, self.marshal().to_vec())
     }
 }
 
@@ -33,12 +72,34 @@ mod tests {
 
     #[test]
     fn test_metric_marshal_unmarshal() {
-        let m = Metric::new(50);
+        let m = Metric::new(50).unwrap();
         let marshaled = m.marshal();
         let unmarshaled = Metric::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, m);
     }
 
+    #[test]
+    fn test_metric_range() {
+        for value in [0, 1, 99, 100] {
+            let m = Metric::new(value).unwrap();
+            let ie = m.to_ie();
+            assert_eq!(ie.ie_type, IeType::Metric);
+            assert_eq!(ie.payload, vec![value]);
+            assert_eq!(Metric::unmarshal(&ie.payload).unwrap(), m);
+        }
+
+        for value in [101, 200, u8::MAX] {
+            assert!(matches!(
+                Metric::new(value),
+                Err(PfcpError::InvalidValue { .. })
+            ));
+            assert!(matches!(
+                Metric::unmarshal(&[value]),
+                Err(PfcpError::InvalidValue { .. })
+            ));
+        }
+    }
+
     #[test]
     fn test_metric_unmarshal_empty() {
         let result = Metric::unmarshal(&[]);
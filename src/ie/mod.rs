@@ -1,6 +1,7 @@
 //! Information Elements for PFCP messages.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 
 pub mod access_availability_control_information;
 pub mod access_availability_information;
@@ -24,6 +25,7 @@ pub mod averaging_window;
 pub mod bar;
 pub mod bar_id;
 pub mod bridge_management_information_container;
+pub(crate) mod byte_sink;
 pub mod c_tag;
 pub mod cause;
 pub mod configured_time_domain;
@@ -61,6 +63,7 @@ pub mod dscp_to_ppi_mapping_information;
 pub mod dstt_port_number;
 pub mod duplicating_parameters;
 pub mod duration_measurement;
+pub(crate) mod duration_seconds;
 pub mod end_time;
 pub mod error_indication_report;
 pub mod ethernet_context_information;
@@ -133,6 +136,7 @@ pub mod network_instance;
 pub mod nf_instance_id;
 pub mod node_id;
 pub mod node_report_type;
+pub(crate) mod ntp_timestamp;
 pub mod number_of_reports;
 pub mod number_of_ue_ip_addresses;
 pub mod nwtt_port_number;
@@ -272,6 +276,7 @@ pub mod usage_report_srr;
 pub mod usage_report_trigger;
 pub mod user_id;
 pub mod user_plane_inactivity_timer;
+pub mod user_plane_ip_resource_information;
 pub mod user_plane_path_recovery_report;
 pub mod validity_timer;
 pub mod vendor_specific_node_report_type;
@@ -639,6 +644,455 @@ pub enum IeType {
     Unknown = 0,
 }
 
+impl IeType {
+    /// Highest IE type value assigned by 3GPP TS 29.244 Table 8.1.2-1.
+    ///
+    /// Values above it, up to the vendor-specific range, are spare.
+    pub const MAX_SPEC_TYPE: u16 = 389;
+
+    /// Converts an IE type value, rejecting values the specification does
+    /// not define.
+    ///
+    /// This is the strict counterpart of `From<u16>`, which maps every
+    /// unrecognized value to [`IeType::Unknown`]. It is not a `TryFrom<u16>`
+    /// impl because the `From<u16>` impl already provides an infallible one.
+    ///
+    /// - Values with a dedicated variant convert to it.
+    /// - Values up to [`MAX_SPEC_TYPE`](Self::MAX_SPEC_TYPE) without a
+    ///   variant, and vendor-specific values (0x8000 and above), convert to
+    ///   [`IeType::Unknown`]; for the latter [`Ie`] keeps the enterprise ID.
+    /// - Reserved value 0 and spare values are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::IeType;
+    ///
+    /// assert_eq!(IeType::try_from_code(389)?, IeType::ReportingControlInformation);
+    /// assert_eq!(IeType::try_from_code(0x8001)?, IeType::Unknown);
+    /// assert!(IeType::try_from_code(999).is_err());
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    pub fn try_from_code(v: u16) -> Result<Self, PfcpError> {
+        match v {
+            1..=Self::MAX_SPEC_TYPE | 0x8000.. => Ok(IeType::from(v)),
+            _ => Err(PfcpError::invalid_value(
+                "IE Type",
+                v.to_string(),
+                "not defined in 3GPP TS 29.244",
+            )),
+        }
+    }
+
+    /// Returns the IE name as written in 3GPP TS 29.244 Table 8.1.2-1.
+    ///
+    /// Used in error messages and logs, where the spec name is easier to
+    /// recognize than the Rust variant name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::IeType;
+    ///
+    /// assert_eq!(IeType::CreatePdr.name(), "Create PDR");
+    /// assert_eq!(IeType::Fteid.name(), "F-TEID");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            IeType::CreatePdr => "Create PDR",
+            IeType::Pdi => "PDI",
+            IeType::CreateFar => "Create FAR",
+            IeType::ForwardingParameters => "Forwarding Parameters",
+            IeType::DuplicatingParameters => "Duplicating Parameters",
+            IeType::CreateUrr => "Create URR",
+            IeType::CreateQer => "Create QER",
+            IeType::CreatedPdr => "Created PDR",
+            IeType::UpdatePdr => "Update PDR",
+            IeType::UpdateFar => "Update FAR",
+            IeType::UpdateForwardingParameters => "Update Forwarding Parameters",
+            IeType::UpdateBarWithinSessionReportResponse => {
+                "Update BAR within Session Report Response"
+            }
+            IeType::UpdateUrr => "Update URR",
+            IeType::UpdateQer => "Update QER",
+            IeType::RemovePdr => "Remove PDR",
+            IeType::RemoveFar => "Remove FAR",
+            IeType::RemoveUrr => "Remove URR",
+            IeType::RemoveQer => "Remove QER",
+            IeType::Cause => "Cause",
+            IeType::SourceInterface => "Source Interface",
+            IeType::Fteid => "F-TEID",
+            IeType::NetworkInstance => "Network Instance",
+            IeType::SdfFilter => "SDF Filter",
+            IeType::ApplicationId => "Application ID",
+            IeType::GateStatus => "Gate Status",
+            IeType::Mbr => "MBR",
+            IeType::Gbr => "GBR",
+            IeType::QerCorrelationId => "QER Correlation ID",
+            IeType::Precedence => "Precedence",
+            IeType::TransportLevelMarking => "Transport Level Marking",
+            IeType::VolumeThreshold => "Volume Threshold",
+            IeType::TimeThreshold => "Time Threshold",
+            IeType::MonitoringTime => "Monitoring Time",
+            IeType::SubsequentVolumeThreshold => "Subsequent Volume Threshold",
+            IeType::SubsequentTimeThreshold => "Subsequent Time Threshold",
+            IeType::InactivityDetectionTime => "Inactivity Detection Time",
+            IeType::ReportingTriggers => "Reporting Triggers",
+            IeType::RedirectInformation => "Redirect Information",
+            IeType::ReportType => "Report Type",
+            IeType::OffendingIe => "Offending IE",
+            IeType::ForwardingPolicy => "Forwarding Policy",
+            IeType::DestinationInterface => "Destination Interface",
+            IeType::UpFunctionFeatures => "UP Function Features",
+            IeType::ApplyAction => "Apply Action",
+            IeType::DownlinkDataServiceInformation => "Downlink Data Service Information",
+            IeType::DownlinkDataNotificationDelay => "Downlink Data Notification Delay",
+            IeType::DlBufferingDuration => "DL Buffering Duration",
+            IeType::DlBufferingSuggestedPacketCount => "DL Buffering Suggested Packet Count",
+            IeType::PfcpsmReqFlags => "PFCPSMReq-Flags",
+            IeType::PfcpsrrspFlags => "PFCPSRRsp-Flags",
+            IeType::LoadControlInformation => "Load Control Information",
+            IeType::SequenceNumber => "Sequence Number",
+            IeType::Metric => "Metric",
+            IeType::OverloadControlInformation => "Overload Control Information",
+            IeType::Timer => "Timer",
+            IeType::PdrId => "PDR ID",
+            IeType::Fseid => "F-SEID",
+            IeType::ApplicationIdsPfds => "Application ID's PFDs",
+            IeType::PfdContext => "PFD Context",
+            IeType::NodeId => "Node ID",
+            IeType::PfdContents => "PFD Contents",
+            IeType::MeasurementMethod => "Measurement Method",
+            IeType::UsageReportTrigger => "Usage Report Trigger",
+            IeType::MeasurementPeriod => "Measurement Period",
+            IeType::FqCsid => "FQ-CSID",
+            IeType::VolumeMeasurement => "Volume Measurement",
+            IeType::DurationMeasurement => "Duration Measurement",
+            IeType::ApplicationDetectionInformation => "Application Detection Information",
+            IeType::TimeOfFirstPacket => "Time of First Packet",
+            IeType::TimeOfLastPacket => "Time of Last Packet",
+            IeType::QuotaHoldingTime => "Quota Holding Time",
+            IeType::DroppedDlTrafficThreshold => "Dropped DL Traffic Threshold",
+            IeType::VolumeQuota => "Volume Quota",
+            IeType::TimeQuota => "Time Quota",
+            IeType::StartTime => "Start Time",
+            IeType::EndTime => "End Time",
+            IeType::QueryUrr => "Query URR",
+            IeType::UsageReportWithinSessionModificationResponse => {
+                "Usage Report within Session Modification Response"
+            }
+            IeType::UsageReportWithinSessionDeletionResponse => {
+                "Usage Report within Session Deletion Response"
+            }
+            IeType::UsageReportWithinSessionReportRequest => {
+                "Usage Report within Session Report Request"
+            }
+            IeType::UrrId => "URR ID",
+            IeType::LinkedUrrId => "Linked URR ID",
+            IeType::DownlinkDataReport => "Downlink Data Report",
+            IeType::OuterHeaderCreation => "Outer Header Creation",
+            IeType::CpFunctionFeatures => "CP Function Features",
+            IeType::UsageInformation => "Usage Information",
+            IeType::ApplicationInstanceId => "Application Instance ID",
+            IeType::FlowInformation => "Flow Information",
+            IeType::UeIpAddress => "UE IP Address",
+            IeType::PacketRate => "Packet Rate",
+            IeType::OuterHeaderRemoval => "Outer Header Removal",
+            IeType::RecoveryTimeStamp => "Recovery Time Stamp",
+            IeType::DlFlowLevelMarking => "DL Flow Level Marking",
+            IeType::HeaderEnrichment => "Header Enrichment",
+            IeType::ErrorIndicationReport => "Error Indication Report",
+            IeType::MeasurementInformation => "Measurement Information",
+            IeType::NodeReportType => "Node Report Type",
+            IeType::UserPlanePathFailureReport => "User Plane Path Failure Report",
+            IeType::RemoteGtpuPeer => "Remote GTP-U Peer",
+            IeType::UrSeqn => "UR-SEQN",
+            IeType::UpdateDuplicatingParameters => "Update Duplicating Parameters",
+            IeType::ActivatePredefinedRules => "Activate Predefined Rules",
+            IeType::DeactivatePredefinedRules => "Deactivate Predefined Rules",
+            IeType::FarId => "FAR ID",
+            IeType::QerId => "QER ID",
+            IeType::OciFlags => "OCI Flags",
+            IeType::PfcpAssociationReleaseRequest => "PFCP Association Release Request",
+            IeType::GracefulReleasePeriod => "Graceful Release Period",
+            IeType::PdnType => "PDN Type",
+            IeType::FailedRuleId => "Failed Rule ID",
+            IeType::TimeQuotaMechanism => "Time Quota Mechanism",
+            IeType::UserPlaneIpResourceInformation => "User Plane IP Resource Information",
+            IeType::UserPlaneInactivityTimer => "User Plane Inactivity Timer",
+            IeType::AggregatedUrrs => "Aggregated URRs",
+            IeType::Multiplier => "Multiplier",
+            IeType::AggregatedUrrId => "Aggregated URR ID",
+            IeType::SubsequentVolumeQuota => "Subsequent Volume Quota",
+            IeType::SubsequentTimeQuota => "Subsequent Time Quota",
+            IeType::Rqi => "RQI",
+            IeType::Qfi => "QFI",
+            IeType::QueryUrrReference => "Query URR Reference",
+            IeType::AdditionalUsageReportsInformation => "Additional Usage Reports Information",
+            IeType::CreateTrafficEndpoint => "Create Traffic Endpoint",
+            IeType::CreatedTrafficEndpoint => "Created Traffic Endpoint",
+            IeType::UpdateTrafficEndpoint => "Update Traffic Endpoint",
+            IeType::RemoveTrafficEndpoint => "Remove Traffic Endpoint",
+            IeType::TrafficEndpointId => "Traffic Endpoint ID",
+            IeType::CreateBar => "Create BAR",
+            IeType::UpdateBar => "Update BAR",
+            IeType::RemoveBar => "Remove BAR",
+            IeType::BarId => "BAR ID",
+            IeType::EthernetPacketFilter => "Ethernet Packet Filter",
+            IeType::MacAddress => "MAC Address",
+            IeType::CTag => "C-TAG",
+            IeType::STag => "S-TAG",
+            IeType::Ethertype => "Ethertype",
+            IeType::Proxying => "Proxying",
+            IeType::EthernetFilterId => "Ethernet Filter ID",
+            IeType::EthernetFilterProperties => "Ethernet Filter Properties",
+            IeType::SuggestedBufferingPacketsCount => "Suggested Buffering Packets Count",
+            IeType::UserId => "User ID",
+            IeType::EthernetPduSessionInformation => "Ethernet PDU Session Information",
+            IeType::EthernetTrafficInformation => "Ethernet Traffic Information",
+            IeType::MacAddressesDetected => "MAC Addresses Detected",
+            IeType::MacAddressesRemoved => "MAC Addresses Removed",
+            IeType::EthernetInactivityTimer => "Ethernet Inactivity Timer",
+            IeType::AdditionalMonitoringTime => "Additional Monitoring Time",
+            IeType::EventQuota => "Event Quota",
+            IeType::EventThreshold => "Event Threshold",
+            IeType::SubsequentEventQuota => "Subsequent Event Quota",
+            IeType::SubsequentEventThreshold => "Subsequent Event Threshold",
+            IeType::TraceInformation => "Trace Information",
+            IeType::FramedRoute => "Framed Route",
+            IeType::FramedRouting => "Framed Routing",
+            IeType::FramedIpv6Route => "Framed IPv6 Route",
+            IeType::EventTimeStamp => "Event Time Stamp",
+            IeType::AveragingWindow => "Averaging Window",
+            IeType::PagingPolicyIndicator => "Paging Policy Indicator",
+            IeType::ApnDnn => "APN/DNN",
+            IeType::TgppInterfaceType => "3GPP Interface Type",
+            IeType::PfcpsrReqFlags => "PFCPSRReq-Flags",
+            IeType::PfcpauReqFlags => "PFCPAUReq-Flags",
+            IeType::ActivationTime => "Activation Time",
+            IeType::DeactivationTime => "Deactivation Time",
+            IeType::CreateMar => "Create MAR",
+            IeType::TgppAccessForwardingActionInformation => {
+                "3GPP Access Forwarding Action Information"
+            }
+            IeType::NonTgppAccessForwardingActionInformation => {
+                "Non-3GPP Access Forwarding Action Information"
+            }
+            IeType::RemoveMar => "Remove MAR",
+            IeType::UpdateMar => "Update MAR",
+            IeType::MarId => "MAR ID",
+            IeType::SteeringFunctionality => "Steering Functionality",
+            IeType::SteeringMode => "Steering Mode",
+            IeType::Weight => "Weight",
+            IeType::Priority => "Priority",
+            IeType::UpdateTgppAccessForwardingActionInformation => {
+                "Update 3GPP Access Forwarding Action Information"
+            }
+            IeType::UpdateNonTgppAccessForwardingActionInformation => {
+                "Update Non-3GPP Access Forwarding Action Information"
+            }
+            IeType::UeIpAddressPoolIdentity => "UE IP Address Pool Identity",
+            IeType::AlternativeSmfIpAddress => "Alternative SMF IP Address",
+            IeType::PacketReplicationAndDetectionCarryOnInformation => {
+                "Packet Replication and Detection Carry On Information"
+            }
+            IeType::SmfSetId => "SMF Set ID",
+            IeType::QuotaValidityTime => "Quota Validity Time",
+            IeType::NumberOfReports => "Number of Reports",
+            IeType::PfcpSessionRetentionInformation => "PFCP Session Retention Information",
+            IeType::PfcpasRspFlags => "PFCPASRsp-Flags",
+            IeType::CpPfcpEntityIpAddress => "CP PFCP Entity IP Address",
+            IeType::PfcpseReqFlags => "PFCPSEReq-Flags",
+            IeType::UserPlanePathRecoveryReport => "User Plane Path Recovery Report",
+            IeType::IpMulticastAddressingInfo => "IP Multicast Addressing Info",
+            IeType::JoinIpMulticastInformationWithinUsageReport => {
+                "Join IP Multicast Information within Usage Report"
+            }
+            IeType::LeaveIpMulticastInformationWithinUsageReport => {
+                "Leave IP Multicast Information within Usage Report"
+            }
+            IeType::IpMulticastAddress => "IP Multicast Address",
+            IeType::SourceIpAddress => "Source IP Address",
+            IeType::PacketRateStatus => "Packet Rate Status",
+            IeType::CreateBridgeInfoForTsc => "Create Bridge Info for TSC",
+            IeType::CreatedBridgeInfoForTsc => "Created Bridge Info for TSC",
+            IeType::DsttPortNumber => "DS-TT Port Number",
+            IeType::NwttPortNumber => "NW-TT Port Number",
+            IeType::TsnBridgeId => "TSN Bridge ID",
+            IeType::TscManagementInformationWithinSessionModificationRequest => {
+                "TSC Management Information within Session Modification Request"
+            }
+            IeType::TscManagementInformationWithinSessionModificationResponse => {
+                "TSC Management Information within Session Modification Response"
+            }
+            IeType::TscManagementInformationWithinSessionReportRequest => {
+                "TSC Management Information within Session Report Request"
+            }
+            IeType::PortManagementInformationContainer => "Port Management Information Container",
+            IeType::ClockDriftControlInformation => "Clock Drift Control Information",
+            IeType::RequestedClockDriftInformation => "Requested Clock Drift Information",
+            IeType::ClockDriftReport => "Clock Drift Report",
+            IeType::TsnTimeDomainNumber => "TSN Time Domain Number",
+            IeType::TimeOffsetThreshold => "Time Offset Threshold",
+            IeType::CumulativeRateRatioThreshold => "Cumulative Rate Ratio Threshold",
+            IeType::TimeOffsetMeasurement => "Time Offset Measurement",
+            IeType::CumulativeRateRatioMeasurement => "Cumulative Rate Ratio Measurement",
+            IeType::RemoveSrr => "Remove SRR",
+            IeType::CreateSrr => "Create SRR",
+            IeType::UpdateSrr => "Update SRR",
+            IeType::SessionReport => "Session Report",
+            IeType::SrrId => "SRR ID",
+            IeType::AccessAvailabilityControlInformation => {
+                "Access Availability Control Information"
+            }
+            IeType::RequestedAccessAvailabilityInformation => {
+                "Requested Access Availability Information"
+            }
+            IeType::AccessAvailabilityReport => "Access Availability Report",
+            IeType::AccessAvailabilityInformation => "Access Availability Information",
+            IeType::ProvideAtsssControlInformation => "Provide ATSSS Control Information",
+            IeType::AtsssControlParameters => "ATSSS Control Parameters",
+            IeType::MptcpControlInformation => "MPTCP Control Information",
+            IeType::AtsssLlControlInformation => "ATSSS-LL Control Information",
+            IeType::PmfControlInformation => "PMF Control Information",
+            IeType::MptcpParameters => "MPTCP Parameters",
+            IeType::AtsssLlParameters => "ATSSS-LL Parameters",
+            IeType::PmfParameters => "PMF Parameters",
+            IeType::MptcpAddressInformation => "MPTCP Address Information",
+            IeType::UeLinkSpecificIpAddress => "UE Link Specific IP Address",
+            IeType::PmfAddressInformation => "PMF Address Information",
+            IeType::AtsssLlInformation => "ATSSS-LL Information",
+            IeType::DataNetworkAccessIdentifier => "Data Network Access Identifier",
+            IeType::UeIpAddressPoolInformation => "UE IP Address Pool Information",
+            IeType::AveragePacketDelay => "Average Packet Delay",
+            IeType::MinimumPacketDelay => "Minimum Packet Delay",
+            IeType::MaximumPacketDelay => "Maximum Packet Delay",
+            IeType::QosReportTrigger => "QoS Report Trigger",
+            IeType::GtpuPathQosControlInformation => "GTP-U Path QoS Control Information",
+            IeType::GtpuPathQosReport => "GTP-U Path QoS Report",
+            IeType::QosInformationInGtpuPathQosReport => "QoS Information in GTP-U Path QoS Report",
+            IeType::GtpuPathInterfaceType => "GTP-U Path Interface Type",
+            IeType::QosMonitoringPerQosFlowControlInformation => {
+                "QoS Monitoring per QoS Flow Control Information"
+            }
+            IeType::RequestedQosMonitoring => "Requested QoS Monitoring",
+            IeType::ReportingFrequency => "Reporting Frequency",
+            IeType::PacketDelayThresholds => "Packet Delay Thresholds",
+            IeType::MinimumWaitTime => "Minimum Wait Time",
+            IeType::QosMonitoringReport => "QoS Monitoring Report",
+            IeType::QosMonitoringMeasurement => "QoS Monitoring Measurement",
+            IeType::MtedtControlInformation => "MT-EDT Control Information",
+            IeType::DlDataPacketsSize => "DL Data Packets Size",
+            IeType::QerControlIndications => "QER Control Indications",
+            IeType::PacketRateStatusReport => "Packet Rate Status Report",
+            IeType::NfInstanceId => "NF Instance ID",
+            IeType::EthernetContextInformation => "Ethernet Context Information",
+            IeType::RedundantTransmissionParameters => "Redundant Transmission Parameters",
+            IeType::UpdatedPdr => "Updated PDR",
+            IeType::Snssai => "S-NSSAI",
+            IeType::IpVersion => "IP Version",
+            IeType::PfcpasReqFlags => "PFCPASReq-Flags",
+            IeType::DataStatus => "Data Status",
+            IeType::ProvideRdsConfigurationInformation => "Provide RDS Configuration Information",
+            IeType::RdsConfigurationInformation => "RDS Configuration Information",
+            IeType::QueryPacketRateStatusWithinSessionModificationRequest => {
+                "Query Packet Rate Status within Session Modification Request"
+            }
+            IeType::PacketRateStatusReportWithinSessionModificationResponse => {
+                "Packet Rate Status Report within Session Modification Response"
+            }
+            IeType::MptcpApplicableIndication => "MPTCP Applicable Indication",
+            IeType::BridgeManagementInformationContainer => {
+                "Bridge Management Information Container"
+            }
+            IeType::UeIpAddressUsageInformation => "UE IP Address Usage Information",
+            IeType::NumberOfUeIpAddresses => "Number of UE IP Addresses",
+            IeType::ValidityTimer => "Validity Timer",
+            IeType::RedundantTransmissionForwardingParameters => {
+                "Redundant Transmission Forwarding Parameters"
+            }
+            IeType::TransportDelayReporting => "Transport Delay Reporting",
+            IeType::PartialFailureInformation => "Partial Failure Information",
+            IeType::L2tpTunnelInformation => "L2TP Tunnel Information",
+            IeType::L2tpSessionInformation => "L2TP Session Information",
+            IeType::CreatedL2tpSession => "Created L2TP Session",
+            IeType::PfcpSessionChangeInfo => "PFCP Session Change Info",
+            IeType::GroupId => "Group ID",
+            IeType::CpIpAddress => "CP IP Address",
+            IeType::MbsSessionN4mbControlInformation => "MBS Session N4mb Control Information",
+            IeType::MbsMulticastParameters => "MBS Multicast Parameters",
+            IeType::AddMbsUnicastParameters => "Add MBS Unicast Parameters",
+            IeType::MbsSessionN4mbInformation => "MBS Session N4mb Information",
+            IeType::RemoveMbsUnicastParameters => "Remove MBS Unicast Parameters",
+            IeType::MbsSessionIdentifier => "MBS Session Identifier",
+            IeType::MulticastTransportInformation => "Multicast Transport Information",
+            IeType::Mbsn4mbReqFlags => "MBSN4mbReq-Flags",
+            IeType::LocalIngressTunnel => "Local Ingress Tunnel",
+            IeType::MbsUnicastParametersId => "MBS Unicast Parameters ID",
+            IeType::MbsSessionN4ControlInformation => "MBS Session N4 Control Information",
+            IeType::MbsSessionN4Information => "MBS Session N4 Information",
+            IeType::Mbsn4RespFlags => "MBSN4Resp-Flags",
+            IeType::TunnelPassword => "Tunnel Password",
+            IeType::AreaSessionId => "Area Session ID",
+            IeType::PeerUpRestartReport => "Peer UP Restart Report",
+            IeType::DscpToPpiControlInformation => "DSCP to PPI Control Information",
+            IeType::DscpToPpiMappingInformation => "DSCP to PPI Mapping Information",
+            IeType::PfcpsdrspFlags => "PFCPSDRsp-Flags",
+            IeType::QerIndications => "QER Indications",
+            IeType::VendorSpecificNodeReportType => "Vendor Specific Node Report Type",
+            IeType::ConfiguredTimeDomain => "Configured Time Domain",
+            IeType::Metadata => "Metadata",
+            IeType::TrafficParameterMeasurementControlInformation => {
+                "Traffic Parameter Measurement Control Information"
+            }
+            IeType::TrafficParameterMeasurementReport => "Traffic Parameter Measurement Report",
+            IeType::TrafficParameterThreshold => "Traffic Parameter Threshold",
+            IeType::DlPeriodicity => "DL Periodicity",
+            IeType::N6JitterMeasurement => "N6 Jitter Measurement",
+            IeType::TrafficParameterMeasurementIndication => {
+                "Traffic Parameter Measurement Indication"
+            }
+            IeType::UlPeriodicity => "UL Periodicity",
+            IeType::MpquicControlInformation => "MPQUIC Control Information",
+            IeType::MpquicParameters => "MPQUIC Parameters",
+            IeType::MpquicAddressInformation => "MPQUIC Address Information",
+            IeType::TransportMode => "Transport Mode",
+            IeType::ProtocolDescription => "Protocol Description",
+            IeType::ReportingSuggestionInfo => "Reporting Suggestion Info",
+            IeType::TlContainer => "TL Container",
+            IeType::MeasurementIndication => "Measurement Indication",
+            IeType::HplmnSNssai => "HPLMN S-NSSAI",
+            IeType::MediaTransportProtocol => "Media Transport Protocol",
+            IeType::RtpHeaderExtensionInformation => "RTP Header Extension Information",
+            IeType::RtpPayloadInformation => "RTP Payload Information",
+            IeType::RtpHeaderExtensionType => "RTP Header Extension Type",
+            IeType::RtpHeaderExtensionId => "RTP Header Extension ID",
+            IeType::RtpPayloadType => "RTP Payload Type",
+            IeType::RtpPayloadFormat => "RTP Payload Format",
+            IeType::ExtendedDlBufferingNotificationPolicy => {
+                "Extended DL Buffering Notification Policy"
+            }
+            IeType::MtSdtControlInformation => "MT-SDT Control Information",
+            IeType::ReportingThresholds => "Reporting Thresholds",
+            IeType::RtpHeaderExtensionAdditionalInformation => {
+                "RTP Header Extension Additional Information"
+            }
+            IeType::MappedN6IpAddress => "Mapped N6 IP Address",
+            IeType::N6RoutingInformation => "N6 Routing Information",
+            IeType::Uri => "URI",
+            IeType::UeLevelMeasurementsConfiguration => "UE Level Measurements Configuration",
+            IeType::ReportingControlInformation => "Reporting Control Information",
+            IeType::Unknown => "Unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for IeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 impl From<u16> for IeType {
     fn from(v: u16) -> Self {
         match v {
@@ -1020,14 +1474,33 @@ impl<const N: usize> IntoIePayload for [u8; N] {
 }
 
 /// Represents a PFCP Information Element.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Ie {
     pub ie_type: IeType,
     pub enterprise_id: Option<u16>,
     pub payload: Vec<u8>,
-    child_ies: Vec<Ie>,
+    /// Parsed child IEs, `None` until the payload has been parsed. An empty
+    /// list is a valid cache entry, so cleared children are not re-parsed.
+    child_ies: Option<Vec<Ie>>,
+}
+
+// Child IEs are a parse cache derived from the payload, so they are excluded
+// from equality; an eagerly parsed IE equals one built with `Ie::new`.
+impl PartialEq for Ie {
+    fn eq(&self, other: &Self) -> bool {
+        self.ie_type == other.ie_type
+            && self.enterprise_id == other.enterprise_id
+            && self.payload == other.payload
+    }
 }
 
+impl Eq for Ie {}
+
+/// Grouped IE nesting depth at which eager parsing gives up with an error,
+/// bounding recursion on hostile input.
+#[cfg(feature = "eager-parse")]
+const MAX_EAGER_PARSE_DEPTH: usize = 8;
+
 impl Ie {
     /// Creates a new IE.
     pub fn new(ie_type: IeType, payload: Vec<u8>) -> Self {
@@ -1035,7 +1508,7 @@ impl Ie {
             ie_type,
             enterprise_id: None,
             payload,
-            child_ies: Vec::new(),
+            child_ies: None,
         }
     }
 
@@ -1045,7 +1518,7 @@ impl Ie {
             ie_type,
             enterprise_id: Some(enterprise_id),
             payload,
-            child_ies: Vec::new(),
+            child_ies: None,
         }
     }
 
@@ -1059,7 +1532,7 @@ impl Ie {
             ie_type,
             enterprise_id: None,
             payload,
-            child_ies: ies,
+            child_ies: Some(ies),
         }
     }
 
@@ -1081,14 +1554,39 @@ impl Ie {
         Ie::new(ie_type, payload.into_payload())
     }
 
-    /// Returns the length of the IE in bytes.
-    pub fn len(&self) -> u16 {
-        let mut length = 4; // Type (2) + Length (2)
+    /// Size of the buffer returned by [`marshal_stack`](Ie::marshal_stack).
+    pub const STACK_MARSHAL_SIZE: usize = 64;
+
+    /// Returns the size of the IE on the wire in bytes.
+    ///
+    /// This is the 4-byte type and length header, plus the 2-byte Enterprise
+    /// ID for vendor-specific IEs, plus the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::{Ie, IeType};
+    ///
+    /// let ie = Ie::new(IeType::Cause, vec![0x01]);
+    /// assert_eq!(ie.encoded_size(), 5);
+    /// assert_eq!(ie.marshal().len(), ie.encoded_size());
+    /// ```
+    pub fn encoded_size(&self) -> usize {
+        let mut size = 4; // Type (2) + Length (2)
         if self.is_vendor_specific() {
-            length += 2;
+            size += 2;
         }
-        length += self.payload.len() as u16;
-        length
+        size + self.payload.len()
+    }
+
+    /// Returns the length of the IE in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the encoded size does not fit in a `u16`.
+    #[deprecated(note = "use `encoded_size`, which returns `usize` and cannot overflow")]
+    pub fn len(&self) -> u16 {
+        u16::try_from(self.encoded_size()).expect("IE encoded size exceeds u16::MAX")
     }
 
     /// Reports whether an IE is empty.
@@ -1096,6 +1594,14 @@ impl Ie {
         self.payload.is_empty()
     }
 
+    /// Reports whether the IE type is not known to this library.
+    ///
+    /// This is the case for IE types introduced after 3GPP TS 29.244 Release 18
+    /// or otherwise not assigned by the specification.
+    pub fn is_unknown(&self) -> bool {
+        self.ie_type == IeType::Unknown
+    }
+
     /// Reports whether an IE is vendor-specific.
     pub fn is_vendor_specific(&self) -> bool {
         self.enterprise_id.is_some() || (self.ie_type as u16) & 0x8000 != 0
@@ -1103,11 +1609,54 @@ impl Ie {
 
     /// Serializes the IE into a byte vector.
     pub fn marshal(&self) -> Vec<u8> {
-        let mut data = Vec::new();
+        let mut data = Vec::with_capacity(self.encoded_size());
         self.marshal_into(&mut data);
         data
     }
 
+    /// Serializes a small IE into a stack buffer without allocating.
+    ///
+    /// Returns the buffer and the number of bytes written; only
+    /// `buf[..len]` is meaningful. Most IEs fit in
+    /// [`STACK_MARSHAL_SIZE`](Ie::STACK_MARSHAL_SIZE) bytes; use
+    /// [`marshal_into`](Ie::marshal_into) for IEs that may be larger.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`encoded_size`](Ie::encoded_size) exceeds
+    /// [`STACK_MARSHAL_SIZE`](Ie::STACK_MARSHAL_SIZE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::{Ie, IeType};
+    ///
+    /// let ie = Ie::new(IeType::Cause, vec![1]);
+    /// let (buf, len) = ie.marshal_stack();
+    /// assert_eq!(&buf[..len], ie.marshal().as_slice());
+    /// ```
+    pub fn marshal_stack(&self) -> ([u8; Self::STACK_MARSHAL_SIZE], usize) {
+        let len = self.encoded_size();
+        assert!(
+            len <= Self::STACK_MARSHAL_SIZE,
+            "IE {:?} is {} bytes, larger than the {}-byte stack buffer",
+            self.ie_type,
+            len,
+            Self::STACK_MARSHAL_SIZE
+        );
+
+        let mut buf = [0u8; Self::STACK_MARSHAL_SIZE];
+        buf[0..2].copy_from_slice(&(self.ie_type as u16).to_be_bytes());
+        buf[2..4].copy_from_slice(&((len - 4) as u16).to_be_bytes());
+        let mut offset = 4;
+        if let Some(eid) = self.enterprise_id {
+            buf[4..6].copy_from_slice(&eid.to_be_bytes());
+            offset = 6;
+        }
+        buf[offset..len].copy_from_slice(&self.payload);
+        (buf, len)
+    }
+
     /// Serializes the IE into an existing buffer.
     ///
     /// This method appends the marshaled IE to the provided buffer,
@@ -1128,20 +1677,47 @@ impl Ie {
     /// ie.marshal_into(&mut buf);
     /// ```
     pub fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.extend_from_slice(&(self.ie_type as u16).to_be_bytes());
+        self.write_into(buf);
+    }
+
+    /// Serializes the IE into a [`bytes::BytesMut`] buffer.
+    ///
+    /// Produces the same bytes as [`marshal_into`](Ie::marshal_into), writing
+    /// directly into `buf` without an intermediate allocation. Requires the
+    /// `bytes` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    /// use rs_pfcp::ie::{Ie, IeType};
+    ///
+    /// let ie = Ie::new(IeType::Cause, vec![1]);
+    /// let mut buf = BytesMut::new();
+    /// ie.write_to_bytes(&mut buf);
+    /// assert_eq!(&buf[..], &ie.marshal()[..]);
+    /// ```
+    #[cfg(feature = "bytes")]
+    pub fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        buf.reserve(self.encoded_size());
+        self.write_into(buf);
+    }
+
+    pub(crate) fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.write_slice(&(self.ie_type as u16).to_be_bytes());
 
         let length = if self.is_vendor_specific() {
             self.payload.len() as u16 + 2
         } else {
             self.payload.len() as u16
         };
-        buf.extend_from_slice(&length.to_be_bytes());
+        buf.write_slice(&length.to_be_bytes());
 
         if let Some(eid) = self.enterprise_id {
-            buf.extend_from_slice(&eid.to_be_bytes());
+            buf.write_slice(&eid.to_be_bytes());
         }
 
-        buf.extend_from_slice(&self.payload);
+        buf.write_slice(&self.payload);
     }
 
     /// Returns true if the IE type legitimately supports zero-length encoding.
@@ -1196,6 +1772,17 @@ impl Ie {
 
     /// Deserializes a byte slice into an IE.
     pub fn unmarshal(b: &[u8]) -> Result<Self, PfcpError> {
+        let ie = Self::unmarshal_shallow(b)?;
+        #[cfg(feature = "eager-parse")]
+        if Self::is_eagerly_parsed(ie.ie_type) {
+            return ie.parse_children_eagerly(0);
+        }
+        Ok(ie)
+    }
+
+    /// Deserializes an IE without parsing any children; those are parsed
+    /// lazily by [`as_ies`](Ie::as_ies).
+    fn unmarshal_shallow(b: &[u8]) -> Result<Self, PfcpError> {
         if b.len() < 4 {
             return Err(PfcpError::invalid_length("IE", IeType::Unknown, 4, b.len()));
         }
@@ -1217,7 +1804,7 @@ impl Ie {
             ));
         }
 
-        let mut offset = 4;
+        let mut offset: usize = 4;
         // Check vendor bit in RAW type value, not converted IeType
         let enterprise_id = if raw_type & 0x8000 != 0 {
             if b.len() < 6 {
@@ -1244,7 +1831,14 @@ impl Ie {
             length
         };
 
-        let end = offset + payload_length as usize;
+        // Checked addition guards against usize overflow on narrow targets
+        let end = offset.checked_add(payload_length as usize).ok_or_else(|| {
+            PfcpError::invalid_value(
+                "IE",
+                format!("{:?} (type {})", ie_type, raw_type),
+                "Declared length overflows buffer bounds",
+            )
+        })?;
         if b.len() < end {
             return Err(PfcpError::invalid_length(
                 "IE payload",
@@ -1259,10 +1853,88 @@ impl Ie {
             ie_type,
             enterprise_id,
             payload,
-            child_ies: Vec::new(), // Parsing child IEs will be handled separately
+            child_ies: None,
         })
     }
 
+    /// Grouped IE types whose children are parsed by [`unmarshal`](Ie::unmarshal)
+    /// when the `eager-parse` feature is enabled.
+    #[cfg(feature = "eager-parse")]
+    fn is_eagerly_parsed(ie_type: IeType) -> bool {
+        matches!(
+            ie_type,
+            IeType::CreatePdr
+                | IeType::CreateFar
+                | IeType::CreateQer
+                | IeType::Pdi
+                | IeType::ForwardingParameters
+                | IeType::DuplicatingParameters
+        )
+    }
+
+    /// Reports whether eager parsing descends from `parent` into a `child` IE.
+    ///
+    /// Only the parent/child pairs defined by 3GPP TS 29.244 qualify, so a
+    /// grouped IE nested where the specification does not allow it keeps its
+    /// payload unparsed, however deeply it nests.
+    #[cfg(feature = "eager-parse")]
+    fn is_eager_child(parent: IeType, child: IeType) -> bool {
+        matches!(
+            (parent, child),
+            (IeType::CreatePdr, IeType::Pdi)
+                | (IeType::CreateFar, IeType::ForwardingParameters)
+                | (IeType::CreateFar, IeType::DuplicatingParameters)
+        )
+    }
+
+    /// Parses the children of an eagerly parsed grouped IE, descending into
+    /// grouped children as allowed by [`is_eager_child`](Ie::is_eager_child).
+    #[cfg(feature = "eager-parse")]
+    fn parse_children_eagerly(mut self, depth: usize) -> Result<Self, PfcpError> {
+        if depth >= MAX_EAGER_PARSE_DEPTH {
+            return Err(PfcpError::invalid_value(
+                "IE",
+                format!("{:?} at nesting depth {}", self.ie_type, depth),
+                "Grouped IE nesting exceeds the eager parsing depth limit",
+            ));
+        }
+        let mut children = Vec::new();
+        let mut offset = 0;
+        while offset < self.payload.len() {
+            let mut child = Self::unmarshal_shallow(&self.payload[offset..])?;
+            offset += child.encoded_size();
+            if Self::is_eager_child(self.ie_type, child.ie_type) {
+                child = child.parse_children_eagerly(depth + 1)?;
+            }
+            children.push(child);
+        }
+        self.child_ies = Some(children);
+        Ok(self)
+    }
+
+    /// Deserializes an IE from a [`bytes::Bytes`] buffer.
+    ///
+    /// Accepts the same input as [`unmarshal`](Ie::unmarshal) so that received
+    /// `Bytes` frames can be handed over without converting them first. This is
+    /// not a zero-copy parse: `Ie` owns its payload as a `Vec<u8>`, so the value
+    /// bytes are copied out of `buf`. Requires the `bytes` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use rs_pfcp::ie::{Ie, IeType};
+    ///
+    /// let buf = Bytes::from_static(&[0x00, 0x13, 0x00, 0x01, 0x01]);
+    /// let ie = Ie::unmarshal_bytes(buf).unwrap();
+    /// assert_eq!(ie.ie_type, IeType::Cause);
+    /// assert_eq!(ie.payload, vec![1]);
+    /// ```
+    #[cfg(feature = "bytes")]
+    pub fn unmarshal_bytes(buf: bytes::Bytes) -> Result<Self, PfcpError> {
+        Self::unmarshal(&buf)
+    }
+
     // --- Value Accessors ---
 
     pub fn as_u8(&self) -> Result<u8, PfcpError> {
@@ -1334,19 +2006,59 @@ impl Ie {
             })
     }
 
+    /// Returns the child IEs of a grouped IE, parsing and caching them on first use.
+    ///
+    /// With the `eager-parse` feature, children of common grouped IEs are
+    /// already populated by [`unmarshal`](Ie::unmarshal) and returned as-is.
     pub fn as_ies(&mut self) -> Result<&[Ie], PfcpError> {
-        if !self.child_ies.is_empty() {
-            return Ok(&self.child_ies);
+        Ok(self.cached_children()?)
+    }
+
+    /// Returns the child IEs of a grouped IE for in-place modification,
+    /// parsing and caching them on first use.
+    ///
+    /// Changes to the returned list are **not** reflected in [`payload`](Ie::payload)
+    /// (and therefore in [`marshal`](Ie::marshal)) until
+    /// [`reserialize_children`](Ie::reserialize_children) is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::{Ie, IeType};
+    ///
+    /// let mut grouped = Ie::new_grouped(
+    ///     IeType::CreateFar,
+    ///     vec![Ie::new(IeType::FarId, vec![0, 0, 0, 1])],
+    /// );
+    /// grouped.as_ies_mut()?[0].payload = vec![0, 0, 0, 2];
+    /// grouped.reserialize_children();
+    /// assert_eq!(grouped.payload, vec![0x00, 0x6C, 0x00, 0x04, 0, 0, 0, 2]);
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    pub fn as_ies_mut(&mut self) -> Result<&mut Vec<Ie>, PfcpError> {
+        self.cached_children()
+    }
+
+    /// Returns the child IE cache, parsing the payload only if it has not
+    /// been parsed yet.
+    fn cached_children(&mut self) -> Result<&mut Vec<Ie>, PfcpError> {
+        match &mut self.child_ies {
+            Some(children) => Ok(children),
+            cache @ None => Ok(cache.insert(parse_ie_list(&self.payload)?)),
         }
+    }
 
-        let mut offset = 0;
-        while offset < self.payload.len() {
-            let ie = Ie::unmarshal(&self.payload[offset..])?;
-            let ie_len = ie.len() as usize;
-            self.child_ies.push(ie);
-            offset += ie_len;
+    /// Rebuilds the payload from the cached child IEs.
+    ///
+    /// Must be called after modifying children through
+    /// [`as_ies_mut`](Ie::as_ies_mut), before the IE is marshaled. Nested
+    /// grouped children are encoded from their own payloads, so modified
+    /// grandchildren must be reserialized bottom-up. Does nothing if the
+    /// children have never been parsed.
+    pub fn reserialize_children(&mut self) {
+        if let Some(children) = &self.child_ies {
+            self.payload = marshal_ies(children);
         }
-        Ok(&self.child_ies)
     }
 
     /// Parse the IE payload into a strongly-typed representation.
@@ -1395,7 +2107,7 @@ impl Ie {
 /// ];
 ///
 /// let marshaled = marshal_ies(&ies);
-/// let expected_len: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+/// let expected_len: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
 /// assert_eq!(marshaled.len(), expected_len);
 /// ```
 ///
@@ -1405,7 +2117,7 @@ impl Ie {
 /// avoiding multiple reallocations during marshaling. It's equivalent to
 /// the pattern used in Phase 1 capacity optimization but extracted for reuse.
 pub fn marshal_ies(ies: &[Ie]) -> Vec<u8> {
-    let capacity: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+    let capacity: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
     let mut data = Vec::with_capacity(capacity);
     for ie in ies {
         data.extend_from_slice(&ie.marshal());
@@ -1413,6 +2125,42 @@ pub fn marshal_ies(ies: &[Ie]) -> Vec<u8> {
     data
 }
 
+/// Parses a sequence of IEs, such as a grouped IE payload, into a vector.
+///
+/// Parsing stops cleanly at the end of `data`; a truncated or malformed IE
+/// returns an error instead.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::{marshal_ies, parse_ie_list, Ie, IeType};
+///
+/// let ies = vec![
+///     Ie::new(IeType::PdrId, vec![0x00, 0x01]),
+///     Ie::new(IeType::FarId, vec![0x00, 0x00, 0x00, 0x02]),
+/// ];
+///
+/// let parsed = parse_ie_list(&marshal_ies(&ies))?;
+/// assert_eq!(parsed, ies);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+pub fn parse_ie_list(data: &[u8]) -> Result<Vec<Ie>, PfcpError> {
+    let mut ies = Vec::new();
+    parse_ie_list_into(data, &mut ies)?;
+    Ok(ies)
+}
+
+/// Parses a sequence of IEs, appending them to `sink`.
+///
+/// Like [`parse_ie_list`] but reuses an existing vector. On error, IEs parsed
+/// before the failure remain in `sink`.
+pub fn parse_ie_list_into(data: &[u8], sink: &mut Vec<Ie>) -> Result<(), PfcpError> {
+    for ie in IeIterator::new(data) {
+        sink.push(ie?);
+    }
+    Ok(())
+}
+
 /// Iterator over Information Elements in a payload.
 ///
 /// Automatically tracks byte offset and unmarshals IEs sequentially.
@@ -1482,7 +2230,7 @@ impl<'a> Iterator for IeIterator<'a> {
 
         match Ie::unmarshal(&self.payload[self.offset..]) {
             Ok(ie) => {
-                let ie_len = ie.len() as usize;
+                let ie_len = ie.encoded_size();
                 self.offset += ie_len;
                 Some(Ok(ie))
             }
@@ -1547,8 +2295,7 @@ pub mod builder_support {
     impl IntoIe for Ipv4Addr {
         fn into_ie(self) -> Ie {
             use crate::ie::source_ip_address::SourceIpAddress;
-            let ip = SourceIpAddress::new(Some(self), None);
-            ip.to_ie()
+            SourceIpAddress::ipv4(self).to_ie()
         }
     }
 
@@ -1556,8 +2303,7 @@ pub mod builder_support {
     impl IntoIe for Ipv6Addr {
         fn into_ie(self) -> Ie {
             use crate::ie::source_ip_address::SourceIpAddress;
-            let ip = SourceIpAddress::new(None, Some(self));
-            ip.to_ie()
+            SourceIpAddress::ipv6(self).to_ie()
         }
     }
 
@@ -1749,6 +2495,25 @@ pub mod builder_support {
             Ie::new(IeType::UeIpAddress, ue_ip.marshal())
         }
     }
+
+    /// SourceInterfaceValue → SourceInterface IE
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rs_pfcp::ie::source_interface::SourceInterfaceValue;
+    /// use rs_pfcp::ie::{IeType, IntoIe};
+    ///
+    /// let value: SourceInterfaceValue = "Access".parse()?;
+    /// let ie = value.into_ie();
+    /// assert_eq!(ie.ie_type, IeType::SourceInterface);
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    impl IntoIe for crate::ie::source_interface::SourceInterfaceValue {
+        fn into_ie(self) -> Ie {
+            crate::ie::source_interface::SourceInterface::new(self).to_ie()
+        }
+    }
 }
 
 // Re-export IntoIe for convenience
@@ -1855,6 +2620,8 @@ impl_parse_ie!(
     packet_delay_thresholds::PacketDelayThresholds,
     qos_monitoring_measurement::QosMonitoringMeasurement,
     remote_gtpu_peer::RemoteGtpuPeer,
+    user_plane_inactivity_timer::UserPlaneInactivityTimer,
+    user_plane_ip_resource_information::UserPlaneIpResourceInformation,
     reporting_suggestion_info::ReportingSuggestionInfo,
     reporting_thresholds::ReportingThresholds,
     traffic_parameter_threshold::TrafficParameterThreshold,
@@ -1880,6 +2647,79 @@ impl_parse_ie!(
     updated_pdr::UpdatedPdr,
 );
 
+// Implement `From<T> for Ie` for IE types whose `to_ie` cannot fail, so they
+// can be passed wherever an `impl Into<Ie>` is accepted.
+macro_rules! impl_from_for_ie {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for Ie {
+                fn from(ie: $ty) -> Self {
+                    ie.to_ie()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_ie!(
+    access_availability_control_information::AccessAvailabilityControlInformation,
+    access_availability_report::AccessAvailabilityReport,
+    additional_monitoring_time::AdditionalMonitoringTime,
+    aggregated_urrs::AggregatedUrrs,
+    alternative_smf_ip_address::AlternativeSmfIpAddress,
+    application_ids_pfds::ApplicationIdsPfds,
+    cp_ip_address::CpIpAddress,
+    create_bar::CreateBar,
+    create_bridge_info_for_tsc::CreateBridgeInfoForTsc,
+    create_far::CreateFar,
+    create_pdr::CreatePdr,
+    create_qer::CreateQer,
+    create_traffic_endpoint::CreateTrafficEndpoint,
+    create_urr::CreateUrr,
+    created_bridge_info_for_tsc::CreatedBridgeInfoForTsc,
+    created_pdr::CreatedPdr,
+    created_traffic_endpoint::CreatedTrafficEndpoint,
+    downlink_data_report::DownlinkDataReport,
+    dscp_to_ppi_control_information::DscpToPpiControlInformation,
+    duplicating_parameters::DuplicatingParameters,
+    error_indication_report::ErrorIndicationReport,
+    ethernet_context_information::EthernetContextInformation,
+    ethernet_packet_filter::EthernetPacketFilter,
+    forwarding_parameters::ForwardingParameters,
+    group_id::GroupId,
+    inactivity_detection_time::InactivityDetectionTime,
+    ip_multicast_addressing_info::IpMulticastAddressingInfo,
+    join_ip_multicast_information_within_usage_report::JoinIpMulticastInformationWithinUsageReport,
+    leave_ip_multicast_information_within_usage_report::LeaveIpMulticastInformationWithinUsageReport,
+    load_control_information::LoadControlInformation,
+    measurement_period::MeasurementPeriod,
+    monitoring_time::MonitoringTime,
+    partial_failure_information::PartialFailureInformation,
+    pdi::Pdi,
+    peer_up_restart_report::PeerUpRestartReport,
+    pfd_context::PfdContext,
+    query_urr::QueryUrr,
+    quota_holding_time::QuotaHoldingTime,
+    session_report::SessionReport,
+    ue_ip_address_pool_information::UeIpAddressPoolInformation,
+    ue_ip_address_usage_information::UeIpAddressUsageInformation,
+    update_bar::UpdateBar,
+    update_bar_within_session_report_response::UpdateBarWithinSessionReportResponse,
+    update_far::UpdateFar,
+    update_forwarding_parameters::UpdateForwardingParameters,
+    update_pdr::UpdatePdr,
+    update_qer::UpdateQer,
+    update_traffic_endpoint::UpdateTrafficEndpoint,
+    update_urr::UpdateUrr,
+    updated_pdr::UpdatedPdr,
+    usage_report::UsageReport,
+    usage_report_sdr::UsageReportSdr,
+    usage_report_smr::UsageReportSmr,
+    usage_report_srr::UsageReportSrr,
+    user_plane_inactivity_timer::UserPlaneInactivityTimer,
+    user_plane_ip_resource_information::UserPlaneIpResourceInformation,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1998,6 +2838,8 @@ mod tests {
         let ie = result.unwrap();
         assert_eq!(ie.ie_type, IeType::ForwardingPolicy);
         assert_eq!(ie.payload.len(), 0);
+        let fp = forwarding_policy::ForwardingPolicy::unmarshal(&ie.payload).unwrap();
+        assert_eq!(fp.identifier(), "");
     }
 
     #[test]
@@ -2153,6 +2995,30 @@ mod tests {
         assert_eq!(IeType::from(65535), IeType::Unknown);
     }
 
+    #[test]
+    fn test_ie_type_try_from_code() {
+        assert_eq!(
+            IeType::try_from_code(389).unwrap(),
+            IeType::ReportingControlInformation
+        );
+        assert_eq!(IeType::try_from_code(1).unwrap(), IeType::CreatePdr);
+        // Assigned by the spec but not modelled here
+        assert_eq!(IeType::try_from_code(360).unwrap(), IeType::Unknown);
+        // Vendor-specific range
+        assert_eq!(IeType::try_from_code(0x8001).unwrap(), IeType::Unknown);
+        assert_eq!(IeType::try_from_code(0xFFFF).unwrap(), IeType::Unknown);
+
+        for v in [0, 390, 999, 0x7FFF] {
+            assert!(
+                matches!(
+                    IeType::try_from_code(v),
+                    Err(PfcpError::InvalidValue { .. })
+                ),
+                "{v}"
+            );
+        }
+    }
+
     #[test]
     fn test_ie_type_to_u16_round_trip() {
         // Test that IE types can be converted to u16 and back
@@ -2186,7 +3052,7 @@ mod tests {
         assert_eq!(ie.ie_type, IeType::Cause);
         assert_eq!(ie.enterprise_id, None);
         assert_eq!(ie.payload, payload);
-        assert_eq!(ie.child_ies.len(), 0);
+        assert!(ie.child_ies.is_none());
         assert!(!ie.is_vendor_specific());
     }
 
@@ -2201,6 +3067,133 @@ mod tests {
         assert!(ie.is_vendor_specific());
     }
 
+    #[test]
+    fn test_ie_is_unknown() {
+        let unknown = Ie::unmarshal(&[0x27, 0x0F, 0x00, 0x01, 0xFF]).unwrap();
+        assert!(unknown.is_unknown());
+
+        let known = Ie::new(IeType::Cause, vec![1]);
+        assert!(!known.is_unknown());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_ie_write_to_bytes_matches_marshal_into() {
+        let ies = [
+            Ie::new(IeType::Cause, vec![1]),
+            Ie::new(IeType::NetworkInstance, vec![]),
+            Ie::new_vendor_specific(IeType::Unknown, 10415, vec![0xAA, 0xBB]),
+        ];
+        for ie in &ies {
+            let mut vec = Vec::new();
+            ie.marshal_into(&mut vec);
+            let mut buf = bytes::BytesMut::new();
+            ie.write_to_bytes(&mut buf);
+            assert_eq!(&buf[..], &vec[..]);
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_ie_unmarshal_bytes_round_trip() {
+        let original = Ie::new(IeType::PdrId, vec![0x00, 0x01]);
+        let mut buf = bytes::BytesMut::new();
+        original.write_to_bytes(&mut buf);
+
+        let parsed = Ie::unmarshal_bytes(buf.freeze()).unwrap();
+        assert_eq!(parsed, original);
+
+        let result = Ie::unmarshal_bytes(bytes::Bytes::from_static(&[0x00, 0x38, 0x00]));
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
+    }
+
+    #[cfg(feature = "eager-parse")]
+    #[test]
+    fn test_ie_unmarshal_eager_parse_nested_create_pdr() {
+        use crate::ie::create_pdr::CreatePdrBuilder;
+        use crate::ie::far_id::FarId;
+        use crate::ie::pdi::Pdi;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+
+        let pdr = CreatePdrBuilder::new(PdrId::new(1))
+            .precedence(Precedence::new(100))
+            .pdi(Pdi::uplink_access())
+            .far_id(FarId::new(1))
+            .build()
+            .unwrap();
+        let bytes = pdr.to_ie().marshal();
+
+        let ie = Ie::unmarshal(&bytes).unwrap();
+        let children = ie.child_ies.as_ref().unwrap();
+        let child_types: Vec<IeType> = children.iter().map(|c| c.ie_type).collect();
+        assert_eq!(
+            child_types,
+            vec![
+                IeType::PdrId,
+                IeType::Precedence,
+                IeType::Pdi,
+                IeType::FarId
+            ]
+        );
+
+        // Nested grouped children are parsed as well
+        let pdi_children = children[2].child_ies.as_ref().unwrap();
+        assert_eq!(pdi_children.len(), 1);
+        assert_eq!(pdi_children[0].ie_type, IeType::SourceInterface);
+
+        // Leaf IEs are left untouched
+        assert!(children[0].child_ies.is_none());
+
+        // as_ies() returns the pre-parsed cache
+        let cached = children.clone();
+        let mut ie = ie;
+        assert_eq!(ie.as_ies().unwrap(), &cached[..]);
+        assert_eq!(ie, pdr.to_ie());
+    }
+
+    #[cfg(feature = "eager-parse")]
+    #[test]
+    fn test_ie_unmarshal_eager_parse_deeply_nested_create_pdr() {
+        // About 64 KB of Create PDR headers, each nested in the previous one
+        let depth = 16_000;
+        let mut bytes = Vec::with_capacity(depth * 4);
+        for level in 0..depth {
+            let len = ((depth - level - 1) * 4) as u16;
+            bytes.extend_from_slice(&[0x00, 0x01]);
+            bytes.extend_from_slice(&len.to_be_bytes());
+        }
+
+        // A Create PDR is not a valid child of a Create PDR, so only the
+        // outermost level is descended into
+        let ie = Ie::unmarshal(&bytes).unwrap();
+        let children = ie.child_ies.as_ref().unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].ie_type, IeType::CreatePdr);
+        assert!(children[0].child_ies.is_none());
+    }
+
+    #[cfg(feature = "eager-parse")]
+    #[test]
+    fn test_ie_eager_parse_depth_limit() {
+        let source_interface = Ie::new(IeType::SourceInterface, vec![0]);
+        let pdi = Ie::new(IeType::Pdi, source_interface.marshal());
+        let ie = Ie::new(IeType::CreatePdr, pdi.marshal());
+        assert!(ie.clone().parse_children_eagerly(0).is_ok());
+        assert!(matches!(
+            ie.parse_children_eagerly(MAX_EAGER_PARSE_DEPTH),
+            Err(PfcpError::InvalidValue { .. })
+        ));
+    }
+
+    #[cfg(feature = "eager-parse")]
+    #[test]
+    fn test_ie_unmarshal_eager_parse_rejects_malformed_child() {
+        // Create PDR whose single child declares more payload than present
+        let bytes = [0x00, 0x01, 0x00, 0x05, 0x00, 0x38, 0x00, 0x04, 0x00];
+        assert!(Ie::unmarshal(&bytes).is_err());
+    }
+
     #[test]
     fn test_ie_new_grouped() {
         // Create child IEs
@@ -2211,7 +3204,7 @@ mod tests {
 
         assert_eq!(grouped.ie_type, IeType::CreatePdr);
         assert_eq!(grouped.enterprise_id, None);
-        assert_eq!(grouped.child_ies.len(), 2);
+        assert_eq!(grouped.child_ies.as_ref().unwrap().len(), 2);
 
         // Payload should contain marshaled child IEs
         let expected_payload = {
@@ -2250,17 +3243,64 @@ mod tests {
     // ========================================================================
 
     #[test]
-    fn test_ie_len_simple() {
+    fn test_ie_encoded_size_simple() {
         let ie = Ie::new(IeType::Cause, vec![0x01]);
-        // Type (2) + Length (2) + Payload (1) = 5, but len() returns header+payload size
-        assert_eq!(ie.len(), 5);
+        // Type (2) + Length (2) + Payload (1) = 5
+        assert_eq!(ie.encoded_size(), 5);
+        assert_eq!(ie.marshal().len(), 5);
     }
 
     #[test]
-    fn test_ie_len_vendor_specific() {
+    fn test_ie_encoded_size_vendor_specific() {
         let ie = Ie::new_vendor_specific(IeType::Unknown, 123, vec![0xAA, 0xBB]);
         // Type (2) + Length (2) + Enterprise ID (2) + Payload (2) = 8
-        assert_eq!(ie.len(), 8);
+        assert_eq!(ie.encoded_size(), 8);
+        assert_eq!(ie.marshal().len(), 8);
+    }
+
+    #[test]
+    fn test_ie_marshal_stack_matches_marshal() {
+        let ie = Ie::new(IeType::Cause, vec![0x01]);
+        let (buf, len) = ie.marshal_stack();
+        assert_eq!(&buf[..len], ie.marshal().as_slice());
+
+        let vendor = Ie::new_vendor_specific(IeType::Unknown, 123, vec![0xAA, 0xBB]);
+        let (buf, len) = vendor.marshal_stack();
+        assert_eq!(&buf[..len], vendor.marshal().as_slice());
+
+        let full = Ie::new(IeType::Unknown, vec![0x5A; Ie::STACK_MARSHAL_SIZE - 4]);
+        let (buf, len) = full.marshal_stack();
+        assert_eq!(len, Ie::STACK_MARSHAL_SIZE);
+        assert_eq!(&buf[..], full.marshal().as_slice());
+    }
+
+    #[test]
+    #[should_panic(expected = "larger than the 64-byte stack buffer")]
+    fn test_ie_marshal_stack_panics_when_too_large() {
+        let ie = Ie::new(IeType::Unknown, vec![0; Ie::STACK_MARSHAL_SIZE - 3]);
+        let _ = ie.marshal_stack();
+    }
+
+    #[test]
+    fn test_ie_encoded_size_exceeds_u16() {
+        // 4-byte header + 65532-byte payload is one byte past u16::MAX
+        let ie = Ie::new(IeType::Unknown, vec![0; 65532]);
+        assert_eq!(ie.encoded_size(), 65536);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_ie_len_deprecated_alias() {
+        let ie = Ie::new(IeType::Cause, vec![0x01]);
+        assert_eq!(ie.len(), 5);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    #[should_panic(expected = "exceeds u16::MAX")]
+    fn test_ie_len_panics_on_overflow() {
+        let ie = Ie::new(IeType::Unknown, vec![0; 65532]);
+        let _ = ie.len();
     }
 
     #[test]
@@ -2372,6 +3412,34 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_ie_unmarshal_declared_length_exceeds_buffer() {
+        // 6-byte buffer declaring a 65000-byte payload must error, not panic
+        let malformed = vec![
+            0x00, 0x13, // Type: Cause
+            0xFD, 0xE8, // Length: 65000
+            0x01, 0x02, // Only 2 payload bytes
+        ];
+        let result = Ie::unmarshal(&malformed);
+
+        match result {
+            Err(PfcpError::InvalidLength {
+                expected, actual, ..
+            }) => {
+                assert_eq!(expected, 4 + 65000);
+                assert_eq!(actual, 6);
+            }
+            other => panic!("expected InvalidLength, got {:?}", other),
+        }
+
+        // Same for a vendor-specific IE declaring the maximum length
+        let vendor = vec![0x80, 0x01, 0xFF, 0xFF, 0x28, 0xAF];
+        assert!(matches!(
+            Ie::unmarshal(&vendor),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+    }
+
     #[test]
     fn test_ie_unmarshal_vendor_specific_too_short() {
         // Buffer too short for enterprise ID parsing
@@ -2521,6 +3589,86 @@ mod tests {
         assert_eq!(children2[0].ie_type, IeType::FarId);
     }
 
+    #[test]
+    fn test_ie_as_ies_mut_modify_create_pdr_precedence() {
+        use crate::ie::create_pdr::{CreatePdr, CreatePdrBuilder};
+        use crate::ie::far_id::FarId;
+        use crate::ie::pdi::Pdi;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+
+        let pdr = CreatePdrBuilder::new(PdrId::new(1))
+            .precedence(Precedence::new(100))
+            .pdi(Pdi::uplink_access())
+            .far_id(FarId::new(1))
+            .build()
+            .unwrap();
+        let mut ie = Ie::unmarshal(&pdr.to_ie().marshal()).unwrap();
+
+        let precedence = ie
+            .as_ies_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|child| child.ie_type == IeType::Precedence)
+            .unwrap();
+        *precedence = Precedence::new(0x0BAD_CAFE).to_ie();
+
+        // The payload is unchanged until the children are reserialized
+        assert_eq!(
+            CreatePdr::unmarshal(&ie.payload).unwrap().precedence,
+            Precedence::new(100)
+        );
+
+        ie.reserialize_children();
+        let marshaled = ie.marshal();
+        assert!(marshaled
+            .windows(8)
+            .any(|w| w == [0x00, 0x1D, 0x00, 0x04, 0x0B, 0xAD, 0xCA, 0xFE]));
+
+        let reparsed = Ie::unmarshal(&marshaled).unwrap();
+        let modified = CreatePdr::unmarshal(&reparsed.payload).unwrap();
+        assert_eq!(modified.precedence, Precedence::new(0x0BAD_CAFE));
+        assert_eq!(modified.pdr_id, pdr.pdr_id);
+        assert_eq!(modified.far_id, pdr.far_id);
+    }
+
+    #[test]
+    fn test_ie_as_ies_mut_push_child() {
+        let mut grouped = Ie::new_grouped(
+            IeType::CreateFar,
+            vec![Ie::new(IeType::FarId, vec![0, 0, 0, 1])],
+        );
+        grouped
+            .as_ies_mut()
+            .unwrap()
+            .push(Ie::new(IeType::ApplyAction, vec![0x02]));
+        grouped.reserialize_children();
+
+        let mut reparsed = Ie::unmarshal(&grouped.marshal()).unwrap();
+        let children = reparsed.as_ies().unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[1].ie_type, IeType::ApplyAction);
+    }
+
+    #[test]
+    fn test_ie_as_ies_mut_clear_children() {
+        let mut grouped = Ie::unmarshal(
+            &Ie::new_grouped(
+                IeType::CreateFar,
+                vec![Ie::new(IeType::FarId, vec![0, 0, 0, 1])],
+            )
+            .marshal(),
+        )
+        .unwrap();
+        grouped.as_ies_mut().unwrap().clear();
+
+        // The emptied cache is kept rather than re-parsed from the payload
+        assert!(grouped.as_ies().unwrap().is_empty());
+        grouped.reserialize_children();
+        assert!(grouped.payload.is_empty());
+        assert!(grouped.as_ies_mut().unwrap().is_empty());
+    }
+
     #[test]
     fn test_ie_as_ies_empty_payload() {
         let mut ie = Ie::new(IeType::CreatePdr, vec![]);
@@ -2581,7 +3729,8 @@ mod tests {
     fn test_ie_all_ie_types_round_trip() {
         // Test a sample of all IE type categories
         let test_types = vec![
-            (IeType::CreatePdr, vec![0x01, 0x02]),
+            // A PDR ID child, so the payload also parses with eager-parse
+            (IeType::CreatePdr, vec![0x00, 0x38, 0x00, 0x02, 0x00, 0x01]),
             (IeType::Cause, vec![0x01]),
             (IeType::NodeId, vec![0x00, 0x01, 0x02, 0x03, 0x04]),
             (IeType::Fseid, vec![0x01; 8]),
@@ -2814,6 +3963,19 @@ mod tests {
             let explicit_ue_ip = UeIpAddress::new(Some(ipv4), Some(ipv6));
             assert_eq!(ue_ip, explicit_ue_ip);
         }
+
+        #[test]
+        fn test_into_ie_source_interface_value() {
+            use crate::ie::source_interface::{SourceInterface, SourceInterfaceValue};
+
+            let ie = SourceInterfaceValue::Core.into_ie();
+            assert_eq!(ie.ie_type, IeType::SourceInterface);
+            assert_eq!(ie.payload, vec![1]);
+            assert_eq!(
+                SourceInterface::unmarshal(&ie.payload).unwrap().value,
+                SourceInterfaceValue::Core
+            );
+        }
     }
 
     /// Tests for grouped IE helper functions (Phase 2)
@@ -2837,7 +3999,7 @@ mod tests {
             assert!(result.len() >= 4);
 
             // Verify length matches sum of IE lengths
-            let expected_len: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+            let expected_len: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
             assert_eq!(result.len(), expected_len);
         }
 
@@ -2851,7 +4013,7 @@ mod tests {
             let result = marshal_ies(&ies);
 
             // Verify length matches sum of all IE lengths
-            let expected_len: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+            let expected_len: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
             assert_eq!(result.len(), expected_len);
         }
 
@@ -3017,12 +4179,12 @@ mod tests {
             // First IE
             assert_eq!(iter.offset, 0);
             let ie1 = iter.next().unwrap().unwrap();
-            let ie1_len = ie1.len() as usize;
+            let ie1_len = ie1.encoded_size();
             assert_eq!(iter.offset, ie1_len);
 
             // Second IE
             let ie2 = iter.next().unwrap().unwrap();
-            assert_eq!(iter.offset, ie1_len + ie2.len() as usize);
+            assert_eq!(iter.offset, ie1_len + ie2.encoded_size());
 
             // End of payload
             assert_eq!(iter.offset, payload.len());
@@ -3111,4 +4273,91 @@ mod tests {
         let cause_ref = ie.parse::<Cause>().unwrap();
         assert_eq!(cause_ref.value, CauseValue::RequestRejected);
     }
+
+    #[test]
+    fn test_parse_ie_list_round_trip() {
+        let ies = vec![
+            Ie::new(IeType::PdrId, vec![0x00, 0x01]),
+            Ie::new(IeType::Precedence, vec![0x00, 0x00, 0x00, 0x64]),
+            Ie::new(IeType::FarId, vec![0x00, 0x00, 0x00, 0x02]),
+        ];
+        let data = marshal_ies(&ies);
+
+        assert_eq!(parse_ie_list(&data).unwrap(), ies);
+        assert!(parse_ie_list(&[]).unwrap().is_empty());
+
+        let mut sink = vec![Ie::new(IeType::BarId, vec![0x01])];
+        parse_ie_list_into(&data, &mut sink).unwrap();
+        assert_eq!(sink.len(), 4);
+        assert_eq!(&sink[1..], &ies[..]);
+    }
+
+    #[test]
+    fn test_parse_ie_list_truncated() {
+        let data = marshal_ies(&[
+            Ie::new(IeType::PdrId, vec![0x00, 0x01]),
+            Ie::new(IeType::FarId, vec![0x00, 0x00, 0x00, 0x02]),
+        ]);
+        let truncated = &data[..data.len() - 1];
+
+        assert!(parse_ie_list(truncated).is_err());
+
+        // IEs decoded before the failure are kept
+        let mut sink = Vec::new();
+        assert!(parse_ie_list_into(truncated, &mut sink).is_err());
+        assert_eq!(sink, vec![Ie::new(IeType::PdrId, vec![0x00, 0x01])]);
+    }
+
+    #[test]
+    fn test_parse_ie_list_fuzz_random_bytes() {
+        // Deterministic xorshift so failures are reproducible
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10_000 {
+            let len = (next() % 256) as usize;
+            let data: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+
+            // Must never panic; any error is acceptable
+            let _ = parse_ie_list(&data);
+            let mut sink = Vec::new();
+            let _ = parse_ie_list_into(&data, &mut sink);
+        }
+    }
+
+    #[test]
+    fn test_parse_ie_list_fuzz_mutated_valid_input() {
+        let data = marshal_ies(&[
+            Ie::new(IeType::PdrId, vec![0x00, 0x01]),
+            Ie::new_grouped(
+                IeType::Pdi,
+                vec![Ie::new(IeType::SourceInterface, vec![0x00])],
+            ),
+            Ie::new_vendor_specific(IeType::Unknown, 10415, vec![0xAA]),
+        ]);
+
+        let mut state: u64 = 0x0123_4567_89AB_CDEF;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10_000 {
+            let mut mutated = data.clone();
+            for _ in 0..=(next() % 4) {
+                let pos = (next() as usize) % mutated.len();
+                mutated[pos] = next() as u8;
+            }
+            let cut = (next() as usize) % (mutated.len() + 1);
+
+            let _ = parse_ie_list(&mutated[..cut]);
+        }
+    }
 }
@@ -1,13 +1,14 @@
 // src/ie/monitoring_time.rs
 
 //! Monitoring Time Information Element.
+//!
+//! Per 3GPP TS 29.244, the time at which the UP function re-applies the
+//! volume or time threshold, as a 32-bit NTP time stamp.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
-
-// NTP epoch (1900-01-01T00:00:00Z) is 2208988800 seconds before the Unix epoch (1970-01-01T00:00:00Z).
-const NTP_EPOCH_OFFSET: u64 = 2208988800;
+use crate::ie::ntp_timestamp::NtpTimestamp;
+use crate::ie::{Ie, IeType};
+use std::time::SystemTime;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MonitoringTime {
@@ -19,42 +20,33 @@ impl MonitoringTime {
         MonitoringTime { timestamp }
     }
 
-    pub fn marshal(&self) -> [u8; 8] {
-        let unix_timestamp_secs = self
-            .timestamp
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
-        let ntp_timestamp = unix_timestamp_secs + NTP_EPOCH_OFFSET;
-        ntp_timestamp.to_be_bytes()
+    /// Marshals the time stamp into its 4-byte NTP encoding.
+    pub fn marshal(&self) -> [u8; 4] {
+        NtpTimestamp::from_system_time(self.timestamp).to_be_bytes()
     }
 
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 8 {
+        if data.len() < 4 {
             return Err(PfcpError::invalid_length(
                 "Monitoring Time",
                 IeType::MonitoringTime,
-                8,
+                4,
                 data.len(),
             ));
         }
-        let ntp_timestamp = u64::from_be_bytes(data[0..8].try_into().unwrap());
-        if ntp_timestamp < NTP_EPOCH_OFFSET {
-            return Err(PfcpError::invalid_value(
-                "timestamp",
-                ntp_timestamp.to_string(),
-                "NTP timestamp is before Unix epoch",
-            ));
-        }
-        let unix_timestamp = ntp_timestamp - NTP_EPOCH_OFFSET;
-        let timestamp = UNIX_EPOCH + Duration::from_secs(unix_timestamp);
+        let timestamp = NtpTimestamp::from_be_bytes(data).to_system_time("Monitoring Time")?;
         Ok(MonitoringTime { timestamp })
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::MonitoringTime, self.marshal().to_vec())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
 
     #[test]
     fn test_monitoring_time_marshal_unmarshal() {
@@ -76,7 +68,7 @@ mod tests {
 
     #[test]
     fn test_monitoring_time_unmarshal_invalid_data() {
-        let data = [0; 7];
+        let data = [0; 3];
         let result = MonitoringTime::unmarshal(&data);
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -90,18 +82,30 @@ mod tests {
         {
             assert_eq!(ie_name, "Monitoring Time");
             assert_eq!(ie_type, IeType::MonitoringTime);
-            assert_eq!(expected, 8);
-            assert_eq!(actual, 7);
+            assert_eq!(expected, 4);
+            assert_eq!(actual, 3);
         }
     }
 
     #[test]
     fn test_monitoring_time_unmarshal_before_unix_epoch() {
-        // NTP timestamp before Unix epoch (less than NTP_EPOCH_OFFSET)
-        let data = 1000u64.to_be_bytes();
+        // MSB set, so era 0: 1968-01-20, before the Unix epoch
+        let data = 0x8000_0000u32.to_be_bytes();
         let result = MonitoringTime::unmarshal(&data);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, PfcpError::InvalidValue { .. }));
     }
+
+    #[test]
+    fn test_monitoring_time_wire_format() {
+        let mt = MonitoringTime::new(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        // 1_700_000_000 + 2_208_988_800 NTP seconds
+        assert_eq!(mt.marshal(), 3_908_988_800u32.to_be_bytes());
+
+        let ie = Ie::from(mt);
+        assert_eq!(ie.ie_type, IeType::MonitoringTime);
+        assert_eq!(ie.payload.len(), 4);
+        assert_eq!(MonitoringTime::unmarshal(&ie.payload).unwrap(), mt);
+    }
 }
@@ -1,7 +1,8 @@
 //! Multiplier Information Element
 //!
-//! The Multiplier IE is used in Usage Reporting Rules to specify a factor
-//! for multiplying usage quota values.
+//! The Multiplier IE is used in Aggregated URRs to specify the factor by
+//! which the usage measured for a URR is multiplied before it is added to
+//! the aggregated usage.
 //! Per 3GPP TS 29.244 Section 8.2.84.
 
 use crate::error::PfcpError;
@@ -9,24 +10,24 @@ use crate::ie::{Ie, IeType};
 
 /// Multiplier
 ///
-/// Represents a quotient multiplier for usage quota calculation.
-/// Encoded as an unsigned 32-bit integer representing a factor
-/// used in usage reporting quota calculations.
+/// A decimal factor expressed as `value_digits * 10^exponent`, following the
+/// Unit-Value representation used by Diameter credit control (RFC 4006).
 ///
 /// # 3GPP Reference
 /// 3GPP TS 29.244 Section 8.2.84
 ///
 /// # Structure
-/// - 4 bytes: Multiplier value (u32, big-endian)
+/// - 8 bytes: Value-Digits (i64, big-endian)
+/// - 4 bytes: Exponent (i32, big-endian)
 ///
 /// # Examples
 ///
 /// ```
 /// use rs_pfcp::ie::multiplier::Multiplier;
 ///
-/// // Create a multiplier with value 100
-/// let multiplier = Multiplier::new(100);
-/// assert_eq!(multiplier.value(), 100);
+/// // 1.25 = 125 * 10^-2
+/// let multiplier = Multiplier::new(125, -2);
+/// assert_eq!(multiplier.as_f64(), 1.25);
 ///
 /// // Marshal and unmarshal
 /// let bytes = multiplier.marshal();
@@ -36,52 +37,67 @@ use crate::ie::{Ie, IeType};
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Multiplier {
-    /// Multiplier quotient value
-    value: u32,
+    /// Significant digits of the factor
+    pub value_digits: i64,
+    /// Power of ten applied to `value_digits`
+    pub exponent: i32,
 }
 
 impl Multiplier {
     /// Create a new Multiplier
     ///
     /// # Arguments
-    /// * `value` - Multiplier quotient value (0 to u32::MAX)
+    /// * `value_digits` - Significant digits of the factor
+    /// * `exponent` - Power of ten applied to `value_digits`
     ///
     /// # Example
     /// ```
     /// use rs_pfcp::ie::multiplier::Multiplier;
     ///
-    /// let multiplier = Multiplier::new(50);
-    /// assert_eq!(multiplier.value(), 50);
+    /// let multiplier = Multiplier::new(5, -1);
+    /// assert_eq!(multiplier.as_f64(), 0.5);
     /// ```
-    pub fn new(value: u32) -> Self {
-        Multiplier { value }
+    pub fn new(value_digits: i64, exponent: i32) -> Self {
+        Multiplier {
+            value_digits,
+            exponent,
+        }
     }
 
-    /// Get the multiplier value
+    /// Get the multiplier as a floating point factor
+    ///
+    /// Computes `value_digits * 10^exponent`. Large values may lose precision.
     ///
     /// # Example
     /// ```
     /// use rs_pfcp::ie::multiplier::Multiplier;
     ///
-    /// let multiplier = Multiplier::new(200);
-    /// assert_eq!(multiplier.value(), 200);
+    /// assert_eq!(Multiplier::new(3, 2).as_f64(), 300.0);
     /// ```
-    pub fn value(&self) -> u32 {
-        self.value
+    pub fn as_f64(&self) -> f64 {
+        let scale = 10f64.powi(self.exponent.unsigned_abs().min(i32::MAX as u32) as i32);
+        if self.exponent < 0 {
+            self.value_digits as f64 / scale
+        } else {
+            self.value_digits as f64 * scale
+        }
     }
 
     /// Marshal Multiplier to bytes
     ///
     /// # Returns
-    /// 4-byte vector containing multiplier value (big-endian)
+    /// 12-byte vector containing Value-Digits followed by Exponent (big-endian)
     pub fn marshal(&self) -> Vec<u8> {
-        self.value.to_be_bytes().to_vec()
+        let mut data = Vec::with_capacity(12);
+        data.extend_from_slice(&self.value_digits.to_be_bytes());
+        data.extend_from_slice(&self.exponent.to_be_bytes());
+        data
     }
 
     /// Unmarshal Multiplier from bytes
     ///
     /// # Arguments
-    /// * `data` - Byte slice containing multiplier data (must be at least 4 bytes)
+    /// * `data` - Byte slice containing multiplier data (must be at least 12 bytes)
     ///
     /// # Errors
     /// Returns error if data is too short
@@ -90,26 +106,29 @@ impl Multiplier {
     /// ```
     /// use rs_pfcp::ie::multiplier::Multiplier;
     ///
-    /// let multiplier = Multiplier::new(75000);
+    /// let multiplier = Multiplier::new(75000, -3);
     /// let bytes = multiplier.marshal();
     /// let parsed = Multiplier::unmarshal(&bytes)?;
     /// assert_eq!(multiplier, parsed);
     /// # Ok::<(), rs_pfcp::error::PfcpError>(())
     /// ```
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 4 {
+        if data.len() < 12 {
             return Err(PfcpError::invalid_length(
                 "Multiplier",
                 IeType::Multiplier,
-                4,
+                12,
                 data.len(),
             ));
         }
 
-        let bytes: [u8; 4] = data[0..4].try_into().unwrap();
-        let value = u32::from_be_bytes(bytes);
+        let value_digits = i64::from_be_bytes(data[0..8].try_into().unwrap());
+        let exponent = i32::from_be_bytes(data[8..12].try_into().unwrap());
 
-        Ok(Multiplier { value })
+        Ok(Multiplier {
+            value_digits,
+            exponent,
+        })
     }
 
     /// Convert to generic IE
@@ -119,7 +138,7 @@ impl Multiplier {
     /// use rs_pfcp::ie::multiplier::Multiplier;
     /// use rs_pfcp::ie::IeType;
     ///
-    /// let multiplier = Multiplier::new(100);
+    /// let multiplier = Multiplier::new(1, 0);
     /// let ie = multiplier.to_ie();
     /// assert_eq!(ie.ie_type, IeType::Multiplier);
     /// ```
@@ -134,123 +153,89 @@ mod tests {
 
     #[test]
     fn test_multiplier_new() {
-        let multiplier = Multiplier::new(100);
-        assert_eq!(multiplier.value(), 100);
+        let multiplier = Multiplier::new(100, 0);
+        assert_eq!(multiplier.value_digits, 100);
+        assert_eq!(multiplier.exponent, 0);
     }
 
     #[test]
     fn test_multiplier_marshal_unmarshal() {
-        let original = Multiplier::new(5000);
+        let original = Multiplier::new(5000, -3);
         let bytes = original.marshal();
-        assert_eq!(bytes.len(), 4);
+        assert_eq!(bytes.len(), 12);
 
         let parsed = Multiplier::unmarshal(&bytes).unwrap();
         assert_eq!(original, parsed);
-        assert_eq!(parsed.value(), 5000);
     }
 
     #[test]
-    fn test_multiplier_marshal_zero() {
-        let multiplier = Multiplier::new(0);
-        let bytes = multiplier.marshal();
-        let parsed = Multiplier::unmarshal(&bytes).unwrap();
-
-        assert_eq!(multiplier, parsed);
-        assert_eq!(parsed.value(), 0);
+    fn test_multiplier_one_and_a_quarter() {
+        let multiplier = Multiplier {
+            value_digits: 125,
+            exponent: -2,
+        };
+        assert_eq!(
+            multiplier.marshal(),
+            vec![
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7D, // Value-Digits: 125
+                0xFF, 0xFF, 0xFF, 0xFE, // Exponent: -2
+            ]
+        );
+        assert_eq!(multiplier.as_f64(), 1.25);
     }
 
     #[test]
-    fn test_multiplier_marshal_max_value() {
-        let multiplier = Multiplier::new(u32::MAX);
-        let bytes = multiplier.marshal();
-        let parsed = Multiplier::unmarshal(&bytes).unwrap();
+    fn test_multiplier_as_f64() {
+        assert_eq!(Multiplier::new(1, 0).as_f64(), 1.0);
+        assert_eq!(Multiplier::new(3, 2).as_f64(), 300.0);
+        assert_eq!(Multiplier::new(-5, -1).as_f64(), -0.5);
+        assert_eq!(Multiplier::new(0, 10).as_f64(), 0.0);
+    }
 
-        assert_eq!(multiplier, parsed);
-        assert_eq!(parsed.value(), u32::MAX);
+    #[test]
+    fn test_multiplier_marshal_extremes() {
+        for multiplier in [
+            Multiplier::new(i64::MAX, i32::MAX),
+            Multiplier::new(i64::MIN, i32::MIN),
+        ] {
+            let parsed = Multiplier::unmarshal(&multiplier.marshal()).unwrap();
+            assert_eq!(multiplier, parsed);
+        }
     }
 
     #[test]
     fn test_multiplier_unmarshal_short() {
-        let data = vec![0x00, 0x00, 0x00]; // Only 3 bytes
+        let data = vec![0x00; 11];
         let result = Multiplier::unmarshal(&data);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(matches!(err, PfcpError::InvalidLength { .. }));
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
     }
 
     #[test]
     fn test_multiplier_unmarshal_empty() {
-        let data = vec![];
-        let result = Multiplier::unmarshal(&data);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(matches!(err, PfcpError::InvalidLength { .. }));
+        let result = Multiplier::unmarshal(&[]);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
     }
 
     #[test]
     fn test_multiplier_to_ie() {
-        let multiplier = Multiplier::new(250);
+        let multiplier = Multiplier::new(250, -1);
         let ie = multiplier.to_ie();
         assert_eq!(ie.ie_type, IeType::Multiplier);
-        assert_eq!(ie.payload.len(), 4);
+        assert_eq!(ie.payload.len(), 12);
 
         // Verify IE can be unmarshaled
         let parsed = Multiplier::unmarshal(&ie.payload).unwrap();
         assert_eq!(multiplier, parsed);
     }
 
-    #[test]
-    fn test_multiplier_round_trip_various() {
-        let values = vec![1, 10, 100, 1000, 10000, 100000, 1000000, u32::MAX / 2];
-        for value in values {
-            let original = Multiplier::new(value);
-            let bytes = original.marshal();
-            let parsed = Multiplier::unmarshal(&bytes).unwrap();
-            assert_eq!(original, parsed, "Failed for value {}", value);
-        }
-    }
-
     #[test]
     fn test_multiplier_byte_order() {
         // Verify big-endian encoding
-        let multiplier = Multiplier::new(0x12345678);
-        let bytes = multiplier.marshal();
-        assert_eq!(bytes, vec![0x12, 0x34, 0x56, 0x78]);
-    }
-
-    #[test]
-    fn test_multiplier_clone() {
-        let multiplier1 = Multiplier::new(5000);
-        let multiplier2 = multiplier1;
-        assert_eq!(multiplier1, multiplier2);
-    }
-
-    #[test]
-    fn test_multiplier_5g_usage_reporting() {
-        // Scenario: Apply multiplier to usage quota
-        let multiplier = Multiplier::new(1000);
+        let multiplier = Multiplier::new(0x0102030405060708, 0x090A0B0C);
         let bytes = multiplier.marshal();
-        let parsed = Multiplier::unmarshal(&bytes).unwrap();
-
-        assert_eq!(parsed.value(), 1000);
-        assert_eq!(multiplier, parsed);
-    }
-
-    #[test]
-    fn test_multiplier_real_world_factors() {
-        // Test common multiplier values used in telecom
-        let test_values = vec![
-            (1, "no multiplier"),
-            (10, "10x multiplier"),
-            (100, "100x multiplier"),
-            (1000, "1000x multiplier"),
-        ];
-
-        for (value, _desc) in test_values {
-            let multiplier = Multiplier::new(value);
-            let bytes = multiplier.marshal();
-            let parsed = Multiplier::unmarshal(&bytes).unwrap();
-            assert_eq!(parsed.value(), value);
-        }
+        assert_eq!(
+            bytes,
+            vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C]
+        );
     }
 }
@@ -2,7 +2,7 @@
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 /// Represents a Node ID.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -24,7 +24,26 @@ impl NodeId {
     }
 
     /// Creates a new Node ID from an FQDN.
+    ///
+    /// The FQDN is normalized so that equivalent names encode identically: a
+    /// trailing `.` is removed and the name is lowercased. Use
+    /// [`NodeId::new_fqdn_exact`] to keep the name byte-for-byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::node_id::NodeId;
+    ///
+    /// let node_id = NodeId::new_fqdn("SMF.5G.Example.com.");
+    /// assert_eq!(node_id, NodeId::FQDN("smf.5g.example.com".to_string()));
+    /// ```
     pub fn new_fqdn(fqdn: &str) -> Self {
+        let fqdn = fqdn.strip_suffix('.').unwrap_or(fqdn);
+        NodeId::FQDN(fqdn.to_ascii_lowercase())
+    }
+
+    /// Creates a new Node ID from an FQDN without normalizing it.
+    pub fn new_fqdn_exact(fqdn: &str) -> Self {
         NodeId::FQDN(fqdn.to_string())
     }
 
@@ -115,10 +134,44 @@ impl NodeId {
     }
 }
 
+impl From<Ipv4Addr> for NodeId {
+    fn from(addr: Ipv4Addr) -> Self {
+        NodeId::IPv4(addr)
+    }
+}
+
+impl From<Ipv6Addr> for NodeId {
+    fn from(addr: Ipv6Addr) -> Self {
+        NodeId::IPv6(addr)
+    }
+}
+
+impl From<IpAddr> for NodeId {
+    fn from(addr: IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(v4) => NodeId::IPv4(v4),
+            IpAddr::V6(v6) => NodeId::IPv6(v6),
+        }
+    }
+}
+
+/// Treats the string as an FQDN, normalized as by [`NodeId::new_fqdn`].
+impl From<&str> for NodeId {
+    fn from(fqdn: &str) -> Self {
+        NodeId::new_fqdn(fqdn)
+    }
+}
+
+/// Treats the string as an FQDN, normalized as by [`NodeId::new_fqdn`].
+impl From<String> for NodeId {
+    fn from(fqdn: String) -> Self {
+        NodeId::new_fqdn(&fqdn)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::net::{Ipv4Addr, Ipv6Addr};
 
     #[test]
     fn test_node_id_marshal_unmarshal_ipv4() {
@@ -144,6 +197,41 @@ mod tests {
         assert_eq!(unmarshaled, node_id);
     }
 
+    #[test]
+    fn test_node_id_new_fqdn_normalizes() {
+        let mixed = NodeId::new_fqdn("SMF.5G.EXAMPLE.COM.");
+        let canonical = NodeId::new_fqdn("smf.5g.example.com");
+
+        assert_eq!(mixed.to_ie().marshal(), canonical.to_ie().marshal());
+        assert_eq!(mixed, NodeId::FQDN("smf.5g.example.com".to_string()));
+    }
+
+    #[test]
+    fn test_node_id_new_fqdn_exact_preserves_input() {
+        let node_id = NodeId::new_fqdn_exact("SMF.5G.EXAMPLE.COM.");
+        assert_eq!(node_id, NodeId::FQDN("SMF.5G.EXAMPLE.COM.".to_string()));
+
+        let unmarshaled = NodeId::unmarshal(&node_id.marshal()).unwrap();
+        assert_eq!(unmarshaled, node_id);
+    }
+
+    #[test]
+    fn test_node_id_from_addresses_and_names() {
+        let v4 = Ipv4Addr::new(10, 0, 0, 1);
+        let v6 = Ipv6Addr::LOCALHOST;
+        assert_eq!(NodeId::from(v4), NodeId::IPv4(v4));
+        assert_eq!(NodeId::from(v6), NodeId::IPv6(v6));
+        assert_eq!(NodeId::from(IpAddr::V4(v4)), NodeId::IPv4(v4));
+        assert_eq!(
+            NodeId::from("UPF.Example.com."),
+            NodeId::new_fqdn("upf.example.com")
+        );
+        assert_eq!(
+            NodeId::from("upf.example.com".to_string()),
+            NodeId::FQDN("upf.example.com".to_string())
+        );
+    }
+
     #[test]
     fn test_node_id_unmarshal_invalid_type() {
         let data = [3, 1, 2, 3, 4];
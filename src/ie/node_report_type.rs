@@ -5,62 +5,62 @@
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
+use bitflags::bitflags;
 
-/// Node Report Type
-///
-/// Contains one-byte bitflags for node report type control.
-/// Used to indicate which types of reports are being sent in PFCP Node Report Request messages.
-///
-/// # 3GPP Reference
-/// 3GPP TS 29.244 Section 8.2.69
-///
-/// # Structure
-/// - 1 byte: Report type flags
-///   - Bit 1 (UPFR): User Plane Path Failure Report
-///   - Bit 2 (UPRR): User Plane Path Recovery Report
-///   - Bit 3 (CKDR): Clock Drift Report
-///   - Bit 4 (GPQR): GTP-U Path QoS Report
-///   - Bit 5 (PURR): Peer GTP-U Entity Restart Report
-///   - Bit 6 (VSR): Vendor-Specific Report
-///   - Bits 7-8: Spare (zeros)
-///
-/// # Examples
-///
-/// ```
-/// use rs_pfcp::ie::node_report_type::NodeReportType;
-///
-/// // Create Node Report Type with UPFR flag set
-/// let nrt = NodeReportType::new(0x01);
-/// assert_eq!(nrt.flags(), 0x01);
-/// assert!(nrt.upfr());
-///
-/// // Marshal and unmarshal
-/// let bytes = nrt.marshal();
-/// let parsed = NodeReportType::unmarshal(&bytes)?;
-/// assert_eq!(nrt, parsed);
-/// # Ok::<(), rs_pfcp::error::PfcpError>(())
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct NodeReportType {
-    /// Report type flags (1 byte)
-    flags: u8,
+bitflags! {
+    /// Node Report Type
+    ///
+    /// Contains one-byte bitflags for node report type control.
+    /// Used to indicate which types of reports are being sent in PFCP Node Report Request messages.
+    ///
+    /// # 3GPP Reference
+    /// 3GPP TS 29.244 Section 8.2.69
+    ///
+    /// # Structure
+    /// - 1 byte: Report type flags
+    ///   - Bit 1 (UPFR): User Plane Path Failure Report
+    ///   - Bit 2 (UPRR): User Plane Path Recovery Report
+    ///   - Bit 3 (CKDR): Clock Drift Report
+    ///   - Bit 4 (GPQR): GTP-U Path QoS Report
+    ///   - Bit 5 (PURR): Peer GTP-U Entity Restart Report
+    ///   - Bit 6 (VSR): Vendor-Specific Report
+    ///   - Bits 7-8: Spare (zeros)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::node_report_type::NodeReportType;
+    ///
+    /// // Create Node Report Type with UPFR flag set
+    /// let nrt = NodeReportType::UPFR;
+    /// assert_eq!(nrt.flags(), 0x01);
+    /// assert!(nrt.upfr());
+    ///
+    /// // Combine flags directly
+    /// let combined = NodeReportType::UPFR | NodeReportType::UPRR;
+    /// assert_eq!(combined.marshal(), vec![0x03]);
+    ///
+    /// // Marshal and unmarshal
+    /// let bytes = nrt.marshal();
+    /// let parsed = NodeReportType::unmarshal(&bytes)?;
+    /// assert_eq!(nrt, parsed);
+    /// # Ok::<(), rs_pfcp::error::PfcpError>(())
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct NodeReportType: u8 {
+        const UPFR = 1 << 0; // Bit 1: User Plane Path Failure Report
+        const UPRR = 1 << 1; // Bit 2: User Plane Path Recovery Report
+        const CKDR = 1 << 2; // Bit 3: Clock Drift Report
+        const GPQR = 1 << 3; // Bit 4: GTP-U Path QoS Report
+        const PURR = 1 << 4; // Bit 5: Peer GTP-U Entity Restart Report
+        const VSR = 1 << 5; // Bit 6: Vendor-Specific Report
+    }
 }
 
 impl NodeReportType {
-    /// UPFR flag - User Plane Path Failure Report (bit 1)
-    pub const UPFR: u8 = 0x01;
-    /// UPRR flag - User Plane Path Recovery Report (bit 2)
-    pub const UPRR: u8 = 0x02;
-    /// CKDR flag - Clock Drift Report (bit 3)
-    pub const CKDR: u8 = 0x04;
-    /// GPQR flag - GTP-U Path QoS Report (bit 4)
-    pub const GPQR: u8 = 0x08;
-    /// PURR flag - Peer GTP-U Entity Restart Report (bit 5)
-    pub const PURR: u8 = 0x10;
-    /// VSR flag - Vendor-Specific Report (bit 6)
-    pub const VSR: u8 = 0x20;
-
-    /// Create a new Node Report Type
+    /// Create a new Node Report Type from raw flags
+    ///
+    /// Spare bits are preserved as received.
     ///
     /// # Arguments
     /// * `flags` - Report type flags (1 byte, at least one bit must be set per spec)
@@ -70,110 +70,110 @@ impl NodeReportType {
     /// use rs_pfcp::ie::node_report_type::NodeReportType;
     ///
     /// let nrt = NodeReportType::new(0x01);
-    /// assert_eq!(nrt.flags(), 0x01);
+    /// assert_eq!(nrt, NodeReportType::UPFR);
     /// ```
     pub fn new(flags: u8) -> Self {
-        NodeReportType { flags }
+        NodeReportType::from_bits_retain(flags)
     }
 
     /// Get the report type flags
     pub fn flags(&self) -> u8 {
-        self.flags
+        self.bits()
     }
 
     /// Check if UPFR flag is set (User Plane Path Failure Report)
     pub fn upfr(&self) -> bool {
-        self.flags & Self::UPFR != 0
+        self.contains(Self::UPFR)
     }
 
     /// Check if UPRR flag is set (User Plane Path Recovery Report)
     pub fn uprr(&self) -> bool {
-        self.flags & Self::UPRR != 0
+        self.contains(Self::UPRR)
     }
 
     /// Check if CKDR flag is set (Clock Drift Report)
     pub fn ckdr(&self) -> bool {
-        self.flags & Self::CKDR != 0
+        self.contains(Self::CKDR)
     }
 
     /// Check if GPQR flag is set (GTP-U Path QoS Report)
     pub fn gpqr(&self) -> bool {
-        self.flags & Self::GPQR != 0
+        self.contains(Self::GPQR)
     }
 
     /// Check if PURR flag is set (Peer GTP-U Entity Restart Report)
     pub fn purr(&self) -> bool {
-        self.flags & Self::PURR != 0
+        self.contains(Self::PURR)
     }
 
     /// Check if VSR flag is set (Vendor-Specific Report)
     pub fn vsr(&self) -> bool {
-        self.flags & Self::VSR != 0
+        self.contains(Self::VSR)
     }
 
     /// Set UPFR flag
     pub fn set_upfr(&mut self) {
-        self.flags |= Self::UPFR;
+        self.insert(Self::UPFR);
     }
 
     /// Clear UPFR flag
     pub fn clear_upfr(&mut self) {
-        self.flags &= !Self::UPFR;
+        self.remove(Self::UPFR);
     }
 
     /// Set UPRR flag
     pub fn set_uprr(&mut self) {
-        self.flags |= Self::UPRR;
+        self.insert(Self::UPRR);
     }
 
     /// Clear UPRR flag
     pub fn clear_uprr(&mut self) {
-        self.flags &= !Self::UPRR;
+        self.remove(Self::UPRR);
     }
 
     /// Set CKDR flag
     pub fn set_ckdr(&mut self) {
-        self.flags |= Self::CKDR;
+        self.insert(Self::CKDR);
     }
 
     /// Clear CKDR flag
     pub fn clear_ckdr(&mut self) {
-        self.flags &= !Self::CKDR;
+        self.remove(Self::CKDR);
     }
 
     /// Set GPQR flag
     pub fn set_gpqr(&mut self) {
-        self.flags |= Self::GPQR;
+        self.insert(Self::GPQR);
     }
 
     /// Clear GPQR flag
     pub fn clear_gpqr(&mut self) {
-        self.flags &= !Self::GPQR;
+        self.remove(Self::GPQR);
     }
 
     /// Set PURR flag
     pub fn set_purr(&mut self) {
-        self.flags |= Self::PURR;
+        self.insert(Self::PURR);
     }
 
     /// Clear PURR flag
     pub fn clear_purr(&mut self) {
-        self.flags &= !Self::PURR;
+        self.remove(Self::PURR);
     }
 
     /// Set VSR flag
     pub fn set_vsr(&mut self) {
-        self.flags |= Self::VSR;
+        self.insert(Self::VSR);
     }
 
     /// Clear VSR flag
     pub fn clear_vsr(&mut self) {
-        self.flags &= !Self::VSR;
+        self.remove(Self::VSR);
     }
 
     /// Marshal Node Report Type to bytes
     pub fn marshal(&self) -> Vec<u8> {
-        vec![self.flags]
+        vec![self.bits()]
     }
 
     /// Unmarshal Node Report Type from bytes
@@ -187,7 +187,7 @@ impl NodeReportType {
             ));
         }
 
-        Ok(NodeReportType { flags: data[0] })
+        Ok(NodeReportType::from_bits_retain(data[0]))
     }
 
     /// Convert to generic IE
@@ -208,40 +208,40 @@ mod tests {
 
     #[test]
     fn test_node_report_type_upfr() {
-        let nrt = NodeReportType::new(NodeReportType::UPFR);
+        let nrt = NodeReportType::UPFR;
         assert!(nrt.upfr());
         assert!(!nrt.uprr());
     }
 
     #[test]
     fn test_node_report_type_uprr() {
-        let nrt = NodeReportType::new(NodeReportType::UPRR);
+        let nrt = NodeReportType::UPRR;
         assert!(!nrt.upfr());
         assert!(nrt.uprr());
     }
 
     #[test]
     fn test_node_report_type_ckdr() {
-        let nrt = NodeReportType::new(NodeReportType::CKDR);
+        let nrt = NodeReportType::CKDR;
         assert!(nrt.ckdr());
         assert!(!nrt.upfr());
     }
 
     #[test]
     fn test_node_report_type_gpqr() {
-        let nrt = NodeReportType::new(NodeReportType::GPQR);
+        let nrt = NodeReportType::GPQR;
         assert!(nrt.gpqr());
     }
 
     #[test]
     fn test_node_report_type_purr() {
-        let nrt = NodeReportType::new(NodeReportType::PURR);
+        let nrt = NodeReportType::PURR;
         assert!(nrt.purr());
     }
 
     #[test]
     fn test_node_report_type_vsr() {
-        let nrt = NodeReportType::new(NodeReportType::VSR);
+        let nrt = NodeReportType::VSR;
         assert!(nrt.vsr());
     }
 
@@ -356,7 +356,7 @@ mod tests {
     #[test]
     fn test_node_report_type_5g_path_failure() {
         // Scenario: Report user plane path failure
-        let nrt = NodeReportType::new(NodeReportType::UPFR);
+        let nrt = NodeReportType::UPFR;
         let bytes = nrt.marshal();
         let parsed = NodeReportType::unmarshal(&bytes).unwrap();
 
@@ -367,7 +367,7 @@ mod tests {
     #[test]
     fn test_node_report_type_5g_path_recovery() {
         // Scenario: Report user plane path recovery
-        let nrt = NodeReportType::new(NodeReportType::UPRR);
+        let nrt = NodeReportType::UPRR;
         let bytes = nrt.marshal();
         let parsed = NodeReportType::unmarshal(&bytes).unwrap();
 
@@ -378,7 +378,7 @@ mod tests {
     #[test]
     fn test_node_report_type_5g_clock_drift() {
         // Scenario: Report clock drift
-        let nrt = NodeReportType::new(NodeReportType::CKDR);
+        let nrt = NodeReportType::CKDR;
         let bytes = nrt.marshal();
         let parsed = NodeReportType::unmarshal(&bytes).unwrap();
 
@@ -389,7 +389,7 @@ mod tests {
     #[test]
     fn test_node_report_type_5g_qos_report() {
         // Scenario: Report GTP-U path QoS status
-        let nrt = NodeReportType::new(NodeReportType::GPQR);
+        let nrt = NodeReportType::GPQR;
         let bytes = nrt.marshal();
         let parsed = NodeReportType::unmarshal(&bytes).unwrap();
 
@@ -400,7 +400,7 @@ mod tests {
     #[test]
     fn test_node_report_type_5g_restart_report() {
         // Scenario: Report peer GTP-U restart
-        let nrt = NodeReportType::new(NodeReportType::PURR);
+        let nrt = NodeReportType::PURR;
         let bytes = nrt.marshal();
         let parsed = NodeReportType::unmarshal(&bytes).unwrap();
 
@@ -411,7 +411,7 @@ mod tests {
     #[test]
     fn test_node_report_type_5g_vendor_report() {
         // Scenario: Vendor-specific report
-        let nrt = NodeReportType::new(NodeReportType::VSR);
+        let nrt = NodeReportType::VSR;
         let bytes = nrt.marshal();
         let parsed = NodeReportType::unmarshal(&bytes).unwrap();
 
@@ -422,8 +422,7 @@ mod tests {
     #[test]
     fn test_node_report_type_5g_multiple_reports() {
         // Scenario: Multiple report types in one node report
-        let nrt =
-            NodeReportType::new(NodeReportType::UPFR | NodeReportType::UPRR | NodeReportType::CKDR);
+        let nrt = NodeReportType::UPFR | NodeReportType::UPRR | NodeReportType::CKDR;
         let bytes = nrt.marshal();
         let parsed = NodeReportType::unmarshal(&bytes).unwrap();
 
@@ -433,4 +432,17 @@ mod tests {
         assert!(!parsed.gpqr());
         assert_eq!(nrt, parsed);
     }
+
+    #[test]
+    fn test_node_report_type_bitflags() {
+        assert_eq!(NodeReportType::UPFR.bits(), 0x01);
+        assert_eq!(NodeReportType::all().bits(), 0x3F);
+        assert_eq!(NodeReportType::default(), NodeReportType::empty());
+        assert_eq!(NodeReportType::new(0x01), NodeReportType::UPFR);
+
+        // Spare bits are preserved on round trip
+        let parsed = NodeReportType::unmarshal(&[0xC1]).unwrap();
+        assert!(parsed.upfr());
+        assert_eq!(parsed.marshal(), vec![0xC1]);
+    }
 }
@@ -0,0 +1,176 @@
+// src/ie/ntp_timestamp.rs
+
+//! 32-bit 3GPP NTP timestamp codec shared by the time stamp IEs.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.5 and the IEs that refer to it, a time
+//! stamp is encoded as the 32-bit seconds field of an NTP timestamp
+//! (IETF RFC 5905), counted from 1900-01-01T00:00:00Z.
+//!
+//! The 32-bit seconds field rolls over on 2036-02-07T06:28:16Z. Following the
+//! era convention of RFC 5905 Section 6 (and RFC 4330 Section 3), a value with
+//! the most significant bit set is read as 1968–2036 (era 0) and one with it
+//! clear as 2036–2104 (era 1).
+
+use crate::error::PfcpError;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// NTP epoch (1900-01-01T00:00:00Z) is 2208988800 seconds before the Unix epoch (1970-01-01T00:00:00Z).
+const NTP_EPOCH_OFFSET: u64 = 2208988800;
+
+// Values with the MSB clear belong to era 1, which starts 2^32 seconds after the NTP epoch.
+const NTP_ERA_SECONDS: u64 = 1 << 32;
+const NTP_ERA_MSB: u32 = 0x8000_0000;
+
+// First second past the end of era 1 (2104-02-26), relative to the NTP epoch.
+const NTP_RANGE_END: u64 = NTP_ERA_SECONDS + NTP_ERA_MSB as u64;
+
+/// Seconds since the NTP epoch, as carried on the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct NtpTimestamp(pub(crate) u32);
+
+impl NtpTimestamp {
+    /// Converts a system time, truncating to whole seconds.
+    ///
+    /// Times from 2036-02-07 onwards are encoded in NTP era 1. `field` names
+    /// the IE in the error returned for times before the Unix epoch or from
+    /// 2104-02-26 onwards, which the 32-bit field cannot carry.
+    pub(crate) fn try_from_system_time(time: SystemTime, field: &str) -> Result<Self, PfcpError> {
+        let unix_timestamp = time
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| {
+                PfcpError::invalid_value(field, format!("{time:?}"), "time is before Unix epoch")
+            })?
+            .as_secs();
+        let ntp_timestamp = unix_timestamp.saturating_add(NTP_EPOCH_OFFSET);
+        if ntp_timestamp >= NTP_RANGE_END {
+            return Err(PfcpError::invalid_value(
+                field,
+                format!("{time:?}"),
+                "time is past the end of NTP era 1 (2104-02-26)",
+            ));
+        }
+        // Truncating to 32 bits drops the era, which decoding restores from the MSB
+        Ok(NtpTimestamp(ntp_timestamp as u32))
+    }
+
+    /// Converts a system time, truncating to whole seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time` is before the Unix epoch or from 2104-02-26 onwards;
+    /// see [`try_from_system_time`](Self::try_from_system_time).
+    pub(crate) fn from_system_time(time: SystemTime) -> Self {
+        Self::try_from_system_time(time, "NTP timestamp").unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Converts back to a system time.
+    ///
+    /// `field` names the IE in the error returned for values before the
+    /// Unix epoch.
+    pub(crate) fn to_system_time(self, field: &str) -> Result<SystemTime, PfcpError> {
+        let ntp_timestamp = if self.0 & NTP_ERA_MSB != 0 {
+            u64::from(self.0)
+        } else {
+            u64::from(self.0) + NTP_ERA_SECONDS
+        };
+        if ntp_timestamp < NTP_EPOCH_OFFSET {
+            return Err(PfcpError::invalid_value(
+                field,
+                self.0.to_string(),
+                "NTP timestamp is before Unix epoch",
+            ));
+        }
+        Ok(UNIX_EPOCH + Duration::from_secs(ntp_timestamp - NTP_EPOCH_OFFSET))
+    }
+
+    pub(crate) fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Reads the first four octets of `data`, which the caller has checked.
+    pub(crate) fn from_be_bytes(data: &[u8]) -> Self {
+        NtpTimestamp(u32::from_be_bytes(data[0..4].try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ntp_timestamp_epochs() {
+        assert_eq!(
+            NtpTimestamp::from_system_time(UNIX_EPOCH),
+            NtpTimestamp(NTP_EPOCH_OFFSET as u32)
+        );
+        assert_eq!(
+            NtpTimestamp(NTP_EPOCH_OFFSET as u32)
+                .to_system_time("test")
+                .unwrap(),
+            UNIX_EPOCH
+        );
+    }
+
+    #[test]
+    fn test_ntp_timestamp_round_trip() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let ntp = NtpTimestamp::from_system_time(time + Duration::from_millis(999));
+        assert_eq!(ntp.0, 3_908_988_800);
+        assert_eq!(NtpTimestamp::from_be_bytes(&ntp.to_be_bytes()), ntp);
+        assert_eq!(ntp.to_system_time("test").unwrap(), time);
+    }
+
+    #[test]
+    fn test_ntp_timestamp_era_1() {
+        // 2036-02-07T06:28:16Z is the first second of era 1
+        let rollover = UNIX_EPOCH + Duration::from_secs(NTP_ERA_SECONDS - NTP_EPOCH_OFFSET);
+        assert_eq!(
+            NtpTimestamp::try_from_system_time(rollover, "test").unwrap(),
+            NtpTimestamp(0)
+        );
+        assert_eq!(NtpTimestamp(0).to_system_time("test").unwrap(), rollover);
+
+        let later = rollover + Duration::from_secs(1_000_000);
+        let ntp = NtpTimestamp::try_from_system_time(later, "test").unwrap();
+        assert_eq!(ntp, NtpTimestamp(1_000_000));
+        assert_eq!(ntp.to_system_time("test").unwrap(), later);
+
+        // The last representable second, then the first one past era 1
+        let last = UNIX_EPOCH + Duration::from_secs(NTP_RANGE_END - 1 - NTP_EPOCH_OFFSET);
+        let ntp = NtpTimestamp::try_from_system_time(last, "test").unwrap();
+        assert_eq!(ntp, NtpTimestamp(NTP_ERA_MSB - 1));
+        assert_eq!(ntp.to_system_time("test").unwrap(), last);
+        let err =
+            NtpTimestamp::try_from_system_time(last + Duration::from_secs(1), "Monitoring Time");
+        assert!(matches!(err, Err(PfcpError::InvalidValue { .. })));
+        assert!(err.unwrap_err().to_string().contains("Monitoring Time"));
+    }
+
+    #[test]
+    fn test_ntp_timestamp_from_system_time_before_unix_epoch() {
+        let before = UNIX_EPOCH - Duration::from_secs(1);
+        let err = NtpTimestamp::try_from_system_time(before, "Time of First Packet");
+        assert!(matches!(err, Err(PfcpError::InvalidValue { .. })));
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .contains("Time of First Packet"));
+    }
+
+    #[test]
+    #[should_panic(expected = "before Unix epoch")]
+    fn test_ntp_timestamp_from_system_time_panics_before_unix_epoch() {
+        NtpTimestamp::from_system_time(UNIX_EPOCH - Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_ntp_timestamp_before_unix_epoch() {
+        // MSB set, so era 0: 1968-01-20, before the Unix epoch
+        let err = NtpTimestamp(NTP_ERA_MSB).to_system_time("Time of First Packet");
+        assert!(matches!(err, Err(PfcpError::InvalidValue { .. })));
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .contains("Time of First Packet"));
+    }
+}
@@ -1,7 +1,13 @@
 //! Overload Control Information IE.
+//!
+//! Per 3GPP TS 29.244 Section 7.5.3.4, the Overload Control Information IE
+//! asks the peer to throttle signalling towards an overloaded UP function.
 
 use crate::error::PfcpError;
-use crate::ie::{metric::Metric, sequence_number::SequenceNumber, timer::Timer, Ie, IeType};
+use crate::ie::{
+    metric::Metric, oci_flags::OciFlags, parse_ie_list, sequence_number::SequenceNumber,
+    timer::Timer, Ie, IeType,
+};
 
 /// Represents the Overload Control Information.
 /// Used to convey overload control information between PFCP entities.
@@ -10,6 +16,7 @@ pub struct OverloadControlInformation {
     pub sequence_number: SequenceNumber,
     pub metric: Metric,
     pub timer: Option<Timer>,
+    pub oci_flags: Option<OciFlags>,
 }
 
 impl OverloadControlInformation {
@@ -19,6 +26,7 @@ impl OverloadControlInformation {
             sequence_number,
             metric,
             timer: None,
+            oci_flags: None,
         }
     }
 
@@ -28,6 +36,12 @@ impl OverloadControlInformation {
         self
     }
 
+    /// Sets the OCI Flags, e.g. AOCI to associate the OCI with the Node ID.
+    pub fn with_oci_flags(mut self, oci_flags: OciFlags) -> Self {
+        self.oci_flags = Some(oci_flags);
+        self
+    }
+
     /// Marshals the Overload Control Information into a byte vector.
     pub fn marshal(&self) -> Vec<u8> {
         let mut ies = Vec::new();
@@ -36,13 +50,16 @@ impl OverloadControlInformation {
             IeType::SequenceNumber,
             self.sequence_number.marshal().to_vec(),
         ));
-        ies.push(Ie::new(IeType::Metric, self.metric.marshal().to_vec()));
+        ies.push(self.metric.to_ie());
 
         if let Some(ref timer) = self.timer {
-            ies.push(Ie::new(IeType::Timer, timer.marshal().to_vec()));
+            ies.push(timer.to_ie());
+        }
+        if let Some(ref oci_flags) = self.oci_flags {
+            ies.push(oci_flags.to_ie());
         }
 
-        let capacity: usize = ies.iter().map(|ie| ie.len() as usize).sum();
+        let capacity: usize = ies.iter().map(|ie| ie.encoded_size()).sum();
 
         let mut data = Vec::with_capacity(capacity);
         for ie in ies {
@@ -53,14 +70,7 @@ impl OverloadControlInformation {
 
     /// Unmarshals a byte slice into an Overload Control Information IE.
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
-        let mut ies = Vec::new();
-        let mut offset = 0;
-        while offset < payload.len() {
-            let ie = Ie::unmarshal(&payload[offset..])?;
-            let ie_len = ie.len() as usize;
-            ies.push(ie);
-            offset += ie_len;
-        }
+        let ies = parse_ie_list(payload)?;
 
         let sequence_number = ies
             .iter()
@@ -90,10 +100,17 @@ impl OverloadControlInformation {
             .map(|ie| Timer::unmarshal(&ie.payload))
             .transpose()?;
 
+        let oci_flags = ies
+            .iter()
+            .find(|ie| ie.ie_type == IeType::OciFlags)
+            .map(|ie| OciFlags::unmarshal(&ie.payload))
+            .transpose()?;
+
         Ok(OverloadControlInformation {
             sequence_number,
             metric,
             timer,
+            oci_flags,
         })
     }
 
@@ -106,12 +123,13 @@ impl OverloadControlInformation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ie::timer::TimerUnit;
 
     #[test]
     fn test_overload_control_information_marshal_unmarshal() {
         let sequence = SequenceNumber::new(12345);
-        let metric = Metric::new(75); // 75% overload
-        let timer = Timer::new(30); // 30 seconds
+        let metric = Metric::new(75).unwrap(); // 75% overload
+        let timer = Timer::new(TimerUnit::TwoSeconds, 15).unwrap(); // 30 seconds
 
         let overload_info = OverloadControlInformation::new(sequence, metric).with_timer(timer);
 
@@ -127,7 +145,7 @@ mod tests {
     #[test]
     fn test_overload_control_information_marshal_unmarshal_minimal() {
         let sequence = SequenceNumber::new(54321);
-        let metric = Metric::new(90); // 90% overload
+        let metric = Metric::new(90).unwrap(); // 90% overload
 
         let overload_info = OverloadControlInformation::new(sequence, metric);
 
@@ -143,7 +161,7 @@ mod tests {
     #[test]
     fn test_overload_control_information_to_ie() {
         let sequence = SequenceNumber::new(98765);
-        let metric = Metric::new(50);
+        let metric = Metric::new(50).unwrap();
         let overload_info = OverloadControlInformation::new(sequence, metric);
 
         let ie = overload_info.to_ie();
@@ -156,8 +174,8 @@ mod tests {
     #[test]
     fn test_overload_control_information_unmarshal_missing_sequence() {
         // Only metric, missing mandatory sequence number
-        let metric = Metric::new(25);
-        let metric_ie = Ie::new(IeType::Metric, metric.marshal().to_vec());
+        let metric = Metric::new(25).unwrap();
+        let metric_ie = metric.to_ie();
         let marshaled = metric_ie.marshal();
 
         let result = OverloadControlInformation::unmarshal(&marshaled);
@@ -188,4 +206,19 @@ mod tests {
         let result = OverloadControlInformation::unmarshal(&[0xFF]);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_overload_control_information_with_oci_flags() {
+        let timer = Timer::new(TimerUnit::OneMinute, 2).unwrap();
+        let overload_info =
+            OverloadControlInformation::new(SequenceNumber::new(3), Metric::new(80).unwrap())
+                .with_timer(timer)
+                .with_oci_flags(OciFlags::AOCI);
+
+        let ie = overload_info.to_ie();
+        let unmarshaled = OverloadControlInformation::unmarshal(&ie.payload).unwrap();
+        assert_eq!(unmarshaled, overload_info);
+        assert_eq!(unmarshaled.oci_flags, Some(OciFlags::AOCI));
+        assert_eq!(unmarshaled.timer, Some(timer));
+    }
 }
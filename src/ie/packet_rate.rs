@@ -24,6 +24,9 @@ pub enum TimeUnit {
     Week = 4,
 }
 
+/// Alias for [`TimeUnit`] when imported alongside other time unit types.
+pub type PacketRateTimeUnit = TimeUnit;
+
 impl TimeUnit {
     /// Convert from bits to TimeUnit
     fn from_bits(bits: u8) -> Self {
@@ -91,6 +94,11 @@ pub struct PacketRate {
 }
 
 impl PacketRate {
+    /// Returns a builder for constructing Packet Rate instances.
+    pub fn builder() -> PacketRateBuilder {
+        PacketRateBuilder::new()
+    }
+
     /// Create a new Packet Rate with only uplink limit
     ///
     /// # Arguments
@@ -425,6 +433,74 @@ impl PacketRate {
     }
 }
 
+/// Builder for Packet Rate Information Elements.
+///
+/// At least one of the uplink or downlink rates must be set.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::packet_rate::{PacketRateBuilder, PacketRateTimeUnit};
+///
+/// let rate = PacketRateBuilder::new()
+///     .max_uplink(5000, PacketRateTimeUnit::Minute)
+///     .max_downlink(200, PacketRateTimeUnit::SixMinutes)
+///     .build()?;
+///
+/// assert_eq!(rate.uplink_max_rate(), Some((PacketRateTimeUnit::Minute, 5000)));
+/// assert_eq!(rate.downlink_max_rate(), Some((PacketRateTimeUnit::SixMinutes, 200)));
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct PacketRateBuilder {
+    uplink_max_rate: Option<(TimeUnit, u16)>,
+    downlink_max_rate: Option<(TimeUnit, u16)>,
+}
+
+impl PacketRateBuilder {
+    /// Creates a new Packet Rate builder with no rates set.
+    pub fn new() -> Self {
+        PacketRateBuilder {
+            uplink_max_rate: None,
+            downlink_max_rate: None,
+        }
+    }
+
+    /// Sets the maximum uplink packet rate (sets the ULPR flag).
+    pub fn max_uplink(mut self, rate: u16, unit: TimeUnit) -> Self {
+        self.uplink_max_rate = Some((unit, rate));
+        self
+    }
+
+    /// Sets the maximum downlink packet rate (sets the DLPR flag).
+    pub fn max_downlink(mut self, rate: u16, unit: TimeUnit) -> Self {
+        self.downlink_max_rate = Some((unit, rate));
+        self
+    }
+
+    /// Builds the Packet Rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither an uplink nor a downlink rate is set.
+    pub fn build(self) -> Result<PacketRate, PfcpError> {
+        if self.uplink_max_rate.is_none() && self.downlink_max_rate.is_none() {
+            return Err(PfcpError::invalid_value(
+                "Packet Rate",
+                "none",
+                "at least one of uplink or downlink rate must be set",
+            ));
+        }
+
+        Ok(PacketRate {
+            uplink_max_rate: self.uplink_max_rate,
+            downlink_max_rate: self.downlink_max_rate,
+            additional_uplink_max_rate: None,
+            additional_downlink_max_rate: None,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -696,4 +772,52 @@ mod tests {
         let rate2 = rate1;
         assert_eq!(rate1, rate2);
     }
+
+    #[test]
+    fn test_packet_rate_builder_uplink_only() {
+        let rate = PacketRateBuilder::new()
+            .max_uplink(5000, PacketRateTimeUnit::Minute)
+            .build()
+            .unwrap();
+        assert_eq!(rate, PacketRate::new_uplink(TimeUnit::Minute, 5000));
+        assert_eq!(rate.marshal(), vec![0x01, 0x00, 0x13, 0x88]);
+
+        let parsed = PacketRate::unmarshal(&rate.marshal()).unwrap();
+        assert_eq!(parsed, rate);
+    }
+
+    #[test]
+    fn test_packet_rate_builder_downlink_only() {
+        let rate = PacketRate::builder()
+            .max_downlink(300, PacketRateTimeUnit::Hour)
+            .build()
+            .unwrap();
+        assert_eq!(rate.uplink_max_rate(), None);
+        assert_eq!(rate.marshal(), vec![0x02, 0x02, 0x01, 0x2C]);
+
+        let parsed = PacketRate::unmarshal(&rate.marshal()).unwrap();
+        assert_eq!(parsed, rate);
+    }
+
+    #[test]
+    fn test_packet_rate_builder_both() {
+        let rate = PacketRateBuilder::new()
+            .max_uplink(100, PacketRateTimeUnit::Day)
+            .max_downlink(200, PacketRateTimeUnit::Week)
+            .build()
+            .unwrap();
+        assert_eq!(
+            rate,
+            PacketRate::new_both(TimeUnit::Day, 100, TimeUnit::Week, 200)
+        );
+
+        let parsed = PacketRate::unmarshal(&rate.to_ie().payload).unwrap();
+        assert_eq!(parsed, rate);
+    }
+
+    #[test]
+    fn test_packet_rate_builder_empty() {
+        let result = PacketRateBuilder::new().build();
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
 }
@@ -92,6 +92,11 @@ impl PacketRateStatus {
         }
     }
 
+    /// Returns a builder that derives the flags from the fields set.
+    pub fn builder() -> PacketRateStatusBuilder {
+        PacketRateStatusBuilder::default()
+    }
+
     /// Set remaining uplink packets
     pub fn with_remaining_uplink_packets(mut self, packets: u16) -> Self {
         self.remaining_uplink_packets = Some(packets);
@@ -365,6 +370,148 @@ impl PacketRateStatus {
     }
 }
 
+/// Builder for [`PacketRateStatus`].
+///
+/// The UL, DL and APR flags are derived from the counts that are set, and
+/// `build` rejects combinations that could not be encoded.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::packet_rate_status::PacketRateStatus;
+///
+/// let prs = PacketRateStatus::builder()
+///     .remaining_uplink_packets(1000)
+///     .remaining_downlink_packets(2000)
+///     .validity_time([0x00; 8])
+///     .build()?;
+/// assert!(prs.uplink_present() && prs.downlink_present());
+/// assert!(!prs.apr_present());
+///
+/// let parsed = PacketRateStatus::unmarshal(&prs.marshal()?)?;
+/// assert_eq!(parsed, prs);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct PacketRateStatusBuilder {
+    remaining_uplink_packets: Option<u16>,
+    remaining_additional_uplink_packets: Option<u16>,
+    remaining_downlink_packets: Option<u16>,
+    remaining_additional_downlink_packets: Option<u16>,
+    validity_time: Option<[u8; 8]>,
+}
+
+impl PacketRateStatusBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of remaining uplink packets allowed (UL flag).
+    pub fn remaining_uplink_packets(mut self, packets: u16) -> Self {
+        self.remaining_uplink_packets = Some(packets);
+        self
+    }
+
+    /// Sets the number of remaining additional uplink packets allowed (APR flag).
+    pub fn remaining_additional_uplink_packets(mut self, packets: u16) -> Self {
+        self.remaining_additional_uplink_packets = Some(packets);
+        self
+    }
+
+    /// Sets the number of remaining downlink packets allowed (DL flag).
+    pub fn remaining_downlink_packets(mut self, packets: u16) -> Self {
+        self.remaining_downlink_packets = Some(packets);
+        self
+    }
+
+    /// Sets the number of remaining additional downlink packets allowed (APR flag).
+    pub fn remaining_additional_downlink_packets(mut self, packets: u16) -> Self {
+        self.remaining_additional_downlink_packets = Some(packets);
+        self
+    }
+
+    /// Sets the Rate Control Status Validity Time (8-byte 3GPP NTP timestamp).
+    pub fn validity_time(mut self, time: [u8; 8]) -> Self {
+        self.validity_time = Some(time);
+        self
+    }
+
+    /// Builds the Packet Rate Status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - an additional count is set without the matching uplink/downlink count
+    /// - additional counts are set for only one of two present directions
+    /// - uplink or downlink counts are set without a validity time, or a
+    ///   validity time is set without either
+    pub fn build(self) -> Result<PacketRateStatus, PfcpError> {
+        let ul_present = self.remaining_uplink_packets.is_some();
+        let dl_present = self.remaining_downlink_packets.is_some();
+
+        if self.remaining_additional_uplink_packets.is_some() && !ul_present {
+            return Err(PfcpError::validation_error(
+                "PacketRateStatusBuilder",
+                "remaining_additional_uplink_packets",
+                "requires remaining_uplink_packets",
+            ));
+        }
+        if self.remaining_additional_downlink_packets.is_some() && !dl_present {
+            return Err(PfcpError::validation_error(
+                "PacketRateStatusBuilder",
+                "remaining_additional_downlink_packets",
+                "requires remaining_downlink_packets",
+            ));
+        }
+
+        let apr_present = self.remaining_additional_uplink_packets.is_some()
+            || self.remaining_additional_downlink_packets.is_some();
+        if apr_present && ul_present && self.remaining_additional_uplink_packets.is_none() {
+            return Err(PfcpError::validation_error(
+                "PacketRateStatusBuilder",
+                "remaining_additional_uplink_packets",
+                "APR applies to both directions; uplink additional count is missing",
+            ));
+        }
+        if apr_present && dl_present && self.remaining_additional_downlink_packets.is_none() {
+            return Err(PfcpError::validation_error(
+                "PacketRateStatusBuilder",
+                "remaining_additional_downlink_packets",
+                "APR applies to both directions; downlink additional count is missing",
+            ));
+        }
+
+        match (ul_present || dl_present, self.validity_time.is_some()) {
+            (true, false) => {
+                return Err(PfcpError::validation_error(
+                    "PacketRateStatusBuilder",
+                    "validity_time",
+                    "required when uplink or downlink packets are set",
+                ))
+            }
+            (false, true) => {
+                return Err(PfcpError::validation_error(
+                    "PacketRateStatusBuilder",
+                    "validity_time",
+                    "only encoded when uplink or downlink packets are set",
+                ))
+            }
+            _ => {}
+        }
+
+        Ok(PacketRateStatus {
+            ul_present,
+            dl_present,
+            apr_present,
+            remaining_uplink_packets: self.remaining_uplink_packets,
+            remaining_additional_uplink_packets: self.remaining_additional_uplink_packets,
+            remaining_downlink_packets: self.remaining_downlink_packets,
+            remaining_additional_downlink_packets: self.remaining_additional_downlink_packets,
+            validity_time: self.validity_time,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -553,4 +700,83 @@ mod tests {
         assert_eq!(parsed.remaining_uplink_packets(), Some(500));
         assert_eq!(parsed.remaining_downlink_packets(), Some(1500));
     }
+
+    #[test]
+    fn test_packet_rate_status_builder_all_flag_combinations() {
+        for flags in 0u8..8 {
+            let ul = flags & PacketRateStatus::UL_FLAG != 0;
+            let dl = flags & PacketRateStatus::DL_FLAG != 0;
+            let apr = flags & PacketRateStatus::APR_FLAG != 0;
+            if apr && !ul && !dl {
+                // APR without UL or DL carries no fields and cannot be built
+                continue;
+            }
+
+            let mut builder = PacketRateStatus::builder();
+            if ul {
+                builder = builder.remaining_uplink_packets(0x0102);
+                if apr {
+                    builder = builder.remaining_additional_uplink_packets(0x0304);
+                }
+            }
+            if dl {
+                builder = builder.remaining_downlink_packets(0x0506);
+                if apr {
+                    builder = builder.remaining_additional_downlink_packets(0x0708);
+                }
+            }
+            if ul || dl {
+                builder = builder.validity_time([0xE8; 8]);
+            }
+            let prs = builder.build().unwrap();
+
+            let ie = prs.to_ie().unwrap();
+            assert_eq!(ie.ie_type, IeType::PacketRateStatus);
+            assert_eq!(ie.payload[0], flags, "flags for {flags:#05b}");
+            let counts = [ul, ul && apr, dl, dl && apr]
+                .iter()
+                .filter(|&&c| c)
+                .count();
+            let validity = if ul || dl { 8 } else { 0 };
+            assert_eq!(ie.payload.len(), 1 + 2 * counts + validity);
+            assert_eq!(PacketRateStatus::unmarshal(&ie.payload).unwrap(), prs);
+        }
+    }
+
+    #[test]
+    fn test_packet_rate_status_builder_validation() {
+        let missing_base = PacketRateStatus::builder()
+            .remaining_additional_uplink_packets(10)
+            .validity_time([0; 8])
+            .build();
+        assert!(matches!(
+            missing_base,
+            Err(PfcpError::ValidationError { .. })
+        ));
+
+        let one_sided_apr = PacketRateStatus::builder()
+            .remaining_uplink_packets(10)
+            .remaining_additional_uplink_packets(5)
+            .remaining_downlink_packets(20)
+            .validity_time([0; 8])
+            .build();
+        assert!(matches!(
+            one_sided_apr,
+            Err(PfcpError::ValidationError { .. })
+        ));
+
+        let missing_time = PacketRateStatus::builder()
+            .remaining_downlink_packets(20)
+            .build();
+        assert!(matches!(
+            missing_time,
+            Err(PfcpError::ValidationError { .. })
+        ));
+
+        let orphan_time = PacketRateStatus::builder().validity_time([0; 8]).build();
+        assert!(matches!(
+            orphan_time,
+            Err(PfcpError::ValidationError { .. })
+        ));
+    }
 }
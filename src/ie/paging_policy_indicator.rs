@@ -139,6 +139,12 @@ impl PagingPolicyIndicator {
     }
 }
 
+impl std::fmt::Display for PagingPolicyIndicator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +232,12 @@ mod tests {
         assert_eq!(original, parsed);
     }
 
+    #[test]
+    fn test_ppi_display() {
+        assert_eq!(PagingPolicyIndicator::new(0).unwrap().to_string(), "0");
+        assert_eq!(PagingPolicyIndicator::new(7).unwrap().to_string(), "7");
+    }
+
     #[test]
     fn test_ppi_5g_qos_flow() {
         // Scenario: Set paging policy for QoS flow
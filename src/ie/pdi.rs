@@ -161,7 +161,10 @@ impl Pdi {
 /// let uplink_pdi = PdiBuilder::uplink_access().build().unwrap();
 /// let downlink_pdi = PdiBuilder::downlink_core().build().unwrap();
 /// ```
-#[derive(Debug, Default)]
+///
+/// [`Default`] yields a placeholder builder with an Access source interface;
+/// it is intended for testing.
+#[derive(Debug)]
 pub struct PdiBuilder {
     source_interface: Option<SourceInterface>,
     f_teid: Option<Fteid>,
@@ -179,7 +182,12 @@ impl PdiBuilder {
     pub fn new(source_interface: SourceInterface) -> Self {
         PdiBuilder {
             source_interface: Some(source_interface),
-            ..Default::default()
+            f_teid: None,
+            network_instance: None,
+            ue_ip_address: None,
+            sdf_filter: None,
+            application_id: None,
+            ethernet_packet_filter: None,
         }
     }
 
@@ -288,6 +296,12 @@ impl PdiBuilder {
     }
 }
 
+impl Default for PdiBuilder {
+    fn default() -> Self {
+        PdiBuilder::uplink_access()
+    }
+}
+
 impl Pdi {
     /// Returns a builder for constructing PDI instances.
     pub fn builder(source_interface: SourceInterface) -> PdiBuilder {
@@ -524,7 +538,11 @@ mod tests {
 
     #[test]
     fn test_pdi_builder_missing_source_interface() {
-        let result = PdiBuilder::default().build();
+        let builder = PdiBuilder {
+            source_interface: None,
+            ..PdiBuilder::default()
+        };
+        let result = builder.build();
         assert!(result.is_err());
         match result.unwrap_err() {
             PfcpError::MissingMandatoryIe { ie_type, .. } => {
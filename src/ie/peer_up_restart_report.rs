@@ -8,6 +8,10 @@ use crate::ie::remote_gtpu_peer::RemoteGtpuPeer;
 use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
 
 /// Peer UP Restart Report per 3GPP TS 29.244 §7.4.5.1.7-1.
+///
+/// The table defines Remote GTP-U Peer as the only member; there is no
+/// Recovery Time Stamp inside this IE. A restart is signalled by the peer's
+/// presence in the report, and any other IE found here is ignored.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PeerUpRestartReport {
     /// Remote GTP-U peers that restarted (mandatory, at least one).
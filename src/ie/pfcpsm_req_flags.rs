@@ -3,10 +3,11 @@
 //! PFCPSM Req-Flags Information Element.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::{Ie, IeType};
 use bitflags::bitflags;
 
 bitflags! {
+    /// PFCPSM Req-Flags per 3GPP TS 29.244 Section 8.2.35.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
     pub struct PfcpsmReqFlags: u8 {
         const DROBU = 1 << 0; // Bit 1: Drop Buffered Packets
@@ -36,6 +37,10 @@ impl PfcpsmReqFlags {
         }
         Ok(PfcpsmReqFlags::from_bits_truncate(data[0]))
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::PfcpsmReqFlags, self.marshal().to_vec())
+    }
 }
 
 #[cfg(test)]
@@ -50,6 +55,16 @@ mod tests {
         assert_eq!(flags, unmarshaled);
     }
 
+    #[test]
+    fn test_pfcpsm_req_flags_bit_positions() {
+        assert_eq!(PfcpsmReqFlags::DROBU.marshal(), [0x01]);
+        assert_eq!(PfcpsmReqFlags::SNDEM.marshal(), [0x02]);
+
+        let ie = PfcpsmReqFlags::SNDEM.to_ie();
+        assert_eq!(ie.ie_type, IeType::PfcpsmReqFlags);
+        assert_eq!(ie.payload, vec![0x02]);
+    }
+
     #[test]
     fn test_pfcpsm_req_flags_unmarshal_invalid_data() {
         let data = [];
@@ -2,7 +2,7 @@
 
 use crate::error::PfcpError;
 use crate::ie::pfd_contents::PfdContents;
-use crate::ie::{Ie, IeType};
+use crate::ie::{Ie, IeIterator, IeType};
 
 /// Represents a PFD Context.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,13 +28,11 @@ impl PfdContext {
     /// Unmarshals a byte slice into a PFD Context.
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut pfd_contents = Vec::new();
-        let mut offset = 0;
-        while offset < payload.len() {
-            let ie = Ie::unmarshal(&payload[offset..])?;
+        for ie_result in IeIterator::new(payload) {
+            let ie = ie_result?;
             if ie.ie_type == IeType::PfdContents {
                 pfd_contents.push(PfdContents::unmarshal(&ie.payload)?);
             }
-            offset += ie.len() as usize;
         }
         Ok(PfdContext { pfd_contents })
     }
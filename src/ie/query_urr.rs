@@ -1,41 +1,64 @@
 //! Query URR IE - Request immediate usage reports from specific URRs.
+//!
+//! Per 3GPP TS 29.244 Table 7.5.4.10-1, the Query URR IE is a grouped IE
+//! carrying the URR ID of a URR whose usage report is requested.
 
 use crate::error::PfcpError;
-use crate::ie::{Ie, IeType};
+use crate::ie::urr_id::UrrId;
+use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
 
 /// Query URR - Request immediate usage reports.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::urr_id::UrrId;
+/// use rs_pfcp::ie::QueryUrr;
+///
+/// let query = QueryUrr::new(7);
+/// assert_eq!(query.urr_id, UrrId::new(7));
+/// // Grouped: a single URR ID IE
+/// assert_eq!(query.marshal(), vec![0x00, 0x51, 0x00, 0x04, 0, 0, 0, 7]);
+/// assert_eq!(QueryUrr::unmarshal(&query.marshal())?, query);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryUrr {
-    pub urr_id: u32,
+    pub urr_id: UrrId,
 }
 
 impl QueryUrr {
     pub fn new(urr_id: u32) -> Self {
-        Self { urr_id }
+        Self {
+            urr_id: UrrId::new(urr_id),
+        }
     }
 
     pub fn marshal(&self) -> Vec<u8> {
-        self.urr_id.to_be_bytes().to_vec()
+        marshal_ies(&[self.urr_id.to_ie()])
     }
 
-    pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 4 {
-            return Err(PfcpError::invalid_length(
-                "Query URR",
-                IeType::QueryUrr,
-                4,
-                data.len(),
-            ));
+    pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
+        let mut urr_id = None;
+        for ie in IeIterator::new(payload) {
+            let ie = ie?;
+            if ie.ie_type == IeType::UrrId {
+                urr_id = Some(UrrId::unmarshal(&ie.payload)?);
+            }
         }
+        let urr_id = urr_id
+            .ok_or_else(|| PfcpError::missing_ie_in_grouped(IeType::UrrId, IeType::QueryUrr))?;
+        Ok(Self { urr_id })
+    }
 
-        let urr_id = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
-        Ok(Self::new(urr_id))
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::QueryUrr, self.marshal())
     }
 }
 
-impl From<QueryUrr> for Ie {
-    fn from(query_urr: QueryUrr) -> Self {
-        Ie::new(IeType::QueryUrr, query_urr.marshal())
+impl From<UrrId> for QueryUrr {
+    fn from(urr_id: UrrId) -> Self {
+        Self { urr_id }
     }
 }
 
@@ -47,6 +70,10 @@ mod tests {
     fn test_query_urr_marshal_unmarshal() {
         let query_urr = QueryUrr::new(0x12345678);
         let marshaled = query_urr.marshal();
+        assert_eq!(
+            marshaled,
+            vec![0x00, 0x51, 0x00, 0x04, 0x12, 0x34, 0x56, 0x78]
+        );
         let unmarshaled = QueryUrr::unmarshal(&marshaled).unwrap();
         assert_eq!(query_urr, unmarshaled);
     }
@@ -54,13 +81,29 @@ mod tests {
     #[test]
     fn test_query_urr_to_ie() {
         let query_urr = QueryUrr::new(42);
-        let ie: Ie = query_urr.into();
+        let ie: Ie = query_urr.clone().into();
         assert_eq!(ie.ie_type, IeType::QueryUrr);
+        assert_eq!(ie, query_urr.to_ie());
+        assert_eq!(QueryUrr::from(UrrId::new(42)), query_urr);
+    }
+
+    #[test]
+    fn test_query_urr_missing_urr_id() {
+        let result = QueryUrr::unmarshal(&[]);
+        assert!(matches!(
+            result,
+            Err(PfcpError::MissingMandatoryIe {
+                ie_type: IeType::UrrId,
+                parent_ie: Some(IeType::QueryUrr),
+                ..
+            })
+        ));
     }
 
     #[test]
     fn test_query_urr_unmarshal_short() {
-        let result = QueryUrr::unmarshal(&[0x12, 0x34]);
+        // URR ID IE truncated
+        let result = QueryUrr::unmarshal(&[0x00, 0x51, 0x00, 0x04, 0x12, 0x34]);
         assert!(result.is_err());
     }
 }
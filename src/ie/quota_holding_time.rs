@@ -1,4 +1,10 @@
+//! Quota Holding Time Information Element.
+//!
+//! Per 3GPP TS 29.244, the time in seconds after which a quota is reported
+//! if no packets have been received for it.
+
 use crate::error::PfcpError;
+use crate::ie::duration_seconds::impl_duration_seconds;
 use crate::ie::{Ie, IeType};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -48,6 +54,8 @@ impl QuotaHoldingTime {
     }
 }
 
+impl_duration_seconds!(QuotaHoldingTime, holding_time_seconds, "Quota Holding Time");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,9 @@
 // src/ie/recovery_time_stamp.rs
 use crate::error::PfcpError;
+use crate::ie::ntp_timestamp::NtpTimestamp;
 use crate::ie::IeType;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
-
-// NTP epoch (1900-01-01T00:00:00Z) is 2208988800 seconds before the Unix epoch (1970-01-01T00:00:00Z).
-const NTP_EPOCH_OFFSET: u64 = 2208988800;
+use std::cmp::Ordering;
+use std::time::{Duration, SystemTime, SystemTimeError};
 
 /// Represents a Recovery Time Stamp Information Element.
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -20,13 +19,7 @@ impl RecoveryTimeStamp {
 
     /// Marshals the RecoveryTimeStamp into a 4-byte array.
     pub fn marshal(&self) -> [u8; 4] {
-        let unix_timestamp = self
-            .timestamp
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards")
-            .as_secs();
-        let ntp_timestamp = unix_timestamp + NTP_EPOCH_OFFSET;
-        (ntp_timestamp as u32).to_be_bytes()
+        NtpTimestamp::from_system_time(self.timestamp).to_be_bytes()
     }
 
     /// Converts this IE to a raw `Ie` value.
@@ -46,18 +39,48 @@ impl RecoveryTimeStamp {
                 data.len(),
             ));
         }
-        let ntp_timestamp = u32::from_be_bytes(data[0..4].try_into().unwrap()) as u64;
-        if ntp_timestamp < NTP_EPOCH_OFFSET {
-            return Err(PfcpError::invalid_value(
-                "Recovery Time Stamp",
-                ntp_timestamp.to_string(),
-                "NTP timestamp is before Unix epoch",
-            ));
-        }
-        let unix_timestamp = ntp_timestamp - NTP_EPOCH_OFFSET;
-        let timestamp = UNIX_EPOCH + Duration::from_secs(unix_timestamp);
+        let timestamp = NtpTimestamp::from_be_bytes(data).to_system_time("Recovery Time Stamp")?;
         Ok(RecoveryTimeStamp { timestamp })
     }
+
+    /// Returns the time elapsed since the recovery time stamp.
+    ///
+    /// Fails if the time stamp lies in the future relative to the local clock.
+    pub fn elapsed(&self) -> Result<Duration, SystemTimeError> {
+        self.timestamp.elapsed()
+    }
+
+    /// Reports whether the recovery time stamp is older than `max_age`.
+    ///
+    /// A time stamp in the future (e.g. due to clock skew between peers) is
+    /// never considered stale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::recovery_time_stamp::RecoveryTimeStamp;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let ts = RecoveryTimeStamp::new(SystemTime::now() - Duration::from_secs(60));
+    /// assert!(ts.is_stale(Duration::from_secs(30)));
+    /// assert!(!ts.is_stale(Duration::from_secs(120)));
+    /// ```
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        self.elapsed().is_ok_and(|age| age > max_age)
+    }
+}
+
+impl PartialOrd for RecoveryTimeStamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders time stamps chronologically, matching the order of their NTP values.
+impl Ord for RecoveryTimeStamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp.cmp(&other.timestamp)
+    }
 }
 
 #[cfg(test)]
@@ -99,4 +122,39 @@ mod tests {
         let err = result.unwrap_err();
         assert!(matches!(err, PfcpError::InvalidLength { .. }));
     }
+
+    #[test]
+    fn test_recovery_time_stamp_is_stale() {
+        let ts = RecoveryTimeStamp::new(SystemTime::now() - Duration::from_secs(10));
+        assert!(ts.elapsed().unwrap() >= Duration::from_secs(10));
+        assert!(ts.is_stale(Duration::from_secs(5)));
+        assert!(!ts.is_stale(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_recovery_time_stamp_future_is_not_stale() {
+        let ts = RecoveryTimeStamp::new(SystemTime::now() + Duration::from_secs(60));
+        assert!(ts.elapsed().is_err());
+        assert!(!ts.is_stale(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_recovery_time_stamp_ordering() {
+        let base = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let older = RecoveryTimeStamp::new(base);
+        let newer = RecoveryTimeStamp::new(base + Duration::from_secs(1));
+        let newest = RecoveryTimeStamp::new(base + Duration::from_secs(3600));
+
+        assert!(older < newer);
+        assert!(newest > newer);
+
+        let timestamps = [newer.clone(), newest.clone(), older.clone()];
+        assert_eq!(timestamps.iter().max(), Some(&newest));
+        assert_eq!(timestamps.iter().min(), Some(&older));
+
+        // Ordering is preserved across the wire encoding
+        let decoded_older = RecoveryTimeStamp::unmarshal(&older.marshal()).unwrap();
+        let decoded_newer = RecoveryTimeStamp::unmarshal(&newer.marshal()).unwrap();
+        assert!(decoded_older < decoded_newer);
+    }
 }
@@ -1,16 +1,23 @@
 // src/ie/redirect_information.rs
 
 //! Redirect Information Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.20, the Redirect Information IE carries the
+//! address the UP function redirects traffic to.
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::{Ie, IeType};
 
+/// Redirect Address Type per 3GPP TS 29.244 Table 8.2.20-1.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RedirectAddressType {
-    Ipv4,
-    Ipv6,
-    Url,
-    SipUri,
+    Ipv4 = 0,
+    Ipv6 = 1,
+    Url = 2,
+    SipUri = 3,
+    /// IPv4 address in the Redirect Server Address and IPv6 address in the
+    /// Other Redirect Server Address.
+    Ipv4AndIpv6 = 4,
 }
 
 impl TryFrom<u8> for RedirectAddressType {
@@ -22,56 +29,213 @@ impl TryFrom<u8> for RedirectAddressType {
             1 => Ok(RedirectAddressType::Ipv6),
             2 => Ok(RedirectAddressType::Url),
             3 => Ok(RedirectAddressType::SipUri),
+            4 => Ok(RedirectAddressType::Ipv4AndIpv6),
             _ => Err(PfcpError::invalid_value(
                 "RedirectAddressType",
                 value.to_string(),
-                "unknown redirect address type; valid values are 0=IPv4, 1=IPv6, 2=URL, 3=SIP URI",
+                "unknown redirect address type; valid values are 0=IPv4, 1=IPv6, 2=URL, 3=SIP URI, 4=IPv4 and IPv6",
             )),
         }
     }
 }
 
+/// Redirect Information per 3GPP TS 29.244 §8.2.20.
+///
+/// # Wire Format
+/// - Octet 5: Spare (bits 8-5) | Redirect Address Type (bits 4-1)
+/// - Octets 6-7: Redirect Server Address Length
+/// - Octets 8-m: Redirect Server Address
+/// - Octets p-(p+1): Other Redirect Server Address Length (optional)
+/// - Octets (p+2)-q: Other Redirect Server Address (optional)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::redirect_information::{RedirectAddressType, RedirectInformation};
+///
+/// let ri = RedirectInformation::new(RedirectAddressType::Url, "http://portal.example.com")?;
+/// let parsed = RedirectInformation::unmarshal(&ri.marshal())?;
+/// assert_eq!(parsed, ri);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RedirectInformation {
     pub address_type: RedirectAddressType,
     pub server_address: String,
+    /// Other Redirect Server Address, used with [`RedirectAddressType::Ipv4AndIpv6`].
+    pub other_address: Option<String>,
 }
 
 impl RedirectInformation {
-    pub fn new(address_type: RedirectAddressType, server_address: &str) -> Self {
-        RedirectInformation {
+    /// Creates Redirect Information for a single server address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address does not fit its 16-bit length field.
+    pub fn new(address_type: RedirectAddressType, server_address: &str) -> Result<Self, PfcpError> {
+        address_length("Redirect Server Address", server_address)?;
+        Ok(RedirectInformation {
             address_type,
             server_address: server_address.to_string(),
-        }
+            other_address: None,
+        })
+    }
+
+    /// Returns a builder for constructing Redirect Information instances.
+    pub fn builder(
+        address_type: RedirectAddressType,
+        server_address: &str,
+    ) -> RedirectInformationBuilder {
+        RedirectInformationBuilder::new(address_type, server_address)
     }
 
     pub fn marshal(&self) -> Vec<u8> {
-        let mut data = Vec::new();
-        data.push(self.address_type as u8);
+        let other_len = self.other_address.as_ref().map_or(0, |a| 2 + a.len());
+        let mut data = Vec::with_capacity(3 + self.server_address.len() + other_len);
+        data.push(self.address_type as u8 & 0x0F);
+        data.extend_from_slice(&(self.server_address.len() as u16).to_be_bytes());
         data.extend_from_slice(self.server_address.as_bytes());
+        if let Some(ref other) = self.other_address {
+            data.extend_from_slice(&(other.len() as u16).to_be_bytes());
+            data.extend_from_slice(other.as_bytes());
+        }
         data
     }
 
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 2 {
+        if data.len() < 3 {
             return Err(PfcpError::invalid_length(
                 "Redirect Information",
                 IeType::RedirectInformation,
-                2,
+                3,
+                data.len(),
+            ));
+        }
+        let address_type = RedirectAddressType::try_from(data[0] & 0x0F)?;
+        let (server_address, offset) = Self::read_address(data, 1)?;
+
+        let other_address = if offset < data.len() {
+            let (other, _) = Self::read_address(data, offset)?;
+            Some(other)
+        } else {
+            None
+        };
+
+        Ok(RedirectInformation {
+            address_type,
+            server_address,
+            other_address,
+        })
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::RedirectInformation, self.marshal())
+    }
+
+    /// Reads a length-prefixed address at `offset`, returning it and the next offset.
+    fn read_address(data: &[u8], offset: usize) -> Result<(String, usize), PfcpError> {
+        if data.len() < offset + 2 {
+            return Err(PfcpError::invalid_length(
+                "Redirect Information address length",
+                IeType::RedirectInformation,
+                offset + 2,
+                data.len(),
+            ));
+        }
+        let len = u16::from_be_bytes([data[offset], data[offset + 1]]) as usize;
+        let start = offset + 2;
+        let end = start + len;
+        if data.len() < end {
+            return Err(PfcpError::invalid_length(
+                "Redirect Information address",
+                IeType::RedirectInformation,
+                end,
                 data.len(),
             ));
         }
-        let address_type = RedirectAddressType::try_from(data[0])?;
-        let server_address = String::from_utf8(data[1..].to_vec()).map_err(|e| {
+        let address = String::from_utf8(data[start..end].to_vec()).map_err(|e| {
             PfcpError::encoding_error(
                 "Redirect Information",
                 IeType::RedirectInformation,
                 e.utf8_error(),
             )
         })?;
-        Ok(RedirectInformation {
+        Ok((address, end))
+    }
+}
+
+/// Returns the length field for `address`, rejecting addresses over 65535 bytes.
+fn address_length(field: &str, address: &str) -> Result<u16, PfcpError> {
+    u16::try_from(address.len()).map_err(|_| {
+        PfcpError::invalid_value(
+            field,
+            address.len().to_string(),
+            "address length exceeds 65535 bytes",
+        )
+    })
+}
+
+/// Builder for Redirect Information Information Elements.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::redirect_information::{RedirectAddressType, RedirectInformationBuilder};
+///
+/// let ri = RedirectInformationBuilder::new(RedirectAddressType::Ipv4AndIpv6, "192.0.2.1")
+///     .other_address("2001:db8::1")
+///     .build()?;
+///
+/// assert_eq!(ri.other_address.as_deref(), Some("2001:db8::1"));
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug)]
+pub struct RedirectInformationBuilder {
+    address_type: RedirectAddressType,
+    server_address: String,
+    other_address: Option<String>,
+}
+
+impl RedirectInformationBuilder {
+    /// Creates a new builder with the redirect address type and server address.
+    pub fn new(address_type: RedirectAddressType, server_address: &str) -> Self {
+        RedirectInformationBuilder {
             address_type,
-            server_address,
+            server_address: server_address.to_string(),
+            other_address: None,
+        }
+    }
+
+    /// Sets the Other Redirect Server Address.
+    pub fn other_address(mut self, other_address: &str) -> Self {
+        self.other_address = Some(other_address.to_string());
+        self
+    }
+
+    /// Builds the Redirect Information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an address does not fit its 16-bit length field, or
+    /// if the Other Redirect Server Address is set for an address type other
+    /// than [`RedirectAddressType::Ipv4AndIpv6`].
+    pub fn build(self) -> Result<RedirectInformation, PfcpError> {
+        address_length("Redirect Server Address", &self.server_address)?;
+        if let Some(ref other) = self.other_address {
+            if self.address_type != RedirectAddressType::Ipv4AndIpv6 {
+                return Err(PfcpError::invalid_value(
+                    "Other Redirect Server Address",
+                    format!("{:?}", self.address_type),
+                    "only allowed with the IPv4 and IPv6 address type",
+                ));
+            }
+            address_length("Other Redirect Server Address", other)?;
+        }
+
+        Ok(RedirectInformation {
+            address_type: self.address_type,
+            server_address: self.server_address,
+            other_address: self.other_address,
         })
     }
 }
@@ -82,20 +246,75 @@ mod tests {
 
     #[test]
     fn test_redirect_information_marshal_unmarshal_ipv4() {
-        let ri = RedirectInformation::new(RedirectAddressType::Ipv4, "1.2.3.4");
+        let ri = RedirectInformation::new(RedirectAddressType::Ipv4, "1.2.3.4").unwrap();
         let marshaled = ri.marshal();
+        assert_eq!(
+            marshaled,
+            vec![0x00, 0x00, 0x07, b'1', b'.', b'2', b'.', b'3', b'.', b'4']
+        );
         let unmarshaled = RedirectInformation::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, ri);
     }
 
     #[test]
     fn test_redirect_information_marshal_unmarshal_url() {
-        let ri = RedirectInformation::new(RedirectAddressType::Url, "http://example.com");
+        let ri = RedirectInformation::new(RedirectAddressType::Url, "http://example.com").unwrap();
         let marshaled = ri.marshal();
+        assert_eq!(marshaled[0], 0x02);
+        assert_eq!(&marshaled[1..3], &[0x00, 18]);
         let unmarshaled = RedirectInformation::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, ri);
     }
 
+    #[test]
+    fn test_redirect_information_ipv4_and_ipv6() {
+        let ri = RedirectInformationBuilder::new(RedirectAddressType::Ipv4AndIpv6, "192.0.2.1")
+            .other_address("2001:db8::1")
+            .build()
+            .unwrap();
+        let ie = ri.to_ie();
+        assert_eq!(ie.ie_type, IeType::RedirectInformation);
+
+        let unmarshaled = RedirectInformation::unmarshal(&ie.payload).unwrap();
+        assert_eq!(unmarshaled, ri);
+        assert_eq!(unmarshaled.other_address.as_deref(), Some("2001:db8::1"));
+    }
+
+    #[test]
+    fn test_redirect_information_builder_rejects_other_address() {
+        let result = RedirectInformation::builder(RedirectAddressType::Url, "http://example.com")
+            .other_address("http://fallback.example.com")
+            .build();
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_redirect_information_rejects_oversized_address() {
+        let long = "a".repeat(u16::MAX as usize + 1);
+        assert!(matches!(
+            RedirectInformation::new(RedirectAddressType::Url, &long),
+            Err(PfcpError::InvalidValue { .. })
+        ));
+        assert!(
+            RedirectInformation::builder(RedirectAddressType::Ipv4AndIpv6, "192.0.2.1")
+                .other_address(&long)
+                .build()
+                .is_err()
+        );
+
+        let longest = "a".repeat(u16::MAX as usize);
+        let ri = RedirectInformation::new(RedirectAddressType::Url, &longest).unwrap();
+        assert_eq!(&ri.marshal()[1..3], &[0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn test_redirect_information_truncated_address() {
+        // Declares a 7-byte address but only carries 3
+        let data = vec![0x00, 0x00, 0x07, b'1', b'.', b'2'];
+        let result = RedirectInformation::unmarshal(&data);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
+    }
+
     #[test]
     fn test_redirect_information_unknown_address_type() {
         // Unknown address type must return error, not panic
@@ -6,56 +6,57 @@
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
+use bitflags::bitflags;
 
-/// Report Type
-///
-/// Identifies the type of report in PFCP Session Report Request messages.
-/// Multiple report type flags can be set simultaneously to indicate combined reports.
-///
-/// # 3GPP Reference
-/// 3GPP TS 29.244 Section 8.2.21
-///
-/// # Structure
-/// - Octet 5: Report type flags (bits 1-4 + optional bits 5-8)
-/// - Octet 6 (optional): Additional report type flags (when subscriber trace enabled)
-///
-/// # Report Types
-/// - **DLDR**: Downlink Data Report
-/// - **USAR**: Usage Report
-/// - **ERIR**: Error Indication Report
-/// - **UPIR**: User Plane Inactivity Report
-///
-/// # Examples
-///
-/// ```
-/// use rs_pfcp::ie::report_type::ReportType;
-///
-/// // Create a usage report
-/// let usage_report = ReportType::new().with_usage_report(true);
-/// assert!(usage_report.is_usage_report());
-///
-/// // Create a combined downlink data + usage report
-/// let combined = ReportType::new()
-///     .with_downlink_data_report(true)
-///     .with_usage_report(true);
-/// assert!(combined.is_downlink_data_report());
-/// assert!(combined.is_usage_report());
-///
-/// // Marshal and unmarshal
-/// let bytes = usage_report.marshal();
-/// let parsed = ReportType::unmarshal(&bytes).unwrap();
-/// assert_eq!(usage_report, parsed);
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub struct ReportType {
-    /// Downlink Data Report (bit 1 of octet 5)
-    pub downlink_data_report: bool,
-    /// Usage Report (bit 2 of octet 5)
-    pub usage_report: bool,
-    /// Error Indication Report (bit 3 of octet 5)
-    pub error_indication_report: bool,
-    /// User Plane Inactivity Report (bit 4 of octet 5)
-    pub user_plane_inactivity_report: bool,
+bitflags! {
+    /// Report Type
+    ///
+    /// Identifies the type of report in PFCP Session Report Request messages.
+    /// Multiple report type flags can be set simultaneously to indicate combined reports.
+    ///
+    /// # 3GPP Reference
+    /// 3GPP TS 29.244 Section 8.2.21
+    ///
+    /// # Structure
+    /// - Octet 5: Report type flags (bits 1-7, bit 8 spare)
+    ///
+    /// # Report Types
+    /// - **DLDR**: Downlink Data Report
+    /// - **USAR**: Usage Report
+    /// - **ERIR**: Error Indication Report
+    /// - **UPIR**: User Plane Inactivity Report
+    /// - **PMIR**: Packet Measurement Indication Report
+    /// - **SESR**: Session Enhancement Success Report
+    /// - **TMIR**: Traffic Measurement Indication Report
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::ie::report_type::ReportType;
+    ///
+    /// // Create a usage report
+    /// let usage_report = ReportType::new().with_usage_report(true);
+    /// assert!(usage_report.is_usage_report());
+    ///
+    /// // Combine flags directly
+    /// let combined = ReportType::USAR | ReportType::UPIR;
+    /// assert_eq!(combined.marshal(), vec![0x0A]);
+    ///
+    /// // Marshal and unmarshal
+    /// let bytes = usage_report.marshal();
+    /// let parsed = ReportType::unmarshal(&bytes).unwrap();
+    /// assert_eq!(usage_report, parsed);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    pub struct ReportType: u8 {
+        const DLDR = 1 << 0; // Bit 1: Downlink Data Report
+        const USAR = 1 << 1; // Bit 2: Usage Report
+        const ERIR = 1 << 2; // Bit 3: Error Indication Report
+        const UPIR = 1 << 3; // Bit 4: User Plane Inactivity Report
+        const PMIR = 1 << 4; // Bit 5: Packet Measurement Indication Report
+        const SESR = 1 << 5; // Bit 6: Session Enhancement Success Report
+        const TMIR = 1 << 6; // Bit 7: Traffic Measurement Indication Report
+    }
 }
 
 impl ReportType {
@@ -70,7 +71,7 @@ impl ReportType {
     /// assert!(!report.is_downlink_data_report());
     /// ```
     pub fn new() -> Self {
-        Default::default()
+        ReportType::empty()
     }
 
     /// Set the Downlink Data Report flag
@@ -83,7 +84,7 @@ impl ReportType {
     /// assert!(report.is_downlink_data_report());
     /// ```
     pub fn with_downlink_data_report(mut self, enabled: bool) -> Self {
-        self.downlink_data_report = enabled;
+        self.set(ReportType::DLDR, enabled);
         self
     }
 
@@ -97,7 +98,7 @@ impl ReportType {
     /// assert!(report.is_usage_report());
     /// ```
     pub fn with_usage_report(mut self, enabled: bool) -> Self {
-        self.usage_report = enabled;
+        self.set(ReportType::USAR, enabled);
         self
     }
 
@@ -111,7 +112,7 @@ impl ReportType {
     /// assert!(report.is_error_indication_report());
     /// ```
     pub fn with_error_indication_report(mut self, enabled: bool) -> Self {
-        self.error_indication_report = enabled;
+        self.set(ReportType::ERIR, enabled);
         self
     }
 
@@ -125,28 +126,28 @@ impl ReportType {
     /// assert!(report.is_user_plane_inactivity_report());
     /// ```
     pub fn with_user_plane_inactivity_report(mut self, enabled: bool) -> Self {
-        self.user_plane_inactivity_report = enabled;
+        self.set(ReportType::UPIR, enabled);
         self
     }
 
     /// Check if Downlink Data Report is enabled
     pub fn is_downlink_data_report(&self) -> bool {
-        self.downlink_data_report
+        self.contains(ReportType::DLDR)
     }
 
     /// Check if Usage Report is enabled
     pub fn is_usage_report(&self) -> bool {
-        self.usage_report
+        self.contains(ReportType::USAR)
     }
 
     /// Check if Error Indication Report is enabled
     pub fn is_error_indication_report(&self) -> bool {
-        self.error_indication_report
+        self.contains(ReportType::ERIR)
     }
 
     /// Check if User Plane Inactivity Report is enabled
     pub fn is_user_plane_inactivity_report(&self) -> bool {
-        self.user_plane_inactivity_report
+        self.contains(ReportType::UPIR)
     }
 
     /// Marshal Report Type to bytes
@@ -154,26 +155,13 @@ impl ReportType {
     /// # Returns
     /// 1-byte vector containing report type flags in octet 5
     pub fn marshal(&self) -> Vec<u8> {
-        let mut octet5 = 0u8;
-
-        if self.downlink_data_report {
-            octet5 |= 0x01; // Bit 1
-        }
-        if self.usage_report {
-            octet5 |= 0x02; // Bit 2
-        }
-        if self.error_indication_report {
-            octet5 |= 0x04; // Bit 3
-        }
-        if self.user_plane_inactivity_report {
-            octet5 |= 0x08; // Bit 4
-        }
-
-        vec![octet5]
+        vec![self.bits()]
     }
 
     /// Unmarshal Report Type from bytes
     ///
+    /// The spare bit 8 is ignored.
+    ///
     /// # Arguments
     /// * `data` - Byte slice containing report type data (must be at least 1 byte)
     ///
@@ -199,14 +187,7 @@ impl ReportType {
             ));
         }
 
-        let octet5 = data[0];
-
-        Ok(ReportType {
-            downlink_data_report: (octet5 & 0x01) != 0,
-            usage_report: (octet5 & 0x02) != 0,
-            error_indication_report: (octet5 & 0x04) != 0,
-            user_plane_inactivity_report: (octet5 & 0x08) != 0,
-        })
+        Ok(ReportType::from_bits_truncate(data[0]))
     }
 
     /// Convert to generic IE
@@ -297,8 +278,8 @@ mod tests {
     #[test]
     fn test_report_type_default() {
         let report: ReportType = Default::default();
-        assert!(!report.downlink_data_report);
-        assert!(!report.usage_report);
+        assert!(report.is_empty());
+        assert_eq!(report, ReportType::new());
     }
 
     #[test]
@@ -478,7 +459,7 @@ mod tests {
 
     #[test]
     fn test_report_type_unmarshal_with_spare_bits() {
-        // Spare bits (5-8) should be ignored
+        // Spare bit 8 should be ignored
         let data = vec![0xFF]; // All bits set
         let report = ReportType::unmarshal(&data).unwrap();
         assert!(report.is_downlink_data_report());
@@ -487,6 +468,54 @@ mod tests {
         assert!(report.is_user_plane_inactivity_report());
     }
 
+    #[test]
+    fn test_report_type_flag_bits() {
+        for (flag, bit) in [
+            (ReportType::DLDR, 0x01),
+            (ReportType::USAR, 0x02),
+            (ReportType::ERIR, 0x04),
+            (ReportType::UPIR, 0x08),
+            (ReportType::PMIR, 0x10),
+            (ReportType::SESR, 0x20),
+            (ReportType::TMIR, 0x40),
+        ] {
+            assert_eq!(flag.marshal(), vec![bit]);
+            assert_eq!(ReportType::unmarshal(&[bit]).unwrap(), flag);
+        }
+    }
+
+    #[test]
+    fn test_report_type_usar_upir() {
+        let report = ReportType::USAR | ReportType::UPIR;
+        assert_eq!(report.marshal(), vec![0x0A]);
+        assert!(report.is_usage_report());
+        assert!(report.is_user_plane_inactivity_report());
+        assert!(!report.is_downlink_data_report());
+        assert!(!report.is_error_indication_report());
+
+        let parsed = ReportType::unmarshal(&[0x0A]).unwrap();
+        assert_eq!(parsed, report);
+        assert_eq!(
+            parsed,
+            ReportType::usage_report().with_user_plane_inactivity_report(true)
+        );
+    }
+
+    #[test]
+    fn test_report_type_round_trip_all_combinations() {
+        for bits in 0u8..=0x7F {
+            let report = ReportType::from_bits(bits).unwrap();
+            let parsed = ReportType::unmarshal(&report.marshal()).unwrap();
+            assert_eq!(parsed, report);
+        }
+    }
+
+    #[test]
+    fn test_report_type_unmarshal_ignores_spare_bit() {
+        let report = ReportType::unmarshal(&[0x80 | 0x20]).unwrap();
+        assert_eq!(report, ReportType::SESR);
+    }
+
     #[test]
     fn test_report_type_unmarshal_empty_buffer() {
         let data = vec![];
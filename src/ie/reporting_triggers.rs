@@ -1,20 +1,80 @@
 //! ReportingTriggers IE.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.19, the Reporting Triggers IE lists the
+//! events that make the UP function send a usage report. Release 15 sends
+//! octets 5 and 6; octet 7 was added in Release 16.
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
 
-/// Represents Reporting Triggers.
+// Octet 5
+const PERIO: u8 = 0x01;
+const VOLTH: u8 = 0x02;
+const TIMTH: u8 = 0x04;
+const QUHTI: u8 = 0x08;
+const START: u8 = 0x10;
+const STOPT: u8 = 0x20;
+const DROTH: u8 = 0x40;
+const LIUSA: u8 = 0x80;
+
+// Octet 6
+const VOLQU: u8 = 0x01;
+const TIMQU: u8 = 0x02;
+const ENVCL: u8 = 0x04;
+const MACAR: u8 = 0x08;
+const EVETH: u8 = 0x10;
+const EVEQU: u8 = 0x20;
+const IPMJL: u8 = 0x40;
+const QUVTI: u8 = 0x80;
+
+// Octet 7
+const REEMR: u8 = 0x01;
+const UPINT: u8 = 0x02;
+
+/// Represents Reporting Triggers per 3GPP TS 29.244 Table 8.2.19-1.
+///
+/// # Wire Format
+/// - Octet 5: LIUSA | DROTH | STOPT | START | QUHTI | TIMTH | VOLTH | PERIO
+/// - Octet 6: QUVTI | IPMJL | EVEQU | EVETH | MACAR | ENVCL | TIMQU | VOLQU
+/// - Octet 7: Spare | UPINT | REEMR (only sent if one of its bits is set)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ReportingTriggers {
+    /// Periodic Reporting.
     pub periodic: bool,
+    /// Volume Threshold reached.
     pub volume_threshold: bool,
+    /// Time Threshold reached.
     pub time_threshold: bool,
+    /// Quota Holding Time expired (QUHTI).
     pub quota_exhausted: bool,
+    /// Start of Traffic detected.
     pub start_of_traffic: bool,
+    /// Stop of Traffic detected.
     pub stop_of_traffic: bool,
+    /// Dropped DL Traffic Threshold reached.
     pub dropped_dl_traffic: bool,
+    /// Linked Usage Reporting.
     pub linked_urr: bool,
+    /// Volume Quota exhausted.
+    pub volume_quota: bool,
+    /// Time Quota exhausted.
+    pub time_quota: bool,
+    /// Envelope Closure.
+    pub envelope_closure: bool,
+    /// MAC Addresses Reporting.
+    pub mac_addresses_reporting: bool,
+    /// Event Threshold reached.
     pub event_threshold: bool,
+    /// Event Quota exhausted.
+    pub event_quota: bool,
+    /// IP Multicast Join/Leave.
+    pub ip_multicast_join_leave: bool,
+    /// Quota Validity Time expired.
+    pub quota_validity_time: bool,
+    /// Report the End Marker Reception.
+    pub end_marker_reception: bool,
+    /// User Plane Inactivity Timer expired.
+    pub user_plane_inactivity_timer: bool,
 }
 
 impl ReportingTriggers {
@@ -63,64 +123,154 @@ impl ReportingTriggers {
         self
     }
 
+    pub fn with_volume_quota(mut self, volume_quota: bool) -> Self {
+        self.volume_quota = volume_quota;
+        self
+    }
+
+    pub fn with_time_quota(mut self, time_quota: bool) -> Self {
+        self.time_quota = time_quota;
+        self
+    }
+
+    pub fn with_envelope_closure(mut self, envelope_closure: bool) -> Self {
+        self.envelope_closure = envelope_closure;
+        self
+    }
+
+    pub fn with_mac_addresses_reporting(mut self, mac_addresses_reporting: bool) -> Self {
+        self.mac_addresses_reporting = mac_addresses_reporting;
+        self
+    }
+
     pub fn with_event_threshold(mut self, event_threshold: bool) -> Self {
         self.event_threshold = event_threshold;
         self
     }
 
+    pub fn with_event_quota(mut self, event_quota: bool) -> Self {
+        self.event_quota = event_quota;
+        self
+    }
+
+    pub fn with_ip_multicast_join_leave(mut self, ip_multicast_join_leave: bool) -> Self {
+        self.ip_multicast_join_leave = ip_multicast_join_leave;
+        self
+    }
+
+    pub fn with_quota_validity_time(mut self, quota_validity_time: bool) -> Self {
+        self.quota_validity_time = quota_validity_time;
+        self
+    }
+
+    pub fn with_end_marker_reception(mut self, end_marker_reception: bool) -> Self {
+        self.end_marker_reception = end_marker_reception;
+        self
+    }
+
+    pub fn with_user_plane_inactivity_timer(mut self, user_plane_inactivity_timer: bool) -> Self {
+        self.user_plane_inactivity_timer = user_plane_inactivity_timer;
+        self
+    }
+
     /// Marshals the Reporting Triggers into a byte vector.
+    ///
+    /// Octets 5 and 6 are always present; octet 7 is appended only when one
+    /// of its triggers is set.
     pub fn marshal(&self) -> Vec<u8> {
-        let mut b = [0; 2];
+        let mut b = [0; 3];
         if self.periodic {
-            b[0] |= 1;
+            b[0] |= PERIO;
         }
         if self.volume_threshold {
-            b[0] |= 2;
+            b[0] |= VOLTH;
         }
         if self.time_threshold {
-            b[0] |= 4;
+            b[0] |= TIMTH;
         }
         if self.quota_exhausted {
-            b[0] |= 8;
+            b[0] |= QUHTI;
         }
         if self.start_of_traffic {
-            b[0] |= 16;
+            b[0] |= START;
         }
         if self.stop_of_traffic {
-            b[0] |= 32;
+            b[0] |= STOPT;
         }
         if self.dropped_dl_traffic {
-            b[0] |= 64;
+            b[0] |= DROTH;
         }
         if self.linked_urr {
-            b[0] |= 128;
+            b[0] |= LIUSA;
+        }
+        if self.volume_quota {
+            b[1] |= VOLQU;
+        }
+        if self.time_quota {
+            b[1] |= TIMQU;
+        }
+        if self.envelope_closure {
+            b[1] |= ENVCL;
+        }
+        if self.mac_addresses_reporting {
+            b[1] |= MACAR;
         }
         if self.event_threshold {
-            b[1] |= 1;
+            b[1] |= EVETH;
+        }
+        if self.event_quota {
+            b[1] |= EVEQU;
+        }
+        if self.ip_multicast_join_leave {
+            b[1] |= IPMJL;
         }
-        b.to_vec()
+        if self.quota_validity_time {
+            b[1] |= QUVTI;
+        }
+        if self.end_marker_reception {
+            b[2] |= REEMR;
+        }
+        if self.user_plane_inactivity_timer {
+            b[2] |= UPINT;
+        }
+        let len = if b[2] != 0 { 3 } else { 2 };
+        b[..len].to_vec()
     }
 
     /// Unmarshals a byte slice into a Reporting Triggers.
+    ///
+    /// Accepts one to three octets; missing octets are treated as zero and
+    /// octets beyond the third are ignored.
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
-        if payload.len() < 2 {
+        if payload.is_empty() {
             return Err(PfcpError::invalid_length(
                 "Reporting Triggers",
                 IeType::ReportingTriggers,
-                2,
-                payload.len(),
+                1,
+                0,
             ));
         }
+        let octet = |i: usize| payload.get(i).copied().unwrap_or(0);
+        let (b0, b1, b2) = (octet(0), octet(1), octet(2));
         Ok(ReportingTriggers {
-            periodic: (payload[0] & 1) != 0,
-            volume_threshold: (payload[0] & 2) != 0,
-            time_threshold: (payload[0] & 4) != 0,
-            quota_exhausted: (payload[0] & 8) != 0,
-            start_of_traffic: (payload[0] & 16) != 0,
-            stop_of_traffic: (payload[0] & 32) != 0,
-            dropped_dl_traffic: (payload[0] & 64) != 0,
-            linked_urr: (payload[0] & 128) != 0,
-            event_threshold: (payload[1] & 1) != 0,
+            periodic: b0 & PERIO != 0,
+            volume_threshold: b0 & VOLTH != 0,
+            time_threshold: b0 & TIMTH != 0,
+            quota_exhausted: b0 & QUHTI != 0,
+            start_of_traffic: b0 & START != 0,
+            stop_of_traffic: b0 & STOPT != 0,
+            dropped_dl_traffic: b0 & DROTH != 0,
+            linked_urr: b0 & LIUSA != 0,
+            volume_quota: b1 & VOLQU != 0,
+            time_quota: b1 & TIMQU != 0,
+            envelope_closure: b1 & ENVCL != 0,
+            mac_addresses_reporting: b1 & MACAR != 0,
+            event_threshold: b1 & EVETH != 0,
+            event_quota: b1 & EVEQU != 0,
+            ip_multicast_join_leave: b1 & IPMJL != 0,
+            quota_validity_time: b1 & QUVTI != 0,
+            end_marker_reception: b2 & REEMR != 0,
+            user_plane_inactivity_timer: b2 & UPINT != 0,
         })
     }
 
@@ -339,8 +489,7 @@ mod tests {
     fn test_reporting_triggers_marshal_event_threshold() {
         let triggers = ReportingTriggers::new().with_event_threshold(true);
         let marshaled = triggers.marshal();
-        assert_eq!(marshaled[0], 0);
-        assert_eq!(marshaled[1] & 1, 1);
+        assert_eq!(marshaled, vec![0x00, EVETH]);
     }
 
     #[test]
@@ -358,7 +507,7 @@ mod tests {
 
         let marshaled = triggers.marshal();
         assert_eq!(marshaled[0], 0xFF); // All 8 bits set
-        assert_eq!(marshaled[1], 0x01); // Event threshold bit set
+        assert_eq!(marshaled[1], EVETH); // Event threshold bit set
     }
 
     #[test]
@@ -380,7 +529,7 @@ mod tests {
 
     #[test]
     fn test_reporting_triggers_unmarshal_all_flags() {
-        let data = vec![0xFF, 0x01];
+        let data = vec![0xFF, EVETH];
         let triggers = ReportingTriggers::unmarshal(&data).unwrap();
         assert!(triggers.periodic);
         assert!(triggers.volume_threshold);
@@ -394,14 +543,72 @@ mod tests {
     }
 
     #[test]
-    fn test_reporting_triggers_unmarshal_short_buffer() {
+    fn test_reporting_triggers_unmarshal_single_octet() {
         let data = vec![0x01];
-        let result = ReportingTriggers::unmarshal(&data);
-        assert!(result.is_err());
-        assert!(matches!(
-            result.unwrap_err(),
-            PfcpError::InvalidLength { .. }
-        ));
+        let triggers = ReportingTriggers::unmarshal(&data).unwrap();
+        assert_eq!(triggers, ReportingTriggers::new().with_periodic(true));
+    }
+
+    #[test]
+    fn test_reporting_triggers_octet_6_bits() {
+        let cases = [
+            (ReportingTriggers::new().with_volume_quota(true), VOLQU),
+            (ReportingTriggers::new().with_time_quota(true), TIMQU),
+            (ReportingTriggers::new().with_envelope_closure(true), ENVCL),
+            (
+                ReportingTriggers::new().with_mac_addresses_reporting(true),
+                MACAR,
+            ),
+            (ReportingTriggers::new().with_event_threshold(true), EVETH),
+            (ReportingTriggers::new().with_event_quota(true), EVEQU),
+            (
+                ReportingTriggers::new().with_ip_multicast_join_leave(true),
+                IPMJL,
+            ),
+            (
+                ReportingTriggers::new().with_quota_validity_time(true),
+                QUVTI,
+            ),
+        ];
+        for (triggers, bit) in cases {
+            assert_eq!(triggers.marshal(), vec![0x00, bit]);
+            assert_eq!(
+                ReportingTriggers::unmarshal(&[0x00, bit]).unwrap(),
+                triggers
+            );
+        }
+    }
+
+    #[test]
+    fn test_reporting_triggers_octet_7_bits() {
+        let triggers = ReportingTriggers::new().with_end_marker_reception(true);
+        assert_eq!(triggers.marshal(), vec![0x00, 0x00, REEMR]);
+        assert_eq!(
+            ReportingTriggers::unmarshal(&triggers.marshal()).unwrap(),
+            triggers
+        );
+
+        let triggers = ReportingTriggers::new()
+            .with_periodic(true)
+            .with_user_plane_inactivity_timer(true);
+        let ie = triggers.to_ie();
+        assert_eq!(ie.payload, vec![PERIO, 0x00, UPINT]);
+        assert_eq!(ReportingTriggers::unmarshal(&ie.payload).unwrap(), triggers);
+    }
+
+    #[test]
+    fn test_reporting_triggers_all_three_octets() {
+        let data = [0xFF, 0xFF, REEMR | UPINT];
+        let triggers = ReportingTriggers::unmarshal(&data).unwrap();
+        assert!(triggers.linked_urr);
+        assert!(triggers.quota_validity_time);
+        assert!(triggers.end_marker_reception);
+        assert!(triggers.user_plane_inactivity_timer);
+        assert_eq!(triggers.marshal(), data.to_vec());
+
+        // Spare bits and octets beyond the third are ignored
+        let triggers = ReportingTriggers::unmarshal(&[0x00, 0x00, 0xFC, 0xFF]).unwrap();
+        assert_eq!(triggers, ReportingTriggers::new());
     }
 
     #[test]
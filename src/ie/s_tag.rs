@@ -3,26 +3,30 @@
 //! The S-TAG IE contains service provider VLAN tagging information for Ethernet packet filtering.
 //! Per 3GPP TS 29.244 Section 8.2.95, this IE is used in Ethernet packet filtering scenarios.
 //!
-//! S-TAG is used in provider bridging (IEEE 802.1ad) for service provider VLAN tagging.
+//! S-TAG consists of priority, DEI (Drop Eligible Indicator), and VID (VLAN ID),
+//! each preceded by a flag telling whether the UP function shall use it.
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
 
+const PCP: u8 = 0x01;
+const DEI: u8 = 0x02;
+const VID: u8 = 0x04;
+
 /// S-TAG (Service VLAN Tag)
 ///
 /// Represents service provider VLAN tagging information per IEEE 802.1ad.
+/// A field that is `None` is not used for matching and its flag is cleared
+/// on the wire.
 ///
 /// # 3GPP Reference
 /// 3GPP TS 29.244 Section 8.2.95
 ///
 /// # Structure
-/// - Octet 5: Flags (PCP Priority, DEI)
-/// - Octet 5-6: VID (VLAN ID) - 12 bits
-///
 /// ```text
-/// Bits 8-6: PCP (Priority Code Point) - 3 bits
-/// Bit 5: DEI (Drop Eligible Indicator) - 1 bit
-/// Bits 4-1 (and next 8 bits): VID (VLAN ID) - 12 bits
+/// Octet 5: Spare (bits 8-4) | VID (bit 3) | DEI (bit 2) | PCP (bit 1)
+/// Octet 6: S-VID value bits 12-9 (bits 8-5) | DEI flag (bit 4) | PCP value (bits 3-1)
+/// Octet 7: S-VID value bits 8-1
 /// ```
 ///
 /// # Examples
@@ -32,23 +36,24 @@ use crate::ie::{Ie, IeType};
 ///
 /// // Create S-TAG with priority 3, no DEI, VLAN ID 100
 /// let stag = STag::new(3, false, 100).unwrap();
-/// assert_eq!(stag.priority(), 3);
-/// assert_eq!(stag.dei(), false);
-/// assert_eq!(stag.vid(), 100);
+/// assert_eq!(stag.priority(), Some(3));
+/// assert_eq!(stag.dei(), Some(false));
+/// assert_eq!(stag.vid(), Some(100));
 ///
 /// // Marshal and unmarshal
 /// let bytes = stag.marshal();
+/// assert_eq!(bytes, [0x07, 0x03, 0x64]);
 /// let parsed = STag::unmarshal(&bytes).unwrap();
 /// assert_eq!(stag, parsed);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct STag {
     /// Priority Code Point (0-7, 3 bits)
-    pcp: u8,
+    pcp: Option<u8>,
     /// Drop Eligible Indicator (boolean)
-    dei: bool,
+    dei: Option<bool>,
     /// VLAN ID (0-4095, 12 bits)
-    vid: u16,
+    vid: Option<u16>,
 }
 
 impl STag {
@@ -57,7 +62,7 @@ impl STag {
     /// Maximum valid VID (12 bits = 0-4095)
     pub const MAX_VID: u16 = 4095;
 
-    /// Create a new S-TAG
+    /// Create a new S-TAG with all of PCP, DEI and VID set
     ///
     /// # Arguments
     /// * `pcp` - Priority Code Point (0-7)
@@ -72,19 +77,41 @@ impl STag {
     /// use rs_pfcp::ie::s_tag::STag;
     ///
     /// let stag = STag::new(5, true, 1000).unwrap();
-    /// assert_eq!(stag.priority(), 5);
-    /// assert_eq!(stag.dei(), true);
-    /// assert_eq!(stag.vid(), 1000);
+    /// assert_eq!(stag.priority(), Some(5));
+    /// assert_eq!(stag.dei(), Some(true));
+    /// assert_eq!(stag.vid(), Some(1000));
     /// ```
     pub fn new(pcp: u8, dei: bool, vid: u16) -> Result<Self, PfcpError> {
-        if pcp > Self::MAX_PRIORITY {
+        Self::from_fields(Some(pcp), Some(dei), Some(vid))
+    }
+
+    /// Create a S-TAG with only some of PCP, DEI and VID set
+    ///
+    /// # Errors
+    /// Returns error if PCP > 7 or VID > 4095
+    ///
+    /// # Example
+    /// ```
+    /// use rs_pfcp::ie::s_tag::STag;
+    ///
+    /// // Match VLAN 100 regardless of priority and DEI
+    /// let stag = STag::from_fields(None, None, Some(100)).unwrap();
+    /// assert_eq!(stag.priority(), None);
+    /// assert_eq!(stag.marshal(), [0x04, 0x00, 0x64]);
+    /// ```
+    pub fn from_fields(
+        pcp: Option<u8>,
+        dei: Option<bool>,
+        vid: Option<u16>,
+    ) -> Result<Self, PfcpError> {
+        if let Some(pcp) = pcp.filter(|pcp| *pcp > Self::MAX_PRIORITY) {
             return Err(PfcpError::invalid_value(
                 "S-TAG PCP",
                 pcp.to_string(),
                 format!("exceeds maximum {}", Self::MAX_PRIORITY),
             ));
         }
-        if vid > Self::MAX_VID {
+        if let Some(vid) = vid.filter(|vid| *vid > Self::MAX_VID) {
             return Err(PfcpError::invalid_value(
                 "S-TAG VID",
                 vid.to_string(),
@@ -94,38 +121,47 @@ impl STag {
         Ok(STag { pcp, dei, vid })
     }
 
-    /// Get the Priority Code Point
-    pub fn priority(&self) -> u8 {
+    /// Get the Priority Code Point, if set
+    pub fn priority(&self) -> Option<u8> {
         self.pcp
     }
 
-    /// Get the Drop Eligible Indicator
-    pub fn dei(&self) -> bool {
+    /// Get the Drop Eligible Indicator, if set
+    pub fn dei(&self) -> Option<bool> {
         self.dei
     }
 
-    /// Get the VLAN ID
-    pub fn vid(&self) -> u16 {
+    /// Get the VLAN ID, if set
+    pub fn vid(&self) -> Option<u16> {
         self.vid
     }
 
     /// Marshal S-TAG to bytes
     ///
     /// # Returns
-    /// 3-byte array with S-TAG encoded
+    /// 3-byte array: the flags octet, then the PCP, DEI and VID values
+    /// (values whose flag is clear are encoded as zero)
     pub fn marshal(&self) -> [u8; 3] {
-        let mut bytes = [0u8; 3];
-
-        // Byte 0: PCP (3 bits) | DEI (1 bit) | VID[11:8] (4 bits)
-        bytes[0] = (self.pcp << 5) | ((self.dei as u8) << 4) | ((self.vid >> 8) as u8 & 0x0F);
-
-        // Byte 1: VID[7:0]
-        bytes[1] = (self.vid & 0xFF) as u8;
+        let mut flags = 0;
+        if self.pcp.is_some() {
+            flags |= PCP;
+        }
+        if self.dei.is_some() {
+            flags |= DEI;
+        }
+        if self.vid.is_some() {
+            flags |= VID;
+        }
 
-        // Byte 2: Spare (0)
-        bytes[2] = 0;
+        let pcp = self.pcp.unwrap_or(0) & 0x07;
+        let dei = self.dei.unwrap_or(false) as u8;
+        let vid = self.vid.unwrap_or(0);
 
-        bytes
+        [
+            flags,
+            ((vid >> 8) as u8 & 0x0F) << 4 | dei << 3 | pcp,
+            (vid & 0xFF) as u8,
+        ]
     }
 
     /// Unmarshal S-TAG from bytes
@@ -155,14 +191,10 @@ impl STag {
             ));
         }
 
-        // Extract PCP (bits 7-5 of byte 0)
-        let pcp = (data[0] >> 5) & 0x07;
-
-        // Extract DEI (bit 4 of byte 0)
-        let dei = (data[0] & 0x10) != 0;
-
-        // Extract VID (bits 3-0 of byte 0 + byte 1)
-        let vid = (((data[0] & 0x0F) as u16) << 8) | (data[1] as u16);
+        let flags = data[0];
+        let pcp = (flags & PCP != 0).then_some(data[1] & 0x07);
+        let dei = (flags & DEI != 0).then_some(data[1] & 0x08 != 0);
+        let vid = (flags & VID != 0).then_some(((data[1] >> 4) as u16) << 8 | data[2] as u16);
 
         Ok(STag { pcp, dei, vid })
     }
@@ -190,14 +222,14 @@ mod tests {
     #[test]
     fn test_stag_new_valid() {
         let stag = STag::new(0, false, 0).unwrap();
-        assert_eq!(stag.priority(), 0);
-        assert!(!stag.dei());
-        assert_eq!(stag.vid(), 0);
+        assert_eq!(stag.priority(), Some(0));
+        assert_eq!(stag.dei(), Some(false));
+        assert_eq!(stag.vid(), Some(0));
 
         let stag2 = STag::new(7, true, 4095).unwrap();
-        assert_eq!(stag2.priority(), 7);
-        assert!(stag2.dei());
-        assert_eq!(stag2.vid(), 4095);
+        assert_eq!(stag2.priority(), Some(7));
+        assert_eq!(stag2.dei(), Some(true));
+        assert_eq!(stag2.vid(), Some(4095));
     }
 
     #[test]
@@ -214,6 +246,9 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, PfcpError::InvalidValue { .. }));
+
+        assert!(STag::from_fields(None, None, Some(4096)).is_err());
+        assert!(STag::from_fields(Some(8), None, None).is_err());
     }
 
     #[test]
@@ -222,28 +257,47 @@ mod tests {
         let bytes = stag.marshal();
         assert_eq!(bytes.len(), 3);
 
-        // Expected byte 0: 101 1 0011 = 0xB3
-        assert_eq!(bytes[0], 0xB3);
+        // PCP, DEI and VID flags all set
+        assert_eq!(bytes[0], 0x07);
 
-        // Verify VID low byte = 0xE8
-        assert_eq!(bytes[1], 0xE8);
+        // VID high nibble 3 (from 1000 = 0x3E8) | DEI 1 | PCP 5 (101)
+        // Expected byte: 0011 1 101 = 0x3D
+        assert_eq!(bytes[1], 0x3D);
 
-        // Spare byte
-        assert_eq!(bytes[2], 0x00);
+        // VID low byte
+        assert_eq!(bytes[2], 0xE8);
     }
 
     #[test]
     fn test_stag_unmarshal_valid() {
-        let data = [0xB3, 0xE8, 0x00]; // PCP=5, DEI=1, VID=1000
+        let data = [0x07, 0x3D, 0xE8]; // PCP=5, DEI=1, VID=1000
         let stag = STag::unmarshal(&data).unwrap();
-        assert_eq!(stag.priority(), 5);
-        assert!(stag.dei());
-        assert_eq!(stag.vid(), 1000);
+        assert_eq!(stag.priority(), Some(5));
+        assert_eq!(stag.dei(), Some(true));
+        assert_eq!(stag.vid(), Some(1000));
+    }
+
+    #[test]
+    fn test_stag_flags_select_fields() {
+        // No flags: no field is used, whatever the value octets hold
+        let stag = STag::unmarshal(&[0x00, 0x3D, 0xE8]).unwrap();
+        assert_eq!(stag, STag::from_fields(None, None, None).unwrap());
+        assert_eq!(stag.marshal(), [0x00, 0x00, 0x00]);
+
+        // VID only
+        let stag = STag::from_fields(None, None, Some(100)).unwrap();
+        assert_eq!(stag.marshal(), [0x04, 0x00, 0x64]);
+        assert_eq!(STag::unmarshal(&stag.marshal()).unwrap(), stag);
+
+        // PCP and DEI only
+        let stag = STag::from_fields(Some(6), Some(true), None).unwrap();
+        assert_eq!(stag.marshal(), [0x03, 0x0E, 0x00]);
+        assert_eq!(STag::unmarshal(&stag.marshal()).unwrap(), stag);
     }
 
     #[test]
     fn test_stag_unmarshal_short() {
-        let data = [0xB3, 0xE8];
+        let data = [0x07, 0x3D];
         let result = STag::unmarshal(&data);
         assert!(result.is_err());
     }
@@ -294,27 +348,27 @@ mod tests {
     fn test_stag_scenarios() {
         // Scenario 1: Service provider backbone VLAN
         let backbone = STag::new(7, false, 100).unwrap();
-        assert_eq!(backbone.vid(), 100);
-        assert_eq!(backbone.priority(), 7);
+        assert_eq!(backbone.vid(), Some(100));
+        assert_eq!(backbone.priority(), Some(7));
 
         // Scenario 2: Customer service VLAN with DEI
         let customer_service = STag::new(5, true, 500).unwrap();
-        assert_eq!(customer_service.priority(), 5);
-        assert!(customer_service.dei());
+        assert_eq!(customer_service.priority(), Some(5));
+        assert_eq!(customer_service.dei(), Some(true));
 
         // Scenario 3: Provider Edge VLAN
         let pe_vlan = STag::new(6, false, 1000).unwrap();
-        assert_eq!(pe_vlan.vid(), 1000);
+        assert_eq!(pe_vlan.vid(), Some(1000));
 
         // Scenario 4: QinQ double tagging scenario
         let outer_tag = STag::new(4, false, 2000).unwrap();
-        assert_eq!(outer_tag.vid(), 2000);
+        assert_eq!(outer_tag.vid(), Some(2000));
 
         // Scenario 5: Maximum values for provider network
         let max_stag = STag::new(7, true, 4095).unwrap();
-        assert_eq!(max_stag.priority(), 7);
-        assert!(max_stag.dei());
-        assert_eq!(max_stag.vid(), 4095);
+        assert_eq!(max_stag.priority(), Some(7));
+        assert_eq!(max_stag.dei(), Some(true));
+        assert_eq!(max_stag.vid(), Some(4095));
     }
 
     #[test]
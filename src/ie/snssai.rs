@@ -141,6 +141,77 @@ impl Snssai {
     }
 }
 
+/// Represents the HPLMN S-NSSAI (IE type 338).
+///
+/// Carries the S-NSSAI of the home PLMN for an HR-SBO PDU session. The
+/// encoding is identical to [`Snssai`]; only the IE type differs.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::snssai::{HplmnSnssai, Snssai};
+///
+/// let hplmn = HplmnSnssai::from_snssai(Snssai::with_sd(1, [0x01, 0x02, 0x03]));
+/// assert_eq!(hplmn.marshal(), vec![1, 0x01, 0x02, 0x03]);
+///
+/// let parsed = HplmnSnssai::unmarshal(&hplmn.marshal())?;
+/// assert_eq!(parsed.to_snssai().sst, 1);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HplmnSnssai(pub Snssai);
+
+impl HplmnSnssai {
+    /// Creates a new HPLMN S-NSSAI with only SST (Slice/Service Type).
+    pub fn new(sst: u8) -> Self {
+        HplmnSnssai(Snssai::new(sst))
+    }
+
+    /// Creates a new HPLMN S-NSSAI with both SST and SD (Slice Differentiator).
+    pub fn with_sd(sst: u8, sd: [u8; 3]) -> Self {
+        HplmnSnssai(Snssai::with_sd(sst, sd))
+    }
+
+    /// Wraps an S-NSSAI as the HPLMN S-NSSAI.
+    pub fn from_snssai(snssai: Snssai) -> Self {
+        HplmnSnssai(snssai)
+    }
+
+    /// Returns the inner S-NSSAI.
+    pub fn to_snssai(&self) -> Snssai {
+        self.0.clone()
+    }
+
+    /// Marshals the HPLMN S-NSSAI into a byte vector.
+    pub fn marshal(&self) -> Vec<u8> {
+        self.0.marshal()
+    }
+
+    /// Unmarshals a byte slice into an HPLMN S-NSSAI.
+    pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
+        if payload.is_empty() {
+            return Err(PfcpError::invalid_length(
+                "HPLMN S-NSSAI",
+                IeType::HplmnSNssai,
+                1,
+                0,
+            ));
+        }
+        Snssai::unmarshal(payload).map(HplmnSnssai)
+    }
+
+    /// Wraps the HPLMN S-NSSAI in an HPLMN S-NSSAI IE.
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::HplmnSNssai, self.marshal())
+    }
+}
+
+impl From<Snssai> for HplmnSnssai {
+    fn from(snssai: Snssai) -> Self {
+        HplmnSnssai(snssai)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,4 +345,49 @@ mod tests {
             assert_eq!(original, unmarshaled);
         }
     }
+
+    #[test]
+    fn test_hplmn_snssai_round_trip() {
+        let hplmn = HplmnSnssai::from_snssai(Snssai::with_sd(1, [0x01, 0x02, 0x03]));
+        let unmarshaled = HplmnSnssai::unmarshal(&hplmn.marshal()).unwrap();
+        assert_eq!(unmarshaled, hplmn);
+        assert_eq!(
+            unmarshaled.to_snssai(),
+            Snssai::with_sd(1, [0x01, 0x02, 0x03])
+        );
+
+        let sst_only = HplmnSnssai::new(2);
+        assert_eq!(
+            HplmnSnssai::unmarshal(&sst_only.marshal()).unwrap(),
+            sst_only
+        );
+    }
+
+    #[test]
+    fn test_hplmn_snssai_wire_matches_snssai() {
+        let snssai = Snssai::with_sd(1, [0x01, 0x02, 0x03]);
+        let hplmn = HplmnSnssai::from(snssai.clone());
+
+        let snssai_bytes = snssai.to_ie().marshal();
+        let hplmn_bytes = hplmn.to_ie().marshal();
+        assert_eq!(hplmn_bytes[..2], 338u16.to_be_bytes());
+        assert_eq!(snssai_bytes[..2], (IeType::Snssai as u16).to_be_bytes());
+        assert_eq!(hplmn_bytes[2..], snssai_bytes[2..]);
+        assert_eq!(hplmn.marshal(), vec![1, 0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn test_hplmn_snssai_unmarshal_invalid() {
+        let err = HplmnSnssai::unmarshal(&[]).unwrap_err();
+        assert!(matches!(
+            err,
+            PfcpError::InvalidLength {
+                ie_type: IeType::HplmnSNssai,
+                ..
+            }
+        ));
+
+        let result = HplmnSnssai::unmarshal(&[1, 2]);
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
 }
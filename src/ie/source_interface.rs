@@ -2,6 +2,7 @@
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
+use std::str::FromStr;
 
 /// Represents a Source Interface.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,6 +27,31 @@ impl From<u8> for SourceInterfaceValue {
     }
 }
 
+/// Parses the interface name case-insensitively, ignoring `-`, `_` and spaces,
+/// so `"Access"`, `"core"`, `"SGi-LAN"` and `"CP-Function"` are all accepted.
+impl FromStr for SourceInterfaceValue {
+    type Err = PfcpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: String = s
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match name.as_str() {
+            "access" => Ok(SourceInterfaceValue::Access),
+            "core" => Ok(SourceInterfaceValue::Core),
+            "sgilan" | "n6lan" => Ok(SourceInterfaceValue::SgiLan),
+            "cpfunction" => Ok(SourceInterfaceValue::CpFunction),
+            _ => Err(PfcpError::invalid_value(
+                "Source Interface",
+                s,
+                "expected Access, Core, SGi-LAN or CP-Function",
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SourceInterface {
     pub value: SourceInterfaceValue,
@@ -65,6 +91,14 @@ impl SourceInterface {
     }
 }
 
+impl FromStr for SourceInterface {
+    type Err = PfcpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(SourceInterface::new)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,4 +121,34 @@ mod tests {
         assert!(err.to_string().contains("1"));
         assert!(err.to_string().contains("0"));
     }
+
+    #[test]
+    fn test_source_interface_from_str() {
+        let cases = [
+            ("Access", SourceInterfaceValue::Access),
+            ("access", SourceInterfaceValue::Access),
+            ("Core", SourceInterfaceValue::Core),
+            ("CORE", SourceInterfaceValue::Core),
+            ("SGi-LAN", SourceInterfaceValue::SgiLan),
+            ("sgilan", SourceInterfaceValue::SgiLan),
+            ("CPFunction", SourceInterfaceValue::CpFunction),
+            ("CP-Function", SourceInterfaceValue::CpFunction),
+        ];
+        for (name, value) in cases {
+            assert_eq!(name.parse::<SourceInterfaceValue>().unwrap(), value);
+            assert_eq!(
+                SourceInterface::from_str(name).unwrap(),
+                SourceInterface::new(value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_source_interface_from_str_unknown() {
+        for name in ["", "Unknown", "N3", "Access1"] {
+            let err = name.parse::<SourceInterfaceValue>().unwrap_err();
+            assert!(matches!(err, PfcpError::InvalidValue { .. }));
+            assert!(SourceInterface::from_str(name).is_err());
+        }
+    }
 }
@@ -20,19 +20,27 @@ pub struct SourceIpAddress {
 }
 
 impl SourceIpAddress {
-    /// Creates a new Source IP Address.
-    pub fn new(ipv4: Option<Ipv4Addr>, ipv6: Option<Ipv6Addr>) -> Self {
-        SourceIpAddress {
-            v4: ipv4.is_some(),
-            v6: ipv6.is_some(),
-            ipv4,
-            ipv6,
-            mask_prefix_length: None,
+    /// Creates a new Source IP Address from optional IPv4 and IPv6 addresses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither address is present, since the IE must carry
+    /// at least one.
+    pub fn new(ipv4: Option<Ipv4Addr>, ipv6: Option<Ipv6Addr>) -> Result<Self, PfcpError> {
+        match (ipv4, ipv6) {
+            (Some(v4), Some(v6)) => Ok(Self::new_dual(v4, v6)),
+            (Some(v4), None) => Ok(Self::ipv4(v4)),
+            (None, Some(v6)) => Ok(Self::ipv6(v6)),
+            (None, None) => Err(PfcpError::invalid_value(
+                "Source IP Address",
+                "none",
+                "at least one IP address must be present",
+            )),
         }
     }
 
     /// Creates a new Source IP Address from IPv4 only.
-    pub fn new_ipv4(addr: Ipv4Addr) -> Self {
+    pub fn ipv4(addr: Ipv4Addr) -> Self {
         SourceIpAddress {
             v4: true,
             v6: false,
@@ -43,7 +51,7 @@ impl SourceIpAddress {
     }
 
     /// Creates a new Source IP Address from IPv6 only.
-    pub fn new_ipv6(addr: Ipv6Addr) -> Self {
+    pub fn ipv6(addr: Ipv6Addr) -> Self {
         SourceIpAddress {
             v4: false,
             v6: true,
@@ -53,6 +61,20 @@ impl SourceIpAddress {
         }
     }
 
+    /// Creates a new Source IP Address from IPv4 only.
+    ///
+    /// Equivalent to [`SourceIpAddress::ipv4`].
+    pub fn new_ipv4(addr: Ipv4Addr) -> Self {
+        Self::ipv4(addr)
+    }
+
+    /// Creates a new Source IP Address from IPv6 only.
+    ///
+    /// Equivalent to [`SourceIpAddress::ipv6`].
+    pub fn new_ipv6(addr: Ipv6Addr) -> Self {
+        Self::ipv6(addr)
+    }
+
     /// Creates a new Source IP Address from both IPv4 and IPv6.
     pub fn new_dual(ipv4: Ipv4Addr, ipv6: Ipv6Addr) -> Self {
         SourceIpAddress {
@@ -256,27 +278,54 @@ mod tests {
         let ipv6 = "::1".parse::<Ipv6Addr>().unwrap();
 
         // Both addresses
-        let source_ip = SourceIpAddress::new(Some(ipv4), Some(ipv6));
+        let source_ip = SourceIpAddress::new(Some(ipv4), Some(ipv6)).unwrap();
         assert_eq!(source_ip.ipv4, Some(ipv4));
         assert_eq!(source_ip.ipv6, Some(ipv6));
         assert!(source_ip.v4);
         assert!(source_ip.v6);
 
         // IPv4 only
-        let source_ip = SourceIpAddress::new(Some(ipv4), None);
+        let source_ip = SourceIpAddress::new(Some(ipv4), None).unwrap();
         assert_eq!(source_ip.ipv4, Some(ipv4));
         assert_eq!(source_ip.ipv6, None);
         assert!(source_ip.v4);
         assert!(!source_ip.v6);
 
         // IPv6 only
-        let source_ip = SourceIpAddress::new(None, Some(ipv6));
+        let source_ip = SourceIpAddress::new(None, Some(ipv6)).unwrap();
         assert_eq!(source_ip.ipv4, None);
         assert_eq!(source_ip.ipv6, Some(ipv6));
         assert!(!source_ip.v4);
         assert!(source_ip.v6);
     }
 
+    #[test]
+    fn test_source_ip_address_new_requires_an_address() {
+        let result = SourceIpAddress::new(None, None);
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_source_ip_address_single_stack_constructors_round_trip() {
+        let ipv4 = SourceIpAddress::ipv4(Ipv4Addr::new(203, 0, 113, 7));
+        assert_eq!(
+            ipv4,
+            SourceIpAddress::new_ipv4(Ipv4Addr::new(203, 0, 113, 7))
+        );
+        let unmarshaled = SourceIpAddress::unmarshal(&ipv4.to_ie().payload).unwrap();
+        assert_eq!(unmarshaled, ipv4);
+        assert_eq!(unmarshaled.ipv6, None);
+
+        let ipv6 = SourceIpAddress::ipv6("2001:db8::7".parse().unwrap());
+        assert_eq!(
+            ipv6,
+            SourceIpAddress::new_ipv6("2001:db8::7".parse().unwrap())
+        );
+        let unmarshaled = SourceIpAddress::unmarshal(&ipv6.to_ie().payload).unwrap();
+        assert_eq!(unmarshaled, ipv6);
+        assert_eq!(unmarshaled.ipv4, None);
+    }
+
     #[test]
     fn test_source_ip_address_round_trip() {
         let test_cases = vec![
@@ -1,135 +1,79 @@
 // src/ie/subsequent_volume_threshold.rs
 
 //! Subsequent Volume Threshold Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.16, the Subsequent Volume Threshold IE
+//! replaces the Volume Threshold once the first threshold has been reached.
+//! It uses the same encoding as [`VolumeThreshold`](crate::ie::volume_threshold::VolumeThreshold).
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::volume_threshold::{marshal_volumes, unmarshal_volumes, validate_volumes};
+use crate::ie::{Ie, IeType};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Subsequent Volume Threshold per 3GPP TS 29.244 §8.2.16.
+///
+/// # Wire Format
+/// - Octet 5: Spare | DLVOL | ULVOL | TOVOL
+/// - Total Volume (8 bytes, if TOVOL)
+/// - Uplink Volume (8 bytes, if ULVOL)
+/// - Downlink Volume (8 bytes, if DLVOL)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::subsequent_volume_threshold::SubsequentVolumeThreshold;
+///
+/// let svt = SubsequentVolumeThreshold::new(None, Some(200), Some(300))?;
+/// let parsed = SubsequentVolumeThreshold::unmarshal(&svt.marshal())?;
+/// assert_eq!(parsed, svt);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SubsequentVolumeThreshold {
-    pub tovol: bool,
-    pub ulvol: bool,
-    pub dlvol: bool,
-    pub total_volume: Option<u64>,
-    pub uplink_volume: Option<u64>,
-    pub downlink_volume: Option<u64>,
+    pub total: Option<u64>,
+    pub uplink: Option<u64>,
+    pub downlink: Option<u64>,
 }
 
 impl SubsequentVolumeThreshold {
+    /// Creates a new Subsequent Volume Threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if none of the volumes is set.
     pub fn new(
-        tovol: bool,
-        ulvol: bool,
-        dlvol: bool,
-        total_volume: Option<u64>,
-        uplink_volume: Option<u64>,
-        downlink_volume: Option<u64>,
-    ) -> Self {
-        SubsequentVolumeThreshold {
-            tovol,
-            ulvol,
-            dlvol,
-            total_volume,
-            uplink_volume,
-            downlink_volume,
-        }
+        total: Option<u64>,
+        uplink: Option<u64>,
+        downlink: Option<u64>,
+    ) -> Result<Self, PfcpError> {
+        validate_volumes("Subsequent Volume Threshold", total, uplink, downlink)?;
+        Ok(SubsequentVolumeThreshold {
+            total,
+            uplink,
+            downlink,
+        })
     }
 
     pub fn marshal(&self) -> Vec<u8> {
-        let mut data = Vec::new();
-        let mut flags = 0;
-        if self.tovol {
-            flags |= 0b1;
-        }
-        if self.ulvol {
-            flags |= 0b10;
-        }
-        if self.dlvol {
-            flags |= 0b100;
-        }
-        data.push(flags);
-        if let Some(vol) = self.total_volume {
-            data.extend_from_slice(&vol.to_be_bytes());
-        }
-        if let Some(vol) = self.uplink_volume {
-            data.extend_from_slice(&vol.to_be_bytes());
-        }
-        if let Some(vol) = self.downlink_volume {
-            data.extend_from_slice(&vol.to_be_bytes());
-        }
-        data
+        marshal_volumes(self.total, self.uplink, self.downlink)
     }
 
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.is_empty() {
-            return Err(PfcpError::invalid_length(
-                "Subsequent Volume Threshold",
-                IeType::SubsequentVolumeThreshold,
-                1,
-                0,
-            ));
-        }
-        let flags = data[0];
-        let tovol = (flags & 0b1) == 0b1;
-        let ulvol = (flags & 0b10) == 0b10;
-        let dlvol = (flags & 0b100) == 0b100;
-
-        let mut offset = 1;
-        let total_volume = if tovol {
-            if data.len() < offset + 8 {
-                return Err(PfcpError::invalid_length(
-                    "Subsequent Volume Threshold (Total Volume)",
-                    IeType::SubsequentVolumeThreshold,
-                    offset + 8,
-                    data.len(),
-                ));
-            }
-            let vol = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
-            offset += 8;
-            Some(vol)
-        } else {
-            None
-        };
-
-        let uplink_volume = if ulvol {
-            if data.len() < offset + 8 {
-                return Err(PfcpError::invalid_length(
-                    "Subsequent Volume Threshold (Uplink Volume)",
-                    IeType::SubsequentVolumeThreshold,
-                    offset + 8,
-                    data.len(),
-                ));
-            }
-            let vol = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
-            offset += 8;
-            Some(vol)
-        } else {
-            None
-        };
-
-        let downlink_volume = if dlvol {
-            if data.len() < offset + 8 {
-                return Err(PfcpError::invalid_length(
-                    "Subsequent Volume Threshold (Downlink Volume)",
-                    IeType::SubsequentVolumeThreshold,
-                    offset + 8,
-                    data.len(),
-                ));
-            }
-            let vol = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
-            Some(vol)
-        } else {
-            None
-        };
-
+        let (total, uplink, downlink) = unmarshal_volumes(
+            data,
+            "Subsequent Volume Threshold",
+            IeType::SubsequentVolumeThreshold,
+        )?;
         Ok(SubsequentVolumeThreshold {
-            tovol,
-            ulvol,
-            dlvol,
-            total_volume,
-            uplink_volume,
-            downlink_volume,
+            total,
+            uplink,
+            downlink,
         })
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::SubsequentVolumeThreshold, self.marshal())
+    }
 }
 
 #[cfg(test)]
@@ -138,16 +82,39 @@ mod tests {
 
     #[test]
     fn test_subsequent_volume_threshold_marshal_unmarshal() {
-        let svt = SubsequentVolumeThreshold::new(true, true, false, Some(1000), Some(500), None);
+        let svt = SubsequentVolumeThreshold::new(Some(1000), Some(500), None).unwrap();
         let marshaled = svt.marshal();
         let unmarshaled = SubsequentVolumeThreshold::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, svt);
     }
 
+    #[test]
+    fn test_subsequent_volume_threshold_all_flag_combinations() {
+        for flags in 1u8..=7 {
+            let total = (flags & 0x01 != 0).then_some(10);
+            let uplink = (flags & 0x02 != 0).then_some(20);
+            let downlink = (flags & 0x04 != 0).then_some(30);
+            let svt = SubsequentVolumeThreshold::new(total, uplink, downlink).unwrap();
+
+            let ie = svt.to_ie();
+            assert_eq!(ie.ie_type, IeType::SubsequentVolumeThreshold);
+            assert_eq!(ie.payload[0], flags);
+            assert_eq!(ie.payload.len(), 1 + 8 * flags.count_ones() as usize);
+            assert_eq!(
+                SubsequentVolumeThreshold::unmarshal(&ie.payload).unwrap(),
+                svt
+            );
+        }
+    }
+
     #[test]
     fn test_subsequent_volume_threshold_unmarshal_invalid_data() {
         let data = [0b1]; // Flag set, but no data
         let result = SubsequentVolumeThreshold::unmarshal(&data);
         assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Subsequent Volume Threshold"));
     }
 }
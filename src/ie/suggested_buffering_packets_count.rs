@@ -1,4 +1,8 @@
 //! Suggested Buffering Packets Count IE.
+//!
+//! Per 3GPP TS 29.244, IE type 140 carries the number of downlink packets
+//! the CP function suggests the UP function buffer for a BAR, in one octet.
+//! It is distinct from the DL Buffering Suggested Packet Count (IE type 48).
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
@@ -6,38 +10,36 @@ use crate::ie::{Ie, IeType};
 /// Represents a Suggested Buffering Packets Count.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SuggestedBufferingPacketsCount {
-    pub count: u16,
+    pub count: u8,
 }
 
 impl SuggestedBufferingPacketsCount {
     /// Creates a new Suggested Buffering Packets Count.
-    pub fn new(count: u16) -> Self {
+    pub fn new(count: u8) -> Self {
         SuggestedBufferingPacketsCount { count }
     }
 
     /// Marshals the Suggested Buffering Packets Count into a byte vector.
     pub fn marshal(&self) -> Vec<u8> {
-        self.count.to_be_bytes().to_vec()
+        vec![self.count]
     }
 
     /// Unmarshals a byte slice into a Suggested Buffering Packets Count.
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
-        if payload.len() < 2 {
+        let Some(&count) = payload.first() else {
             return Err(PfcpError::invalid_length(
                 "Suggested Buffering Packets Count",
-                IeType::DlBufferingSuggestedPacketCount,
-                2,
-                payload.len(),
+                IeType::SuggestedBufferingPacketsCount,
+                1,
+                0,
             ));
-        }
-        Ok(SuggestedBufferingPacketsCount {
-            count: u16::from_be_bytes([payload[0], payload[1]]),
-        })
+        };
+        Ok(SuggestedBufferingPacketsCount { count })
     }
 
     /// Wraps the Suggested Buffering Packets Count in a SuggestedBufferingPacketsCount IE.
     pub fn to_ie(&self) -> Ie {
-        Ie::new(IeType::DlBufferingSuggestedPacketCount, self.marshal())
+        Ie::new(IeType::SuggestedBufferingPacketsCount, self.marshal())
     }
 }
 
@@ -47,15 +49,20 @@ mod tests {
 
     #[test]
     fn test_suggested_buffering_packets_count_marshal_unmarshal() {
-        let sbpc = SuggestedBufferingPacketsCount::new(1000);
+        let sbpc = SuggestedBufferingPacketsCount::new(200);
         let marshaled = sbpc.marshal();
+        assert_eq!(marshaled, vec![200]);
         let unmarshaled = SuggestedBufferingPacketsCount::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, sbpc);
+
+        let ie = sbpc.to_ie();
+        assert_eq!(ie.ie_type, IeType::SuggestedBufferingPacketsCount);
+        assert_eq!(ie.marshal(), vec![0x00, 140, 0x00, 1, 200]);
     }
 
     #[test]
     fn test_suggested_buffering_packets_count_unmarshal_short() {
-        let result = SuggestedBufferingPacketsCount::unmarshal(&[0]);
+        let result = SuggestedBufferingPacketsCount::unmarshal(&[]);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, PfcpError::InvalidLength { .. }));
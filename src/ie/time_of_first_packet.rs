@@ -1,5 +1,7 @@
 use crate::error::PfcpError;
+use crate::ie::ntp_timestamp::NtpTimestamp;
 use crate::ie::{Ie, IeType};
+use std::time::SystemTime;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TimeOfFirstPacket {
@@ -11,6 +13,30 @@ impl TimeOfFirstPacket {
         Self { timestamp }
     }
 
+    /// Creates the IE from a system time, truncated to whole seconds.
+    ///
+    /// Times from 2036-02-07 onwards use the NTP era 1 encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PfcpError::InvalidValue`] if `time` is before the Unix epoch
+    /// or from 2104-02-26 onwards, which the 32-bit NTP seconds field cannot
+    /// carry.
+    pub fn from_system_time(time: SystemTime) -> Result<Self, PfcpError> {
+        Ok(Self::new(
+            NtpTimestamp::try_from_system_time(time, "Time Of First Packet")?.0,
+        ))
+    }
+
+    /// Returns the time stamp as a system time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the NTP value lies before the Unix epoch.
+    pub fn to_system_time(&self) -> Result<SystemTime, PfcpError> {
+        NtpTimestamp(self.timestamp).to_system_time("Time Of First Packet")
+    }
+
     pub fn marshal_len(&self) -> usize {
         4 // u32 for 3GPP NTP timestamp
     }
@@ -44,6 +70,14 @@ impl TimeOfFirstPacket {
     }
 }
 
+impl TryFrom<SystemTime> for TimeOfFirstPacket {
+    type Error = PfcpError;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        Self::from_system_time(time)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,4 +187,34 @@ mod tests {
         // Verify big-endian byte order
         assert_eq!(data, vec![0x12, 0x34, 0x56, 0x78]);
     }
+
+    #[test]
+    fn test_time_of_first_packet_system_time_matches_recovery_time_stamp() {
+        use crate::ie::recovery_time_stamp::RecoveryTimeStamp;
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let ie = TimeOfFirstPacket::from_system_time(time).unwrap();
+        assert_eq!(
+            ie.marshal(),
+            RecoveryTimeStamp::new(time).marshal().to_vec()
+        );
+        assert_eq!(ie.timestamp, 3_908_988_800);
+
+        let unmarshaled = TimeOfFirstPacket::unmarshal(&ie.marshal()).unwrap();
+        assert_eq!(unmarshaled.to_system_time().unwrap(), time);
+        assert_eq!(TimeOfFirstPacket::try_from(time).unwrap(), ie);
+
+        // Era 0 values before 1970 have no SystemTime representation
+        assert!(TimeOfFirstPacket::new(0x8000_0000)
+            .to_system_time()
+            .is_err());
+        assert!(TimeOfFirstPacket::from_system_time(UNIX_EPOCH - Duration::from_secs(1)).is_err());
+
+        // Past the 2036 rollover the value wraps into NTP era 1
+        let era_1 = UNIX_EPOCH + Duration::from_secs(2_085_978_496 + 60);
+        let ie = TimeOfFirstPacket::from_system_time(era_1).unwrap();
+        assert_eq!(ie.timestamp, 60);
+        assert_eq!(ie.to_system_time().unwrap(), era_1);
+    }
 }
@@ -1,40 +1,151 @@
 // src/ie/timer.rs
 
 //! Timer Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.44, a Timer is a single octet carrying a
+//! 3-bit unit and a 5-bit value. The same encoding is reused by other IEs,
+//! such as [`GracefulReleasePeriod`](crate::ie::graceful_release_period::GracefulReleasePeriod).
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::{Ie, IeType};
+use std::time::Duration;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Largest value representable in the 5-bit Timer value field.
+pub const MAX_TIMER_VALUE: u8 = 0x1F;
+
+/// Timer unit per 3GPP TS 29.244 Section 8.2.44 (bits 8-6 of octet 5).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimerUnit {
+    /// Value is incremented in multiples of 2 seconds.
+    TwoSeconds = 0,
+    /// Value is incremented in multiples of 1 minute.
+    OneMinute = 1,
+    /// Value is incremented in multiples of 10 minutes.
+    TenMinutes = 2,
+    /// Value is incremented in multiples of 1 hour.
+    OneHour = 3,
+    /// Value is incremented in multiples of 10 hours.
+    TenHours = 4,
+    /// The timer is infinite (deactivated); the value is ignored.
+    Infinite = 7,
+}
+
+impl TimerUnit {
+    /// Length of one unit step, or `None` for [`TimerUnit::Infinite`].
+    pub fn step(&self) -> Option<Duration> {
+        match self {
+            TimerUnit::TwoSeconds => Some(Duration::from_secs(2)),
+            TimerUnit::OneMinute => Some(Duration::from_secs(60)),
+            TimerUnit::TenMinutes => Some(Duration::from_secs(600)),
+            TimerUnit::OneHour => Some(Duration::from_secs(3600)),
+            TimerUnit::TenHours => Some(Duration::from_secs(36_000)),
+            TimerUnit::Infinite => None,
+        }
+    }
+}
+
+impl From<u8> for TimerUnit {
+    /// Decodes the 3-bit unit field.
+    ///
+    /// Values 5 and 6 are undefined and, as the specification requires, are
+    /// interpreted as multiples of 1 minute.
+    fn from(value: u8) -> Self {
+        match value & 0x07 {
+            0 => TimerUnit::TwoSeconds,
+            2 => TimerUnit::TenMinutes,
+            3 => TimerUnit::OneHour,
+            4 => TimerUnit::TenHours,
+            7 => TimerUnit::Infinite,
+            _ => TimerUnit::OneMinute,
+        }
+    }
+}
+
+impl From<TimerUnit> for u8 {
+    fn from(unit: TimerUnit) -> Self {
+        unit as u8
+    }
+}
+
+/// Timer per 3GPP TS 29.244 §8.2.44.
+///
+/// # Wire Format
+/// - Octet 5: Timer unit (bits 8-6) | Timer value (bits 5-1)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::timer::{Timer, TimerUnit};
+/// use std::time::Duration;
+///
+/// let timer = Timer::new(TimerUnit::OneMinute, 5)?;
+/// assert_eq!(timer.as_duration(), Some(Duration::from_secs(300)));
+/// assert_eq!(Timer::unmarshal(&timer.marshal())?, timer);
+///
+/// assert_eq!(Timer::infinite().as_duration(), None);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Timer {
-    pub value: u32,
+    pub unit: TimerUnit,
+    pub value: u8,
 }
 
 impl Timer {
-    pub fn new(value: u32) -> Self {
-        Timer { value }
+    /// Creates a new Timer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` does not fit the 5-bit value field.
+    pub fn new(unit: TimerUnit, value: u8) -> Result<Self, PfcpError> {
+        if value > MAX_TIMER_VALUE {
+            return Err(PfcpError::invalid_value(
+                "Timer value",
+                value.to_string(),
+                "timer value must be at most 31",
+            ));
+        }
+        Ok(Timer { unit, value })
     }
 
-    pub fn marshal(&self) -> [u8; 4] {
-        self.value.to_be_bytes()
+    /// Creates an infinite (deactivated) Timer.
+    pub fn infinite() -> Self {
+        Timer {
+            unit: TimerUnit::Infinite,
+            value: 0,
+        }
+    }
+
+    /// Returns the timer as a duration, or `None` if the timer is infinite.
+    pub fn as_duration(&self) -> Option<Duration> {
+        self.unit.step().map(|step| step * u32::from(self.value))
+    }
+
+    pub fn marshal(&self) -> [u8; 1] {
+        [(u8::from(self.unit) << 5) | (self.value & MAX_TIMER_VALUE)]
     }
 
     /// Unmarshals a byte slice into a Timer.
     ///
-    /// Per 3GPP TS 29.244, Timer requires exactly 4 bytes (u32).
+    /// Per 3GPP TS 29.244, Timer requires exactly 1 byte.
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.len() < 4 {
+        if data.is_empty() {
             return Err(PfcpError::invalid_length(
                 "Timer",
                 IeType::Timer,
-                4,
+                1,
                 data.len(),
             ));
         }
         Ok(Timer {
-            value: u32::from_be_bytes(data[0..4].try_into().unwrap()),
+            unit: TimerUnit::from(data[0] >> 5),
+            value: data[0] & MAX_TIMER_VALUE,
         })
     }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::Timer, self.marshal().to_vec())
+    }
 }
 
 #[cfg(test)]
@@ -43,33 +154,64 @@ mod tests {
 
     #[test]
     fn test_timer_marshal_unmarshal() {
-        let timer = Timer::new(3600);
+        let timer = Timer::new(TimerUnit::OneHour, 2).unwrap();
         let marshaled = timer.marshal();
+        assert_eq!(marshaled, [0x62]); // 011 00010
         let unmarshaled = Timer::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, timer);
     }
 
     #[test]
-    fn test_timer_unmarshal_invalid_data() {
-        let data = [0; 3];
-        let result = Timer::unmarshal(&data);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(matches!(err, PfcpError::InvalidLength { .. }));
-        if let PfcpError::InvalidLength {
-            ie_name,
-            ie_type,
-            expected,
-            actual,
-        } = err
-        {
-            assert_eq!(ie_name, "Timer");
-            assert_eq!(ie_type, IeType::Timer);
-            assert_eq!(expected, 4);
-            assert_eq!(actual, 3);
+    fn test_timer_all_units() {
+        let cases = [
+            (TimerUnit::TwoSeconds, 0x00, 2),
+            (TimerUnit::OneMinute, 0x20, 60),
+            (TimerUnit::TenMinutes, 0x40, 600),
+            (TimerUnit::OneHour, 0x60, 3600),
+            (TimerUnit::TenHours, 0x80, 36_000),
+        ];
+        for (unit, unit_bits, step_secs) in cases {
+            let timer = Timer::new(unit, 3).unwrap();
+            assert_eq!(timer.marshal(), [unit_bits | 3], "{unit:?}");
+            assert_eq!(
+                timer.as_duration(),
+                Some(Duration::from_secs(3 * step_secs)),
+                "{unit:?}"
+            );
+            assert_eq!(Timer::unmarshal(&timer.marshal()).unwrap(), timer);
         }
     }
 
+    #[test]
+    fn test_timer_undefined_units_are_minutes() {
+        for raw in [0xA4u8, 0xC4] {
+            let timer = Timer::unmarshal(&[raw]).unwrap();
+            assert_eq!(timer.unit, TimerUnit::OneMinute);
+            assert_eq!(timer.as_duration(), Some(Duration::from_secs(240)));
+        }
+    }
+
+    #[test]
+    fn test_timer_infinite() {
+        let timer = Timer::infinite();
+        assert_eq!(timer.marshal(), [0xE0]);
+        assert_eq!(timer.as_duration(), None);
+
+        // The value is ignored when the unit is infinite
+        let parsed = Timer::unmarshal(&[0xFF]).unwrap();
+        assert_eq!(parsed.unit, TimerUnit::Infinite);
+        assert_eq!(parsed.as_duration(), None);
+    }
+
+    #[test]
+    fn test_timer_value_out_of_range() {
+        assert!(Timer::new(TimerUnit::TwoSeconds, MAX_TIMER_VALUE).is_ok());
+        assert!(matches!(
+            Timer::new(TimerUnit::TwoSeconds, 32),
+            Err(PfcpError::InvalidValue { .. })
+        ));
+    }
+
     #[test]
     fn test_timer_unmarshal_empty() {
         let result = Timer::unmarshal(&[]);
@@ -85,19 +227,15 @@ mod tests {
         {
             assert_eq!(ie_name, "Timer");
             assert_eq!(ie_type, IeType::Timer);
-            assert_eq!(expected, 4);
+            assert_eq!(expected, 1);
             assert_eq!(actual, 0);
         }
     }
 
     #[test]
-    fn test_timer_round_trip() {
-        let test_values = vec![0, 1, 60, 3600, 86400, 0xFFFFFFFF];
-        for value in test_values {
-            let timer = Timer::new(value);
-            let marshaled = timer.marshal();
-            let unmarshaled = Timer::unmarshal(&marshaled).unwrap();
-            assert_eq!(unmarshaled.value, value);
-        }
+    fn test_timer_to_ie() {
+        let ie = Timer::new(TimerUnit::TenMinutes, 1).unwrap().to_ie();
+        assert_eq!(ie.ie_type, IeType::Timer);
+        assert_eq!(ie.payload, vec![0x41]);
     }
 }
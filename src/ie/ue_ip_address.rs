@@ -5,10 +5,18 @@ use crate::ie::{Ie, IeType};
 use std::net::{Ipv4Addr, Ipv6Addr};
 
 /// Represents a UE IP Address.
+///
+/// The `sd` flag (S/D) marks the address as a destination address when set;
+/// it is used for downlink PDIs where the UE address is the packet destination.
+/// `ipv6_prefix_delegation_bits` carries the IPv6 Prefix Delegation Bits octet;
+/// the IPv6D flag is set exactly when it is present.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UeIpAddress {
     pub v4: bool,
     pub v6: bool,
+    pub sd: bool,
+    /// Number of bits of the IPv6 prefix that the UE may delegate (IPv6D).
+    pub ipv6_prefix_delegation_bits: Option<u8>,
     pub ipv4_address: Option<Ipv4Addr>,
     pub ipv6_address: Option<Ipv6Addr>,
 }
@@ -16,14 +24,32 @@ pub struct UeIpAddress {
 impl UeIpAddress {
     /// Creates a new UE IP Address.
     pub fn new(ipv4_address: Option<Ipv4Addr>, ipv6_address: Option<Ipv6Addr>) -> Self {
+        Self::new_with_flags(ipv4_address, ipv6_address, false, None)
+    }
+
+    /// Creates a new UE IP Address with an explicit S/D flag and optional
+    /// IPv6 Prefix Delegation Bits.
+    pub fn new_with_flags(
+        ipv4_address: Option<Ipv4Addr>,
+        ipv6_address: Option<Ipv6Addr>,
+        sd: bool,
+        ipv6_prefix_delegation_bits: Option<u8>,
+    ) -> Self {
         UeIpAddress {
             v4: ipv4_address.is_some(),
             v6: ipv6_address.is_some(),
+            sd,
+            ipv6_prefix_delegation_bits,
             ipv4_address,
             ipv6_address,
         }
     }
 
+    /// Creates an IPv4 UE IP Address for downlink detection (S/D = 1).
+    pub fn downlink_source(ipv4_address: Ipv4Addr) -> Self {
+        Self::new_with_flags(Some(ipv4_address), None, true, None)
+    }
+
     /// Marshals the UE IP Address into a byte vector.
     pub fn marshal(&self) -> Vec<u8> {
         let mut data = Vec::new();
@@ -34,6 +60,12 @@ impl UeIpAddress {
         if self.v4 {
             flags |= 2; // Bit 1: V4 (IPv4)
         }
+        if self.sd {
+            flags |= 4; // Bit 2: S/D (Source/Destination)
+        }
+        if self.ipv6_prefix_delegation_bits.is_some() {
+            flags |= 8; // Bit 3: IPv6D (IPv6 Prefix Delegation)
+        }
         data.push(flags);
         if let Some(addr) = self.ipv4_address {
             data.extend_from_slice(&addr.octets());
@@ -41,6 +73,9 @@ impl UeIpAddress {
         if let Some(addr) = self.ipv6_address {
             data.extend_from_slice(&addr.octets());
         }
+        if let Some(bits) = self.ipv6_prefix_delegation_bits {
+            data.push(bits);
+        }
         data
     }
 
@@ -59,6 +94,8 @@ impl UeIpAddress {
         let flags = payload[0];
         let v6 = flags & 1 != 0; // Bit 0: V6 (IPv6)
         let v4 = flags & 2 != 0; // Bit 1: V4 (IPv4)
+        let sd = flags & 4 != 0; // Bit 2: S/D (Source/Destination)
+        let ipv6pd = flags & 8 != 0; // Bit 3: IPv6D (IPv6 Prefix Delegation)
         let mut offset = 1;
         let ipv4_address = if v4 {
             if payload.len() < offset + 4 {
@@ -91,13 +128,29 @@ impl UeIpAddress {
             }
             let mut octets = [0; 16];
             octets.copy_from_slice(&payload[offset..offset + 16]);
+            offset += 16;
             Some(Ipv6Addr::from(octets))
         } else {
             None
         };
+        let ipv6_prefix_delegation_bits = if ipv6pd {
+            let Some(&bits) = payload.get(offset) else {
+                return Err(PfcpError::invalid_length(
+                    "UE IP Address IPv6 Prefix Delegation Bits",
+                    IeType::UeIpAddress,
+                    offset + 1,
+                    payload.len(),
+                ));
+            };
+            Some(bits)
+        } else {
+            None
+        };
         Ok(UeIpAddress {
             v4,
             v6,
+            sd,
+            ipv6_prefix_delegation_bits,
             ipv4_address,
             ipv6_address,
         })
@@ -327,6 +380,59 @@ mod tests {
         assert_eq!(original, unmarshaled);
     }
 
+    #[test]
+    fn test_ue_ip_address_sd_flag_encoding() {
+        let ipv4 = Ipv4Addr::new(10, 45, 0, 1);
+        let downlink = UeIpAddress::downlink_source(ipv4);
+        assert!(downlink.sd);
+        assert_eq!(downlink.ipv6_prefix_delegation_bits, None);
+
+        let marshaled = downlink.marshal();
+        assert_eq!(marshaled[0], 0x06); // V4 (bit 1) + S/D (bit 2)
+        assert_eq!(marshaled[0] & 0x04, 0x04);
+
+        let uplink = UeIpAddress::new(Some(ipv4), None);
+        assert_eq!(uplink.marshal()[0] & 0x04, 0);
+    }
+
+    #[test]
+    fn test_ue_ip_address_sd_flag_round_trip() {
+        let ipv4 = Ipv4Addr::new(10, 45, 0, 1);
+        let ipv6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+
+        for sd in [false, true] {
+            let original = UeIpAddress::new_with_flags(Some(ipv4), Some(ipv6), sd, None);
+            let unmarshaled = UeIpAddress::unmarshal(&original.marshal()).unwrap();
+            assert_eq!(unmarshaled.sd, sd);
+            assert_eq!(original, unmarshaled);
+        }
+    }
+
+    #[test]
+    fn test_ue_ip_address_ipv6_prefix_delegation_round_trip() {
+        let ipv6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+        let original = UeIpAddress::new_with_flags(None, Some(ipv6), true, Some(8));
+
+        let marshaled = original.marshal();
+        assert_eq!(marshaled[0], 0x0D); // V6 + S/D + IPv6D
+        assert_eq!(marshaled.len(), 18); // flags + IPv6 + delegation bits
+        assert_eq!(marshaled[17], 8);
+
+        let unmarshaled = UeIpAddress::unmarshal(&marshaled).unwrap();
+        assert_eq!(unmarshaled.ipv6_prefix_delegation_bits, Some(8));
+        assert_eq!(original, unmarshaled);
+    }
+
+    #[test]
+    fn test_ue_ip_address_ipv6d_without_delegation_bits() {
+        let mut data = vec![0x09]; // V6 + IPv6D
+        data.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+        assert!(matches!(
+            UeIpAddress::unmarshal(&data),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+    }
+
     #[test]
     fn test_ue_ip_address_to_ie() {
         let ipv4 = Ipv4Addr::new(10, 10, 10, 10);
@@ -1,8 +1,228 @@
+//! UE IP Address Usage Information Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 7.4.4.3.1, the UE IP Address Usage Information
+//! grouped IE lets the UP function report how much of a UE IP address pool
+//! is in use, in the Association Update Request.
+
 use std::net::{Ipv4Addr, Ipv6Addr};
 
 use crate::error::PfcpError;
-use crate::ie::{Ie, IeType};
+use crate::ie::metric::Metric;
+use crate::ie::network_instance::NetworkInstance;
+use crate::ie::number_of_ue_ip_addresses::NumberOfUeIpAddresses;
+use crate::ie::sequence_number::SequenceNumber;
+use crate::ie::snssai::Snssai;
+use crate::ie::ue_ip_address_pool_identity::UeIpAddressPoolIdentity;
+use crate::ie::validity_timer::ValidityTimer;
+use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
+
+/// UE IP Address Usage Information per 3GPP TS 29.244 Table 7.4.4.3.1-1.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::metric::Metric;
+/// use rs_pfcp::ie::number_of_ue_ip_addresses::NumberOfUeIpAddresses;
+/// use rs_pfcp::ie::sequence_number::SequenceNumber;
+/// use rs_pfcp::ie::ue_ip_address_pool_identity::UeIpAddressPoolIdentity;
+/// use rs_pfcp::ie::ue_ip_address_usage_information::UeIpAddressUsageInformation;
+/// use rs_pfcp::ie::validity_timer::ValidityTimer;
+///
+/// let info = UeIpAddressUsageInformation::builder(
+///     SequenceNumber::new(1),
+///     Metric::new(80)?,
+///     ValidityTimer::new(600),
+/// )
+/// .number_of_ue_ip_addresses(NumberOfUeIpAddresses::ipv4(1000))
+/// .ue_ip_address_pool_identity(UeIpAddressPoolIdentity::new("pool-a"))
+/// .build();
+///
+/// let parsed = UeIpAddressUsageInformation::unmarshal(&info.marshal())?;
+/// assert_eq!(parsed, info);
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UeIpAddressUsageInformation {
+    /// Orders successive reports for the same pool (mandatory).
+    pub sequence_number: SequenceNumber,
+    /// Percentage of UE IP addresses in use (mandatory).
+    pub metric: Metric,
+    /// How long, in seconds, the report stays valid (mandatory).
+    pub validity_timer: ValidityTimer,
+    /// Number of UE IP addresses in use (conditional).
+    pub number_of_ue_ip_addresses: Option<NumberOfUeIpAddresses>,
+    /// Network instance the pool belongs to (optional).
+    pub network_instance: Option<NetworkInstance>,
+    /// Identity of the reported pool (optional).
+    pub ue_ip_address_pool_identity: Option<UeIpAddressPoolIdentity>,
+    /// Slice the pool is associated with (optional).
+    pub snssai: Option<Snssai>,
+}
+
+impl UeIpAddressUsageInformation {
+    pub fn new(
+        sequence_number: SequenceNumber,
+        metric: Metric,
+        validity_timer: ValidityTimer,
+    ) -> Self {
+        UeIpAddressUsageInformation {
+            sequence_number,
+            metric,
+            validity_timer,
+            number_of_ue_ip_addresses: None,
+            network_instance: None,
+            ue_ip_address_pool_identity: None,
+            snssai: None,
+        }
+    }
+
+    /// Returns a builder for constructing UE IP Address Usage Information.
+    pub fn builder(
+        sequence_number: SequenceNumber,
+        metric: Metric,
+        validity_timer: ValidityTimer,
+    ) -> UeIpAddressUsageInformationBuilder {
+        UeIpAddressUsageInformationBuilder::new(sequence_number, metric, validity_timer)
+    }
+
+    pub fn marshal(&self) -> Vec<u8> {
+        let mut ies = vec![
+            self.sequence_number.to_ie(),
+            self.metric.to_ie(),
+            self.validity_timer.to_ie(),
+        ];
+        if let Some(count) = &self.number_of_ue_ip_addresses {
+            ies.push(count.to_ie());
+        }
+        if let Some(ni) = &self.network_instance {
+            ies.push(ni.to_ie());
+        }
+        if let Some(pool) = &self.ue_ip_address_pool_identity {
+            ies.push(pool.to_ie());
+        }
+        if let Some(snssai) = &self.snssai {
+            ies.push(snssai.to_ie());
+        }
+        marshal_ies(&ies)
+    }
+
+    pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
+        let mut sequence_number = None;
+        let mut metric = None;
+        let mut validity_timer = None;
+        let mut number_of_ue_ip_addresses = None;
+        let mut network_instance = None;
+        let mut ue_ip_address_pool_identity = None;
+        let mut snssai = None;
+
+        for ie_result in IeIterator::new(payload) {
+            let ie = ie_result?;
+            match ie.ie_type {
+                IeType::SequenceNumber => {
+                    sequence_number = Some(SequenceNumber::unmarshal(&ie.payload)?);
+                }
+                IeType::Metric => {
+                    metric = Some(Metric::unmarshal(&ie.payload)?);
+                }
+                IeType::ValidityTimer => {
+                    validity_timer = Some(ValidityTimer::unmarshal(&ie.payload)?);
+                }
+                IeType::NumberOfUeIpAddresses => {
+                    number_of_ue_ip_addresses =
+                        Some(NumberOfUeIpAddresses::unmarshal(&ie.payload)?);
+                }
+                IeType::NetworkInstance => {
+                    network_instance = Some(NetworkInstance::unmarshal(&ie.payload)?);
+                }
+                IeType::UeIpAddressPoolIdentity => {
+                    ue_ip_address_pool_identity =
+                        Some(UeIpAddressPoolIdentity::unmarshal(&ie.payload)?);
+                }
+                IeType::Snssai => {
+                    snssai = Some(Snssai::unmarshal(&ie.payload)?);
+                }
+                _ => (),
+            }
+        }
+
+        let missing =
+            |child| PfcpError::missing_ie_in_grouped(child, IeType::UeIpAddressUsageInformation);
+        Ok(UeIpAddressUsageInformation {
+            sequence_number: sequence_number.ok_or_else(|| missing(IeType::SequenceNumber))?,
+            metric: metric.ok_or_else(|| missing(IeType::Metric))?,
+            validity_timer: validity_timer.ok_or_else(|| missing(IeType::ValidityTimer))?,
+            number_of_ue_ip_addresses,
+            network_instance,
+            ue_ip_address_pool_identity,
+            snssai,
+        })
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::UeIpAddressUsageInformation, self.marshal())
+    }
+}
+
+/// Builder for UE IP Address Usage Information.
+///
+/// Sequence Number, Metric and Validity Timer are mandatory and taken by
+/// [`new`](Self::new), so building cannot fail.
+#[derive(Debug)]
+pub struct UeIpAddressUsageInformationBuilder {
+    info: UeIpAddressUsageInformation,
+}
 
+impl UeIpAddressUsageInformationBuilder {
+    /// Creates a new builder with the mandatory child IEs.
+    pub fn new(
+        sequence_number: SequenceNumber,
+        metric: Metric,
+        validity_timer: ValidityTimer,
+    ) -> Self {
+        UeIpAddressUsageInformationBuilder {
+            info: UeIpAddressUsageInformation::new(sequence_number, metric, validity_timer),
+        }
+    }
+
+    /// Sets the Number of UE IP Addresses.
+    pub fn number_of_ue_ip_addresses(mut self, count: NumberOfUeIpAddresses) -> Self {
+        self.info.number_of_ue_ip_addresses = Some(count);
+        self
+    }
+
+    /// Sets the Network Instance.
+    pub fn network_instance(mut self, network_instance: NetworkInstance) -> Self {
+        self.info.network_instance = Some(network_instance);
+        self
+    }
+
+    /// Sets the UE IP Address Pool Identity.
+    pub fn ue_ip_address_pool_identity(mut self, pool: UeIpAddressPoolIdentity) -> Self {
+        self.info.ue_ip_address_pool_identity = Some(pool);
+        self
+    }
+
+    /// Sets the S-NSSAI.
+    pub fn snssai(mut self, snssai: Snssai) -> Self {
+        self.info.snssai = Some(snssai);
+        self
+    }
+
+    /// Builds the UE IP Address Usage Information.
+    pub fn build(self) -> UeIpAddressUsageInformation {
+        self.info
+    }
+}
+
+/// Flat, flag-based UE IP address usage record.
+///
+/// This is not the grouped IE defined by 3GPP TS 29.244, yet it encodes with
+/// the same IE type; nothing in the crate decodes it any more. Use
+/// [`UeIpAddressUsageInformation`] instead.
+#[deprecated(
+    since = "0.4.0",
+    note = "not the 3GPP encoding of IE 267; use `UeIpAddressUsageInformation`"
+)]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UEIPAddressUsageInformation {
     pub flags: u8,
@@ -12,6 +232,7 @@ pub struct UEIPAddressUsageInformation {
     pub validity_timer: Option<u32>,
 }
 
+#[allow(deprecated)]
 impl UEIPAddressUsageInformation {
     pub fn new(
         flags: u8,
@@ -235,9 +456,74 @@ impl UEIPAddressUsageInformation {
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
 
+    fn usage_information() -> UeIpAddressUsageInformation {
+        UeIpAddressUsageInformation::new(
+            SequenceNumber::new(7),
+            Metric::new(85).unwrap(),
+            ValidityTimer::new(300),
+        )
+    }
+
+    #[test]
+    fn test_ue_ip_address_usage_information_grouped_mandatory_only() {
+        let info = usage_information();
+        let ie = info.to_ie();
+        assert_eq!(ie.ie_type, IeType::UeIpAddressUsageInformation);
+
+        let unmarshaled = UeIpAddressUsageInformation::unmarshal(&ie.payload).unwrap();
+        assert_eq!(unmarshaled, info);
+        assert_eq!(unmarshaled.sequence_number.value, 7);
+        assert_eq!(unmarshaled.metric.value, 85);
+        assert_eq!(unmarshaled.validity_timer.value, 300);
+        assert!(unmarshaled.number_of_ue_ip_addresses.is_none());
+    }
+
+    #[test]
+    fn test_ue_ip_address_usage_information_grouped_all_children() {
+        let info = UeIpAddressUsageInformationBuilder::new(
+            SequenceNumber::new(0x01020304),
+            Metric::new(100).unwrap(),
+            ValidityTimer::new(3600),
+        )
+        .number_of_ue_ip_addresses(NumberOfUeIpAddresses::both(4000, 120))
+        .network_instance(NetworkInstance::new("internet"))
+        .ue_ip_address_pool_identity(UeIpAddressPoolIdentity::new("pool-1"))
+        .snssai(Snssai::new(1))
+        .build();
+
+        let unmarshaled = UeIpAddressUsageInformation::unmarshal(&info.marshal()).unwrap();
+        assert_eq!(unmarshaled, info);
+        assert_eq!(
+            unmarshaled.ue_ip_address_pool_identity.unwrap().value,
+            "pool-1"
+        );
+    }
+
+    #[test]
+    fn test_ue_ip_address_usage_information_grouped_missing_mandatory() {
+        let info = usage_information();
+        for (skip, missing) in [
+            (0, IeType::SequenceNumber),
+            (1, IeType::Metric),
+            (2, IeType::ValidityTimer),
+        ] {
+            let mut children = vec![
+                info.sequence_number.to_ie(),
+                info.metric.to_ie(),
+                info.validity_timer.to_ie(),
+            ];
+            children.remove(skip);
+            let result = UeIpAddressUsageInformation::unmarshal(&marshal_ies(&children));
+            assert!(
+                matches!(result, Err(PfcpError::MissingMandatoryIe { ie_type, .. }) if ie_type == missing)
+            );
+        }
+    }
+
     #[test]
     fn test_ue_ip_address_usage_information_new() {
         let flags = 0x05;
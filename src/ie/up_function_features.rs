@@ -1,55 +1,194 @@
 // src/ie/up_function_features.rs
 
 //! UP Function Features Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.25, the UP Function Features IE lists the
+//! optional features supported by the UP function, one bit per feature,
+//! starting at octet 5. Later releases append octets; a peer only sends as
+//! many octets as it needs.
 
+use crate::error::PfcpError;
+use crate::ie::{Ie, IeType};
 use bitflags::bitflags;
 
 bitflags! {
+    /// UP Function Features per 3GPP TS 29.244 Section 8.2.25 (Table 8.2.25-1).
+    ///
+    /// Bit `n` of octet `5 + k` maps to flag bit `8 * k + (n - 1)`, so octet 5
+    /// occupies the low byte.
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-    pub struct UPFunctionFeatures: u16 {
-        const BUCP = 1 << 0;  // Bit 1
-        const DDND = 1 << 1;  // Bit 2
-        const DLBD = 1 << 2;  // Bit 3
-        const TRST = 1 << 3;  // Bit 4
-        const FTUP = 1 << 4;  // Bit 5
-        const PFDM = 1 << 5;  // Bit 6
-        const HEEU = 1 << 6;  // Bit 7
-        const TREU = 1 << 7;  // Bit 8
-        const EMPU = 1 << 8;  // Bit 9
-        const PDIU = 1 << 9;  // Bit 10
-        const UDBC = 1 << 10; // Bit 11
-        const QUOV = 1 << 11; // Bit 12
-        const ADPDP = 1 << 12;// Bit 13
-        const UEIP = 1 << 13; // Bit 14
-        const SSET = 1 << 14; // Bit 15
-        const MPTCP = 1 << 15;// Bit 16
+    pub struct UPFunctionFeatures: u64 {
+        // Octet 5
+        /// Downlink data buffering in the CP function.
+        const BUCP = 1 << 0;
+        /// Downlink Data Notification Delay buffering parameter.
+        const DDND = 1 << 1;
+        /// DL Buffering Duration buffering parameter.
+        const DLBD = 1 << 2;
+        /// Traffic steering.
+        const TRST = 1 << 3;
+        /// F-TEID allocation and release in the UP function.
+        const FTUP = 1 << 4;
+        /// PFD Management procedure.
+        const PFDM = 1 << 5;
+        /// Header Enrichment of uplink traffic.
+        const HEEU = 1 << 6;
+        /// Traffic Redirection Enforcement in the UP function.
+        const TREU = 1 << 7;
+
+        // Octet 6
+        /// Sending of End Marker packets.
+        const EMPU = 1 << 8;
+        /// PDI optimised signalling.
+        const PDIU = 1 << 9;
+        /// UL/DL Buffering Control.
+        const UDBC = 1 << 10;
+        /// Provisioning of the Quota Action to apply when reaching quotas.
+        const QUOAC = 1 << 11;
+        /// Trace.
+        const TRACE = 1 << 12;
+        /// Framed Routing.
+        const FRRT = 1 << 13;
+        /// PFD contents including a property with multiple values.
+        const PFDE = 1 << 14;
+        /// Enhanced PFCP Association Release.
+        const EPFAR = 1 << 15;
+
+        // Octet 7
+        /// Deferred PDR activation or deactivation.
+        const DPDRA = 1 << 16;
+        /// Activation and deactivation of pre-defined PDRs.
+        const ADPDP = 1 << 17;
+        /// Allocation of UE IP addresses or prefixes.
+        const UEIP = 1 << 18;
+        /// PFCP sessions successively controlled by different SMFs of an SMF Set.
+        const SSET = 1 << 19;
+        /// Measurement of number of packets.
+        const MNOP = 1 << 20;
+        /// Multiple instances of Traffic Endpoint IDs in a PDI.
+        const MTE = 1 << 21;
+        /// PFCP message bundling.
+        const BUNDL = 1 << 22;
+        /// 5G VN Group Communication.
+        const GCOM = 1 << 23;
+
+        // Octet 8
+        /// Multiple PFCP associations to the SMFs in an SMF Set.
+        const MPAS = 1 << 24;
+        /// Redundant transmission at transport layer.
+        const RTTL = 1 << 25;
+        /// Quota validity time.
+        const VTIME = 1 << 26;
+        /// Number of Reports.
+        const NORP = 1 << 27;
+        /// IPTV service.
+        const IPTV = 1 << 28;
+        /// UE IPv6 prefix allocation with a prefix length other than /64.
+        const IP6PL = 1 << 29;
+        /// Time Sensitive Communication.
+        const TSCU = 1 << 30;
+        /// MPTCP Proxy functionality.
+        const MPTCP = 1 << 31;
+
+        // Octet 9
+        /// ATSSS-LL steering functionality.
+        const ATSSSLL = 1 << 32;
+        /// Per QoS flow per UE QoS monitoring.
+        const QFQM = 1 << 33;
+        /// Per GTP-U path QoS monitoring.
+        const GPQM = 1 << 34;
+        /// Reporting the size of DL data packets (MT-EDT).
+        const MTEDT = 1 << 35;
+        /// CIoT features, e.g. small data rate enforcement.
+        const CIOT = 1 << 36;
+        /// Ethernet PDU Session Anchor relocation.
+        const ETHAR = 1 << 37;
+        /// Reporting of buffered or discarded DL data for delivery status.
+        const DDDS = 1 << 38;
+        /// Reliable Data Service.
+        const RDS = 1 << 39;
+
+        // Octet 10
+        /// RTT measurements towards the UE without PMF.
+        const RTTWP = 1 << 40;
+        /// Exempted Application ID or SDF filter for Quota Action.
+        const QUASF = 1 << 41;
+        /// Notifying start of Pause of Charging via the user plane.
+        const NSPOC = 1 << 42;
+        /// L2TP.
+        const L2TP = 1 << 43;
+        /// Uplink packet buffering during EAS relocation.
+        const UPBER = 1 << 44;
+        /// Restoration of PFCP sessions per FQ-CSID, Group Id or CP IP address.
+        const RESPS = 1 << 45;
+        /// IP address and port number replacement.
+        const IPREP = 1 << 46;
+        /// DNS traffic steering based on the FQDN in DNS queries.
+        const DNSTS = 1 << 47;
+
+        // Octet 11
+        /// Direct reporting of QoS monitoring events to a local NEF or AF.
+        const DRQOS = 1 << 48;
+        /// MBS multicast session data delivered over associated PDU sessions.
+        const MBSN4 = 1 << 49;
+        /// Per slice UP resource management.
+        const PSUPRM = 1 << 50;
+        /// Enhanced provisioning of Paging Policy Indicator.
+        const EPPPI = 1 << 51;
+        /// Redirect address types including a port.
+        const RATP = 1 << 52;
+        /// User plane inactivity detection and reporting per PDR.
+        const UPIDP = 1 << 53;
     }
 }
 
 impl UPFunctionFeatures {
-    pub fn new(features: u16) -> Self {
+    /// Former name of [`QUOAC`](Self::QUOAC).
+    #[deprecated(note = "renamed to QUOAC per 3GPP TS 29.244 Table 8.2.25-1")]
+    pub const QUOV: Self = Self::QUOAC;
+
+    /// Number of feature octets always sent, as in Release 15.
+    const MIN_OCTETS: usize = 2;
+
+    pub fn new(features: u64) -> Self {
         UPFunctionFeatures::from_bits_truncate(features)
     }
 
-    pub fn marshal(&self) -> [u8; 2] {
-        self.bits().to_be_bytes()
+    /// Returns true if either End Marker sending (EMPU) or PDI optimised
+    /// signalling (PDIU) is supported.
+    pub fn has_edge_capability(&self) -> bool {
+        self.intersects(UPFunctionFeatures::EMPU | UPFunctionFeatures::PDIU)
     }
 
-    pub fn unmarshal(data: &[u8]) -> Result<Self, crate::error::PfcpError> {
+    /// Encodes the feature octets, starting at octet 5.
+    ///
+    /// At least two octets are emitted; further octets are added up to the
+    /// last one with a feature bit set.
+    pub fn marshal(&self) -> Vec<u8> {
+        let bytes = self.bits().to_le_bytes();
+        let used = bytes.len() - bytes.iter().rev().take_while(|b| **b == 0).count();
+        bytes[..used.max(Self::MIN_OCTETS)].to_vec()
+    }
+
+    pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
         if data.is_empty() {
-            return Err(crate::error::PfcpError::invalid_length(
+            return Err(PfcpError::invalid_length(
                 "UP Function Features",
-                crate::ie::IeType::UpFunctionFeatures,
+                IeType::UpFunctionFeatures,
                 1,
                 0,
             ));
         }
-        let bits = if data.len() == 1 {
-            u16::from_be_bytes([0, data[0]])
-        } else {
-            u16::from_be_bytes([data[0], data[1]])
-        };
-        Ok(UPFunctionFeatures::from_bits_truncate(bits))
+        let mut bytes = [0u8; 8];
+        let len = data.len().min(bytes.len());
+        bytes[..len].copy_from_slice(&data[..len]);
+        Ok(UPFunctionFeatures::from_bits_truncate(u64::from_le_bytes(
+            bytes,
+        )))
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::UpFunctionFeatures, self.marshal())
     }
 }
 
@@ -65,4 +204,98 @@ mod tests {
         let unmarshaled = UPFunctionFeatures::unmarshal(&marshaled).unwrap();
         assert_eq!(features, unmarshaled);
     }
+
+    #[test]
+    fn test_up_function_features_octet_layout() {
+        // Octet 5 comes first on the wire
+        let features = UPFunctionFeatures::BUCP | UPFunctionFeatures::FTUP;
+        assert_eq!(features.marshal(), vec![0x11, 0x00]);
+
+        let features = UPFunctionFeatures::EMPU | UPFunctionFeatures::EPFAR;
+        assert_eq!(features.marshal(), vec![0x00, 0x81]);
+
+        assert_eq!(UPFunctionFeatures::empty().marshal(), vec![0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_up_function_features_octet_6_bits() {
+        let bits = [
+            (UPFunctionFeatures::EMPU, 0x01),
+            (UPFunctionFeatures::PDIU, 0x02),
+            (UPFunctionFeatures::UDBC, 0x04),
+            (UPFunctionFeatures::QUOAC, 0x08),
+            (UPFunctionFeatures::TRACE, 0x10),
+            (UPFunctionFeatures::FRRT, 0x20),
+            (UPFunctionFeatures::PFDE, 0x40),
+            (UPFunctionFeatures::EPFAR, 0x80),
+        ];
+        for (feature, octet_6) in bits {
+            assert_eq!(feature.marshal(), vec![0x00, octet_6]);
+            assert_eq!(
+                UPFunctionFeatures::unmarshal(&[0x00, octet_6]).unwrap(),
+                feature
+            );
+        }
+    }
+
+    #[test]
+    fn test_up_function_features_has_edge_capability() {
+        assert!(UPFunctionFeatures::EMPU.has_edge_capability());
+        assert!(UPFunctionFeatures::PDIU.has_edge_capability());
+        assert!((UPFunctionFeatures::FTUP | UPFunctionFeatures::PDIU).has_edge_capability());
+        assert!(!UPFunctionFeatures::empty().has_edge_capability());
+        assert!(!(UPFunctionFeatures::UDBC | UPFunctionFeatures::FTUP).has_edge_capability());
+    }
+
+    #[test]
+    fn test_up_function_features_three_octets() {
+        let features = UPFunctionFeatures::FTUP | UPFunctionFeatures::UEIP;
+        let ie = features.to_ie();
+        assert_eq!(ie.ie_type, IeType::UpFunctionFeatures);
+        assert_eq!(ie.payload, vec![0x10, 0x00, 0x04]);
+        assert_eq!(ie.payload.len(), 3);
+        assert_eq!(
+            UPFunctionFeatures::unmarshal(&ie.payload).unwrap(),
+            features
+        );
+    }
+
+    #[test]
+    fn test_up_function_features_later_octets() {
+        let features = UPFunctionFeatures::MPAS
+            | UPFunctionFeatures::ATSSSLL
+            | UPFunctionFeatures::DNSTS
+            | UPFunctionFeatures::UPIDP;
+        let marshaled = features.marshal();
+        assert_eq!(marshaled, vec![0x00, 0x00, 0x00, 0x01, 0x01, 0x80, 0x20]);
+        assert_eq!(UPFunctionFeatures::unmarshal(&marshaled).unwrap(), features);
+    }
+
+    #[test]
+    fn test_up_function_features_unmarshal_short_and_long() {
+        // A single octet is accepted
+        assert_eq!(
+            UPFunctionFeatures::unmarshal(&[0x01]).unwrap(),
+            UPFunctionFeatures::BUCP
+        );
+
+        // Octets beyond the known features are ignored
+        let data = [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF];
+        assert_eq!(
+            UPFunctionFeatures::unmarshal(&data).unwrap(),
+            UPFunctionFeatures::BUCP
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_up_function_features_quov_alias() {
+        assert_eq!(UPFunctionFeatures::QUOV, UPFunctionFeatures::QUOAC);
+    }
+
+    #[test]
+    fn test_up_function_features_unmarshal_empty() {
+        let result = UPFunctionFeatures::unmarshal(&[]);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
+    }
 }
@@ -4,13 +4,18 @@
 
 use crate::error::PfcpError;
 use crate::ie::bar_id::BarId;
+use crate::ie::dl_buffering_duration::DlBufferingDuration;
+use crate::ie::downlink_data_notification_delay::DownlinkDataNotificationDelay;
 use crate::ie::suggested_buffering_packets_count::SuggestedBufferingPacketsCount;
 use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UpdateBar {
     pub bar_id: BarId,
+    pub downlink_data_notification_delay: Option<DownlinkDataNotificationDelay>,
     pub suggested_buffering_packets_count: Option<SuggestedBufferingPacketsCount>,
+    pub dl_buffering_duration: Option<DlBufferingDuration>,
 }
 
 impl UpdateBar {
@@ -20,23 +25,38 @@ impl UpdateBar {
     ) -> Self {
         UpdateBar {
             bar_id,
+            downlink_data_notification_delay: None,
             suggested_buffering_packets_count,
+            dl_buffering_duration: None,
         }
     }
 
+    /// Returns a builder for constructing Update BAR instances.
+    pub fn builder(bar_id: BarId) -> UpdateBarBuilder {
+        UpdateBarBuilder::new(bar_id)
+    }
+
     pub fn marshal(&self) -> Vec<u8> {
         let mut ies = vec![self.bar_id.to_ie()];
 
+        if let Some(delay) = &self.downlink_data_notification_delay {
+            ies.push(delay.to_ie());
+        }
         if let Some(sbpc) = &self.suggested_buffering_packets_count {
             ies.push(sbpc.to_ie());
         }
+        if let Some(duration) = &self.dl_buffering_duration {
+            ies.push(duration.to_ie());
+        }
 
         marshal_ies(&ies)
     }
 
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut bar_id = None;
+        let mut downlink_data_notification_delay = None;
         let mut suggested_buffering_packets_count = None;
+        let mut dl_buffering_duration = None;
 
         for ie_result in IeIterator::new(payload) {
             let ie = ie_result?;
@@ -44,10 +64,17 @@ impl UpdateBar {
                 IeType::BarId => {
                     bar_id = Some(BarId::unmarshal(&ie.payload)?);
                 }
-                IeType::DlBufferingSuggestedPacketCount => {
+                IeType::DownlinkDataNotificationDelay => {
+                    downlink_data_notification_delay =
+                        Some(DownlinkDataNotificationDelay::unmarshal(&ie.payload)?);
+                }
+                IeType::SuggestedBufferingPacketsCount => {
                     suggested_buffering_packets_count =
                         Some(SuggestedBufferingPacketsCount::unmarshal(&ie.payload)?);
                 }
+                IeType::DlBufferingDuration => {
+                    dl_buffering_duration = Some(DlBufferingDuration::unmarshal(&ie.payload)?);
+                }
                 _ => (),
             }
         }
@@ -56,7 +83,9 @@ impl UpdateBar {
             bar_id: bar_id.ok_or_else(|| {
                 PfcpError::missing_ie_in_grouped(IeType::BarId, IeType::UpdateBar)
             })?,
+            downlink_data_notification_delay,
             suggested_buffering_packets_count,
+            dl_buffering_duration,
         })
     }
 
@@ -64,3 +93,72 @@ impl UpdateBar {
         Ie::new(IeType::UpdateBar, self.marshal())
     }
 }
+
+/// Builder for Update BAR Information Elements.
+///
+/// Mirrors [`CreateBarBuilder`](crate::ie::create_bar::CreateBarBuilder).
+///
+/// # Examples
+///
+/// ```rust
+/// use rs_pfcp::ie::bar_id::BarId;
+/// use rs_pfcp::ie::dl_buffering_duration::DlBufferingDuration;
+/// use rs_pfcp::ie::timer::{Timer, TimerUnit};
+/// use rs_pfcp::ie::update_bar::UpdateBarBuilder;
+///
+/// let bar = UpdateBarBuilder::new(BarId::new(1))
+///     .dl_buffering_duration(DlBufferingDuration::new(Timer::new(TimerUnit::TwoSeconds, 15)?))
+///     .build();
+///
+/// assert!(bar.dl_buffering_duration.is_some());
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug)]
+pub struct UpdateBarBuilder {
+    bar_id: BarId,
+    downlink_data_notification_delay: Option<DownlinkDataNotificationDelay>,
+    suggested_buffering_packets_count: Option<SuggestedBufferingPacketsCount>,
+    dl_buffering_duration: Option<DlBufferingDuration>,
+}
+
+impl UpdateBarBuilder {
+    /// Creates a new Update BAR builder with the specified BAR ID.
+    pub fn new(bar_id: BarId) -> Self {
+        UpdateBarBuilder {
+            bar_id,
+            downlink_data_notification_delay: None,
+            suggested_buffering_packets_count: None,
+            dl_buffering_duration: None,
+        }
+    }
+
+    /// Sets the Downlink Data Notification Delay, in multiples of 50 ms.
+    pub fn downlink_data_notification_delay(mut self, delay: u8) -> Self {
+        self.downlink_data_notification_delay = Some(DownlinkDataNotificationDelay::new(
+            Duration::from_millis(u64::from(delay) * 50),
+        ));
+        self
+    }
+
+    /// Sets the Suggested Buffering Packets Count.
+    pub fn suggested_buffering_packets_count(mut self, count: u8) -> Self {
+        self.suggested_buffering_packets_count = Some(SuggestedBufferingPacketsCount::new(count));
+        self
+    }
+
+    /// Sets the DL Buffering Duration.
+    pub fn dl_buffering_duration(mut self, duration: DlBufferingDuration) -> Self {
+        self.dl_buffering_duration = Some(duration);
+        self
+    }
+
+    /// Builds the Update BAR.
+    pub fn build(self) -> UpdateBar {
+        UpdateBar {
+            bar_id: self.bar_id,
+            downlink_data_notification_delay: self.downlink_data_notification_delay,
+            suggested_buffering_packets_count: self.suggested_buffering_packets_count,
+            dl_buffering_duration: self.dl_buffering_duration,
+        }
+    }
+}
@@ -1,7 +1,13 @@
 //! Update BAR within Session Report Response IE.
+//!
+//! Per 3GPP TS 29.244 Table 7.5.9.2-1, the CP function uses this grouped IE
+//! to adjust the buffering parameters of a BAR when answering a Session
+//! Report Request.
 
 use crate::error::PfcpError;
 use crate::ie::bar_id::BarId;
+use crate::ie::dl_buffering_duration::DlBufferingDuration;
+use crate::ie::dl_buffering_suggested_packet_count::DlBufferingSuggestedPacketCount;
 use crate::ie::downlink_data_notification_delay::DownlinkDataNotificationDelay;
 use crate::ie::suggested_buffering_packets_count::SuggestedBufferingPacketsCount;
 use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
@@ -13,6 +19,8 @@ use crate::ie::{marshal_ies, Ie, IeIterator, IeType};
 pub struct UpdateBarWithinSessionReportResponse {
     pub bar_id: BarId,
     pub downlink_data_notification_delay: Option<DownlinkDataNotificationDelay>,
+    pub dl_buffering_duration: Option<DlBufferingDuration>,
+    pub dl_buffering_suggested_packet_count: Option<DlBufferingSuggestedPacketCount>,
     pub suggested_buffering_packets_count: Option<SuggestedBufferingPacketsCount>,
 }
 
@@ -22,6 +30,8 @@ impl UpdateBarWithinSessionReportResponse {
         UpdateBarWithinSessionReportResponse {
             bar_id,
             downlink_data_notification_delay: None,
+            dl_buffering_duration: None,
+            dl_buffering_suggested_packet_count: None,
             suggested_buffering_packets_count: None,
         }
     }
@@ -35,12 +45,22 @@ impl UpdateBarWithinSessionReportResponse {
         self
     }
 
+    /// Adds a DL Buffering Duration.
+    pub fn with_dl_buffering_duration(mut self, duration: DlBufferingDuration) -> Self {
+        self.dl_buffering_duration = Some(duration);
+        self
+    }
+
+    /// Adds a DL Buffering Suggested Packet Count.
+    pub fn with_dl_buffering_suggested_packet_count(mut self, count: u16) -> Self {
+        self.dl_buffering_suggested_packet_count =
+            Some(DlBufferingSuggestedPacketCount::new(count));
+        self
+    }
+
     /// Adds a Suggested Buffering Packets Count.
-    pub fn with_suggested_buffering_packets_count(
-        mut self,
-        count: SuggestedBufferingPacketsCount,
-    ) -> Self {
-        self.suggested_buffering_packets_count = Some(count);
+    pub fn with_suggested_buffering_packets_count(mut self, count: u8) -> Self {
+        self.suggested_buffering_packets_count = Some(SuggestedBufferingPacketsCount::new(count));
         self
     }
 
@@ -57,11 +77,16 @@ impl UpdateBarWithinSessionReportResponse {
             ));
         }
 
+        if let Some(ref duration) = self.dl_buffering_duration {
+            ies.push(duration.to_ie());
+        }
+
+        if let Some(ref count) = self.dl_buffering_suggested_packet_count {
+            ies.push(count.to_ie());
+        }
+
         if let Some(ref count) = self.suggested_buffering_packets_count {
-            ies.push(Ie::new(
-                IeType::DlBufferingSuggestedPacketCount,
-                count.marshal(),
-            ));
+            ies.push(count.to_ie());
         }
 
         marshal_ies(&ies)
@@ -71,6 +96,8 @@ impl UpdateBarWithinSessionReportResponse {
     pub fn unmarshal(payload: &[u8]) -> Result<Self, PfcpError> {
         let mut bar_id = None;
         let mut downlink_data_notification_delay = None;
+        let mut dl_buffering_duration = None;
+        let mut dl_buffering_suggested_packet_count = None;
         let mut suggested_buffering_packets_count = None;
 
         for ie_result in IeIterator::new(payload) {
@@ -81,7 +108,14 @@ impl UpdateBarWithinSessionReportResponse {
                     downlink_data_notification_delay =
                         Some(DownlinkDataNotificationDelay::unmarshal(&ie.payload)?)
                 }
+                IeType::DlBufferingDuration => {
+                    dl_buffering_duration = Some(DlBufferingDuration::unmarshal(&ie.payload)?)
+                }
                 IeType::DlBufferingSuggestedPacketCount => {
+                    dl_buffering_suggested_packet_count =
+                        Some(DlBufferingSuggestedPacketCount::unmarshal(&ie.payload)?)
+                }
+                IeType::SuggestedBufferingPacketsCount => {
                     suggested_buffering_packets_count =
                         Some(SuggestedBufferingPacketsCount::unmarshal(&ie.payload)?)
                 }
@@ -98,6 +132,8 @@ impl UpdateBarWithinSessionReportResponse {
         Ok(UpdateBarWithinSessionReportResponse {
             bar_id,
             downlink_data_notification_delay,
+            dl_buffering_duration,
+            dl_buffering_suggested_packet_count,
             suggested_buffering_packets_count,
         })
     }
@@ -111,6 +147,7 @@ impl UpdateBarWithinSessionReportResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ie::timer::{Timer, TimerUnit};
 
     #[test]
     fn test_update_bar_within_session_report_response_marshal_unmarshal_minimal() {
@@ -130,11 +167,13 @@ mod tests {
     fn test_update_bar_within_session_report_response_marshal_unmarshal_complete() {
         let bar_id = BarId::new(10);
         let delay = DownlinkDataNotificationDelay::new(std::time::Duration::from_millis(1000)); // 1000ms
-        let count = SuggestedBufferingPacketsCount::new(50);
+        let duration = DlBufferingDuration::new(Timer::new(TimerUnit::OneMinute, 5).unwrap());
 
         let update_bar = UpdateBarWithinSessionReportResponse::new(bar_id.clone())
             .with_downlink_data_notification_delay(delay.clone())
-            .with_suggested_buffering_packets_count(count.clone());
+            .with_dl_buffering_duration(duration)
+            .with_dl_buffering_suggested_packet_count(300)
+            .with_suggested_buffering_packets_count(50);
 
         let marshaled = update_bar.marshal();
         let unmarshaled = UpdateBarWithinSessionReportResponse::unmarshal(&marshaled).unwrap();
@@ -142,7 +181,30 @@ mod tests {
         assert_eq!(update_bar, unmarshaled);
         assert_eq!(unmarshaled.bar_id, bar_id);
         assert_eq!(unmarshaled.downlink_data_notification_delay, Some(delay));
-        assert_eq!(unmarshaled.suggested_buffering_packets_count, Some(count));
+        assert_eq!(unmarshaled.dl_buffering_duration, Some(duration));
+        assert_eq!(
+            unmarshaled.dl_buffering_suggested_packet_count,
+            Some(DlBufferingSuggestedPacketCount::new(300))
+        );
+        assert_eq!(
+            unmarshaled.suggested_buffering_packets_count,
+            Some(SuggestedBufferingPacketsCount::new(50))
+        );
+    }
+
+    #[test]
+    fn test_update_bar_within_session_report_response_suggested_count_ie_type() {
+        let update_bar = UpdateBarWithinSessionReportResponse::new(BarId::new(1))
+            .with_suggested_buffering_packets_count(20);
+
+        // BAR ID followed by Suggested Buffering Packets Count (type 140, 1 octet)
+        assert_eq!(
+            update_bar.marshal(),
+            vec![0x00, 0x58, 0x00, 0x01, 0x01, 0x00, 0x8C, 0x00, 0x01, 20]
+        );
+
+        let empty = [0x00, 0x58, 0x00, 0x01, 0x01, 0x00, 0x8C, 0x00, 0x00];
+        assert!(UpdateBarWithinSessionReportResponse::unmarshal(&empty).is_err());
     }
 
     #[test]
@@ -188,6 +250,7 @@ mod tests {
         assert_eq!(update_bar, unmarshaled);
         assert_eq!(unmarshaled.bar_id, bar_id);
         assert_eq!(unmarshaled.downlink_data_notification_delay, Some(delay));
+        assert_eq!(unmarshaled.dl_buffering_duration, None);
         assert_eq!(unmarshaled.suggested_buffering_packets_count, None);
     }
 }
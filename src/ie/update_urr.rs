@@ -70,13 +70,13 @@ impl UpdateUrr {
             ies.push(Ie::new(IeType::MonitoringTime, mt.marshal().to_vec()));
         }
         if let Some(vt) = &self.volume_threshold {
-            ies.push(Ie::new(IeType::VolumeThreshold, vt.marshal()));
+            ies.push(vt.to_ie());
         }
         if let Some(tt) = &self.time_threshold {
             ies.push(Ie::new(IeType::TimeThreshold, tt.marshal().to_vec()));
         }
         if let Some(svt) = &self.subsequent_volume_threshold {
-            ies.push(Ie::new(IeType::SubsequentVolumeThreshold, svt.marshal()));
+            ies.push(svt.to_ie());
         }
         if let Some(stt) = &self.subsequent_time_threshold {
             ies.push(Ie::new(
@@ -255,27 +255,21 @@ impl UpdateUrrBuilder {
     ///
     /// Creates a volume threshold for total traffic (uplink + downlink).
     pub fn volume_threshold_bytes(mut self, bytes: u64) -> Self {
-        self.volume_threshold = Some(VolumeThreshold::new(
-            true,  // total volume
-            false, // not uplink only
-            false, // not downlink only
-            Some(bytes),
-            None,
-            None,
-        ));
+        self.volume_threshold = Some(VolumeThreshold {
+            total: Some(bytes),
+            uplink: None,
+            downlink: None,
+        });
         self
     }
 
     /// Convenience method to set volume threshold for uplink and downlink separately.
     pub fn volume_threshold_uplink_downlink(mut self, uplink: u64, downlink: u64) -> Self {
-        self.volume_threshold = Some(VolumeThreshold::new(
-            false, // not total
-            true,  // uplink
-            true,  // downlink
-            None,
-            Some(uplink),
-            Some(downlink),
-        ));
+        self.volume_threshold = Some(VolumeThreshold {
+            total: None,
+            uplink: Some(uplink),
+            downlink: Some(downlink),
+        });
         self
     }
 
@@ -303,14 +297,11 @@ impl UpdateUrrBuilder {
 
     /// Convenience method to set subsequent volume threshold in bytes.
     pub fn subsequent_volume_threshold_bytes(mut self, bytes: u64) -> Self {
-        self.subsequent_volume_threshold = Some(SubsequentVolumeThreshold::new(
-            true,  // total volume
-            false, // not uplink only
-            false, // not downlink only
-            Some(bytes),
-            None,
-            None,
-        ));
+        self.subsequent_volume_threshold = Some(SubsequentVolumeThreshold {
+            total: Some(bytes),
+            uplink: None,
+            downlink: None,
+        });
         self
     }
 
@@ -499,8 +490,8 @@ mod tests {
 
         assert!(urr.volume_threshold.is_some());
         let vt = urr.volume_threshold.unwrap();
-        assert_eq!(vt.uplink_volume, Some(1_000_000));
-        assert_eq!(vt.downlink_volume, Some(5_000_000));
+        assert_eq!(vt.uplink, Some(1_000_000));
+        assert_eq!(vt.downlink, Some(5_000_000));
     }
 
     #[test]
@@ -748,9 +739,9 @@ mod tests {
 
         assert!(urr.volume_threshold.is_some());
         let vt = urr.volume_threshold.unwrap();
-        assert_eq!(vt.uplink_volume, Some(2_000_000));
-        assert_eq!(vt.downlink_volume, Some(5_000_000));
-        assert!(vt.total_volume.is_none());
+        assert_eq!(vt.uplink, Some(2_000_000));
+        assert_eq!(vt.downlink, Some(5_000_000));
+        assert!(vt.total.is_none());
     }
 
     #[test]
@@ -763,8 +754,8 @@ mod tests {
 
         assert!(urr.volume_threshold.is_some());
         let vt = urr.volume_threshold.unwrap();
-        assert_eq!(vt.uplink_volume, Some(3_000_000));
-        assert_eq!(vt.downlink_volume, Some(3_000_000));
+        assert_eq!(vt.uplink, Some(3_000_000));
+        assert_eq!(vt.downlink, Some(3_000_000));
     }
 
     #[test]
@@ -776,7 +767,7 @@ mod tests {
 
         assert!(urr.subsequent_volume_threshold.is_some());
         let svt = urr.subsequent_volume_threshold.unwrap();
-        assert_eq!(svt.total_volume, Some(1_500_000));
+        assert_eq!(svt.total, Some(1_500_000));
     }
 
     #[test]
@@ -792,8 +783,8 @@ mod tests {
         assert!(urr.subsequent_volume_threshold.is_some());
         let vt = urr.volume_threshold.unwrap();
         let svt = urr.subsequent_volume_threshold.unwrap();
-        assert_eq!(vt.uplink_volume, Some(5_000_000));
-        assert_eq!(svt.total_volume, Some(3_000_000));
+        assert_eq!(vt.uplink, Some(5_000_000));
+        assert_eq!(svt.total, Some(3_000_000));
     }
 
     #[test]
@@ -807,7 +798,7 @@ mod tests {
         assert_eq!(urr.urr_id, UrrId::new(23));
         assert!(urr.volume_threshold.is_some());
         let vt = urr.volume_threshold.unwrap();
-        assert_eq!(vt.total_volume, Some(10_000_000_000));
+        assert_eq!(vt.total, Some(10_000_000_000));
     }
 
     #[test]
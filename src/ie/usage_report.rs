@@ -13,7 +13,7 @@ use crate::ie::start_time::StartTime;
 use crate::ie::time_of_first_packet::TimeOfFirstPacket;
 use crate::ie::time_of_last_packet::TimeOfLastPacket;
 use crate::ie::time_quota::TimeQuota;
-use crate::ie::ue_ip_address_usage_information::UEIPAddressUsageInformation;
+use crate::ie::ue_ip_address_usage_information::UeIpAddressUsageInformation;
 use crate::ie::urr_id::UrrId;
 use crate::ie::usage_information::UsageInformation;
 use crate::ie::usage_report_trigger::UsageReportTrigger;
@@ -44,7 +44,7 @@ pub struct UsageReport {
     // Phase 3: Extended IEs
     pub query_urr_reference: Option<QueryURRReference>,
     pub application_detection_information: Option<ApplicationDetectionInformation>,
-    pub ue_ip_address_usage_information: Option<UEIPAddressUsageInformation>,
+    pub ue_ip_address_usage_information: Option<UeIpAddressUsageInformation>,
     pub additional_usage_reports_information: Option<AdditionalUsageReportsInformation>,
 
     // Ethernet PDU Session IEs
@@ -147,9 +147,7 @@ impl UsageReport {
             }
         }
         if let Some(ref ueip) = self.ue_ip_address_usage_information {
-            if let Ok(ie) = ueip.to_ie() {
-                buffer.extend_from_slice(&ie.marshal());
-            }
+            buffer.extend_from_slice(&ueip.to_ie().marshal());
         }
         if let Some(ref auri) = self.additional_usage_reports_information {
             let ie = auri.to_ie();
@@ -224,7 +222,7 @@ impl UsageReport {
                 }
                 IeType::UeIpAddressUsageInformation => {
                     ue_ip_address_usage_information =
-                        Some(UEIPAddressUsageInformation::unmarshal(&ie.payload)?)
+                        Some(UeIpAddressUsageInformation::unmarshal(&ie.payload)?)
                 }
                 IeType::AdditionalUsageReportsInformation => {
                     additional_usage_reports_information =
@@ -236,7 +234,7 @@ impl UsageReport {
                 }
                 _ => (),
             }
-            cursor += ie.len() as usize;
+            cursor += ie.encoded_size();
         }
 
         Ok(UsageReport {
@@ -338,7 +336,7 @@ pub struct UsageReportBuilder {
     end_time: Option<EndTime>,
     query_urr_reference: Option<QueryURRReference>,
     application_detection_information: Option<ApplicationDetectionInformation>,
-    ue_ip_address_usage_information: Option<UEIPAddressUsageInformation>,
+    ue_ip_address_usage_information: Option<UeIpAddressUsageInformation>,
     additional_usage_reports_information: Option<AdditionalUsageReportsInformation>,
     ethernet_traffic_information: Option<EthernetTrafficInformation>,
 }
@@ -863,10 +861,10 @@ impl UsageReportBuilder {
     ///
     /// # Arguments
     ///
-    /// * `ue_ip_address_usage_information` - UE IP usage statistics
+    /// * `ue_ip_address_usage_information` - UE IP address pool usage (grouped IE 267)
     pub fn ue_ip_address_usage_information(
         mut self,
-        ue_ip_address_usage_information: UEIPAddressUsageInformation,
+        ue_ip_address_usage_information: UeIpAddressUsageInformation,
     ) -> Self {
         self.ue_ip_address_usage_information = Some(ue_ip_address_usage_information);
         self
@@ -936,18 +934,6 @@ impl UsageReportBuilder {
         self
     }
 
-    /// Convenience method to set UE IP usage with IPv4.
-    ///
-    /// # Arguments
-    ///
-    /// * `ipv4` - The IPv4 address
-    /// * `count` - Number of UE IP addresses
-    pub fn with_ue_ipv4_usage(mut self, ipv4: std::net::Ipv4Addr, count: u32) -> Self {
-        self.ue_ip_address_usage_information =
-            Some(UEIPAddressUsageInformation::with_ipv4(ipv4, count));
-        self
-    }
-
     /// Convenience method to set additional usage report flags.
     ///
     /// # Arguments
@@ -965,6 +951,19 @@ impl UsageReportBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ie::metric::Metric;
+    use crate::ie::number_of_ue_ip_addresses::NumberOfUeIpAddresses;
+    use crate::ie::validity_timer::ValidityTimer;
+
+    fn ue_ip_usage(ipv4_addresses: u32) -> UeIpAddressUsageInformation {
+        UeIpAddressUsageInformation::builder(
+            SequenceNumber::new(1),
+            Metric::new(50).unwrap(),
+            ValidityTimer::new(600),
+        )
+        .number_of_ue_ip_addresses(NumberOfUeIpAddresses::ipv4(ipv4_addresses))
+        .build()
+    }
 
     #[test]
     fn test_usage_report_marshal_unmarshal() {
@@ -1908,8 +1907,6 @@ mod tests {
 
     #[test]
     fn test_usage_report_phase3_individual_ies() {
-        use std::net::Ipv4Addr;
-
         // Test each Phase 3 IE individually to ensure proper handling
 
         // QueryURRReference only
@@ -1938,14 +1935,11 @@ mod tests {
         let unmarshaled = UsageReport::unmarshal(&marshaled).unwrap();
         assert_eq!(adi_report, unmarshaled);
 
-        // UEIPAddressUsageInformation only
+        // UeIpAddressUsageInformation only
         let ueip_report = UsageReportBuilder::new(UrrId::new(3))
             .sequence_number(SequenceNumber::new(3))
             .quota_exhausted()
-            .ue_ip_address_usage_information(UEIPAddressUsageInformation::with_ipv4(
-                Ipv4Addr::new(192, 168, 1, 100),
-                5,
-            ))
+            .ue_ip_address_usage_information(ue_ip_usage(5))
             .build()
             .unwrap();
 
@@ -1968,8 +1962,6 @@ mod tests {
 
     #[test]
     fn test_usage_report_with_all_phase3_fields() {
-        use std::net::Ipv4Addr;
-
         let comprehensive_report = UsageReportBuilder::new(UrrId::new(1))
             .sequence_number(SequenceNumber::new(100))
             .periodic_report()
@@ -1979,10 +1971,7 @@ mod tests {
                 "video_session_123",
                 "tcp:443,udp:443",
             ))
-            .ue_ip_address_usage_information(UEIPAddressUsageInformation::with_ipv4(
-                Ipv4Addr::new(10, 0, 0, 1),
-                10,
-            ))
+            .ue_ip_address_usage_information(ue_ip_usage(10))
             .additional_usage_reports_information(
                 AdditionalUsageReportsInformation::with_both_flags(),
             )
@@ -2023,8 +2012,10 @@ mod tests {
         let ueip = comprehensive_report
             .ue_ip_address_usage_information
             .unwrap();
-        assert_eq!(ueip.ipv4_address, Some(Ipv4Addr::new(10, 0, 0, 1)));
-        assert_eq!(ueip.number_of_ue_ip_addresses, Some(10));
+        assert_eq!(
+            ueip.number_of_ue_ip_addresses,
+            Some(NumberOfUeIpAddresses::ipv4(10))
+        );
 
         let auri = comprehensive_report
             .additional_usage_reports_information
@@ -2035,15 +2026,13 @@ mod tests {
 
     #[test]
     fn test_usage_report_phase3_convenience_methods() {
-        use std::net::Ipv4Addr;
-
         let convenience_report = UsageReportBuilder::new(UrrId::new(42))
             .sequence_number(SequenceNumber::new(123))
             .quota_exhausted()
             .with_query_reference(0x87654321)
             .with_detected_application("WhatsApp")
             .with_application_instance("Instagram", "mobile_session_456")
-            .with_ue_ipv4_usage(Ipv4Addr::new(203, 0, 113, 1), 3)
+            .ue_ip_address_usage_information(ue_ip_usage(3))
             .with_additional_flags(true, false) // AURI=true, NOURI=false
             .build()
             .unwrap();
@@ -2068,8 +2057,10 @@ mod tests {
         );
 
         let ueip = convenience_report.ue_ip_address_usage_information.unwrap();
-        assert_eq!(ueip.ipv4_address, Some(Ipv4Addr::new(203, 0, 113, 1)));
-        assert_eq!(ueip.number_of_ue_ip_addresses, Some(3));
+        assert_eq!(
+            ueip.number_of_ue_ip_addresses,
+            Some(NumberOfUeIpAddresses::ipv4(3))
+        );
 
         let auri = convenience_report
             .additional_usage_reports_information
@@ -2080,8 +2071,6 @@ mod tests {
 
     #[test]
     fn test_usage_report_comprehensive_phase1_phase2_phase3_scenario() {
-        use std::net::Ipv4Addr;
-
         // Test a comprehensive scenario combining all phases
         let complete_report = UsageReportBuilder::new(UrrId::new(999))
             .sequence_number(SequenceNumber::new(555))
@@ -2100,7 +2089,7 @@ mod tests {
             // Phase 3: Extended
             .with_query_reference(0xCAFEBABE)
             .with_detected_application("TikTok")
-            .with_ue_ipv4_usage(Ipv4Addr::new(172, 16, 0, 1), 1)
+            .ue_ip_address_usage_information(ue_ip_usage(1))
             .with_additional_flags(true, true)
             .build()
             .unwrap();
@@ -2149,15 +2138,13 @@ mod tests {
             complete_report
                 .ue_ip_address_usage_information
                 .unwrap()
-                .ipv4_address,
-            Some(Ipv4Addr::new(172, 16, 0, 1))
+                .number_of_ue_ip_addresses,
+            Some(NumberOfUeIpAddresses::ipv4(1))
         );
     }
 
     #[test]
     fn test_usage_report_phase3_marshal_unmarshal_edge_cases() {
-        use std::net::Ipv4Addr;
-
         // Test edge cases for Phase 3 IEs
         let edge_case_report = UsageReportBuilder::new(UrrId::new(777))
             .sequence_number(SequenceNumber::new(888))
@@ -2168,10 +2155,7 @@ mod tests {
                 "session_with_unicode_🎧",
                 "complex:flow:info:with:colons",
             ))
-            .ue_ip_address_usage_information(UEIPAddressUsageInformation::with_ipv4(
-                Ipv4Addr::new(0, 0, 0, 0), // Edge case IP
-                u32::MAX,                  // Maximum count
-            ))
+            .ue_ip_address_usage_information(ue_ip_usage(u32::MAX))
             .additional_usage_reports_information(AdditionalUsageReportsInformation::new(0xFF)) // All flags
             .build()
             .unwrap();
@@ -2192,8 +2176,10 @@ mod tests {
         assert!(adi.application_instance_id.unwrap().contains("unicode_🎧"));
 
         let ueip = edge_case_report.ue_ip_address_usage_information.unwrap();
-        assert_eq!(ueip.ipv4_address, Some(Ipv4Addr::new(0, 0, 0, 0)));
-        assert_eq!(ueip.number_of_ue_ip_addresses, Some(u32::MAX));
+        assert_eq!(
+            ueip.number_of_ue_ip_addresses,
+            Some(NumberOfUeIpAddresses::ipv4(u32::MAX))
+        );
 
         let auri = edge_case_report
             .additional_usage_reports_information
@@ -2203,14 +2189,12 @@ mod tests {
 
     #[test]
     fn test_usage_report_phase3_real_world_scenarios() {
-        use std::net::Ipv4Addr;
-
         // Scenario 1: Video streaming with DPI detection
         let video_streaming =
             UsageReportBuilder::quota_exhausted_report(UrrId::new(1), SequenceNumber::new(1))
                 .with_volume_data(500000000, 50000000, 450000000) // 500MB total, mostly downlink
                 .with_detected_application("Netflix")
-                .with_ue_ipv4_usage(Ipv4Addr::new(192, 168, 1, 100), 1)
+                .ue_ip_address_usage_information(ue_ip_usage(1))
                 .with_query_reference(0x10000001)
                 .build()
                 .unwrap();
@@ -2220,7 +2204,7 @@ mod tests {
             .sequence_number(SequenceNumber::new(2))
             .stop_of_traffic()
             .with_application_instance("Instagram", "stories_session")
-            .with_ue_ipv4_usage(Ipv4Addr::new(10, 0, 0, 50), 1)
+            .ue_ip_address_usage_information(ue_ip_usage(1))
             .with_additional_flags(false, true) // No additional interim reports
             .build()
             .unwrap();
@@ -2235,10 +2219,7 @@ mod tests {
                         "tcp:443,websocket:443",
                     ),
                 )
-                .ue_ip_address_usage_information(UEIPAddressUsageInformation::with_ipv4(
-                    Ipv4Addr::new(172, 16, 100, 200),
-                    25, // Multiple users
-                ))
+                .ue_ip_address_usage_information(ue_ip_usage(25))
                 .with_query_reference(0x30000003)
                 .with_additional_flags(true, false) // Additional interim reporting
                 .build()
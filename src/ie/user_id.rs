@@ -1,141 +1,159 @@
 //! User ID IE.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.101, the User ID IE carries one or more
+//! subscriber and equipment identities, each announced by a flag in the
+//! first octet.
 
 use crate::error::PfcpError;
 use crate::ie::{Ie, IeType};
 
-/// Represents the User ID Information Element.
-/// Used for enhanced user identification in 5G networks.
-/// Defined in 3GPP TS 29.244 Section 8.2.100.
-#[derive(Debug, Clone, PartialEq, Eq)]
+const IMSIF: u8 = 0x01;
+const IMEIF: u8 = 0x02;
+const MSISDNF: u8 = 0x04;
+const NAIF: u8 = 0x08;
+const SUPIF: u8 = 0x10;
+const GPSIF: u8 = 0x20;
+const PEIF: u8 = 0x40;
+
+/// User ID per 3GPP TS 29.244 §8.2.101.
+///
+/// IMSI, IMEI and MSISDN are carried as TBCD-encoded octets (3GPP TS 29.274
+/// §8.3, §8.10 and §8.11) and are kept as raw bytes. NAI, SUPI, GPSI and PEI
+/// are text identities.
+///
+/// The identities are private so that each one is known to fit its 1-byte
+/// length field: build a User ID with [`UserIdBuilder`] or
+/// [`unmarshal`](UserId::unmarshal), and read it back through the accessors.
+///
+/// # Wire Format
+/// - Octet 5: Spare | PEIF | GPSIF | SUPIF | NAIF | MSISDNF | IMEIF | IMSIF
+/// - For each flag set, in flag order: 1-byte length + identity octets
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::user_id::UserId;
+///
+/// let user_id = UserId::builder()
+///     .imsi(&[0x21, 0x43, 0x65, 0x87, 0x09, 0x21, 0x43, 0xF5])
+///     .nai("user@example.com")
+///     .build()?;
+///
+/// let parsed = UserId::unmarshal(&user_id.marshal())?;
+/// assert_eq!(parsed, user_id);
+/// assert_eq!(parsed.nai(), Some("user@example.com"));
+///
+/// // User ID is carried at message level, e.g. in a Session Establishment Request
+/// use rs_pfcp::message::session_establishment_request::SessionEstablishmentRequestBuilder;
+/// let _builder = SessionEstablishmentRequestBuilder::new(0, 1).user_id(user_id.to_ie());
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct UserId {
-    pub user_id_type: UserIdType,
-    pub user_id_value: Vec<u8>,
-}
-
-/// User ID type values as defined in 3GPP TS 29.244.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum UserIdType {
-    /// IMSI (International Mobile Subscriber Identity)
-    Imsi = 0,
-    /// IMEI (International Mobile Equipment Identity)
-    Imei = 1,
-    /// MSISDN (Mobile Station International Subscriber Directory Number)
-    Msisdn = 2,
-    /// NAI (Network Access Identifier)
-    Nai = 3,
-    /// SUPI (Subscription Permanent Identifier)
-    Supi = 4,
-    /// GPSI (Generic Public Subscription Identifier)
-    Gpsi = 5,
-    /// Unknown/reserved user ID type
-    Unknown(u8),
-}
-
-impl From<u8> for UserIdType {
-    fn from(value: u8) -> Self {
-        match value {
-            0 => UserIdType::Imsi,
-            1 => UserIdType::Imei,
-            2 => UserIdType::Msisdn,
-            3 => UserIdType::Nai,
-            4 => UserIdType::Supi,
-            5 => UserIdType::Gpsi,
-            _ => UserIdType::Unknown(value),
-        }
-    }
-}
-
-impl From<UserIdType> for u8 {
-    fn from(user_id_type: UserIdType) -> u8 {
-        match user_id_type {
-            UserIdType::Imsi => 0,
-            UserIdType::Imei => 1,
-            UserIdType::Msisdn => 2,
-            UserIdType::Nai => 3,
-            UserIdType::Supi => 4,
-            UserIdType::Gpsi => 5,
-            UserIdType::Unknown(value) => value,
-        }
-    }
+    /// IMSI, TBCD-encoded.
+    imsi: Option<Vec<u8>>,
+    /// IMEI or IMEISV, TBCD-encoded.
+    imei: Option<Vec<u8>>,
+    /// MSISDN, TBCD-encoded.
+    msisdn: Option<Vec<u8>>,
+    /// Network Access Identifier.
+    nai: Option<String>,
+    /// Subscription Permanent Identifier.
+    supi: Option<String>,
+    /// Generic Public Subscription Identifier.
+    gpsi: Option<String>,
+    /// Permanent Equipment Identifier.
+    pei: Option<String>,
 }
 
 impl UserId {
-    /// Creates a new User ID IE.
-    pub fn new(user_id_type: UserIdType, user_id_value: Vec<u8>) -> Self {
-        UserId {
-            user_id_type,
-            user_id_value,
-        }
+    /// Returns a builder for constructing User ID instances.
+    pub fn builder() -> UserIdBuilder {
+        UserIdBuilder::new()
     }
 
-    /// Creates a User ID with IMSI.
-    pub fn imsi(imsi: Vec<u8>) -> Self {
-        UserId::new(UserIdType::Imsi, imsi)
+    /// Returns the TBCD-encoded IMSI, if present.
+    pub fn imsi(&self) -> Option<&[u8]> {
+        self.imsi.as_deref()
     }
 
-    /// Creates a User ID with IMEI.
-    pub fn imei(imei: Vec<u8>) -> Self {
-        UserId::new(UserIdType::Imei, imei)
+    /// Returns the TBCD-encoded IMEI or IMEISV, if present.
+    pub fn imei(&self) -> Option<&[u8]> {
+        self.imei.as_deref()
     }
 
-    /// Creates a User ID with MSISDN.
-    pub fn msisdn(msisdn: Vec<u8>) -> Self {
-        UserId::new(UserIdType::Msisdn, msisdn)
+    /// Returns the TBCD-encoded MSISDN, if present.
+    pub fn msisdn(&self) -> Option<&[u8]> {
+        self.msisdn.as_deref()
     }
 
-    /// Creates a User ID with NAI.
-    pub fn nai(nai: Vec<u8>) -> Self {
-        UserId::new(UserIdType::Nai, nai)
+    /// Returns the Network Access Identifier, if present.
+    pub fn nai(&self) -> Option<&str> {
+        self.nai.as_deref()
     }
 
-    /// Creates a User ID with SUPI.
-    pub fn supi(supi: Vec<u8>) -> Self {
-        UserId::new(UserIdType::Supi, supi)
+    /// Returns the SUPI, if present.
+    pub fn supi(&self) -> Option<&str> {
+        self.supi.as_deref()
     }
 
-    /// Creates a User ID with GPSI.
-    pub fn gpsi(gpsi: Vec<u8>) -> Self {
-        UserId::new(UserIdType::Gpsi, gpsi)
+    /// Returns the GPSI, if present.
+    pub fn gpsi(&self) -> Option<&str> {
+        self.gpsi.as_deref()
     }
 
-    /// Creates a User ID with NAI from string (convenience method).
-    pub fn nai_from_string(nai: String) -> Self {
-        UserId::nai(nai.into_bytes())
+    /// Returns the PEI, if present.
+    pub fn pei(&self) -> Option<&str> {
+        self.pei.as_deref()
     }
 
-    /// Creates a User ID with SUPI from string (convenience method).
-    pub fn supi_from_string(supi: String) -> Self {
-        UserId::supi(supi.into_bytes())
+    /// Returns `true` if no identity is present.
+    pub fn is_empty(&self) -> bool {
+        self.flags() == 0
     }
 
-    /// Gets the user ID value as a string (for text-based types like NAI).
-    pub fn as_string(&self) -> Option<String> {
-        match self.user_id_type {
-            UserIdType::Nai | UserIdType::Supi | UserIdType::Gpsi => {
-                std::str::from_utf8(&self.user_id_value)
-                    .map(|s| s.to_string())
-                    .ok()
-            }
-            _ => None,
+    fn flags(&self) -> u8 {
+        let mut flags = 0u8;
+        if self.imsi.is_some() {
+            flags |= IMSIF;
         }
-    }
-
-    /// Gets the length of the marshaled User ID.
-    pub fn len(&self) -> usize {
-        1 + self.user_id_value.len() // 1 byte for type + value length
-    }
-
-    /// Checks if the User ID is empty.
-    pub fn is_empty(&self) -> bool {
-        self.user_id_value.is_empty()
+        if self.imei.is_some() {
+            flags |= IMEIF;
+        }
+        if self.msisdn.is_some() {
+            flags |= MSISDNF;
+        }
+        if self.nai.is_some() {
+            flags |= NAIF;
+        }
+        if self.supi.is_some() {
+            flags |= SUPIF;
+        }
+        if self.gpsi.is_some() {
+            flags |= GPSIF;
+        }
+        if self.pei.is_some() {
+            flags |= PEIF;
+        }
+        flags
     }
 
     /// Marshals the User ID into a byte vector.
     pub fn marshal(&self) -> Vec<u8> {
-        let mut data = Vec::new();
-        data.push(u8::from(self.user_id_type));
-        data.extend_from_slice(&self.user_id_value);
+        let mut data = vec![self.flags()];
+        let fields = [
+            self.imsi.as_deref(),
+            self.imei.as_deref(),
+            self.msisdn.as_deref(),
+            self.nai.as_ref().map(String::as_bytes),
+            self.supi.as_ref().map(String::as_bytes),
+            self.gpsi.as_ref().map(String::as_bytes),
+            self.pei.as_ref().map(String::as_bytes),
+        ];
+        for value in fields.into_iter().flatten() {
+            data.push(value.len() as u8);
+            data.extend_from_slice(value);
+        }
         data
     }
 
@@ -145,204 +163,272 @@ impl UserId {
             return Err(PfcpError::invalid_length("User ID", IeType::UserId, 1, 0));
         }
 
-        let user_id_type = UserIdType::from(payload[0]);
-        let user_id_value = payload[1..].to_vec();
+        let flags = payload[0];
+        let mut offset = 1;
+        let mut user_id = UserId::default();
+
+        if flags & IMSIF != 0 {
+            user_id.imsi = Some(Self::read_field(payload, &mut offset, "User ID (IMSI)")?);
+        }
+        if flags & IMEIF != 0 {
+            user_id.imei = Some(Self::read_field(payload, &mut offset, "User ID (IMEI)")?);
+        }
+        if flags & MSISDNF != 0 {
+            user_id.msisdn = Some(Self::read_field(payload, &mut offset, "User ID (MSISDN)")?);
+        }
+        if flags & NAIF != 0 {
+            user_id.nai = Some(Self::read_text(payload, &mut offset, "User ID (NAI)")?);
+        }
+        if flags & SUPIF != 0 {
+            user_id.supi = Some(Self::read_text(payload, &mut offset, "User ID (SUPI)")?);
+        }
+        if flags & GPSIF != 0 {
+            user_id.gpsi = Some(Self::read_text(payload, &mut offset, "User ID (GPSI)")?);
+        }
+        if flags & PEIF != 0 {
+            user_id.pei = Some(Self::read_text(payload, &mut offset, "User ID (PEI)")?);
+        }
 
-        Ok(UserId {
-            user_id_type,
-            user_id_value,
-        })
+        Ok(user_id)
     }
 
     /// Wraps the User ID in a User ID IE.
     pub fn to_ie(&self) -> Ie {
         Ie::new(IeType::UserId, self.marshal())
     }
+
+    /// Reads a length-prefixed identity at `offset` and advances past it.
+    fn read_field(data: &[u8], offset: &mut usize, name: &str) -> Result<Vec<u8>, PfcpError> {
+        if data.len() < *offset + 1 {
+            return Err(PfcpError::invalid_length(
+                name,
+                IeType::UserId,
+                *offset + 1,
+                data.len(),
+            ));
+        }
+        let len = data[*offset] as usize;
+        let start = *offset + 1;
+        let end = start + len;
+        if data.len() < end {
+            return Err(PfcpError::invalid_length(
+                name,
+                IeType::UserId,
+                end,
+                data.len(),
+            ));
+        }
+        *offset = end;
+        Ok(data[start..end].to_vec())
+    }
+
+    fn read_text(data: &[u8], offset: &mut usize, name: &str) -> Result<String, PfcpError> {
+        let bytes = Self::read_field(data, offset, name)?;
+        String::from_utf8(bytes)
+            .map_err(|e| PfcpError::encoding_error(name, IeType::UserId, e.utf8_error()))
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Builder for User ID Information Elements.
+///
+/// At least one identity must be set.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::user_id::UserIdBuilder;
+///
+/// let user_id = UserIdBuilder::new()
+///     .msisdn(&[0x94, 0x71, 0x52, 0x76, 0x00, 0xF1])
+///     .build()?;
+///
+/// assert!(user_id.msisdn().is_some());
+/// assert!(UserIdBuilder::new().build().is_err());
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct UserIdBuilder {
+    user_id: UserId,
+}
 
-    #[test]
-    fn test_user_id_marshal_unmarshal_imsi() {
-        let imsi_data = vec![0x12, 0x34, 0x56, 0x78, 0x90];
-        let user_id = UserId::imsi(imsi_data.clone());
-        let marshaled = user_id.marshal();
-        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
+impl UserIdBuilder {
+    /// Creates a new User ID builder with no identities set.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        assert_eq!(user_id, unmarshaled);
-        assert_eq!(unmarshaled.user_id_type, UserIdType::Imsi);
-        assert_eq!(unmarshaled.user_id_value, imsi_data);
-        assert_eq!(marshaled[0], 0); // IMSI type
-        assert_eq!(marshaled[1..], imsi_data);
-        assert_eq!(user_id.len(), 6);
-        assert!(!user_id.is_empty());
+    /// Sets the TBCD-encoded IMSI.
+    pub fn imsi(mut self, bcd: &[u8]) -> Self {
+        self.user_id.imsi = Some(bcd.to_vec());
+        self
     }
 
-    #[test]
-    fn test_user_id_marshal_unmarshal_nai_string() {
-        let nai_string = "user@example.com".to_string();
-        let user_id = UserId::nai_from_string(nai_string.clone());
-        let marshaled = user_id.marshal();
-        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
+    /// Sets the TBCD-encoded IMEI or IMEISV.
+    pub fn imei(mut self, bcd: &[u8]) -> Self {
+        self.user_id.imei = Some(bcd.to_vec());
+        self
+    }
 
-        assert_eq!(user_id, unmarshaled);
-        assert_eq!(unmarshaled.user_id_type, UserIdType::Nai);
-        assert_eq!(unmarshaled.user_id_value, nai_string.as_bytes());
-        assert_eq!(unmarshaled.as_string(), Some(nai_string.clone()));
-        assert_eq!(marshaled[0], 3); // NAI type
-        assert_eq!(&marshaled[1..], nai_string.as_bytes());
+    /// Sets the TBCD-encoded MSISDN.
+    pub fn msisdn(mut self, bcd: &[u8]) -> Self {
+        self.user_id.msisdn = Some(bcd.to_vec());
+        self
     }
 
-    #[test]
-    fn test_user_id_marshal_unmarshal_supi() {
-        let supi_string = "supi-imsi-001010123456789".to_string();
-        let user_id = UserId::supi_from_string(supi_string.clone());
-        let marshaled = user_id.marshal();
-        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
+    /// Sets the Network Access Identifier.
+    pub fn nai(mut self, nai: &str) -> Self {
+        self.user_id.nai = Some(nai.to_string());
+        self
+    }
 
-        assert_eq!(user_id, unmarshaled);
-        assert_eq!(unmarshaled.user_id_type, UserIdType::Supi);
-        assert_eq!(unmarshaled.as_string(), Some(supi_string.clone()));
-        assert_eq!(marshaled[0], 4); // SUPI type
-        assert_eq!(&marshaled[1..], supi_string.as_bytes());
+    /// Sets the SUPI.
+    pub fn supi(mut self, supi: &str) -> Self {
+        self.user_id.supi = Some(supi.to_string());
+        self
     }
 
-    #[test]
-    fn test_user_id_marshal_unmarshal_imei() {
-        let imei_data = vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF];
-        let user_id = UserId::imei(imei_data.clone());
-        let marshaled = user_id.marshal();
-        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
+    /// Sets the GPSI.
+    pub fn gpsi(mut self, gpsi: &str) -> Self {
+        self.user_id.gpsi = Some(gpsi.to_string());
+        self
+    }
 
-        assert_eq!(user_id, unmarshaled);
-        assert_eq!(unmarshaled.user_id_type, UserIdType::Imei);
-        assert_eq!(unmarshaled.user_id_value, imei_data);
-        assert_eq!(marshaled[0], 1); // IMEI type
-        assert_eq!(marshaled[1..], imei_data);
+    /// Sets the PEI.
+    pub fn pei(mut self, pei: &str) -> Self {
+        self.user_id.pei = Some(pei.to_string());
+        self
     }
 
-    #[test]
-    fn test_user_id_marshal_unmarshal_msisdn() {
-        let msisdn_data = vec![0x91, 0x12, 0x34, 0x56, 0x78, 0x90];
-        let user_id = UserId::msisdn(msisdn_data.clone());
-        let marshaled = user_id.marshal();
-        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
+    /// Builds the User ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no identity is set, or if an identity does not fit
+    /// its 1-byte length field.
+    pub fn build(self) -> Result<UserId, PfcpError> {
+        let user_id = self.user_id;
+        if user_id.is_empty() {
+            return Err(PfcpError::validation_error(
+                "UserIdBuilder",
+                "identity",
+                "at least one of IMSI, IMEI, MSISDN, NAI, SUPI, GPSI or PEI is required",
+            ));
+        }
 
-        assert_eq!(user_id, unmarshaled);
-        assert_eq!(unmarshaled.user_id_type, UserIdType::Msisdn);
-        assert_eq!(unmarshaled.user_id_value, msisdn_data);
-        assert_eq!(marshaled[0], 2); // MSISDN type
+        let lengths = [
+            ("imsi", user_id.imsi.as_ref().map(Vec::len)),
+            ("imei", user_id.imei.as_ref().map(Vec::len)),
+            ("msisdn", user_id.msisdn.as_ref().map(Vec::len)),
+            ("nai", user_id.nai.as_ref().map(String::len)),
+            ("supi", user_id.supi.as_ref().map(String::len)),
+            ("gpsi", user_id.gpsi.as_ref().map(String::len)),
+            ("pei", user_id.pei.as_ref().map(String::len)),
+        ];
+        for (field, len) in lengths {
+            if let Some(len) = len.filter(|&len| len > u8::MAX as usize) {
+                return Err(PfcpError::validation_error(
+                    "UserIdBuilder",
+                    field,
+                    format!("length {len} exceeds 255 bytes"),
+                ));
+            }
+        }
+
+        Ok(user_id)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMSI: [u8; 8] = [0x21, 0x43, 0x65, 0x87, 0x09, 0x21, 0x43, 0xF5];
 
     #[test]
-    fn test_user_id_marshal_unmarshal_gpsi() {
-        let gpsi_string = "msisdn-1234567890".to_string();
-        let user_id = UserId::gpsi(gpsi_string.as_bytes().to_vec());
+    fn test_user_id_imsi_only_round_trip() {
+        let user_id = UserIdBuilder::new().imsi(&IMSI).build().unwrap();
         let marshaled = user_id.marshal();
-        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
 
-        assert_eq!(user_id, unmarshaled);
-        assert_eq!(unmarshaled.user_id_type, UserIdType::Gpsi);
-        assert_eq!(unmarshaled.as_string(), Some(gpsi_string));
-        assert_eq!(marshaled[0], 5); // GPSI type
+        let mut expected = vec![IMSIF, IMSI.len() as u8];
+        expected.extend_from_slice(&IMSI);
+        assert_eq!(marshaled, expected);
+
+        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
+        assert_eq!(unmarshaled, user_id);
+        assert_eq!(unmarshaled.imsi(), Some(&IMSI[..]));
+        assert!(unmarshaled.nai().is_none());
     }
 
     #[test]
-    fn test_user_id_unknown_type() {
-        let unknown_data = vec![0xAA, 0xBB, 0xCC];
-        let user_id = UserId::new(UserIdType::Unknown(99), unknown_data.clone());
+    fn test_user_id_imsi_and_nai_round_trip() {
+        let user_id = UserId::builder()
+            .nai("user@example.com")
+            .imsi(&IMSI)
+            .build()
+            .unwrap();
         let marshaled = user_id.marshal();
-        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
 
-        assert_eq!(user_id, unmarshaled);
-        assert_eq!(unmarshaled.user_id_type, UserIdType::Unknown(99));
-        assert_eq!(unmarshaled.user_id_value, unknown_data);
-        assert_eq!(marshaled[0], 99);
-        assert_eq!(unmarshaled.as_string(), None);
+        // Flags, then IMSI before NAI regardless of the order they were set in
+        assert_eq!(marshaled[0], IMSIF | NAIF);
+        assert_eq!(marshaled[1], IMSI.len() as u8);
+        assert_eq!(marshaled[10], 16);
+        assert_eq!(&marshaled[11..], b"user@example.com");
+
+        let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
+        assert_eq!(unmarshaled, user_id);
     }
 
     #[test]
-    fn test_user_id_to_ie() {
-        let user_id = UserId::nai_from_string("test@domain.com".to_string());
-        let ie = user_id.to_ie();
+    fn test_user_id_all_identities_round_trip() {
+        let user_id = UserId::builder()
+            .imsi(&IMSI)
+            .imei(&[0x53, 0x09, 0x71, 0x00, 0x27, 0x54, 0x31, 0xF7])
+            .msisdn(&[0x94, 0x71, 0x52, 0x76, 0x00, 0xF1])
+            .nai("user@example.com")
+            .supi("imsi-001010123456789")
+            .gpsi("msisdn-491725670001")
+            .pei("imei-359071002745317")
+            .build()
+            .unwrap();
 
+        let ie = user_id.to_ie();
         assert_eq!(ie.ie_type, IeType::UserId);
-
-        let unmarshaled = UserId::unmarshal(&ie.payload).unwrap();
-        assert_eq!(user_id, unmarshaled);
+        assert_eq!(ie.payload[0], 0x7F);
+        assert_eq!(UserId::unmarshal(&ie.payload).unwrap(), user_id);
     }
 
     #[test]
-    fn test_user_id_unmarshal_empty() {
-        use crate::error::PfcpError;
-
-        let result = UserId::unmarshal(&[]);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(matches!(err, PfcpError::InvalidLength { .. }));
+    fn test_user_id_builder_requires_identity() {
+        let result = UserIdBuilder::new().build();
+        assert!(matches!(result, Err(PfcpError::ValidationError { .. })));
     }
 
     #[test]
-    fn test_user_id_unmarshal_type_only() {
-        let result = UserId::unmarshal(&[3]); // NAI type with no value
-        assert!(result.is_ok());
-        let user_id = result.unwrap();
-        assert_eq!(user_id.user_id_type, UserIdType::Nai);
-        assert!(user_id.user_id_value.is_empty());
-        assert!(user_id.is_empty());
+    fn test_user_id_builder_rejects_oversized_identity() {
+        let result = UserIdBuilder::new().nai(&"a".repeat(256)).build();
+        assert!(matches!(result, Err(PfcpError::ValidationError { .. })));
     }
 
     #[test]
-    fn test_user_id_type_conversions() {
-        // Test u8 to UserIdType conversion
-        assert_eq!(UserIdType::from(0), UserIdType::Imsi);
-        assert_eq!(UserIdType::from(1), UserIdType::Imei);
-        assert_eq!(UserIdType::from(2), UserIdType::Msisdn);
-        assert_eq!(UserIdType::from(3), UserIdType::Nai);
-        assert_eq!(UserIdType::from(4), UserIdType::Supi);
-        assert_eq!(UserIdType::from(5), UserIdType::Gpsi);
-        assert_eq!(UserIdType::from(99), UserIdType::Unknown(99));
-
-        // Test UserIdType to u8 conversion
-        assert_eq!(u8::from(UserIdType::Imsi), 0);
-        assert_eq!(u8::from(UserIdType::Imei), 1);
-        assert_eq!(u8::from(UserIdType::Msisdn), 2);
-        assert_eq!(u8::from(UserIdType::Nai), 3);
-        assert_eq!(u8::from(UserIdType::Supi), 4);
-        assert_eq!(u8::from(UserIdType::Gpsi), 5);
-        assert_eq!(u8::from(UserIdType::Unknown(99)), 99);
+    fn test_user_id_unmarshal_empty() {
+        let result = UserId::unmarshal(&[]);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
     }
 
     #[test]
-    fn test_user_id_as_string_binary_types() {
-        // Binary types (IMSI, IMEI, MSISDN) should not return string
-        let imsi = UserId::imsi(vec![0x12, 0x34, 0x56]);
-        let imei = UserId::imei(vec![0x12, 0x34, 0x56]);
-        let msisdn = UserId::msisdn(vec![0x12, 0x34, 0x56]);
-
-        assert_eq!(imsi.as_string(), None);
-        assert_eq!(imei.as_string(), None);
-        assert_eq!(msisdn.as_string(), None);
+    fn test_user_id_unmarshal_truncated_identity() {
+        // IMSIF set, declares 8 bytes but carries 3
+        let result = UserId::unmarshal(&[IMSIF, 8, 0x21, 0x43, 0x65]);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
+
+        // NAIF set, length octet missing
+        let result = UserId::unmarshal(&[NAIF]);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
     }
 
     #[test]
-    fn test_user_id_round_trip_all_types() {
-        let test_cases = vec![
-            UserId::imsi(vec![0x12, 0x34, 0x56, 0x78, 0x90]),
-            UserId::imei(vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF]),
-            UserId::msisdn(vec![0x91, 0x12, 0x34, 0x56, 0x78, 0x90]),
-            UserId::nai_from_string("user@example.com".to_string()),
-            UserId::supi_from_string("supi-imsi-001010123456789".to_string()),
-            UserId::gpsi(b"msisdn-1234567890".to_vec()),
-            UserId::new(UserIdType::Unknown(200), vec![0xFF, 0xEE, 0xDD]),
-        ];
-
-        for original in test_cases {
-            let marshaled = original.marshal();
-            let unmarshaled = UserId::unmarshal(&marshaled).unwrap();
-            assert_eq!(original, unmarshaled);
-        }
+    fn test_user_id_unmarshal_invalid_nai() {
+        let result = UserId::unmarshal(&[NAIF, 2, 0xFF, 0xFE]);
+        assert!(matches!(result, Err(PfcpError::EncodingError { .. })));
     }
 }
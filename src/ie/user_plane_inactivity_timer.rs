@@ -206,6 +206,7 @@ mod tests {
 
         let unmarshaled = UserPlaneInactivityTimer::unmarshal(&ie.payload).unwrap();
         assert_eq!(timer, unmarshaled);
+        assert_eq!(Ie::from(timer), ie);
     }
 
     #[test]
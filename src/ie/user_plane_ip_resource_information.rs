@@ -0,0 +1,266 @@
+//! User Plane IP Resource Information Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.82, advertises a GTP-U address and TEID
+//! range of the UP function, optionally tied to a network instance or source
+//! interface. Only used over Sxa/Sxb by UP functions that allocate F-TEIDs
+//! themselves without the CP function's help (release 15 and earlier).
+
+use crate::error::PfcpError;
+use crate::ie::source_interface::SourceInterfaceValue;
+use crate::ie::{Ie, IeType};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+const V4: u8 = 0x01;
+const V6: u8 = 0x02;
+const TEIDRI_SHIFT: u8 = 2;
+const TEIDRI_MASK: u8 = 0x07;
+const ASSONI: u8 = 0x20;
+const ASSOSI: u8 = 0x40;
+
+/// User Plane IP Resource Information per 3GPP TS 29.244 §8.2.82.
+///
+/// # Wire Format
+/// - Byte 0: flags
+///   - Bit 1 (V4=0x01): IPv4 address present
+///   - Bit 2 (V6=0x02): IPv6 address present
+///   - Bits 3-5 (TEIDRI): number of TEID bits used for the TEID range
+///   - Bit 6 (ASSONI=0x20): Network Instance present
+///   - Bit 7 (ASSOSI=0x40): Source Interface present
+/// - If TEIDRI != 0: 1 byte TEID range
+/// - If V4: 4 bytes IPv4
+/// - If V6: 16 bytes IPv6
+/// - If ASSONI: Network Instance octets, up to the Source Interface or the end
+/// - If ASSOSI: 1 byte Source Interface (bits 1-4)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserPlaneIpResourceInformation {
+    pub ipv4: Option<Ipv4Addr>,
+    pub ipv6: Option<Ipv6Addr>,
+    /// Number of most significant TEID bits fixed by `teid_range` (0-7).
+    pub teid_range_indication: u8,
+    /// Value of the fixed TEID bits; ignored when `teid_range_indication` is 0.
+    pub teid_range: u8,
+    /// Network Instance the resources belong to.
+    pub network_instance: Option<Vec<u8>>,
+    /// Source Interface the resources belong to.
+    pub source_interface: Option<SourceInterfaceValue>,
+}
+
+impl UserPlaneIpResourceInformation {
+    /// Creates the IE for an IPv4 and/or IPv6 GTP-U address without a TEID range.
+    pub fn new(ipv4: Option<Ipv4Addr>, ipv6: Option<Ipv6Addr>) -> Self {
+        Self {
+            ipv4,
+            ipv6,
+            teid_range_indication: 0,
+            teid_range: 0,
+            network_instance: None,
+            source_interface: None,
+        }
+    }
+
+    /// Restricts the TEIDs to those whose `bits` most significant bits equal `range`.
+    pub fn with_teid_range(mut self, bits: u8, range: u8) -> Self {
+        self.teid_range_indication = bits & TEIDRI_MASK;
+        self.teid_range = range;
+        self
+    }
+
+    /// Associates the resources with a network instance.
+    pub fn with_network_instance(mut self, network_instance: impl Into<Vec<u8>>) -> Self {
+        self.network_instance = Some(network_instance.into());
+        self
+    }
+
+    /// Associates the resources with a source interface.
+    pub fn with_source_interface(mut self, source_interface: SourceInterfaceValue) -> Self {
+        self.source_interface = Some(source_interface);
+        self
+    }
+
+    pub fn marshal(&self) -> Vec<u8> {
+        let teidri = self.teid_range_indication & TEIDRI_MASK;
+        let mut flags = teidri << TEIDRI_SHIFT;
+        if self.ipv4.is_some() {
+            flags |= V4;
+        }
+        if self.ipv6.is_some() {
+            flags |= V6;
+        }
+        if self.network_instance.is_some() {
+            flags |= ASSONI;
+        }
+        if self.source_interface.is_some() {
+            flags |= ASSOSI;
+        }
+        let mut data = vec![flags];
+        if teidri != 0 {
+            data.push(self.teid_range);
+        }
+        if let Some(ip) = self.ipv4 {
+            data.extend_from_slice(&ip.octets());
+        }
+        if let Some(ip) = self.ipv6 {
+            data.extend_from_slice(&ip.octets());
+        }
+        if let Some(ni) = &self.network_instance {
+            data.extend_from_slice(ni);
+        }
+        if let Some(si) = self.source_interface {
+            data.push(si as u8 & 0x0F);
+        }
+        data
+    }
+
+    pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
+        let Some(&flags) = data.first() else {
+            return Err(PfcpError::invalid_length(
+                "User Plane IP Resource Information",
+                IeType::UserPlaneIpResourceInformation,
+                1,
+                0,
+            ));
+        };
+        let teid_range_indication = (flags >> TEIDRI_SHIFT) & TEIDRI_MASK;
+
+        let mut needed = 1;
+        if teid_range_indication != 0 {
+            needed += 1;
+        }
+        if flags & V4 != 0 {
+            needed += 4;
+        }
+        if flags & V6 != 0 {
+            needed += 16;
+        }
+        if flags & ASSOSI != 0 {
+            needed += 1;
+        }
+        if data.len() < needed {
+            return Err(PfcpError::invalid_length(
+                "User Plane IP Resource Information",
+                IeType::UserPlaneIpResourceInformation,
+                needed,
+                data.len(),
+            ));
+        }
+
+        let mut offset = 1;
+        let teid_range = if teid_range_indication != 0 {
+            offset += 1;
+            data[1]
+        } else {
+            0
+        };
+        let ipv4 = if flags & V4 != 0 {
+            let octets: [u8; 4] = data[offset..offset + 4].try_into().unwrap();
+            offset += 4;
+            Some(Ipv4Addr::from(octets))
+        } else {
+            None
+        };
+        let ipv6 = if flags & V6 != 0 {
+            let octets: [u8; 16] = data[offset..offset + 16].try_into().unwrap();
+            offset += 16;
+            Some(Ipv6Addr::from(octets))
+        } else {
+            None
+        };
+
+        // The Network Instance has no length field; it runs up to the
+        // Source Interface octet, if any, or the end of the IE.
+        let mut end = data.len();
+        let source_interface = if flags & ASSOSI != 0 {
+            end -= 1;
+            Some(SourceInterfaceValue::from(data[end] & 0x0F))
+        } else {
+            None
+        };
+        let network_instance = if flags & ASSONI != 0 {
+            Some(data[offset..end].to_vec())
+        } else {
+            None
+        };
+
+        Ok(Self {
+            ipv4,
+            ipv6,
+            teid_range_indication,
+            teid_range,
+            network_instance,
+            source_interface,
+        })
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::UserPlaneIpResourceInformation, self.marshal())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marshal_unmarshal_ipv4_only() {
+        let ie = UserPlaneIpResourceInformation::new(Some(Ipv4Addr::new(10, 0, 0, 1)), None);
+        let data = ie.marshal();
+        assert_eq!(data, vec![V4, 10, 0, 0, 1]);
+        assert_eq!(
+            UserPlaneIpResourceInformation::unmarshal(&data).unwrap(),
+            ie
+        );
+    }
+
+    #[test]
+    fn test_marshal_unmarshal_all_fields() {
+        let ie = UserPlaneIpResourceInformation::new(
+            Some(Ipv4Addr::new(192, 168, 1, 1)),
+            Some(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+        )
+        .with_teid_range(3, 0x05)
+        .with_network_instance(b"internet".to_vec())
+        .with_source_interface(SourceInterfaceValue::Core);
+
+        let data = ie.marshal();
+        assert_eq!(data[0], V4 | V6 | (3 << TEIDRI_SHIFT) | ASSONI | ASSOSI);
+        assert_eq!(data[1], 0x05);
+        assert_eq!(data.len(), 1 + 1 + 4 + 16 + 8 + 1);
+        assert_eq!(
+            UserPlaneIpResourceInformation::unmarshal(&data).unwrap(),
+            ie
+        );
+    }
+
+    #[test]
+    fn test_network_instance_without_source_interface() {
+        let ie = UserPlaneIpResourceInformation::new(None, Some(Ipv6Addr::LOCALHOST))
+            .with_network_instance(b"ims".to_vec());
+        let parsed = UserPlaneIpResourceInformation::unmarshal(&ie.marshal()).unwrap();
+        assert_eq!(parsed.network_instance.as_deref(), Some(&b"ims"[..]));
+        assert_eq!(parsed.source_interface, None);
+    }
+
+    #[test]
+    fn test_unmarshal_short() {
+        assert!(matches!(
+            UserPlaneIpResourceInformation::unmarshal(&[]),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+        // V4 set but the address is truncated
+        assert!(matches!(
+            UserPlaneIpResourceInformation::unmarshal(&[V4, 10, 0]),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+        // ASSOSI set but no Source Interface octet
+        assert!(matches!(
+            UserPlaneIpResourceInformation::unmarshal(&[ASSOSI]),
+            Err(PfcpError::InvalidLength { .. })
+        ));
+    }
+
+    #[test]
+    fn test_to_ie() {
+        let ie: Ie = UserPlaneIpResourceInformation::new(Some(Ipv4Addr::LOCALHOST), None).into();
+        assert_eq!(ie.ie_type, IeType::UserPlaneIpResourceInformation);
+    }
+}
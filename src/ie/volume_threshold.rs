@@ -1,135 +1,163 @@
 // src/ie/volume_threshold.rs
 
 //! Volume Threshold Information Element.
+//!
+//! Per 3GPP TS 29.244 Section 8.2.13, the Volume Threshold IE carries the
+//! traffic volume after which the UP function reports usage. The same
+//! encoding is shared by [`SubsequentVolumeThreshold`](crate::ie::subsequent_volume_threshold::SubsequentVolumeThreshold).
 
 use crate::error::PfcpError;
-use crate::ie::IeType;
+use crate::ie::{Ie, IeType};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+const TOVOL: u8 = 0x01;
+const ULVOL: u8 = 0x02;
+const DLVOL: u8 = 0x04;
+
+/// Volume Threshold per 3GPP TS 29.244 §8.2.13.
+///
+/// The TOVOL, ULVOL and DLVOL flags are derived from which volumes are set.
+///
+/// # Wire Format
+/// - Octet 5: Spare | DLVOL | ULVOL | TOVOL
+/// - Total Volume (8 bytes, if TOVOL)
+/// - Uplink Volume (8 bytes, if ULVOL)
+/// - Downlink Volume (8 bytes, if DLVOL)
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::volume_threshold::VolumeThreshold;
+///
+/// let vt = VolumeThreshold::new(Some(1_000_000), None, Some(400_000))?;
+/// let parsed = VolumeThreshold::unmarshal(&vt.marshal())?;
+/// assert_eq!(parsed, vt);
+///
+/// assert!(VolumeThreshold::new(None, None, None).is_err());
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VolumeThreshold {
-    pub tovol: bool,
-    pub ulvol: bool,
-    pub dlvol: bool,
-    pub total_volume: Option<u64>,
-    pub uplink_volume: Option<u64>,
-    pub downlink_volume: Option<u64>,
+    pub total: Option<u64>,
+    pub uplink: Option<u64>,
+    pub downlink: Option<u64>,
 }
 
 impl VolumeThreshold {
+    /// Creates a new Volume Threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if none of the volumes is set.
     pub fn new(
-        tovol: bool,
-        ulvol: bool,
-        dlvol: bool,
-        total_volume: Option<u64>,
-        uplink_volume: Option<u64>,
-        downlink_volume: Option<u64>,
-    ) -> Self {
-        VolumeThreshold {
-            tovol,
-            ulvol,
-            dlvol,
-            total_volume,
-            uplink_volume,
-            downlink_volume,
-        }
+        total: Option<u64>,
+        uplink: Option<u64>,
+        downlink: Option<u64>,
+    ) -> Result<Self, PfcpError> {
+        validate_volumes("Volume Threshold", total, uplink, downlink)?;
+        Ok(VolumeThreshold {
+            total,
+            uplink,
+            downlink,
+        })
     }
 
     pub fn marshal(&self) -> Vec<u8> {
-        let mut data = Vec::new();
-        let mut flags = 0;
-        if self.tovol {
-            flags |= 0b1;
-        }
-        if self.ulvol {
-            flags |= 0b10;
-        }
-        if self.dlvol {
-            flags |= 0b100;
-        }
-        data.push(flags);
-        if let Some(vol) = self.total_volume {
-            data.extend_from_slice(&vol.to_be_bytes());
-        }
-        if let Some(vol) = self.uplink_volume {
-            data.extend_from_slice(&vol.to_be_bytes());
-        }
-        if let Some(vol) = self.downlink_volume {
-            data.extend_from_slice(&vol.to_be_bytes());
-        }
-        data
+        marshal_volumes(self.total, self.uplink, self.downlink)
     }
 
     pub fn unmarshal(data: &[u8]) -> Result<Self, PfcpError> {
-        if data.is_empty() {
+        let (total, uplink, downlink) =
+            unmarshal_volumes(data, "Volume Threshold", IeType::VolumeThreshold)?;
+        Ok(VolumeThreshold {
+            total,
+            uplink,
+            downlink,
+        })
+    }
+
+    pub fn to_ie(&self) -> Ie {
+        Ie::new(IeType::VolumeThreshold, self.marshal())
+    }
+}
+
+/// Rejects a threshold with no volume set, which would encode no flags.
+pub(crate) fn validate_volumes(
+    ie_name: &str,
+    total: Option<u64>,
+    uplink: Option<u64>,
+    downlink: Option<u64>,
+) -> Result<(), PfcpError> {
+    if total.is_none() && uplink.is_none() && downlink.is_none() {
+        return Err(PfcpError::invalid_value(
+            ie_name,
+            "none",
+            "at least one of total, uplink or downlink volume is required",
+        ));
+    }
+    Ok(())
+}
+
+/// Encodes the flags octet followed by each present volume.
+pub(crate) fn marshal_volumes(
+    total: Option<u64>,
+    uplink: Option<u64>,
+    downlink: Option<u64>,
+) -> Vec<u8> {
+    let mut flags = 0;
+    if total.is_some() {
+        flags |= TOVOL;
+    }
+    if uplink.is_some() {
+        flags |= ULVOL;
+    }
+    if downlink.is_some() {
+        flags |= DLVOL;
+    }
+
+    let mut data = Vec::with_capacity(1 + 8 * flags.count_ones() as usize);
+    data.push(flags);
+    for vol in [total, uplink, downlink].into_iter().flatten() {
+        data.extend_from_slice(&vol.to_be_bytes());
+    }
+    data
+}
+
+/// Total, uplink and downlink volumes, each present if its flag is set.
+pub(crate) type Volumes = (Option<u64>, Option<u64>, Option<u64>);
+
+/// Decodes the flags octet and the volumes it announces.
+pub(crate) fn unmarshal_volumes(
+    data: &[u8],
+    ie_name: &str,
+    ie_type: IeType,
+) -> Result<Volumes, PfcpError> {
+    if data.is_empty() {
+        return Err(PfcpError::invalid_length(ie_name, ie_type, 1, 0));
+    }
+    let flags = data[0];
+
+    let mut offset = 1;
+    let mut read = |flag: u8| -> Result<Option<u64>, PfcpError> {
+        if flags & flag == 0 {
+            return Ok(None);
+        }
+        if data.len() < offset + 8 {
             return Err(PfcpError::invalid_length(
-                "Volume Threshold",
-                IeType::VolumeThreshold,
-                1,
-                0,
+                ie_name,
+                ie_type,
+                offset + 8,
+                data.len(),
             ));
         }
-        let flags = data[0];
-        let tovol = (flags & 0b1) == 0b1;
-        let ulvol = (flags & 0b10) == 0b10;
-        let dlvol = (flags & 0b100) == 0b100;
-
-        let mut offset = 1;
-        let total_volume = if tovol {
-            if data.len() < offset + 8 {
-                return Err(PfcpError::invalid_length(
-                    "Volume Threshold (total volume)",
-                    IeType::VolumeThreshold,
-                    offset + 8,
-                    data.len(),
-                ));
-            }
-            let vol = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
-            offset += 8;
-            Some(vol)
-        } else {
-            None
-        };
-
-        let uplink_volume = if ulvol {
-            if data.len() < offset + 8 {
-                return Err(PfcpError::invalid_length(
-                    "Volume Threshold (uplink volume)",
-                    IeType::VolumeThreshold,
-                    offset + 8,
-                    data.len(),
-                ));
-            }
-            let vol = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
-            offset += 8;
-            Some(vol)
-        } else {
-            None
-        };
-
-        let downlink_volume = if dlvol {
-            if data.len() < offset + 8 {
-                return Err(PfcpError::invalid_length(
-                    "Volume Threshold (downlink volume)",
-                    IeType::VolumeThreshold,
-                    offset + 8,
-                    data.len(),
-                ));
-            }
-            let vol = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
-            Some(vol)
-        } else {
-            None
-        };
+        let vol = u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+        Ok(Some(vol))
+    };
 
-        Ok(VolumeThreshold {
-            tovol,
-            ulvol,
-            dlvol,
-            total_volume,
-            uplink_volume,
-            downlink_volume,
-        })
-    }
+    let total = read(TOVOL)?;
+    let uplink = read(ULVOL)?;
+    let downlink = read(DLVOL)?;
+    Ok((total, uplink, downlink))
 }
 
 #[cfg(test)]
@@ -138,16 +166,43 @@ mod tests {
 
     #[test]
     fn test_volume_threshold_marshal_unmarshal() {
-        let vt = VolumeThreshold::new(true, true, false, Some(1000), Some(500), None);
+        let vt = VolumeThreshold::new(Some(1000), Some(500), None).unwrap();
         let marshaled = vt.marshal();
+        assert_eq!(marshaled[0], TOVOL | ULVOL);
+        assert_eq!(marshaled.len(), 17);
         let unmarshaled = VolumeThreshold::unmarshal(&marshaled).unwrap();
         assert_eq!(unmarshaled, vt);
     }
 
+    #[test]
+    fn test_volume_threshold_all_flag_combinations() {
+        for flags in 1u8..=7 {
+            let total = (flags & TOVOL != 0).then_some(0x0102_0304_0506_0708);
+            let uplink = (flags & ULVOL != 0).then_some(2_000);
+            let downlink = (flags & DLVOL != 0).then_some(u64::MAX);
+            let vt = VolumeThreshold::new(total, uplink, downlink).unwrap();
+
+            let ie = vt.to_ie();
+            assert_eq!(ie.ie_type, IeType::VolumeThreshold);
+            assert_eq!(ie.payload[0], flags);
+            assert_eq!(ie.payload.len(), 1 + 8 * flags.count_ones() as usize);
+            assert_eq!(VolumeThreshold::unmarshal(&ie.payload).unwrap(), vt);
+        }
+    }
+
+    #[test]
+    fn test_volume_threshold_new_requires_volume() {
+        let result = VolumeThreshold::new(None, None, None);
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
+
     #[test]
     fn test_volume_threshold_unmarshal_invalid_data() {
-        let data = [0b1]; // Flag set, but no data
+        let data = [TOVOL]; // Flag set, but no data
         let result = VolumeThreshold::unmarshal(&data);
-        assert!(result.is_err());
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
+
+        let result = VolumeThreshold::unmarshal(&[]);
+        assert!(matches!(result, Err(PfcpError::InvalidLength { .. })));
     }
 }
@@ -109,7 +109,14 @@
 //!     .unwrap();
 //!
 //! // Forward and duplicate pattern for lawful intercept
+//! # use rs_pfcp::ie::destination_interface::DestinationInterface;
+//! # use rs_pfcp::ie::duplicating_parameters::DuplicatingParameters;
 //! let intercept_far = CreateFarBuilder::forward_and_duplicate(FarId::new(15), Interface::Core)
+//!     .duplicating_parameters(DuplicatingParameters::new(
+//!         DestinationInterface::new(Interface::Lcs),
+//!         None,
+//!         None,
+//!     ))
 //!     .build()
 //!     .unwrap();
 //! # Ok::<(), Box<dyn std::error::Error>>(())
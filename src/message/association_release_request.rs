@@ -3,6 +3,7 @@
 //! Association Release Request message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -16,7 +17,7 @@ pub struct AssociationReleaseRequest {
 impl AssociationReleaseRequest {
     pub fn new(seq: impl Into<SequenceNumber>, node_id: Ie) -> Self {
         let mut header = Header::new(MsgType::AssociationReleaseRequest, false, 0, seq);
-        header.length = node_id.len() + (header.len() - 4);
+        header.set_body_length(node_id.encoded_size());
         AssociationReleaseRequest { header, node_id }
     }
 
@@ -33,6 +34,12 @@ impl AssociationReleaseRequest {
     pub fn node_id_ie(&self) -> &Ie {
         &self.node_id
     }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+    }
 }
 
 impl Message for AssociationReleaseRequest {
@@ -47,14 +54,17 @@ impl Message for AssociationReleaseRequest {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
+        size += self.node_id.encoded_size();
         size
     }
 
@@ -68,7 +78,7 @@ impl Message for AssociationReleaseRequest {
         let mut offset = header.len() as usize;
         while offset < buf.len() {
             let ie = Ie::unmarshal(&buf[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             if ie.ie_type == IeType::NodeId {
                 node_id = Some(ie);
             }
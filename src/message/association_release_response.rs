@@ -3,6 +3,7 @@
 //! Association Release Response message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -17,7 +18,7 @@ pub struct AssociationReleaseResponse {
 impl AssociationReleaseResponse {
     pub fn new(seq: impl Into<SequenceNumber>, cause: Ie, node_id: Ie) -> Self {
         let mut header = Header::new(MsgType::AssociationReleaseResponse, false, 0, seq);
-        header.length = cause.len() + node_id.len() + (header.len() - 4);
+        header.set_body_length(cause.encoded_size() + node_id.encoded_size());
         AssociationReleaseResponse {
             header,
             cause,
@@ -48,6 +49,13 @@ impl AssociationReleaseResponse {
     pub fn cause_ie(&self) -> &Ie {
         &self.cause
     }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.cause.write_into(buf);
+        self.node_id.write_into(buf);
+    }
 }
 
 impl Message for AssociationReleaseResponse {
@@ -62,16 +70,18 @@ impl Message for AssociationReleaseResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.cause.marshal_into(buf);
-        self.node_id.marshal_into(buf);
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.cause.len() as usize;
-        size += self.node_id.len() as usize;
+        size += self.cause.encoded_size();
+        size += self.node_id.encoded_size();
         size
     }
 
@@ -86,7 +96,7 @@ impl Message for AssociationReleaseResponse {
         let mut offset = header.len() as usize;
         while offset < buf.len() {
             let ie = Ie::unmarshal(&buf[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Cause => cause = Some(ie),
                 IeType::NodeId => node_id = Some(ie),
@@ -3,6 +3,7 @@
 //! Association Setup Request message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -20,86 +21,104 @@ pub struct AssociationSetupRequest {
     pub gtpu_path_qos_control_information: Vec<Ie>, // C - Multiple - IE Type 238 (N4 only)
     pub nf_instance_id: Option<Ie>, // O - IE Type 253 - When sent by 5G UP function (N4/N4mb only)
     pub pfcpas_req_flags: Option<Ie>, // O - IE Type 259 - UUPSI flag for IPUPS support (N4 only)
-    // TODO: [IE Type 233] UE IP address Pool Information - O - Multiple instances allowed (Sxb/N4 only)
+    pub ue_ip_address_pool_information: Vec<Ie>, // O - Multiple - IE Type 233 (Sxb/N4 only)
     // TODO: [IE Type 203] Clock Drift Control Information - O - Multiple instances allowed, Grouped IE (N4 only)
-    pub ies: Vec<Ie>, // For any other IEs
+    pub ies: Vec<Ie>,       // For any other IEs
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for AssociationSetupRequest {
-    fn msg_type(&self) -> MsgType {
-        MsgType::AssociationSetupRequest
-    }
-
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl AssociationSetupRequest {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        self.recovery_time_stamp.marshal_into(buf);
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        self.recovery_time_stamp.write_into(buf);
         if let Some(ref ie) = self.up_function_features {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.cp_function_features {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.alternative_smf_ip_addresses {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.smf_set_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcp_session_retention_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.gtpu_path_qos_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.nf_instance_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcpas_req_flags {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
+        }
+        for ie in &self.ue_ip_address_pool_information {
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for AssociationSetupRequest {
+    fn msg_type(&self) -> MsgType {
+        MsgType::AssociationSetupRequest
+    }
+
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
-        size += self.recovery_time_stamp.len() as usize;
+        size += self.node_id.encoded_size();
+        size += self.recovery_time_stamp.encoded_size();
         if let Some(ref ie) = self.up_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.alternative_smf_ip_addresses {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.smf_set_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcp_session_retention_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.gtpu_path_qos_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.nf_instance_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcpas_req_flags {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        for ie in &self.ue_ip_address_pool_information {
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -119,12 +138,13 @@ impl Message for AssociationSetupRequest {
         let mut gtpu_path_qos_control_information = Vec::new();
         let mut nf_instance_id = None;
         let mut pfcpas_req_flags = None;
+        let mut ue_ip_address_pool_information = Vec::new();
         let mut ies = Vec::new();
 
         let mut offset = header.len() as usize;
         while offset < buf.len() {
             let ie = Ie::unmarshal(&buf[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::RecoveryTimeStamp => recovery_time_stamp = Some(ie),
@@ -138,12 +158,13 @@ impl Message for AssociationSetupRequest {
                 IeType::GtpuPathQosControlInformation => gtpu_path_qos_control_information.push(ie),
                 IeType::NfInstanceId => nf_instance_id = Some(ie),
                 IeType::PfcpasReqFlags => pfcpas_req_flags = Some(ie),
+                IeType::UeIpAddressPoolInformation => ue_ip_address_pool_information.push(ie),
                 _ => ies.push(ie),
             }
             offset += ie_len;
         }
 
-        Ok(AssociationSetupRequest {
+        let mut message = AssociationSetupRequest {
             header,
             node_id: node_id.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::NodeId,
@@ -165,8 +186,12 @@ impl Message for AssociationSetupRequest {
             gtpu_path_qos_control_information,
             nf_instance_id,
             pfcpas_req_flags,
+            ue_ip_address_pool_information,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -209,10 +234,17 @@ impl Message for AssociationSetupRequest {
             }
             IeType::NfInstanceId => IeIter::single(self.nf_instance_id.as_ref(), ie_type),
             IeType::PfcpasReqFlags => IeIter::single(self.pfcpas_req_flags.as_ref(), ie_type),
+            IeType::UeIpAddressPoolInformation => {
+                IeIter::multiple(&self.ue_ip_address_pool_information, ie_type)
+            }
             _ => IeIter::generic(&self.ies, ie_type),
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id, &self.recovery_time_stamp];
         if let Some(ref ie) = self.up_function_features {
@@ -235,6 +267,7 @@ impl Message for AssociationSetupRequest {
         if let Some(ref ie) = self.pfcpas_req_flags {
             result.push(ie);
         }
+        result.extend(self.ue_ip_address_pool_information.iter());
         result.extend(self.ies.iter());
         result
     }
@@ -254,39 +287,43 @@ impl AssociationSetupRequest {
         gtpu_path_qos_control_information: Vec<Ie>,
         nf_instance_id: Option<Ie>,
         pfcpas_req_flags: Option<Ie>,
+        ue_ip_address_pool_information: Vec<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = node_id.len() + recovery_time_stamp.len();
+        let mut payload_len = node_id.encoded_size() + recovery_time_stamp.encoded_size();
         if let Some(ie) = &up_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &cp_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &alternative_smf_ip_addresses {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &smf_set_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &pfcp_session_retention_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &gtpu_path_qos_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &nf_instance_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &pfcpas_req_flags {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
+        }
+        for ie in &ue_ip_address_pool_information {
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         let mut header = Header::new(MsgType::AssociationSetupRequest, false, 0, seq);
-        header.length = payload_len + (header.len() - 4);
-        AssociationSetupRequest {
+        header.set_body_length(payload_len);
+        let mut message = AssociationSetupRequest {
             header,
             node_id,
             recovery_time_stamp,
@@ -298,8 +335,12 @@ impl AssociationSetupRequest {
             gtpu_path_qos_control_information,
             nf_instance_id,
             pfcpas_req_flags,
+            ue_ip_address_pool_information,
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
     }
 }
 
@@ -317,6 +358,7 @@ pub struct AssociationSetupRequestBuilder {
     gtpu_path_qos_control_information: Vec<Ie>,
     nf_instance_id: Option<Ie>,
     pfcpas_req_flags: Option<Ie>,
+    ue_ip_address_pool_information: Vec<Ie>,
     ies: Vec<Ie>,
 }
 
@@ -335,6 +377,7 @@ impl AssociationSetupRequestBuilder {
             gtpu_path_qos_control_information: Vec::new(),
             nf_instance_id: None,
             pfcpas_req_flags: None,
+            ue_ip_address_pool_information: Vec::new(),
             ies: Vec::new(),
         }
     }
@@ -451,15 +494,21 @@ impl AssociationSetupRequestBuilder {
         self
     }
 
-    /// Adds an Alternative SMF IP Address IE (optional, multiple allowed).
-    pub fn alternative_smf_ip_address(mut self, ie: Ie) -> Self {
-        self.alternative_smf_ip_addresses.push(ie);
+    /// Adds an Alternative SMF IP Address (optional, multiple allowed), from an
+    /// IE or a typed [`AlternativeSmfIpAddress`](crate::ie::alternative_smf_ip_address::AlternativeSmfIpAddress).
+    pub fn alternative_smf_ip_address(mut self, addr: impl Into<Ie>) -> Self {
+        self.alternative_smf_ip_addresses.push(addr.into());
         self
     }
 
-    /// Adds multiple Alternative SMF IP Address IEs.
-    pub fn alternative_smf_ip_addresses(mut self, mut ies: Vec<Ie>) -> Self {
-        self.alternative_smf_ip_addresses.append(&mut ies);
+    /// Adds multiple Alternative SMF IP Addresses.
+    pub fn alternative_smf_ip_addresses<I>(mut self, addrs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Ie>,
+    {
+        self.alternative_smf_ip_addresses
+            .extend(addrs.into_iter().map(Into::into));
         self
     }
 
@@ -493,6 +542,15 @@ impl AssociationSetupRequestBuilder {
         self
     }
 
+    /// Adds a UE IP Address Pool Information IE (optional, multiple allowed).
+    pub fn ue_ip_address_pool(
+        mut self,
+        pool: crate::ie::ue_ip_address_pool_information::UeIpAddressPoolInformation,
+    ) -> Self {
+        self.ue_ip_address_pool_information.push(pool.to_ie());
+        self
+    }
+
     /// Adds an additional IE.
     pub fn ie(mut self, ie: Ie) -> Self {
         self.ies.push(ie);
@@ -529,6 +587,7 @@ impl AssociationSetupRequestBuilder {
             self.gtpu_path_qos_control_information,
             self.nf_instance_id,
             self.pfcpas_req_flags,
+            self.ue_ip_address_pool_information,
             self.ies,
         )
     }
@@ -580,6 +639,7 @@ impl AssociationSetupRequestBuilder {
             self.gtpu_path_qos_control_information,
             self.nf_instance_id,
             self.pfcpas_req_flags,
+            self.ue_ip_address_pool_information,
             self.ies,
         ))
     }
@@ -1038,7 +1098,7 @@ mod tests {
         let recovery_time = RecoveryTimeStamp::new(SystemTime::now());
         let recovery_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_time.marshal().to_vec());
 
-        header.length = recovery_ie.len() + (header.len() - 4);
+        header.set_body_length(recovery_ie.encoded_size());
         let mut buf = header.marshal();
         buf.extend_from_slice(&recovery_ie.marshal());
 
@@ -1059,7 +1119,7 @@ mod tests {
         let node_id = NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 1));
         let node_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
-        header.length = node_ie.len() + (header.len() - 4);
+        header.set_body_length(node_ie.encoded_size());
         let mut buf = header.marshal();
         buf.extend_from_slice(&node_ie.marshal());
 
@@ -1123,6 +1183,33 @@ mod tests {
         assert_eq!(unmarshaled.alternative_smf_ip_addresses.len(), 2);
     }
 
+    #[test]
+    fn test_alternative_smf_ip_address_typed() {
+        use crate::ie::alternative_smf_ip_address::AlternativeSmfIpAddress;
+        use std::net::{Ipv4Addr, Ipv6Addr};
+
+        let v4 = AlternativeSmfIpAddress::new_ipv4(Ipv4Addr::new(10, 0, 0, 9));
+        let dual = AlternativeSmfIpAddress::new_dual_stack(
+            Ipv4Addr::new(10, 0, 0, 10),
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 10),
+        );
+
+        let original = AssociationSetupRequestBuilder::new(20001)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .recovery_time_stamp(SystemTime::now())
+            .alternative_smf_ip_address(v4.clone())
+            .alternative_smf_ip_addresses([dual.clone()])
+            .build();
+
+        let unmarshaled = AssociationSetupRequest::unmarshal(&original.marshal()).unwrap();
+        let addrs: Vec<_> = unmarshaled
+            .alternative_smf_ip_addresses
+            .iter()
+            .map(|ie| AlternativeSmfIpAddress::unmarshal(&ie.payload).unwrap())
+            .collect();
+        assert_eq!(addrs, vec![v4, dual]);
+    }
+
     #[test]
     fn test_smf_set_id_roundtrip() {
         use std::net::Ipv4Addr;
@@ -1258,6 +1345,44 @@ mod tests {
         assert_eq!(request.ies(IeType::PfcpasReqFlags).next(), Some(&flags));
     }
 
+    #[test]
+    fn test_ue_ip_address_pool_information_roundtrip() {
+        use crate::ie::ip_version::IpVersion;
+        use crate::ie::network_instance::NetworkInstance;
+        use crate::ie::snssai::Snssai;
+        use crate::ie::ue_ip_address_pool_identity::UeIpAddressPoolIdentity;
+        use crate::ie::ue_ip_address_pool_information::UeIpAddressPoolInformation;
+
+        let mut v4_pool =
+            UeIpAddressPoolInformation::new(UeIpAddressPoolIdentity::new("10.0.0.0/8"));
+        v4_pool.network_instance = Some(NetworkInstance::new("internet"));
+        v4_pool.ip_version = Some(IpVersion::V4);
+
+        let mut v6_pool =
+            UeIpAddressPoolInformation::new(UeIpAddressPoolIdentity::new("fd00::/48"));
+        v6_pool.network_instance = Some(NetworkInstance::new("ims"));
+        v6_pool.snssai = Some(Snssai::with_sd_u32(1, 0x000001));
+        v6_pool.ip_version = Some(IpVersion::V6);
+
+        let original = AssociationSetupRequestBuilder::new(27000)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .recovery_time_stamp(SystemTime::now())
+            .ue_ip_address_pool(v4_pool.clone())
+            .ue_ip_address_pool(v6_pool.clone())
+            .build();
+
+        let marshaled = original.marshal();
+        let unmarshaled = AssociationSetupRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert!(unmarshaled.ies.is_empty());
+
+        let pools: Vec<UeIpAddressPoolInformation> = unmarshaled
+            .ies(IeType::UeIpAddressPoolInformation)
+            .map(|ie| UeIpAddressPoolInformation::unmarshal(&ie.payload).unwrap())
+            .collect();
+        assert_eq!(pools, vec![v4_pool, v6_pool]);
+    }
+
     #[test]
     fn test_recovery_time_stamp_to_ie_consistency() {
         // Verify the to_ie() path produces identical results to the manual IE construction
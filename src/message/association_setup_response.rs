@@ -3,6 +3,9 @@
 //! Association Setup Response message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::cp_function_features::CPFunctionFeatures;
+use crate::ie::up_function_features::UPFunctionFeatures;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -23,83 +26,95 @@ pub struct AssociationSetupResponse {
     // TODO: [IE Type 203] Clock Drift Control Information - C - Multiple instances allowed, Grouped IE (N4 only)
     // TODO: [IE Type 233] UE IP address Pool Information - O - Multiple instances allowed (Sxb/N4 only)
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for AssociationSetupResponse {
-    fn msg_type(&self) -> MsgType {
-        MsgType::AssociationSetupResponse
-    }
-
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl AssociationSetupResponse {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.cause.marshal_into(buf);
-        self.node_id.marshal_into(buf);
+        self.header.write_into(buf);
+        self.cause.write_into(buf);
+        self.node_id.write_into(buf);
         if let Some(ref ie) = self.up_function_features {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.cp_function_features {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.recovery_time_stamp {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.alternative_smf_ip_addresses {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.smf_set_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcpas_rsp_flags {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.gtpu_path_qos_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.nf_instance_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for AssociationSetupResponse {
+    fn msg_type(&self) -> MsgType {
+        MsgType::AssociationSetupResponse
+    }
+
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.cause.len() as usize;
-        size += self.node_id.len() as usize;
+        size += self.cause.encoded_size();
+        size += self.node_id.encoded_size();
         if let Some(ref ie) = self.up_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.recovery_time_stamp {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.alternative_smf_ip_addresses {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.smf_set_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcpas_rsp_flags {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.gtpu_path_qos_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.nf_instance_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -124,7 +139,7 @@ impl Message for AssociationSetupResponse {
         let mut offset = header.len() as usize;
         while offset < buf.len() {
             let ie = Ie::unmarshal(&buf[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Cause => cause = Some(ie),
                 IeType::NodeId => node_id = Some(ie),
@@ -141,7 +156,7 @@ impl Message for AssociationSetupResponse {
             offset += ie_len;
         }
 
-        Ok(AssociationSetupResponse {
+        let mut message = AssociationSetupResponse {
             header,
             cause: cause.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::Cause,
@@ -162,7 +177,10 @@ impl Message for AssociationSetupResponse {
             gtpu_path_qos_control_information,
             nf_instance_id,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -207,6 +225,10 @@ impl Message for AssociationSetupResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.cause, &self.node_id];
         if let Some(ref ie) = self.up_function_features {
@@ -250,37 +272,37 @@ impl AssociationSetupResponse {
         nf_instance_id: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = cause.len() + node_id.len();
+        let mut payload_len = cause.encoded_size() + node_id.encoded_size();
         if let Some(ie) = &up_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &cp_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &recovery_time_stamp {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &alternative_smf_ip_addresses {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &smf_set_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &pfcpas_rsp_flags {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &gtpu_path_qos_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &nf_instance_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         let mut header = Header::new(MsgType::AssociationSetupResponse, false, 0, seq);
-        header.length = payload_len + (header.len() - 4);
-        AssociationSetupResponse {
+        header.set_body_length(payload_len);
+        let mut message = AssociationSetupResponse {
             header,
             cause,
             node_id,
@@ -293,7 +315,24 @@ impl AssociationSetupResponse {
             gtpu_path_qos_control_information,
             nf_instance_id,
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    /// Returns the UP function features if present.
+    pub fn up_function_features(&self) -> Option<Result<UPFunctionFeatures, PfcpError>> {
+        self.up_function_features
+            .as_ref()
+            .map(|ie| UPFunctionFeatures::unmarshal(&ie.payload))
+    }
+
+    /// Returns the CP function features if present.
+    pub fn cp_function_features(&self) -> Option<Result<CPFunctionFeatures, PfcpError>> {
+        self.cp_function_features
+            .as_ref()
+            .map(|ie| CPFunctionFeatures::unmarshal(&ie.payload))
     }
 }
 
@@ -445,14 +484,30 @@ impl AssociationSetupResponseBuilder {
         self
     }
 
+    /// Sets the UP function features advertised by the UP function (optional).
+    ///
+    /// For a raw IE, use [`up_function_features_ie`](Self::up_function_features_ie).
+    pub fn up_function_features(mut self, features: UPFunctionFeatures) -> Self {
+        self.up_function_features = Some(features.to_ie());
+        self
+    }
+
     /// Sets the UP function features IE (optional).
-    pub fn up_function_features(mut self, up_function_features: Ie) -> Self {
+    pub fn up_function_features_ie(mut self, up_function_features: Ie) -> Self {
         self.up_function_features = Some(up_function_features);
         self
     }
 
+    /// Sets the CP function features advertised by the CP function (optional).
+    ///
+    /// For a raw IE, use [`cp_function_features_ie`](Self::cp_function_features_ie).
+    pub fn cp_function_features(mut self, features: CPFunctionFeatures) -> Self {
+        self.cp_function_features = Some(features.to_ie());
+        self
+    }
+
     /// Sets the CP function features IE (optional).
-    pub fn cp_function_features(mut self, cp_function_features: Ie) -> Self {
+    pub fn cp_function_features_ie(mut self, cp_function_features: Ie) -> Self {
         self.cp_function_features = Some(cp_function_features);
         self
     }
@@ -641,7 +696,7 @@ mod tests {
         let response = AssociationSetupResponseBuilder::new(67890)
             .cause_ie(cause_ie.clone())
             .node_id_ie(node_id_ie.clone())
-            .up_function_features(up_features_ie.clone())
+            .up_function_features_ie(up_features_ie.clone())
             .build();
 
         assert_eq!(*response.sequence(), 67890);
@@ -665,7 +720,7 @@ mod tests {
         let response = AssociationSetupResponseBuilder::new(11111)
             .cause_ie(cause_ie.clone())
             .node_id_ie(node_id_ie.clone())
-            .cp_function_features(cp_features_ie.clone())
+            .cp_function_features_ie(cp_features_ie.clone())
             .build();
 
         assert_eq!(*response.sequence(), 11111);
@@ -748,8 +803,8 @@ mod tests {
         let response = AssociationSetupResponseBuilder::new(44444)
             .cause_ie(cause_ie.clone())
             .node_id_ie(node_id_ie.clone())
-            .up_function_features(up_features_ie.clone())
-            .cp_function_features(cp_features_ie.clone())
+            .up_function_features_ie(up_features_ie.clone())
+            .cp_function_features_ie(cp_features_ie.clone())
             .recovery_time_stamp_ie(recovery_time_ie.clone())
             .ie(additional_ie.clone())
             .build();
@@ -851,7 +906,7 @@ mod tests {
         let original = AssociationSetupResponseBuilder::new(11110)
             .cause_ie(cause_ie)
             .node_id_ie(node_id_ie)
-            .up_function_features(up_features_ie)
+            .up_function_features_ie(up_features_ie)
             .build();
 
         let marshaled = original.marshal();
@@ -981,7 +1036,7 @@ mod tests {
         let response = AssociationSetupResponseBuilder::new(11000)
             .cause_accepted()
             .node_id(Ipv4Addr::new(10, 4, 4, 4))
-            .up_function_features(up_features.clone())
+            .up_function_features_ie(up_features.clone())
             .build();
 
         let found = response.ies(IeType::UpFunctionFeatures).next();
@@ -995,7 +1050,7 @@ mod tests {
         let response = AssociationSetupResponseBuilder::new(12000)
             .cause_accepted()
             .node_id(Ipv4Addr::new(10, 5, 5, 5))
-            .cp_function_features(cp_features.clone())
+            .cp_function_features_ie(cp_features.clone())
             .build();
 
         let found = response.ies(IeType::CpFunctionFeatures).next();
@@ -1112,8 +1167,8 @@ mod tests {
         let response = AssociationSetupResponseBuilder::new(20000)
             .cause_accepted()
             .node_id(Ipv4Addr::new(10, 13, 13, 13))
-            .up_function_features(up_features.clone())
-            .cp_function_features(cp_features.clone())
+            .up_function_features_ie(up_features.clone())
+            .cp_function_features_ie(cp_features.clone())
             .recovery_time_stamp(SystemTime::now())
             .ie(custom_ie1.clone())
             .ie(custom_ie2.clone())
@@ -1133,7 +1188,7 @@ mod tests {
         let node_id = NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 1));
         let node_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
-        header.length = node_ie.len() + (header.len() - 4);
+        header.set_body_length(node_ie.encoded_size());
         let mut buf = header.marshal();
         buf.extend_from_slice(&node_ie.marshal());
 
@@ -1154,7 +1209,7 @@ mod tests {
         let cause = Cause::new(CauseValue::RequestAccepted);
         let cause_ie = Ie::new(IeType::Cause, cause.marshal().to_vec());
 
-        header.length = cause_ie.len() + (header.len() - 4);
+        header.set_body_length(cause_ie.encoded_size());
         let mut buf = header.marshal();
         buf.extend_from_slice(&cause_ie.marshal());
 
@@ -1180,8 +1235,8 @@ mod tests {
         let original = AssociationSetupResponseBuilder::new(21000)
             .cause_accepted()
             .node_id(Ipv4Addr::new(192, 168, 50, 50))
-            .up_function_features(up_features)
-            .cp_function_features(cp_features)
+            .up_function_features_ie(up_features)
+            .cp_function_features_ie(cp_features)
             .recovery_time_stamp(SystemTime::now())
             .ie(custom_ie)
             .build();
@@ -1373,8 +1428,8 @@ mod tests {
         let response = AssociationSetupResponseBuilder::new(30000)
             .cause_accepted()
             .node_id(Ipv4Addr::new(10, 16, 16, 16))
-            .up_function_features(up_features.clone())
-            .cp_function_features(cp_features.clone())
+            .up_function_features_ie(up_features.clone())
+            .cp_function_features_ie(cp_features.clone())
             .recovery_time_stamp(SystemTime::now())
             .build();
 
@@ -1391,4 +1446,46 @@ mod tests {
         assert!(unmarshaled.cp_function_features.is_some());
         assert!(unmarshaled.recovery_time_stamp.is_some());
     }
+
+    #[test]
+    fn test_association_setup_response_typed_features_roundtrip() {
+        let up_features =
+            UPFunctionFeatures::BUCP | UPFunctionFeatures::FTUP | UPFunctionFeatures::UEIP;
+
+        let original = AssociationSetupResponseBuilder::new(13000)
+            .cause_accepted()
+            .node_id(Ipv4Addr::new(10, 6, 6, 6))
+            .recovery_time_stamp(SystemTime::UNIX_EPOCH)
+            .up_function_features(up_features)
+            .build();
+
+        let unmarshaled = AssociationSetupResponse::unmarshal(&original.marshal()).unwrap();
+        assert_eq!(unmarshaled, original);
+        assert_eq!(
+            unmarshaled.up_function_features().unwrap().unwrap(),
+            up_features
+        );
+        assert!(unmarshaled.cp_function_features().is_none());
+
+        let ie = unmarshaled.ies(IeType::UpFunctionFeatures).next().unwrap();
+        assert_eq!(ie.payload, vec![0x11, 0x00, 0x04]);
+    }
+
+    #[test]
+    fn test_association_setup_response_typed_cp_features() {
+        let cp_features = CPFunctionFeatures::LOAD | CPFunctionFeatures::APDP;
+
+        let original = AssociationSetupResponseBuilder::new(13001)
+            .cause_accepted()
+            .node_id(Ipv4Addr::new(10, 6, 6, 7))
+            .cp_function_features(cp_features)
+            .build();
+
+        let unmarshaled = AssociationSetupResponse::unmarshal(&original.marshal()).unwrap();
+        assert_eq!(
+            unmarshaled.cp_function_features().unwrap().unwrap(),
+            cp_features
+        );
+        assert!(unmarshaled.up_function_features().is_none());
+    }
 }
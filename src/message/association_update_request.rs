@@ -3,6 +3,8 @@
 //! Association Update Request message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::ue_ip_address_usage_information::UeIpAddressUsageInformation;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -21,8 +23,29 @@ pub struct AssociationUpdateRequest {
     // TODO: [IE Type 203] Clock Drift Control Information - C - Multiple instances, Grouped IE, null length stops reporting (N4 only)
     // TODO: [IE Type 233] UE IP address Pool Information - O - Multiple instances allowed (Sxb/N4 only)
     // TODO: [IE Type 238] GTP-U Path QoS Control Information - C - Multiple instances, Grouped IE, null length stops monitoring (N4 only)
-    // TODO: [IE Type 267] UE IP Address Usage Information - O - Multiple instances, Grouped IE with 7 child IEs, see Table 7.4.4.3.1-1 (Sxb/N4 only)
+    pub ue_ip_address_usage_information: Vec<Ie>, // O - 3GPP TS 29.244 Table 7.4.4.3-1 - IE Type 267 - Multiple instances, Grouped IE, see Table 7.4.4.3.1-1 (Sxb/N4 only)
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
+}
+
+impl AssociationUpdateRequest {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        if let Some(ref ie) = self.up_function_features {
+            ie.write_into(buf);
+        }
+        if let Some(ref ie) = self.cp_function_features {
+            ie.write_into(buf);
+        }
+        for ie in &self.ue_ip_address_usage_information {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
+    }
 }
 
 impl Message for AssociationUpdateRequest {
@@ -37,31 +60,28 @@ impl Message for AssociationUpdateRequest {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        if let Some(ref ie) = self.up_function_features {
-            ie.marshal_into(buf);
-        }
-        if let Some(ref ie) = self.cp_function_features {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
+        size += self.node_id.encoded_size();
         if let Some(ref ie) = self.up_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        for ie in &self.ue_ip_address_usage_information {
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -74,22 +94,24 @@ impl Message for AssociationUpdateRequest {
         let mut node_id = None;
         let mut up_function_features = None;
         let mut cp_function_features = None;
+        let mut ue_ip_address_usage_information = Vec::new();
         let mut ies = Vec::new();
 
         let mut offset = header.len() as usize;
         while offset < buf.len() {
             let ie = Ie::unmarshal(&buf[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::UpFunctionFeatures => up_function_features = Some(ie),
                 IeType::CpFunctionFeatures => cp_function_features = Some(ie),
+                IeType::UeIpAddressUsageInformation => ue_ip_address_usage_information.push(ie),
                 _ => ies.push(ie),
             }
             offset += ie_len;
         }
 
-        Ok(AssociationUpdateRequest {
+        let mut message = AssociationUpdateRequest {
             header,
             node_id: node_id.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::NodeId,
@@ -98,8 +120,12 @@ impl Message for AssociationUpdateRequest {
             })?,
             up_function_features,
             cp_function_features,
+            ue_ip_address_usage_information,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -129,10 +155,17 @@ impl Message for AssociationUpdateRequest {
             IeType::CpFunctionFeatures => {
                 IeIter::single(self.cp_function_features.as_ref(), ie_type)
             }
+            IeType::UeIpAddressUsageInformation => {
+                IeIter::multiple(&self.ue_ip_address_usage_information, ie_type)
+            }
             _ => IeIter::generic(&self.ies, ie_type),
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id];
         if let Some(ref ie) = self.up_function_features {
@@ -141,6 +174,7 @@ impl Message for AssociationUpdateRequest {
         if let Some(ref ie) = self.cp_function_features {
             result.push(ie);
         }
+        result.extend(self.ue_ip_address_usage_information.iter());
         result.extend(self.ies.iter());
         result
     }
@@ -148,6 +182,10 @@ impl Message for AssociationUpdateRequest {
 
 impl AssociationUpdateRequest {
     /// Creates a new AssociationUpdateRequest message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         node_id: Ie,
@@ -155,27 +193,49 @@ impl AssociationUpdateRequest {
         cp_function_features: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = node_id.len();
+        let mut payload_len = node_id.encoded_size();
         if let Some(ref ie) = up_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ref ie) = cp_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::AssociationUpdateRequest, false, 0, seq);
-        header.length = payload_len + (header.len() - 4);
+        header.set_body_length(payload_len);
 
-        AssociationUpdateRequest {
+        let mut message = AssociationUpdateRequest {
             header,
             node_id,
             up_function_features,
             cp_function_features,
+            ue_ip_address_usage_information: Vec::new(),
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    /// Returns the UE IP Address Usage Information IEs.
+    pub fn ue_ip_address_usage_information(
+        &self,
+    ) -> Result<Vec<UeIpAddressUsageInformation>, PfcpError> {
+        self.ue_ip_address_usage_information
+            .iter()
+            .map(|ie| UeIpAddressUsageInformation::unmarshal(&ie.payload))
+            .collect()
+    }
+
+    /// Sets the UE IP Address Usage Information IEs, keeping the header
+    /// length in sync.
+    fn set_ue_ip_address_usage_information(&mut self, ies: Vec<Ie>) {
+        self.ue_ip_address_usage_information = ies;
+        let ies_len = self.marshaled_size() - usize::from(self.header.len());
+        self.header.set_body_length(ies_len);
     }
 }
 
@@ -186,6 +246,7 @@ pub struct AssociationUpdateRequestBuilder {
     node_id: Option<Ie>,
     up_function_features: Option<Ie>,
     cp_function_features: Option<Ie>,
+    ue_ip_address_usage_information: Vec<Ie>,
     ies: Vec<Ie>,
 }
 
@@ -197,6 +258,7 @@ impl AssociationUpdateRequestBuilder {
             node_id: None,
             up_function_features: None,
             cp_function_features: None,
+            ue_ip_address_usage_information: Vec::new(),
             ies: Vec::new(),
         }
     }
@@ -219,6 +281,21 @@ impl AssociationUpdateRequestBuilder {
         self
     }
 
+    /// Adds a UE IP Address Usage Information (optional, may be repeated).
+    ///
+    /// For a raw IE, use
+    /// [`ue_ip_address_usage_information_ie`](Self::ue_ip_address_usage_information_ie).
+    pub fn ue_ip_address_usage_information(mut self, info: UeIpAddressUsageInformation) -> Self {
+        self.ue_ip_address_usage_information.push(info.to_ie());
+        self
+    }
+
+    /// Adds a UE IP Address Usage Information IE directly.
+    pub fn ue_ip_address_usage_information_ie(mut self, ie: Ie) -> Self {
+        self.ue_ip_address_usage_information.push(ie);
+        self
+    }
+
     /// Adds an additional IE.
     pub fn ie(mut self, ie: Ie) -> Self {
         self.ies.push(ie);
@@ -240,13 +317,15 @@ impl AssociationUpdateRequestBuilder {
             .node_id
             .expect("Node ID IE is required for AssociationUpdateRequest");
 
-        AssociationUpdateRequest::new(
+        let mut request = AssociationUpdateRequest::new(
             self.sequence,
             node_id,
             self.up_function_features,
             self.cp_function_features,
             self.ies,
-        )
+        );
+        request.set_ue_ip_address_usage_information(self.ue_ip_address_usage_information);
+        request
     }
 
     /// Tries to build the AssociationUpdateRequest message.
@@ -258,13 +337,15 @@ impl AssociationUpdateRequestBuilder {
             .node_id
             .ok_or("Node ID IE is required for AssociationUpdateRequest")?;
 
-        Ok(AssociationUpdateRequest::new(
+        let mut request = AssociationUpdateRequest::new(
             self.sequence,
             node_id,
             self.up_function_features,
             self.cp_function_features,
             self.ies,
-        ))
+        );
+        request.set_ue_ip_address_usage_information(self.ue_ip_address_usage_information);
+        Ok(request)
     }
 }
 
@@ -424,4 +505,48 @@ mod tests {
 
         assert_eq!(original, unmarshaled);
     }
+
+    #[test]
+    fn test_association_update_request_ue_ip_address_usage_information_roundtrip() {
+        use crate::ie::metric::Metric;
+        use crate::ie::number_of_ue_ip_addresses::NumberOfUeIpAddresses;
+        use crate::ie::sequence_number::SequenceNumber as IeSequenceNumber;
+        use crate::ie::ue_ip_address_pool_identity::UeIpAddressPoolIdentity;
+        use crate::ie::validity_timer::ValidityTimer;
+
+        let pool_a = UeIpAddressUsageInformation::builder(
+            IeSequenceNumber::new(1),
+            Metric::new(90).unwrap(),
+            ValidityTimer::new(60),
+        )
+        .number_of_ue_ip_addresses(NumberOfUeIpAddresses::ipv4(230))
+        .ue_ip_address_pool_identity(UeIpAddressPoolIdentity::new("pool-a"))
+        .build();
+        let pool_b = UeIpAddressUsageInformation::new(
+            IeSequenceNumber::new(2),
+            Metric::new(10).unwrap(),
+            ValidityTimer::new(60),
+        );
+
+        let node_id = NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 2));
+        let original = AssociationUpdateRequestBuilder::new(42)
+            .node_id(Ie::new(IeType::NodeId, node_id.marshal()))
+            .ue_ip_address_usage_information(pool_a.clone())
+            .ue_ip_address_usage_information(pool_b.clone())
+            .build();
+
+        let marshaled = original.marshal();
+        assert_eq!(marshaled.len(), original.marshaled_size());
+        let unmarshaled = AssociationUpdateRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert!(unmarshaled.ies.is_empty());
+        assert_eq!(
+            unmarshaled.ue_ip_address_usage_information().unwrap(),
+            vec![pool_a, pool_b]
+        );
+        assert_eq!(
+            unmarshaled.ies(IeType::UeIpAddressUsageInformation).count(),
+            2
+        );
+    }
 }
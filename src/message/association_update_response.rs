@@ -3,6 +3,9 @@
 //! Association Update Response message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::cp_function_features::CPFunctionFeatures;
+use crate::ie::up_function_features::UPFunctionFeatures;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -16,10 +19,15 @@ pub struct AssociationUpdateResponse {
     pub cp_function_features: Option<Ie>, // O - 3GPP TS 29.244 Table 7.4.4.4-1 - IE Type 89
     // TODO: [IE Type 267] UE IP Address Usage Information - O - Multiple instances, Grouped IE with 7 child IEs, see Table 7.4.4.3.1-1 (Sxb/N4 only)
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl AssociationUpdateResponse {
     /// Creates a new Association Update Response message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         node_id: Ie,
@@ -28,27 +36,60 @@ impl AssociationUpdateResponse {
         cp_function_features: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = node_id.len() + cause.len();
+        let mut payload_len = node_id.encoded_size() + cause.encoded_size();
         if let Some(ref ie) = up_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ref ie) = cp_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::AssociationUpdateResponse, false, 0, seq);
-        header.length = payload_len + (header.len() - 4);
+        header.set_body_length(payload_len);
 
-        AssociationUpdateResponse {
+        let mut message = AssociationUpdateResponse {
             header,
             node_id,
             cause,
             up_function_features,
             cp_function_features,
             ies,
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    /// Returns the UP function features if present.
+    pub fn up_function_features(&self) -> Option<Result<UPFunctionFeatures, PfcpError>> {
+        self.up_function_features
+            .as_ref()
+            .map(|ie| UPFunctionFeatures::unmarshal(&ie.payload))
+    }
+
+    /// Returns the CP function features if present.
+    pub fn cp_function_features(&self) -> Option<Result<CPFunctionFeatures, PfcpError>> {
+        self.cp_function_features
+            .as_ref()
+            .map(|ie| CPFunctionFeatures::unmarshal(&ie.payload))
+    }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        self.cause.write_into(buf);
+        if let Some(ref ie) = self.up_function_features {
+            ie.write_into(buf);
+        }
+        if let Some(ref ie) = self.cp_function_features {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
         }
     }
 }
@@ -65,33 +106,26 @@ impl Message for AssociationUpdateResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        self.cause.marshal_into(buf);
-        if let Some(ref ie) = self.up_function_features {
-            ie.marshal_into(buf);
-        }
-        if let Some(ref ie) = self.cp_function_features {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.node_id.encoded_size();
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.up_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -110,7 +144,7 @@ impl Message for AssociationUpdateResponse {
         let mut cursor = header.len() as usize;
         while cursor < buf.len() {
             let ie = Ie::unmarshal(&buf[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::Cause => cause = Some(ie),
@@ -132,14 +166,17 @@ impl Message for AssociationUpdateResponse {
             parent_ie: None,
         })?;
 
-        Ok(AssociationUpdateResponse {
+        let mut message = AssociationUpdateResponse {
             header,
             node_id,
             cause,
             up_function_features,
             cp_function_features,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -174,6 +211,10 @@ impl Message for AssociationUpdateResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id, &self.cause];
         if let Some(ref ie) = self.up_function_features {
@@ -381,14 +422,30 @@ impl AssociationUpdateResponseBuilder {
         self
     }
 
+    /// Sets the UP function features advertised by the UP function (optional).
+    ///
+    /// For a raw IE, use [`up_function_features_ie`](Self::up_function_features_ie).
+    pub fn up_function_features(mut self, features: UPFunctionFeatures) -> Self {
+        self.up_function_features = Some(features.to_ie());
+        self
+    }
+
     /// Sets the UP function features IE (optional).
-    pub fn up_function_features(mut self, up_function_features: Ie) -> Self {
+    pub fn up_function_features_ie(mut self, up_function_features: Ie) -> Self {
         self.up_function_features = Some(up_function_features);
         self
     }
 
+    /// Sets the CP function features advertised by the CP function (optional).
+    ///
+    /// For a raw IE, use [`cp_function_features_ie`](Self::cp_function_features_ie).
+    pub fn cp_function_features(mut self, features: CPFunctionFeatures) -> Self {
+        self.cp_function_features = Some(features.to_ie());
+        self
+    }
+
     /// Sets the CP function features IE (optional).
-    pub fn cp_function_features(mut self, cp_function_features: Ie) -> Self {
+    pub fn cp_function_features_ie(mut self, cp_function_features: Ie) -> Self {
         self.cp_function_features = Some(cp_function_features);
         self
     }
@@ -513,7 +570,7 @@ mod builder_tests {
         let response = AssociationUpdateResponseBuilder::new(67890)
             .node_id(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
-            .up_function_features(up_features_ie.clone())
+            .up_function_features_ie(up_features_ie.clone())
             .build();
 
         assert_eq!(*response.sequence(), 67890);
@@ -536,7 +593,7 @@ mod builder_tests {
         let response = AssociationUpdateResponseBuilder::new(11111)
             .node_id(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
-            .cp_function_features(cp_features_ie.clone())
+            .cp_function_features_ie(cp_features_ie.clone())
             .build();
 
         assert_eq!(*response.sequence(), 11111);
@@ -589,8 +646,8 @@ mod builder_tests {
         let response = AssociationUpdateResponseBuilder::new(33333)
             .node_id(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
-            .up_function_features(up_features_ie.clone())
-            .cp_function_features(cp_features_ie.clone())
+            .up_function_features_ie(up_features_ie.clone())
+            .cp_function_features_ie(cp_features_ie.clone())
             .ie(additional_ie.clone())
             .build();
 
@@ -690,7 +747,7 @@ mod builder_tests {
         let original = AssociationUpdateResponseBuilder::new(99999)
             .node_id(node_id_ie)
             .cause_ie(cause_ie)
-            .up_function_features(up_features_ie)
+            .up_function_features_ie(up_features_ie)
             .build();
 
         let marshaled = original.marshal();
@@ -698,4 +755,29 @@ mod builder_tests {
 
         assert_eq!(original, unmarshaled);
     }
+
+    #[test]
+    fn test_association_update_response_typed_features_roundtrip() {
+        let node_id = NodeId::new_ipv4(Ipv4Addr::new(172, 16, 100, 2));
+        let up_features = UPFunctionFeatures::FTUP | UPFunctionFeatures::PFDM;
+        let cp_features = CPFunctionFeatures::LOAD | CPFunctionFeatures::OVRL;
+
+        let original = AssociationUpdateResponseBuilder::new(99998)
+            .node_id(node_id.to_ie())
+            .cause_accepted()
+            .up_function_features(up_features)
+            .cp_function_features(cp_features)
+            .build();
+
+        let unmarshaled = AssociationUpdateResponse::unmarshal(&original.marshal()).unwrap();
+        assert_eq!(unmarshaled, original);
+        assert_eq!(
+            unmarshaled.up_function_features().unwrap().unwrap(),
+            up_features
+        );
+        assert_eq!(
+            unmarshaled.cp_function_features().unwrap().unwrap(),
+            cp_features
+        );
+    }
 }
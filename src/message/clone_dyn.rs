@@ -0,0 +1,114 @@
+//! Cloning of boxed messages.
+//!
+//! `Clone` is not object safe, so a `Box<dyn Message>` returned by
+//! [`parse`](crate::message::parse) cannot be cloned directly. [`DynClone`]
+//! is a supertrait of [`Message`] implemented for every `Clone` message type,
+//! which makes [`clone_box`](DynClone::clone_box) available on trait objects
+//! and lets `Box<dyn Message>` implement `Clone`.
+
+use crate::message::Message;
+
+/// Clones a message into a new `Box<dyn Message>`.
+///
+/// Implemented automatically for every message type that is `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::message::{heartbeat_request::HeartbeatRequestBuilder, parse, Message};
+/// use std::time::SystemTime;
+///
+/// let bytes = HeartbeatRequestBuilder::new(7)
+///     .recovery_time_stamp(SystemTime::now())
+///     .marshal();
+/// let parsed = parse(&bytes)?;
+///
+/// let cloned = parsed.clone_box();
+/// assert_eq!(cloned.marshal(), parsed.marshal());
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+pub trait DynClone {
+    /// Returns a boxed copy of this message.
+    fn clone_box(&self) -> Box<dyn Message>;
+}
+
+impl<T> DynClone for T
+where
+    T: Message + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn Message> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Message> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::comparison::MessageComparator;
+    use crate::ie::cause::CauseValue;
+    use crate::message::session_deletion_response::SessionDeletionResponseBuilder;
+    use crate::message::session_establishment_request::SessionEstablishmentRequestBuilder;
+    use crate::message::{heartbeat_request::HeartbeatRequestBuilder, parse, Message, MsgType};
+    use std::net::Ipv4Addr;
+    use std::time::SystemTime;
+
+    fn assert_clone_matches(bytes: &[u8]) {
+        let parsed = parse(bytes).unwrap();
+        let cloned = parsed.clone_box();
+
+        assert_eq!(cloned.msg_type(), parsed.msg_type());
+        assert_eq!(cloned.marshal(), bytes);
+        let result = MessageComparator::new(parsed.as_ref(), cloned.as_ref())
+            .strict_mode()
+            .compare()
+            .unwrap();
+        assert!(result.is_match());
+    }
+
+    #[test]
+    fn test_clone_box_heartbeat() {
+        let bytes = HeartbeatRequestBuilder::new(42)
+            .recovery_time_stamp(SystemTime::now())
+            .marshal();
+        assert_clone_matches(&bytes);
+    }
+
+    #[test]
+    fn test_clone_box_session_messages() {
+        use crate::ie::{Ie, IeType};
+
+        let pdr = Ie::new(IeType::CreatePdr, vec![0x00, 0x38, 0x00, 0x02, 0x00, 0x01]);
+        let far = Ie::new(IeType::CreateFar, vec![0x00, 0x6C, 0x00, 0x04, 0, 0, 0, 1]);
+        let bytes = SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(vec![pdr])
+            .create_fars(vec![far])
+            .build()
+            .unwrap()
+            .marshal();
+        assert_clone_matches(&bytes);
+
+        let bytes = SessionDeletionResponseBuilder::new(0x1234, 2)
+            .cause(CauseValue::RequestAccepted)
+            .marshal();
+        assert_clone_matches(&bytes);
+    }
+
+    #[test]
+    fn test_box_dyn_message_clone() {
+        let bytes = HeartbeatRequestBuilder::new(9)
+            .recovery_time_stamp(SystemTime::now())
+            .marshal();
+        let queue: Vec<Box<dyn Message>> = vec![parse(&bytes).unwrap()];
+
+        let retransmit = queue.clone();
+        assert_eq!(retransmit[0].msg_type(), MsgType::HeartbeatRequest);
+        assert_eq!(retransmit[0].marshal(), queue[0].marshal());
+    }
+}
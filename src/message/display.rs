@@ -3,6 +3,8 @@
 //! Converts PFCP messages to YAML and JSON formats for debugging and logging.
 //! IE order in the output matches the binary message (wire format).
 
+#![deny(deprecated)]
+
 use crate::ie::{Ie, IeType};
 use crate::message::Message;
 use serde_json::{json, Map, Value};
@@ -21,6 +23,22 @@ pub trait MessageDisplay {
 
     /// Converts the message to pretty-printed JSON format.
     fn to_json_pretty(&self) -> Result<String, serde_json::Error>;
+
+    /// Converts the message to YAML format with the given display options.
+    fn to_yaml_with_options(
+        &self,
+        options: &DisplayOptions,
+    ) -> Result<String, serde_yaml_ng::Error>;
+
+    /// Converts the message to compact JSON format with the given display options.
+    fn to_json_with_options(&self, options: &DisplayOptions) -> Result<String, serde_json::Error>;
+
+    /// Converts the message to a single-line summary for logging.
+    ///
+    /// Includes the message type, sequence number, SEID (if present),
+    /// recovery timestamp (if present) and counts of major grouped IEs, e.g.
+    /// `SessionEstablishmentRequest seq=12345 seid=0x1234567890abcdef pdrs=2 fars=2 qers=1`.
+    fn to_summary(&self) -> String;
 }
 
 impl<T: Message> MessageDisplay for T {
@@ -35,6 +53,21 @@ impl<T: Message> MessageDisplay for T {
     fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(&message_to_value(self))
     }
+
+    fn to_yaml_with_options(
+        &self,
+        options: &DisplayOptions,
+    ) -> Result<String, serde_yaml_ng::Error> {
+        serde_yaml_ng::to_string(&message_to_value_with_options(self, options))
+    }
+
+    fn to_json_with_options(&self, options: &DisplayOptions) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&message_to_value_with_options(self, options))
+    }
+
+    fn to_summary(&self) -> String {
+        message_summary(self)
+    }
 }
 
 impl MessageDisplay for Box<dyn Message> {
@@ -49,6 +82,72 @@ impl MessageDisplay for Box<dyn Message> {
     fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(&message_to_value(self.as_ref()))
     }
+
+    fn to_yaml_with_options(
+        &self,
+        options: &DisplayOptions,
+    ) -> Result<String, serde_yaml_ng::Error> {
+        serde_yaml_ng::to_string(&message_to_value_with_options(self.as_ref(), options))
+    }
+
+    fn to_json_with_options(&self, options: &DisplayOptions) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&message_to_value_with_options(self.as_ref(), options))
+    }
+
+    fn to_summary(&self) -> String {
+        message_summary(self.as_ref())
+    }
+}
+
+/// How much detail the YAML/JSON output includes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only IE type names and how often each occurs.
+    Brief,
+    /// Structured IE data, without raw hex for IEs that have no rich display.
+    Normal,
+    /// Structured IE data plus a hex dump of IEs that have no rich display.
+    Verbose,
+}
+
+/// Options for [`MessageDisplay::to_yaml_with_options`] and
+/// [`MessageDisplay::to_json_with_options`].
+///
+/// The default matches the output of [`MessageDisplay::to_yaml`]: verbose,
+/// with hex dumps of up to 32 payload bytes.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::message::display::{DisplayOptions, MessageDisplay, Verbosity};
+/// use rs_pfcp::message::heartbeat_request::HeartbeatRequestBuilder;
+/// use std::time::SystemTime;
+///
+/// let msg = HeartbeatRequestBuilder::new(1)
+///     .recovery_time_stamp(SystemTime::now())
+///     .build();
+/// let options = DisplayOptions {
+///     verbosity: Verbosity::Brief,
+///     ..Default::default()
+/// };
+/// let yaml = msg.to_yaml_with_options(&options)?;
+/// assert!(yaml.contains("RecoveryTimeStamp: 1"));
+/// # Ok::<(), serde_yaml_ng::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    pub verbosity: Verbosity,
+    /// Payloads longer than this are shown as `payload_size` instead of `payload_hex`.
+    pub max_payload_hex_bytes: usize,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        DisplayOptions {
+            verbosity: Verbosity::Verbose,
+            max_payload_hex_bytes: 32,
+        }
+    }
 }
 
 // ============================================================================
@@ -58,6 +157,11 @@ impl MessageDisplay for Box<dyn Message> {
 /// Convert any message to a JSON value. Single source of truth.
 /// IE order matches the binary message (wire format).
 fn message_to_value(msg: &dyn Message) -> Value {
+    message_to_value_with_options(msg, &DisplayOptions::default())
+}
+
+/// Convert any message to a JSON value at the requested verbosity.
+fn message_to_value_with_options(msg: &dyn Message, options: &DisplayOptions) -> Value {
     let mut map = Map::new();
 
     map.insert("message_type".into(), json!(msg.msg_name()));
@@ -71,13 +175,130 @@ fn message_to_value(msg: &dyn Message) -> Value {
 
     let all = msg.all_ies();
     if !all.is_empty() {
-        let ie_array: Vec<Value> = all.iter().map(|ie| ie_to_value(ie)).collect();
-        map.insert("information_elements".into(), Value::Array(ie_array));
+        let ies = match options.verbosity {
+            Verbosity::Brief => ie_counts_to_value(&all),
+            Verbosity::Normal | Verbosity::Verbose => Value::Array(
+                all.iter()
+                    .map(|ie| ie_to_value_with_options(ie, options))
+                    .collect(),
+            ),
+        };
+        map.insert("information_elements".into(), ies);
     }
 
     Value::Object(map)
 }
 
+/// Grouped IEs counted in the summary line, with their labels.
+const SUMMARY_COUNTED_IES: &[(IeType, &str)] = &[
+    (IeType::CreatePdr, "pdrs"),
+    (IeType::CreateFar, "fars"),
+    (IeType::CreateUrr, "urrs"),
+    (IeType::CreateQer, "qers"),
+    (IeType::CreateBar, "bars"),
+    (IeType::CreatedPdr, "created_pdrs"),
+    (IeType::UpdatePdr, "update_pdrs"),
+    (IeType::UpdateFar, "update_fars"),
+    (IeType::UpdateUrr, "update_urrs"),
+    (IeType::UpdateQer, "update_qers"),
+    (IeType::RemovePdr, "remove_pdrs"),
+    (IeType::RemoveFar, "remove_fars"),
+    (IeType::RemoveUrr, "remove_urrs"),
+    (IeType::RemoveQer, "remove_qers"),
+    (
+        IeType::UsageReportWithinSessionModificationResponse,
+        "usage_reports",
+    ),
+    (
+        IeType::UsageReportWithinSessionDeletionResponse,
+        "usage_reports",
+    ),
+    (
+        IeType::UsageReportWithinSessionReportRequest,
+        "usage_reports",
+    ),
+];
+
+/// Build the single-line summary used by [`MessageDisplay::to_summary`].
+fn message_summary(msg: &dyn Message) -> String {
+    let mut summary = format!("{} seq={}", msg.msg_name(), *msg.sequence());
+
+    if let Some(seid) = msg.seid() {
+        summary.push_str(&format!(" seid=0x{:016x}", seid));
+    }
+
+    if let Some(ts) = msg.ies(IeType::RecoveryTimeStamp).next().and_then(|ie| {
+        crate::ie::recovery_time_stamp::RecoveryTimeStamp::unmarshal(&ie.payload).ok()
+    }) {
+        if let Ok(duration) = ts.timestamp.duration_since(std::time::UNIX_EPOCH) {
+            summary.push_str(&format!(" ts={}", format_utc_timestamp(duration.as_secs())));
+        }
+    }
+
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for (ie_type, label) in SUMMARY_COUNTED_IES {
+        let count = msg.ies(*ie_type).count();
+        if count == 0 {
+            continue;
+        }
+        match counts.iter_mut().find(|(l, _)| l == label) {
+            Some((_, c)) => *c += count,
+            None => counts.push((label, count)),
+        }
+    }
+    for (label, count) in counts {
+        summary.push_str(&format!(" {}={}", label, count));
+    }
+
+    summary
+}
+
+/// Format seconds since the Unix epoch as `YYYY-MM-DDTHH:MM:SSZ`.
+fn format_utc_timestamp(secs: u64) -> String {
+    let dt = UtcDateTime::from_unix_secs(secs);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        dt.year, dt.month, dt.day, dt.hour, dt.minute, dt.second
+    )
+}
+
+/// Broken-down UTC date and time for a Unix timestamp.
+struct UtcDateTime {
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: u64,
+    minute: u64,
+    second: u64,
+}
+
+impl UtcDateTime {
+    fn from_unix_secs(secs: u64) -> Self {
+        let days = (secs / 86400) as i64;
+        let rem = secs % 86400;
+
+        // Civil-from-days conversion (proleptic Gregorian calendar)
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        UtcDateTime {
+            year,
+            month,
+            day,
+            hour: rem / 3600,
+            minute: (rem % 3600) / 60,
+            second: rem % 60,
+        }
+    }
+}
+
 // ============================================================================
 // Layer 1: IE → Value
 // ============================================================================
@@ -89,8 +310,25 @@ enum IeDisplayResult {
     Detailed(Map<String, Value>),
 }
 
-/// Convert a single IE to a JSON value.
+/// Count IEs by type name, in order of first appearance.
+fn ie_counts_to_value(ies: &[&Ie]) -> Value {
+    let mut counts = Map::new();
+    for ie in ies {
+        let name = format!("{:?}", ie.ie_type);
+        let count = counts.get(&name).and_then(Value::as_u64).unwrap_or(0);
+        counts.insert(name, json!(count + 1));
+    }
+    Value::Object(counts)
+}
+
+/// Convert a single IE to a JSON value with default options.
+#[cfg(test)]
 fn ie_to_value(ie: &Ie) -> Value {
+    ie_to_value_with_options(ie, &DisplayOptions::default())
+}
+
+/// Convert a single IE to a JSON value, honouring the payload options.
+fn ie_to_value_with_options(ie: &Ie, options: &DisplayOptions) -> Value {
     let type_name = format!("{:?}", ie.ie_type);
 
     match rich_display(ie) {
@@ -108,8 +346,10 @@ fn ie_to_value(ie: &Ie) -> Value {
         None => {
             let mut obj = Map::new();
             obj.insert("type".into(), json!(type_name));
-            obj.insert("length".into(), json!(ie.len()));
-            add_fallback_payload(&mut obj, &ie.payload);
+            obj.insert("length".into(), json!(ie.encoded_size()));
+            if options.verbosity == Verbosity::Verbose {
+                add_fallback_payload(&mut obj, &ie.payload, options.max_payload_hex_bytes);
+            }
             Value::Object(obj)
         }
     }
@@ -138,21 +378,25 @@ fn rich_display(ie: &Ie) -> Option<IeDisplayResult> {
         IeType::CreatePdr => display_create_pdr(&ie.payload),
         IeType::CreatedPdr => display_created_pdr(&ie.payload),
         IeType::CreateFar => display_create_far(&ie.payload),
+        IeType::UpdatePdr => display_update_pdr(&ie.payload),
+        IeType::UpdateFar => display_update_far(&ie.payload),
         IeType::UsageReportWithinSessionReportRequest => display_usage_report(&ie.payload),
         IeType::SourceIpAddress => display_source_ip_address(&ie.payload),
-        IeType::Snssai => display_snssai(&ie.payload),
+        IeType::Snssai | IeType::HplmnSNssai => display_snssai(&ie.payload),
+        IeType::PacketRateStatus => display_packet_rate_status(&ie.payload),
         IeType::UserId => display_user_id(&ie.payload),
         IeType::AlternativeSmfIpAddress => display_alternative_smf_ip_address(&ie.payload),
         IeType::FqCsid => display_fq_csid(&ie.payload),
         IeType::EthernetPduSessionInformation => display_ethernet_pdu_info(&ie.payload),
         IeType::EthernetContextInformation => display_ethernet_context(&ie.payload),
+        IeType::MacAddress => display_mac_address(&ie.payload),
         _ => None,
     }
 }
 
 /// Fallback: hex dump for small payloads, size for large ones.
-fn add_fallback_payload(obj: &mut Map<String, Value>, payload: &[u8]) {
-    if payload.len() <= 32 {
+fn add_fallback_payload(obj: &mut Map<String, Value>, payload: &[u8], max_hex_bytes: usize) {
+    if payload.len() <= max_hex_bytes {
         let hex = payload
             .iter()
             .map(|b| format!("{b:02x}"))
@@ -206,42 +450,12 @@ fn display_recovery_timestamp(payload: &[u8]) -> Option<IeDisplayResult> {
     let mut map = Map::new();
     map.insert("timestamp_seconds".into(), json!(secs));
 
-    // Date calculation
-    let days_since_epoch = secs / 86400;
-    let remaining_secs = secs % 86400;
-    let hours = remaining_secs / 3600;
-    let minutes = (remaining_secs % 3600) / 60;
-    let seconds = remaining_secs % 60;
-
-    let mut year = 1970u64;
-    let mut remaining_days = days_since_epoch;
-    while remaining_days >= 365 {
-        let days_in_year =
-            if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) {
-                366
-            } else {
-                365
-            };
-        if remaining_days >= days_in_year {
-            remaining_days -= days_in_year;
-            year += 1;
-        } else {
-            break;
-        }
-    }
-    let month = (remaining_days / 30) + 1;
-    let day = (remaining_days % 30) + 1;
-
+    let dt = UtcDateTime::from_unix_secs(secs);
     map.insert(
         "timestamp_readable".into(),
         json!(format!(
             "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
-            year,
-            month.min(12),
-            day.min(31),
-            hours,
-            minutes,
-            seconds
+            dt.year, dt.month, dt.day, dt.hour, dt.minute, dt.second
         )),
     );
     map.insert(
@@ -252,17 +466,36 @@ fn display_recovery_timestamp(payload: &[u8]) -> Option<IeDisplayResult> {
 }
 
 fn display_report_type(payload: &[u8]) -> Option<IeDisplayResult> {
-    if payload.is_empty() {
-        return None;
-    }
-    let report_name = match payload[0] {
-        0x01 => "DLDR (Downlink Data Report)",
-        0x02 => "USAR (Usage Report)",
-        0x04 => "ERIR (Error Indication Report)",
-        0x08 => "UPIR (User Plane Inactivity Report)",
-        _ => "Unknown",
-    };
-    Some(IeDisplayResult::Compact(json!(report_name)))
+    use crate::ie::report_type::ReportType;
+    let report_type = ReportType::unmarshal(payload).ok()?;
+    let names: Vec<&str> = [
+        (ReportType::DLDR, "DLDR (Downlink Data Report)"),
+        (ReportType::USAR, "USAR (Usage Report)"),
+        (ReportType::ERIR, "ERIR (Error Indication Report)"),
+        (ReportType::UPIR, "UPIR (User Plane Inactivity Report)"),
+        (
+            ReportType::PMIR,
+            "PMIR (Packet Measurement Indication Report)",
+        ),
+        (
+            ReportType::SESR,
+            "SESR (Session Enhancement Success Report)",
+        ),
+        (
+            ReportType::TMIR,
+            "TMIR (Traffic Measurement Indication Report)",
+        ),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| report_type.contains(*flag))
+    .map(|(_, name)| name)
+    .collect();
+
+    match names.as_slice() {
+        [] => Some(IeDisplayResult::Compact(json!("None"))),
+        [name] => Some(IeDisplayResult::Compact(json!(name))),
+        _ => Some(IeDisplayResult::Compact(json!(names))),
+    }
 }
 
 fn display_usage_report(payload: &[u8]) -> Option<IeDisplayResult> {
@@ -321,15 +554,22 @@ fn display_create_pdr(payload: &[u8]) -> Option<IeDisplayResult> {
     let mut map = Map::new();
     map.insert("pdr_id".into(), json!(pdr.pdr_id.value));
     map.insert("precedence".into(), json!(pdr.precedence.value));
+    map.insert("pdi".into(), pdi_to_value(&pdr.pdi));
 
-    // PDI
-    let mut pdi = Map::new();
-    pdi.insert(
+    if let Some(ref far_id) = pdr.far_id {
+        map.insert("far_id".into(), json!(far_id.value));
+    }
+    Some(IeDisplayResult::Detailed(map))
+}
+
+fn pdi_to_value(pdi: &crate::ie::pdi::Pdi) -> Value {
+    let mut m = Map::new();
+    m.insert(
         "source_interface".into(),
-        json!(format!("{:?}", pdr.pdi.source_interface.value)),
+        json!(format!("{:?}", pdi.source_interface.value)),
     );
 
-    if let Some(ref fteid) = pdr.pdi.f_teid {
+    if let Some(ref fteid) = pdi.f_teid {
         let mut fteid_map = Map::new();
         fteid_map.insert("teid".into(), json!(format!("0x{:08x}", fteid.teid)));
         if let Some(ipv4) = fteid.ipv4_address {
@@ -338,10 +578,10 @@ fn display_create_pdr(payload: &[u8]) -> Option<IeDisplayResult> {
         if let Some(ipv6) = fteid.ipv6_address {
             fteid_map.insert("ipv6".into(), json!(ipv6.to_string()));
         }
-        pdi.insert("f_teid".into(), Value::Object(fteid_map));
+        m.insert("f_teid".into(), Value::Object(fteid_map));
     }
 
-    if let Some(ref ue_ip) = pdr.pdi.ue_ip_address {
+    if let Some(ref ue_ip) = pdi.ue_ip_address {
         let mut ue_ip_map = Map::new();
         if let Some(ipv4) = ue_ip.ipv4_address {
             ue_ip_map.insert("ipv4".into(), json!(ipv4.to_string()));
@@ -349,22 +589,22 @@ fn display_create_pdr(payload: &[u8]) -> Option<IeDisplayResult> {
         if let Some(ipv6) = ue_ip.ipv6_address {
             ue_ip_map.insert("ipv6".into(), json!(ipv6.to_string()));
         }
-        pdi.insert("ue_ip_address".into(), Value::Object(ue_ip_map));
+        m.insert("ue_ip_address".into(), Value::Object(ue_ip_map));
     }
 
-    if let Some(ref ni) = pdr.pdi.network_instance {
-        pdi.insert("network_instance".into(), json!(&ni.instance));
+    if let Some(ref ni) = pdi.network_instance {
+        m.insert("network_instance".into(), json!(&ni.instance));
     }
 
-    if let Some(ref sdf) = pdr.pdi.sdf_filter {
-        pdi.insert("sdf_filter".into(), json!(format!("{sdf:?}")));
+    if let Some(ref sdf) = pdi.sdf_filter {
+        m.insert("sdf_filter".into(), sdf_filter_to_value(sdf));
     }
 
-    if let Some(ref app_id) = pdr.pdi.application_id {
-        pdi.insert("application_id".into(), json!(app_id));
+    if let Some(ref app_id) = pdi.application_id {
+        m.insert("application_id".into(), json!(app_id));
     }
 
-    if let Some(ref eth_filter) = pdr.pdi.ethernet_packet_filter {
+    if let Some(ref eth_filter) = pdi.ethernet_packet_filter {
         let mut ef = Map::new();
         ef.insert(
             "filter_id".into(),
@@ -398,30 +638,45 @@ fn display_create_pdr(payload: &[u8]) -> Option<IeDisplayResult> {
             ef.insert("s_tag".into(), s_tag_to_value(s_tag));
         }
 
-        pdi.insert("ethernet_packet_filter".into(), Value::Object(ef));
+        m.insert("ethernet_packet_filter".into(), Value::Object(ef));
     }
 
-    map.insert("pdi".into(), Value::Object(pdi));
+    Value::Object(m)
+}
 
-    if let Some(ref far_id) = pdr.far_id {
-        map.insert("far_id".into(), json!(far_id.value));
-    }
-    Some(IeDisplayResult::Detailed(map))
+fn sdf_filter_to_value(sdf: &crate::ie::sdf_filter::SdfFilter) -> Value {
+    // Only the flow description (FD) is modeled; TTC, SPI and FL have no
+    // fields on `SdfFilter` yet and are therefore omitted.
+    let mut m = Map::new();
+    m.insert("flow_description".into(), json!(&sdf.flow_description));
+    Value::Object(m)
 }
 
 fn c_tag_to_value(tag: &crate::ie::c_tag::CTag) -> Value {
     let mut m = Map::new();
-    m.insert("pcp".into(), json!(tag.priority()));
-    m.insert("dei".into(), json!(tag.dei()));
-    m.insert("vid".into(), json!(tag.vid()));
+    if let Some(pcp) = tag.priority() {
+        m.insert("pcp".into(), json!(pcp));
+    }
+    if let Some(dei) = tag.dei() {
+        m.insert("dei".into(), json!(dei));
+    }
+    if let Some(vid) = tag.vid() {
+        m.insert("vid".into(), json!(vid));
+    }
     Value::Object(m)
 }
 
 fn s_tag_to_value(tag: &crate::ie::s_tag::STag) -> Value {
     let mut m = Map::new();
-    m.insert("pcp".into(), json!(tag.priority()));
-    m.insert("dei".into(), json!(tag.dei()));
-    m.insert("vid".into(), json!(tag.vid()));
+    if let Some(pcp) = tag.priority() {
+        m.insert("pcp".into(), json!(pcp));
+    }
+    if let Some(dei) = tag.dei() {
+        m.insert("dei".into(), json!(dei));
+    }
+    if let Some(vid) = tag.vid() {
+        m.insert("vid".into(), json!(vid));
+    }
     Value::Object(m)
 }
 
@@ -464,8 +719,95 @@ fn display_create_far(payload: &[u8]) -> Option<IeDisplayResult> {
     let mut map = Map::new();
     map.insert("far_id".into(), json!(far.far_id.value));
 
-    let mut actions = Vec::new();
+    map.insert(
+        "apply_action".into(),
+        apply_action_to_value(&far.apply_action),
+    );
+
+    if let Some(ref fp) = far.forwarding_parameters {
+        let mut fp_map = Map::new();
+        fp_map.insert(
+            "destination_interface".into(),
+            json!(format!("{:?}", fp.destination_interface.interface)),
+        );
+        if let Some(ref ni) = fp.network_instance {
+            fp_map.insert("network_instance".into(), json!(&ni.instance));
+        }
+        if let Some(ref ohc) = fp.outer_header_creation {
+            fp_map.insert(
+                "outer_header_creation".into(),
+                outer_header_creation_to_value(ohc),
+            );
+        }
+        if let Some(ref he) = fp.header_enrichment {
+            fp_map.insert("header_enrichment".into(), header_enrichment_to_value(he));
+        }
+        map.insert("forwarding_parameters".into(), Value::Object(fp_map));
+    }
+
+    if let Some(ref bar_id) = far.bar_id {
+        map.insert("bar_id".into(), json!(bar_id.id));
+    }
+    Some(IeDisplayResult::Detailed(map))
+}
+
+fn outer_header_creation_to_value(
+    ohc: &crate::ie::outer_header_creation::OuterHeaderCreation,
+) -> Value {
+    let flags = &ohc.description;
+    let description: Vec<&str> = [
+        (flags.gtpu_udp_ipv4, "GTP-U/UDP/IPv4"),
+        (flags.gtpu_udp_ipv6, "GTP-U/UDP/IPv6"),
+        (flags.udp_ipv4, "UDP/IPv4"),
+        (flags.udp_ipv6, "UDP/IPv6"),
+        (flags.ipv4, "IPv4"),
+        (flags.ipv6, "IPv6"),
+        (flags.ctag, "C-TAG"),
+        (flags.stag, "S-TAG"),
+    ]
+    .into_iter()
+    .filter_map(|(set, name)| set.then_some(name))
+    .collect();
+
+    let mut map = Map::new();
+    map.insert("description".into(), json!(description.join(", ")));
+    if let Some(teid) = ohc.teid {
+        map.insert("teid".into(), json!(format!("0x{:08x}", teid)));
+    }
+    if let Some(ipv4) = ohc.ipv4_address {
+        map.insert("ipv4_address".into(), json!(ipv4.to_string()));
+    }
+    if let Some(ipv6) = ohc.ipv6_address {
+        map.insert("ipv6_address".into(), json!(ipv6.to_string()));
+    }
+    if let Some(port) = ohc.port_number {
+        map.insert("port_number".into(), json!(port));
+    }
+    if let Some(ctag) = ohc.ctag {
+        map.insert("ctag".into(), json!(format!("0x{:06x}", ctag)));
+    }
+    if let Some(stag) = ohc.stag {
+        map.insert("stag".into(), json!(format!("0x{:06x}", stag)));
+    }
+    Value::Object(map)
+}
+
+fn header_enrichment_to_value(he: &crate::ie::header_enrichment::HeaderEnrichment) -> Value {
+    use crate::ie::header_enrichment::HeaderType;
+    let header_type = match he.header_type {
+        HeaderType::HttpHeaderField => "HTTP header field",
+        HeaderType::HttpUrlUri => "HTTP URL/URI",
+    };
+    json!({
+        "header_type": header_type,
+        "name": &he.name,
+        "value": &he.value,
+    })
+}
+
+fn apply_action_to_value(apply_action: &crate::ie::apply_action::ApplyAction) -> Value {
     use crate::ie::apply_action::ApplyAction;
+    let mut actions = Vec::new();
     for (flag, name) in [
         (ApplyAction::DROP, "DROP"),
         (ApplyAction::FORW, "FORW"),
@@ -473,24 +815,61 @@ fn display_create_far(payload: &[u8]) -> Option<IeDisplayResult> {
         (ApplyAction::NOCP, "NOCP"),
         (ApplyAction::DUPL, "DUPL"),
     ] {
-        if far.apply_action.contains(flag) {
+        if apply_action.contains(flag) {
             actions.push(name);
         }
     }
-    map.insert("apply_action".into(), json!(actions));
+    json!(actions)
+}
 
-    if let Some(ref fp) = far.forwarding_parameters {
+fn display_update_pdr(payload: &[u8]) -> Option<IeDisplayResult> {
+    let pdr = crate::ie::update_pdr::UpdatePdr::unmarshal(payload).ok()?;
+    let mut map = Map::new();
+    map.insert("pdr_id".into(), json!(pdr.pdr_id.value));
+    if let Some(ref precedence) = pdr.precedence {
+        map.insert("precedence".into(), json!(precedence.value));
+    }
+    if let Some(ref pdi) = pdr.pdi {
+        map.insert("pdi".into(), pdi_to_value(pdi));
+    }
+    if let Some(ref far_id) = pdr.far_id {
+        map.insert("far_id".into(), json!(far_id.value));
+    }
+    if let Some(ref qer_id) = pdr.qer_id {
+        map.insert("qer_id".into(), json!(qer_id.value));
+    }
+    Some(IeDisplayResult::Detailed(map))
+}
+
+fn display_update_far(payload: &[u8]) -> Option<IeDisplayResult> {
+    let far = crate::ie::update_far::UpdateFar::unmarshal(payload).ok()?;
+    let mut map = Map::new();
+    map.insert("far_id".into(), json!(far.far_id.value));
+    if let Some(ref apply_action) = far.apply_action {
+        map.insert("apply_action".into(), apply_action_to_value(apply_action));
+    }
+    if let Some(ref ufp) = far.update_forwarding_parameters {
         let mut fp_map = Map::new();
-        fp_map.insert(
-            "destination_interface".into(),
-            json!(format!("{:?}", fp.destination_interface.interface)),
-        );
-        if let Some(ref ni) = fp.network_instance {
+        if let Some(ref di) = ufp.destination_interface {
+            fp_map.insert(
+                "destination_interface".into(),
+                json!(format!("{:?}", di.interface)),
+            );
+        }
+        if let Some(ref ni) = ufp.network_instance {
             fp_map.insert("network_instance".into(), json!(&ni.instance));
         }
-        map.insert("forwarding_parameters".into(), Value::Object(fp_map));
+        if let Some(ref ohc) = ufp.outer_header_creation {
+            fp_map.insert(
+                "outer_header_creation".into(),
+                outer_header_creation_to_value(ohc),
+            );
+        }
+        if let Some(ref he) = ufp.header_enrichment {
+            fp_map.insert("header_enrichment".into(), header_enrichment_to_value(he));
+        }
+        map.insert("update_forwarding_parameters".into(), Value::Object(fp_map));
     }
-
     if let Some(ref bar_id) = far.bar_id {
         map.insert("bar_id".into(), json!(bar_id.id));
     }
@@ -505,7 +884,11 @@ fn display_offending_ie(payload: &[u8]) -> Option<IeDisplayResult> {
 
 fn display_timer(payload: &[u8]) -> Option<IeDisplayResult> {
     let timer = crate::ie::timer::Timer::unmarshal(payload).ok()?;
-    Some(IeDisplayResult::Compact(json!(timer.value)))
+    let value = match timer.as_duration() {
+        Some(duration) => json!(duration.as_secs()),
+        None => json!("infinite"),
+    };
+    Some(IeDisplayResult::Compact(value))
 }
 
 fn display_pdn_type(payload: &[u8]) -> Option<IeDisplayResult> {
@@ -537,29 +920,7 @@ fn display_cp_function_features(payload: &[u8]) -> Option<IeDisplayResult> {
 
 fn display_up_function_features(payload: &[u8]) -> Option<IeDisplayResult> {
     let features = crate::ie::up_function_features::UPFunctionFeatures::unmarshal(payload).ok()?;
-    use crate::ie::up_function_features::UPFunctionFeatures;
-    let flags: Vec<&str> = [
-        (UPFunctionFeatures::BUCP, "BUCP"),
-        (UPFunctionFeatures::DDND, "DDND"),
-        (UPFunctionFeatures::DLBD, "DLBD"),
-        (UPFunctionFeatures::TRST, "TRST"),
-        (UPFunctionFeatures::FTUP, "FTUP"),
-        (UPFunctionFeatures::PFDM, "PFDM"),
-        (UPFunctionFeatures::HEEU, "HEEU"),
-        (UPFunctionFeatures::TREU, "TREU"),
-        (UPFunctionFeatures::EMPU, "EMPU"),
-        (UPFunctionFeatures::PDIU, "PDIU"),
-        (UPFunctionFeatures::UDBC, "UDBC"),
-        (UPFunctionFeatures::QUOV, "QUOV"),
-        (UPFunctionFeatures::ADPDP, "ADPDP"),
-        (UPFunctionFeatures::UEIP, "UEIP"),
-        (UPFunctionFeatures::SSET, "SSET"),
-        (UPFunctionFeatures::MPTCP, "MPTCP"),
-    ]
-    .into_iter()
-    .filter(|(flag, _)| features.contains(*flag))
-    .map(|(_, name)| name)
-    .collect();
+    let flags: Vec<&str> = features.iter_names().map(|(name, _)| name).collect();
     Some(IeDisplayResult::Compact(json!(flags)))
 }
 
@@ -594,6 +955,25 @@ fn display_source_ip_address(payload: &[u8]) -> Option<IeDisplayResult> {
     Some(IeDisplayResult::Detailed(map))
 }
 
+fn display_mac_address(payload: &[u8]) -> Option<IeDisplayResult> {
+    use crate::ie::mac_address::{format_mac, MacAddress};
+    let mac = MacAddress::unmarshal(payload).ok()?;
+    let mut map = Map::new();
+    if let Some(ref src) = mac.source_mac {
+        map.insert("source".into(), json!(format_mac(src)));
+    }
+    if let Some(ref dst) = mac.destination_mac {
+        map.insert("destination".into(), json!(format_mac(dst)));
+    }
+    if let Some(ref upper_src) = mac.upper_source_mac {
+        map.insert("upper_source".into(), json!(format_mac(upper_src)));
+    }
+    if let Some(ref upper_dst) = mac.upper_destination_mac {
+        map.insert("upper_destination".into(), json!(format_mac(upper_dst)));
+    }
+    Some(IeDisplayResult::Detailed(map))
+}
+
 fn display_apn_dnn(payload: &[u8]) -> Option<IeDisplayResult> {
     let apn = crate::ie::apn_dnn::ApnDnn::unmarshal(payload).ok()?;
     Some(IeDisplayResult::Compact(json!(&apn.name)))
@@ -619,20 +999,66 @@ fn display_snssai(payload: &[u8]) -> Option<IeDisplayResult> {
     Some(IeDisplayResult::Detailed(map))
 }
 
+fn display_packet_rate_status(payload: &[u8]) -> Option<IeDisplayResult> {
+    let prs = crate::ie::packet_rate_status::PacketRateStatus::unmarshal(payload).ok()?;
+    let mut map = Map::new();
+    let counts = [
+        ("remaining_uplink_packets", prs.remaining_uplink_packets()),
+        (
+            "remaining_additional_uplink_packets",
+            prs.remaining_additional_uplink_packets(),
+        ),
+        (
+            "remaining_downlink_packets",
+            prs.remaining_downlink_packets(),
+        ),
+        (
+            "remaining_additional_downlink_packets",
+            prs.remaining_additional_downlink_packets(),
+        ),
+    ];
+    for (key, count) in counts {
+        if let Some(count) = count {
+            map.insert(key.into(), json!(count));
+        }
+    }
+    if let Some(time) = prs.validity_time() {
+        let hex: String = time.iter().map(|b| format!("{b:02x}")).collect();
+        map.insert("validity_time".into(), json!(format!("0x{hex}")));
+    }
+    Some(IeDisplayResult::Detailed(map))
+}
+
 fn display_user_id(payload: &[u8]) -> Option<IeDisplayResult> {
     let uid = crate::ie::user_id::UserId::unmarshal(payload).ok()?;
-    let mut map = Map::new();
-    map.insert("id_type".into(), json!(format!("{:?}", uid.user_id_type)));
-    if let Some(s) = uid.as_string() {
-        map.insert("value".into(), json!(s));
-    } else {
-        let hex = uid
-            .user_id_value
+    let hex = |bytes: &[u8]| {
+        bytes
             .iter()
             .map(|b| format!("{b:02x}"))
             .collect::<Vec<_>>()
-            .join(" ");
-        map.insert("value_hex".into(), json!(hex));
+            .join(" ")
+    };
+    let mut map = Map::new();
+    if let Some(imsi) = uid.imsi() {
+        map.insert("imsi".into(), json!(hex(imsi)));
+    }
+    if let Some(imei) = uid.imei() {
+        map.insert("imei".into(), json!(hex(imei)));
+    }
+    if let Some(msisdn) = uid.msisdn() {
+        map.insert("msisdn".into(), json!(hex(msisdn)));
+    }
+    if let Some(nai) = uid.nai() {
+        map.insert("nai".into(), json!(nai));
+    }
+    if let Some(supi) = uid.supi() {
+        map.insert("supi".into(), json!(supi));
+    }
+    if let Some(gpsi) = uid.gpsi() {
+        map.insert("gpsi".into(), json!(gpsi));
+    }
+    if let Some(pei) = uid.pei() {
+        map.insert("pei".into(), json!(pei));
     }
     Some(IeDisplayResult::Detailed(map))
 }
@@ -666,7 +1092,7 @@ fn display_fq_csid(payload: &[u8]) -> Option<IeDisplayResult> {
     let node_addr = match &csid.node_id {
         crate::ie::fq_csid::NodeId::Ipv4(ip) => ip.to_string(),
         crate::ie::fq_csid::NodeId::Ipv6(ip) => ip.to_string(),
-        crate::ie::fq_csid::NodeId::Fqdn(fqdn) => fqdn.clone(),
+        crate::ie::fq_csid::NodeId::MccMnc(addr) => format!("{:#010x}", addr),
     };
     map.insert(
         "node_id_type".into(),
@@ -684,11 +1110,7 @@ fn display_ethernet_pdu_info(payload: &[u8]) -> Option<IeDisplayResult> {
         )
         .ok()?;
     let mut map = Map::new();
-    map.insert("untagged".into(), json!(info.is_untagged()));
-    map.insert(
-        "has_ethernet_header".into(),
-        json!(info.has_ethernet_header()),
-    );
+    map.insert("ETHI".into(), json!(info.ethi()));
     Some(IeDisplayResult::Detailed(map))
 }
 
@@ -1018,6 +1440,40 @@ mod tests {
         assert!(create_pdr_pos < create_far_pos);
     }
 
+    #[test]
+    fn test_every_present_ie_is_displayed() {
+        use crate::ie::user_plane_ip_resource_information::UserPlaneIpResourceInformation;
+
+        // Display walks the IEs in the message, so a type without a rich
+        // renderer is still listed rather than silently dropped.
+        let request: Box<dyn Message> = Box::new(
+            HeartbeatRequestBuilder::new(1)
+                .recovery_time_stamp(SystemTime::now())
+                .ie(
+                    UserPlaneIpResourceInformation::new(Some(Ipv4Addr::new(10, 0, 0, 1)), None)
+                        .to_ie(),
+                )
+                .ie(Ie::new(IeType::ValidityTimer, vec![0x00, 0x3C]))
+                .build(),
+        );
+        let value = message_to_value(request.as_ref());
+        let types: Vec<&str> = value["information_elements"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|ie| ie["type"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            types,
+            vec![
+                "RecoveryTimeStamp",
+                "UserPlaneIpResourceInformation",
+                "ValidityTimer"
+            ]
+        );
+    }
+
     // ========================================================================
     // IE-Specific Display Tests
     // ========================================================================
@@ -1054,6 +1510,49 @@ mod tests {
         assert_eq!(value["Cause"], "RequestAccepted");
     }
 
+    #[test]
+    fn test_display_report_type() {
+        use crate::ie::report_type::ReportType;
+
+        let ie = ReportType::USAR.to_ie();
+        assert_eq!(ie_to_value(&ie)["ReportType"], "USAR (Usage Report)");
+
+        let ie = (ReportType::USAR | ReportType::UPIR).to_ie();
+        assert_eq!(
+            ie_to_value(&ie)["ReportType"],
+            json!(["USAR (Usage Report)", "UPIR (User Plane Inactivity Report)"])
+        );
+    }
+
+    #[test]
+    fn test_display_ethernet_pdu_session_information() {
+        use crate::ie::ethernet_pdu_session_information::EthernetPduSessionInformation;
+
+        let value = ie_to_value(&EthernetPduSessionInformation::untagged().to_ie());
+        assert_eq!(value["type"], "EthernetPduSessionInformation");
+        assert_eq!(value["ETHI"], true);
+        assert!(value.get("has_ethernet_header").is_none());
+
+        let value = ie_to_value(&EthernetPduSessionInformation::with_ethernet_header().to_ie());
+        assert_eq!(value["ETHI"], false);
+    }
+
+    #[test]
+    fn test_display_mac_address() {
+        use crate::ie::mac_address::MacAddress;
+
+        let mac = MacAddress::source_and_dest(
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+        );
+        let value = ie_to_value(&mac.to_ie());
+
+        assert_eq!(value["type"], "MacAddress");
+        assert_eq!(value["source"], "00:11:22:33:44:55");
+        assert_eq!(value["destination"], "AA:BB:CC:DD:EE:FF");
+        assert!(value.get("upper_source").is_none());
+    }
+
     #[test]
     fn test_display_recovery_timestamp() {
         let ts = RecoveryTimeStamp::new(SystemTime::UNIX_EPOCH);
@@ -1076,6 +1575,58 @@ mod tests {
         assert!(fseid_ie.get("seid_decimal").is_some());
     }
 
+    #[test]
+    fn test_display_create_pdr_sdf_filter() {
+        use crate::ie::create_pdr::CreatePdrBuilder;
+        use crate::ie::pdi::PdiBuilder;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+        use crate::ie::sdf_filter::SdfFilter;
+        use crate::ie::source_interface::{SourceInterface, SourceInterfaceValue};
+
+        let pdi = PdiBuilder::new(SourceInterface::new(SourceInterfaceValue::Core))
+            .sdf_filter(SdfFilter::new("permit out tcp from any to assigned"))
+            .application_id("video-streaming")
+            .build()
+            .unwrap();
+        let pdr = CreatePdrBuilder::new(PdrId::new(1))
+            .precedence(Precedence::new(100))
+            .pdi(pdi)
+            .build()
+            .unwrap();
+        let value = ie_to_value(&pdr.to_ie());
+
+        assert_eq!(
+            value["pdi"]["sdf_filter"]["flow_description"],
+            "permit out tcp from any to assigned"
+        );
+        assert_eq!(value["pdi"]["application_id"], "video-streaming");
+
+        let yaml = serde_yaml_ng::to_string(&value).unwrap();
+        assert!(yaml.contains("flow_description: permit out tcp from any to assigned"));
+        assert!(yaml.contains("application_id: video-streaming"));
+    }
+
+    #[test]
+    fn test_packet_rate_status_display() {
+        use crate::ie::packet_rate_status::PacketRateStatus;
+
+        let prs = PacketRateStatus::builder()
+            .remaining_uplink_packets(100)
+            .remaining_downlink_packets(200)
+            .validity_time([0, 0, 0, 1, 0, 0, 0, 0])
+            .build()
+            .unwrap();
+        let value = ie_to_value(&prs.to_ie().unwrap());
+
+        assert_eq!(value["type"], "PacketRateStatus");
+        assert_eq!(value["remaining_uplink_packets"], 100);
+        assert_eq!(value["remaining_downlink_packets"], 200);
+        assert_eq!(value["validity_time"], "0x0000000100000000");
+        assert!(value.get("remaining_additional_uplink_packets").is_none());
+        assert!(value.get("payload_hex").is_none());
+    }
+
     #[test]
     fn test_unknown_ie_hex_fallback() {
         let ie = Ie::new(IeType::ValidityTimer, vec![0xDE, 0xAD, 0xBE, 0xEF]);
@@ -1093,6 +1644,83 @@ mod tests {
         assert!(value.get("payload_hex").is_none());
     }
 
+    // ========================================================================
+    // Display Options
+    // ========================================================================
+
+    fn create_request_with_unknown_ie() -> Box<dyn Message> {
+        let pdr_ie = Ie::new(IeType::CreatePdr, vec![0, 56, 0, 2, 0, 1]);
+        let far_ie = Ie::new(IeType::CreateFar, vec![0, 108, 0, 4, 0, 0, 0, 1]);
+        Box::new(
+            SessionEstablishmentRequestBuilder::new(0x1234, 7)
+                .node_id(Ipv4Addr::new(192, 168, 1, 1))
+                .fseid(0x1234, Ipv4Addr::new(10, 0, 0, 1))
+                .create_pdrs(vec![pdr_ie.clone(), pdr_ie])
+                .create_fars(vec![far_ie])
+                .ies(vec![Ie::new(
+                    IeType::ValidityTimer,
+                    vec![0xDE, 0xAD, 0xBE, 0xEF],
+                )])
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_display_options_brief_shows_counts_only() {
+        let msg = create_request_with_unknown_ie();
+        let options = DisplayOptions {
+            verbosity: Verbosity::Brief,
+            ..Default::default()
+        };
+
+        let yaml = msg.to_yaml_with_options(&options).unwrap();
+        assert!(!yaml.contains("payload_hex"));
+        assert!(!yaml.contains("192.168.1.1"));
+
+        let json: Value =
+            serde_json::from_str(&msg.to_json_with_options(&options).unwrap()).unwrap();
+        let ies = &json["information_elements"];
+        assert_eq!(ies["NodeId"], 1);
+        assert_eq!(ies["CreatePdr"], 2);
+        assert_eq!(ies["ValidityTimer"], 1);
+    }
+
+    #[test]
+    fn test_display_options_normal_omits_hex() {
+        let msg = create_request_with_unknown_ie();
+        let options = DisplayOptions {
+            verbosity: Verbosity::Normal,
+            ..Default::default()
+        };
+
+        let yaml = msg.to_yaml_with_options(&options).unwrap();
+        assert!(!yaml.contains("payload_hex"));
+        assert!(yaml.contains("192.168.1.1"));
+        assert!(yaml.contains("type: ValidityTimer"));
+    }
+
+    #[test]
+    fn test_display_options_verbose_shows_hex_for_unknown_ie() {
+        let msg = create_request_with_unknown_ie();
+        let options = DisplayOptions {
+            verbosity: Verbosity::Verbose,
+            ..Default::default()
+        };
+
+        let yaml = msg.to_yaml_with_options(&options).unwrap();
+        assert!(yaml.contains("payload_hex: de ad be ef"));
+        assert_eq!(yaml, msg.to_yaml().unwrap());
+
+        let options = DisplayOptions {
+            verbosity: Verbosity::Verbose,
+            max_payload_hex_bytes: 2,
+        };
+        let json = msg.to_json_with_options(&options).unwrap();
+        assert!(!json.contains("payload_hex"));
+        assert!(json.contains("\"payload_size\":4"));
+    }
+
     // ========================================================================
     // Edge Cases
     // ========================================================================
@@ -1113,6 +1741,214 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // Summary Tests
+    // ========================================================================
+
+    #[test]
+    fn test_to_summary_heartbeat_request() {
+        let msg = create_heartbeat_request();
+        assert_eq!(
+            msg.to_summary(),
+            "HeartbeatRequest seq=12345 ts=1970-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_to_summary_session_establishment_request() {
+        let pdr = |id: u8| Ie::new(IeType::CreatePdr, vec![0, 56, 0, 2, 0, id]);
+        let far = Ie::new(IeType::CreateFar, vec![0, 108, 0, 4, 0, 0, 0, 1]);
+        let msg = SessionEstablishmentRequestBuilder::new(0x1234567890ABCDEF, 12345)
+            .node_id(Ipv4Addr::new(192, 168, 1, 1))
+            .fseid(0x1, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(vec![pdr(1), pdr(2)])
+            .create_fars(vec![far])
+            .build()
+            .unwrap();
+
+        let summary = msg.to_summary();
+        assert_eq!(
+            summary,
+            "SessionEstablishmentRequest seq=12345 seid=0x1234567890abcdef pdrs=2 fars=1"
+        );
+        assert!(!summary.contains('\n'));
+    }
+
+    #[test]
+    fn test_summary_snapshot_all_counted_ies() {
+        use crate::message::session_modification_request::SessionModificationRequestBuilder;
+
+        let ie = |ie_type: IeType| Ie::new(ie_type, vec![0, 56, 0, 2, 0, 1]);
+        let msg = SessionModificationRequestBuilder::new(0xABCD, 7)
+            .create_pdrs(vec![ie(IeType::CreatePdr), ie(IeType::CreatePdr)])
+            .update_fars(vec![ie(IeType::UpdateFar)])
+            .remove_qers(vec![ie(IeType::RemoveQer)])
+            .build();
+
+        assert_eq!(
+            msg.to_summary(),
+            "SessionModificationRequest seq=7 seid=0x000000000000abcd pdrs=2 update_fars=1 remove_qers=1"
+        );
+    }
+
+    #[test]
+    fn test_to_yaml_session_modification_request_update_pdr() {
+        use crate::ie::far_id::FarId;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+        use crate::ie::update_pdr::UpdatePdr;
+        use crate::message::session_modification_request::SessionModificationRequestBuilder;
+
+        let update_pdr = UpdatePdr::builder(PdrId::new(3))
+            .precedence(Precedence::new(200))
+            .far_id(FarId::new(5))
+            .build()
+            .unwrap();
+        let msg = SessionModificationRequestBuilder::new(0xABCD, 8)
+            .update_pdrs(vec![update_pdr.to_ie()])
+            .build();
+
+        let yaml = msg.to_yaml().unwrap();
+        assert!(yaml.contains("type: UpdatePdr"));
+        assert!(yaml.contains("pdr_id: 3"));
+        assert!(yaml.contains("precedence: 200"));
+        assert!(yaml.contains("far_id: 5"));
+        assert!(!yaml.contains("payload_hex"));
+    }
+
+    #[test]
+    fn test_to_json_session_modification_request_update_far() {
+        use crate::ie::apply_action::ApplyAction;
+        use crate::ie::destination_interface::{DestinationInterface, Interface};
+        use crate::ie::far_id::FarId;
+        use crate::ie::update_far::UpdateFar;
+        use crate::ie::update_forwarding_parameters::UpdateForwardingParameters;
+        use crate::message::session_modification_request::SessionModificationRequestBuilder;
+
+        let update_far = UpdateFar::builder(FarId::new(5))
+            .apply_action(ApplyAction::FORW)
+            .update_forwarding_parameters(
+                UpdateForwardingParameters::new()
+                    .with_destination_interface(DestinationInterface::new(Interface::Core)),
+            )
+            .build()
+            .unwrap();
+        let msg = SessionModificationRequestBuilder::new(0xABCD, 9)
+            .update_fars(vec![update_far.to_ie()])
+            .build();
+
+        let json = msg.to_json().unwrap();
+        assert!(json.contains(
+            r#"{"type":"UpdateFar","far_id":5,"apply_action":["FORW"],"update_forwarding_parameters":{"destination_interface":"Core"}}"#
+        ));
+    }
+
+    #[test]
+    fn test_to_json_up_function_features() {
+        use crate::ie::up_function_features::UPFunctionFeatures;
+        use crate::message::association_setup_response::AssociationSetupResponseBuilder;
+
+        let msg = AssociationSetupResponseBuilder::new(7)
+            .cause_accepted()
+            .node_id(std::net::Ipv4Addr::new(10, 0, 0, 1))
+            .recovery_time_stamp(std::time::SystemTime::UNIX_EPOCH)
+            .up_function_features(
+                UPFunctionFeatures::FTUP | UPFunctionFeatures::UEIP | UPFunctionFeatures::MPAS,
+            )
+            .build();
+
+        let json = msg.to_json().unwrap();
+        assert!(
+            json.contains(r#"{"UpFunctionFeatures":["FTUP","UEIP","MPAS"]}"#),
+            "{json}"
+        );
+    }
+
+    #[test]
+    fn test_to_yaml_create_far_outer_header_creation() {
+        use crate::ie::apply_action::ApplyAction;
+        use crate::ie::create_far::CreateFar;
+        use crate::ie::destination_interface::{DestinationInterface, Interface};
+        use crate::ie::far_id::FarId;
+        use crate::ie::forwarding_parameters::ForwardingParameters;
+        use crate::ie::header_enrichment::HeaderEnrichment;
+        use crate::ie::outer_header_creation::OuterHeaderCreation;
+        use crate::message::session_modification_request::SessionModificationRequestBuilder;
+
+        let far = CreateFar::new(FarId::new(2), ApplyAction::FORW).with_forwarding_parameters(
+            ForwardingParameters::new(DestinationInterface::new(Interface::Access))
+                .with_outer_header_creation(OuterHeaderCreation::gtpu_ipv4(
+                    0x1234_5678,
+                    std::net::Ipv4Addr::new(192, 0, 2, 1),
+                ))
+                .with_header_enrichment(HeaderEnrichment::http_header(
+                    "X-MSISDN".to_string(),
+                    "123456789".to_string(),
+                )),
+        );
+        let msg = SessionModificationRequestBuilder::new(0xABCD, 10)
+            .create_fars(vec![far.to_ie()])
+            .build();
+
+        let yaml = msg.to_yaml().unwrap();
+        assert!(yaml.contains("description: GTP-U/UDP/IPv4"), "{yaml}");
+        assert!(yaml.contains("teid: '0x12345678'"), "{yaml}");
+        assert!(yaml.contains("ipv4_address: 192.0.2.1"), "{yaml}");
+        assert!(!yaml.contains("port_number"), "{yaml}");
+        assert!(yaml.contains("header_type: HTTP header field"), "{yaml}");
+        assert!(yaml.contains("name: X-MSISDN"), "{yaml}");
+        assert!(yaml.contains("value: '123456789'"), "{yaml}");
+
+        let json = msg.to_json().unwrap();
+        assert!(json.contains(
+            r#""outer_header_creation":{"description":"GTP-U/UDP/IPv4","teid":"0x12345678","ipv4_address":"192.0.2.1"}"#
+        ));
+    }
+
+    #[test]
+    fn test_json_snapshot_heartbeat_request() {
+        let msg = create_heartbeat_request();
+        assert_eq!(
+            msg.to_json().unwrap(),
+            concat!(
+                r#"{"message_type":"HeartbeatRequest","sequence":12345,"version":1,"#,
+                r#""information_elements":[{"type":"RecoveryTimeStamp","timestamp_seconds":0,"#,
+                r#""timestamp_readable":"1970-01-01 00:00:00 UTC","#,
+                r#""timestamp_description":"0 seconds since Unix epoch"}]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_utc_timestamp() {
+        assert_eq!(format_utc_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_utc_timestamp(1_704_067_200), "2024-01-01T00:00:00Z");
+        assert_eq!(format_utc_timestamp(1_709_210_096), "2024-02-29T12:34:56Z");
+        assert_eq!(format_utc_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_utc_timestamp(4_107_542_399), "2100-02-28T23:59:59Z");
+        assert_eq!(format_utc_timestamp(4_107_542_400), "2100-03-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_display_recovery_timestamp_calendar() {
+        use std::time::Duration;
+
+        let readable = |secs: u64| {
+            let ts = RecoveryTimeStamp::new(SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+            ie_to_value(&ts.to_ie())["timestamp_readable"].clone()
+        };
+
+        // Leap day
+        assert_eq!(readable(1_709_164_800), "2024-02-29 00:00:00 UTC");
+        // Day after leap day
+        assert_eq!(readable(1_709_251_200), "2024-03-01 00:00:00 UTC");
+        // Last second of a 31-day month
+        assert_eq!(readable(1_706_745_599), "2024-01-31 23:59:59 UTC");
+        // End of year
+        assert_eq!(readable(1_735_689_599), "2024-12-31 23:59:59 UTC");
+        assert_eq!(readable(1_893_456_000), "2030-01-01 00:00:00 UTC");
+    }
+
     #[test]
     fn test_yaml_json_equivalence() {
         let request = create_heartbeat_request();
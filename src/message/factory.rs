@@ -0,0 +1,571 @@
+//! Response factory pairing each PFCP request with its response type.
+
+use crate::error::PfcpError;
+use crate::ie::cause::CauseValue;
+use crate::ie::fseid::Fseid;
+use crate::ie::node_id::NodeId;
+use crate::ie::IeType;
+use crate::message::association_release_response::AssociationReleaseResponseBuilder;
+use crate::message::association_setup_response::AssociationSetupResponseBuilder;
+use crate::message::association_update_response::AssociationUpdateResponseBuilder;
+use crate::message::heartbeat_response::HeartbeatResponseBuilder;
+use crate::message::node_report_response::NodeReportResponseBuilder;
+use crate::message::pfd_management_response::PfdManagementResponseBuilder;
+use crate::message::session_deletion_response::SessionDeletionResponseBuilder;
+use crate::message::session_establishment_response::SessionEstablishmentResponseBuilder;
+use crate::message::session_modification_response::SessionModificationResponseBuilder;
+use crate::message::session_report_response::SessionReportResponseBuilder;
+use crate::message::session_set_deletion_response::SessionSetDeletionResponseBuilder;
+use crate::message::session_set_modification_response::SessionSetModificationResponseBuilder;
+use crate::message::{
+    HeartbeatRequest, Message, MsgType, SessionDeletionRequest, SessionEstablishmentRequest,
+    SessionModificationRequest, SessionReportRequest,
+};
+use crate::types::Seid;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+/// Creates the response matching a received request.
+///
+/// The response type is the one given by [`MsgType::response_type`], so a
+/// Session Establishment Request can only be answered with a Session
+/// Establishment Response. The response carries the request's sequence
+/// number, the given cause and the IEs the factory knows about the local
+/// node: its Node ID and Recovery Time Stamp. Heartbeat Responses carry no
+/// Cause IE, so `cause` is ignored for them.
+///
+/// # SEID handling
+///
+/// - Session Establishment Response: the header SEID is the CP F-SEID from
+///   the request. When `cause` accepts the request the factory allocates a
+///   UP SEID and adds the UP F-SEID; a rejection carries neither.
+/// - Session Modification, Deletion and Report Responses: per 3GPP TS 29.244
+///   Section 7.2.2.4.2 the header SEID is the one assigned by the peer, which
+///   the request does not carry. Build these with
+///   [`create_session_response`](Self::create_session_response).
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::ie::cause::CauseValue;
+/// use rs_pfcp::ie::node_id::NodeId;
+/// use rs_pfcp::message::heartbeat_request::HeartbeatRequestBuilder;
+/// use rs_pfcp::message::{Message, MessageFactory, MsgType};
+/// use std::net::Ipv4Addr;
+/// use std::time::SystemTime;
+///
+/// let factory = MessageFactory::new(
+///     NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 2)),
+///     SystemTime::now(),
+/// );
+///
+/// let request = HeartbeatRequestBuilder::new(42)
+///     .recovery_time_stamp(SystemTime::now())
+///     .build();
+/// let response = factory.create_response(&request, CauseValue::RequestAccepted)?;
+/// assert_eq!(response.msg_type(), MsgType::HeartbeatResponse);
+/// assert_eq!(response.sequence(), request.sequence());
+/// # Ok::<(), rs_pfcp::error::PfcpError>(())
+/// ```
+#[derive(Debug)]
+pub struct MessageFactory {
+    node_id: NodeId,
+    recovery_time_stamp: SystemTime,
+    fseid_address: Option<IpAddr>,
+    next_seid: AtomicU64,
+}
+
+impl MessageFactory {
+    /// Creates a factory for the local node.
+    ///
+    /// When `node_id` is an IP address it is also used for the F-SEIDs the
+    /// factory allocates; for FQDN node IDs set one with
+    /// [`fseid_address`](Self::fseid_address).
+    pub fn new(node_id: NodeId, recovery_time_stamp: SystemTime) -> Self {
+        let fseid_address = match &node_id {
+            NodeId::IPv4(addr) => Some(IpAddr::V4(*addr)),
+            NodeId::IPv6(addr) => Some(IpAddr::V6(*addr)),
+            NodeId::FQDN(_) => None,
+        };
+        MessageFactory {
+            node_id,
+            recovery_time_stamp,
+            fseid_address,
+            next_seid: AtomicU64::new(1),
+        }
+    }
+
+    /// Sets the address used in allocated F-SEIDs.
+    pub fn fseid_address(mut self, addr: impl Into<IpAddr>) -> Self {
+        self.fseid_address = Some(addr.into());
+        self
+    }
+
+    /// Sets the first SEID handed out for new sessions (default 1).
+    pub fn first_seid(self, seid: impl Into<Seid>) -> Self {
+        self.next_seid.store(seid.into().0, Ordering::Relaxed);
+        self
+    }
+
+    /// Creates the response to `request` with the given cause.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `request` is not a request message, if it is a
+    /// Session Modification, Deletion or Report Request (see
+    /// [`create_session_response`](Self::create_session_response)), or if an
+    /// accepted Session Establishment Request lacks a CP F-SEID or the
+    /// factory has no F-SEID address.
+    pub fn create_response(
+        &self,
+        request: &dyn Message,
+        cause: CauseValue,
+    ) -> Result<Box<dyn Message>, PfcpError> {
+        let seq = request.sequence();
+        let node_id = self.node_id.to_ie();
+
+        let response: Box<dyn Message> = match request.msg_type() {
+            MsgType::HeartbeatRequest => Box::new(
+                HeartbeatResponseBuilder::new(seq)
+                    .recovery_time_stamp(self.recovery_time_stamp)
+                    .build(),
+            ),
+            MsgType::PfdManagementRequest => Box::new(
+                PfdManagementResponseBuilder::new(seq)
+                    .cause(cause)
+                    .node_id_ie(node_id)
+                    .build(),
+            ),
+            MsgType::AssociationSetupRequest => Box::new(
+                AssociationSetupResponseBuilder::new(seq)
+                    .cause(cause)
+                    .node_id_ie(node_id)
+                    .recovery_time_stamp(self.recovery_time_stamp)
+                    .build(),
+            ),
+            MsgType::AssociationUpdateRequest => Box::new(
+                AssociationUpdateResponseBuilder::new(seq)
+                    .node_id(node_id)
+                    .cause(cause)
+                    .build(),
+            ),
+            MsgType::AssociationReleaseRequest => Box::new(
+                AssociationReleaseResponseBuilder::new(seq)
+                    .cause(cause)
+                    .node_id(node_id)
+                    .build(),
+            ),
+            MsgType::NodeReportRequest => Box::new(
+                NodeReportResponseBuilder::new(seq)
+                    .node_id_ie(node_id)
+                    .cause(cause)
+                    .build(),
+            ),
+            MsgType::SessionSetDeletionRequest => Box::new(
+                SessionSetDeletionResponseBuilder::new(seq)
+                    .node_id(node_id)
+                    .cause(cause)
+                    .build(),
+            ),
+            MsgType::SessionSetModificationRequest => Box::new(
+                SessionSetModificationResponseBuilder::new(seq)
+                    .cause(cause)
+                    .build()?,
+            ),
+            MsgType::SessionEstablishmentRequest => {
+                let cp_fseid_ie =
+                    request
+                        .ies(IeType::Fseid)
+                        .next()
+                        .ok_or(PfcpError::MissingMandatoryIe {
+                            ie_type: IeType::Fseid,
+                            message_type: Some(MsgType::SessionEstablishmentRequest),
+                            parent_ie: None,
+                        })?;
+                let cp_fseid = Fseid::unmarshal(&cp_fseid_ie.payload)?;
+                let mut builder =
+                    SessionEstablishmentResponseBuilder::new(cp_fseid.seid, seq, cause)
+                        .node_id_ie(node_id);
+                if cause.is_accepted() {
+                    let addr = self.fseid_address.ok_or_else(|| {
+                        PfcpError::validation_error(
+                            "MessageFactory",
+                            "fseid_address",
+                            "an F-SEID address is required for FQDN node IDs",
+                        )
+                    })?;
+                    let up_seid = self.next_seid.fetch_add(1, Ordering::Relaxed);
+                    builder = builder.fseid(up_seid, addr);
+                }
+                Box::new(builder.build()?)
+            }
+            MsgType::SessionModificationRequest
+            | MsgType::SessionDeletionRequest
+            | MsgType::SessionReportRequest => {
+                return Err(PfcpError::validation_error(
+                    "MessageFactory",
+                    "peer_seid",
+                    "the response carries the peer's SEID; use create_session_response",
+                ))
+            }
+            other => {
+                return Err(PfcpError::invalid_value(
+                    "Message Type",
+                    format!("{:?}", other),
+                    "not a request message",
+                ))
+            }
+        };
+        Ok(response)
+    }
+
+    /// Creates the response to a Session Modification, Deletion or Report
+    /// Request.
+    ///
+    /// `peer_seid` is the SEID the peer assigned to the session: the CP SEID
+    /// when answering as the UP function, the UP SEID when answering as the
+    /// CP function (3GPP TS 29.244 Section 7.2.2.4.2).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `request` is not one of those three requests.
+    pub fn create_session_response(
+        &self,
+        request: &dyn Message,
+        peer_seid: impl Into<Seid>,
+        cause: CauseValue,
+    ) -> Result<Box<dyn Message>, PfcpError> {
+        let seq = request.sequence();
+        let peer_seid = peer_seid.into();
+
+        let response: Box<dyn Message> = match request.msg_type() {
+            MsgType::SessionModificationRequest => Box::new(
+                SessionModificationResponseBuilder::new(peer_seid, seq)
+                    .cause(cause)
+                    .build(),
+            ),
+            MsgType::SessionDeletionRequest => Box::new(
+                SessionDeletionResponseBuilder::new(peer_seid, seq)
+                    .cause(cause)
+                    .build(),
+            ),
+            MsgType::SessionReportRequest => {
+                Box::new(SessionReportResponseBuilder::new(peer_seid, seq, cause).build()?)
+            }
+            other => {
+                return Err(PfcpError::invalid_value(
+                    "Message Type",
+                    format!("{:?}", other),
+                    "not a Session Modification, Deletion or Report Request",
+                ))
+            }
+        };
+        Ok(response)
+    }
+}
+
+// Typed request/response pairing
+//
+// Unlike `MessageFactory::create_response`, these take the concrete request
+// type, so the compiler checks which response answers which request. Each
+// returns the response builder with the request's sequence number already
+// set, leaving the remaining IEs to the caller.
+
+/// Starts the Heartbeat Response to `request`.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::message::heartbeat_request::HeartbeatRequestBuilder;
+/// use rs_pfcp::message::{heartbeat_response_for, Message};
+/// use std::time::SystemTime;
+///
+/// let request = HeartbeatRequestBuilder::new(42)
+///     .recovery_time_stamp(SystemTime::now())
+///     .build();
+/// let response = heartbeat_response_for(&request)
+///     .recovery_time_stamp(SystemTime::now())
+///     .build();
+/// assert_eq!(response.sequence(), request.sequence());
+/// ```
+pub fn heartbeat_response_for(request: &HeartbeatRequest) -> HeartbeatResponseBuilder {
+    HeartbeatResponseBuilder::new(request.sequence())
+}
+
+/// Starts the Session Establishment Response to `request`.
+///
+/// The header SEID is the CP SEID from the request's F-SEID.
+///
+/// # Errors
+///
+/// Returns an error if the request's CP F-SEID doesn't decode.
+pub fn session_establishment_response_for(
+    request: &SessionEstablishmentRequest,
+    cause: CauseValue,
+) -> Result<SessionEstablishmentResponseBuilder, PfcpError> {
+    let cp_fseid = request.fseid()?;
+    Ok(SessionEstablishmentResponseBuilder::new(
+        cp_fseid.seid,
+        request.sequence(),
+        cause,
+    ))
+}
+
+/// Starts the Session Modification Response to `request`.
+///
+/// `peer_seid` is the SEID the peer assigned to the session, i.e. the CP SEID
+/// when answering as the UP function (3GPP TS 29.244 Section 7.2.2.4.2).
+pub fn session_modification_response_for(
+    request: &SessionModificationRequest,
+    peer_seid: impl Into<Seid>,
+) -> SessionModificationResponseBuilder {
+    SessionModificationResponseBuilder::new(peer_seid, request.sequence())
+}
+
+/// Starts the Session Deletion Response to `request`.
+///
+/// `peer_seid` is the SEID the peer assigned to the session.
+pub fn session_deletion_response_for(
+    request: &SessionDeletionRequest,
+    peer_seid: impl Into<Seid>,
+) -> SessionDeletionResponseBuilder {
+    SessionDeletionResponseBuilder::new(peer_seid, request.sequence())
+}
+
+/// Starts the Session Report Response to `request`.
+///
+/// `peer_seid` is the SEID the peer assigned to the session, i.e. the UP SEID
+/// when answering as the CP function.
+pub fn session_report_response_for(
+    request: &SessionReportRequest,
+    peer_seid: impl Into<Seid>,
+    cause: CauseValue,
+) -> SessionReportResponseBuilder {
+    SessionReportResponseBuilder::new(peer_seid, request.sequence(), cause)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ie::cause::Cause;
+    use crate::ie::create_far::CreateFar;
+    use crate::ie::create_pdr::CreatePdr;
+    use crate::ie::destination_interface::Interface;
+    use crate::ie::far_id::FarId;
+    use crate::ie::pdi::Pdi;
+    use crate::ie::pdr_id::PdrId;
+    use crate::ie::precedence::Precedence;
+    use crate::ie::source_interface::{SourceInterface, SourceInterfaceValue};
+    use crate::message::heartbeat_request::HeartbeatRequestBuilder;
+    use crate::message::session_deletion_request::SessionDeletionRequestBuilder;
+    use crate::message::session_establishment_request::{
+        SessionEstablishmentRequest, SessionEstablishmentRequestBuilder,
+    };
+    use crate::message::{parse, SessionEstablishmentResponse};
+    use std::net::Ipv4Addr;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn factory() -> MessageFactory {
+        MessageFactory::new(
+            NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 2)),
+            UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+        )
+    }
+
+    fn establishment_request(cp_seid: u64) -> SessionEstablishmentRequest {
+        let pdi = Pdi {
+            source_interface: SourceInterface::new(SourceInterfaceValue::Access),
+            f_teid: None,
+            network_instance: None,
+            ue_ip_address: None,
+            sdf_filter: None,
+            application_id: None,
+            ethernet_packet_filter: None,
+        };
+        let pdr = CreatePdr::new(
+            PdrId::new(1),
+            Precedence::new(100),
+            pdi,
+            None,
+            Some(FarId::new(1)),
+            None,
+            None,
+            None,
+        );
+        let far = CreateFar::builder(FarId::new(1))
+            .forward_to(Interface::Core)
+            .build()
+            .unwrap();
+
+        SessionEstablishmentRequestBuilder::new(0, 7)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(cp_seid, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs([pdr])
+            .create_fars([far])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_heartbeat_response() {
+        let request = HeartbeatRequestBuilder::new(0x123456)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+        let response = factory()
+            .create_response(&request, CauseValue::RequestAccepted)
+            .unwrap();
+        assert_eq!(response.msg_type(), MsgType::HeartbeatResponse);
+        assert_eq!(response.sequence(), request.sequence());
+        assert_eq!(response.seid(), None);
+    }
+
+    #[test]
+    fn test_session_establishment_response() {
+        let request = establishment_request(0xABCD);
+        let factory = factory().first_seid(0x1000);
+
+        let response = factory
+            .create_response(&request, CauseValue::RequestAccepted)
+            .unwrap();
+        assert_eq!(response.msg_type(), MsgType::SessionEstablishmentResponse);
+        assert_eq!(response.sequence(), request.sequence());
+        assert_eq!(response.seid(), Some(Seid(0xABCD)));
+
+        let response = SessionEstablishmentResponse::unmarshal(&response.marshal()).unwrap();
+        assert_eq!(
+            response.cause().unwrap(),
+            Cause::new(CauseValue::RequestAccepted)
+        );
+        assert_eq!(
+            response.node_id().unwrap(),
+            NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 2))
+        );
+        assert_eq!(
+            response.fseid().unwrap().unwrap(),
+            Fseid::new(0x1000, Some(Ipv4Addr::new(10, 0, 0, 2)), None)
+        );
+
+        // Each establishment is given a new UP SEID
+        let next = factory
+            .create_response(&request, CauseValue::RequestAccepted)
+            .unwrap();
+        let next = SessionEstablishmentResponse::unmarshal(&next.marshal()).unwrap();
+        assert_eq!(next.fseid().unwrap().unwrap().seid, Seid(0x1001));
+    }
+
+    #[test]
+    fn test_session_establishment_rejection_allocates_no_seid() {
+        let request = establishment_request(0xABCD);
+        let factory = factory().first_seid(0x1000);
+
+        let response = factory
+            .create_response(&request, CauseValue::NoResourcesAvailable)
+            .unwrap();
+        assert_eq!(response.seid(), Some(Seid(0xABCD)));
+        assert_eq!(response.ies(IeType::Fseid).count(), 0);
+
+        // A rejection needs no F-SEID address either
+        let fqdn = MessageFactory::new(NodeId::new_fqdn("upf.example.com"), SystemTime::now());
+        assert!(fqdn
+            .create_response(&request, CauseValue::RequestRejected)
+            .is_ok());
+
+        // The rejection did not consume a SEID
+        let accepted = factory
+            .create_response(&request, CauseValue::RequestAccepted)
+            .unwrap();
+        let accepted = SessionEstablishmentResponse::unmarshal(&accepted.marshal()).unwrap();
+        assert_eq!(accepted.fseid().unwrap().unwrap().seid, Seid(0x1000));
+    }
+
+    #[test]
+    fn test_session_establishment_fqdn_node_id_needs_address() {
+        let request = establishment_request(1);
+        let factory = MessageFactory::new(NodeId::new_fqdn("upf.example.com"), SystemTime::now());
+        let result = factory.create_response(&request, CauseValue::RequestAccepted);
+        assert!(matches!(result, Err(PfcpError::ValidationError { .. })));
+
+        let factory = factory.fseid_address(Ipv4Addr::new(10, 0, 0, 2));
+        assert!(factory
+            .create_response(&request, CauseValue::RequestAccepted)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_session_response_uses_peer_seid() {
+        let request = SessionDeletionRequestBuilder::new(0x55, 9).build();
+        let result = factory().create_response(&request, CauseValue::RequestAccepted);
+        assert!(matches!(result, Err(PfcpError::ValidationError { .. })));
+
+        let response = factory()
+            .create_session_response(&request, 0xAA, CauseValue::SessionContextNotFound)
+            .unwrap();
+        assert_eq!(response.msg_type(), MsgType::SessionDeletionResponse);
+        assert_eq!(response.sequence(), request.sequence());
+        assert_eq!(response.seid(), Some(Seid(0xAA)));
+
+        let parsed = parse(&response.marshal()).unwrap();
+        assert_eq!(parsed.msg_type(), MsgType::SessionDeletionResponse);
+    }
+
+    #[test]
+    fn test_response_message_is_rejected() {
+        let request = HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+        let response = factory()
+            .create_response(&request, CauseValue::RequestAccepted)
+            .unwrap();
+        let result = factory().create_response(response.as_ref(), CauseValue::RequestAccepted);
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+
+        let result = factory().create_session_response(&request, 1, CauseValue::RequestAccepted);
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_typed_responses_match_request_sequence() {
+        use crate::message::session_modification_request::SessionModificationRequestBuilder;
+        use crate::message::session_report_request::SessionReportRequestBuilder;
+
+        let request = HeartbeatRequestBuilder::new(0x010203)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+        let response = heartbeat_response_for(&request)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+        assert_eq!(response.sequence(), request.sequence());
+
+        let request = establishment_request(0xABCD);
+        let response = session_establishment_response_for(&request, CauseValue::RequestAccepted)
+            .unwrap()
+            .node_id(Ipv4Addr::new(10, 0, 0, 2))
+            .fseid(0x1000, Ipv4Addr::new(10, 0, 0, 2))
+            .build()
+            .unwrap();
+        assert_eq!(response.sequence(), request.sequence());
+        assert_eq!(response.seid(), Some(Seid(0xABCD)));
+
+        let request = SessionModificationRequestBuilder::new(0x1000, 11).build();
+        let response = session_modification_response_for(&request, 0xABCD)
+            .cause(CauseValue::RequestAccepted)
+            .build();
+        assert_eq!(response.sequence(), request.sequence());
+        assert_eq!(response.seid(), Some(Seid(0xABCD)));
+
+        let request = SessionDeletionRequestBuilder::new(0x1000, 12).build();
+        let response = session_deletion_response_for(&request, 0xABCD)
+            .cause(CauseValue::RequestAccepted)
+            .build();
+        assert_eq!(response.sequence(), request.sequence());
+        assert_eq!(response.seid(), Some(Seid(0xABCD)));
+
+        let request = SessionReportRequestBuilder::new(0xABCD, 13).build();
+        let response = session_report_response_for(&request, 0x1000, CauseValue::RequestAccepted)
+            .build()
+            .unwrap();
+        assert_eq!(response.sequence(), request.sequence());
+        assert_eq!(response.seid(), Some(Seid(0x1000)));
+    }
+}
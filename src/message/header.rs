@@ -1,6 +1,7 @@
 //! PFCP message header.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::message::MsgType;
 use crate::types::{Seid, SequenceNumber};
 
@@ -8,7 +9,9 @@ use crate::types::{Seid, SequenceNumber};
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Header {
     pub version: u8,
-    pub has_fo: bool, // Follow-on
+    /// FO (Follow On) flag: set when another PFCP message follows this one in
+    /// the same UDP datagram (PFCP message bundling, 3GPP TS 29.244 Section 6.6).
+    pub has_fo: bool,
     pub has_mp: bool, // Message Priority
     pub has_seid: bool,
     pub message_type: MsgType,
@@ -48,6 +51,38 @@ impl Header {
         length
     }
 
+    /// Sets the Length field for a message carrying `ies_len` octets of IEs.
+    ///
+    /// The Length field excludes the first 4 octets of the header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in the 16-bit Length field; see
+    /// [`try_set_body_length`](Header::try_set_body_length).
+    pub(crate) fn set_body_length(&mut self, ies_len: usize) {
+        if let Err(e) = self.try_set_body_length(ies_len) {
+            panic!("{e}");
+        }
+    }
+
+    /// Sets the Length field for a message carrying `ies_len` octets of IEs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PfcpError::InvalidValue`] if the message would be longer than
+    /// the 65535 octets the Length field can describe.
+    pub(crate) fn try_set_body_length(&mut self, ies_len: usize) -> Result<(), PfcpError> {
+        let length = ies_len + usize::from(self.len()) - 4;
+        self.length = u16::try_from(length).map_err(|_| {
+            PfcpError::invalid_value(
+                "Message Length",
+                length.to_string(),
+                "exceeds the 65535 octets the PFCP header Length field can carry",
+            )
+        })?;
+        Ok(())
+    }
+
     /// Reports whether a Header is empty.
     pub fn is_empty(&self) -> bool {
         self.length == 0
@@ -79,9 +114,23 @@ impl Header {
     /// assert_eq!(buf.len(), header.len() as usize);
     /// ```
     pub fn marshal_into(&self, buf: &mut Vec<u8>) {
-        let start = buf.len();
-        buf.resize(start + self.len() as usize, 0);
-        self.marshal_to(&mut buf[start..]);
+        self.write_into(buf);
+    }
+
+    /// Serializes the Header into a [`bytes::BytesMut`] buffer.
+    ///
+    /// Equivalent to [`marshal_into`](Header::marshal_into). Requires the `bytes` feature.
+    #[cfg(feature = "bytes")]
+    pub fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
+
+    pub(crate) fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        // Version/flags, type, length, optional SEID, sequence and priority.
+        let mut b = [0u8; 16];
+        let len = self.len() as usize;
+        self.marshal_to(&mut b[..len]);
+        buf.write_slice(&b[..len]);
     }
 
     /// Serializes the Header into a byte slice.
@@ -107,6 +156,13 @@ impl Header {
     }
 
     /// Deserializes a byte slice into a Header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slice is too short for the header, or if the
+    /// version is not 1 ([`PfcpError::UnsupportedVersion`]). A Version
+    /// Not Supported Response is exempt, since its version field carries the
+    /// highest version the sender supports.
     pub fn unmarshal(b: &[u8]) -> Result<Self, PfcpError> {
         if b.len() < 8 {
             return Err(PfcpError::MessageParseError {
@@ -120,6 +176,9 @@ impl Header {
 
         let flags = b[0];
         let version = flags >> 5;
+        if version != 1 && b[1] != MsgType::VersionNotSupportedResponse as u8 {
+            return Err(PfcpError::unsupported_version(version));
+        }
         let has_fo = (flags & 0x04) >> 2 == 1;
         let has_mp = (flags & 0x02) >> 1 == 1;
         let has_seid = (flags & 0x01) == 1;
@@ -185,3 +244,32 @@ impl Header {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_unmarshal_version_1() {
+        let data = [0x20, 0x01, 0x00, 0x04, 0x00, 0x00, 0x07, 0x00];
+        let header = Header::unmarshal(&data).unwrap();
+        assert_eq!(header.version, 1);
+        assert_eq!(header.message_type, MsgType::HeartbeatRequest);
+        assert_eq!(*header.sequence_number, 7);
+    }
+
+    #[test]
+    fn test_header_unmarshal_rejects_other_versions() {
+        for version in [0u8, 2, 7] {
+            let data = [version << 5, 0x01, 0x00, 0x04, 0x00, 0x00, 0x07, 0x00];
+            let err = Header::unmarshal(&data).unwrap_err();
+            assert_eq!(err.unsupported_version_number(), Some(version));
+        }
+
+        // Version Not Supported Response advertises the sender's version
+        let data = [2 << 5, 11, 0x00, 0x04, 0x00, 0x00, 0x07, 0x00];
+        let header = Header::unmarshal(&data).unwrap();
+        assert_eq!(header.version, 2);
+        assert_eq!(header.message_type, MsgType::VersionNotSupportedResponse);
+    }
+}
@@ -1,6 +1,7 @@
 //! Heartbeat Request message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -12,28 +13,20 @@ pub struct HeartbeatRequest {
     recovery_time_stamp: Ie, // M - 3GPP TS 29.244 Table 7.4.2.1-1 - IE Type 96
     source_ip_address: Option<Ie>, // O - 3GPP TS 29.244 Table 7.4.2.1-1 - IE Type 192 - When NAT is deployed
     ies: Vec<Ie>,
+    extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl HeartbeatRequest {
     /// Creates a new Heartbeat Request message.
+    #[deprecated(since = "0.4.0", note = "use `HeartbeatRequestBuilder` instead")]
     pub fn new(seq: impl Into<SequenceNumber>, ts: Ie, ip: Option<Ie>, ies: Vec<Ie>) -> Self {
-        let mut payload_len = ts.len();
-        if let Some(ref ie) = ip {
-            payload_len += ie.len();
-        }
-        for ie in &ies {
-            payload_len += ie.len();
-        }
-
-        let mut header = Header::new(MsgType::HeartbeatRequest, false, 0, seq);
-        header.length = 4 + payload_len;
-
-        HeartbeatRequest {
-            header,
-            recovery_time_stamp: ts,
-            source_ip_address: ip,
-            ies,
+        let mut builder = HeartbeatRequestBuilder::new(seq)
+            .recovery_time_stamp_ie(ts)
+            .ies(ies);
+        if let Some(ip) = ip {
+            builder = builder.source_ip_address_ie(ip);
         }
+        builder.build()
     }
 
     // Typed accessors (recommended API)
@@ -100,6 +93,18 @@ impl HeartbeatRequest {
     pub fn source_ip_address_ie(&self) -> Option<&Ie> {
         self.source_ip_address.as_ref()
     }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.recovery_time_stamp.write_into(buf);
+        if let Some(ref ie) = self.source_ip_address {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
+    }
 }
 
 impl Message for HeartbeatRequest {
@@ -110,25 +115,22 @@ impl Message for HeartbeatRequest {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.recovery_time_stamp.marshal_into(buf);
-        if let Some(ref ie) = self.source_ip_address {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.recovery_time_stamp.len() as usize;
+        size += self.recovery_time_stamp.encoded_size();
         if let Some(ref ie) = self.source_ip_address {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -142,7 +144,7 @@ impl Message for HeartbeatRequest {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::RecoveryTimeStamp => recovery_time_stamp = Some(ie),
                 IeType::SourceIpAddress => source_ip_address = Some(ie),
@@ -158,12 +160,15 @@ impl Message for HeartbeatRequest {
             parent_ie: None,
         })?;
 
-        Ok(HeartbeatRequest {
+        let mut message = HeartbeatRequest {
             header,
             recovery_time_stamp,
             source_ip_address,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -196,6 +201,10 @@ impl Message for HeartbeatRequest {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = Vec::new();
         result.push(&self.recovery_time_stamp);
@@ -214,6 +223,7 @@ pub struct HeartbeatRequestBuilder {
     recovery_time_stamp: Option<Ie>,
     source_ip_address: Option<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl HeartbeatRequestBuilder {
@@ -224,6 +234,7 @@ impl HeartbeatRequestBuilder {
             recovery_time_stamp: None,
             source_ip_address: None,
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -288,8 +299,8 @@ impl HeartbeatRequestBuilder {
         use crate::ie::source_ip_address::SourceIpAddress;
         let ip_addr = ip_addr.into();
         let source_ip = match ip_addr {
-            std::net::IpAddr::V4(v4) => SourceIpAddress::new(Some(v4), None),
-            std::net::IpAddr::V6(v6) => SourceIpAddress::new(None, Some(v6)),
+            std::net::IpAddr::V4(v4) => SourceIpAddress::ipv4(v4),
+            std::net::IpAddr::V6(v6) => SourceIpAddress::ipv6(v6),
         };
         self.source_ip_address = Some(source_ip.to_ie());
         self
@@ -318,22 +329,62 @@ impl HeartbeatRequestBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
     /// Builds the HeartbeatRequest message.
     ///
     /// # Panics
     ///
-    /// Panics if the mandatory recovery_time_stamp is not set.
+    /// Panics if the mandatory recovery_time_stamp is not set, or if the
+    /// message would not fit in the header Length field; see [`try_build`].
     /// Per 3GPP TS 29.244 Table 7.4.2.1-1, Recovery Time Stamp is mandatory.
+    ///
+    /// [`try_build`]: #method.try_build
     pub fn build(self) -> HeartbeatRequest {
-        let recovery_time_stamp = self.recovery_time_stamp.expect(
+        assert!(
+            self.recovery_time_stamp.is_some(),
             "HeartbeatRequest requires recovery_time_stamp (mandatory per 3GPP TS 29.244 Table 7.4.2.1-1)"
         );
-        HeartbeatRequest::new(
-            self.sequence,
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Tries to build the HeartbeatRequest message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PfcpError::MissingMandatoryIe`] if the recovery_time_stamp is
+    /// not set, or [`PfcpError::InvalidValue`] if the IEs add up to more than
+    /// the 65535 octets the header Length field can carry.
+    pub fn try_build(self) -> Result<HeartbeatRequest, PfcpError> {
+        let recovery_time_stamp = self.recovery_time_stamp.ok_or_else(|| {
+            PfcpError::missing_ie_in_message(IeType::RecoveryTimeStamp, MsgType::HeartbeatRequest)
+        })?;
+
+        let mut payload_len = recovery_time_stamp.encoded_size();
+        if let Some(ref ie) = self.source_ip_address {
+            payload_len += ie.encoded_size();
+        }
+        for ie in &self.ies {
+            payload_len += ie.encoded_size();
+        }
+
+        let mut header = Header::new(MsgType::HeartbeatRequest, false, 0, self.sequence);
+        header.try_set_body_length(payload_len)?;
+        header.has_fo = self.follow_on;
+
+        let mut message = HeartbeatRequest {
+            header,
             recovery_time_stamp,
-            self.source_ip_address,
-            self.ies,
-        )
+            source_ip_address: self.source_ip_address,
+            ies: self.ies,
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     /// Builds the HeartbeatRequest message and marshals it to bytes in one step.
@@ -395,7 +446,7 @@ mod tests {
 
     #[test]
     fn test_heartbeat_request_builder_with_source_ip() {
-        let ip = SourceIpAddress::new(Some(Ipv4Addr::new(192, 168, 1, 1)), None);
+        let ip = SourceIpAddress::ipv4(Ipv4Addr::new(192, 168, 1, 1));
         let ip_ie = Ie::new(IeType::SourceIpAddress, ip.marshal());
 
         let request = HeartbeatRequestBuilder::new(12345)
@@ -417,7 +468,7 @@ mod tests {
         let recovery_ts = RecoveryTimeStamp::new(timestamp);
         let recovery_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
 
-        let ip = SourceIpAddress::new(Some(Ipv4Addr::new(192, 168, 1, 1)), None);
+        let ip = SourceIpAddress::ipv4(Ipv4Addr::new(192, 168, 1, 1));
         let ip_ie = Ie::new(IeType::SourceIpAddress, ip.marshal());
 
         let additional_ie = Ie::new(IeType::Unknown, vec![0x01, 0x02, 0x03]);
@@ -859,4 +910,128 @@ mod tests {
         // Builder should panic if recovery_time_stamp is not set
         HeartbeatRequestBuilder::new(18000).build();
     }
+
+    #[test]
+    fn test_try_build_without_mandatory_field() {
+        let err = HeartbeatRequestBuilder::new(18000).try_build().unwrap_err();
+        assert!(matches!(
+            err,
+            PfcpError::MissingMandatoryIe {
+                ie_type: IeType::RecoveryTimeStamp,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_try_build_rejects_message_over_length_field() {
+        // Length = 4 (sequence) + 8 (Recovery Time Stamp IE) + 4 (IE header) + payload,
+        // so a 65519-octet payload fills the Length field exactly.
+        let fits = HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(SystemTime::now())
+            .ie(Ie::new(IeType::Unknown, vec![0; 65519]))
+            .try_build()
+            .unwrap();
+        assert_eq!(fits.marshal().len(), 65539);
+        assert_eq!(fits.header.length, u16::MAX);
+
+        let err = HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(SystemTime::now())
+            .ie(Ie::new(IeType::Unknown, vec![0; 65520]))
+            .try_build()
+            .unwrap_err();
+        assert!(matches!(err, PfcpError::InvalidValue { ref value, .. } if value == "65536"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Message Length")]
+    fn test_build_panics_on_message_over_length_field() {
+        HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(SystemTime::now())
+            .ie(Ie::new(IeType::Unknown, vec![0; 65520]))
+            .build();
+    }
+
+    #[test]
+    fn test_parse_tolerates_unknown_ie_type() {
+        let mut bytes = HeartbeatRequestBuilder::new(19000)
+            .recovery_time_stamp(SystemTime::now())
+            .marshal();
+
+        // Inject an IE of type 9999, which is not assigned by 3GPP TS 29.244
+        bytes.extend_from_slice(&[0x27, 0x0F, 0x00, 0x02, 0xDE, 0xAD]);
+        let length = (bytes.len() - 4) as u16;
+        bytes[2..4].copy_from_slice(&length.to_be_bytes());
+
+        let msg = crate::message::parse(&bytes).unwrap();
+        assert_eq!(msg.msg_type(), MsgType::HeartbeatRequest);
+        assert_eq!(msg.sequence(), SequenceNumber::new(19000));
+
+        let unknown = msg.unknown_ies();
+        assert_eq!(unknown.len(), 1);
+        assert!(unknown[0].is_unknown());
+        assert_eq!(unknown[0].payload, vec![0xDE, 0xAD]);
+        assert!(msg.ies(IeType::RecoveryTimeStamp).next().is_some());
+    }
+
+    #[test]
+    fn test_unknown_ies_skips_ies_with_a_field() {
+        let unknown = Ie::new(IeType::Unknown, vec![0xDE, 0xAD]);
+        let create_pdr = Ie::new(IeType::CreatePdr, vec![]);
+        let source_ip = SourceIpAddress::ipv4(Ipv4Addr::new(10, 0, 0, 1)).to_ie();
+        let msg = HeartbeatRequestBuilder::new(19500)
+            .recovery_time_stamp(SystemTime::now())
+            .ie(source_ip)
+            .ie(unknown.clone())
+            .ie(create_pdr.clone())
+            .build();
+
+        // Source IP Address is a field of Heartbeat Request; the other two are not
+        assert_eq!(msg.unknown_ies(), [unknown, create_pdr]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_new_matches_builder_wire_bytes() {
+        let ts = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let ts_ie = RecoveryTimeStamp::new(ts).to_ie();
+        let ip_ie = SourceIpAddress::ipv4(Ipv4Addr::new(10, 0, 0, 1)).to_ie();
+        let extra_ie = Ie::new(IeType::Unknown, vec![0x01, 0x02]);
+
+        let minimal_new = HeartbeatRequest::new(7, ts_ie.clone(), None, vec![]);
+        let minimal_builder = HeartbeatRequestBuilder::new(7)
+            .recovery_time_stamp(ts)
+            .build();
+        assert_eq!(minimal_new.marshal(), minimal_builder.marshal());
+
+        let full_new = HeartbeatRequest::new(
+            8,
+            ts_ie.clone(),
+            Some(ip_ie.clone()),
+            vec![extra_ie.clone()],
+        );
+        let full_builder = HeartbeatRequestBuilder::new(8)
+            .recovery_time_stamp_ie(ts_ie)
+            .source_ip_address(Ipv4Addr::new(10, 0, 0, 1))
+            .ie(extra_ie)
+            .build();
+        assert_eq!(full_new, full_builder);
+        assert_eq!(full_new.marshal(), full_builder.marshal());
+    }
+
+    #[test]
+    fn test_follow_on_sets_header_flag() {
+        let msg = HeartbeatRequestBuilder::new(20000)
+            .recovery_time_stamp(SystemTime::now())
+            .follow_on(true)
+            .build();
+        let parsed = HeartbeatRequest::unmarshal(&msg.marshal()).unwrap();
+        assert!(parsed.header.has_fo);
+        assert_eq!(parsed, msg);
+
+        let msg = HeartbeatRequestBuilder::new(20001)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+        assert!(!msg.header.has_fo);
+    }
 }
@@ -1,6 +1,7 @@
 //! Heartbeat Response message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -11,24 +12,32 @@ pub struct HeartbeatResponse {
     header: Header,
     recovery_time_stamp: Ie, // M - 3GPP TS 29.244 Table 7.4.2.2-1 - IE Type 96
     ies: Vec<Ie>,
+    extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl HeartbeatResponse {
     /// Creates a new Heartbeat Response message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(seq: impl Into<SequenceNumber>, ts: Ie, ies: Vec<Ie>) -> Self {
-        let mut payload_len = ts.len();
+        let mut payload_len = ts.encoded_size();
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::HeartbeatResponse, false, 0, seq);
-        header.length = 4 + payload_len;
+        header.set_body_length(payload_len);
 
-        HeartbeatResponse {
+        let mut message = HeartbeatResponse {
             header,
             recovery_time_stamp: ts,
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
     }
 
     // Typed accessors (recommended API)
@@ -66,6 +75,15 @@ impl HeartbeatResponse {
     pub fn recovery_time_stamp_ie(&self) -> &Ie {
         &self.recovery_time_stamp
     }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.recovery_time_stamp.write_into(buf);
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
+    }
 }
 
 impl Message for HeartbeatResponse {
@@ -76,19 +94,19 @@ impl Message for HeartbeatResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.recovery_time_stamp.marshal_into(buf);
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.recovery_time_stamp.len() as usize;
+        size += self.recovery_time_stamp.encoded_size();
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -101,7 +119,7 @@ impl Message for HeartbeatResponse {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::RecoveryTimeStamp => recovery_time_stamp = Some(ie),
                 _ => ies.push(ie),
@@ -116,11 +134,14 @@ impl Message for HeartbeatResponse {
             parent_ie: None,
         })?;
 
-        Ok(HeartbeatResponse {
+        let mut message = HeartbeatResponse {
             header,
             recovery_time_stamp,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -152,6 +173,10 @@ impl Message for HeartbeatResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = Vec::new();
         result.push(&self.recovery_time_stamp);
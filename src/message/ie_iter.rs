@@ -38,24 +38,40 @@
 //! # }
 //! ```
 
+use crate::error::PfcpError;
+use crate::ie::create_far::CreateFar;
+use crate::ie::create_pdr::CreatePdr;
+use crate::ie::create_qer::CreateQer;
+use crate::ie::create_urr::CreateUrr;
+use crate::ie::created_pdr::CreatedPdr;
+use crate::ie::update_far::UpdateFar;
+use crate::ie::update_pdr::UpdatePdr;
+use crate::ie::update_qer::UpdateQer;
+use crate::ie::update_urr::UpdateUrr;
 use crate::ie::Ie;
 use crate::ie::IeType;
+use crate::ie::ParseIe;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
 
 /// Iterator over Information Elements of a specific type in a message.
 ///
 /// This iterator provides a unified way to access IEs regardless of their storage pattern
 /// (single mandatory, optional, or multiple). It's zero-cost and optimizes to direct access.
 ///
+/// The number of matching IEs is always known up front, so [`Iterator::size_hint`]
+/// is exact and `collect()` allocates once.
+///
 /// # Examples
 ///
 /// ```rust
-/// use rs_pfcp::message::{Message, HeartbeatRequest};
-/// use rs_pfcp::ie::{Ie, IeType, recovery_time_stamp::RecoveryTimeStamp};
+/// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequestBuilder};
+/// use rs_pfcp::ie::IeType;
 /// use std::time::SystemTime;
 ///
-/// let recovery_ts = RecoveryTimeStamp::new(SystemTime::now());
-/// let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-/// let request = HeartbeatRequest::new(123, ts_ie, None, vec![]);
+/// let request = HeartbeatRequestBuilder::new(123)
+///     .recovery_time_stamp(SystemTime::now())
+///     .build();
 ///
 /// // Iterate over all Recovery Time Stamp IEs (there's one)
 /// let mut iter = request.ies(IeType::RecoveryTimeStamp);
@@ -86,8 +102,13 @@ enum IeIterState<'a> {
     /// Generic fallback: searching through all IEs.
     ///
     /// This variant is used when the specific IE storage location is unknown.
-    /// It searches through a slice of all IEs in the message.
-    Generic { all_ies: &'a [Ie], position: usize },
+    /// It searches through a slice of all IEs in the message. `remaining` is
+    /// the number of matches not yet yielded, counted at construction.
+    Generic {
+        all_ies: &'a [Ie],
+        position: usize,
+        remaining: usize,
+    },
 }
 
 impl<'a> Iterator for IeIter<'a> {
@@ -97,11 +118,19 @@ impl<'a> Iterator for IeIter<'a> {
         match &mut self.state {
             IeIterState::Single(opt) => opt.take(),
             IeIterState::Multiple(iter) => iter.next(),
-            IeIterState::Generic { all_ies, position } => {
+            IeIterState::Generic {
+                all_ies,
+                position,
+                remaining,
+            } => {
+                if *remaining == 0 {
+                    return None;
+                }
                 while *position < all_ies.len() {
                     let ie = &all_ies[*position];
                     *position += 1;
                     if ie.ie_type == self.ie_type {
+                        *remaining -= 1;
                         return Some(ie);
                     }
                 }
@@ -115,15 +144,17 @@ impl<'a> Iterator for IeIter<'a> {
             IeIterState::Single(Some(_)) => (1, Some(1)),
             IeIterState::Single(None) => (0, Some(0)),
             IeIterState::Multiple(iter) => iter.size_hint(),
-            IeIterState::Generic { all_ies, position } => {
-                // We don't know how many match, so give conservative bounds
-                let remaining = all_ies.len() - *position;
-                (0, Some(remaining))
-            }
+            IeIterState::Generic { remaining, .. } => (*remaining, Some(*remaining)),
         }
     }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
 }
 
+impl FusedIterator for IeIter<'_> {}
+
 impl<'a> IeIter<'a> {
     /// Create an iterator for a single IE (mandatory or optional).
     ///
@@ -153,6 +184,10 @@ impl<'a> IeIter<'a> {
 
     /// Create an iterator for multiple IEs (vector).
     ///
+    /// `ies` must already hold only IEs of `ie_type`, as the typed `Vec<Ie>`
+    /// fields of a message do. Iteration walks the slice directly without
+    /// comparing IE types, unlike [`generic`](Self::generic).
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -198,16 +233,140 @@ impl<'a> IeIter<'a> {
     /// assert_eq!(causes.len(), 2);
     /// ```
     pub fn generic(all_ies: &'a [Ie], ie_type: IeType) -> Self {
+        let remaining = all_ies.iter().filter(|ie| ie.ie_type == ie_type).count();
         IeIter {
             ie_type,
             state: IeIterState::Generic {
                 all_ies,
                 position: 0,
+                remaining,
             },
         }
     }
+
+    /// Reports whether the iterator scans a message's additional IEs, i.e. the
+    /// message has no field for this IE type.
+    pub(crate) fn is_fallback(&self) -> bool {
+        matches!(self.state, IeIterState::Generic { .. })
+    }
+
+    /// Parse each IE into a typed value as it is yielded.
+    ///
+    /// Every IE is decoded with [`Ie::parse`], so a malformed IE produces an
+    /// `Err` item instead of being silently dropped. Use [`Iterator::flatten`]
+    /// to skip failures or `collect::<Result<Vec<_>, _>>()` to stop at the
+    /// first one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rs_pfcp::message::ie_iter::IeIter;
+    /// use rs_pfcp::ie::{Ie, IeType};
+    /// use rs_pfcp::ie::cause::{Cause, CauseValue};
+    ///
+    /// let ies = vec![Ie::new(IeType::Cause, vec![1]), Ie::new(IeType::Cause, vec![])];
+    ///
+    /// let causes: Vec<_> = IeIter::multiple(&ies, IeType::Cause)
+    ///     .parse_as::<Cause>()
+    ///     .collect();
+    /// assert_eq!(causes[0].as_ref().unwrap().value, CauseValue::RequestAccepted);
+    /// assert!(causes[1].is_err());
+    /// ```
+    pub fn parse_as<T: ParseIe>(self) -> TypedIeIter<'a, T> {
+        TypedIeIter {
+            inner: self,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Parse each IE as a [`CreatePdr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rs_pfcp::message::{Message, SessionEstablishmentRequest};
+    /// use rs_pfcp::ie::IeType;
+    ///
+    /// # fn example(msg: &SessionEstablishmentRequest) -> Result<(), rs_pfcp::error::PfcpError> {
+    /// for pdr in msg.ies(IeType::CreatePdr).as_create_pdrs() {
+    ///     println!("PDR {}", pdr?.pdr_id.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_create_pdrs(self) -> TypedIeIter<'a, CreatePdr> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as a [`CreateFar`].
+    pub fn as_create_fars(self) -> TypedIeIter<'a, CreateFar> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as a [`CreateUrr`].
+    pub fn as_create_urrs(self) -> TypedIeIter<'a, CreateUrr> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as a [`CreateQer`].
+    pub fn as_create_qers(self) -> TypedIeIter<'a, CreateQer> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as a [`CreatedPdr`].
+    pub fn as_created_pdrs(self) -> TypedIeIter<'a, CreatedPdr> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as an [`UpdatePdr`].
+    pub fn as_update_pdrs(self) -> TypedIeIter<'a, UpdatePdr> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as an [`UpdateFar`].
+    pub fn as_update_fars(self) -> TypedIeIter<'a, UpdateFar> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as an [`UpdateUrr`].
+    pub fn as_update_urrs(self) -> TypedIeIter<'a, UpdateUrr> {
+        self.parse_as()
+    }
+
+    /// Parse each IE as an [`UpdateQer`].
+    pub fn as_update_qers(self) -> TypedIeIter<'a, UpdateQer> {
+        self.parse_as()
+    }
+}
+
+/// Iterator that parses each IE of an [`IeIter`] into `T`.
+///
+/// Created by [`IeIter::parse_as`] and the `as_*` shorthands. It yields one
+/// `Result` per underlying IE, so its length always matches the raw iterator.
+#[derive(Debug)]
+pub struct TypedIeIter<'a, T> {
+    inner: IeIter<'a>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: ParseIe> Iterator for TypedIeIter<'_, T> {
+    type Item = Result<T, PfcpError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Ie::parse::<T>)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
 }
 
+impl<T: ParseIe> FusedIterator for TypedIeIter<'_, T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -348,4 +507,122 @@ mod tests {
 
         assert_eq!(sum, 30);
     }
+
+    #[test]
+    fn test_size_hint_generic_is_exact() {
+        let ies = vec![
+            Ie::new(IeType::Cause, vec![1]),
+            Ie::new(IeType::CreatePdr, vec![2]),
+            Ie::new(IeType::Cause, vec![3]),
+        ];
+
+        let mut iter = IeIter::generic(&ies, IeType::Cause);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_size_hint_matches_count_for_message_pdrs() {
+        use crate::ie::create_far::CreateFar;
+        use crate::ie::create_pdr::CreatePdr;
+        use crate::ie::destination_interface::Interface;
+        use crate::ie::far_id::FarId;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+        use crate::message::session_establishment_request::SessionEstablishmentRequestBuilder;
+        use crate::message::Message;
+        use std::net::Ipv4Addr;
+
+        let pdrs: Vec<_> = (1..=3)
+            .map(|i| CreatePdr::uplink_access(PdrId::new(i), Precedence::new(100)).to_ie())
+            .collect();
+        let msg = SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(pdrs)
+            .add_far(CreateFar::uplink_forward(FarId::new(1), Interface::Core))
+            .ies(vec![
+                Ie::new(IeType::Unknown, vec![1]),
+                Ie::new(IeType::Unknown, vec![2]),
+                Ie::new(IeType::Unknown, vec![3]),
+            ])
+            .build()
+            .unwrap();
+
+        // Create PDRs are served straight from their own slice, not a scan
+        let iter = msg.ies(IeType::CreatePdr);
+        assert!(matches!(iter.state, IeIterState::Multiple(_)));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.size_hint().0, msg.ies(IeType::CreatePdr).count());
+
+        // Unknown IEs take the generic search path
+        let iter = msg.ies(IeType::Unknown);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.collect::<Vec<_>>().len(), 3);
+    }
+
+    #[test]
+    fn test_iterator_is_fused_and_peekable() {
+        let ies = vec![
+            Ie::new(IeType::CreatePdr, vec![1]),
+            Ie::new(IeType::CreatePdr, vec![2]),
+            Ie::new(IeType::CreatePdr, vec![3]),
+        ];
+
+        let mut iter = IeIter::multiple(&ies, IeType::CreatePdr).fuse().peekable();
+        assert_eq!(iter.peek().map(|ie| ie.payload[0]), Some(1));
+        let leading: Vec<_> = iter.by_ref().take_while(|ie| ie.payload[0] < 2).collect();
+        assert_eq!(leading.len(), 1);
+        assert_eq!(iter.next().map(|ie| ie.payload[0]), Some(3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_as_create_pdrs_parses_each_ie() {
+        use crate::ie::create_pdr::CreatePdr;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+
+        let ies = vec![
+            CreatePdr::uplink_access(PdrId::new(1), Precedence::new(100)).to_ie(),
+            CreatePdr::downlink_core(PdrId::new(2), Precedence::new(200)).to_ie(),
+        ];
+
+        let iter = IeIter::multiple(&ies, IeType::CreatePdr).as_create_pdrs();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        let pdrs: Vec<_> = iter.collect();
+        assert_eq!(pdrs.len(), 2);
+        assert_eq!(pdrs[0].as_ref().unwrap().pdr_id, PdrId::new(1));
+        assert_eq!(pdrs[1].as_ref().unwrap().pdr_id, PdrId::new(2));
+    }
+
+    #[test]
+    fn test_parse_as_reports_malformed_ies() {
+        use crate::ie::create_pdr::CreatePdr;
+        use crate::ie::pdr_id::PdrId;
+        use crate::ie::precedence::Precedence;
+
+        let ies = vec![
+            Ie::new(IeType::CreatePdr, vec![0x00, 0x38, 0x00]),
+            CreatePdr::uplink_access(PdrId::new(7), Precedence::new(10)).to_ie(),
+        ];
+
+        let results: Vec<_> = IeIter::generic(&ies, IeType::CreatePdr)
+            .parse_as::<CreatePdr>()
+            .collect();
+        assert!(results[0].is_err());
+        assert_eq!(results[1].as_ref().unwrap().pdr_id, PdrId::new(7));
+
+        let valid: Vec<CreatePdr> = IeIter::generic(&ies, IeType::CreatePdr)
+            .as_create_pdrs()
+            .flatten()
+            .collect();
+        assert_eq!(valid.len(), 1);
+    }
 }
@@ -6,7 +6,9 @@ pub mod association_setup_request;
 pub mod association_setup_response;
 pub mod association_update_request;
 pub mod association_update_response;
+pub mod clone_dyn;
 pub mod display;
+pub mod factory;
 pub mod header;
 pub mod heartbeat_request;
 pub mod heartbeat_response;
@@ -30,12 +32,22 @@ pub mod session_set_modification_response;
 pub mod version_not_supported_response;
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::Ie;
 use crate::types::{Seid, SequenceNumber};
 
 // Re-export IE iterator types for public API
 pub use ie_iter::IeIter;
 
+// Re-export the response factory for public API
+pub use factory::{
+    heartbeat_response_for, session_deletion_response_for, session_establishment_response_for,
+    session_modification_response_for, session_report_response_for, MessageFactory,
+};
+
+// Re-export boxed message cloning for public API
+pub use clone_dyn::DynClone;
+
 // Re-export message types for public API
 pub use crate::message::association_release_request::AssociationReleaseRequest;
 pub use crate::message::association_release_response::AssociationReleaseResponse;
@@ -128,11 +140,36 @@ impl From<u8> for MsgType {
     }
 }
 
+impl MsgType {
+    /// Returns the response type paired with a request type.
+    ///
+    /// Returns `None` for response types and [`MsgType::Unknown`].
+    pub fn response_type(self) -> Option<MsgType> {
+        match self {
+            MsgType::HeartbeatRequest => Some(MsgType::HeartbeatResponse),
+            MsgType::PfdManagementRequest => Some(MsgType::PfdManagementResponse),
+            MsgType::AssociationSetupRequest => Some(MsgType::AssociationSetupResponse),
+            MsgType::AssociationUpdateRequest => Some(MsgType::AssociationUpdateResponse),
+            MsgType::AssociationReleaseRequest => Some(MsgType::AssociationReleaseResponse),
+            MsgType::NodeReportRequest => Some(MsgType::NodeReportResponse),
+            MsgType::SessionSetDeletionRequest => Some(MsgType::SessionSetDeletionResponse),
+            MsgType::SessionSetModificationRequest => Some(MsgType::SessionSetModificationResponse),
+            MsgType::SessionEstablishmentRequest => Some(MsgType::SessionEstablishmentResponse),
+            MsgType::SessionModificationRequest => Some(MsgType::SessionModificationResponse),
+            MsgType::SessionDeletionRequest => Some(MsgType::SessionDeletionResponse),
+            MsgType::SessionReportRequest => Some(MsgType::SessionReportResponse),
+            _ => None,
+        }
+    }
+}
+
 /// A trait representing a PFCP message.
 ///
 /// All concrete message types implement `Send + Sync`, making `Box<dyn Message>`
 /// safe to use across thread boundaries and `.await` points in async runtimes.
-pub trait Message: Send + Sync {
+/// They are also `Clone`, and the [`DynClone`] supertrait extends that to
+/// `Box<dyn Message>` through [`clone_box`](DynClone::clone_box).
+pub trait Message: Send + Sync + DynClone {
     /// Marshals the message into a new `Vec<u8>`.
     ///
     /// This is the standard marshaling method that allocates a new vector.
@@ -141,13 +178,12 @@ pub trait Message: Send + Sync {
     /// # Examples
     ///
     /// ```
-    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequest};
-    /// use rs_pfcp::ie::{Ie, IeType, recovery_time_stamp::RecoveryTimeStamp};
+    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequestBuilder};
     /// use std::time::SystemTime;
     ///
-    /// let recovery_ts = RecoveryTimeStamp::new(SystemTime::now());
-    /// let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-    /// let request = HeartbeatRequest::new(123, ts_ie, None, vec![]);
+    /// let request = HeartbeatRequestBuilder::new(123)
+    ///     .recovery_time_stamp(SystemTime::now())
+    ///     .build();
     /// let bytes = request.marshal();
     /// ```
     fn marshal(&self) -> Vec<u8>;
@@ -165,13 +201,12 @@ pub trait Message: Send + Sync {
     /// # Examples
     ///
     /// ```
-    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequest};
-    /// use rs_pfcp::ie::{Ie, IeType, recovery_time_stamp::RecoveryTimeStamp};
+    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequestBuilder};
     /// use std::time::SystemTime;
     ///
-    /// let recovery_ts = RecoveryTimeStamp::new(SystemTime::now());
-    /// let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-    /// let request = HeartbeatRequest::new(123, ts_ie, None, vec![]);
+    /// let request = HeartbeatRequestBuilder::new(123)
+    ///     .recovery_time_stamp(SystemTime::now())
+    ///     .build();
     ///
     /// // Reuse buffer for multiple messages
     /// let mut buf = Vec::new();
@@ -185,6 +220,36 @@ pub trait Message: Send + Sync {
         buf.extend_from_slice(&self.marshal());
     }
 
+    /// Marshals the message into a [`bytes::BytesMut`] buffer.
+    ///
+    /// This is the `BytesMut` counterpart of [`marshal_into`](Message::marshal_into)
+    /// and produces identical bytes. Requires the `bytes` feature.
+    ///
+    /// # Performance Note
+    ///
+    /// The default implementation calls `marshal()` and extends the buffer.
+    /// Message types should override this to write directly into `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::BytesMut;
+    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequestBuilder};
+    /// use std::time::SystemTime;
+    ///
+    /// let request = HeartbeatRequestBuilder::new(123)
+    ///     .recovery_time_stamp(SystemTime::now())
+    ///     .build();
+    ///
+    /// let mut buf = BytesMut::with_capacity(request.marshaled_size());
+    /// request.write_to_bytes(&mut buf);
+    /// assert_eq!(&buf[..], &request.marshal()[..]);
+    /// ```
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        buf.extend_from_slice(&self.marshal());
+    }
+
     /// Returns the size of the marshaled message in bytes.
     ///
     /// This can be used to pre-allocate buffers of the correct size,
@@ -198,13 +263,12 @@ pub trait Message: Send + Sync {
     /// # Examples
     ///
     /// ```
-    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequest};
-    /// use rs_pfcp::ie::{Ie, IeType, recovery_time_stamp::RecoveryTimeStamp};
+    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequestBuilder};
     /// use std::time::SystemTime;
     ///
-    /// let recovery_ts = RecoveryTimeStamp::new(SystemTime::now());
-    /// let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-    /// let request = HeartbeatRequest::new(123, ts_ie, None, vec![]);
+    /// let request = HeartbeatRequestBuilder::new(123)
+    ///     .recovery_time_stamp(SystemTime::now())
+    ///     .build();
     ///
     /// // Pre-allocate exact size needed
     /// let size = request.marshaled_size();
@@ -285,25 +349,85 @@ pub trait Message: Send + Sync {
     /// # Examples
     ///
     /// ```
-    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequest};
-    /// use rs_pfcp::ie::{Ie, IeType, recovery_time_stamp::RecoveryTimeStamp};
+    /// use rs_pfcp::message::{Message, heartbeat_request::HeartbeatRequestBuilder};
     /// use std::time::SystemTime;
     ///
-    /// let recovery_ts = RecoveryTimeStamp::new(SystemTime::now());
-    /// let ts_ie = Ie::new(IeType::RecoveryTimeStamp, recovery_ts.marshal().to_vec());
-    /// let request = HeartbeatRequest::new(123, ts_ie, None, vec![]);
+    /// let request = HeartbeatRequestBuilder::new(123)
+    ///     .recovery_time_stamp(SystemTime::now())
+    ///     .build();
     /// let all_ies = request.all_ies();
     /// println!("Message contains {} IEs", all_ies.len());
     /// ```
     fn all_ies(&self) -> Vec<&Ie>;
+
+    /// Get the IEs that do not map to a field of this message's structure.
+    ///
+    /// Unmarshaling tolerates IEs it does not recognise, such as vendor extensions
+    /// or IE types newer than this library, and collects them here instead of
+    /// failing the parse. IEs whose type is unknown to the library can be singled
+    /// out with [`Ie::is_unknown`]. IEs of a type the message has a field for are
+    /// never returned, even when they were added through a builder's `ie` method.
+    ///
+    /// The IEs are set aside once, when the message is built or unmarshaled,
+    /// so later edits to a public `ies` field are not reflected here.
+    ///
+    /// The default implementation returns no IEs for messages that do not retain
+    /// additional IEs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rs_pfcp::message::{parse, Message, heartbeat_request::HeartbeatRequestBuilder};
+    /// use std::time::SystemTime;
+    ///
+    /// let mut bytes = HeartbeatRequestBuilder::new(1)
+    ///     .recovery_time_stamp(SystemTime::now())
+    ///     .marshal();
+    ///
+    /// // Append an IE of type 9999, which is not defined by 3GPP TS 29.244
+    /// bytes.extend_from_slice(&[0x27, 0x0F, 0x00, 0x01, 0xAB]);
+    /// let len = (bytes.len() - 4) as u16;
+    /// bytes[2..4].copy_from_slice(&len.to_be_bytes());
+    ///
+    /// let msg = parse(&bytes).unwrap();
+    /// assert_eq!(msg.unknown_ies().len(), 1);
+    /// assert!(msg.unknown_ies()[0].is_unknown());
+    /// ```
+    fn unknown_ies(&self) -> &[Ie] {
+        &[]
+    }
+}
+
+/// Copies out the IEs of `ies` whose type has no field in `msg`.
+///
+/// A message's [`Message::ies`] only falls back to scanning its additional IEs
+/// for types it has no field for, which is what identifies them here. Messages
+/// store the result when they are built or unmarshaled and hand it out from
+/// [`Message::unknown_ies`].
+pub(crate) fn unmapped_ies<M: Message + ?Sized>(msg: &M, ies: &[Ie]) -> Vec<Ie> {
+    ies.iter()
+        .filter(|ie| msg.ies(ie.ie_type).is_fallback())
+        .cloned()
+        .collect()
 }
 
 // A generic message for unknown message types.
+#[derive(Clone)]
 pub struct Generic {
     header: header::Header,
     ies: Vec<Ie>,
 }
 
+impl Generic {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
+    }
+}
+
 impl Message for Generic {
     fn marshal(&self) -> Vec<u8> {
         let mut buf = Vec::with_capacity(self.marshaled_size());
@@ -312,17 +436,18 @@ impl Message for Generic {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -333,7 +458,7 @@ impl Message for Generic {
         let mut ies = Vec::new();
         while cursor < data.len() {
             let ie = Ie::unmarshal(&data[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             ies.push(ie);
             cursor += ie_len;
         }
@@ -364,6 +489,11 @@ impl Message for Generic {
         IeIter::generic(&self.ies, ie_type)
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        // A generic message has no fields, so every IE is unmapped
+        &self.ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         self.ies.iter().collect()
     }
@@ -374,6 +504,15 @@ impl Message for Generic {
 /// Returns a `Box<dyn Message + Send + Sync>`, making the result safe to use
 /// across thread boundaries and `.await` points in async runtimes without
 /// `unsafe` transmutes.
+///
+/// # Errors
+///
+/// Returns an error if the message is malformed. A header carrying a PFCP
+/// version other than 1 is reported as [`PfcpError::UnsupportedVersion`]; reply
+/// to it with a [`VersionNotSupportedResponse`]. A message that decodes but
+/// lacks a mandatory IE is reported as [`PfcpError::MissingMandatoryIe`] with
+/// `message_type` set, which maps to the "Mandatory IE missing" cause through
+/// [`PfcpError::to_cause_code`].
 pub fn parse(data: &[u8]) -> Result<Box<dyn Message>, PfcpError> {
     let header = header::Header::unmarshal(data)?;
     match header.message_type {
@@ -434,6 +573,111 @@ pub fn parse(data: &[u8]) -> Result<Box<dyn Message>, PfcpError> {
     }
 }
 
+/// Parse a single PFCP message starting at `offset` within `data`.
+///
+/// The message extent is taken from the header length field, which counts
+/// every octet after the first four. Returns the number of bytes consumed
+/// together with the parsed message, so that concatenated messages can be
+/// walked segment by segment.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::message::{parse_at, heartbeat_request::HeartbeatRequestBuilder, Message};
+/// use std::time::SystemTime;
+///
+/// let mut buf = HeartbeatRequestBuilder::new(1)
+///     .recovery_time_stamp(SystemTime::now())
+///     .marshal();
+/// let first_len = buf.len();
+/// buf.extend(HeartbeatRequestBuilder::new(2)
+///     .recovery_time_stamp(SystemTime::now())
+///     .marshal());
+///
+/// let (consumed, msg) = parse_at(&buf, first_len).unwrap();
+/// assert_eq!(consumed, buf.len() - first_len);
+/// assert_eq!(*msg.sequence(), 2);
+/// ```
+pub fn parse_at(data: &[u8], offset: usize) -> Result<(usize, Box<dyn Message>), PfcpError> {
+    let frame_len = message_frame_len(data, offset)?;
+    let msg = parse(&data[offset..offset + frame_len])?;
+    Ok((frame_len, msg))
+}
+
+/// Parse every PFCP message in a buffer of back-to-back messages.
+///
+/// A message that fails to parse yields an `Err` entry and parsing resumes
+/// at the next message. If a header is truncated or declares more bytes than
+/// remain, the error is recorded and no further messages are parsed.
+pub fn parse_all(data: &[u8]) -> Vec<Result<Box<dyn Message>, PfcpError>> {
+    let mut results = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        match message_frame_len(data, offset) {
+            Ok(frame_len) => {
+                results.push(parse(&data[offset..offset + frame_len]));
+                offset += frame_len;
+            }
+            Err(e) => {
+                results.push(Err(e));
+                break;
+            }
+        }
+    }
+    results
+}
+
+/// Parse a buffer that must hold exactly one PFCP message.
+///
+/// Unlike [`parse`], which ignores anything after the message, this rejects
+/// an empty buffer and a buffer with bytes left over after the first message.
+///
+/// # Examples
+///
+/// ```
+/// use rs_pfcp::message::{parse_exactly_one, heartbeat_request::HeartbeatRequestBuilder};
+/// use std::time::SystemTime;
+///
+/// let mut buf = HeartbeatRequestBuilder::new(1)
+///     .recovery_time_stamp(SystemTime::now())
+///     .marshal();
+/// assert!(parse_exactly_one(&buf).is_ok());
+///
+/// buf.extend_from_slice(&buf.clone());
+/// assert!(parse_exactly_one(&buf).is_err());
+/// ```
+pub fn parse_exactly_one(data: &[u8]) -> Result<Box<dyn Message>, PfcpError> {
+    let frame_len = message_frame_len(data, 0)?;
+    if frame_len != data.len() {
+        return Err(PfcpError::message_parse_error(format!(
+            "Expected a single message of {} bytes but the buffer holds {}",
+            frame_len,
+            data.len()
+        )));
+    }
+    parse(data)
+}
+
+/// Returns the total on-wire size of the message starting at `offset`.
+fn message_frame_len(data: &[u8], offset: usize) -> Result<usize, PfcpError> {
+    let available = data.len().saturating_sub(offset);
+    if available < 4 {
+        return Err(PfcpError::message_parse_error(format!(
+            "Message header at offset {} too short (expected at least 4 bytes, got {})",
+            offset, available
+        )));
+    }
+    let length = u16::from_be_bytes([data[offset + 2], data[offset + 3]]) as usize;
+    let frame_len = length + 4;
+    if available < frame_len {
+        return Err(PfcpError::message_parse_error(format!(
+            "Message at offset {} declares {} bytes but only {} remain",
+            offset, frame_len, available
+        )));
+    }
+    Ok(frame_len)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -529,6 +773,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msg_type_response_type() {
+        let requests: Vec<MsgType> = (1..=17)
+            .chain(50..=57)
+            .map(MsgType::from)
+            .filter(|t| t.response_type().is_some())
+            .collect();
+        assert_eq!(requests.len(), 12);
+        for request in requests {
+            let response = request.response_type().unwrap();
+            assert_eq!(response as u8, request as u8 + 1);
+            assert_eq!(response.response_type(), None);
+        }
+        assert_eq!(
+            MsgType::SessionReportRequest.response_type(),
+            Some(MsgType::SessionReportResponse)
+        );
+        assert_eq!(MsgType::VersionNotSupportedResponse.response_type(), None);
+        assert_eq!(MsgType::Unknown.response_type(), None);
+    }
+
     // ========================================================================
     // Generic Message Tests
     // ========================================================================
@@ -899,6 +1164,24 @@ mod tests {
         assert_eq!(parsed.sequence(), SequenceNumber::new(33333));
     }
 
+    #[test]
+    fn test_parse_unsupported_version() {
+        let header = Header {
+            version: 2,
+            has_fo: false,
+            has_mp: false,
+            message_type: MsgType::HeartbeatRequest,
+            length: 4,
+            seid: Seid(0),
+            sequence_number: SequenceNumber::new(1),
+            message_priority: 0,
+            has_seid: false,
+        };
+
+        let err = parse(&header.marshal()).err().unwrap();
+        assert_eq!(err.unsupported_version_number(), Some(2));
+    }
+
     #[test]
     fn test_parse_error_short_buffer() {
         let short_data = vec![0x20, 0x01]; // Too short for valid message
@@ -906,6 +1189,47 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_truncated_heartbeat_is_parse_error() {
+        use crate::message::heartbeat_request::HeartbeatRequestBuilder;
+        use std::time::SystemTime;
+
+        let data = HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(SystemTime::now())
+            .build()
+            .marshal();
+
+        // Cut inside the header
+        let err = parse(&data[..6]).err().unwrap();
+        assert!(matches!(err, PfcpError::MessageParseError { .. }));
+
+        // Cut inside the Recovery Time Stamp IE
+        let err = parse(&data[..data.len() - 2]).err().unwrap();
+        assert!(matches!(
+            err,
+            PfcpError::InvalidLength {
+                ie_type: IeType::RecoveryTimeStamp,
+                ..
+            }
+        ));
+        assert!(!matches!(err, PfcpError::IoError { .. }));
+    }
+
+    #[test]
+    fn test_parse_heartbeat_missing_mandatory_ie() {
+        // Structurally valid header with no IEs
+        let data = [0x20, 0x01, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00];
+        let err = parse(&data).err().unwrap();
+        assert_eq!(
+            err,
+            PfcpError::MissingMandatoryIe {
+                ie_type: IeType::RecoveryTimeStamp,
+                message_type: Some(MsgType::HeartbeatRequest),
+                parent_ie: None,
+            }
+        );
+    }
+
     #[test]
     fn test_parse_all_message_types() {
         // Test that parse function handles all known message types
@@ -968,4 +1292,153 @@ mod tests {
         assert!(found_ie.is_some());
         assert_eq!(found_ie.unwrap().ie_type, IeType::RecoveryTimeStamp);
     }
+
+    // ========================================================================
+    // Segment Parsing Tests
+    // ========================================================================
+
+    fn back_to_back_heartbeats() -> (Vec<u8>, usize) {
+        use crate::message::heartbeat_request::HeartbeatRequestBuilder;
+        use crate::message::heartbeat_response::HeartbeatResponseBuilder;
+        use std::time::SystemTime;
+
+        let mut buf = HeartbeatRequestBuilder::new(100)
+            .recovery_time_stamp(SystemTime::UNIX_EPOCH)
+            .marshal();
+        let first_len = buf.len();
+        buf.extend(
+            HeartbeatResponseBuilder::new(101)
+                .recovery_time_stamp(SystemTime::UNIX_EPOCH)
+                .marshal(),
+        );
+        (buf, first_len)
+    }
+
+    #[test]
+    fn test_parse_at_back_to_back_heartbeats() {
+        let (buf, first_len) = back_to_back_heartbeats();
+
+        let (consumed, first) = parse_at(&buf, 0).unwrap();
+        assert_eq!(consumed, first_len);
+        assert_eq!(first.msg_type(), MsgType::HeartbeatRequest);
+        assert_eq!(*first.sequence(), 100);
+
+        let (consumed, second) = parse_at(&buf, first_len).unwrap();
+        assert_eq!(first_len + consumed, buf.len());
+        assert_eq!(second.msg_type(), MsgType::HeartbeatResponse);
+        assert_eq!(*second.sequence(), 101);
+    }
+
+    #[test]
+    fn test_parse_at_truncated() {
+        let (buf, first_len) = back_to_back_heartbeats();
+
+        assert!(parse_at(&buf[..buf.len() - 1], first_len).is_err());
+        assert!(parse_at(&buf, buf.len() - 2).is_err());
+        assert!(parse_at(&buf, buf.len() + 10).is_err());
+    }
+
+    #[test]
+    fn test_parse_all_back_to_back_heartbeats() {
+        let (buf, _) = back_to_back_heartbeats();
+
+        let messages: Vec<_> = parse_all(&buf).into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].msg_type(), MsgType::HeartbeatRequest);
+        assert_eq!(messages[1].msg_type(), MsgType::HeartbeatResponse);
+
+        assert!(parse_all(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_all_stops_on_truncated_trailer() {
+        let (buf, _) = back_to_back_heartbeats();
+
+        let results = parse_all(&buf[..buf.len() - 3]);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            Err(PfcpError::MessageParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_all_resumes_after_corrupted_message() {
+        let (mut buf, first_len) = back_to_back_heartbeats();
+        // The first message's Recovery Time Stamp IE now claims more bytes
+        // than the message holds; the header length, and so the framing, is
+        // untouched.
+        buf[first_len - 6] = 0x03;
+
+        let results = parse_all(&buf);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_err());
+        assert_eq!(
+            results[1].as_ref().unwrap().msg_type(),
+            MsgType::HeartbeatResponse
+        );
+    }
+
+    #[test]
+    fn test_parse_exactly_one() {
+        let (buf, first_len) = back_to_back_heartbeats();
+
+        let msg = parse_exactly_one(&buf[..first_len]).unwrap();
+        assert_eq!(msg.msg_type(), MsgType::HeartbeatRequest);
+
+        assert!(matches!(
+            parse_exactly_one(&buf),
+            Err(PfcpError::MessageParseError { .. })
+        ));
+        assert!(matches!(
+            parse_exactly_one(&[]),
+            Err(PfcpError::MessageParseError { .. })
+        ));
+        assert!(parse_exactly_one(&buf[..first_len - 1]).is_err());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_write_to_bytes_matches_marshal_into() {
+        use crate::ie::create_far::CreateFar;
+        use crate::ie::destination_interface::Interface;
+        use crate::ie::far_id::FarId;
+        use crate::message::heartbeat_request::HeartbeatRequestBuilder;
+        use crate::message::session_establishment_request::SessionEstablishmentRequestBuilder;
+        use std::net::Ipv4Addr;
+        use std::time::SystemTime;
+
+        let heartbeat = HeartbeatRequestBuilder::new(1)
+            .recovery_time_stamp(SystemTime::now())
+            .build();
+        let session = SessionEstablishmentRequestBuilder::new(0x1234, 2)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(vec![Ie::new(
+                IeType::CreatePdr,
+                vec![0x00, 0x38, 0x00, 0x02, 0x00, 0x01],
+            )])
+            .create_fars(vec![CreateFar::uplink_forward(
+                FarId::new(1),
+                Interface::Core,
+            )
+            .to_ie()])
+            .build()
+            .unwrap();
+        let generic =
+            Generic::unmarshal(&[0x20, 0x63, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00]).unwrap();
+
+        let messages: [&dyn Message; 3] = [&heartbeat, &session, &generic];
+        for msg in messages {
+            let mut vec = Vec::new();
+            msg.marshal_into(&mut vec);
+            let mut buf = bytes::BytesMut::new();
+            msg.write_to_bytes(&mut buf);
+            assert_eq!(&buf[..], &vec[..], "{} mismatch", msg.msg_name());
+
+            let parsed = parse(&buf.freeze()).unwrap();
+            assert_eq!(parsed.marshal(), vec);
+        }
+    }
 }
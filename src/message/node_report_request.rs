@@ -3,6 +3,8 @@
 //! Node Report Request message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::peer_up_restart_report::PeerUpRestartReport;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -18,13 +20,18 @@ pub struct NodeReportRequest {
     // TODO: [IE Type 187] User Plane Path Recovery Report - C - Multiple instances allowed, Grouped IE, when UPRR bit=1 in Node Report Type, see Table 7.4.5.1.2-1
     // TODO: [IE Type 205] Clock Drift Report - C - Multiple instances allowed, Grouped IE, when CDR bit=1 (N4 only, not Sxc), see Table 7.4.5.1.3-1
     // TODO: [IE Type 239] GTP-U Path QoS Report - C - Multiple instances allowed, Grouped IE, when GPQR bit=1 (N4 only), contains nested QoS Information (Type 240)
-    // TODO: [IE Type 315] Peer UP Restart Report - C - Grouped IE, when PURR bit=1 in Node Report Type, see Table 7.4.5.1.4-1
+    pub peer_up_restart_report: Option<Ie>, // C - 3GPP TS 29.244 Table 7.4.5.1.1-1 - IE Type 315 - Grouped IE, when PURR bit=1 in Node Report Type, see Table 7.4.5.1.4-1
     // TODO: [IE Type 320] Vendor-Specific Node Report Type - O - Multiple instances allowed, Grouped IE with Vendor ID + proprietary info
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl NodeReportRequest {
     /// Creates a new Node Report Request message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         node_id: Ie,
@@ -32,26 +39,62 @@ impl NodeReportRequest {
         user_plane_path_failure_report: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = node_id.len();
+        let mut payload_len = node_id.encoded_size();
         if let Some(ref ie) = node_report_type {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ref ie) = user_plane_path_failure_report {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::NodeReportRequest, false, 0, seq);
-        header.length = payload_len + (header.len() - 4);
+        header.set_body_length(payload_len);
 
-        NodeReportRequest {
+        let mut message = NodeReportRequest {
             header,
             node_id,
             node_report_type,
             user_plane_path_failure_report,
+            peer_up_restart_report: None,
             ies,
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    /// Returns the Peer UP Restart Report if present.
+    pub fn peer_up_restart_report(&self) -> Option<Result<PeerUpRestartReport, PfcpError>> {
+        self.peer_up_restart_report
+            .as_ref()
+            .map(|ie| PeerUpRestartReport::unmarshal(&ie.payload))
+    }
+
+    /// Sets the Peer UP Restart Report IE, keeping the header length in sync.
+    fn set_peer_up_restart_report(&mut self, ie: Option<Ie>) {
+        self.peer_up_restart_report = ie;
+        let ies_len = self.marshaled_size() - usize::from(self.header.len());
+        self.header.set_body_length(ies_len);
+    }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        if let Some(ref ie) = self.node_report_type {
+            ie.write_into(buf);
+        }
+        if let Some(ref ie) = self.user_plane_path_failure_report {
+            ie.write_into(buf);
+        }
+        if let Some(ref ie) = self.peer_up_restart_report {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
         }
     }
 }
@@ -68,31 +111,28 @@ impl Message for NodeReportRequest {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        if let Some(ref ie) = self.node_report_type {
-            ie.marshal_into(buf);
-        }
-        if let Some(ref ie) = self.user_plane_path_failure_report {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
+        size += self.node_id.encoded_size();
         if let Some(ref ie) = self.node_report_type {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.user_plane_path_failure_report {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        if let Some(ref ie) = self.peer_up_restart_report {
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -105,16 +145,18 @@ impl Message for NodeReportRequest {
         let mut node_id = None;
         let mut node_report_type = None;
         let mut user_plane_path_failure_report = None;
+        let mut peer_up_restart_report = None;
         let mut ies = Vec::new();
 
         let mut cursor = header.len() as usize;
         while cursor < buf.len() {
             let ie = Ie::unmarshal(&buf[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
-                IeType::ReportType => node_report_type = Some(ie),
+                IeType::NodeReportType => node_report_type = Some(ie),
                 IeType::UserPlanePathFailureReport => user_plane_path_failure_report = Some(ie),
+                IeType::PeerUpRestartReport => peer_up_restart_report = Some(ie),
                 _ => ies.push(ie),
             }
             cursor += ie_len;
@@ -126,13 +168,17 @@ impl Message for NodeReportRequest {
             parent_ie: None,
         })?;
 
-        Ok(NodeReportRequest {
+        let mut message = NodeReportRequest {
             header,
             node_id,
             node_report_type,
             user_plane_path_failure_report,
+            peer_up_restart_report,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -156,14 +202,21 @@ impl Message for NodeReportRequest {
 
         match ie_type {
             IeType::NodeId => IeIter::single(Some(&self.node_id), ie_type),
-            IeType::ReportType => IeIter::single(self.node_report_type.as_ref(), ie_type),
+            IeType::NodeReportType => IeIter::single(self.node_report_type.as_ref(), ie_type),
             IeType::UserPlanePathFailureReport => {
                 IeIter::single(self.user_plane_path_failure_report.as_ref(), ie_type)
             }
+            IeType::PeerUpRestartReport => {
+                IeIter::single(self.peer_up_restart_report.as_ref(), ie_type)
+            }
             _ => IeIter::generic(&self.ies, ie_type),
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id];
         if let Some(ref ie) = self.node_report_type {
@@ -172,6 +225,9 @@ impl Message for NodeReportRequest {
         if let Some(ref ie) = self.user_plane_path_failure_report {
             result.push(ie);
         }
+        if let Some(ref ie) = self.peer_up_restart_report {
+            result.push(ie);
+        }
         result.extend(self.ies.iter());
         result
     }
@@ -184,6 +240,7 @@ pub struct NodeReportRequestBuilder {
     node_id: Option<Ie>,
     node_report_type: Option<Ie>,
     user_plane_path_failure_report: Option<Ie>,
+    peer_up_restart_report: Option<Ie>,
     ies: Vec<Ie>,
 }
 
@@ -195,18 +252,54 @@ impl NodeReportRequestBuilder {
             node_id: None,
             node_report_type: None,
             user_plane_path_failure_report: None,
+            peer_up_restart_report: None,
             ies: Vec::new(),
         }
     }
 
-    /// Sets the node ID IE (required).
-    pub fn node_id(mut self, node_id: Ie) -> Self {
+    /// Sets the node ID from an IP address (required).
+    ///
+    /// For FQDN node IDs use [`node_id_fqdn`]; for full control use [`node_id_ie`].
+    ///
+    /// [`node_id_fqdn`]: #method.node_id_fqdn
+    /// [`node_id_ie`]: #method.node_id_ie
+    pub fn node_id<T>(mut self, node_id: T) -> Self
+    where
+        T: Into<std::net::IpAddr>,
+    {
+        use crate::ie::node_id::NodeId;
+        let node = match node_id.into() {
+            std::net::IpAddr::V4(v4) => NodeId::new_ipv4(v4),
+            std::net::IpAddr::V6(v6) => NodeId::new_ipv6(v6),
+        };
+        self.node_id = Some(node.to_ie());
+        self
+    }
+
+    /// Sets the node ID from a string (FQDN) (required).
+    pub fn node_id_fqdn(mut self, fqdn: &str) -> Self {
+        use crate::ie::node_id::NodeId;
+        self.node_id = Some(NodeId::new_fqdn(fqdn).to_ie());
+        self
+    }
+
+    /// Sets the node ID IE directly (required).
+    pub fn node_id_ie(mut self, node_id: Ie) -> Self {
         self.node_id = Some(node_id);
         self
     }
 
-    /// Sets the node report type IE (optional).
-    pub fn node_report_type(mut self, node_report_type: Ie) -> Self {
+    /// Sets the node report type.
+    pub fn node_report_type(
+        mut self,
+        node_report_type: crate::ie::node_report_type::NodeReportType,
+    ) -> Self {
+        self.node_report_type = Some(node_report_type.to_ie());
+        self
+    }
+
+    /// Sets the node report type IE directly.
+    pub fn node_report_type_ie(mut self, node_report_type: Ie) -> Self {
         self.node_report_type = Some(node_report_type);
         self
     }
@@ -217,6 +310,21 @@ impl NodeReportRequestBuilder {
         self
     }
 
+    /// Sets the Peer UP Restart Report (sent when the PURR bit is set in the
+    /// Node Report Type).
+    ///
+    /// For a raw IE, use [`peer_up_restart_report_ie`](Self::peer_up_restart_report_ie).
+    pub fn peer_up_restart_report(mut self, report: PeerUpRestartReport) -> Self {
+        self.peer_up_restart_report = Some(report.to_ie());
+        self
+    }
+
+    /// Sets the Peer UP Restart Report IE directly.
+    pub fn peer_up_restart_report_ie(mut self, peer_up_restart_report: Ie) -> Self {
+        self.peer_up_restart_report = Some(peer_up_restart_report);
+        self
+    }
+
     /// Adds an additional IE.
     pub fn ie(mut self, ie: Ie) -> Self {
         self.ies.push(ie);
@@ -238,13 +346,15 @@ impl NodeReportRequestBuilder {
             .node_id
             .expect("Node ID IE is required for NodeReportRequest");
 
-        NodeReportRequest::new(
+        let mut request = NodeReportRequest::new(
             self.sequence,
             node_id,
             self.node_report_type,
             self.user_plane_path_failure_report,
             self.ies,
-        )
+        );
+        request.set_peer_up_restart_report(self.peer_up_restart_report);
+        request
     }
 
     /// Tries to build the NodeReportRequest message.
@@ -256,13 +366,15 @@ impl NodeReportRequestBuilder {
             .node_id
             .ok_or("Node ID IE is required for NodeReportRequest")?;
 
-        Ok(NodeReportRequest::new(
+        let mut request = NodeReportRequest::new(
             self.sequence,
             node_id,
             self.node_report_type,
             self.user_plane_path_failure_report,
             self.ies,
-        ))
+        );
+        request.set_peer_up_restart_report(self.peer_up_restart_report);
+        Ok(request)
     }
 }
 
@@ -305,7 +417,7 @@ mod tests {
             IeType::NodeId,
             NodeId::IPv4(Ipv4Addr::new(10, 0, 0, 1)).marshal().to_vec(),
         );
-        let report_type_ie = Ie::new(IeType::ReportType, vec![0x01]); // USAR
+        let report_type_ie = Ie::new(IeType::NodeReportType, vec![0x01]); // UPFR
         let path_failure_ie = Ie::new(IeType::UserPlanePathFailureReport, vec![0x01, 0x02, 0x03]);
 
         let original = NodeReportRequest::new(
@@ -359,13 +471,13 @@ mod tests {
             IeType::NodeId,
             NodeId::IPv4(Ipv4Addr::new(10, 0, 0, 1)).marshal().to_vec(),
         );
-        let report_type_ie = Ie::new(IeType::ReportType, vec![0x02]); // ERIR
+        let report_type_ie = Ie::new(IeType::NodeReportType, vec![0x02]); // UPRR
 
         let message =
             NodeReportRequest::new(123, node_id_ie, Some(report_type_ie), None, Vec::new());
 
         assert!(message.ies(IeType::NodeId).next().is_some());
-        assert!(message.ies(IeType::ReportType).next().is_some());
+        assert!(message.ies(IeType::NodeReportType).next().is_some());
         assert!(message
             .ies(IeType::UserPlanePathFailureReport)
             .next()
@@ -394,7 +506,7 @@ mod tests {
         let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
         let request = NodeReportRequestBuilder::new(12345)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .build();
 
         assert_eq!(*request.sequence(), 12345);
@@ -411,11 +523,11 @@ mod tests {
         let node_id = NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 1));
         let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
-        let report_type_ie = Ie::new(IeType::ReportType, vec![0x01]); // USAR
+        let report_type_ie = Ie::new(IeType::NodeReportType, vec![0x01]); // UPFR
 
         let request = NodeReportRequestBuilder::new(67890)
-            .node_id(node_id_ie.clone())
-            .node_report_type(report_type_ie.clone())
+            .node_id_ie(node_id_ie.clone())
+            .node_report_type_ie(report_type_ie.clone())
             .build();
 
         assert_eq!(*request.sequence(), 67890);
@@ -432,7 +544,7 @@ mod tests {
         let path_failure_ie = Ie::new(IeType::UserPlanePathFailureReport, vec![0x01, 0x02, 0x03]);
 
         let request = NodeReportRequestBuilder::new(11111)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .user_plane_path_failure_report(path_failure_ie.clone())
             .build();
 
@@ -455,7 +567,7 @@ mod tests {
         let ie3 = Ie::new(IeType::Unknown, vec![0xFF, 0xFF]);
 
         let request = NodeReportRequestBuilder::new(22222)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .ie(ie1.clone())
             .ies(vec![ie2.clone(), ie3.clone()])
             .build();
@@ -473,13 +585,13 @@ mod tests {
         let node_id = NodeId::new_ipv4(Ipv4Addr::new(198, 51, 100, 1));
         let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
-        let report_type_ie = Ie::new(IeType::ReportType, vec![0x02]); // ERIR
+        let report_type_ie = Ie::new(IeType::NodeReportType, vec![0x02]); // UPRR
         let path_failure_ie = Ie::new(IeType::UserPlanePathFailureReport, vec![0x04, 0x05, 0x06]);
         let additional_ie = Ie::new(IeType::Timer, vec![0x00, 0x00, 0x03, 0x00]);
 
         let request = NodeReportRequestBuilder::new(33333)
-            .node_id(node_id_ie.clone())
-            .node_report_type(report_type_ie.clone())
+            .node_id_ie(node_id_ie.clone())
+            .node_report_type_ie(report_type_ie.clone())
             .user_plane_path_failure_report(path_failure_ie.clone())
             .ie(additional_ie.clone())
             .build();
@@ -501,7 +613,7 @@ mod tests {
         let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
         let result = NodeReportRequestBuilder::new(44444)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .try_build();
 
         assert!(result.is_ok());
@@ -532,11 +644,11 @@ mod tests {
         let node_id = NodeId::new_ipv4(Ipv4Addr::new(192, 168, 100, 1));
         let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
-        let report_type_ie = Ie::new(IeType::ReportType, vec![0x01]);
+        let report_type_ie = Ie::new(IeType::NodeReportType, vec![0x01]);
 
         let original = NodeReportRequestBuilder::new(99999)
-            .node_id(node_id_ie)
-            .node_report_type(report_type_ie)
+            .node_id_ie(node_id_ie)
+            .node_report_type_ie(report_type_ie)
             .build();
 
         let marshaled = original.marshal();
@@ -544,4 +656,89 @@ mod tests {
 
         assert_eq!(original, unmarshaled);
     }
+
+    #[test]
+    fn test_node_report_request_builder_typed_roundtrip() {
+        use crate::ie::node_report_type::NodeReportType;
+
+        let path_failure_ie = Ie::new(IeType::UserPlanePathFailureReport, vec![0x01, 0x02]);
+
+        let original = NodeReportRequestBuilder::new(4242)
+            .node_id(Ipv4Addr::new(10, 1, 1, 1))
+            .node_report_type(NodeReportType::UPFR)
+            .user_plane_path_failure_report(path_failure_ie.clone())
+            .build();
+
+        let marshaled = original.marshal();
+        let unmarshaled = NodeReportRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert!(unmarshaled.ies.is_empty());
+
+        let node_id = NodeId::unmarshal(&unmarshaled.node_id.payload).unwrap();
+        assert_eq!(node_id, NodeId::new_ipv4(Ipv4Addr::new(10, 1, 1, 1)));
+
+        let nrt_ie = unmarshaled.node_report_type.as_ref().unwrap();
+        assert_eq!(nrt_ie.ie_type, IeType::NodeReportType);
+        let nrt = NodeReportType::unmarshal(&nrt_ie.payload).unwrap();
+        assert!(nrt.upfr());
+
+        assert_eq!(
+            unmarshaled.user_plane_path_failure_report,
+            Some(path_failure_ie)
+        );
+    }
+
+    #[test]
+    fn test_node_report_request_builder_node_id_conversions() {
+        let from_ip = NodeReportRequestBuilder::new(1)
+            .node_id(Ipv4Addr::new(10, 1, 1, 1))
+            .build();
+        assert_eq!(
+            NodeId::unmarshal(&from_ip.node_id.payload).unwrap(),
+            NodeId::new_ipv4(Ipv4Addr::new(10, 1, 1, 1))
+        );
+
+        let from_fqdn = NodeReportRequestBuilder::new(2)
+            .node_id_fqdn("upf.example.com")
+            .build();
+        assert_eq!(
+            NodeId::unmarshal(&from_fqdn.node_id.payload).unwrap(),
+            NodeId::new_fqdn("upf.example.com")
+        );
+    }
+
+    #[test]
+    fn test_node_report_request_peer_up_restart_report_roundtrip() {
+        use crate::ie::node_report_type::NodeReportType;
+        use crate::ie::remote_gtpu_peer::RemoteGtpuPeer;
+
+        let peer = RemoteGtpuPeer {
+            ipv4: Some(Ipv4Addr::new(192, 0, 2, 10)),
+            ipv6: None,
+            destination_interface: None,
+            network_instance: None,
+            remote_tunnel_state: None,
+        };
+        let report = PeerUpRestartReport::new(vec![peer.clone()]);
+
+        let original = NodeReportRequestBuilder::new(77)
+            .node_id(Ipv4Addr::new(10, 1, 1, 1))
+            .node_report_type(NodeReportType::PURR)
+            .peer_up_restart_report(report.clone())
+            .build();
+
+        let marshaled = original.marshal();
+        assert_eq!(marshaled.len(), original.marshaled_size());
+        let unmarshaled = NodeReportRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert!(unmarshaled.ies.is_empty());
+
+        let nrt_ie = unmarshaled.node_report_type.as_ref().unwrap();
+        assert!(NodeReportType::unmarshal(&nrt_ie.payload).unwrap().purr());
+
+        let decoded = unmarshaled.peer_up_restart_report().unwrap().unwrap();
+        assert_eq!(decoded, report);
+        assert_eq!(decoded.remote_gtpu_peers, vec![peer]);
+        assert_eq!(unmarshaled.ies(IeType::PeerUpRestartReport).count(), 1);
+    }
 }
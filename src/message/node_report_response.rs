@@ -3,6 +3,7 @@
 //! Node Report Response message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -16,10 +17,15 @@ pub struct NodeReportResponse {
     pub cause: Ie,                // M - 3GPP TS 29.244 Table 7.4.5.2.1-1 - IE Type 19
     pub offending_ie: Option<Ie>, // C - 3GPP TS 29.244 Table 7.4.5.2.1-1 - IE Type 40 - When Cause indicates error with specific IE
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl NodeReportResponse {
     /// Creates a new Node Report Response message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         node_id: Ie,
@@ -27,23 +33,39 @@ impl NodeReportResponse {
         offending_ie: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = node_id.len() + cause.len();
+        let mut payload_len = node_id.encoded_size() + cause.encoded_size();
         if let Some(ref ie) = offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::NodeReportResponse, false, 0, seq);
-        header.length = payload_len + (header.len() - 4);
+        header.set_body_length(payload_len);
 
-        NodeReportResponse {
+        let mut message = NodeReportResponse {
             header,
             node_id,
             cause,
             offending_ie,
             ies,
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        self.cause.write_into(buf);
+        if let Some(ref ie) = self.offending_ie {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
         }
     }
 }
@@ -60,27 +82,23 @@ impl Message for NodeReportResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        self.cause.marshal_into(buf);
-        if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.node_id.encoded_size();
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -98,7 +116,7 @@ impl Message for NodeReportResponse {
         let mut cursor = header.len() as usize;
         while cursor < buf.len() {
             let ie = Ie::unmarshal(&buf[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::Cause => cause = Some(ie),
@@ -119,13 +137,16 @@ impl Message for NodeReportResponse {
             parent_ie: None,
         })?;
 
-        Ok(NodeReportResponse {
+        let mut message = NodeReportResponse {
             header,
             node_id,
             cause,
             offending_ie,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -155,6 +176,10 @@ impl Message for NodeReportResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id, &self.cause];
         if let Some(ref ie) = self.offending_ie {
@@ -352,8 +377,34 @@ impl NodeReportResponseBuilder {
         }
     }
 
-    /// Sets the node ID IE (required).
-    pub fn node_id(mut self, node_id: Ie) -> Self {
+    /// Sets the node ID from an IP address (required).
+    ///
+    /// For FQDN node IDs use [`node_id_fqdn`]; for full control use [`node_id_ie`].
+    ///
+    /// [`node_id_fqdn`]: #method.node_id_fqdn
+    /// [`node_id_ie`]: #method.node_id_ie
+    pub fn node_id<T>(mut self, node_id: T) -> Self
+    where
+        T: Into<std::net::IpAddr>,
+    {
+        use crate::ie::node_id::NodeId;
+        let node = match node_id.into() {
+            std::net::IpAddr::V4(v4) => NodeId::new_ipv4(v4),
+            std::net::IpAddr::V6(v6) => NodeId::new_ipv6(v6),
+        };
+        self.node_id = Some(node.to_ie());
+        self
+    }
+
+    /// Sets the node ID from a string (FQDN) (required).
+    pub fn node_id_fqdn(mut self, fqdn: &str) -> Self {
+        use crate::ie::node_id::NodeId;
+        self.node_id = Some(NodeId::new_fqdn(fqdn).to_ie());
+        self
+    }
+
+    /// Sets the node ID IE directly (required).
+    pub fn node_id_ie(mut self, node_id: Ie) -> Self {
         self.node_id = Some(node_id);
         self
     }
@@ -462,12 +513,11 @@ impl NodeReportResponseBuilder {
     /// # Example
     /// ```
     /// use rs_pfcp::message::node_report_response::NodeReportResponseBuilder;
-    /// use rs_pfcp::ie::{Ie, IeType, cause::CauseValue, node_id::NodeId};
+    /// use rs_pfcp::ie::cause::CauseValue;
     /// use std::net::Ipv4Addr;
     ///
-    /// let node_id = Ie::new(IeType::NodeId, NodeId::IPv4(Ipv4Addr::new(127, 0, 0, 1)).marshal().to_vec());
     /// let bytes = NodeReportResponseBuilder::new(1)
-    ///     .node_id(node_id)
+    ///     .node_id(Ipv4Addr::new(127, 0, 0, 1))
     ///     .cause(CauseValue::RequestAccepted)
     ///     .marshal();
     /// ```
@@ -492,7 +542,7 @@ mod builder_tests {
         let cause_ie = Ie::new(IeType::Cause, cause.marshal().to_vec());
 
         let response = NodeReportResponseBuilder::new(12345)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
             .build();
 
@@ -516,7 +566,7 @@ mod builder_tests {
         let offending_ie = Ie::new(IeType::OffendingIe, vec![0x00, 0x3C]);
 
         let response = NodeReportResponseBuilder::new(67890)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
             .offending_ie(offending_ie.clone())
             .build();
@@ -540,7 +590,7 @@ mod builder_tests {
         let ie3 = Ie::new(IeType::Unknown, vec![0xFF, 0xFF]);
 
         let response = NodeReportResponseBuilder::new(11111)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
             .ie(ie1.clone())
             .ies(vec![ie2.clone(), ie3.clone()])
@@ -567,7 +617,7 @@ mod builder_tests {
         let additional_ie = Ie::new(IeType::Timer, vec![0x00, 0x00, 0x05, 0x00]);
 
         let response = NodeReportResponseBuilder::new(22222)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
             .offending_ie(offending_ie.clone())
             .ie(additional_ie.clone())
@@ -590,7 +640,7 @@ mod builder_tests {
         let cause_ie = Ie::new(IeType::Cause, cause.marshal().to_vec());
 
         let result = NodeReportResponseBuilder::new(33333)
-            .node_id(node_id_ie.clone())
+            .node_id_ie(node_id_ie.clone())
             .cause_ie(cause_ie.clone())
             .try_build();
 
@@ -623,7 +673,7 @@ mod builder_tests {
         let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
         let result = NodeReportResponseBuilder::new(55555)
-            .node_id(node_id_ie)
+            .node_id_ie(node_id_ie)
             .try_build();
 
         assert!(result.is_err());
@@ -651,7 +701,7 @@ mod builder_tests {
         let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
         NodeReportResponseBuilder::new(77777)
-            .node_id(node_id_ie)
+            .node_id_ie(node_id_ie)
             .build();
     }
 
@@ -666,7 +716,7 @@ mod builder_tests {
         let offending_ie = Ie::new(IeType::OffendingIe, vec![0x00, 0x50]);
 
         let original = NodeReportResponseBuilder::new(88888)
-            .node_id(node_id_ie)
+            .node_id_ie(node_id_ie)
             .cause_ie(cause_ie)
             .offending_ie(offending_ie)
             .build();
@@ -676,4 +726,21 @@ mod builder_tests {
 
         assert_eq!(original, unmarshaled);
     }
+
+    #[test]
+    fn test_node_report_response_builder_typed_roundtrip() {
+        let original = NodeReportResponseBuilder::new(4242)
+            .node_id(Ipv4Addr::new(10, 1, 1, 2))
+            .cause(CauseValue::RequestAccepted)
+            .build();
+
+        let marshaled = original.marshal();
+        let unmarshaled = NodeReportResponse::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+
+        let node_id = NodeId::unmarshal(&unmarshaled.node_id.payload).unwrap();
+        assert_eq!(node_id, NodeId::new_ipv4(Ipv4Addr::new(10, 1, 1, 2)));
+        let cause = Cause::unmarshal(&unmarshaled.cause.payload).unwrap();
+        assert_eq!(cause.value, CauseValue::RequestAccepted);
+    }
 }
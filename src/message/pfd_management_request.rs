@@ -2,6 +2,7 @@
 
 use crate::error::PfcpError;
 use crate::ie::application_ids_pfds::ApplicationIdsPfds;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::node_id::NodeId;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
@@ -18,10 +19,15 @@ pub struct PfdManagementRequest {
     pub node_id: Option<NodeId>, // O - 3GPP TS 29.244 Table 7.4.3.1-1 - IE Type 60 - Unique identifier of sending node (Sxb/Sxc/N4 only)
     pub application_ids_pfds: Option<Vec<ApplicationIdsPfds>>, // C - 3GPP TS 29.244 Table 7.4.3.1-1 - IE Type 58 - Grouped IE, Multiple instances, if absent UP deletes all stored PFDs (Sxb/Sxc/N4 only)
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl PfdManagementRequest {
     /// Creates a new PFD Management Request message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         node_id: Option<NodeId>,
@@ -31,27 +37,46 @@ impl PfdManagementRequest {
         let mut payload_len = 0;
 
         if let Some(ref node_id) = node_id {
-            payload_len += node_id.to_ie().len();
+            payload_len += node_id.to_ie().encoded_size();
         }
 
         if let Some(ref app_pfds) = application_ids_pfds {
             for app_pfd in app_pfds {
-                payload_len += app_pfd.to_ie().len();
+                payload_len += app_pfd.to_ie().encoded_size();
             }
         }
 
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::PfdManagementRequest, false, 0, seq);
-        header.length = 4 + payload_len;
+        header.set_body_length(payload_len);
 
-        PfdManagementRequest {
+        let mut message = PfdManagementRequest {
             header,
             node_id,
             application_ids_pfds,
             ies,
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        if let Some(ref node_id) = self.node_id {
+            node_id.to_ie().write_into(buf);
+        }
+        if let Some(ref app_pfds) = self.application_ids_pfds {
+            for app_pfd in app_pfds {
+                app_pfd.to_ie().write_into(buf);
+            }
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
         }
     }
 }
@@ -64,33 +89,26 @@ impl Message for PfdManagementRequest {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        if let Some(ref node_id) = self.node_id {
-            node_id.to_ie().marshal_into(buf);
-        }
-        if let Some(ref app_pfds) = self.application_ids_pfds {
-            for app_pfd in app_pfds {
-                app_pfd.to_ie().marshal_into(buf);
-            }
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
         if let Some(ref node_id) = self.node_id {
-            size += node_id.to_ie().len() as usize;
+            size += node_id.to_ie().encoded_size();
         }
         if let Some(ref app_pfds) = self.application_ids_pfds {
             for app_pfd in app_pfds {
-                size += app_pfd.to_ie().len() as usize;
+                size += app_pfd.to_ie().encoded_size();
             }
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -104,7 +122,7 @@ impl Message for PfdManagementRequest {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => {
                     if node_id.is_some() {
@@ -126,12 +144,15 @@ impl Message for PfdManagementRequest {
             offset += ie_len;
         }
 
-        Ok(PfdManagementRequest {
+        let mut message = PfdManagementRequest {
             header,
             node_id,
             application_ids_pfds,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -164,6 +185,10 @@ impl Message for PfdManagementRequest {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         // Note: PfdManagementRequest uses type-safe fields (NodeId and ApplicationIdsPfds)
         // that are not stored as Ie. Only return IEs from the ies vector.
@@ -494,7 +519,7 @@ mod tests {
         assert_eq!(app_pfds[0], app_ids_pfds);
 
         // Verify the PFD contents are properly structured
-        let pfd_context = &app_pfds[0].pfd_context;
+        let pfd_context = &app_pfds[0].pfd_contexts[0];
         assert_eq!(pfd_context.pfd_contents.len(), 1);
         let pfd_content = &pfd_context.pfd_contents[0];
         assert_eq!(pfd_content.flags, 0xFF); // All flags should be set
@@ -513,4 +538,43 @@ mod tests {
         // ies() still works for other IEs
         assert_eq!(request.ies(IeType::Unknown).next(), Some(&other_ie));
     }
+
+    #[test]
+    fn test_pfd_management_request_multiple_contexts_round_trip() {
+        let app = |name: &str| {
+            ApplicationIdsPfds::with_pfd_contexts(
+                ApplicationId::new(name),
+                vec![
+                    PfdContext::new(vec![PfdContents::flow_description(
+                        "permit out tcp from any to any port 443",
+                    )
+                    .unwrap()]),
+                    PfdContext::new(vec![
+                        PfdContents::url(format!("https://{name}/")).unwrap(),
+                        PfdContents::domain_name(name).unwrap(),
+                    ]),
+                ],
+            )
+        };
+
+        let request = PfdManagementRequestBuilder::new(42)
+            .application_ids_pfds(app("app1.example"))
+            .application_ids_pfds(app("app2.example"))
+            .build();
+
+        let bytes = request.marshal();
+        let generic = crate::message::parse(&bytes).unwrap();
+        assert_eq!(generic.msg_type(), MsgType::PfdManagementRequest);
+        assert_eq!(generic.marshal(), bytes);
+
+        let parsed = PfdManagementRequest::unmarshal(&bytes).unwrap();
+        assert_eq!(parsed, request);
+
+        let apps = parsed.application_ids_pfds.as_ref().unwrap();
+        assert_eq!(apps.len(), 2);
+        for app in apps {
+            assert_eq!(app.pfd_contexts.len(), 2);
+            assert_eq!(app.pfd_contexts[1].pfd_contents.len(), 2);
+        }
+    }
 }
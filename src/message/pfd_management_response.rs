@@ -1,6 +1,7 @@
 //! PFD Management Response message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -13,10 +14,15 @@ pub struct PfdManagementResponse {
     pub offending_ie: Option<Ie>, // C - 3GPP TS 29.244 Table 7.4.3.2-1 - IE Type 40 - When rejection due to conditional/mandatory IE missing or faulty (Sxb/Sxc/N4 only)
     pub node_id: Option<Ie>, // O - 3GPP TS 29.244 Table 7.4.3.2-1 - IE Type 60 - Unique identifier of sending node (Sxb/Sxc/N4 only)
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl PfdManagementResponse {
     /// Creates a new PFD Management Response message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         cause: Ie,
@@ -24,26 +30,44 @@ impl PfdManagementResponse {
         node_id: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = cause.len();
+        let mut payload_len = cause.encoded_size();
         if let Some(ref ie) = offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ref ie) = node_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::PfdManagementResponse, false, 0, seq);
-        header.length = 4 + payload_len;
+        header.set_body_length(payload_len);
 
-        PfdManagementResponse {
+        let mut message = PfdManagementResponse {
             header,
             cause,
             offending_ie,
             node_id,
             ies,
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.cause.write_into(buf);
+        if let Some(ref ie) = self.offending_ie {
+            ie.write_into(buf);
+        }
+        if let Some(ref ie) = self.node_id {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
         }
     }
 }
@@ -56,31 +80,25 @@ impl Message for PfdManagementResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.cause.marshal_into(buf);
-        if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
-        }
-        if let Some(ref ie) = self.node_id {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.node_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -95,7 +113,7 @@ impl Message for PfdManagementResponse {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Cause => cause = Some(ie),
                 IeType::OffendingIe => offending_ie = Some(ie),
@@ -105,7 +123,7 @@ impl Message for PfdManagementResponse {
             offset += ie_len;
         }
 
-        Ok(PfdManagementResponse {
+        let mut message = PfdManagementResponse {
             header,
             cause: cause.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::Cause,
@@ -115,7 +133,10 @@ impl Message for PfdManagementResponse {
             offending_ie,
             node_id,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -149,6 +170,10 @@ impl Message for PfdManagementResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.cause];
         if let Some(ref ie) = self.offending_ie {
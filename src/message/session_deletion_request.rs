@@ -1,6 +1,7 @@
 // src/message/session_deletion_request.rs
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::fseid::Fseid;
 use crate::ie::node_id::NodeId;
 use crate::ie::{Ie, IeType};
@@ -12,53 +13,65 @@ use crate::types::{Seid, SequenceNumber};
 /// The PFCP Session Deletion Request is sent by the CP function to request
 /// the UP function to delete a PFCP session. The F-SEID identifying the PFCP
 /// session is carried in the PFCP header (header.seid), not as an IE in the body.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SessionDeletionRequest {
     pub header: Header,
     pub tl_container: Vec<Ie>, // C - 3GPP TS 29.244 Table 7.5.6-1 - IE Type 336 - Multiple instances, when SMF/CUC sends to UPF/CN-TL (N4 only)
     pub node_id: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.6-1 - IE Type 60 - When new SMF in SMF Set takes over (N4/N4mb only)
     pub cp_fseid: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.6-1 - IE Type 57 - When Node ID present and SMF changes CP F-SEID (N4/N4mb only)
     // ✅ 100% compliant with 3GPP TS 29.244 v18.10.0 - No missing IEs
-    pub ies: Vec<Ie>, // Additional/unknown IEs
+    pub ies: Vec<Ie>,       // Additional/unknown IEs
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for SessionDeletionRequest {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl SessionDeletionRequest {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
+        self.header.write_into(buf);
         for ie in &self.tl_container {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.node_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.cp_fseid {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionDeletionRequest {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
         for ie in &self.tl_container {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.node_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_fseid {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -74,7 +87,7 @@ impl Message for SessionDeletionRequest {
 
         while cursor < data.len() {
             let ie = Ie::unmarshal(&data[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::Fseid => cp_fseid = Some(ie),
@@ -84,13 +97,16 @@ impl Message for SessionDeletionRequest {
             cursor += ie_len;
         }
 
-        Ok(SessionDeletionRequest {
+        let mut message = SessionDeletionRequest {
             header,
             tl_container,
             node_id,
             cp_fseid,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -123,6 +139,10 @@ impl Message for SessionDeletionRequest {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = Vec::new();
         result.extend(self.tl_container.iter());
@@ -148,6 +168,10 @@ impl SessionDeletionRequest {
     /// * `node_id` - Optional Node ID when new SMF takes over
     /// * `cp_fseid` - Optional CP F-SEID when Node ID present and CP changes F-SEID
     /// * `ies` - Additional/unknown IEs
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seid: impl Into<Seid>,
         seq: impl Into<SequenceNumber>,
@@ -159,25 +183,28 @@ impl SessionDeletionRequest {
         let mut header = Header::new(MsgType::SessionDeletionRequest, true, seid, seq);
         let mut payload_len = 0;
         for ie in &tl_container {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &node_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &cp_fseid {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
-        header.length = payload_len + header.len() - 4;
-        SessionDeletionRequest {
+        header.set_body_length(payload_len);
+        let mut message = SessionDeletionRequest {
             header,
             tl_container,
             node_id,
             cp_fseid,
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
     }
 
     /// Returns the Node ID if present.
@@ -207,6 +234,7 @@ pub struct SessionDeletionRequestBuilder {
     node_id: Option<Ie>,
     cp_fseid: Option<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionDeletionRequestBuilder {
@@ -224,6 +252,7 @@ impl SessionDeletionRequestBuilder {
             node_id: None,
             cp_fseid: None,
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -334,19 +363,27 @@ impl SessionDeletionRequestBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
     /// Builds the SessionDeletionRequest message.
     ///
     /// All IEs are optional per the 3GPP TS 29.244 specification.
     /// The F-SEID identifying the PFCP session is carried in the header (seid).
     pub fn build(self) -> SessionDeletionRequest {
-        SessionDeletionRequest::new(
+        let mut message = SessionDeletionRequest::new(
             self.seid,
             self.sequence,
             self.tl_container,
             self.node_id,
             self.cp_fseid,
             self.ies,
-        )
+        );
+        message.header.has_fo = self.follow_on;
+        message
     }
 
     /// Builds the SessionDeletionRequest message and marshals it to bytes in one step.
@@ -1,6 +1,7 @@
 // src/message/session_deletion_response.rs
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -9,7 +10,7 @@ use crate::types::{Seid, SequenceNumber};
 ///
 /// The PFCP Session Deletion Response is sent by the UP function to the CP function
 /// as a reply to the PFCP Session Deletion Request.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SessionDeletionResponse {
     pub header: Header,
     pub cause: Ie,                // M - 3GPP TS 29.244 Table 7.5.7.1-1 - IE Type 19
@@ -23,83 +24,95 @@ pub struct SessionDeletionResponse {
     pub pfcpsdrsp_flags: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.7.1-1 - IE Type 318 - PURU flag
     pub tl_container: Vec<Ie>, // C - 3GPP TS 29.244 Table 7.5.7.1-1 - IE Type 336 - Multiple instances (N4 only)
     pub ies: Vec<Ie>,          // Additional/unknown IEs
+    pub extra_ies: Vec<Ie>,    // IEs of `ies` with no field of their own
 }
 
-impl Message for SessionDeletionResponse {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl SessionDeletionResponse {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.cause.marshal_into(buf);
+        self.header.write_into(buf);
+        self.cause.write_into(buf);
         if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.load_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.overload_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.usage_reports {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.additional_usage_reports_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.packet_rate_status_reports {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.mbs_session_n4_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcpsdrsp_flags {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.tl_container {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionDeletionResponse {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.load_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.overload_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.usage_reports {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.additional_usage_reports_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.packet_rate_status_reports {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.mbs_session_n4_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcpsdrsp_flags {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.tl_container {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -121,7 +134,7 @@ impl Message for SessionDeletionResponse {
 
         while cursor < data.len() {
             let ie = Ie::unmarshal(&data[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Cause => cause = Some(ie),
                 IeType::OffendingIe => offending_ie = Some(ie),
@@ -140,7 +153,7 @@ impl Message for SessionDeletionResponse {
             cursor += ie_len;
         }
 
-        Ok(SessionDeletionResponse {
+        let mut message = SessionDeletionResponse {
             header,
             cause: cause.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::Cause,
@@ -157,7 +170,10 @@ impl Message for SessionDeletionResponse {
             pfcpsdrsp_flags,
             tl_container,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -210,6 +226,10 @@ impl Message for SessionDeletionResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.cause];
         if let Some(ref ie) = self.offending_ie {
@@ -254,6 +274,10 @@ impl SessionDeletionResponse {
     /// * `pfcpsdrsp_flags` - Optional PFCPSDRsp-Flags (IE Type 318)
     /// * `tl_container` - Optional TL-Container IEs for TSN support (multiple - IE Type 336)
     /// * `ies` - Additional/unknown IEs
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         seid: impl Into<Seid>,
@@ -271,39 +295,39 @@ impl SessionDeletionResponse {
         ies: Vec<Ie>,
     ) -> Self {
         let mut header = Header::new(MsgType::SessionDeletionResponse, true, seid, seq);
-        let mut payload_len = cause_ie.len();
+        let mut payload_len = cause_ie.encoded_size();
         if let Some(ie) = &offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &load_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &overload_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &usage_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &additional_usage_reports_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &packet_rate_status_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &mbs_session_n4_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &pfcpsdrsp_flags {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &tl_container {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
-        header.length = payload_len + header.len() - 4;
-        SessionDeletionResponse {
+        header.set_body_length(payload_len);
+        let mut message = SessionDeletionResponse {
             header,
             cause: cause_ie,
             offending_ie,
@@ -316,7 +340,10 @@ impl SessionDeletionResponse {
             pfcpsdrsp_flags,
             tl_container,
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
     }
 }
 
@@ -336,6 +363,7 @@ pub struct SessionDeletionResponseBuilder {
     pfcpsdrsp_flags: Option<Ie>,
     tl_container: Vec<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionDeletionResponseBuilder {
@@ -362,6 +390,7 @@ impl SessionDeletionResponseBuilder {
             pfcpsdrsp_flags: None,
             tl_container: Vec::new(),
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -514,6 +543,12 @@ impl SessionDeletionResponseBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
     /// Builds the SessionDeletionResponse message.
     ///
     /// # Panics
@@ -523,7 +558,7 @@ impl SessionDeletionResponseBuilder {
             .cause
             .expect("Cause IE is required for SessionDeletionResponse");
 
-        SessionDeletionResponse::new(
+        let mut message = SessionDeletionResponse::new(
             self.seid,
             self.sequence,
             cause,
@@ -537,7 +572,9 @@ impl SessionDeletionResponseBuilder {
             self.pfcpsdrsp_flags,
             self.tl_container,
             self.ies,
-        )
+        );
+        message.header.has_fo = self.follow_on;
+        message
     }
 
     /// Tries to build the SessionDeletionResponse message.
@@ -549,7 +586,7 @@ impl SessionDeletionResponseBuilder {
             .cause
             .ok_or("Cause IE is required for SessionDeletionResponse")?;
 
-        Ok(SessionDeletionResponse::new(
+        let mut message = SessionDeletionResponse::new(
             self.seid,
             self.sequence,
             cause,
@@ -563,7 +600,9 @@ impl SessionDeletionResponseBuilder {
             self.pfcpsdrsp_flags,
             self.tl_container,
             self.ies,
-        ))
+        );
+        message.header.has_fo = self.follow_on;
+        Ok(message)
     }
 
     /// Builds and marshals the SessionDeletionResponse in one step.
@@ -1,6 +1,10 @@
 //! Session Establishment Request message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::fq_csid::FqCsid;
+use crate::ie::pfcpsm_req_flags::PfcpsmReqFlags;
+use crate::ie::snssai::HplmnSnssai;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -20,23 +24,19 @@ pub struct SessionEstablishmentRequest {
     pub create_bars: Vec<Ie>, // O - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 85 - Grouped IE (Sxa/N4 only, not Sxb/Sxc/N4mb)
     pub create_traffic_endpoints: Vec<Ie>, // C - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 131 - Multiple instances, Grouped IE
     pub pdn_type: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 113 - IP/non-IP/Ethernet PDN connection/PDU session
-    // TODO: [IE Type 65] SGW-C FQ-CSID - C - Multiple instances (Sxa/Sxb only, not Sxc/N4/N4mb) - Per clause 23 of 3GPP TS 23.007
-    // TODO: [IE Type 65] MME FQ-CSID - C - Multiple instances (Sxa/Sxb only, not Sxc/N4/N4mb) - When received on S11/S5/S8
-    // TODO: [IE Type 65] PGW-C/SMF FQ-CSID - C - Multiple instances (Sxa/Sxb/N4 only, not Sxc/N4mb) - Per clause 23 of 3GPP TS 23.007
-    // TODO: [IE Type 65] ePDG FQ-CSID - C - Multiple instances (Sxb only) - Per clause 23 of 3GPP TS 23.007
-    // TODO: [IE Type 65] TWAN FQ-CSID - C - Multiple instances (Sxb only) - Per clause 23 of 3GPP TS 23.007
+    pub fq_csids: Vec<Ie>, // C - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 65 - SGW-C/MME/PGW-C/SMF/ePDG/TWAN FQ-CSIDs, multiple instances, in encoding order
     pub user_plane_inactivity_timer: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 117 - Duration to send inactivity report (Sxb/Sxc/N4/N4mb only)
     pub user_id: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 141 - Only if UP in trusted environment (not N4mb)
     pub trace_information: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 152 - Trace instructions (not N4mb)
     pub apn_dnn: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 22 - Access Point Name / Data Network Name
     // TODO: [IE Type 165] Create MAR - C - Multiple instances, Grouped IE (N4 only, not Sxa/Sxb/Sxc/N4mb) - For MA PDU session
-    pub pfcpsm_req_flags: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 138 - PFCPSEReq-Flags (RESTI/SUMPC/HRSBOM)
+    pub pfcpsm_req_flags: Option<Ie>, // C - 3GPP TS 29.244 Section 8.2.35 - IE Type 49 - PFCPSMReq-Flags (DROBU/SNDEM)
     // TODO: [IE Type 204] Create Bridge/Router Info - C - Grouped IE (N4 only, not Sxa/Sxb/Sxc/N4mb) - For TSN/TSCTS/DetNet
     // TODO: [IE Type 208] Create SRR - O - Multiple instances, Grouped IE (N4 only, not Sxa/Sxb/Sxc/N4mb) - For session-level reporting
     // TODO: [IE Type 179] Provide ATSSS Control Information - C - Grouped IE (N4 only, not Sxa/Sxb/Sxc/N4mb) - For MA PDU session
     pub recovery_time_stamp: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 96 - CP function start time (not N4mb)
     pub s_nssai: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 25 - S-NSSAI of PDU/MBS session (N4/N4mb only)
-    // TODO: [IE Type 242] HPLMN S-NSSAI - C - (N4 only, not Sxa/Sxb/Sxc/N4mb) - For HR-SBO PDU session, from V-SMF to V-UPF
+    pub hplmn_s_nssai: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.2.1-1 - IE Type 338 - For HR-SBO PDU session, from V-SMF to V-UPF (N4 only)
     // TODO: [IE Type 181] Provide RDS configuration information - O - Grouped IE (Sxb/N4 only, not Sxa/Sxc/N4mb)
     // TODO: [IE Type 82] RAT Type - O - Current RAT type for statistics (not N4mb, not for MA PDU)
     // TODO: [IE Type 276] L2TP Tunnel Information - C - Multiple instances, Grouped IE (Sxb/N4 only, not Sxa/Sxc/N4mb) - See Table 7.5.2.1-2
@@ -51,127 +51,184 @@ pub struct SessionEstablishmentRequest {
     pub cp_function_features: Option<Ie>, // Note: Not in 3GPP TS 29.244 Table 7.5.2.1-1 - May be legacy/vendor-specific
     pub ethernet_pdu_session_information: Option<Ie>, // Note: Not in 3GPP TS 29.244 Table 7.5.2.1-1 - May be legacy/vendor-specific
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for SessionEstablishmentRequest {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
+impl SessionEstablishmentRequest {
+    // Typed accessors (recommended API)
+
+    /// Returns the Node ID.
+    pub fn node_id(&self) -> Result<crate::ie::node_id::NodeId, PfcpError> {
+        crate::ie::node_id::NodeId::unmarshal(&self.node_id.payload)
     }
 
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+    /// Returns the CP F-SEID.
+    pub fn fseid(&self) -> Result<crate::ie::fseid::Fseid, PfcpError> {
+        crate::ie::fseid::Fseid::unmarshal(&self.fseid.payload)
+    }
+
+    /// Returns the Create PDRs, decoded.
+    ///
+    /// Fails on the first Create PDR IE that doesn't decode.
+    pub fn create_pdrs_typed(&self) -> Result<Vec<crate::ie::create_pdr::CreatePdr>, PfcpError> {
+        self.create_pdrs.iter().map(Ie::parse).collect()
+    }
+
+    /// Returns the Create FARs, decoded.
+    ///
+    /// Fails on the first Create FAR IE that doesn't decode.
+    pub fn create_fars_typed(&self) -> Result<Vec<crate::ie::create_far::CreateFar>, PfcpError> {
+        self.create_fars.iter().map(Ie::parse).collect()
+    }
+
+    /// Returns the Create QERs, decoded.
+    ///
+    /// Fails on the first Create QER IE that doesn't decode.
+    pub fn create_qers_typed(&self) -> Result<Vec<crate::ie::create_qer::CreateQer>, PfcpError> {
+        self.create_qers.iter().map(Ie::parse).collect()
+    }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        self.fseid.marshal_into(buf);
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        self.fseid.write_into(buf);
         for ie in &self.create_pdrs {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.create_fars {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.create_urrs {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.create_qers {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.create_bars {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.create_traffic_endpoints {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pdn_type {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
+        }
+        for ie in &self.fq_csids {
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.user_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.s_nssai {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
+        }
+        if let Some(ref ie) = self.hplmn_s_nssai {
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.trace_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.recovery_time_stamp {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.cp_function_features {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.apn_dnn {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.user_plane_inactivity_timer {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcpsm_req_flags {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.ethernet_pdu_session_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionEstablishmentRequest {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
-        size += self.fseid.len() as usize;
+        size += self.node_id.encoded_size();
+        size += self.fseid.encoded_size();
         for ie in &self.create_pdrs {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.create_fars {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.create_urrs {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.create_qers {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.create_bars {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.create_traffic_endpoints {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pdn_type {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        for ie in &self.fq_csids {
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.user_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.s_nssai {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        if let Some(ref ie) = self.hplmn_s_nssai {
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.trace_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.recovery_time_stamp {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.apn_dnn {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.user_plane_inactivity_timer {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcpsm_req_flags {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.ethernet_pdu_session_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -187,8 +244,10 @@ impl Message for SessionEstablishmentRequest {
         let mut create_bars = Vec::new();
         let mut create_traffic_endpoints = Vec::new();
         let mut pdn_type = None;
+        let mut fq_csids = Vec::new();
         let mut user_id = None;
         let mut s_nssai = None;
+        let mut hplmn_s_nssai = None;
         let mut trace_information = None;
         let mut recovery_time_stamp = None;
         let mut cp_function_features = None;
@@ -201,7 +260,7 @@ impl Message for SessionEstablishmentRequest {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::Fseid => fseid = Some(ie),
@@ -212,8 +271,10 @@ impl Message for SessionEstablishmentRequest {
                 IeType::CreateBar => create_bars.push(ie),
                 IeType::CreateTrafficEndpoint => create_traffic_endpoints.push(ie),
                 IeType::PdnType => pdn_type = Some(ie),
+                IeType::FqCsid => fq_csids.push(ie),
                 IeType::UserId => user_id = Some(ie),
                 IeType::Snssai => s_nssai = Some(ie),
+                IeType::HplmnSNssai => hplmn_s_nssai = Some(ie),
                 IeType::TraceInformation => trace_information = Some(ie),
                 IeType::RecoveryTimeStamp => recovery_time_stamp = Some(ie),
                 IeType::CpFunctionFeatures => cp_function_features = Some(ie),
@@ -243,7 +304,7 @@ impl Message for SessionEstablishmentRequest {
             });
         }
 
-        Ok(SessionEstablishmentRequest {
+        let mut message = SessionEstablishmentRequest {
             header,
             node_id: node_id.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::NodeId,
@@ -262,8 +323,10 @@ impl Message for SessionEstablishmentRequest {
             create_bars,
             create_traffic_endpoints,
             pdn_type,
+            fq_csids,
             user_id,
             s_nssai,
+            hplmn_s_nssai,
             trace_information,
             recovery_time_stamp,
             cp_function_features,
@@ -272,7 +335,10 @@ impl Message for SessionEstablishmentRequest {
             pfcpsm_req_flags,
             ethernet_pdu_session_information,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -310,6 +376,7 @@ impl Message for SessionEstablishmentRequest {
                 IeIter::multiple(&self.create_traffic_endpoints, ie_type)
             }
             IeType::PdnType => IeIter::single(self.pdn_type.as_ref(), ie_type),
+            IeType::FqCsid => IeIter::multiple(&self.fq_csids, ie_type),
             IeType::UserPlaneInactivityTimer => {
                 IeIter::single(self.user_plane_inactivity_timer.as_ref(), ie_type)
             }
@@ -319,6 +386,7 @@ impl Message for SessionEstablishmentRequest {
             IeType::PfcpsmReqFlags => IeIter::single(self.pfcpsm_req_flags.as_ref(), ie_type),
             IeType::RecoveryTimeStamp => IeIter::single(self.recovery_time_stamp.as_ref(), ie_type),
             IeType::Snssai => IeIter::single(self.s_nssai.as_ref(), ie_type),
+            IeType::HplmnSNssai => IeIter::single(self.hplmn_s_nssai.as_ref(), ie_type),
             IeType::CpFunctionFeatures => {
                 IeIter::single(self.cp_function_features.as_ref(), ie_type)
             }
@@ -329,6 +397,10 @@ impl Message for SessionEstablishmentRequest {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id, &self.fseid];
         result.extend(self.create_pdrs.iter());
@@ -340,12 +412,16 @@ impl Message for SessionEstablishmentRequest {
         if let Some(ref ie) = self.pdn_type {
             result.push(ie);
         }
+        result.extend(self.fq_csids.iter());
         if let Some(ref ie) = self.user_id {
             result.push(ie);
         }
         if let Some(ref ie) = self.s_nssai {
             result.push(ie);
         }
+        if let Some(ref ie) = self.hplmn_s_nssai {
+            result.push(ie);
+        }
         if let Some(ref ie) = self.trace_information {
             result.push(ie);
         }
@@ -385,8 +461,10 @@ pub struct SessionEstablishmentRequestBuilder {
     create_bars: Vec<Ie>,
     create_traffic_endpoints: Vec<Ie>,
     pdn_type: Option<Ie>,
+    fq_csids: Vec<FqCsid>,
     user_id: Option<Ie>,
     s_nssai: Option<Ie>,
+    hplmn_s_nssai: Option<Ie>,
     trace_information: Option<Ie>,
     recovery_time_stamp: Option<Ie>,
     cp_function_features: Option<Ie>,
@@ -395,6 +473,7 @@ pub struct SessionEstablishmentRequestBuilder {
     pfcpsm_req_flags: Option<Ie>,
     ethernet_pdu_session_information: Option<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionEstablishmentRequestBuilder {
@@ -411,8 +490,10 @@ impl SessionEstablishmentRequestBuilder {
             create_bars: Vec::new(),
             create_traffic_endpoints: Vec::new(),
             pdn_type: None,
+            fq_csids: Vec::new(),
             user_id: None,
             s_nssai: None,
+            hplmn_s_nssai: None,
             trace_information: None,
             recovery_time_stamp: None,
             cp_function_features: None,
@@ -421,6 +502,7 @@ impl SessionEstablishmentRequestBuilder {
             pfcpsm_req_flags: None,
             ethernet_pdu_session_information: None,
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -520,13 +602,23 @@ impl SessionEstablishmentRequestBuilder {
         self
     }
 
-    pub fn create_pdrs(mut self, create_pdrs: Vec<Ie>) -> Self {
-        self.create_pdrs = create_pdrs;
+    /// Sets the Create PDRs from IEs or typed [`CreatePdr`](crate::ie::create_pdr::CreatePdr)s.
+    pub fn create_pdrs<I>(mut self, create_pdrs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Ie>,
+    {
+        self.create_pdrs = create_pdrs.into_iter().map(Into::into).collect();
         self
     }
 
-    pub fn create_fars(mut self, create_fars: Vec<Ie>) -> Self {
-        self.create_fars = create_fars;
+    /// Sets the Create FARs from IEs or typed [`CreateFar`](crate::ie::create_far::CreateFar)s.
+    pub fn create_fars<I>(mut self, create_fars: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Ie>,
+    {
+        self.create_fars = create_fars.into_iter().map(Into::into).collect();
         self
     }
 
@@ -535,8 +627,13 @@ impl SessionEstablishmentRequestBuilder {
         self
     }
 
-    pub fn create_qers(mut self, create_qers: Vec<Ie>) -> Self {
-        self.create_qers = create_qers;
+    /// Sets the Create QERs from IEs or typed [`CreateQer`](crate::ie::create_qer::CreateQer)s.
+    pub fn create_qers<I>(mut self, create_qers: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Ie>,
+    {
+        self.create_qers = create_qers.into_iter().map(Into::into).collect();
         self
     }
 
@@ -577,16 +674,42 @@ impl SessionEstablishmentRequestBuilder {
         self
     }
 
+    /// Adds a single BAR, converting it to an IE internally.
+    ///
+    /// Avoids constructing an intermediate `vec![bar.to_ie()]`.
+    pub fn create_bar(mut self, bar: crate::ie::create_bar::CreateBar) -> Self {
+        self.create_bars.push(bar.to_ie());
+        self
+    }
+
     pub fn create_traffic_endpoints(mut self, create_traffic_endpoints: Vec<Ie>) -> Self {
         self.create_traffic_endpoints = create_traffic_endpoints;
         self
     }
 
+    /// Adds a single Traffic Endpoint, converting it to an IE internally.
+    pub fn create_traffic_endpoint(
+        mut self,
+        ep: crate::ie::create_traffic_endpoint::CreateTrafficEndpoint,
+    ) -> Self {
+        self.create_traffic_endpoints.push(ep.to_ie());
+        self
+    }
+
     pub fn pdn_type(mut self, pdn_type: Ie) -> Self {
         self.pdn_type = Some(pdn_type);
         self
     }
 
+    /// Adds an FQ-CSID, converting it to an IE when the message is built.
+    ///
+    /// The SGW-C, MME, PGW-C/SMF, ePDG and TWAN FQ-CSIDs all share IE type 65
+    /// and are encoded in the order they are added.
+    pub fn fq_csid(mut self, fq_csid: FqCsid) -> Self {
+        self.fq_csids.push(fq_csid);
+        self
+    }
+
     pub fn user_id(mut self, user_id: Ie) -> Self {
         self.user_id = Some(user_id);
         self
@@ -597,6 +720,23 @@ impl SessionEstablishmentRequestBuilder {
         self
     }
 
+    /// Sets the HPLMN S-NSSAI of an HR-SBO PDU session.
+    ///
+    /// HPLMN S-NSSAI is a per-session IE (3GPP TS 29.244 Table 7.5.2.1-1);
+    /// node-level messages such as the Association Setup Request do not carry it.
+    ///
+    /// For a raw IE, use [`hplmn_s_nssai_ie`](Self::hplmn_s_nssai_ie).
+    pub fn hplmn_s_nssai(mut self, hplmn_s_nssai: HplmnSnssai) -> Self {
+        self.hplmn_s_nssai = Some(hplmn_s_nssai.to_ie());
+        self
+    }
+
+    /// Sets the HPLMN S-NSSAI IE directly.
+    pub fn hplmn_s_nssai_ie(mut self, hplmn_s_nssai: Ie) -> Self {
+        self.hplmn_s_nssai = Some(hplmn_s_nssai);
+        self
+    }
+
     pub fn trace_information(mut self, trace_information: Ie) -> Self {
         self.trace_information = Some(trace_information);
         self
@@ -646,12 +786,28 @@ impl SessionEstablishmentRequestBuilder {
         self
     }
 
-    pub fn user_plane_inactivity_timer(mut self, user_plane_inactivity_timer: Ie) -> Self {
-        self.user_plane_inactivity_timer = Some(user_plane_inactivity_timer);
+    /// Sets the User Plane Inactivity Timer from an IE or a typed
+    /// [`UserPlaneInactivityTimer`](crate::ie::user_plane_inactivity_timer::UserPlaneInactivityTimer).
+    pub fn user_plane_inactivity_timer(
+        mut self,
+        user_plane_inactivity_timer: impl Into<Ie>,
+    ) -> Self {
+        self.user_plane_inactivity_timer = Some(user_plane_inactivity_timer.into());
         self
     }
 
-    pub fn pfcpsm_req_flags(mut self, pfcpsm_req_flags: Ie) -> Self {
+    /// Sets the PFCPSMReq-Flags, e.g. SNDEM to request End Marker packets.
+    ///
+    /// For full control, use [`pfcpsm_req_flags_ie`].
+    ///
+    /// [`pfcpsm_req_flags_ie`]: #method.pfcpsm_req_flags_ie
+    pub fn pfcpsm_req_flags(mut self, flags: PfcpsmReqFlags) -> Self {
+        self.pfcpsm_req_flags = Some(flags.to_ie());
+        self
+    }
+
+    /// Sets the PFCPSMReq-Flags IE directly.
+    pub fn pfcpsm_req_flags_ie(mut self, pfcpsm_req_flags: Ie) -> Self {
         self.pfcpsm_req_flags = Some(pfcpsm_req_flags);
         self
     }
@@ -669,6 +825,12 @@ impl SessionEstablishmentRequestBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
     pub fn build(self) -> Result<SessionEstablishmentRequest, PfcpError> {
         let node_id = self.node_id.ok_or(PfcpError::MissingMandatoryIe {
             ie_type: IeType::NodeId,
@@ -695,57 +857,69 @@ impl SessionEstablishmentRequestBuilder {
             });
         }
 
-        let mut payload_len = node_id.len() + fseid.len();
+        let fq_csids = self
+            .fq_csids
+            .iter()
+            .map(FqCsid::to_ie)
+            .collect::<Result<Vec<Ie>, _>>()?;
+
+        let mut payload_len = node_id.encoded_size() + fseid.encoded_size();
         for ie in &self.create_pdrs {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.create_fars {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.create_urrs {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.create_qers {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.create_bars {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.create_traffic_endpoints {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.pdn_type {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
+        }
+        for ie in &fq_csids {
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.user_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.s_nssai {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
+        }
+        if let Some(ie) = &self.hplmn_s_nssai {
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.trace_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.recovery_time_stamp {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.cp_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.apn_dnn {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.user_plane_inactivity_timer {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.pfcpsm_req_flags {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.ethernet_pdu_session_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         let mut header = Header::new(
             MsgType::SessionEstablishmentRequest,
@@ -753,9 +927,10 @@ impl SessionEstablishmentRequestBuilder {
             self.seid,
             self.seq,
         );
-        header.length = payload_len + (header.len() - 4);
+        header.try_set_body_length(payload_len)?;
+        header.has_fo = self.follow_on;
 
-        Ok(SessionEstablishmentRequest {
+        let mut message = SessionEstablishmentRequest {
             header,
             node_id,
             fseid,
@@ -766,8 +941,10 @@ impl SessionEstablishmentRequestBuilder {
             create_bars: self.create_bars,
             create_traffic_endpoints: self.create_traffic_endpoints,
             pdn_type: self.pdn_type,
+            fq_csids,
             user_id: self.user_id,
             s_nssai: self.s_nssai,
+            hplmn_s_nssai: self.hplmn_s_nssai,
             trace_information: self.trace_information,
             recovery_time_stamp: self.recovery_time_stamp,
             cp_function_features: self.cp_function_features,
@@ -776,7 +953,10 @@ impl SessionEstablishmentRequestBuilder {
             pfcpsm_req_flags: self.pfcpsm_req_flags,
             ethernet_pdu_session_information: self.ethernet_pdu_session_information,
             ies: self.ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     /// Builds the SessionEstablishmentRequest message and marshals it to bytes in one step.
@@ -914,6 +1094,124 @@ mod tests {
         assert_eq!(node, NodeId::FQDN(fqdn.to_string()));
     }
 
+    #[test]
+    fn test_session_establishment_builder_typed_pdrs_and_fars() {
+        use crate::ie::{create_far::CreateFar, create_pdr::CreatePdr};
+
+        let (pdr_ies, far_ies) = create_minimal_pdr_far();
+        let pdrs: Vec<CreatePdr> = pdr_ies
+            .iter()
+            .map(|ie| CreatePdr::unmarshal(&ie.payload).unwrap())
+            .collect();
+        let fars: Vec<CreateFar> = far_ies
+            .iter()
+            .map(|ie| CreateFar::unmarshal(&ie.payload).unwrap())
+            .collect();
+
+        let from_ies = SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(Ipv4Addr::new(192, 168, 1, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(pdr_ies)
+            .create_fars(far_ies)
+            .build()
+            .unwrap();
+        let from_typed = SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(Ipv4Addr::new(192, 168, 1, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .build()
+            .unwrap();
+
+        assert_eq!(from_typed.marshal(), from_ies.marshal());
+    }
+
+    #[test]
+    fn test_session_establishment_typed_accessors_after_parse() {
+        let node_ip = Ipv4Addr::new(192, 168, 1, 1);
+        let cp_ip = Ipv4Addr::new(10, 0, 0, 1);
+        let (pdrs, fars) = create_minimal_pdr_far();
+
+        let bytes = SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(node_ip)
+            .fseid(0x5678, cp_ip)
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .marshal()
+            .unwrap();
+        let parsed = SessionEstablishmentRequest::unmarshal(&bytes).unwrap();
+
+        assert_eq!(parsed.node_id().unwrap(), NodeId::IPv4(node_ip));
+        assert_eq!(
+            parsed.fseid().unwrap(),
+            Fseid::new(0x5678, Some(cp_ip), None)
+        );
+
+        // A malformed IE is reported by the accessor, not at parse time
+        let mut broken = parsed.clone();
+        broken.fseid = Ie::new(IeType::Fseid, vec![0x02]);
+        assert!(broken.fseid().is_err());
+    }
+
+    #[test]
+    fn test_session_establishment_user_plane_inactivity_timer() {
+        use crate::ie::user_plane_inactivity_timer::UserPlaneInactivityTimer;
+
+        let (pdrs, fars) = create_minimal_pdr_far();
+        let bytes = SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(Ipv4Addr::new(192, 168, 1, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .user_plane_inactivity_timer(UserPlaneInactivityTimer::from_minutes(5))
+            .marshal()
+            .unwrap();
+        let parsed = SessionEstablishmentRequest::unmarshal(&bytes).unwrap();
+
+        let ie = parsed.user_plane_inactivity_timer.as_ref().unwrap();
+        let timer = ie.parse::<UserPlaneInactivityTimer>().unwrap();
+        assert_eq!(timer.as_seconds(), 300);
+    }
+
+    #[test]
+    fn test_session_establishment_typed_rules_after_parse() {
+        use crate::ie::{
+            create_far::CreateFar, create_pdr::CreatePdr, create_qer::CreateQer, qer_id::QerId,
+        };
+
+        let (pdr_ies, far_ies) = create_minimal_pdr_far();
+        let qer = CreateQer::open_gate(QerId::new(3));
+
+        let bytes = SessionEstablishmentRequestBuilder::new(0x1234, 1)
+            .node_id(Ipv4Addr::new(192, 168, 1, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 1))
+            .create_pdrs(pdr_ies.clone())
+            .create_fars(far_ies.clone())
+            .create_qers(vec![qer.clone()])
+            .marshal()
+            .unwrap();
+        let parsed = SessionEstablishmentRequest::unmarshal(&bytes).unwrap();
+
+        let pdrs: Vec<CreatePdr> = parsed.create_pdrs_typed().unwrap();
+        let fars: Vec<CreateFar> = parsed.create_fars_typed().unwrap();
+        let qers: Vec<CreateQer> = parsed.create_qers_typed().unwrap();
+        assert_eq!(
+            pdrs,
+            vec![CreatePdr::unmarshal(&pdr_ies[0].payload).unwrap()]
+        );
+        assert_eq!(
+            fars,
+            vec![CreateFar::unmarshal(&far_ies[0].payload).unwrap()]
+        );
+        assert_eq!(qers, vec![qer]);
+
+        let mut broken = parsed.clone();
+        broken
+            .create_qers
+            .push(Ie::new(IeType::CreateQer, vec![0x00]));
+        assert!(broken.create_qers_typed().is_err());
+    }
+
     #[test]
     fn test_session_establishment_builder_ergonomic_fseid_ipv4() {
         let ipv4 = Ipv4Addr::new(10, 0, 0, 1);
@@ -1175,6 +1473,32 @@ mod tests {
         assert_eq!(msg.create_traffic_endpoints.len(), 1);
     }
 
+    #[test]
+    fn test_builder_with_typed_traffic_endpoint() {
+        use crate::ie::create_traffic_endpoint::{CreateTrafficEndpoint, TrafficEndpointId};
+
+        let (pdrs, fars) = create_minimal_pdr_far();
+        let te = CreateTrafficEndpoint::builder(TrafficEndpointId::new(2))
+            .network_instance("internet")
+            .build();
+
+        let msg = SessionEstablishmentRequestBuilder::new(0x1111, 301)
+            .node_id(std::net::Ipv4Addr::new(10, 1, 1, 1))
+            .fseid(0x2222, std::net::Ipv4Addr::new(10, 1, 1, 2))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .create_traffic_endpoint(te.clone())
+            .build()
+            .unwrap();
+
+        let parsed = SessionEstablishmentRequest::unmarshal(&msg.marshal()).unwrap();
+        assert_eq!(parsed.create_traffic_endpoints.len(), 1);
+        assert_eq!(
+            CreateTrafficEndpoint::unmarshal(&parsed.create_traffic_endpoints[0].payload).unwrap(),
+            te
+        );
+    }
+
     #[test]
     fn test_builder_with_user_plane_inactivity_timer() {
         let (pdrs, fars) = create_minimal_pdr_far();
@@ -1244,6 +1568,32 @@ mod tests {
         assert!(unmarshaled.apn_dnn.is_some());
     }
 
+    #[test]
+    fn test_marshal_unmarshal_with_hplmn_s_nssai() {
+        use crate::ie::snssai::Snssai;
+
+        let (pdrs, fars) = create_minimal_pdr_far();
+        let hplmn = HplmnSnssai::from_snssai(Snssai::with_sd(1, [0x01, 0x02, 0x03]));
+
+        let original = SessionEstablishmentRequestBuilder::new(0x7777, 601)
+            .node_id(std::net::Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x8888, std::net::Ipv4Addr::new(10, 0, 0, 2))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .s_nssai(Snssai::embb().to_ie())
+            .hplmn_s_nssai(hplmn.clone())
+            .build()
+            .unwrap();
+
+        let marshaled = original.marshal();
+        assert_eq!(marshaled.len(), original.marshaled_size());
+        let unmarshaled = SessionEstablishmentRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(unmarshaled, original);
+
+        let ie = unmarshaled.ies(IeType::HplmnSNssai).next().unwrap();
+        assert_eq!(HplmnSnssai::unmarshal(&ie.payload).unwrap(), hplmn);
+    }
+
     #[test]
     fn test_marshal_unmarshal_with_urrs_qers_bars() {
         let (pdrs, fars) = create_minimal_pdr_far();
@@ -1270,6 +1620,34 @@ mod tests {
         assert_eq!(unmarshaled.create_bars.len(), 1);
     }
 
+    #[test]
+    fn test_marshal_unmarshal_with_typed_create_bar() {
+        use crate::ie::bar_id::BarId;
+        use crate::ie::create_bar::{CreateBar, CreateBarBuilder};
+
+        let (pdrs, fars) = create_minimal_pdr_far();
+        let bar = CreateBarBuilder::new(BarId::new(1))
+            .downlink_data_notification_delay(10)
+            .suggested_buffering_packets_count(32)
+            .build();
+
+        let original = SessionEstablishmentRequestBuilder::new(0x9999, 701)
+            .node_id(std::net::Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0xAAAA, std::net::Ipv4Addr::new(10, 0, 0, 2))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .create_bar(bar.clone())
+            .build()
+            .unwrap();
+
+        let marshaled = original.marshal();
+        let unmarshaled = SessionEstablishmentRequest::unmarshal(&marshaled).unwrap();
+
+        assert_eq!(unmarshaled.create_bars.len(), 1);
+        let parsed = CreateBar::unmarshal(&unmarshaled.create_bars[0].payload).unwrap();
+        assert_eq!(parsed, bar);
+    }
+
     // ========================================================================
     // Message Trait Tests
     // ========================================================================
@@ -1524,7 +1902,7 @@ mod tests {
             .create_pdrs(pdrs)
             .create_fars(fars)
             .create_urrs(vec![]) // Empty vec
-            .create_qers(vec![])
+            .create_qers(Vec::<Ie>::new())
             .create_bars(vec![])
             .build()
             .unwrap();
@@ -1533,4 +1911,70 @@ mod tests {
         assert_eq!(msg.create_qers.len(), 0);
         assert_eq!(msg.create_bars.len(), 0);
     }
+
+    #[test]
+    fn test_fq_csids_round_trip() {
+        let (pdrs, fars) = create_minimal_pdr_far();
+        let sgw_c = FqCsid::new_ipv4(Ipv4Addr::new(10, 0, 0, 3), vec![1, 2, 3]);
+        let smf = FqCsid::new_ipv6(
+            "2001:db8::3".parse::<Ipv6Addr>().unwrap(),
+            vec![0x100, 0x200],
+        );
+
+        let msg = SessionEstablishmentRequestBuilder::new(0x1234, 2200)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 2))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .fq_csid(sgw_c.clone())
+            .fq_csid(smf.clone())
+            .build()
+            .unwrap();
+
+        let unmarshaled = SessionEstablishmentRequest::unmarshal(&msg.marshal()).unwrap();
+        assert_eq!(unmarshaled, msg);
+
+        let fq_csids: Vec<FqCsid> = unmarshaled
+            .ies(IeType::FqCsid)
+            .map(|ie| FqCsid::unmarshal(&ie.payload).unwrap())
+            .collect();
+        assert_eq!(fq_csids, vec![sgw_c, smf]);
+    }
+
+    #[test]
+    fn test_fq_csid_without_csids_fails_build() {
+        let (pdrs, fars) = create_minimal_pdr_far();
+
+        let result = SessionEstablishmentRequestBuilder::new(0x1234, 2300)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 2))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .fq_csid(FqCsid::new_ipv4(Ipv4Addr::new(10, 0, 0, 3), vec![]))
+            .build();
+
+        assert!(matches!(result, Err(PfcpError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_pfcpsm_req_flags_round_trip() {
+        let (pdrs, fars) = create_minimal_pdr_far();
+
+        let msg = SessionEstablishmentRequestBuilder::new(0x1234, 2400)
+            .node_id(Ipv4Addr::new(10, 0, 0, 1))
+            .fseid(0x5678, Ipv4Addr::new(10, 0, 0, 2))
+            .create_pdrs(pdrs)
+            .create_fars(fars)
+            .pfcpsm_req_flags(PfcpsmReqFlags::SNDEM)
+            .build()
+            .unwrap();
+
+        let unmarshaled = SessionEstablishmentRequest::unmarshal(&msg.marshal()).unwrap();
+        assert_eq!(unmarshaled, msg);
+
+        let ie = unmarshaled.pfcpsm_req_flags.as_ref().unwrap();
+        let flags = PfcpsmReqFlags::unmarshal(&ie.payload).unwrap();
+        assert!(flags.contains(PfcpsmReqFlags::SNDEM));
+        assert!(!flags.contains(PfcpsmReqFlags::DROBU));
+    }
 }
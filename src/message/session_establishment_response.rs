@@ -1,6 +1,7 @@
 //! Session Establishment Response message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -12,7 +13,7 @@ pub struct SessionEstablishmentResponse {
     node_id: Ie, // M - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 60 - Unique identifier of sending node
     cause: Ie, // M - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 19 - Acceptance/rejection/partial acceptance
     offending_ie: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 40 - When conditional/mandatory IE missing or faulty
-    fseid: Ie, // C - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 57 - UP F-SEID when cause is success
+    fseid: Option<Ie>, // C - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 57 - UP F-SEID when cause is success
     created_pdrs: Vec<Ie>, // C - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 16 - Multiple instances, Grouped IE
     load_control_information: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 51 - Grouped IE (if load control feature supported)
     overload_control_information: Option<Ie>, // O - 3GPP TS 29.244 Table 7.5.3.1-1 - IE Type 54 - Grouped IE (during overload condition)
@@ -29,6 +30,7 @@ pub struct SessionEstablishmentResponse {
     // TODO: [IE Type 336] TL-Container - C - (N4 only, not Sxa/Sxb/Sxc/N4mb) - From UPF/CN-TL to SMF/CUC in response
     pdn_type: Option<Ie>, // Note: Not in 3GPP TS 29.244 Table 7.5.3.1-1 - May be legacy/vendor-specific
     ies: Vec<Ie>,
+    extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl SessionEstablishmentResponse {
@@ -51,9 +53,13 @@ impl SessionEstablishmentResponse {
             .map(|ie| crate::ie::offending_ie::OffendingIe::unmarshal(&ie.payload))
     }
 
-    /// Returns the F-SEID.
-    pub fn fseid(&self) -> Result<crate::ie::fseid::Fseid, PfcpError> {
-        crate::ie::fseid::Fseid::unmarshal(&self.fseid.payload)
+    /// Returns the UP F-SEID if present.
+    ///
+    /// It is only present when the request was accepted.
+    pub fn fseid(&self) -> Option<Result<crate::ie::fseid::Fseid, PfcpError>> {
+        self.fseid
+            .as_ref()
+            .map(|ie| crate::ie::fseid::Fseid::unmarshal(&ie.payload))
     }
 
     /// Returns a slice of created PDR IEs.
@@ -61,13 +67,27 @@ impl SessionEstablishmentResponse {
         &self.created_pdrs
     }
 
-    /// Returns an iterator over created PDRs with typed access.
-    pub fn created_pdrs_typed(
-        &self,
-    ) -> impl Iterator<Item = Result<crate::ie::created_pdr::CreatedPdr, PfcpError>> + '_ {
+    /// Returns the created PDRs, decoded.
+    ///
+    /// Fails on the first Created PDR IE that doesn't decode.
+    pub fn created_pdrs_typed(&self) -> Result<Vec<crate::ie::created_pdr::CreatedPdr>, PfcpError> {
         self.created_pdrs
             .iter()
             .map(|ie| crate::ie::created_pdr::CreatedPdr::unmarshal(&ie.payload))
+            .collect()
+    }
+
+    /// Returns the first created PDR, decoded.
+    ///
+    /// Convenient for sessions with a single PDR whose F-TEID was allocated by
+    /// the UP function.
+    pub fn first_created_pdr(
+        &self,
+    ) -> Result<Option<crate::ie::created_pdr::CreatedPdr>, PfcpError> {
+        self.created_pdrs
+            .first()
+            .map(|ie| crate::ie::created_pdr::CreatedPdr::unmarshal(&ie.payload))
+            .transpose()
     }
 
     /// Returns the PDN type if present.
@@ -122,9 +142,9 @@ impl SessionEstablishmentResponse {
         self.offending_ie.as_ref()
     }
 
-    /// Returns the raw F-SEID IE.
-    pub fn fseid_ie(&self) -> &Ie {
-        &self.fseid
+    /// Returns the raw F-SEID IE if present.
+    pub fn fseid_ie(&self) -> Option<&Ie> {
+        self.fseid.as_ref()
     }
 
     /// Returns the raw PDN type IE if present.
@@ -141,63 +161,76 @@ impl SessionEstablishmentResponse {
     pub fn overload_control_information_ie(&self) -> Option<&Ie> {
         self.overload_control_information.as_ref()
     }
-}
 
-impl Message for SessionEstablishmentResponse {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        self.cause.marshal_into(buf);
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        self.cause.write_into(buf);
         if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
+        }
+        if let Some(ref ie) = self.fseid {
+            ie.write_into(buf);
         }
-        self.fseid.marshal_into(buf);
         for ie in &self.created_pdrs {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pdn_type {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.load_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.overload_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionEstablishmentResponse {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.node_id.encoded_size();
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        if let Some(ref ie) = self.fseid {
+            size += ie.encoded_size();
         }
-        size += self.fseid.len() as usize;
         for ie in &self.created_pdrs {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pdn_type {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.load_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.overload_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -217,7 +250,7 @@ impl Message for SessionEstablishmentResponse {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::Cause => cause = Some(ie),
@@ -232,7 +265,7 @@ impl Message for SessionEstablishmentResponse {
             offset += ie_len;
         }
 
-        Ok(SessionEstablishmentResponse {
+        let mut message = SessionEstablishmentResponse {
             header,
             node_id: node_id.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::NodeId,
@@ -245,17 +278,16 @@ impl Message for SessionEstablishmentResponse {
                 parent_ie: None,
             })?,
             offending_ie,
-            fseid: fseid.ok_or(PfcpError::MissingMandatoryIe {
-                ie_type: IeType::Fseid,
-                message_type: Some(MsgType::SessionEstablishmentResponse),
-                parent_ie: None,
-            })?,
+            fseid,
             created_pdrs,
             pdn_type,
             load_control_information,
             overload_control_information,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -284,7 +316,7 @@ impl Message for SessionEstablishmentResponse {
         match ie_type {
             IeType::NodeId => IeIter::single(Some(&self.node_id), ie_type),
             IeType::Cause => IeIter::single(Some(&self.cause), ie_type),
-            IeType::Fseid => IeIter::single(Some(&self.fseid), ie_type),
+            IeType::Fseid => IeIter::single(self.fseid.as_ref(), ie_type),
             IeType::OffendingIe => IeIter::single(self.offending_ie.as_ref(), ie_type),
             IeType::CreatedPdr => IeIter::multiple(&self.created_pdrs, ie_type),
             IeType::PdnType => IeIter::single(self.pdn_type.as_ref(), ie_type),
@@ -298,11 +330,18 @@ impl Message for SessionEstablishmentResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
-        let mut result = vec![&self.node_id, &self.cause, &self.fseid];
+        let mut result = vec![&self.node_id, &self.cause];
         if let Some(ref ie) = self.offending_ie {
             result.push(ie);
         }
+        if let Some(ref ie) = self.fseid {
+            result.push(ie);
+        }
         result.extend(self.created_pdrs.iter());
         if let Some(ref ie) = self.pdn_type {
             result.push(ie);
@@ -331,6 +370,7 @@ pub struct SessionEstablishmentResponseBuilder {
     load_control_information: Option<Ie>,
     overload_control_information: Option<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionEstablishmentResponseBuilder {
@@ -362,6 +402,7 @@ impl SessionEstablishmentResponseBuilder {
             load_control_information: None,
             overload_control_information: None,
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -407,6 +448,7 @@ impl SessionEstablishmentResponseBuilder {
             load_control_information: None,
             overload_control_information: None,
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -515,8 +557,17 @@ impl SessionEstablishmentResponseBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
     /// Builds the `SessionEstablishmentResponse`.
     ///
+    /// The UP F-SEID is required when the cause accepts the request, per
+    /// 3GPP TS 29.244 Table 7.5.3.1-1; a rejection carries none.
+    ///
     /// Prefer calling `.marshal()` directly, which performs the same validation
     /// and is consistent with other response builders that go straight to bytes.
     pub fn build(self) -> Result<SessionEstablishmentResponse, PfcpError> {
@@ -530,30 +581,39 @@ impl SessionEstablishmentResponseBuilder {
             message_type: Some(MsgType::SessionEstablishmentResponse),
             parent_ie: None,
         })?;
-        let fseid = self.fseid.ok_or(PfcpError::MissingMandatoryIe {
-            ie_type: IeType::Fseid,
-            message_type: Some(MsgType::SessionEstablishmentResponse),
-            parent_ie: None,
-        })?;
+        let accepted = cause
+            .payload
+            .first()
+            .is_some_and(|&code| crate::ie::cause::CauseValue::from(code).is_accepted());
+        if accepted && self.fseid.is_none() {
+            return Err(PfcpError::MissingMandatoryIe {
+                ie_type: IeType::Fseid,
+                message_type: Some(MsgType::SessionEstablishmentResponse),
+                parent_ie: None,
+            });
+        }
 
-        let mut payload_len = node_id.len() + cause.len() + fseid.len();
+        let mut payload_len = node_id.encoded_size() + cause.encoded_size();
         if let Some(ie) = &self.offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
+        }
+        if let Some(ie) = &self.fseid {
+            payload_len += ie.encoded_size();
         }
         for ie in &self.created_pdrs {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.pdn_type {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.load_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.overload_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(
@@ -562,20 +622,24 @@ impl SessionEstablishmentResponseBuilder {
             self.seid,
             self.seq,
         );
-        header.length = payload_len + (header.len() - 4);
+        header.try_set_body_length(payload_len)?;
+        header.has_fo = self.follow_on;
 
-        Ok(SessionEstablishmentResponse {
+        let mut message = SessionEstablishmentResponse {
             header,
             node_id,
             cause,
             offending_ie: self.offending_ie,
-            fseid,
+            fseid: self.fseid,
             created_pdrs: self.created_pdrs,
             pdn_type: self.pdn_type,
             load_control_information: self.load_control_information,
             overload_control_information: self.overload_control_information,
             ies: self.ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     /// Builds and marshals the SessionEstablishmentResponse in one step.
@@ -624,6 +688,19 @@ mod tests {
         assert_eq!(*msg.sequence(), 200);
     }
 
+    #[test]
+    fn test_builder_rejected_without_fseid() {
+        let msg = SessionEstablishmentResponseBuilder::rejected(0xABCD, 201)
+            .node_id_ie(test_node_id())
+            .build()
+            .unwrap();
+        assert!(msg.fseid().is_none());
+        assert_eq!(msg.ies(IeType::Fseid).count(), 0);
+
+        let parsed = SessionEstablishmentResponse::unmarshal(&msg.marshal()).unwrap();
+        assert_eq!(parsed, msg);
+    }
+
     #[test]
     fn test_builder_with_fseid_ipv6() {
         let ipv6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
@@ -633,7 +710,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert!(!msg.fseid_ie().is_empty());
+        assert!(!msg.fseid_ie().unwrap().is_empty());
     }
 
     #[test]
@@ -645,7 +722,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(msg.fseid_ie().ie_type, IeType::Fseid);
+        assert_eq!(msg.fseid_ie().unwrap().ie_type, IeType::Fseid);
     }
 
     // ========================================================================
@@ -864,6 +941,64 @@ mod tests {
         assert_eq!(unmarshaled.created_pdrs.len(), 1);
     }
 
+    #[test]
+    fn test_marshal_unmarshal_with_typed_created_pdrs() {
+        use crate::ie::created_pdr::CreatedPdr;
+        use crate::ie::f_teid::Fteid;
+        use crate::ie::pdr_id::PdrId;
+
+        let uplink = CreatedPdr::new(
+            PdrId::new(1),
+            Fteid::ipv4(0x1000_0001, Ipv4Addr::new(192, 0, 2, 1)),
+        );
+        let downlink = CreatedPdr::new(
+            PdrId::new(2),
+            Fteid::ipv4(0x1000_0002, Ipv4Addr::new(192, 0, 2, 2)),
+        );
+
+        let original = SessionEstablishmentResponseBuilder::accepted(0x7777, 1401)
+            .node_id_ie(test_node_id())
+            .fseid(0x8888, Ipv4Addr::new(10, 0, 0, 1))
+            .created_pdr(uplink.to_ie())
+            .created_pdr(downlink.to_ie())
+            .build()
+            .unwrap();
+
+        let unmarshaled = SessionEstablishmentResponse::unmarshal(&original.marshal()).unwrap();
+        let created = unmarshaled.created_pdrs_typed().unwrap();
+        assert_eq!(created, vec![uplink.clone(), downlink.clone()]);
+        assert_eq!(created[0].pdr_id.value, 1);
+        assert_eq!(created[1].pdr_id.value, 2);
+        assert_eq!(*created[1].f_teid.teid, 0x1000_0002);
+        assert_eq!(
+            created[1].f_teid.ipv4_address,
+            Some(Ipv4Addr::new(192, 0, 2, 2))
+        );
+
+        assert_eq!(unmarshaled.first_created_pdr().unwrap(), Some(uplink));
+    }
+
+    #[test]
+    fn test_typed_created_pdrs_empty_and_invalid() {
+        let msg = SessionEstablishmentResponseBuilder::accepted(0x7777, 1402)
+            .node_id_ie(test_node_id())
+            .fseid(0x8888, Ipv4Addr::new(10, 0, 0, 1))
+            .build()
+            .unwrap();
+        assert!(msg.created_pdrs_typed().unwrap().is_empty());
+        assert_eq!(msg.first_created_pdr().unwrap(), None);
+
+        // Created PDR without its F-TEID
+        let msg = SessionEstablishmentResponseBuilder::accepted(0x7777, 1403)
+            .node_id_ie(test_node_id())
+            .fseid(0x8888, Ipv4Addr::new(10, 0, 0, 1))
+            .created_pdr(Ie::new(IeType::CreatedPdr, vec![0, 56, 0, 2, 0, 1]))
+            .build()
+            .unwrap();
+        assert!(msg.created_pdrs_typed().is_err());
+        assert!(msg.first_created_pdr().is_err());
+    }
+
     #[test]
     fn test_marshal_unmarshal_with_optional_ies() {
         let pdn_ie = Ie::new(IeType::PdnType, vec![0x01]);
@@ -1070,4 +1205,38 @@ mod tests {
 
         assert_eq!(msg.created_pdrs().len(), 0);
     }
+
+    #[test]
+    fn test_typed_accessors_from_wire_bytes() {
+        use crate::ie::cause::CauseValue;
+        use crate::ie::fseid::Fseid;
+
+        #[rustfmt::skip]
+        let bytes = [
+            // Header: version 1, S flag, type 51, length 43, SEID, sequence 7
+            0x21, 51, 0x00, 43,
+            0, 0, 0, 0, 0, 0, 0x12, 0x34,
+            0x00, 0x00, 0x07, 0x00,
+            // Node ID: IPv4 10.0.0.100
+            0x00, 60, 0x00, 5, 0x00, 10, 0, 0, 100,
+            // Cause: Request accepted
+            0x00, 19, 0x00, 1, 1,
+            // F-SEID: SEID 0x5678, IPv4 10.0.0.1
+            0x00, 57, 0x00, 13, 0x02, 0, 0, 0, 0, 0, 0, 0x56, 0x78, 10, 0, 0, 1,
+        ];
+
+        let msg = SessionEstablishmentResponse::unmarshal(&bytes).unwrap();
+        assert_eq!(msg.seid(), Some(Seid(0x1234)));
+        assert_eq!(*msg.sequence(), 7);
+        assert_eq!(
+            msg.node_id().unwrap(),
+            NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 100))
+        );
+        assert_eq!(msg.cause().unwrap().value, CauseValue::RequestAccepted);
+        assert_eq!(
+            msg.fseid().unwrap().unwrap(),
+            Fseid::new(0x5678, Some(Ipv4Addr::new(10, 0, 0, 1)), None)
+        );
+        assert!(msg.offending_ie().is_none());
+    }
 }
@@ -1,6 +1,7 @@
 //! Session Modification Request message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -65,289 +66,301 @@ pub struct SessionModificationRequest {
     pub recovery_time_stamp: Option<Ie>, // Note: Not in 3GPP TS 29.244 Table 7.5.4.1-1 - May be legacy/vendor-specific
     pub cp_function_features: Option<Ie>, // Note: Not in 3GPP TS 29.244 Table 7.5.4.1-1 - May be legacy/vendor-specific
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for SessionModificationRequest {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl SessionModificationRequest {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
+        self.header.write_into(buf);
         if let Some(ref ie) = self.fseid {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ies) = self.remove_pdrs {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.remove_fars {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.remove_urrs {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.remove_qers {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.remove_bars {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.remove_traffic_endpoints {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.create_pdrs {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.create_fars {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.create_urrs {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.create_qers {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.create_bars {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.create_traffic_endpoints {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.update_pdrs {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.update_fars {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.update_urrs {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.update_qers {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.update_bars {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.update_traffic_endpoints {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ie) = self.pdn_type {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.user_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.s_nssai {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.trace_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.recovery_time_stamp {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.cp_function_features {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.apn_dnn {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.user_plane_inactivity_timer {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcpsm_req_flags {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ies) = self.query_urrs {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ie) = self.node_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.ethernet_context_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionModificationRequest {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
         if let Some(ref ie) = self.fseid {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ies) = self.remove_pdrs {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.remove_fars {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.remove_urrs {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.remove_qers {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.remove_bars {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.remove_traffic_endpoints {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.create_pdrs {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.create_fars {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.create_urrs {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.create_qers {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.create_bars {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.create_traffic_endpoints {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.update_pdrs {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.update_fars {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.update_urrs {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.update_qers {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.update_bars {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.update_traffic_endpoints {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ie) = self.pdn_type {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.user_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.s_nssai {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.trace_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.recovery_time_stamp {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.apn_dnn {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.user_plane_inactivity_timer {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcpsm_req_flags {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ies) = self.query_urrs {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ie) = self.node_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.ethernet_context_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -390,7 +403,7 @@ impl Message for SessionModificationRequest {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Fseid => fseid = Some(ie),
                 IeType::RemovePdr => remove_pdrs.get_or_insert(Vec::new()).push(ie),
@@ -434,7 +447,7 @@ impl Message for SessionModificationRequest {
             offset += ie_len;
         }
 
-        Ok(SessionModificationRequest {
+        let mut message = SessionModificationRequest {
             header,
             fseid,
             remove_pdrs,
@@ -468,7 +481,10 @@ impl Message for SessionModificationRequest {
             node_id,
             ethernet_context_information,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -577,6 +593,10 @@ impl Message for SessionModificationRequest {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = Vec::new();
         if let Some(ref ie) = self.fseid {
@@ -713,6 +733,7 @@ pub struct SessionModificationRequestBuilder {
     node_id: Option<Ie>,
     ethernet_context_information: Option<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionModificationRequestBuilder {
@@ -752,6 +773,7 @@ impl SessionModificationRequestBuilder {
             node_id: None,
             ethernet_context_information: None,
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -851,6 +873,14 @@ impl SessionModificationRequestBuilder {
         self
     }
 
+    /// Adds a single BAR, converting it to an IE internally.
+    pub fn create_bar(mut self, bar: crate::ie::create_bar::CreateBar) -> Self {
+        self.create_bars
+            .get_or_insert_with(Vec::new)
+            .push(bar.to_ie());
+        self
+    }
+
     pub fn create_traffic_endpoints(mut self, create_traffic_endpoints: Vec<Ie>) -> Self {
         self.create_traffic_endpoints = Some(create_traffic_endpoints);
         self
@@ -955,6 +985,16 @@ impl SessionModificationRequestBuilder {
         self
     }
 
+    /// Adds a Query URR requesting an immediate usage report for `urr_id`.
+    ///
+    /// May be called repeatedly to query several URRs.
+    pub fn query_urr(mut self, urr_id: crate::ie::urr_id::UrrId) -> Self {
+        self.query_urrs
+            .get_or_insert_with(Vec::new)
+            .push(crate::ie::query_urr::QueryUrr::from(urr_id).to_ie());
+        self
+    }
+
     pub fn node_id(mut self, node_id: Ie) -> Self {
         self.node_id = Some(node_id);
         self
@@ -970,141 +1010,163 @@ impl SessionModificationRequestBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
+    /// Builds the SessionModificationRequest message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message would not fit in the header Length field; see
+    /// [`try_build`](Self::try_build).
     pub fn build(self) -> SessionModificationRequest {
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Tries to build the SessionModificationRequest message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PfcpError::InvalidValue`] if the IEs add up to more than the
+    /// 65535 octets the header Length field can carry.
+    pub fn try_build(self) -> Result<SessionModificationRequest, PfcpError> {
         let mut payload_len = 0;
         if let Some(ie) = &self.fseid {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ies) = &self.remove_pdrs {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.remove_fars {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.remove_urrs {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.remove_qers {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.remove_bars {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.remove_traffic_endpoints {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.create_pdrs {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.create_fars {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.create_urrs {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.create_qers {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.create_bars {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.create_traffic_endpoints {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.update_pdrs {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.update_fars {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.update_urrs {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.update_qers {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.update_bars {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ies) = &self.update_traffic_endpoints {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ie) = &self.pdn_type {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.user_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.s_nssai {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.trace_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.recovery_time_stamp {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.cp_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.apn_dnn {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.user_plane_inactivity_timer {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.pfcpsm_req_flags {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ies) = &self.query_urrs {
             for ie in ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
         }
         if let Some(ie) = &self.node_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.ethernet_context_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         let mut header = Header::new(
             MsgType::SessionModificationRequest,
@@ -1112,8 +1174,9 @@ impl SessionModificationRequestBuilder {
             self.seid,
             self.seq,
         );
-        header.length = payload_len + (header.len() - 4);
-        SessionModificationRequest {
+        header.try_set_body_length(payload_len)?;
+        header.has_fo = self.follow_on;
+        let mut message = SessionModificationRequest {
             header,
             fseid: self.fseid,
             remove_pdrs: self.remove_pdrs,
@@ -1147,7 +1210,10 @@ impl SessionModificationRequestBuilder {
             node_id: self.node_id,
             ethernet_context_information: self.ethernet_context_information,
             ies: self.ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     /// Builds the SessionModificationRequest message and marshals it to bytes in one step.
@@ -1582,6 +1648,34 @@ mod tests {
         assert!(unmarshaled.remove_urrs.is_some());
     }
 
+    #[test]
+    fn test_marshal_unmarshal_with_typed_create_bar() {
+        use crate::ie::bar_id::BarId;
+        use crate::ie::create_bar::{CreateBar, CreateBarBuilder};
+        use crate::ie::dl_buffering_duration::DlBufferingDuration;
+        use crate::ie::timer::{Timer, TimerUnit};
+
+        let bar = CreateBarBuilder::new(BarId::new(2))
+            .suggested_buffering_packets_count(16)
+            .dl_buffering_duration(DlBufferingDuration::new(
+                Timer::new(TimerUnit::OneMinute, 5).unwrap(),
+            ))
+            .build();
+
+        let original = SessionModificationRequestBuilder::new(0x3333, 401)
+            .fseid(0x4444, Ipv4Addr::new(192, 168, 1, 1))
+            .create_bar(bar.clone())
+            .build();
+
+        let marshaled = original.marshal();
+        let unmarshaled = SessionModificationRequest::unmarshal(&marshaled).unwrap();
+
+        let create_bars = unmarshaled.create_bars.unwrap();
+        assert_eq!(create_bars.len(), 1);
+        let parsed = CreateBar::unmarshal(&create_bars[0].payload).unwrap();
+        assert_eq!(parsed, bar);
+    }
+
     // ========================================================================
     // Builder Convenience Methods Tests
     // ========================================================================
@@ -1755,4 +1849,24 @@ mod tests {
 
         assert!(msg.create_bars.is_some());
     }
+
+    #[test]
+    fn test_query_urr_round_trip() {
+        use crate::ie::query_urr::QueryUrr;
+        use crate::ie::urr_id::UrrId;
+
+        let msg = SessionModificationRequestBuilder::new(0x1234, 1)
+            .query_urr(UrrId::new(1))
+            .query_urr(UrrId::new(5))
+            .build();
+
+        let parsed = SessionModificationRequest::unmarshal(&msg.marshal()).unwrap();
+        assert_eq!(parsed, msg);
+
+        let urr_ids: Vec<UrrId> = parsed
+            .ies(IeType::QueryUrr)
+            .map(|ie| QueryUrr::unmarshal(&ie.payload).unwrap().urr_id)
+            .collect();
+        assert_eq!(urr_ids, vec![UrrId::new(1), UrrId::new(5)]);
+    }
 }
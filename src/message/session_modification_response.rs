@@ -1,6 +1,8 @@
 //! Session Modification Response message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::usage_report_smr::UsageReportSmr;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -26,65 +28,77 @@ pub struct SessionModificationResponse {
     // TODO: [IE Type 299] MBS Session N4 Information - C - Multiple instances, Grouped IE (N4 only, not Sxa/Sxb/Sxc/N4mb) - Per clause 5.34.1
     pub pdn_type: Option<Ie>, // Note: Not in 3GPP TS 29.244 Table 7.5.5.1-1 - May be legacy/vendor-specific
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for SessionModificationResponse {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl SessionModificationResponse {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.cause.marshal_into(buf);
+        self.header.write_into(buf);
+        self.cause.write_into(buf);
         if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.created_pdr {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.load_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.overload_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pdn_type {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.usage_reports {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionModificationResponse {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.created_pdr {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.load_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.overload_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pdn_type {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.usage_reports {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -103,7 +117,7 @@ impl Message for SessionModificationResponse {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Cause => cause = Some(ie),
                 IeType::OffendingIe => offending_ie = Some(ie),
@@ -117,7 +131,7 @@ impl Message for SessionModificationResponse {
             offset += ie_len;
         }
 
-        Ok(SessionModificationResponse {
+        let mut message = SessionModificationResponse {
             header,
             cause: cause.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::Cause,
@@ -131,7 +145,10 @@ impl Message for SessionModificationResponse {
             pdn_type,
             usage_reports,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -175,6 +192,10 @@ impl Message for SessionModificationResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.cause];
         if let Some(ref ie) = self.offending_ie {
@@ -213,30 +234,30 @@ impl SessionModificationResponse {
         ies: Vec<Ie>,
     ) -> Self {
         let mut header = Header::new(MsgType::SessionModificationResponse, true, seid, seq);
-        let mut payload_len = cause_ie.len();
+        let mut payload_len = cause_ie.encoded_size();
         if let Some(ie) = &offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &created_pdr {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &load_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &overload_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &pdn_type {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &usage_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
-        header.length = payload_len + header.len() - 4;
-        SessionModificationResponse {
+        header.set_body_length(payload_len);
+        let mut message = SessionModificationResponse {
             header,
             cause: cause_ie,
             offending_ie,
@@ -246,7 +267,19 @@ impl SessionModificationResponse {
             pdn_type,
             usage_reports,
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    /// Returns an iterator over usage reports with typed access.
+    pub fn usage_reports_typed(
+        &self,
+    ) -> impl Iterator<Item = Result<UsageReportSmr, PfcpError>> + '_ {
+        self.usage_reports
+            .iter()
+            .map(|ie| UsageReportSmr::unmarshal(&ie.payload))
     }
 }
 
@@ -263,6 +296,7 @@ pub struct SessionModificationResponseBuilder {
     pdn_type: Option<Ie>,
     usage_reports: Vec<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionModificationResponseBuilder {
@@ -286,6 +320,7 @@ impl SessionModificationResponseBuilder {
             pdn_type: None,
             usage_reports: Vec::new(),
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -371,6 +406,12 @@ impl SessionModificationResponseBuilder {
         self
     }
 
+    /// Adds a typed usage report (optional), encoded as IE type 78.
+    pub fn add_usage_report(mut self, usage_report: UsageReportSmr) -> Self {
+        self.usage_reports.push(usage_report.to_ie());
+        self
+    }
+
     /// Adds an additional IE.
     pub fn ie(mut self, ie: Ie) -> Self {
         self.ies.push(ie);
@@ -383,6 +424,12 @@ impl SessionModificationResponseBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
     /// Builds the SessionModificationResponse message.
     ///
     /// # Panics
@@ -392,7 +439,7 @@ impl SessionModificationResponseBuilder {
             .cause
             .expect("Cause IE is required for SessionModificationResponse");
 
-        SessionModificationResponse::new(
+        let mut message = SessionModificationResponse::new(
             self.seid,
             self.sequence,
             cause,
@@ -403,7 +450,9 @@ impl SessionModificationResponseBuilder {
             self.pdn_type,
             self.usage_reports,
             self.ies,
-        )
+        );
+        message.header.has_fo = self.follow_on;
+        message
     }
 
     /// Tries to build the SessionModificationResponse message.
@@ -415,7 +464,7 @@ impl SessionModificationResponseBuilder {
             .cause
             .ok_or("Cause IE is required for SessionModificationResponse")?;
 
-        Ok(SessionModificationResponse::new(
+        let mut message = SessionModificationResponse::new(
             self.seid,
             self.sequence,
             cause,
@@ -426,7 +475,9 @@ impl SessionModificationResponseBuilder {
             self.pdn_type,
             self.usage_reports,
             self.ies,
-        ))
+        );
+        message.header.has_fo = self.follow_on;
+        Ok(message)
     }
 
     /// Builds and marshals the SessionModificationResponse in one step.
@@ -442,7 +493,6 @@ mod tests {
     use crate::ie::sequence_number::SequenceNumber;
     use crate::ie::urr_id::UrrId;
     use crate::ie::usage_report::UsageReportBuilder;
-    use crate::ie::usage_report_smr::UsageReportSmr;
 
     #[test]
     fn test_session_modification_response_builder_minimal() {
@@ -690,4 +740,56 @@ mod tests {
         let unmarshaled = SessionModificationResponse::unmarshal(&marshaled).unwrap();
         assert_eq!(response, unmarshaled);
     }
+
+    #[test]
+    fn test_session_modification_response_typed_usage_reports_round_trip() {
+        use crate::ie::duration_measurement::DurationMeasurement;
+        use crate::ie::usage_report::UsageReport;
+        use crate::ie::usage_report_trigger::UsageReportTrigger;
+        use crate::ie::volume_measurement::VolumeMeasurement;
+
+        let minimal = UsageReportSmr::new(UsageReport::new(
+            UrrId::new(1),
+            SequenceNumber::new(10),
+            UsageReportTrigger::PERIO,
+        ));
+        let measured = UsageReportSmr::new(
+            UsageReportBuilder::new(UrrId::new(2))
+                .sequence_number(SequenceNumber::new(11))
+                .trigger(UsageReportTrigger::VOLTH)
+                .volume_measurement(VolumeMeasurement::new(
+                    0x07,
+                    Some(3000),
+                    Some(1000),
+                    Some(2000),
+                    None,
+                    None,
+                    None,
+                ))
+                .duration_measurement(DurationMeasurement::new(120))
+                .build()
+                .unwrap(),
+        );
+
+        let response = SessionModificationResponseBuilder::new(0x1234, 42)
+            .cause_accepted()
+            .add_usage_report(minimal.clone())
+            .add_usage_report(measured.clone())
+            .build();
+
+        let unmarshaled = SessionModificationResponse::unmarshal(&response.marshal()).unwrap();
+        assert_eq!(unmarshaled, response);
+
+        let reports: Vec<UsageReportSmr> = unmarshaled
+            .usage_reports_typed()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(reports, vec![minimal, measured.clone()]);
+        assert_eq!(reports[1].report.ur_seqn, SequenceNumber::new(11));
+        assert_eq!(
+            reports[1].report.duration_measurement,
+            Some(DurationMeasurement::new(120))
+        );
+        assert!(reports[1].report.volume_measurement.is_some());
+    }
 }
@@ -1,6 +1,8 @@
 //! Session Report Request message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::downlink_data_report::DownlinkDataReport;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -17,70 +19,89 @@ pub struct SessionReportRequest {
     pub overload_control_information: Option<Ie>,
     pub additional_usage_reports_information: Option<Ie>,
     pub pfcpsrreq_flags: Option<Ie>,
+    pub session_reports: Vec<Ie>,
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for SessionReportRequest {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl SessionReportRequest {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
+        self.header.write_into(buf);
         if let Some(ref ie) = self.report_type {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.downlink_data_report {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.usage_reports {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.load_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.overload_control_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.additional_usage_reports_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcpsrreq_flags {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
+        }
+        for ie in &self.session_reports {
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionReportRequest {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
         if let Some(ref ie) = self.report_type {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.downlink_data_report {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.usage_reports {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.load_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.overload_control_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.additional_usage_reports_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcpsrreq_flags {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        for ie in &self.session_reports {
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -94,24 +115,26 @@ impl Message for SessionReportRequest {
         let mut overload_control_information = None;
         let additional_usage_reports_information = None;
         let pfcpsrreq_flags = None;
+        let mut session_reports = Vec::new();
         let mut ies = Vec::new();
 
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::ReportType => report_type = Some(ie),
-                IeType::DownlinkDataServiceInformation => downlink_data_report = Some(ie),
+                IeType::DownlinkDataReport => downlink_data_report = Some(ie),
                 IeType::UsageReportWithinSessionReportRequest => usage_reports.push(ie),
                 IeType::LoadControlInformation => load_control_information = Some(ie),
                 IeType::OverloadControlInformation => overload_control_information = Some(ie),
+                IeType::SessionReport => session_reports.push(ie),
                 _ => ies.push(ie),
             }
             offset += ie_len;
         }
 
-        Ok(SessionReportRequest {
+        let mut message = SessionReportRequest {
             header,
             report_type,
             downlink_data_report,
@@ -120,8 +143,12 @@ impl Message for SessionReportRequest {
             overload_control_information,
             additional_usage_reports_information,
             pfcpsrreq_flags,
+            session_reports,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -149,7 +176,7 @@ impl Message for SessionReportRequest {
 
         match ie_type {
             IeType::ReportType => IeIter::single(self.report_type.as_ref(), ie_type),
-            IeType::DownlinkDataServiceInformation => {
+            IeType::DownlinkDataReport => {
                 IeIter::single(self.downlink_data_report.as_ref(), ie_type)
             }
             IeType::UsageReportWithinSessionReportRequest => {
@@ -164,10 +191,15 @@ impl Message for SessionReportRequest {
             IeType::AdditionalUsageReportsInformation => {
                 IeIter::single(self.additional_usage_reports_information.as_ref(), ie_type)
             }
+            IeType::SessionReport => IeIter::multiple(&self.session_reports, ie_type),
             _ => IeIter::generic(&self.ies, ie_type),
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = Vec::new();
         if let Some(ref ie) = self.report_type {
@@ -189,6 +221,7 @@ impl Message for SessionReportRequest {
         if let Some(ref ie) = self.pfcpsrreq_flags {
             result.push(ie);
         }
+        result.extend(self.session_reports.iter());
         result.extend(self.ies.iter());
         result
     }
@@ -196,6 +229,10 @@ impl Message for SessionReportRequest {
 
 impl SessionReportRequest {
     /// Creates a new Session Report Request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seid: impl Into<Seid>,
         sequence: impl Into<SequenceNumber>,
@@ -206,22 +243,22 @@ impl SessionReportRequest {
     ) -> Self {
         let mut payload_len = 0;
         if let Some(ie) = &report_type {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &downlink_data_report {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &usage_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::SessionReportRequest, true, seid, sequence);
-        header.length = payload_len + (header.len() - 4);
+        header.set_body_length(payload_len);
 
-        SessionReportRequest {
+        let mut message = SessionReportRequest {
             header,
             report_type,
             downlink_data_report,
@@ -230,8 +267,12 @@ impl SessionReportRequest {
             overload_control_information: None,
             additional_usage_reports_information: None,
             pfcpsrreq_flags: None,
+            session_reports: Vec::new(),
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
     }
 }
 
@@ -246,7 +287,9 @@ pub struct SessionReportRequestBuilder {
     overload_control_information: Option<Ie>,
     additional_usage_reports_information: Option<Ie>,
     pfcpsrreq_flags: Option<Ie>,
+    session_reports: Vec<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionReportRequestBuilder {
@@ -261,7 +304,9 @@ impl SessionReportRequestBuilder {
             overload_control_information: None,
             additional_usage_reports_information: None,
             pfcpsrreq_flags: None,
+            session_reports: Vec::new(),
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -270,7 +315,16 @@ impl SessionReportRequestBuilder {
         self
     }
 
-    pub fn downlink_data_report(mut self, downlink_data_report: Ie) -> Self {
+    /// Sets the Downlink Data Report for buffered downlink data.
+    ///
+    /// For a raw IE, use [`downlink_data_report_ie`](Self::downlink_data_report_ie).
+    pub fn downlink_data_report(mut self, report: DownlinkDataReport) -> Self {
+        self.downlink_data_report = Some(report.to_ie());
+        self
+    }
+
+    /// Sets the Downlink Data Report IE directly.
+    pub fn downlink_data_report_ie(mut self, downlink_data_report: Ie) -> Self {
         self.downlink_data_report = Some(downlink_data_report);
         self
     }
@@ -303,42 +357,74 @@ impl SessionReportRequestBuilder {
         self
     }
 
+    /// Adds a Session Report IE (optional, multiple allowed).
+    pub fn session_report(mut self, report: crate::ie::session_report::SessionReport) -> Self {
+        self.session_reports.push(report.to_ie());
+        self
+    }
+
     pub fn ies(mut self, ies: Vec<Ie>) -> Self {
         self.ies = ies;
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
+    /// Builds the SessionReportRequest message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message would not fit in the header Length field; see
+    /// [`try_build`](Self::try_build).
     pub fn build(self) -> SessionReportRequest {
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Tries to build the SessionReportRequest message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PfcpError::InvalidValue`] if the IEs add up to more than the
+    /// 65535 octets the header Length field can carry.
+    pub fn try_build(self) -> Result<SessionReportRequest, PfcpError> {
         let mut payload_len = 0;
         if let Some(ie) = &self.report_type {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.downlink_data_report {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.usage_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.load_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.overload_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.additional_usage_reports_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.pfcpsrreq_flags {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
+        }
+        for ie in &self.session_reports {
+            payload_len += ie.encoded_size();
         }
         for ie in &self.ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::SessionReportRequest, true, self.seid, self.seq);
-        header.length = payload_len + (header.len() - 4);
+        header.try_set_body_length(payload_len)?;
+        header.has_fo = self.follow_on;
 
-        SessionReportRequest {
+        let mut message = SessionReportRequest {
             header,
             report_type: self.report_type,
             downlink_data_report: self.downlink_data_report,
@@ -347,8 +433,12 @@ impl SessionReportRequestBuilder {
             overload_control_information: self.overload_control_information,
             additional_usage_reports_information: self.additional_usage_reports_information,
             pfcpsrreq_flags: self.pfcpsrreq_flags,
+            session_reports: self.session_reports,
             ies: self.ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     /// Builds and marshals the SessionReportRequest directly to bytes.
@@ -365,6 +455,7 @@ impl SessionReportRequestBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ie::pdr_id::PdrId;
     use crate::ie::sequence_number::SequenceNumber;
     use crate::ie::urr_id::UrrId;
     use crate::ie::usage_report::UsageReport;
@@ -452,9 +543,9 @@ mod tests {
         // Recalculate header length
         let mut payload_len = 0;
         if let Some(ie) = &req.load_control_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
-        req.header.length = payload_len + (req.header.len() - 4);
+        req.header.set_body_length(payload_len);
 
         let serialized = req.marshal();
         let unmarshaled = SessionReportRequest::unmarshal(&serialized).unwrap();
@@ -502,10 +593,7 @@ mod tests {
         let sequence = 0x112233;
 
         let report_type_ie = Ie::new(IeType::ReportType, vec![0x06]); // EVIR
-        let downlink_data_report_ie = Ie::new(
-            IeType::DownlinkDataServiceInformation,
-            vec![0x01, 0x02, 0x03],
-        );
+        let downlink_data_report_ie = DownlinkDataReport::new(PdrId::new(1)).to_ie();
 
         // Create multiple usage reports
         let usage_report1 = Ie::new(
@@ -524,7 +612,7 @@ mod tests {
 
         let req = SessionReportRequestBuilder::new(seid, sequence)
             .report_type(report_type_ie.clone())
-            .downlink_data_report(downlink_data_report_ie.clone())
+            .downlink_data_report_ie(downlink_data_report_ie.clone())
             .usage_reports(usage_reports.clone())
             .load_control_information(load_control_ie.clone())
             .overload_control_information(overload_control_ie.clone())
@@ -602,4 +690,81 @@ mod tests {
         assert!(unmarshaled.usage_reports.is_empty());
         assert!(unmarshaled.ies.is_empty());
     }
+
+    #[test]
+    fn test_session_report_request_with_session_report() {
+        use crate::ie::access_availability_information::{
+            AccessAvailabilityInformation, AccessType, AvailabilityStatus,
+        };
+        use crate::ie::access_availability_report::AccessAvailabilityReport;
+        use crate::ie::session_report::SessionReport;
+        use crate::ie::srr_id::SrrId;
+
+        let mut report = SessionReport::new(vec![SrrId::new(1)]);
+        report
+            .access_availability_reports
+            .push(AccessAvailabilityReport::new(
+                AccessAvailabilityInformation::new(
+                    AccessType::NonTgpp,
+                    AvailabilityStatus::Available,
+                ),
+            ));
+
+        let original = SessionReportRequestBuilder::new(0x1234, 42)
+            .session_report(report.clone())
+            .build();
+
+        let marshaled = original.marshal();
+        let unmarshaled = SessionReportRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert!(unmarshaled.ies.is_empty());
+
+        let ie = unmarshaled.ies(IeType::SessionReport).next().unwrap();
+        let parsed = SessionReport::unmarshal(&ie.payload).unwrap();
+        assert_eq!(parsed, report);
+        assert_eq!(parsed.srr_ids, vec![SrrId::new(1)]);
+        assert_eq!(
+            parsed.access_availability_reports[0]
+                .access_availability_information
+                .availability_status,
+            AvailabilityStatus::Available
+        );
+    }
+
+    #[test]
+    fn test_session_report_request_buffered_downlink_data_notification() {
+        use crate::ie::data_status::DataStatus;
+        use crate::ie::dl_data_packets_size::DlDataPacketsSize;
+        use crate::ie::downlink_data_service_information::DownlinkDataServiceInformation;
+        use crate::ie::report_type::ReportType;
+
+        // UPF buffered the first DL packet for PDR 2 (QFI 9) and notifies the SMF
+        let mut report =
+            DownlinkDataReport::new(PdrId::new(2)).with_downlink_data_service_information(
+                DownlinkDataServiceInformation::new(Some(1), Some(9)),
+            );
+        report.dl_data_packets_size = Some(DlDataPacketsSize::new(1400));
+        report.data_status = Some(DataStatus::BUFF);
+
+        let original = SessionReportRequestBuilder::new(0xABCD, 77)
+            .report_type(ReportType::DLDR.to_ie())
+            .downlink_data_report(report.clone())
+            .build();
+
+        let marshaled = original.marshal();
+        let unmarshaled = SessionReportRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(unmarshaled, original);
+        assert!(unmarshaled.ies.is_empty());
+
+        let report_type = unmarshaled.report_type.as_ref().unwrap();
+        assert!(ReportType::unmarshal(&report_type.payload)
+            .unwrap()
+            .contains(ReportType::DLDR));
+
+        let ie = unmarshaled.ies(IeType::DownlinkDataReport).next().unwrap();
+        let parsed = DownlinkDataReport::unmarshal(&ie.payload).unwrap();
+        assert_eq!(parsed, report);
+        assert_eq!(parsed.pdr_id, PdrId::new(2));
+        assert_eq!(parsed.downlink_data_service_informations[0].qfi, Some(9));
+    }
 }
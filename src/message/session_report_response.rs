@@ -1,6 +1,8 @@
 //! Session Report Response message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::update_bar_within_session_report_response::UpdateBarWithinSessionReportResponse;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -21,77 +23,89 @@ pub struct SessionReportResponse {
     pub additional_usage_reports_information: Option<Ie>,
     pub created_updated_usage_reports: Vec<Ie>,
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
-impl Message for SessionReportResponse {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl SessionReportResponse {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.cause.marshal_into(buf);
+        self.header.write_into(buf);
+        self.cause.write_into(buf);
         if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.update_bar_within_session_report_response {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.pfcpsrrsp_flags {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.cp_function_features {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.usage_reports {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.failed_rules_id {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         if let Some(ref ie) = self.additional_usage_reports_information {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.created_updated_usage_reports {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionReportResponse {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.update_bar_within_session_report_response {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.pfcpsrrsp_flags {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.cp_function_features {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.usage_reports {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.failed_rules_id {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         if let Some(ref ie) = self.additional_usage_reports_information {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.created_updated_usage_reports {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -112,7 +126,7 @@ impl Message for SessionReportResponse {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Cause => cause = Some(ie),
                 IeType::OffendingIe => offending_ie = Some(ie),
@@ -127,7 +141,7 @@ impl Message for SessionReportResponse {
             offset += ie_len;
         }
 
-        Ok(SessionReportResponse {
+        let mut message = SessionReportResponse {
             header,
             cause: cause.ok_or(PfcpError::MissingMandatoryIe {
                 ie_type: IeType::Cause,
@@ -143,7 +157,10 @@ impl Message for SessionReportResponse {
             additional_usage_reports_information,
             created_updated_usage_reports,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -191,6 +208,10 @@ impl Message for SessionReportResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.cause];
         if let Some(ref ie) = self.offending_ie {
@@ -220,6 +241,10 @@ impl Message for SessionReportResponse {
 
 impl SessionReportResponse {
     /// Creates a new Session Report Response.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seid: impl Into<Seid>,
         sequence: impl Into<SequenceNumber>,
@@ -228,21 +253,21 @@ impl SessionReportResponse {
         usage_reports: Vec<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = cause.len();
+        let mut payload_len = cause.encoded_size();
         if let Some(ie) = &offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &usage_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::SessionReportResponse, true, seid, sequence);
-        header.length = payload_len + (header.len() - 4);
+        header.set_body_length(payload_len);
 
-        SessionReportResponse {
+        let mut message = SessionReportResponse {
             header,
             cause,
             offending_ie,
@@ -254,7 +279,10 @@ impl SessionReportResponse {
             additional_usage_reports_information: None,
             created_updated_usage_reports: Vec::new(),
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
     }
 }
 
@@ -272,6 +300,7 @@ pub struct SessionReportResponseBuilder {
     additional_usage_reports_information: Option<Ie>,
     created_updated_usage_reports: Vec<Ie>,
     ies: Vec<Ie>,
+    follow_on: bool,
 }
 
 impl SessionReportResponseBuilder {
@@ -304,6 +333,7 @@ impl SessionReportResponseBuilder {
             additional_usage_reports_information: None,
             created_updated_usage_reports: Vec::new(),
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -350,6 +380,7 @@ impl SessionReportResponseBuilder {
             additional_usage_reports_information: None,
             created_updated_usage_reports: Vec::new(),
             ies: Vec::new(),
+            follow_on: false,
         }
     }
 
@@ -367,6 +398,12 @@ impl SessionReportResponseBuilder {
         self
     }
 
+    /// Sets the Update BAR IE, adjusting the buffering parameters of a BAR.
+    pub fn update_bar(mut self, bar: UpdateBarWithinSessionReportResponse) -> Self {
+        self.update_bar_within_session_report_response = Some(bar.to_ie());
+        self
+    }
+
     pub fn pfcpsrrsp_flags(mut self, pfcpsrrsp_flags: Ie) -> Self {
         self.pfcpsrrsp_flags = Some(pfcpsrrsp_flags);
         self
@@ -405,6 +442,12 @@ impl SessionReportResponseBuilder {
         self
     }
 
+    /// Sets the FO (Follow On) header flag; see [`Header::has_fo`].
+    pub fn follow_on(mut self, follow_on: bool) -> Self {
+        self.follow_on = follow_on;
+        self
+    }
+
     pub fn build(self) -> Result<SessionReportResponse, PfcpError> {
         let cause = self.cause.ok_or(PfcpError::MissingMandatoryIe {
             ie_type: IeType::Cause,
@@ -412,39 +455,40 @@ impl SessionReportResponseBuilder {
             parent_ie: None,
         })?;
 
-        let mut payload_len = cause.len();
+        let mut payload_len = cause.encoded_size();
         if let Some(ie) = &self.offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.update_bar_within_session_report_response {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.pfcpsrrsp_flags {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.cp_function_features {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.usage_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.failed_rules_id {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         if let Some(ie) = &self.additional_usage_reports_information {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.created_updated_usage_reports {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &self.ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::SessionReportResponse, true, self.seid, self.seq);
-        header.length = payload_len + (header.len() - 4);
+        header.try_set_body_length(payload_len)?;
+        header.has_fo = self.follow_on;
 
-        Ok(SessionReportResponse {
+        let mut message = SessionReportResponse {
             header,
             cause,
             offending_ie: self.offending_ie,
@@ -457,7 +501,10 @@ impl SessionReportResponseBuilder {
             additional_usage_reports_information: self.additional_usage_reports_information,
             created_updated_usage_reports: self.created_updated_usage_reports,
             ies: self.ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     /// Builds and marshals the SessionReportResponse directly to bytes.
@@ -3,6 +3,8 @@
 //! Session Set Deletion Request message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
+use crate::ie::fq_csid::FqCsid;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -14,40 +16,52 @@ pub struct SessionSetDeletionRequest {
     pub header: Header,
     pub node_id: Ie, // M - 3GPP TS 29.244 Table 7.4.6.1-1 - IE Type 60 - Node identity of originating node (Sxa/Sxb/N4 only, not Sxc/N4mb)
     pub fseid_set: Option<Ie>, // Note: Currently accepts F-SEID (Type 57), but spec defines FQ-CSID (Type 65) for session sets
-    // TODO: [IE Type 65] SGW-C FQ-CSID - C - Per clause 23 of 3GPP TS 23.007 (Sxa/Sxb only)
-    // TODO: [IE Type 65] PGW-C/SMF FQ-CSID - C - Per clause 23 of 3GPP TS 23.007 and clause 4.6 of 3GPP TS 23.527 (Sxa/Sxb/N4 only)
-    // TODO: [IE Type 65] PGW-U/SGW-U/UPF FQ-CSID - C - Per clause 23 of 3GPP TS 23.007 and clause 4.6 of 3GPP TS 23.527 (Sxa/Sxb/N4 only)
-    // TODO: [IE Type 65] TWAN FQ-CSID - C - Per clause 23 of 3GPP TS 23.007 (Sxb only)
-    // TODO: [IE Type 65] ePDG FQ-CSID - C - Per clause 23 of 3GPP TS 23.007 (Sxb only)
-    // TODO: [IE Type 65] MME FQ-CSID - C - Per clause 23 of 3GPP TS 23.007 (Sxa/Sxb only)
+    pub fq_csids: Vec<Ie>, // C - IE Type 65 - SGW-C, PGW-C/SMF, PGW-U/SGW-U/UPF, TWAN, ePDG and MME FQ-CSIDs, in the order they appear
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl SessionSetDeletionRequest {
     /// Creates a new Session Set Deletion Request message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         node_id: Ie,
         fseid_set: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = node_id.len();
+        let mut payload_len = node_id.encoded_size();
         if let Some(ref ie) = fseid_set {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::SessionSetDeletionRequest, false, 0, seq);
-        header.length = 4 + payload_len;
+        header.set_body_length(payload_len);
 
-        SessionSetDeletionRequest {
+        let mut message = SessionSetDeletionRequest {
             header,
             node_id,
             fseid_set,
+            fq_csids: Vec::new(),
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
+    }
+
+    /// Returns the FQ-CSIDs identifying the sessions to delete.
+    pub fn fq_csids(&self) -> Result<Vec<FqCsid>, PfcpError> {
+        self.fq_csids
+            .iter()
+            .map(|ie| FqCsid::unmarshal(&ie.payload))
+            .collect()
     }
 }
 
@@ -57,6 +71,7 @@ pub struct SessionSetDeletionRequestBuilder {
     sequence: SequenceNumber,
     node_id: Option<Ie>,
     fseid_set: Option<Ie>,
+    fq_csids: Vec<FqCsid>,
     ies: Vec<Ie>,
 }
 
@@ -67,6 +82,7 @@ impl SessionSetDeletionRequestBuilder {
             sequence: sequence.into(),
             node_id: None,
             fseid_set: None,
+            fq_csids: Vec::new(),
             ies: Vec::new(),
         }
     }
@@ -83,6 +99,18 @@ impl SessionSetDeletionRequestBuilder {
         self
     }
 
+    /// Sets the FQ-CSIDs (optional), replacing any added so far.
+    pub fn fq_csids(mut self, fq_csids: Vec<FqCsid>) -> Self {
+        self.fq_csids = fq_csids;
+        self
+    }
+
+    /// Adds an FQ-CSID (optional, may be repeated).
+    pub fn add_fq_csid(mut self, fq_csid: FqCsid) -> Self {
+        self.fq_csids.push(fq_csid);
+        self
+    }
+
     /// Adds additional Information Elements.
     pub fn additional_ies(mut self, mut ies: Vec<Ie>) -> Self {
         self.ies.append(&mut ies);
@@ -96,14 +124,16 @@ impl SessionSetDeletionRequestBuilder {
     }
 
     /// Builds the Session Set Deletion Request message.
-    /// Panics if required fields are missing.
+    /// Panics if required fields are missing or [`try_build`](Self::try_build)
+    /// fails.
     pub fn build(self) -> SessionSetDeletionRequest {
-        let node_id = self.node_id.expect("Node ID is required");
-        SessionSetDeletionRequest::new(self.sequence, node_id, self.fseid_set, self.ies)
+        assert!(self.node_id.is_some(), "Node ID is required");
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// Tries to build the Session Set Deletion Request message.
-    /// Returns an error if required fields are missing.
+    /// Returns an error if required fields are missing, an FQ-CSID cannot
+    /// be encoded, or the message is too long for the header Length field.
     pub fn try_build(self) -> Result<SessionSetDeletionRequest, PfcpError> {
         let node_id = self.node_id.ok_or_else(|| {
             PfcpError::validation_error(
@@ -112,12 +142,41 @@ impl SessionSetDeletionRequestBuilder {
                 "Node ID is required",
             )
         })?;
-        Ok(SessionSetDeletionRequest::new(
-            self.sequence,
+        let fq_csids = self
+            .fq_csids
+            .iter()
+            .map(FqCsid::to_ie)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut request = SessionSetDeletionRequest {
+            header: Header::new(MsgType::SessionSetDeletionRequest, false, 0, self.sequence),
             node_id,
-            self.fseid_set,
-            self.ies,
-        ))
+            fseid_set: self.fseid_set,
+            fq_csids,
+            ies: self.ies,
+            extra_ies: Vec::new(),
+        };
+        request.extra_ies = crate::message::unmapped_ies(&request, &request.ies);
+        let ies_len = request.marshaled_size() - usize::from(request.header.len());
+        request.header.try_set_body_length(ies_len)?;
+        Ok(request)
+    }
+}
+
+impl SessionSetDeletionRequest {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        if let Some(ref ie) = self.fseid_set {
+            ie.write_into(buf);
+        }
+        for ie in &self.fq_csids {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
     }
 }
 
@@ -133,25 +192,25 @@ impl Message for SessionSetDeletionRequest {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        if let Some(ref ie) = self.fseid_set {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
+        size += self.node_id.encoded_size();
         if let Some(ref ie) = self.fseid_set {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
+        }
+        for ie in &self.fq_csids {
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -163,15 +222,17 @@ impl Message for SessionSetDeletionRequest {
         let header = Header::unmarshal(buf)?;
         let mut node_id = None;
         let mut fseid_set = None;
+        let mut fq_csids = Vec::new();
         let mut ies = Vec::new();
 
         let mut cursor = header.len() as usize;
         while cursor < buf.len() {
             let ie = Ie::unmarshal(&buf[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::Fseid => fseid_set = Some(ie), // F-SEID for session set identification
+                IeType::FqCsid => fq_csids.push(ie),
                 _ => ies.push(ie),
             }
             cursor += ie_len;
@@ -183,12 +244,16 @@ impl Message for SessionSetDeletionRequest {
             parent_ie: None,
         })?;
 
-        Ok(SessionSetDeletionRequest {
+        let mut message = SessionSetDeletionRequest {
             header,
             node_id,
             fseid_set,
+            fq_csids,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -213,15 +278,21 @@ impl Message for SessionSetDeletionRequest {
         match ie_type {
             IeType::NodeId => IeIter::single(Some(&self.node_id), ie_type),
             IeType::Fseid => IeIter::single(self.fseid_set.as_ref(), ie_type),
+            IeType::FqCsid => IeIter::multiple(&self.fq_csids, ie_type),
             _ => IeIter::generic(&self.ies, ie_type),
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id];
         if let Some(ref ie) = self.fseid_set {
             result.push(ie);
         }
+        result.extend(self.fq_csids.iter());
         result.extend(self.ies.iter());
         result
     }
@@ -529,4 +600,41 @@ mod tests {
 
         assert_eq!(original, unmarshaled);
     }
+
+    #[test]
+    fn test_session_set_deletion_request_builder_fq_csids_round_trip() {
+        let node_id_ie = NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 1)).to_ie();
+        let sgw_c = FqCsid::new_ipv4(Ipv4Addr::new(192, 0, 2, 1), vec![1, 2, 3]);
+        let smf = FqCsid::new_ipv6("2001:db8::1".parse().unwrap(), vec![0x1234]);
+
+        let original = SessionSetDeletionRequestBuilder::new(321)
+            .node_id(node_id_ie)
+            .add_fq_csid(sgw_c.clone())
+            .add_fq_csid(smf.clone())
+            .try_build()
+            .unwrap();
+        assert_eq!(original.ies(IeType::FqCsid).count(), 2);
+
+        let marshaled = original.marshal();
+        assert_eq!(marshaled.len(), original.marshaled_size());
+        let parsed = crate::message::parse(&marshaled).unwrap();
+        assert_eq!(parsed.msg_type(), MsgType::SessionSetDeletionRequest);
+
+        let unmarshaled = SessionSetDeletionRequest::unmarshal(&marshaled).unwrap();
+        assert_eq!(original, unmarshaled);
+        assert_eq!(unmarshaled.fq_csids().unwrap(), vec![sgw_c, smf]);
+    }
+
+    #[test]
+    fn test_session_set_deletion_request_builder_try_build_invalid_fq_csid() {
+        let node_id_ie = NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 1)).to_ie();
+        // The CSID count is a 4-bit field
+        let oversized = FqCsid::new_ipv4(Ipv4Addr::new(192, 0, 2, 1), (0..16).collect());
+
+        let result = SessionSetDeletionRequestBuilder::new(1)
+            .node_id(node_id_ie)
+            .fq_csids(vec![oversized])
+            .try_build();
+        assert!(result.is_err());
+    }
 }
@@ -3,6 +3,7 @@
 //! Session Set Deletion Response message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -16,10 +17,15 @@ pub struct SessionSetDeletionResponse {
     pub cause: Ie, // M - 3GPP TS 29.244 Table 7.4.6.2-1 - IE Type 19 - Acceptance or rejection of request (Sxa/Sxb/N4 only, not Sxc/N4mb)
     pub offending_ie: Option<Ie>, // C - 3GPP TS 29.244 Table 7.4.6.2-1 - IE Type 40 - When rejection due to conditional/mandatory IE missing or faulty (Sxa/Sxb/N4 only, not Sxc/N4mb)
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
 }
 
 impl SessionSetDeletionResponse {
     /// Creates a new Session Set Deletion Response message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new(
         seq: impl Into<SequenceNumber>,
         node_id: Ie,
@@ -27,24 +33,27 @@ impl SessionSetDeletionResponse {
         offending_ie: Option<Ie>,
         ies: Vec<Ie>,
     ) -> Self {
-        let mut payload_len = node_id.len() + cause.len();
+        let mut payload_len = node_id.encoded_size() + cause.encoded_size();
         if let Some(ref ie) = offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::SessionSetDeletionResponse, false, 0, seq);
-        header.length = 4 + payload_len;
+        header.set_body_length(payload_len);
 
-        SessionSetDeletionResponse {
+        let mut message = SessionSetDeletionResponse {
             header,
             node_id,
             cause,
             offending_ie,
             ies,
-        }
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        message
     }
 }
 
@@ -146,7 +155,8 @@ impl SessionSetDeletionResponseBuilder {
     }
 
     /// Tries to build the Session Set Deletion Response message.
-    /// Returns an error if required fields are missing.
+    /// Returns an error if required fields are missing or the message is too
+    /// long for the header Length field.
     pub fn try_build(self) -> Result<SessionSetDeletionResponse, PfcpError> {
         let node_id = self.node_id.ok_or_else(|| {
             PfcpError::validation_error(
@@ -162,13 +172,18 @@ impl SessionSetDeletionResponseBuilder {
                 "Cause is required",
             )
         })?;
-        Ok(SessionSetDeletionResponse::new(
-            self.sequence,
+        let mut response = SessionSetDeletionResponse {
+            header: Header::new(MsgType::SessionSetDeletionResponse, false, 0, self.sequence),
             node_id,
             cause,
-            self.offending_ie,
-            self.ies,
-        ))
+            offending_ie: self.offending_ie,
+            ies: self.ies,
+            extra_ies: Vec::new(),
+        };
+        response.extra_ies = crate::message::unmapped_ies(&response, &response.ies);
+        let ies_len = response.marshaled_size() - usize::from(response.header.len());
+        response.header.try_set_body_length(ies_len)?;
+        Ok(response)
     }
 
     /// Builds the SessionSetDeletionResponse message and marshals it to bytes in one step.
@@ -192,6 +207,21 @@ impl SessionSetDeletionResponseBuilder {
     }
 }
 
+impl SessionSetDeletionResponse {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.node_id.write_into(buf);
+        self.cause.write_into(buf);
+        if let Some(ref ie) = self.offending_ie {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
+    }
+}
+
 impl Message for SessionSetDeletionResponse {
     fn msg_type(&self) -> MsgType {
         MsgType::SessionSetDeletionResponse
@@ -204,27 +234,23 @@ impl Message for SessionSetDeletionResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id.marshal_into(buf);
-        self.cause.marshal_into(buf);
-        if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.node_id.encoded_size();
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -242,7 +268,7 @@ impl Message for SessionSetDeletionResponse {
         let mut cursor = header.len() as usize;
         while cursor < buf.len() {
             let ie = Ie::unmarshal(&buf[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => node_id = Some(ie),
                 IeType::Cause => cause = Some(ie),
@@ -263,13 +289,16 @@ impl Message for SessionSetDeletionResponse {
             parent_ie: None,
         })?;
 
-        Ok(SessionSetDeletionResponse {
+        let mut message = SessionSetDeletionResponse {
             header,
             node_id,
             cause,
             offending_ie,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn seid(&self) -> Option<Seid> {
@@ -299,6 +328,10 @@ impl Message for SessionSetDeletionResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.node_id, &self.cause];
         if let Some(ref ie) = self.offending_ie {
@@ -6,6 +6,7 @@
 
 use crate::error::PfcpError;
 use crate::ie::alternative_smf_ip_address::AlternativeSmfIpAddress;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::cp_ip_address::CpIpAddress;
 use crate::ie::fq_csid::FqCsid;
 use crate::ie::group_id::GroupId;
@@ -35,6 +36,7 @@ pub struct SessionSetModificationRequest {
     pub group_ids: Option<Vec<GroupId>>,
     pub cp_ip_addresses: Option<Vec<CpIpAddress>>,
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
     // Raw IEs for additional/unknown IE types
     node_id_ie: Ie,
     alternative_smf_ip_address_ie: Ie,
@@ -43,59 +45,70 @@ pub struct SessionSetModificationRequest {
     cp_ip_addresses_ies: Option<Vec<Ie>>,
 }
 
-impl Message for SessionSetModificationRequest {
-    fn marshal(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(self.marshaled_size());
-        self.marshal_into(&mut buf);
-        buf
-    }
-
-    fn marshal_into(&self, buf: &mut Vec<u8>) {
+impl SessionSetModificationRequest {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
         buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.node_id_ie.marshal_into(buf);
-        self.alternative_smf_ip_address_ie.marshal_into(buf);
+        self.header.write_into(buf);
+        self.node_id_ie.write_into(buf);
+        self.alternative_smf_ip_address_ie.write_into(buf);
         if let Some(ref ies) = self.fq_csids_ies {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.group_ids_ies {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         if let Some(ref ies) = self.cp_ip_addresses_ies {
             for ie in ies {
-                ie.marshal_into(buf);
+                ie.write_into(buf);
             }
         }
         for ie in &self.ies {
-            ie.marshal_into(buf);
+            ie.write_into(buf);
         }
     }
+}
+
+impl Message for SessionSetModificationRequest {
+    fn marshal(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.marshaled_size());
+        self.marshal_into(&mut buf);
+        buf
+    }
+
+    fn marshal_into(&self, buf: &mut Vec<u8>) {
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
+    }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.node_id_ie.len() as usize;
-        size += self.alternative_smf_ip_address_ie.len() as usize;
+        size += self.node_id_ie.encoded_size();
+        size += self.alternative_smf_ip_address_ie.encoded_size();
         if let Some(ref ies) = self.fq_csids_ies {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.group_ids_ies {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         if let Some(ref ies) = self.cp_ip_addresses_ies {
             for ie in ies {
-                size += ie.len() as usize;
+                size += ie.encoded_size();
             }
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -112,7 +125,7 @@ impl Message for SessionSetModificationRequest {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::NodeId => {
                     if node_id.is_none() {
@@ -194,7 +207,7 @@ impl Message for SessionSetModificationRequest {
             (None, None)
         };
 
-        Ok(SessionSetModificationRequest {
+        let mut message = SessionSetModificationRequest {
             header,
             node_id,
             pfcp_session_change_info: Vec::new(), // TODO: Parse from IEs
@@ -208,7 +221,10 @@ impl Message for SessionSetModificationRequest {
             fq_csids_ies,
             group_ids_ies,
             cp_ip_addresses_ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -248,8 +264,12 @@ impl Message for SessionSetModificationRequest {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
-        let mut result = vec![&self.alternative_smf_ip_address_ie];
+        let mut result = vec![&self.node_id_ie, &self.alternative_smf_ip_address_ie];
         if let Some(ref vec) = self.fq_csids_ies {
             result.extend(vec.iter());
         }
@@ -358,12 +378,16 @@ impl SessionSetModificationRequestBuilder {
         // Create raw IE versions for backwards compatibility
         let node_id_ie = node_id.to_ie();
         let alternative_smf_ip_address_ie = alternative_smf_ip_address.to_ie();
-        let mut payload_len = node_id_ie.len() + alternative_smf_ip_address_ie.len();
+        let mut payload_len =
+            node_id_ie.encoded_size() + alternative_smf_ip_address_ie.encoded_size();
 
         let fq_csids_ies = if let Some(ref ies) = self.fq_csids {
-            let raw_ies: Vec<Ie> = ies.iter().map(|ie| ie.to_ie()).collect();
+            let raw_ies = ies
+                .iter()
+                .map(FqCsid::to_ie)
+                .collect::<Result<Vec<Ie>, _>>()?;
             for ie in &raw_ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
             Some(raw_ies)
         } else {
@@ -373,7 +397,7 @@ impl SessionSetModificationRequestBuilder {
         let group_ids_ies = if let Some(ref ies) = self.group_ids {
             let raw_ies: Vec<Ie> = ies.iter().map(|ie| ie.to_ie()).collect();
             for ie in &raw_ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
             Some(raw_ies)
         } else {
@@ -383,7 +407,7 @@ impl SessionSetModificationRequestBuilder {
         let cp_ip_addresses_ies = if let Some(ref ies) = self.cp_ip_addresses {
             let raw_ies: Vec<Ie> = ies.iter().map(|ie| ie.to_ie()).collect();
             for ie in &raw_ies {
-                payload_len += ie.len();
+                payload_len += ie.encoded_size();
             }
             Some(raw_ies)
         } else {
@@ -391,7 +415,7 @@ impl SessionSetModificationRequestBuilder {
         };
 
         for ie in &self.ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(
@@ -400,9 +424,9 @@ impl SessionSetModificationRequestBuilder {
             0,
             self.seq,
         );
-        header.length = payload_len + (header.len() - 4);
+        header.try_set_body_length(payload_len)?;
 
-        Ok(SessionSetModificationRequest {
+        let mut message = SessionSetModificationRequest {
             header,
             node_id,
             pfcp_session_change_info: self.pfcp_session_change_info.unwrap_or_default(),
@@ -416,7 +440,10 @@ impl SessionSetModificationRequestBuilder {
             fq_csids_ies,
             group_ids_ies,
             cp_ip_addresses_ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 }
 
@@ -538,4 +565,26 @@ mod tests {
         let all_fq_csids: Vec<_> = request.ies(IeType::FqCsid).collect();
         assert_eq!(all_fq_csids.len(), 2);
     }
+
+    #[test]
+    fn test_session_set_modification_request_parse_and_all_ies() {
+        let node_id = crate::ie::node_id::NodeId::new_ipv4(Ipv4Addr::new(10, 0, 0, 1));
+        let alt_smf_ip = AlternativeSmfIpAddress::new_ipv4(Ipv4Addr::new(192, 168, 1, 100));
+
+        let request = SessionSetModificationRequestBuilder::new(222)
+            .node_id(node_id)
+            .alternative_smf_ip_address(alt_smf_ip)
+            .add_fq_csid(FqCsid::new_ipv4(Ipv4Addr::new(1, 2, 3, 4), vec![1, 2]))
+            .add_fq_csid(FqCsid::new_ipv4(Ipv4Addr::new(5, 6, 7, 8), vec![3]))
+            .build()
+            .unwrap();
+
+        let parsed = crate::message::parse(&request.marshal()).unwrap();
+        assert_eq!(parsed.msg_type(), MsgType::SessionSetModificationRequest);
+        assert_eq!(parsed.ies(IeType::FqCsid).count(), 2);
+
+        let all = request.all_ies();
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[0].ie_type, IeType::NodeId);
+    }
 }
@@ -4,6 +4,7 @@
 //! as a response to the Session Set Modification Request message.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::cause::CauseValue;
 use crate::ie::offending_ie::OffendingIe;
 use crate::ie::{Ie, IeType};
@@ -22,6 +23,21 @@ pub struct SessionSetModificationResponse {
     pub cause: Ie, // M - 3GPP TS 29.244 Table 7.4.7.2-1 - IE Type 19 - Acceptance or rejection of request (Sxb/N4 only)
     pub offending_ie: Option<Ie>, // C - 3GPP TS 29.244 Table 7.4.7.2-1 - IE Type 40 - When rejection due to conditional/mandatory IE missing or faulty (Sxb/N4 only)
     pub ies: Vec<Ie>,
+    pub extra_ies: Vec<Ie>, // IEs of `ies` with no field of their own
+}
+
+impl SessionSetModificationResponse {
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        self.cause.write_into(buf);
+        if let Some(ref ie) = self.offending_ie {
+            ie.write_into(buf);
+        }
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
+    }
 }
 
 impl Message for SessionSetModificationResponse {
@@ -32,25 +48,22 @@ impl Message for SessionSetModificationResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        self.cause.marshal_into(buf);
-        if let Some(ref ie) = self.offending_ie {
-            ie.marshal_into(buf);
-        }
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
-        size += self.cause.len() as usize;
+        size += self.cause.encoded_size();
         if let Some(ref ie) = self.offending_ie {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -64,7 +77,7 @@ impl Message for SessionSetModificationResponse {
         let mut offset = header.len() as usize;
         while offset < data.len() {
             let ie = Ie::unmarshal(&data[offset..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             match ie.ie_type {
                 IeType::Cause => {
                     if cause.is_none() {
@@ -88,12 +101,15 @@ impl Message for SessionSetModificationResponse {
             parent_ie: None,
         })?;
 
-        Ok(SessionSetModificationResponse {
+        let mut message = SessionSetModificationResponse {
             header,
             cause,
             offending_ie,
             ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     fn msg_type(&self) -> MsgType {
@@ -122,6 +138,10 @@ impl Message for SessionSetModificationResponse {
         }
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        &self.extra_ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         let mut result = vec![&self.cause];
         if let Some(ref ie) = self.offending_ie {
@@ -210,14 +230,14 @@ impl SessionSetModificationResponseBuilder {
             parent_ie: None,
         })?;
 
-        let mut payload_len = cause.len();
+        let mut payload_len = cause.encoded_size();
 
         if let Some(ie) = &self.offending_ie {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         for ie in &self.ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(
@@ -226,14 +246,17 @@ impl SessionSetModificationResponseBuilder {
             0,
             self.seq,
         );
-        header.length = payload_len + (header.len() - 4);
+        header.try_set_body_length(payload_len)?;
 
-        Ok(SessionSetModificationResponse {
+        let mut message = SessionSetModificationResponse {
             header,
             cause,
             offending_ie: self.offending_ie,
             ies: self.ies,
-        })
+            extra_ies: Vec::new(),
+        };
+        message.extra_ies = crate::message::unmapped_ies(&message, &message.ies);
+        Ok(message)
     }
 
     /// Builds the SessionSetModificationResponse message and marshals it to bytes in one step.
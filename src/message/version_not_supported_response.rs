@@ -3,6 +3,7 @@
 //! Version Not Supported Response message implementation.
 
 use crate::error::PfcpError;
+use crate::ie::byte_sink::ByteSink;
 use crate::ie::{Ie, IeType};
 use crate::message::{header::Header, Message, MsgType};
 use crate::types::{Seid, SequenceNumber};
@@ -31,17 +32,37 @@ impl VersionNotSupportedResponse {
     }
 
     /// Creates a new Version Not Supported Response with additional IEs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the IEs do not fit in the 16-bit header Length field.
     pub fn new_with_ies(seq: impl Into<SequenceNumber>, ies: Vec<Ie>) -> Self {
         let mut payload_len = 0;
         for ie in &ies {
-            payload_len += ie.len();
+            payload_len += ie.encoded_size();
         }
 
         let mut header = Header::new(MsgType::VersionNotSupportedResponse, false, 0, seq);
-        header.length = 4 + payload_len;
+        header.set_body_length(payload_len);
 
         VersionNotSupportedResponse { header, ies }
     }
+
+    /// Returns the highest PFCP version supported by the sender.
+    ///
+    /// Per 3GPP TS 29.244 Section 7.4.4.7 this is carried in the header's
+    /// version field rather than in a dedicated IE.
+    pub fn supported_version(&self) -> u8 {
+        self.header.version
+    }
+
+    fn write_into<B: ByteSink>(&self, buf: &mut B) {
+        buf.reserve(self.marshaled_size());
+        self.header.write_into(buf);
+        for ie in &self.ies {
+            ie.write_into(buf);
+        }
+    }
 }
 
 impl Message for VersionNotSupportedResponse {
@@ -56,17 +77,18 @@ impl Message for VersionNotSupportedResponse {
     }
 
     fn marshal_into(&self, buf: &mut Vec<u8>) {
-        buf.reserve(self.marshaled_size());
-        self.header.marshal_into(buf);
-        for ie in &self.ies {
-            ie.marshal_into(buf);
-        }
+        self.write_into(buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    fn write_to_bytes(&self, buf: &mut bytes::BytesMut) {
+        self.write_into(buf);
     }
 
     fn marshaled_size(&self) -> usize {
         let mut size = self.header.len() as usize;
         for ie in &self.ies {
-            size += ie.len() as usize;
+            size += ie.encoded_size();
         }
         size
     }
@@ -81,7 +103,7 @@ impl Message for VersionNotSupportedResponse {
         let mut cursor = header.len() as usize;
         while cursor < buf.len() {
             let ie = Ie::unmarshal(&buf[cursor..])?;
-            let ie_len = ie.len() as usize;
+            let ie_len = ie.encoded_size();
             ies.push(ie);
             cursor += ie_len;
         }
@@ -89,6 +111,10 @@ impl Message for VersionNotSupportedResponse {
         Ok(VersionNotSupportedResponse { header, ies })
     }
 
+    fn version(&self) -> u8 {
+        self.header.version
+    }
+
     fn seid(&self) -> Option<Seid> {
         if self.header.has_seid {
             Some(self.header.seid)
@@ -111,6 +137,11 @@ impl Message for VersionNotSupportedResponse {
         IeIter::generic(&self.ies, ie_type)
     }
 
+    fn unknown_ies(&self) -> &[Ie] {
+        // No IEs are defined for this message, so every IE is unmapped
+        &self.ies
+    }
+
     fn all_ies(&self) -> Vec<&Ie> {
         self.ies.iter().collect()
     }
@@ -120,6 +151,7 @@ impl Message for VersionNotSupportedResponse {
 #[derive(Debug, Default)]
 pub struct VersionNotSupportedResponseBuilder {
     sequence: SequenceNumber,
+    supported_version: Option<u8>,
     ies: Vec<Ie>,
 }
 
@@ -128,10 +160,19 @@ impl VersionNotSupportedResponseBuilder {
     pub fn new(sequence: impl Into<SequenceNumber>) -> Self {
         Self {
             sequence: sequence.into(),
+            supported_version: None,
             ies: Vec::new(),
         }
     }
 
+    /// Sets the highest PFCP version supported by the sender.
+    ///
+    /// Written to the 3-bit version field of the header; defaults to 1.
+    pub fn supported_version(mut self, version: u8) -> Self {
+        self.supported_version = Some(version & 0x07);
+        self
+    }
+
     /// Adds an IE to the message.
     pub fn ie(mut self, ie: Ie) -> Self {
         self.ies.push(ie);
@@ -146,11 +187,15 @@ impl VersionNotSupportedResponseBuilder {
 
     /// Builds the VersionNotSupportedResponse message.
     pub fn build(self) -> VersionNotSupportedResponse {
-        if self.ies.is_empty() {
+        let mut response = if self.ies.is_empty() {
             VersionNotSupportedResponse::new(self.sequence)
         } else {
             VersionNotSupportedResponse::new_with_ies(self.sequence, self.ies)
+        };
+        if let Some(version) = self.supported_version {
+            response.header.version = version;
         }
+        response
     }
 }
 
@@ -299,4 +344,42 @@ mod tests {
         assert_eq!(with_ies_response.ies.len(), 1);
         assert_ne!(empty_response.sequence(), with_ies_response.sequence());
     }
+
+    #[test]
+    fn test_version_not_supported_response_builder_supported_version() {
+        let response = VersionNotSupportedResponseBuilder::new(77)
+            .supported_version(2)
+            .build();
+
+        assert_eq!(response.supported_version(), 2);
+        assert_eq!(response.version(), 2);
+
+        let marshaled = response.marshal();
+        assert_eq!(marshaled[0] >> 5, 2);
+        let unmarshaled = VersionNotSupportedResponse::unmarshal(&marshaled).unwrap();
+        assert_eq!(unmarshaled.supported_version(), 2);
+        assert_eq!(response, unmarshaled);
+    }
+
+    #[test]
+    fn test_version_not_supported_response_parse_wire_format() {
+        let wire = [
+            0x20, // Version 1, no FO/MP/S flags
+            0x0B, // Message type 11: Version Not Supported Response
+            0x00, 0x04, // Length: sequence number + spare only
+            0x00, 0x01, 0x02, // Sequence number
+            0x00, // Spare
+        ];
+
+        let response = VersionNotSupportedResponse::unmarshal(&wire).unwrap();
+        assert_eq!(response.msg_type(), MsgType::VersionNotSupportedResponse);
+        assert_eq!(response.supported_version(), 1);
+        assert_eq!(*response.sequence(), 0x000102);
+        assert!(response.ies.is_empty());
+        assert_eq!(response.marshal(), wire);
+
+        let parsed = crate::message::parse(&wire).unwrap();
+        assert_eq!(parsed.msg_type(), MsgType::VersionNotSupportedResponse);
+        assert_eq!(parsed.version(), 1);
+    }
 }
@@ -61,7 +61,7 @@ fn test_association_setup_response_marshal_unmarshal() {
     use rs_pfcp::ie::node_id::NodeId;
     use rs_pfcp::message::association_setup_response::AssociationSetupResponse;
 
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
     let node_id = NodeId::new_ipv4(Ipv4Addr::new(127, 0, 0, 1));
     let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
     let up_features_ie = Ie::new(IeType::UpFunctionFeatures, vec![0x01, 0x02]);
@@ -72,12 +72,12 @@ fn test_association_setup_response_marshal_unmarshal() {
     );
 
     let mut header = Header::new(MsgType::AssociationSetupResponse, false, 0, 0x112233);
-    let payload_len = cause_ie.len()
-        + node_id_ie.len()
-        + up_features_ie.len()
-        + cp_features_ie.len()
-        + ts_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len = cause_ie.encoded_size()
+        + node_id_ie.encoded_size()
+        + up_features_ie.encoded_size()
+        + cp_features_ie.encoded_size()
+        + ts_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     let res = AssociationSetupResponse {
         header,
@@ -92,6 +92,7 @@ fn test_association_setup_response_marshal_unmarshal() {
         gtpu_path_qos_control_information: vec![],
         nf_instance_id: None,
         ies: vec![],
+        extra_ies: vec![],
     };
 
     let serialized = res.marshal();
@@ -129,17 +130,30 @@ fn test_association_setup_response_from_request() {
         None,
         None,
         vec![],
+        vec![],
     );
 
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
 
     let mut header = Header::new(MsgType::AssociationSetupResponse, false, 0, req.sequence());
-    header.length = 8
-        + cause_ie.len()
-        + req.ies(IeType::NodeId).next().unwrap().len()
-        + req.ies(IeType::UpFunctionFeatures).next().unwrap().len()
-        + req.ies(IeType::CpFunctionFeatures).next().unwrap().len()
-        + req.ies(IeType::RecoveryTimeStamp).next().unwrap().len();
+    header.length = (8
+        + cause_ie.encoded_size()
+        + req.ies(IeType::NodeId).next().unwrap().encoded_size()
+        + req
+            .ies(IeType::UpFunctionFeatures)
+            .next()
+            .unwrap()
+            .encoded_size()
+        + req
+            .ies(IeType::CpFunctionFeatures)
+            .next()
+            .unwrap()
+            .encoded_size()
+        + req
+            .ies(IeType::RecoveryTimeStamp)
+            .next()
+            .unwrap()
+            .encoded_size()) as u16;
 
     let res = AssociationSetupResponse {
         header,
@@ -154,6 +168,7 @@ fn test_association_setup_response_from_request() {
         gtpu_path_qos_control_information: vec![],
         nf_instance_id: None,
         ies: vec![],
+        extra_ies: vec![],
     };
 
     assert_eq!(res.msg_type(), MsgType::AssociationSetupResponse);
@@ -176,15 +191,18 @@ fn test_association_update_request_marshal_unmarshal() {
     let cp_features_ie = Ie::new(IeType::CpFunctionFeatures, vec![0x03]);
 
     let mut header = Header::new(MsgType::AssociationUpdateRequest, false, 0, 0x112233);
-    let payload_len = node_id_ie.len() + up_features_ie.len() + cp_features_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len =
+        node_id_ie.encoded_size() + up_features_ie.encoded_size() + cp_features_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     let req = AssociationUpdateRequest {
         header,
         node_id: node_id_ie.clone(),
         up_function_features: Some(up_features_ie.clone()),
         cp_function_features: Some(cp_features_ie.clone()),
+        ue_ip_address_usage_information: vec![],
         ies: vec![],
+        extra_ies: vec![],
     };
 
     let serialized = req.marshal();
@@ -215,7 +233,7 @@ fn test_association_release_response_marshal_unmarshal() {
     use rs_pfcp::ie::node_id::NodeId;
     use rs_pfcp::message::association_release_response::AssociationReleaseResponse;
 
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
     let node_id = NodeId::new_ipv4(Ipv4Addr::new(127, 0, 0, 1));
     let node_id_ie = Ie::new(IeType::NodeId, node_id.marshal());
 
@@ -232,7 +250,7 @@ fn test_pfd_management_response_marshal_unmarshal() {
     use rs_pfcp::ie::cause::CauseValue;
     use rs_pfcp::message::pfd_management_response::PfdManagementResponse;
 
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
     let offending_ie = Ie::new(IeType::OffendingIe, vec![0x01, 0x02, 0x03, 0x04]);
 
     let res = PfdManagementResponse::new(
@@ -265,8 +283,12 @@ fn test_session_establishment_request_marshal_unmarshal() {
         vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
     );
 
-    let pdr_ie = Ie::new(IeType::CreatePdr, vec![0x01, 0x02, 0x03, 0x04]);
-    let far_ie = Ie::new(IeType::CreateFar, vec![0x05, 0x06, 0x07, 0x08]);
+    // PDR ID and FAR ID children, so the IEs also parse with eager-parse
+    let pdr_ie = Ie::new(IeType::CreatePdr, vec![0x00, 0x38, 0x00, 0x02, 0x00, 0x01]);
+    let far_ie = Ie::new(
+        IeType::CreateFar,
+        vec![0x00, 0x6C, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01],
+    );
     let req = SessionEstablishmentRequestBuilder::new(0x1122334455667788, 0x112233)
         .node_id_ie(node_id_ie.clone())
         .fseid_ie(fseid_ie.clone())
@@ -307,7 +329,7 @@ fn test_session_deletion_response_marshal_unmarshal() {
     use rs_pfcp::ie::cause::CauseValue;
     use rs_pfcp::message::session_deletion_response::SessionDeletionResponse;
 
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
 
     let res = SessionDeletionResponse::new(
         0x1122334455667788,
@@ -341,8 +363,12 @@ fn test_session_modification_request_marshal_unmarshal() {
         IeType::Fseid,
         vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
     );
-    let pdr_ie = Ie::new(IeType::CreatePdr, vec![0x01, 0x02, 0x03, 0x04]);
-    let far_ie = Ie::new(IeType::CreateFar, vec![0x05, 0x06, 0x07, 0x08]);
+    // PDR ID and FAR ID children, so the IEs also parse with eager-parse
+    let pdr_ie = Ie::new(IeType::CreatePdr, vec![0x00, 0x38, 0x00, 0x02, 0x00, 0x01]);
+    let far_ie = Ie::new(
+        IeType::CreateFar,
+        vec![0x00, 0x6C, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01],
+    );
     let req = SessionModificationRequestBuilder::new(0x1122334455667788, 0x112233)
         .fseid_ie(fseid_ie.clone())
         .create_pdrs(vec![pdr_ie.clone()])
@@ -360,7 +386,7 @@ fn test_session_modification_response_marshal_unmarshal() {
     use rs_pfcp::ie::cause::CauseValue;
     use rs_pfcp::message::session_modification_response::SessionModificationResponse;
 
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
     let pdr_ie = Ie::new(IeType::CreatedPdr, vec![0x01, 0x02, 0x03, 0x04]);
 
     let mut header = Header::new(
@@ -369,8 +395,8 @@ fn test_session_modification_response_marshal_unmarshal() {
         0x1122334455667788,
         0x112233,
     );
-    let payload_len = cause_ie.len() + pdr_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len = cause_ie.encoded_size() + pdr_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     let res = SessionModificationResponse {
         header,
@@ -382,6 +408,7 @@ fn test_session_modification_response_marshal_unmarshal() {
         pdn_type: None,
         usage_reports: vec![],
         ies: vec![],
+        extra_ies: vec![],
     };
 
     let serialized = res.marshal();
@@ -398,7 +425,7 @@ fn test_session_report_response_marshal_unmarshal_minimal() {
 
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
 
     let res = SessionReportResponse::new(seid, sequence, cause_ie.clone(), None, vec![], vec![]);
 
@@ -419,7 +446,10 @@ fn test_session_report_response_marshal_unmarshal_with_offending_ie() {
 
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::MandatoryIeMissing as u8]);
+    let cause_ie = Ie::new(
+        IeType::Cause,
+        vec![u8::from(CauseValue::MandatoryIeMissing)],
+    );
     let offending_ie = Ie::new(IeType::OffendingIe, vec![0x01, 0x02, 0x03, 0x04]);
 
     let res = SessionReportResponse::new(
@@ -450,7 +480,7 @@ fn test_session_report_response_marshal_unmarshal_with_usage_reports() {
 
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
 
     // Create usage report IE
     let urr_id = UrrId::new(1);
@@ -489,7 +519,7 @@ fn test_session_report_response_builder() {
 
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
     let usage_report_ie = Ie::new(
         IeType::UsageReportWithinSessionReportRequest,
         vec![0x01, 0x02, 0x03, 0x04],
@@ -521,7 +551,7 @@ fn test_session_report_response_builder_comprehensive() {
 
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
     let offending_ie = Ie::new(IeType::OffendingIe, vec![0x01, 0x02, 0x03, 0x04]);
     let update_bar_ie = Ie::new(
         IeType::UpdateBarWithinSessionReportResponse,
@@ -569,6 +599,41 @@ fn test_session_report_response_builder_comprehensive() {
     assert_eq!(res, unmarshaled);
 }
 
+#[test]
+fn test_session_report_response_update_bar() {
+    use rs_pfcp::ie::bar_id::BarId;
+    use rs_pfcp::ie::dl_buffering_duration::DlBufferingDuration;
+    use rs_pfcp::ie::timer::{Timer, TimerUnit};
+    use rs_pfcp::ie::update_bar_within_session_report_response::UpdateBarWithinSessionReportResponse;
+    use rs_pfcp::message::session_report_response::SessionReportResponseBuilder;
+
+    let update_bar = UpdateBarWithinSessionReportResponse::new(BarId::new(3))
+        .with_dl_buffering_duration(DlBufferingDuration::new(
+            Timer::new(TimerUnit::TenMinutes, 2).unwrap(),
+        ))
+        .with_dl_buffering_suggested_packet_count(512)
+        .with_suggested_buffering_packets_count(16);
+
+    let serialized = SessionReportResponseBuilder::accepted(0x1122334455667788u64, 0x000042)
+        .update_bar(update_bar.clone())
+        .marshal()
+        .unwrap();
+
+    let parsed = SessionReportResponse::unmarshal(&serialized).unwrap();
+    let ie = parsed
+        .ies(IeType::UpdateBarWithinSessionReportResponse)
+        .next()
+        .unwrap();
+    let parsed_bar = UpdateBarWithinSessionReportResponse::unmarshal(&ie.payload).unwrap();
+    assert_eq!(parsed_bar, update_bar);
+    assert_eq!(
+        parsed_bar
+            .suggested_buffering_packets_count
+            .map(|count| count.count),
+        Some(16)
+    );
+}
+
 #[test]
 fn test_session_report_response_set_sequence() {
     use rs_pfcp::ie::cause::CauseValue;
@@ -577,7 +642,7 @@ fn test_session_report_response_set_sequence() {
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
     let new_sequence = 0x445566;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
 
     let mut res = SessionReportResponse::new(seid, sequence, cause_ie, None, vec![], vec![]);
 
@@ -593,7 +658,7 @@ fn test_session_report_response_ies() {
 
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
     let usage_report_ie = Ie::new(
         IeType::UsageReportWithinSessionReportRequest,
         vec![0x01, 0x02],
@@ -623,10 +688,10 @@ fn test_session_report_response_empty_unmarshal() {
 
     let seid = 0x1122334455667788;
     let sequence = 0x112233;
-    let cause_ie = Ie::new(IeType::Cause, vec![CauseValue::RequestAccepted as u8]);
+    let cause_ie = Ie::new(IeType::Cause, vec![u8::from(CauseValue::RequestAccepted)]);
 
     let mut header = Header::new(MsgType::SessionReportResponse, true, seid, sequence);
-    header.length = cause_ie.len() + (header.len() - 4);
+    header.length = cause_ie.encoded_size() as u16 + (header.len() - 4);
 
     let mut serialized = header.marshal();
     serialized.extend_from_slice(&cause_ie.marshal());
@@ -879,3 +944,111 @@ fn test_version_not_supported_response_parse_integration() {
     assert_eq!(*parsed_message.sequence(), 0x654321);
     assert!(parsed_message.ies(IeType::OffendingIe).next().is_some());
 }
+
+#[test]
+fn test_bundled_session_messages_follow_on() {
+    use rs_pfcp::ie::create_far::CreateFarBuilder;
+    use rs_pfcp::ie::create_pdr::CreatePdr;
+    use rs_pfcp::ie::far_id::FarId;
+    use rs_pfcp::ie::pdi::Pdi;
+    use rs_pfcp::ie::pdr_id::PdrId;
+    use rs_pfcp::ie::precedence::Precedence;
+    use rs_pfcp::ie::source_interface::{SourceInterface, SourceInterfaceValue};
+    use rs_pfcp::message::session_establishment_request::{
+        SessionEstablishmentRequest, SessionEstablishmentRequestBuilder,
+    };
+    use rs_pfcp::message::session_establishment_response::{
+        SessionEstablishmentResponse, SessionEstablishmentResponseBuilder,
+    };
+    use rs_pfcp::message::session_modification_request::{
+        SessionModificationRequest, SessionModificationRequestBuilder,
+    };
+    use rs_pfcp::message::session_modification_response::{
+        SessionModificationResponse, SessionModificationResponseBuilder,
+    };
+
+    let smf = Ipv4Addr::new(10, 0, 0, 1);
+    let pdr = CreatePdr::new(
+        PdrId::new(1),
+        Precedence::new(100),
+        Pdi::new(
+            SourceInterface::new(SourceInterfaceValue::Access),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let far = CreateFarBuilder::uplink_to_core(FarId::new(1))
+        .build()
+        .unwrap();
+
+    // CP function bundles an establishment and a modification in one datagram
+    let establishment = SessionEstablishmentRequestBuilder::new(0, 1)
+        .node_id(smf)
+        .fseid(0x1111, smf)
+        .add_pdr(pdr)
+        .add_far(far)
+        .follow_on(true)
+        .build()
+        .unwrap();
+    let modification = SessionModificationRequestBuilder::new(0x2222, 2)
+        .fseid(0x2222, smf)
+        .build();
+
+    let mut datagram = establishment.marshal();
+    let first_len = datagram.len();
+    modification.marshal_into(&mut datagram);
+    assert_eq!(datagram[0] & 0x04, 0x04);
+    assert_eq!(datagram[first_len] & 0x04, 0);
+
+    let parsed = rs_pfcp::message::parse_all(&datagram);
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(
+        parsed[0].as_ref().unwrap().msg_type(),
+        MsgType::SessionEstablishmentRequest
+    );
+    assert_eq!(
+        parsed[1].as_ref().unwrap().msg_type(),
+        MsgType::SessionModificationRequest
+    );
+
+    let parsed_establishment =
+        SessionEstablishmentRequest::unmarshal(&datagram[..first_len]).unwrap();
+    assert!(parsed_establishment.header.has_fo);
+    assert_eq!(parsed_establishment, establishment);
+    let parsed_modification =
+        SessionModificationRequest::unmarshal(&datagram[first_len..]).unwrap();
+    assert!(!parsed_modification.header.has_fo);
+
+    // UP function answers with bundled responses
+    let establishment_response = SessionEstablishmentResponseBuilder::accepted(0x1111, 1)
+        .node_id(Ipv4Addr::new(10, 0, 0, 2))
+        .fseid(0x3333, Ipv4Addr::new(10, 0, 0, 2))
+        .follow_on(true)
+        .build()
+        .unwrap();
+    let modification_response = SessionModificationResponseBuilder::new(0x2222, 2)
+        .cause_accepted()
+        .build();
+
+    let mut datagram = establishment_response.marshal();
+    let first_len = datagram.len();
+    modification_response.marshal_into(&mut datagram);
+
+    let first = Header::unmarshal(&datagram).unwrap();
+    assert!(first.has_fo);
+    assert_eq!(first.message_type, MsgType::SessionEstablishmentResponse);
+    let parsed_response = SessionEstablishmentResponse::unmarshal(&datagram[..first_len]).unwrap();
+    // The FO flag survives a round trip
+    assert_eq!(parsed_response.marshal(), datagram[..first_len]);
+    let parsed_response = SessionModificationResponse::unmarshal(&datagram[first_len..]).unwrap();
+    assert!(!parsed_response.header.has_fo);
+}
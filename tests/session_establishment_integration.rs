@@ -131,8 +131,11 @@ fn test_session_establishment_happy_path_with_all_mandatory_ies() {
         TEST_SEID,
         TEST_SEQUENCE,
     );
-    let payload_len = node_id_ie.len() + fseid_ie.len() + create_pdr_ie.len() + create_far_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len = node_id_ie.encoded_size()
+        + fseid_ie.encoded_size()
+        + create_pdr_ie.encoded_size()
+        + create_far_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     let req = SessionEstablishmentRequest {
         header,
@@ -145,6 +148,7 @@ fn test_session_establishment_happy_path_with_all_mandatory_ies() {
         create_bars: vec![],
         create_traffic_endpoints: vec![],
         pdn_type: None,
+        fq_csids: vec![],
         user_plane_inactivity_timer: None,
         user_id: None,
         trace_information: None,
@@ -152,9 +156,11 @@ fn test_session_establishment_happy_path_with_all_mandatory_ies() {
         pfcpsm_req_flags: None,
         recovery_time_stamp: None,
         s_nssai: None,
+        hplmn_s_nssai: None,
         cp_function_features: None,
         ethernet_pdu_session_information: None,
         ies: vec![],
+        extra_ies: vec![],
     };
 
     // Marshal and unmarshal to verify round-trip
@@ -193,8 +199,9 @@ fn test_session_establishment_request_missing_node_id() {
         TEST_SEID,
         TEST_SEQUENCE,
     );
-    let payload_len = fseid_ie.len() + create_pdr_ie.len() + create_far_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len =
+        fseid_ie.encoded_size() + create_pdr_ie.encoded_size() + create_far_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     // Create a raw message WITHOUT NodeId to test parsing
     let mut raw_message = Vec::new();
@@ -234,8 +241,9 @@ fn test_session_establishment_request_missing_fseid() {
         TEST_SEID,
         TEST_SEQUENCE,
     );
-    let payload_len = node_id_ie.len() + create_pdr_ie.len() + create_far_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len =
+        node_id_ie.encoded_size() + create_pdr_ie.encoded_size() + create_far_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     // Create a raw message WITHOUT F-SEID to test parsing
     let mut raw_message = Vec::new();
@@ -275,8 +283,9 @@ fn test_session_establishment_request_missing_create_pdrs() {
         TEST_SEID,
         TEST_SEQUENCE,
     );
-    let payload_len = node_id_ie.len() + fseid_ie.len() + create_far_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len =
+        node_id_ie.encoded_size() + fseid_ie.encoded_size() + create_far_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     // Create a raw message WITHOUT Create PDRs to test parsing
     let mut raw_message = Vec::new();
@@ -320,8 +329,9 @@ fn test_session_establishment_request_missing_create_fars() {
         TEST_SEID,
         TEST_SEQUENCE,
     );
-    let payload_len = node_id_ie.len() + fseid_ie.len() + create_pdr_ie.len();
-    header.length = payload_len + header.len() - 4;
+    let payload_len =
+        node_id_ie.encoded_size() + fseid_ie.encoded_size() + create_pdr_ie.encoded_size();
+    header.length = payload_len as u16 + header.len() - 4;
 
     // Create a raw message WITHOUT Create FARs to test parsing
     let mut raw_message = Vec::new();
@@ -494,14 +504,14 @@ fn test_session_establishment_multiple_pdrs_fars() {
         TEST_SEID,
         TEST_SEQUENCE,
     );
-    let mut payload_len = node_id_ie.len() + fseid_ie.len();
+    let mut payload_len = node_id_ie.encoded_size() + fseid_ie.encoded_size();
     for ie in &create_pdr_ies {
-        payload_len += ie.len();
+        payload_len += ie.encoded_size();
     }
     for ie in &create_far_ies {
-        payload_len += ie.len();
+        payload_len += ie.encoded_size();
     }
-    header.length = payload_len + header.len() - 4;
+    header.length = payload_len as u16 + header.len() - 4;
 
     let req = SessionEstablishmentRequest {
         header,
@@ -514,6 +524,7 @@ fn test_session_establishment_multiple_pdrs_fars() {
         create_bars: vec![],
         create_traffic_endpoints: vec![],
         pdn_type: None,
+        fq_csids: vec![],
         user_plane_inactivity_timer: None,
         user_id: None,
         trace_information: None,
@@ -521,9 +532,11 @@ fn test_session_establishment_multiple_pdrs_fars() {
         pfcpsm_req_flags: None,
         recovery_time_stamp: None,
         s_nssai: None,
+        hplmn_s_nssai: None,
         cp_function_features: None,
         ethernet_pdu_session_information: None,
         ies: vec![],
+        extra_ies: vec![],
     };
 
     // Marshal and unmarshal to verify round-trip
@@ -2,8 +2,10 @@
 
 use rs_pfcp::error::PfcpError;
 use rs_pfcp::ie::bar_id::BarId;
+use rs_pfcp::ie::dl_buffering_duration::DlBufferingDuration;
 use rs_pfcp::ie::suggested_buffering_packets_count::SuggestedBufferingPacketsCount;
-use rs_pfcp::ie::update_bar::UpdateBar;
+use rs_pfcp::ie::timer::{Timer, TimerUnit};
+use rs_pfcp::ie::update_bar::{UpdateBar, UpdateBarBuilder};
 use rs_pfcp::ie::IeType;
 
 #[test]
@@ -161,3 +163,32 @@ fn test_update_bar_debug() {
 
     assert!(debug_string.contains("UpdateBar"));
 }
+
+#[test]
+fn test_update_bar_builder_round_trip() {
+    let update_bar = UpdateBarBuilder::new(BarId::new(7))
+        .downlink_data_notification_delay(4)
+        .suggested_buffering_packets_count(25)
+        .dl_buffering_duration(DlBufferingDuration::new(
+            Timer::new(TimerUnit::OneMinute, 2).unwrap(),
+        ))
+        .build();
+
+    assert!(update_bar.downlink_data_notification_delay.is_some());
+    assert_eq!(
+        update_bar.suggested_buffering_packets_count,
+        Some(SuggestedBufferingPacketsCount::new(25))
+    );
+
+    let ie = update_bar.to_ie();
+    assert_eq!(ie.ie_type, IeType::UpdateBar);
+    let unmarshaled = UpdateBar::unmarshal(&ie.payload).unwrap();
+    assert_eq!(unmarshaled, update_bar);
+}
+
+#[test]
+fn test_update_bar_builder_minimal_matches_new() {
+    let built = UpdateBar::builder(BarId::new(8)).build();
+
+    assert_eq!(built, UpdateBar::new(BarId::new(8), None));
+}